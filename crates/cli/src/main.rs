@@ -5,8 +5,8 @@ use std::env;
 fn print_help() {
     println!("Selenet CLI\n");
     println!("USAGE:\n  selenet <command> [args]\n");
-    println!("COMMANDS:\n  encode <text>             Percent-encode input (RFC3986 unreserved as-is)\n  decode <text>             Percent-decode input\n  url encode [--form] <t>   URL encode (RFC3986 or form mode)\n  url decode [--form] <t>   URL decode (RFC3986 or form mode)\n  url parse <url>           Parse URL into components\n  url serialize <url>       Parse then serialize URL\n  help                      Show this help\n");
-    println!("日本語:\n  encode <text>             入力をパーセントエンコード（RFC3986 非予約は素通し）\n  decode <text>             入力をパーセントデコード\n  url encode [--form] <t>   URL エンコード（RFC3986/フォーム互換）\n  url decode [--form] <t>   URL デコード（RFC3986/フォーム互換）\n  url parse <url>           URL を解析して構成要素を表示\n  url serialize <url>       URL を解析して正規化して出力\n  help                      このヘルプを表示\n");
+    println!("COMMANDS:\n  encode <text>             Percent-encode input (RFC3986 unreserved as-is)\n  decode <text>             Percent-decode input\n  url encode [--form] <t>   URL encode (RFC3986 or form mode)\n  url decode [--form] <t>   URL decode (RFC3986 or form mode)\n  url parse <url>           Parse URL into components\n  url serialize <url>       Parse then serialize URL\n  url join <base> <ref>     Resolve a relative reference against a base URL\n  base64 encode <text>      Base64-encode input\n  base64 decode [--strict] <t>  Base64-decode input (forgiving unless --strict)\n  sniff [--type <mime>] [--nosniff] <file>  Compute a file's MIME type by content sniffing\n  help                      Show this help\n");
+    println!("日本語:\n  encode <text>             入力をパーセントエンコード（RFC3986 非予約は素通し）\n  decode <text>             入力をパーセントデコード\n  url encode [--form] <t>   URL エンコード（RFC3986/フォーム互換）\n  url decode [--form] <t>   URL デコード（RFC3986/フォーム互換）\n  url parse <url>           URL を解析して構成要素を表示\n  url serialize <url>       URL を解析して正規化して出力\n  url join <base> <ref>     相対参照を基底 URL に対して解決\n  base64 encode <text>      入力を Base64 エンコード\n  base64 decode [--strict] <t>  入力を Base64 デコード（--strict 以外は寛容デコード）\n  sniff [--type <mime>] [--nosniff] <file>  内容からファイルの MIME タイプを判定\n  help                      このヘルプを表示\n");
 }
 
 fn main() {
@@ -60,14 +60,20 @@ fn main() {
                     let text = args.collect::<Vec<_>>().join(" ");
                     match selenet_infra::url::Url::parse(&text) {
                         Ok(u) => {
-                            println!("scheme: {}", u.scheme);
-                            if !u.username.is_empty() { println!("username: {}", u.username); }
-                            if let Some(p) = &u.password { println!("password: {}", p); }
-                            println!("host: {}", match u.host { selenet_infra::url::Host::Domain(ref d)=>d.clone(), selenet_infra::url::Host::Ipv4(ip)=>ip.to_string(), selenet_infra::url::Host::Ipv6(ip)=>format!("[{}]", ip)});
-                            if let Some(port) = u.port { println!("port: {}", port); }
-                            if !u.path.is_empty() { println!("path: /{}", u.path.join("/")); }
-                            if let Some(q) = u.query { println!("query: {}", q); }
-                            if let Some(f) = u.fragment { println!("fragment: {}", f); }
+                            println!("scheme: {}", u.scheme());
+                            if !u.username().is_empty() { println!("username: {}", u.username()); }
+                            if let Some(p) = u.password() { println!("password: {}", p); }
+                            if let Some(host) = u.host() {
+                                println!("host: {}", host);
+                                let unicode = host.to_unicode();
+                                if unicode != host.to_string() { println!("host (unicode): {}", unicode); }
+                                if let Some(domain) = host.registrable_domain() { println!("registrable domain: {}", domain); }
+                            }
+                            if let Some(port) = u.port() { println!("port: {}", port); }
+                            if !u.path().is_empty() { println!("path: {}", u.path()); }
+                            if let Some(q) = u.query() { println!("query: {}", q); }
+                            if let Some(f) = u.fragment() { println!("fragment: {}", f); }
+                            println!("origin: {}", u.origin());
                         }
                         Err(e) => { eprintln!("parse error: {}", e); std::process::exit(1); }
                     }
@@ -75,13 +81,72 @@ fn main() {
                 Some("serialize") => {
                     let text = args.collect::<Vec<_>>().join(" ");
                     match selenet_infra::url::Url::parse(&text) {
-                        Ok(u) => println!("{}", u.serialize()),
+                        Ok(u) => println!("{}", u),
+                        Err(e) => { eprintln!("parse error: {}", e); std::process::exit(1); }
+                    }
+                }
+                Some("join") => {
+                    let base_text = args.next().unwrap_or_default();
+                    let reference = args.collect::<Vec<_>>().join(" ");
+                    let joined = selenet_infra::url::Url::parse(&base_text).and_then(|base| base.join(&reference));
+                    match joined {
+                        Ok(u) => println!("{}", u),
                         Err(e) => { eprintln!("parse error: {}", e); std::process::exit(1); }
                     }
                 }
                 _ => print_help(),
             }
         }
+        Some("base64") => {
+            match args.next().as_deref() {
+                Some("encode") => {
+                    let text = args.collect::<Vec<_>>().join(" ");
+                    println!("{}", selenet_infra::base64::encode(text.as_bytes()));
+                }
+                Some("decode") => {
+                    let mut strict = false;
+                    let mut rest: Vec<String> = Vec::new();
+                    for a in args { if a == "--strict" { strict = true } else { rest.push(a) } }
+                    let text = rest.join(" ");
+                    let res = if strict {
+                        selenet_infra::base64::decode(text.as_bytes())
+                    } else {
+                        selenet_infra::base64::forgiving_decode(text.as_bytes())
+                    };
+                    match res {
+                        Ok(bytes) => {
+                            use std::io::Write;
+                            let mut stdout = std::io::stdout();
+                            let _ = stdout.write_all(&bytes).and_then(|_| stdout.write_all(b"\n"));
+                        }
+                        Err(e) => { eprintln!("decode error: {}", e); std::process::exit(1); }
+                    }
+                }
+                _ => print_help(),
+            }
+        }
+        Some("sniff") => {
+            let mut content_type: Option<String> = None;
+            let mut nosniff = false;
+            let mut path: Option<String> = None;
+            while let Some(a) = args.next() {
+                match a.as_str() {
+                    "--type" => content_type = args.next(),
+                    "--nosniff" => nosniff = true,
+                    _ => path = Some(a),
+                }
+            }
+            let Some(path) = path else { print_help(); return; };
+            let mut header = Vec::new();
+            let read = std::fs::File::open(&path).and_then(|file| {
+                use std::io::Read;
+                file.take(selenet_infra::mime::RESOURCE_HEADER_LEN as u64).read_to_end(&mut header)
+            });
+            if let Err(e) = read { eprintln!("{}: {}", path, e); std::process::exit(1); }
+            let mut metadata = selenet_infra::mime::ResourceMetadata::from_headers(content_type.as_deref(), None);
+            metadata.no_sniff = nosniff;
+            println!("{}", selenet_infra::mime::sniff(&metadata, &header));
+        }
         _ => {
             print_help();
         }
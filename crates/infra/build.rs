@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+//! Compiles the bundled Public Suffix List (`data/public_suffix_list.dat`) into a label trie
+//! for `crate::psl`. Updating the list means replacing that file; the build needs no network.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/punycode.rs"]
+mod punycode;
+
+const LIST: &str = "data/public_suffix_list.dat";
+
+// Keep in sync with the flag constants in src/psl.rs.
+const RULE: u8 = 1;
+const EXCEPTION: u8 = 2;
+const PRIVATE: u8 = 4;
+
+#[derive(Default)]
+struct TrieNode {
+    flags: u8,
+    children: BTreeMap<String, TrieNode>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", LIST);
+    let text = fs::read_to_string(LIST).unwrap_or_else(|e| panic!("reading {}: {}", LIST, e));
+
+    let mut root = TrieNode::default();
+    let mut private = false;
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with("// ===BEGIN PRIVATE DOMAINS===") {
+            private = true;
+        }
+        let Some(rule) = line.split_whitespace().next() else {
+            continue;
+        };
+        if rule.starts_with("//") {
+            continue;
+        }
+        let (rule, exception) = match rule.strip_prefix('!') {
+            Some(rest) => (rest, true),
+            None => (rule, false),
+        };
+        let mut node = &mut root;
+        for label in rule.rsplit('.') {
+            node = node.children.entry(to_ascii_label(label)).or_default();
+        }
+        node.flags |= if exception { EXCEPTION } else { RULE };
+        if private {
+            node.flags |= PRIVATE;
+        }
+    }
+
+    // Lay the trie out breadth-first so that each node's children are contiguous and sorted.
+    let mut labels = String::new();
+    let mut label_offsets: HashMap<String, usize> = HashMap::new();
+    let mut nodes = String::new();
+    let mut count = 0;
+    let mut next_child = 1;
+    let mut queue = VecDeque::from([("", &root)]);
+    while let Some((label, node)) = queue.pop_front() {
+        let offset = *label_offsets.entry(label.to_owned()).or_insert_with(|| {
+            labels.push_str(label);
+            labels.len() - label.len()
+        });
+        let first_child = if node.children.is_empty() {
+            0
+        } else {
+            next_child
+        };
+        writeln!(
+            nodes,
+            "    n({}, {}, {}, {}, {}),",
+            offset,
+            label.len(),
+            first_child,
+            node.children.len(),
+            node.flags
+        )
+        .unwrap();
+        count += 1;
+        next_child += node.children.len();
+        queue.extend(node.children.iter().map(|(l, n)| (l.as_str(), n)));
+    }
+    assert!(
+        next_child <= usize::from(u16::MAX),
+        "public suffix trie too large"
+    );
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by build.rs from {}.", LIST).unwrap();
+    writeln!(out, "static LABELS: &str = \"{}\";", labels).unwrap();
+    writeln!(out, "static NODES: [Node; {}] = [", count).unwrap();
+    out.push_str(&nodes);
+    out.push_str("];\n");
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("public_suffix_list.rs");
+    fs::write(dest, out).unwrap();
+}
+
+/// Rules are stored as hosts are: lowercase, with non-ASCII labels in their `xn--` form.
+fn to_ascii_label(label: &str) -> String {
+    if label.is_ascii() {
+        label.to_ascii_lowercase()
+    } else {
+        let encoded = punycode::encode(label).expect("public suffix label is valid punycode");
+        format!("xn--{}", encoded)
+    }
+}
@@ -0,0 +1,225 @@
+#!/usr/bin/env python3
+# SPDX-License-Identifier: MIT
+"""Generate crates/infra/src/idna/tables.rs.
+
+The tables are derived from the Unicode Character Database shipped with CPython
+(`unicodedata`) and the UTS #46 mapping table vendored by the `idna` package, so the
+build itself needs neither network access nor native Unicode libraries.
+
+Both sources must describe the same Unicode version; run with an interpreter whose
+`unicodedata.unidata_version` matches the `idna` data version, e.g.:
+
+    python3.13 crates/infra/scripts/gen_idna_tables.py > crates/infra/src/idna/tables.rs
+"""
+
+import sys
+import unicodedata
+
+try:
+    from idna import idnadata, uts46data
+except ImportError:  # pip vendors a copy of idna
+    from pip._vendor.idna import idnadata, uts46data
+
+MAX_CODE_POINT = 0x10FFFF
+
+
+def escape(text):
+    return "".join(
+        c if 0x20 <= ord(c) < 0x7F and c not in '"\\' else "\\u{%X}" % ord(c) for c in text
+    )
+
+
+def ranges(values):
+    """Collapse a code point -> value function into (start, end, value) runs, skipping None."""
+    out = []
+    start = None
+    current = None
+    for cp in range(MAX_CODE_POINT + 2):
+        value = values(cp) if cp <= MAX_CODE_POINT else None
+        if value != current:
+            if current is not None:
+                out.append((start, cp - 1, current))
+            start, current = cp, value
+    return out
+
+
+def emit_rows(lines, rows, per_line):
+    for i in range(0, len(rows), per_line):
+        lines.append("    " + " ".join(rows[i : i + per_line]))
+
+
+def main():
+    version = unicodedata.unidata_version
+    if uts46data.__version__ != version or idnadata.__version__ != version:
+        sys.exit(
+            "unicodedata %s does not match idna data %s" % (version, uts46data.__version__)
+        )
+
+    lines = [
+        "// SPDX-License-Identifier: MIT",
+        "// Generated by crates/infra/scripts/gen_idna_tables.py from Unicode %s data." % version,
+        "// Do not edit by hand; rerun the script instead.",
+        "",
+        "#![allow(clippy::unreadable_literal)]",
+        "",
+        "pub(super) const UNICODE_VERSION: &str = \"%s\";" % version,
+        "",
+    ]
+
+    # UTS #46 mapping table.
+    status_names = {
+        "V": "Valid",
+        "I": "Ignored",
+        "M": "Mapped",
+        "D": "Deviation",
+        "X": "Disallowed",
+    }
+    pool = ""
+    offsets = {}
+    rows = []
+    for entry in uts46data.uts46data:
+        cp, status = entry[0], entry[1]
+        mapping = entry[2] if len(entry) == 3 else None
+        if status == "3":
+            status = "Std3Mapped" if mapping is not None else "Std3Valid"
+        else:
+            status = status_names[status]
+        offset, length = 0, 0
+        if mapping is not None:
+            if mapping not in offsets:
+                offsets[mapping] = len(pool.encode("utf-8"))
+                pool += mapping
+            offset, length = offsets[mapping], len(mapping.encode("utf-8"))
+        rows.append("(0x%X, %s, %d, %d)," % (cp, status, offset, length))
+    assert len(pool.encode("utf-8")) < 0x10000
+    lines.append("use super::Status::{self, *};")
+    lines.append("")
+    lines.append("/// UTS #46 mapping: (first code point of the run, status, mapping offset, mapping length).")
+    lines.append("/// Offsets and lengths are in bytes into `MAPPING_DATA`.")
+    lines.append("pub(super) static MAPPING: &[(u32, Status, u16, u8)] = &[")
+    emit_rows(lines, rows, 4)
+    lines.append("];")
+    lines.append("")
+    lines.append("pub(super) static MAPPING_DATA: &str = \"\\")
+    chunk = 64
+    for i in range(0, len(pool), chunk):
+        lines.append(escape(pool[i : i + chunk]) + "\\")
+    lines.append("\";")
+    lines.append("")
+
+    # Canonical combining classes (non-zero only).
+    rows = [
+        "(0x%X, 0x%X, %d)," % (s, e, v)
+        for s, e, v in ranges(lambda cp: unicodedata.combining(chr(cp)) or None)
+    ]
+    lines.append("/// Non-zero canonical combining classes: (first, last, class).")
+    lines.append("pub(super) static COMBINING_CLASS: &[(u32, u32, u8)] = &[")
+    emit_rows(lines, rows, 4)
+    lines.append("];")
+    lines.append("")
+
+    # Full canonical decompositions (Hangul syllables are handled algorithmically).
+    def canonical(cp):
+        if 0xAC00 <= cp <= 0xD7A3:
+            return None
+        raw = unicodedata.decomposition(chr(cp))
+        if not raw or raw.startswith("<"):
+            return None
+        return "".join(chr(int(part, 16)) for part in raw.split())
+
+    def full_decomposition(cp):
+        text = canonical(cp)
+        if text is None:
+            return None
+        out = ""
+        for c in text:
+            expanded = full_decomposition(ord(c))
+            out += expanded if expanded is not None else c
+        return out
+
+    data = []
+    rows = []
+    for cp in range(MAX_CODE_POINT + 1):
+        decomposed = full_decomposition(cp)
+        if decomposed is None:
+            continue
+        rows.append("(0x%X, %d, %d)," % (cp, len(data), len(decomposed)))
+        data.extend(decomposed)
+    lines.append("/// Full canonical decompositions: (code point, offset, length) into `DECOMPOSITION_DATA`.")
+    lines.append("pub(super) static DECOMPOSITION: &[(u32, u16, u8)] = &[")
+    emit_rows(lines, rows, 4)
+    lines.append("];")
+    lines.append("")
+    lines.append("pub(super) static DECOMPOSITION_DATA: &[char] = &[")
+    emit_rows(lines, ["'\\u{%X}'," % ord(c) for c in data], 8)
+    lines.append("];")
+    lines.append("")
+
+    # Primary composites: canonical pairs that survive NFC.
+    rows = []
+    for cp in range(MAX_CODE_POINT + 1):
+        pair = canonical(cp)
+        if pair is None or len(pair) != 2:
+            continue
+        if unicodedata.normalize("NFC", pair) != chr(cp):
+            continue
+        rows.append((ord(pair[0]), ord(pair[1]), cp))
+    rows.sort()
+    lines.append("/// Canonical composition pairs sorted by (first, second): (first, second, composite).")
+    lines.append("pub(super) static COMPOSITION: &[(u32, u32, u32)] = &[")
+    emit_rows(lines, ["(0x%X, 0x%X, 0x%X)," % row for row in rows], 3)
+    lines.append("];")
+    lines.append("")
+
+    # General_Category=Mark.
+    rows = [
+        "(0x%X, 0x%X)," % (s, e)
+        for s, e, _ in ranges(
+            lambda cp: True if unicodedata.category(chr(cp)).startswith("M") else None
+        )
+    ]
+    lines.append("/// Code point ranges with General_Category=Mark.")
+    lines.append("pub(super) static MARKS: &[(u32, u32)] = &[")
+    emit_rows(lines, rows, 5)
+    lines.append("];")
+    lines.append("")
+
+    # Bidi classes relevant to RFC 5893; everything unlisted is L.
+    bidi_names = {
+        "R": "R", "AL": "AL", "AN": "AN", "EN": "EN", "ES": "ES", "CS": "CS",
+        "ET": "ET", "ON": "ON", "BN": "BN", "NSM": "NSM",
+    }
+
+    def bidi(cp):
+        cls = unicodedata.bidirectional(chr(cp))
+        if cls in ("L", ""):
+            return None
+        return bidi_names.get(cls, "Other")
+
+    rows = ["(0x%X, 0x%X, %s)," % (s, e, v) for s, e, v in ranges(bidi)]
+    lines.append("use super::BidiClass::{self, *};")
+    lines.append("")
+    lines.append("/// Bidi_Class runs other than L: (first, last, class).")
+    lines.append("pub(super) static BIDI_CLASS: &[(u32, u32, BidiClass)] = &[")
+    emit_rows(lines, rows, 4)
+    lines.append("];")
+    lines.append("")
+
+    # Joining types for CONTEXTJ.
+    joining = {cp: chr(t) for cp, t in idnadata.joining_types.items()}
+    rows = [
+        "(0x%X, 0x%X, JoiningType::%s)," % (s, e, v)
+        for s, e, v in ranges(lambda cp: joining.get(cp) if joining.get(cp) != "U" else None)
+    ]
+    lines.append("use super::JoiningType;")
+    lines.append("")
+    lines.append("/// Joining_Type runs other than U (non-joining): (first, last, type).")
+    lines.append("pub(super) static JOINING_TYPE: &[(u32, u32, JoiningType)] = &[")
+    emit_rows(lines, rows, 3)
+    lines.append("];")
+
+    print("\n".join(lines))
+
+
+if __name__ == "__main__":
+    main()
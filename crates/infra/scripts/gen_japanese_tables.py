@@ -0,0 +1,107 @@
+#!/usr/bin/env python3
+# SPDX-License-Identifier: MIT
+"""Generate crates/infra/src/encoding/japanese/tables.rs.
+
+The indexes are read from the Encoding Standard's index files bundled under
+crates/infra/data/ (index-jis0208.txt, index-jis0212.txt and
+index-iso-2022-jp-katakana.txt); the reverse lookups the encoders need are derived here:
+
+    python3 crates/infra/scripts/gen_japanese_tables.py > crates/infra/src/encoding/japanese/tables.rs
+"""
+
+import os
+
+DATA = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "data")
+
+JIS0208_LEN = 11104
+JIS0212_LEN = 94 * 94
+KATAKANA_LEN = 63
+NEC_SELECTED_IBM = range(8272, 8836)
+
+
+def read_index(name, length):
+    """Parse index-<name>.txt into a list of code points by pointer, None where unmapped."""
+    index = [None] * length
+    with open(os.path.join(DATA, "index-%s.txt" % name), encoding="utf-8") as f:
+        for line in f:
+            if not line.strip() or line.startswith("#"):
+                continue
+            pointer, code_point = line.split("\t")[:2]
+            pointer = int(pointer)
+            assert index[pointer] is None, (name, pointer)
+            index[pointer] = int(code_point, 16)
+    return index
+
+
+def emit_index(lines, name, doc, index):
+    assert all(cp is None or 0 < cp <= 0xFFFF for cp in index)
+    lines.append(doc)
+    lines.append("pub(super) static %s: [u16; %d] = [" % (name, len(index)))
+    values = ["0x%04X," % (cp or 0) for cp in index]
+    for i in range(0, len(values), 12):
+        lines.append("    " + " ".join(values[i : i + 12]))
+    lines.append("];")
+    lines.append("")
+
+
+def emit_pairs(lines, name, doc, pairs):
+    lines.append(doc)
+    lines.append("pub(super) static %s: &[(u16, u16)] = &[" % name)
+    rows = ["(0x%04X, %d)," % pair for pair in pairs]
+    for i in range(0, len(rows), 6):
+        lines.append("    " + " ".join(rows[i : i + 6]))
+    lines.append("];")
+    lines.append("")
+
+
+def main():
+    index0208 = read_index("jis0208", JIS0208_LEN)
+    index0212 = read_index("jis0212", JIS0212_LEN)
+    katakana = read_index("iso-2022-jp-katakana", KATAKANA_LEN)
+    assert None not in katakana
+
+    # Reverse lookups: the first pointer for each code point ("index pointer"), and the
+    # Shift_JIS pointer where it differs because NEC-selected IBM extensions are skipped.
+    first = {}
+    shift_jis = {}
+    for pointer, cp in enumerate(index0208):
+        if cp is None:
+            continue
+        first.setdefault(cp, pointer)
+        if pointer not in NEC_SELECTED_IBM:
+            shift_jis.setdefault(cp, pointer)
+    overrides = sorted((cp, p) for cp, p in shift_jis.items() if first[cp] != p)
+
+    lines = [
+        "// SPDX-License-Identifier: MIT",
+        "// Generated by crates/infra/scripts/gen_japanese_tables.py from the index files in crates/infra/data.",
+        "// Do not edit by hand; rerun the script instead.",
+        "",
+        "#![allow(clippy::unreadable_literal)]",
+        "",
+    ]
+    emit_index(lines, "JIS0208", "/// index-jis0208 by pointer; 0 marks a pointer without a code point.", index0208)
+    emit_index(lines, "JIS0212", "/// index-jis0212 by pointer; 0 marks a pointer without a code point.", index0212)
+    emit_pairs(
+        lines,
+        "JIS0208_POINTERS",
+        "/// (code point, first jis0208 pointer), sorted by code point.",
+        sorted(first.items()),
+    )
+    emit_pairs(
+        lines,
+        "SHIFT_JIS_POINTERS",
+        "/// (code point, Shift_JIS pointer) where it is not the first jis0208 pointer.",
+        overrides,
+    )
+    lines.append("/// index-iso-2022-jp-katakana: U+FF61..=U+FF9F to their full-width forms.")
+    lines.append("pub(super) static ISO_2022_JP_KATAKANA: [u16; %d] = [" % len(katakana))
+    values = ["0x%04X," % cp for cp in katakana]
+    for i in range(0, len(values), 12):
+        lines.append("    " + " ".join(values[i : i + 12]))
+    lines.append("];")
+    print("\n".join(lines))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+# SPDX-License-Identifier: MIT
+"""Generate crates/infra/src/encoding/single_byte/tables.rs.
+
+Each single-byte index maps bytes 0x80..=0xFF to code points. They are read from the
+Encoding Standard's index-<name>.txt files bundled under crates/infra/data/, where the
+pointer is the byte minus 0x80:
+
+    python3 crates/infra/scripts/gen_single_byte_tables.py > crates/infra/src/encoding/single_byte/tables.rs
+"""
+
+import os
+
+DATA = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "data")
+
+# (table name, index file name); ISO-8859-8-I shares the ISO-8859-8 index.
+INDEXES = [
+    ("IBM866", "ibm866"),
+    ("ISO_8859_2", "iso-8859-2"),
+    ("ISO_8859_3", "iso-8859-3"),
+    ("ISO_8859_4", "iso-8859-4"),
+    ("ISO_8859_5", "iso-8859-5"),
+    ("ISO_8859_6", "iso-8859-6"),
+    ("ISO_8859_7", "iso-8859-7"),
+    ("ISO_8859_8", "iso-8859-8"),
+    ("ISO_8859_10", "iso-8859-10"),
+    ("ISO_8859_13", "iso-8859-13"),
+    ("ISO_8859_14", "iso-8859-14"),
+    ("ISO_8859_15", "iso-8859-15"),
+    ("ISO_8859_16", "iso-8859-16"),
+    ("KOI8_R", "koi8-r"),
+    ("KOI8_U", "koi8-u"),
+    ("MACINTOSH", "macintosh"),
+    ("WINDOWS_874", "windows-874"),
+    ("WINDOWS_1250", "windows-1250"),
+    ("WINDOWS_1251", "windows-1251"),
+    ("WINDOWS_1252", "windows-1252"),
+    ("WINDOWS_1253", "windows-1253"),
+    ("WINDOWS_1254", "windows-1254"),
+    ("WINDOWS_1255", "windows-1255"),
+    ("WINDOWS_1256", "windows-1256"),
+    ("WINDOWS_1257", "windows-1257"),
+    ("WINDOWS_1258", "windows-1258"),
+    ("X_MAC_CYRILLIC", "x-mac-cyrillic"),
+]
+
+
+def index(name):
+    """Parse index-<name>.txt into 128 code points, None where a byte is unmapped."""
+    out = [None] * 128
+    with open(os.path.join(DATA, "index-%s.txt" % name), encoding="utf-8") as f:
+        for line in f:
+            if not line.strip() or line.startswith("#"):
+                continue
+            pointer, code_point = line.split("\t")[:2]
+            out[int(pointer)] = int(code_point, 16)
+    return out
+
+
+def main():
+    lines = [
+        "// SPDX-License-Identifier: MIT",
+        "// Generated by crates/infra/scripts/gen_single_byte_tables.py from the index files in crates/infra/data.",
+        "// Do not edit by hand; rerun the script instead.",
+        "",
+        "#![allow(clippy::unreadable_literal)]",
+        "",
+    ]
+    for name, file_name in INDEXES:
+        values = index(file_name)
+        assert all(cp is None or 0x80 <= cp <= 0xFFFF for cp in values)
+        lines.append("pub(super) static %s: [u16; 128] = [" % name)
+        rows = ["0x%04X," % (cp or 0) for cp in values]
+        for i in range(0, len(rows), 16):
+            lines.append("    " + " ".join(rows[i : i + 16]))
+        lines.append("];")
+        lines.append("")
+    print("\n".join(lines).rstrip("\n"))
+
+
+if __name__ == "__main__":
+    main()
@@ -1,19 +1,19 @@
 // SPDX-License-Identifier: MIT
 // ASCII utilities aligned with WHATWG Infra behaviors where applicable.
 // Note: These functions operate on ASCII bytes only. Callers must validate input range when needed.
-/// ASCII utilities aligned with WHATWG Infra behaviors where applicable.
-/// Note: These functions operate on ASCII bytes only. Callers must validate input range when needed.
+//! ASCII utilities aligned with WHATWG Infra behaviors where applicable.
+//! Note: These functions operate on ASCII bytes only. Callers must validate input range when needed.
 
 /// Returns true when the byte is in 'A'..='Z'.
 #[inline]
 pub fn is_ascii_uppercase(byte: u8) -> bool {
-  (b'A'..=b'Z').contains(&byte)
+  byte.is_ascii_uppercase()
 }
 
 /// Returns true when the byte is in 'a'..='z'.
 #[inline]
 pub fn is_ascii_lowercase(byte: u8) -> bool {
-  (b'a'..=b'z').contains(&byte)
+  byte.is_ascii_lowercase()
 }
 
 /// Returns true when the byte is an ASCII alphabetic character.
@@ -25,7 +25,7 @@ pub fn is_ascii_alpha(byte: u8) -> bool {
 /// Returns true when the byte is in '0'..='9'.
 #[inline]
 pub fn is_ascii_digit(byte: u8) -> bool {
-  (b'0'..=b'9').contains(&byte)
+  byte.is_ascii_digit()
 }
 
 /// Returns true when the byte is alphanumeric.
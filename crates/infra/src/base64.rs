@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: MIT
+//! Base64 (RFC 4648 section 4) with the standard alphabet.
+//! `decode` is strict: padding is required and no other characters are allowed.
+//! `forgiving_decode` is Infra's forgiving-base64 decode, used by `atob()` and data: URLs: ASCII
+//! whitespace is ignored and padding may be omitted.
+
+use std::fmt::{Display, Formatter};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The input length (after removing whitespace and padding) cannot be base64.
+    InvalidLength,
+    /// A byte outside the base64 alphabet, at this offset of the input.
+    InvalidByte(usize),
+    /// Padding in the wrong place, or bits after the last full byte that are not zero.
+    InvalidPadding,
+}
+
+impl Display for Base64Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use Base64Error::*;
+        match self {
+            InvalidLength => write!(f, "invalid base64 length"),
+            InvalidByte(offset) => write!(f, "invalid base64 byte at offset {}", offset),
+            InvalidPadding => write!(f, "invalid base64 padding"),
+        }
+    }
+}
+
+impl std::error::Error for Base64Error {}
+
+/// Encode bytes with '=' padding.
+pub fn encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(group >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Decode canonical base64: the length is a multiple of four, padding only at the end, and
+/// unused bits are zero.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Base64Error> {
+    if !input.len().is_multiple_of(4) {
+        return Err(Base64Error::InvalidLength);
+    }
+    let padding = input
+        .iter()
+        .rev()
+        .take(2)
+        .take_while(|&&b| b == b'=')
+        .count();
+    let data = &input[..input.len() - padding];
+    let (out, leftover) = decode_symbols(data.iter().copied().enumerate())?;
+    if leftover != 0 {
+        return Err(Base64Error::InvalidPadding);
+    }
+    Ok(out)
+}
+
+/// Infra's forgiving-base64 decode.
+pub fn forgiving_decode(input: &[u8]) -> Result<Vec<u8>, Base64Error> {
+    let mut data: Vec<(usize, u8)> = input
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|&(_, b)| !crate::is_ascii_whitespace(b))
+        .collect();
+    if data.len().is_multiple_of(4) {
+        for _ in 0..2 {
+            if data.last().is_some_and(|&(_, b)| b == b'=') {
+                data.pop();
+            }
+        }
+    }
+    decode_symbols(data).map(|(out, _)| out)
+}
+
+/// Decode unpadded symbols, returning the bytes and the bits left over after the last byte.
+fn decode_symbols<I>(symbols: I) -> Result<(Vec<u8>, u32), Base64Error>
+where
+    I: IntoIterator<Item = (usize, u8)>,
+{
+    let symbols = symbols.into_iter();
+    let mut out = Vec::with_capacity(symbols.size_hint().0 / 4 * 3 + 2);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    let mut count = 0;
+    for (offset, b) in symbols {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => return Err(Base64Error::InvalidPadding),
+            _ => return Err(Base64Error::InvalidByte(offset)),
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        count += 1;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    if count % 4 == 1 {
+        return Err(Base64Error::InvalidLength);
+    }
+    Ok((out, buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_rfc4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (plain, encoded) in vectors {
+            assert_eq!(encode(plain.as_bytes()), encoded);
+            assert_eq!(decode(encoded.as_bytes()).unwrap(), plain.as_bytes());
+            assert_eq!(
+                forgiving_decode(encoded.as_bytes()).unwrap(),
+                plain.as_bytes()
+            );
+        }
+        assert_eq!(encode(&[0xFB, 0xFF]), "+/8=");
+    }
+
+    #[test]
+    fn strict_decode_errors() {
+        assert_eq!(decode(b"Zm8"), Err(Base64Error::InvalidLength));
+        assert_eq!(decode(b"Zm 8"), Err(Base64Error::InvalidByte(2)));
+        assert_eq!(decode(b"Zg=a"), Err(Base64Error::InvalidPadding));
+        assert_eq!(decode(b"Zh=="), Err(Base64Error::InvalidPadding));
+        assert_eq!(decode(b"Z==="), Err(Base64Error::InvalidPadding));
+    }
+
+    #[test]
+    fn forgiving_decode_rules() {
+        assert_eq!(forgiving_decode(b" Zm\n9v YmE ").unwrap(), b"fooba");
+        assert_eq!(forgiving_decode(b"Zg").unwrap(), b"f");
+        assert_eq!(forgiving_decode(b"Zh==").unwrap(), b"f");
+        assert_eq!(forgiving_decode(b"Zg="), Err(Base64Error::InvalidPadding));
+        assert_eq!(forgiving_decode(b"Zm9vY"), Err(Base64Error::InvalidLength));
+        assert_eq!(
+            forgiving_decode(b"Zm9v\x0bYg"),
+            Err(Base64Error::InvalidByte(4))
+        );
+    }
+}
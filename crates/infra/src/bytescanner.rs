@@ -20,6 +20,7 @@ impl<'a> ByteScanner<'a> {
     }
 
     /// Advance and return the next byte.
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Option<u8> {
         if self.index >= self.bytes.len() {
             return None;
@@ -38,6 +39,16 @@ impl<'a> ByteScanner<'a> {
     pub fn position(&self) -> usize {
         self.index
     }
+
+    /// Advance over bytes matching `predicate` and return them ("collect a sequence of code
+    /// points" in Infra terms).
+    pub fn collect_while<F: FnMut(u8) -> bool>(&mut self, mut predicate: F) -> &'a [u8] {
+        let start = self.index;
+        while self.peek().is_some_and(&mut predicate) {
+            self.index += 1;
+        }
+        &self.bytes[start..self.index]
+    }
 }
 
 #[cfg(test)]
@@ -55,6 +66,14 @@ mod tests {
         assert!(s.is_eof());
         assert_eq!(s.next(), None);
     }
-}
-
 
+    #[test]
+    fn collecting() {
+        let mut s = ByteScanner::new(b"text/html;x");
+        assert_eq!(s.collect_while(|b| b != b'/'), b"text");
+        assert_eq!(s.next(), Some(b'/'));
+        assert_eq!(s.collect_while(|b| b != b';'), b"html");
+        assert_eq!(s.collect_while(|b| b == b'/'), b"");
+        assert_eq!(s.position(), 9);
+    }
+}
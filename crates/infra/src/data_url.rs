@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+//! The Fetch Standard's data: URL processor.
+//! `data:[<mediatype>][;base64],<data>` is split at the first ',', the body is percent-decoded
+//! and, with `;base64`, forgiving-base64 decoded. A missing or invalid media type becomes
+//! `text/plain;charset=US-ASCII`.
+
+use std::fmt::{Display, Formatter};
+
+use crate::base64;
+use crate::mime::MimeType;
+use crate::percent;
+use crate::url::Url;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataUrl {
+    pub mime_type: MimeType,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataUrlError {
+    NotDataScheme,
+    MissingComma,
+    InvalidBase64,
+}
+
+impl Display for DataUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use DataUrlError::*;
+        match self {
+            NotDataScheme => write!(f, "not a data: URL"),
+            MissingComma => write!(f, "data: URL has no ',' before its body"),
+            InvalidBase64 => write!(f, "invalid base64 in data: URL body"),
+        }
+    }
+}
+
+impl std::error::Error for DataUrlError {}
+
+/// Run the data: URL processor on `url`. The fragment is ignored.
+pub fn process(url: &Url) -> Result<DataUrl, DataUrlError> {
+    if url.scheme() != "data" {
+        return Err(DataUrlError::NotDataScheme);
+    }
+    let serialized = match url.fragment() {
+        Some(fragment) => &url.as_str()[..url.as_str().len() - fragment.len() - 1],
+        None => url.as_str(),
+    };
+    let input = &serialized["data:".len()..];
+    let (mime_type, encoded_body) = input.split_once(',').ok_or(DataUrlError::MissingComma)?;
+    let mut mime_type =
+        mime_type.trim_matches(|c: char| c.is_ascii() && crate::is_ascii_whitespace(c as u8));
+    let mut body = percent::percent_decode_lenient(encoded_body.as_bytes());
+    if let Some(rest) = strip_base64_suffix(mime_type) {
+        body = base64::forgiving_decode(&body).map_err(|_| DataUrlError::InvalidBase64)?;
+        mime_type = rest;
+    }
+    let mime_type = if mime_type.starts_with(';') {
+        MimeType::parse(&format!("text/plain{}", mime_type))
+    } else {
+        MimeType::parse(mime_type)
+    };
+    Ok(DataUrl {
+        mime_type: mime_type.unwrap_or_else(|| {
+            MimeType::parse("text/plain;charset=US-ASCII").expect("valid MIME type")
+        }),
+        body,
+    })
+}
+
+/// Strip `;` + spaces + `base64` (ASCII case-insensitive) from the end of `mime_type`.
+fn strip_base64_suffix(mime_type: &str) -> Option<&str> {
+    let split = mime_type.len().checked_sub("base64".len())?;
+    let (rest, suffix) = mime_type.split_at_checked(split)?;
+    if !suffix.eq_ignore_ascii_case("base64") {
+        return None;
+    }
+    rest.trim_end_matches(' ').strip_suffix(';')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process_str(input: &str) -> Result<(String, Vec<u8>), DataUrlError> {
+        let url = Url::parse(input).unwrap();
+        process(&url).map(|data| (data.mime_type.to_string(), data.body))
+    }
+
+    #[test]
+    fn processes_data_urls() {
+        assert_eq!(
+            process_str("data:text/plain,hi"),
+            Ok(("text/plain".into(), b"hi".to_vec()))
+        );
+        assert_eq!(
+            process_str("data:,A%20brief%20note#frag"),
+            Ok((
+                "text/plain;charset=US-ASCII".into(),
+                b"A brief note".to_vec()
+            ))
+        );
+        assert_eq!(
+            process_str("data:;charset=utf-8,%E3%81%82"),
+            Ok(("text/plain;charset=utf-8".into(), "あ".as_bytes().to_vec()))
+        );
+        assert_eq!(
+            process_str("data:image/png ; BASE64,iVBO Rw0K"),
+            Ok(("image/png".into(), b"\x89PNG\r\n".to_vec()))
+        );
+        assert_eq!(
+            process_str("data:text/html;base64x,%3Cp%3E"),
+            Ok(("text/html".into(), b"<p>".to_vec()))
+        );
+        assert_eq!(
+            process_str("data:bogus,x"),
+            Ok(("text/plain;charset=US-ASCII".into(), b"x".to_vec()))
+        );
+    }
+
+    #[test]
+    fn processing_failures() {
+        assert_eq!(
+            process_str("data:text/plain"),
+            Err(DataUrlError::MissingComma)
+        );
+        assert_eq!(
+            process_str("data:;base64,a"),
+            Err(DataUrlError::InvalidBase64)
+        );
+        assert_eq!(
+            process_str("data:;base64,a!=="),
+            Err(DataUrlError::InvalidBase64)
+        );
+        assert_eq!(process_str("http://h/,x"), Err(DataUrlError::NotDataScheme));
+    }
+}
@@ -0,0 +1,858 @@
+// SPDX-License-Identifier: MIT
+//! The Encoding Standard's encodings and their labels.
+//! `Encoding::for_label` implements "get an encoding": the label is trimmed of ASCII whitespace
+//! and matched ASCII case-insensitively against the label table, so `" Latin1 "`, `sjis` and
+//! `x-euc-jp` all resolve to their canonical encoding.
+//! `Decoder` and `Encoder` are the streaming codecs: input arrives chunk by chunk, state such
+//! as an unfinished multi-byte sequence or an ISO-2022-JP escape mode carries across chunks,
+//! and errors either stop the stream (fatal) or are replaced.
+
+use std::fmt::{Display, Formatter};
+
+use crate::utf16::Utf16Decoder;
+pub use crate::utf8::ErrorMode;
+use crate::utf8::Utf8Decoder;
+
+mod japanese;
+mod single_byte;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    Utf8,
+    Ibm866,
+    Iso8859_2,
+    Iso8859_3,
+    Iso8859_4,
+    Iso8859_5,
+    Iso8859_6,
+    Iso8859_7,
+    Iso8859_8,
+    Iso8859_8I,
+    Iso8859_10,
+    Iso8859_13,
+    Iso8859_14,
+    Iso8859_15,
+    Iso8859_16,
+    Koi8R,
+    Koi8U,
+    Macintosh,
+    Windows874,
+    Windows1250,
+    Windows1251,
+    Windows1252,
+    Windows1253,
+    Windows1254,
+    Windows1255,
+    Windows1256,
+    Windows1257,
+    Windows1258,
+    XMacCyrillic,
+    Gbk,
+    Gb18030,
+    Big5,
+    EucJp,
+    Iso2022Jp,
+    ShiftJis,
+    EucKr,
+    /// Decodes any non-empty input to a single U+FFFD; stands in for encodings with known
+    /// security problems such as ISO-2022-KR and HZ-GB-2312.
+    Replacement,
+    Utf16Be,
+    Utf16Le,
+    XUserDefined,
+}
+
+use Encoding::*;
+
+/// Every label in the Encoding Standard, lowercase and sorted for binary search.
+static LABELS: &[(&str, Encoding)] = &[
+    ("866", Ibm866),
+    ("ansi_x3.4-1968", Windows1252),
+    ("arabic", Iso8859_6),
+    ("ascii", Windows1252),
+    ("asmo-708", Iso8859_6),
+    ("big5", Big5),
+    ("big5-hkscs", Big5),
+    ("chinese", Gbk),
+    ("cn-big5", Big5),
+    ("cp1250", Windows1250),
+    ("cp1251", Windows1251),
+    ("cp1252", Windows1252),
+    ("cp1253", Windows1253),
+    ("cp1254", Windows1254),
+    ("cp1255", Windows1255),
+    ("cp1256", Windows1256),
+    ("cp1257", Windows1257),
+    ("cp1258", Windows1258),
+    ("cp819", Windows1252),
+    ("cp866", Ibm866),
+    ("csbig5", Big5),
+    ("cseuckr", EucKr),
+    ("cseucpkdfmtjapanese", EucJp),
+    ("csgb2312", Gbk),
+    ("csibm866", Ibm866),
+    ("csiso2022jp", Iso2022Jp),
+    ("csiso2022kr", Replacement),
+    ("csiso58gb231280", Gbk),
+    ("csiso88596e", Iso8859_6),
+    ("csiso88596i", Iso8859_6),
+    ("csiso88598e", Iso8859_8),
+    ("csiso88598i", Iso8859_8I),
+    ("csisolatin1", Windows1252),
+    ("csisolatin2", Iso8859_2),
+    ("csisolatin3", Iso8859_3),
+    ("csisolatin4", Iso8859_4),
+    ("csisolatin5", Windows1254),
+    ("csisolatin6", Iso8859_10),
+    ("csisolatin9", Iso8859_15),
+    ("csisolatinarabic", Iso8859_6),
+    ("csisolatincyrillic", Iso8859_5),
+    ("csisolatingreek", Iso8859_7),
+    ("csisolatinhebrew", Iso8859_8),
+    ("cskoi8r", Koi8R),
+    ("csksc56011987", EucKr),
+    ("csmacintosh", Macintosh),
+    ("csshiftjis", ShiftJis),
+    ("csunicode", Utf16Le),
+    ("cyrillic", Iso8859_5),
+    ("dos-874", Windows874),
+    ("ecma-114", Iso8859_6),
+    ("ecma-118", Iso8859_7),
+    ("elot_928", Iso8859_7),
+    ("euc-jp", EucJp),
+    ("euc-kr", EucKr),
+    ("gb18030", Gb18030),
+    ("gb2312", Gbk),
+    ("gb_2312", Gbk),
+    ("gb_2312-80", Gbk),
+    ("gbk", Gbk),
+    ("greek", Iso8859_7),
+    ("greek8", Iso8859_7),
+    ("hebrew", Iso8859_8),
+    ("hz-gb-2312", Replacement),
+    ("ibm819", Windows1252),
+    ("ibm866", Ibm866),
+    ("iso-10646-ucs-2", Utf16Le),
+    ("iso-2022-cn", Replacement),
+    ("iso-2022-cn-ext", Replacement),
+    ("iso-2022-jp", Iso2022Jp),
+    ("iso-2022-kr", Replacement),
+    ("iso-8859-1", Windows1252),
+    ("iso-8859-10", Iso8859_10),
+    ("iso-8859-11", Windows874),
+    ("iso-8859-13", Iso8859_13),
+    ("iso-8859-14", Iso8859_14),
+    ("iso-8859-15", Iso8859_15),
+    ("iso-8859-16", Iso8859_16),
+    ("iso-8859-2", Iso8859_2),
+    ("iso-8859-3", Iso8859_3),
+    ("iso-8859-4", Iso8859_4),
+    ("iso-8859-5", Iso8859_5),
+    ("iso-8859-6", Iso8859_6),
+    ("iso-8859-6-e", Iso8859_6),
+    ("iso-8859-6-i", Iso8859_6),
+    ("iso-8859-7", Iso8859_7),
+    ("iso-8859-8", Iso8859_8),
+    ("iso-8859-8-e", Iso8859_8),
+    ("iso-8859-8-i", Iso8859_8I),
+    ("iso-8859-9", Windows1254),
+    ("iso-ir-100", Windows1252),
+    ("iso-ir-101", Iso8859_2),
+    ("iso-ir-109", Iso8859_3),
+    ("iso-ir-110", Iso8859_4),
+    ("iso-ir-126", Iso8859_7),
+    ("iso-ir-127", Iso8859_6),
+    ("iso-ir-138", Iso8859_8),
+    ("iso-ir-144", Iso8859_5),
+    ("iso-ir-148", Windows1254),
+    ("iso-ir-149", EucKr),
+    ("iso-ir-157", Iso8859_10),
+    ("iso-ir-58", Gbk),
+    ("iso8859-1", Windows1252),
+    ("iso8859-10", Iso8859_10),
+    ("iso8859-11", Windows874),
+    ("iso8859-13", Iso8859_13),
+    ("iso8859-14", Iso8859_14),
+    ("iso8859-15", Iso8859_15),
+    ("iso8859-2", Iso8859_2),
+    ("iso8859-3", Iso8859_3),
+    ("iso8859-4", Iso8859_4),
+    ("iso8859-5", Iso8859_5),
+    ("iso8859-6", Iso8859_6),
+    ("iso8859-7", Iso8859_7),
+    ("iso8859-8", Iso8859_8),
+    ("iso8859-9", Windows1254),
+    ("iso88591", Windows1252),
+    ("iso885910", Iso8859_10),
+    ("iso885911", Windows874),
+    ("iso885913", Iso8859_13),
+    ("iso885914", Iso8859_14),
+    ("iso885915", Iso8859_15),
+    ("iso88592", Iso8859_2),
+    ("iso88593", Iso8859_3),
+    ("iso88594", Iso8859_4),
+    ("iso88595", Iso8859_5),
+    ("iso88596", Iso8859_6),
+    ("iso88597", Iso8859_7),
+    ("iso88598", Iso8859_8),
+    ("iso88599", Windows1254),
+    ("iso_8859-1", Windows1252),
+    ("iso_8859-15", Iso8859_15),
+    ("iso_8859-1:1987", Windows1252),
+    ("iso_8859-2", Iso8859_2),
+    ("iso_8859-2:1987", Iso8859_2),
+    ("iso_8859-3", Iso8859_3),
+    ("iso_8859-3:1988", Iso8859_3),
+    ("iso_8859-4", Iso8859_4),
+    ("iso_8859-4:1988", Iso8859_4),
+    ("iso_8859-5", Iso8859_5),
+    ("iso_8859-5:1988", Iso8859_5),
+    ("iso_8859-6", Iso8859_6),
+    ("iso_8859-6:1987", Iso8859_6),
+    ("iso_8859-7", Iso8859_7),
+    ("iso_8859-7:1987", Iso8859_7),
+    ("iso_8859-8", Iso8859_8),
+    ("iso_8859-8:1988", Iso8859_8),
+    ("iso_8859-9", Windows1254),
+    ("iso_8859-9:1989", Windows1254),
+    ("koi", Koi8R),
+    ("koi8", Koi8R),
+    ("koi8-r", Koi8R),
+    ("koi8-ru", Koi8U),
+    ("koi8-u", Koi8U),
+    ("koi8_r", Koi8R),
+    ("korean", EucKr),
+    ("ks_c_5601-1987", EucKr),
+    ("ks_c_5601-1989", EucKr),
+    ("ksc5601", EucKr),
+    ("ksc_5601", EucKr),
+    ("l1", Windows1252),
+    ("l2", Iso8859_2),
+    ("l3", Iso8859_3),
+    ("l4", Iso8859_4),
+    ("l5", Windows1254),
+    ("l6", Iso8859_10),
+    ("l9", Iso8859_15),
+    ("latin1", Windows1252),
+    ("latin2", Iso8859_2),
+    ("latin3", Iso8859_3),
+    ("latin4", Iso8859_4),
+    ("latin5", Windows1254),
+    ("latin6", Iso8859_10),
+    ("logical", Iso8859_8I),
+    ("mac", Macintosh),
+    ("macintosh", Macintosh),
+    ("ms932", ShiftJis),
+    ("ms_kanji", ShiftJis),
+    ("replacement", Replacement),
+    ("shift-jis", ShiftJis),
+    ("shift_jis", ShiftJis),
+    ("sjis", ShiftJis),
+    ("sun_eu_greek", Iso8859_7),
+    ("tis-620", Windows874),
+    ("ucs-2", Utf16Le),
+    ("unicode", Utf16Le),
+    ("unicode-1-1-utf-8", Utf8),
+    ("unicode11utf8", Utf8),
+    ("unicode20utf8", Utf8),
+    ("unicodefeff", Utf16Le),
+    ("unicodefffe", Utf16Be),
+    ("us-ascii", Windows1252),
+    ("utf-16", Utf16Le),
+    ("utf-16be", Utf16Be),
+    ("utf-16le", Utf16Le),
+    ("utf-8", Utf8),
+    ("utf8", Utf8),
+    ("visual", Iso8859_8),
+    ("windows-1250", Windows1250),
+    ("windows-1251", Windows1251),
+    ("windows-1252", Windows1252),
+    ("windows-1253", Windows1253),
+    ("windows-1254", Windows1254),
+    ("windows-1255", Windows1255),
+    ("windows-1256", Windows1256),
+    ("windows-1257", Windows1257),
+    ("windows-1258", Windows1258),
+    ("windows-31j", ShiftJis),
+    ("windows-874", Windows874),
+    ("windows-949", EucKr),
+    ("x-cp1250", Windows1250),
+    ("x-cp1251", Windows1251),
+    ("x-cp1252", Windows1252),
+    ("x-cp1253", Windows1253),
+    ("x-cp1254", Windows1254),
+    ("x-cp1255", Windows1255),
+    ("x-cp1256", Windows1256),
+    ("x-cp1257", Windows1257),
+    ("x-cp1258", Windows1258),
+    ("x-euc-jp", EucJp),
+    ("x-gbk", Gbk),
+    ("x-mac-cyrillic", XMacCyrillic),
+    ("x-mac-roman", Macintosh),
+    ("x-mac-ukrainian", XMacCyrillic),
+    ("x-sjis", ShiftJis),
+    ("x-unicode20utf8", Utf8),
+    ("x-user-defined", XUserDefined),
+    ("x-x-big5", Big5),
+];
+
+impl Encoding {
+    /// Every encoding, in the order the Encoding Standard lists them.
+    pub const ALL: [Encoding; 40] = [
+        Utf8,
+        Ibm866,
+        Iso8859_2,
+        Iso8859_3,
+        Iso8859_4,
+        Iso8859_5,
+        Iso8859_6,
+        Iso8859_7,
+        Iso8859_8,
+        Iso8859_8I,
+        Iso8859_10,
+        Iso8859_13,
+        Iso8859_14,
+        Iso8859_15,
+        Iso8859_16,
+        Koi8R,
+        Koi8U,
+        Macintosh,
+        Windows874,
+        Windows1250,
+        Windows1251,
+        Windows1252,
+        Windows1253,
+        Windows1254,
+        Windows1255,
+        Windows1256,
+        Windows1257,
+        Windows1258,
+        XMacCyrillic,
+        Gbk,
+        Gb18030,
+        Big5,
+        EucJp,
+        Iso2022Jp,
+        ShiftJis,
+        EucKr,
+        Replacement,
+        Utf16Be,
+        Utf16Le,
+        XUserDefined,
+    ];
+
+    /// Get an encoding from a label, or `None` if the label is unknown.
+    pub fn for_label(label: &str) -> Option<Encoding> {
+        let label =
+            label.trim_matches(|c: char| c.is_ascii() && crate::is_ascii_whitespace(c as u8));
+        LABELS
+            .binary_search_by(|(candidate, _)| {
+                candidate
+                    .bytes()
+                    .cmp(label.bytes().map(|b| b.to_ascii_lowercase()))
+            })
+            .ok()
+            .map(|index| LABELS[index].1)
+    }
+
+    /// BOM sniff: the encoding a byte order mark at the start of `bytes` selects, and the
+    /// length of the mark.
+    pub fn for_bom(bytes: &[u8]) -> Option<(Encoding, usize)> {
+        match bytes {
+            [0xEF, 0xBB, 0xBF, ..] => Some((Utf8, 3)),
+            [0xFE, 0xFF, ..] => Some((Utf16Be, 2)),
+            [0xFF, 0xFE, ..] => Some((Utf16Le, 2)),
+            _ => None,
+        }
+    }
+
+    /// The canonical name, as exposed by `document.characterSet` and `TextDecoder.encoding`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Utf8 => "UTF-8",
+            Ibm866 => "IBM866",
+            Iso8859_2 => "ISO-8859-2",
+            Iso8859_3 => "ISO-8859-3",
+            Iso8859_4 => "ISO-8859-4",
+            Iso8859_5 => "ISO-8859-5",
+            Iso8859_6 => "ISO-8859-6",
+            Iso8859_7 => "ISO-8859-7",
+            Iso8859_8 => "ISO-8859-8",
+            Iso8859_8I => "ISO-8859-8-I",
+            Iso8859_10 => "ISO-8859-10",
+            Iso8859_13 => "ISO-8859-13",
+            Iso8859_14 => "ISO-8859-14",
+            Iso8859_15 => "ISO-8859-15",
+            Iso8859_16 => "ISO-8859-16",
+            Koi8R => "KOI8-R",
+            Koi8U => "KOI8-U",
+            Macintosh => "macintosh",
+            Windows874 => "windows-874",
+            Windows1250 => "windows-1250",
+            Windows1251 => "windows-1251",
+            Windows1252 => "windows-1252",
+            Windows1253 => "windows-1253",
+            Windows1254 => "windows-1254",
+            Windows1255 => "windows-1255",
+            Windows1256 => "windows-1256",
+            Windows1257 => "windows-1257",
+            Windows1258 => "windows-1258",
+            XMacCyrillic => "x-mac-cyrillic",
+            Gbk => "GBK",
+            Gb18030 => "gb18030",
+            Big5 => "Big5",
+            EucJp => "EUC-JP",
+            Iso2022Jp => "ISO-2022-JP",
+            ShiftJis => "Shift_JIS",
+            EucKr => "EUC-KR",
+            Replacement => "replacement",
+            Utf16Be => "UTF-16BE",
+            Utf16Le => "UTF-16LE",
+            XUserDefined => "x-user-defined",
+        }
+    }
+
+    /// The encoding used when encoding text for URLs and form submission: replacement and
+    /// UTF-16 cannot encode, so they become UTF-8.
+    pub fn output_encoding(self) -> Encoding {
+        match self {
+            Replacement | Utf16Be | Utf16Le => Utf8,
+            other => other,
+        }
+    }
+
+    /// A decoder for this encoding, or `None` if it is not implemented yet.
+    pub fn new_decoder(self, mode: ErrorMode) -> Option<Decoder> {
+        let state = match self {
+            Utf8 => DecoderState::Utf8(Utf8Decoder::new(mode)),
+            ShiftJis => DecoderState::ShiftJis(Default::default()),
+            EucJp => DecoderState::EucJp(Default::default()),
+            Iso2022Jp => DecoderState::Iso2022Jp(Default::default()),
+            Utf16Be => DecoderState::Utf16(Utf16Decoder::new_be(mode)),
+            Utf16Le => DecoderState::Utf16(Utf16Decoder::new_le(mode)),
+            _ => DecoderState::SingleByte(single_byte::index(self)?),
+        };
+        Some(Decoder {
+            encoding: self,
+            mode,
+            state,
+        })
+    }
+
+    /// An encoder for this encoding, or `None` if it is not implemented yet or, like UTF-16 and
+    /// replacement, has no encoder (see `output_encoding`).
+    pub fn new_encoder(self, mode: EncoderErrorMode) -> Option<Encoder> {
+        let state = match self {
+            Utf8 => EncoderState::Utf8,
+            ShiftJis => EncoderState::ShiftJis(Default::default()),
+            EucJp => EncoderState::EucJp(Default::default()),
+            Iso2022Jp => EncoderState::Iso2022Jp(Default::default()),
+            _ => EncoderState::SingleByte(single_byte::index(self)?),
+        };
+        Some(Encoder {
+            encoding: self,
+            mode,
+            state,
+        })
+    }
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A fatal decoder met malformed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    encoding: Encoding,
+}
+
+impl DecodeError {
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "malformed {} input", self.encoding)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// A fatal encoder met a code point the encoding cannot represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeError {
+    encoding: Encoding,
+    code_point: char,
+}
+
+impl EncodeError {
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn code_point(&self) -> char {
+        self.code_point
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "U+{:04X} cannot be encoded in {}",
+            u32::from(self.code_point),
+            self.encoding
+        )
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// What an encoder does with a code point the encoding cannot represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EncoderErrorMode {
+    /// Write an HTML numeric character reference such as `&#128512;`, as form submission and
+    /// URL query encoding do.
+    #[default]
+    Html,
+    /// Stop at the first unencodable code point.
+    Fatal,
+}
+
+/// Incremental decoder for one encoding, created by `Encoding::new_decoder`.
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    encoding: Encoding,
+    mode: ErrorMode,
+    state: DecoderState,
+}
+
+#[derive(Debug, Clone)]
+enum DecoderState {
+    Utf8(Utf8Decoder),
+    Utf16(Utf16Decoder),
+    ShiftJis(japanese::ShiftJisDecoder),
+    EucJp(japanese::EucJpDecoder),
+    Iso2022Jp(japanese::Iso2022JpDecoder),
+    SingleByte(&'static single_byte::Index),
+}
+
+impl Decoder {
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn mode(&self) -> ErrorMode {
+        self.mode
+    }
+
+    /// Decode `chunk`, appending to `output`. Set `last` on the final chunk so that input cut
+    /// off mid-sequence is reported.
+    ///
+    /// In fatal mode the first error is returned; text decoded before it has already been
+    /// appended, and the decoder is ready for a new stream.
+    pub fn decode(
+        &mut self,
+        chunk: &[u8],
+        output: &mut String,
+        last: bool,
+    ) -> Result<(), DecodeError> {
+        let mode = self.mode;
+        let ok = match &mut self.state {
+            DecoderState::Utf8(decoder) => decoder.decode(chunk, output, last).is_ok(),
+            DecoderState::Utf16(decoder) => decoder.decode(chunk, output, last).is_ok(),
+            DecoderState::ShiftJis(handler) => run_decoder(handler, mode, chunk, output, last),
+            DecoderState::EucJp(handler) => run_decoder(handler, mode, chunk, output, last),
+            DecoderState::Iso2022Jp(handler) => run_decoder(handler, mode, chunk, output, last),
+            DecoderState::SingleByte(index) => single_byte::decode(index, mode, chunk, output),
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(DecodeError {
+                encoding: self.encoding,
+            })
+        }
+    }
+}
+
+/// Incremental encoder for one encoding, created by `Encoding::new_encoder`.
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    encoding: Encoding,
+    mode: EncoderErrorMode,
+    state: EncoderState,
+}
+
+#[derive(Debug, Clone)]
+enum EncoderState {
+    Utf8,
+    ShiftJis(japanese::ShiftJisEncoder),
+    EucJp(japanese::EucJpEncoder),
+    Iso2022Jp(japanese::Iso2022JpEncoder),
+    SingleByte(&'static single_byte::Index),
+}
+
+impl Encoder {
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn mode(&self) -> EncoderErrorMode {
+        self.mode
+    }
+
+    /// Encode `input`, appending to `output`. Set `last` on the final chunk so that stateful
+    /// encodings return to their initial state (ISO-2022-JP switches back to ASCII).
+    ///
+    /// In fatal mode the first unencodable code point is returned; bytes for the text before
+    /// it have already been appended, and the encoder is ready for a new stream.
+    pub fn encode(
+        &mut self,
+        input: &str,
+        output: &mut Vec<u8>,
+        last: bool,
+    ) -> Result<(), EncodeError> {
+        let mode = self.mode;
+        let result = match &mut self.state {
+            EncoderState::Utf8 => {
+                output.extend_from_slice(input.as_bytes());
+                Ok(())
+            }
+            EncoderState::ShiftJis(handler) => run_encoder(handler, mode, input, output, last),
+            EncoderState::EucJp(handler) => run_encoder(handler, mode, input, output, last),
+            EncoderState::Iso2022Jp(handler) => run_encoder(handler, mode, input, output, last),
+            EncoderState::SingleByte(index) => single_byte::encode(index, mode, input, output),
+        };
+        result.map_err(|code_point| EncodeError {
+            encoding: self.encoding,
+            code_point,
+        })
+    }
+}
+
+/// The input of a handler: the rest of the current chunk, preceded by anything the handler
+/// put back ("restore" and "prepend" in the Encoding Standard).
+struct Queue<I: Iterator> {
+    restored: Vec<I::Item>,
+    rest: I,
+}
+
+type ByteQueue<'a> = Queue<std::iter::Copied<std::slice::Iter<'a, u8>>>;
+type CharQueue<'a> = Queue<std::str::Chars<'a>>;
+
+impl<I: Iterator> Queue<I> {
+    fn new(rest: I) -> Self {
+        Queue {
+            restored: Vec::new(),
+            rest,
+        }
+    }
+
+    fn read(&mut self) -> Option<I::Item> {
+        self.restored.pop().or_else(|| self.rest.next())
+    }
+
+    /// Put `item` back so that it is read next.
+    fn restore(&mut self, item: I::Item) {
+        self.restored.push(item);
+    }
+}
+
+/// The result of running a decoder's handler on one byte or on end-of-stream.
+enum Decoded {
+    Continue,
+    Emit(char),
+    Error,
+    Finished,
+}
+
+/// The result of running an encoder's handler on one code point or on end-of-stream.
+enum Encoded {
+    Continue,
+    Error(char),
+    Finished,
+}
+
+/// A decoder's handler; `byte` is `None` at the end of the stream.
+trait DecoderHandler: Default {
+    fn handle(&mut self, queue: &mut ByteQueue<'_>, byte: Option<u8>) -> Decoded;
+}
+
+/// An encoder's handler; `c` is `None` at the end of the stream.
+trait EncoderHandler: Default {
+    fn handle(
+        &mut self,
+        queue: &mut CharQueue<'_>,
+        c: Option<char>,
+        output: &mut Vec<u8>,
+    ) -> Encoded;
+}
+
+/// Run `handler` over `chunk`; returns false if a fatal decoder hit an error.
+fn run_decoder<H: DecoderHandler>(
+    handler: &mut H,
+    mode: ErrorMode,
+    chunk: &[u8],
+    output: &mut String,
+    last: bool,
+) -> bool {
+    output.reserve(chunk.len());
+    let mut queue = Queue::new(chunk.iter().copied());
+    loop {
+        let byte = queue.read();
+        if byte.is_none() && !last {
+            return true;
+        }
+        match handler.handle(&mut queue, byte) {
+            Decoded::Continue => {}
+            Decoded::Emit(c) => output.push(c),
+            Decoded::Error => match mode {
+                ErrorMode::Replacement => output.push(char::REPLACEMENT_CHARACTER),
+                ErrorMode::Fatal => {
+                    *handler = H::default();
+                    return false;
+                }
+            },
+            Decoded::Finished => {
+                *handler = H::default();
+                return true;
+            }
+        }
+    }
+}
+
+/// Run `handler` over `input`, returning the code point a fatal encoder could not encode.
+fn run_encoder<H: EncoderHandler>(
+    handler: &mut H,
+    mode: EncoderErrorMode,
+    input: &str,
+    output: &mut Vec<u8>,
+    last: bool,
+) -> Result<(), char> {
+    let mut queue = Queue::new(input.chars());
+    loop {
+        let c = queue.read();
+        if c.is_none() && !last {
+            return Ok(());
+        }
+        match handler.handle(&mut queue, c, output) {
+            Encoded::Continue => {}
+            Encoded::Error(c) => match mode {
+                EncoderErrorMode::Html => {
+                    for c in format!("&#{};", u32::from(c)).chars().rev() {
+                        queue.restore(c);
+                    }
+                }
+                EncoderErrorMode::Fatal => {
+                    *handler = H::default();
+                    return Err(c);
+                }
+            },
+            Encoded::Finished => {
+                *handler = H::default();
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// One-shot decode/encode helpers for the codec tests.
+#[cfg(test)]
+mod test_support {
+    use super::{DecodeError, EncodeError, EncoderErrorMode, Encoding, ErrorMode};
+
+    /// Decode `bytes` as one complete stream.
+    pub(super) fn decode_with(
+        encoding: Encoding,
+        bytes: &[u8],
+        mode: ErrorMode,
+    ) -> Result<String, DecodeError> {
+        let mut decoder = encoding.new_decoder(mode).unwrap();
+        let mut output = String::new();
+        decoder.decode(bytes, &mut output, true).map(|()| output)
+    }
+
+    /// Encode `text` as one complete stream.
+    pub(super) fn encode_with(
+        encoding: Encoding,
+        text: &str,
+        mode: EncoderErrorMode,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let mut encoder = encoding.new_encoder(mode).unwrap();
+        let mut output = Vec::new();
+        encoder.encode(text, &mut output, true).map(|()| output)
+    }
+
+    /// `decode_with` in replacement mode, which cannot fail.
+    pub(super) fn decode(encoding: Encoding, bytes: &[u8]) -> String {
+        decode_with(encoding, bytes, ErrorMode::Replacement).unwrap()
+    }
+
+    /// `encode_with` in HTML mode, which cannot fail.
+    pub(super) fn encode(encoding: Encoding, text: &str) -> Vec<u8> {
+        encode_with(encoding, text, EncoderErrorMode::Html).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_table_is_sorted_and_lowercase() {
+        assert_eq!(LABELS.len(), 228);
+        for pair in LABELS.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{} >= {}", pair[0].0, pair[1].0);
+        }
+        for &(label, _) in LABELS {
+            assert_eq!(label, label.to_ascii_lowercase());
+        }
+        for encoding in Encoding::ALL {
+            assert_eq!(
+                Encoding::for_label(encoding.name()),
+                Some(encoding),
+                "{}",
+                encoding
+            );
+        }
+    }
+
+    #[test]
+    fn resolves_labels() {
+        assert_eq!(Encoding::for_label("latin1"), Some(Windows1252));
+        assert_eq!(Encoding::for_label("US-ASCII"), Some(Windows1252));
+        assert_eq!(Encoding::for_label("sjis"), Some(ShiftJis));
+        assert_eq!(Encoding::for_label("MS932"), Some(ShiftJis));
+        assert_eq!(Encoding::for_label(" x-EUC-jp\n"), Some(EucJp));
+        assert_eq!(Encoding::for_label("\t\x0Cutf8\r "), Some(Utf8));
+        assert_eq!(Encoding::for_label("utf-16"), Some(Utf16Le));
+        assert_eq!(Encoding::for_label("iso-2022-kr"), Some(Replacement));
+        assert_eq!(Encoding::for_label("ISO-8859-11"), Some(Windows874));
+        assert_eq!(Encoding::for_label("utf 8"), None);
+        assert_eq!(Encoding::for_label("\u{A0}utf-8"), None);
+        assert_eq!(Encoding::for_label("\u{212A}oi8-r"), None);
+        assert_eq!(Encoding::for_label(""), None);
+    }
+
+    #[test]
+    fn output_encodings() {
+        assert_eq!(Utf16Be.output_encoding(), Utf8);
+        assert_eq!(Utf16Le.output_encoding(), Utf8);
+        assert_eq!(Replacement.output_encoding(), Utf8);
+        assert_eq!(ShiftJis.output_encoding(), ShiftJis);
+        assert_eq!(Encoding::for_bom(b"\xFF\xFEa\0"), Some((Utf16Le, 2)));
+        assert_eq!(Encoding::for_bom(b"\xEF\xBB\xBFa"), Some((Utf8, 3)));
+        assert_eq!(Encoding::for_bom(b"\xEF\xBB"), None);
+        let mut decoder = Utf16Be.new_decoder(ErrorMode::Replacement).unwrap();
+        let mut output = String::new();
+        decoder.decode(b"\xD8\x3D\x00a", &mut output, true).unwrap();
+        assert_eq!(output, "\u{FFFD}a");
+        assert_eq!(Windows1252.to_string(), "windows-1252");
+    }
+}
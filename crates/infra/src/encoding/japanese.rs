@@ -0,0 +1,516 @@
+// SPDX-License-Identifier: MIT
+//! Shift_JIS, EUC-JP and ISO-2022-JP as defined by the Encoding Standard.
+//! All three map two-byte codes through the jis0208 index (Windows-31J, so NEC and IBM
+//! extensions included); EUC-JP additionally reaches JIS X 0212 through `0x8F` and the jis0212
+//! index, which is decode-only. Index data lives in the generated `tables` module (see
+//! `scripts/gen_japanese_tables.py`).
+
+use super::{ByteQueue, CharQueue, Decoded, DecoderHandler, Encoded, EncoderHandler};
+
+#[rustfmt::skip]
+mod tables;
+
+fn index_code_point(index: &[u16], pointer: usize) -> Option<char> {
+    match index.get(pointer) {
+        Some(&cp) if cp != 0 => char::from_u32(u32::from(cp)),
+        _ => None,
+    }
+}
+
+fn lookup_pointer(pairs: &[(u16, u16)], c: char) -> Option<usize> {
+    let cp = u16::try_from(u32::from(c)).ok()?;
+    let index = pairs.binary_search_by_key(&cp, |&(cp, _)| cp).ok()?;
+    Some(usize::from(pairs[index].1))
+}
+
+/// The index jis0208 pointer: the first pointer for `c`.
+fn jis0208_pointer(c: char) -> Option<usize> {
+    lookup_pointer(tables::JIS0208_POINTERS, c)
+}
+
+/// The index Shift_JIS pointer: like `jis0208_pointer`, but skipping the NEC-selected IBM
+/// extensions (pointers 8272..=8835) in favour of the IBM extensions proper.
+fn shift_jis_pointer(c: char) -> Option<usize> {
+    lookup_pointer(tables::SHIFT_JIS_POINTERS, c).or_else(|| jis0208_pointer(c))
+}
+
+/// U+FF61..=U+FF9F, the half-width katakana, from a single byte 0xA1..=0xDF (or 0x21..=0x5F).
+fn half_width_katakana(offset: u8) -> char {
+    char::from_u32(0xFF61 + u32::from(offset)).expect("U+FF61..=U+FF9F")
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct ShiftJisDecoder {
+    lead: u8,
+}
+
+impl DecoderHandler for ShiftJisDecoder {
+    fn handle(&mut self, queue: &mut ByteQueue<'_>, byte: Option<u8>) -> Decoded {
+        let Some(byte) = byte else {
+            if self.lead != 0 {
+                self.lead = 0;
+                return Decoded::Error;
+            }
+            return Decoded::Finished;
+        };
+        if self.lead != 0 {
+            let lead = std::mem::take(&mut self.lead);
+            if matches!(byte, 0x40..=0x7E | 0x80..=0xFC) {
+                let offset = if byte < 0x7F { 0x40 } else { 0x41 };
+                let lead_offset = if lead < 0xA0 { 0x81 } else { 0xC1 };
+                let pointer = usize::from(lead - lead_offset) * 188 + usize::from(byte - offset);
+                if (8836..=10715).contains(&pointer) {
+                    // The user-defined area maps onto the Private Use Area.
+                    let cp = 0xE000 + (pointer - 8836) as u32;
+                    return Decoded::Emit(char::from_u32(cp).expect("private use code point"));
+                }
+                if let Some(c) = index_code_point(&tables::JIS0208, pointer) {
+                    return Decoded::Emit(c);
+                }
+            }
+            if byte.is_ascii() {
+                queue.restore(byte);
+            }
+            return Decoded::Error;
+        }
+        match byte {
+            0x00..=0x80 => Decoded::Emit(char::from(byte)),
+            0xA1..=0xDF => Decoded::Emit(half_width_katakana(byte - 0xA1)),
+            0x81..=0x9F | 0xE0..=0xFC => {
+                self.lead = byte;
+                Decoded::Continue
+            }
+            _ => Decoded::Error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct ShiftJisEncoder;
+
+impl EncoderHandler for ShiftJisEncoder {
+    fn handle(
+        &mut self,
+        _queue: &mut CharQueue<'_>,
+        c: Option<char>,
+        output: &mut Vec<u8>,
+    ) -> Encoded {
+        let Some(c) = c else {
+            return Encoded::Finished;
+        };
+        match c {
+            '\0'..='\u{80}' => output.push(c as u8),
+            '\u{A5}' => output.push(0x5C),
+            '\u{203E}' => output.push(0x7E),
+            '\u{FF61}'..='\u{FF9F}' => output.push((u32::from(c) - 0xFF61 + 0xA1) as u8),
+            _ => {
+                let c = if c == '\u{2212}' { '\u{FF0D}' } else { c };
+                let Some(pointer) = shift_jis_pointer(c) else {
+                    return Encoded::Error(c);
+                };
+                let (lead, trail) = (pointer / 188, pointer % 188);
+                let lead_offset = if lead < 0x1F { 0x81 } else { 0xC1 };
+                let offset = if trail < 0x3F { 0x40 } else { 0x41 };
+                output.extend_from_slice(&[(lead + lead_offset) as u8, (trail + offset) as u8]);
+            }
+        }
+        Encoded::Continue
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct EucJpDecoder {
+    jis0212: bool,
+    lead: u8,
+}
+
+impl DecoderHandler for EucJpDecoder {
+    fn handle(&mut self, queue: &mut ByteQueue<'_>, byte: Option<u8>) -> Decoded {
+        let Some(byte) = byte else {
+            if self.lead != 0 {
+                self.lead = 0;
+                return Decoded::Error;
+            }
+            return Decoded::Finished;
+        };
+        match (self.lead, byte) {
+            (0x8E, 0xA1..=0xDF) => {
+                self.lead = 0;
+                Decoded::Emit(half_width_katakana(byte - 0xA1))
+            }
+            (0x8F, 0xA1..=0xFE) => {
+                self.jis0212 = true;
+                self.lead = byte;
+                Decoded::Continue
+            }
+            (0, _) => match byte {
+                0x00..=0x7F => Decoded::Emit(char::from(byte)),
+                0x8E | 0x8F | 0xA1..=0xFE => {
+                    self.lead = byte;
+                    Decoded::Continue
+                }
+                _ => Decoded::Error,
+            },
+            (lead, _) => {
+                self.lead = 0;
+                let jis0212 = std::mem::take(&mut self.jis0212);
+                if (0xA1..=0xFE).contains(&lead) && (0xA1..=0xFE).contains(&byte) {
+                    let pointer = usize::from(lead - 0xA1) * 94 + usize::from(byte - 0xA1);
+                    let index: &[u16] = if jis0212 {
+                        &tables::JIS0212
+                    } else {
+                        &tables::JIS0208
+                    };
+                    if let Some(c) = index_code_point(index, pointer) {
+                        return Decoded::Emit(c);
+                    }
+                }
+                if byte.is_ascii() {
+                    queue.restore(byte);
+                }
+                Decoded::Error
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct EucJpEncoder;
+
+impl EncoderHandler for EucJpEncoder {
+    fn handle(
+        &mut self,
+        _queue: &mut CharQueue<'_>,
+        c: Option<char>,
+        output: &mut Vec<u8>,
+    ) -> Encoded {
+        let Some(c) = c else {
+            return Encoded::Finished;
+        };
+        match c {
+            '\0'..='\x7F' => output.push(c as u8),
+            '\u{A5}' => output.push(0x5C),
+            '\u{203E}' => output.push(0x7E),
+            '\u{FF61}'..='\u{FF9F}' => {
+                output.extend_from_slice(&[0x8E, (u32::from(c) - 0xFF61 + 0xA1) as u8])
+            }
+            _ => {
+                let c = if c == '\u{2212}' { '\u{FF0D}' } else { c };
+                let Some(pointer) = jis0208_pointer(c) else {
+                    return Encoded::Error(c);
+                };
+                output
+                    .extend_from_slice(&[(pointer / 94 + 0xA1) as u8, (pointer % 94 + 0xA1) as u8]);
+            }
+        }
+        Encoded::Continue
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Iso2022JpState {
+    #[default]
+    Ascii,
+    Roman,
+    Katakana,
+    LeadByte,
+    TrailByte,
+    EscapeStart,
+    Escape,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Iso2022JpDecoder {
+    state: Iso2022JpState,
+    /// The mode to return to after an escape sequence that turned out to be invalid.
+    output_state: Iso2022JpState,
+    lead: u8,
+    /// Set right after an escape sequence; two escapes in a row without text between them
+    /// are an error.
+    output: bool,
+}
+
+impl DecoderHandler for Iso2022JpDecoder {
+    fn handle(&mut self, queue: &mut ByteQueue<'_>, byte: Option<u8>) -> Decoded {
+        use Iso2022JpState::*;
+        match self.state {
+            Ascii | Roman | Katakana | LeadByte => {
+                let Some(byte) = byte else {
+                    return Decoded::Finished;
+                };
+                if byte == 0x1B {
+                    self.state = EscapeStart;
+                    return Decoded::Continue;
+                }
+                self.output = false;
+                match (self.state, byte) {
+                    (Ascii, 0x00..=0x7F) if byte != 0x0E && byte != 0x0F => {
+                        Decoded::Emit(char::from(byte))
+                    }
+                    (Roman, 0x5C) => Decoded::Emit('\u{A5}'),
+                    (Roman, 0x7E) => Decoded::Emit('\u{203E}'),
+                    (Roman, 0x00..=0x7F) if byte != 0x0E && byte != 0x0F => {
+                        Decoded::Emit(char::from(byte))
+                    }
+                    (Katakana, 0x21..=0x5F) => Decoded::Emit(half_width_katakana(byte - 0x21)),
+                    (LeadByte, 0x21..=0x7E) => {
+                        self.lead = byte;
+                        self.state = TrailByte;
+                        Decoded::Continue
+                    }
+                    _ => Decoded::Error,
+                }
+            }
+            TrailByte => {
+                match byte {
+                    Some(0x1B) => self.state = EscapeStart,
+                    Some(byte @ 0x21..=0x7E) => {
+                        self.state = LeadByte;
+                        let pointer = usize::from(self.lead - 0x21) * 94 + usize::from(byte - 0x21);
+                        if let Some(c) = index_code_point(&tables::JIS0208, pointer) {
+                            return Decoded::Emit(c);
+                        }
+                    }
+                    _ => self.state = LeadByte,
+                }
+                Decoded::Error
+            }
+            EscapeStart => match byte {
+                Some(byte @ (0x24 | 0x28)) => {
+                    self.lead = byte;
+                    self.state = Escape;
+                    Decoded::Continue
+                }
+                _ => {
+                    if let Some(byte) = byte {
+                        queue.restore(byte);
+                    }
+                    self.output = false;
+                    self.state = self.output_state;
+                    Decoded::Error
+                }
+            },
+            Escape => {
+                let lead = std::mem::take(&mut self.lead);
+                let state = match (lead, byte) {
+                    (0x28, Some(0x42)) => Some(Ascii),
+                    (0x28, Some(0x4A)) => Some(Roman),
+                    (0x28, Some(0x49)) => Some(Katakana),
+                    (0x24, Some(0x40 | 0x42)) => Some(LeadByte),
+                    _ => None,
+                };
+                if let Some(state) = state {
+                    self.state = state;
+                    self.output_state = state;
+                    if std::mem::replace(&mut self.output, true) {
+                        return Decoded::Error;
+                    }
+                    return Decoded::Continue;
+                }
+                if let Some(byte) = byte {
+                    queue.restore(byte);
+                }
+                queue.restore(lead);
+                self.output = false;
+                self.state = self.output_state;
+                Decoded::Error
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Iso2022JpEncoderState {
+    #[default]
+    Ascii,
+    Roman,
+    Jis0208,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Iso2022JpEncoder {
+    state: Iso2022JpEncoderState,
+}
+
+impl Iso2022JpEncoder {
+    /// Put `c` back and emit the escape sequence that switches to `state`.
+    fn switch(
+        &mut self,
+        queue: &mut CharQueue<'_>,
+        c: char,
+        state: Iso2022JpEncoderState,
+        output: &mut Vec<u8>,
+    ) -> Encoded {
+        queue.restore(c);
+        self.state = state;
+        output.extend_from_slice(match state {
+            Iso2022JpEncoderState::Ascii => b"\x1B(B",
+            Iso2022JpEncoderState::Roman => b"\x1B(J",
+            Iso2022JpEncoderState::Jis0208 => b"\x1B$B",
+        });
+        Encoded::Continue
+    }
+}
+
+impl EncoderHandler for Iso2022JpEncoder {
+    fn handle(
+        &mut self,
+        queue: &mut CharQueue<'_>,
+        c: Option<char>,
+        output: &mut Vec<u8>,
+    ) -> Encoded {
+        use Iso2022JpEncoderState::*;
+        let Some(c) = c else {
+            if self.state != Ascii {
+                self.state = Ascii;
+                output.extend_from_slice(b"\x1B(B");
+                return Encoded::Continue;
+            }
+            return Encoded::Finished;
+        };
+        if self.state != Jis0208 && matches!(c, '\x0E' | '\x0F' | '\x1B') {
+            return Encoded::Error(char::REPLACEMENT_CHARACTER);
+        }
+        match (self.state, c) {
+            (Ascii, '\0'..='\x7F') => output.push(c as u8),
+            (Roman, '\u{A5}') => output.push(0x5C),
+            (Roman, '\u{203E}') => output.push(0x7E),
+            (Roman, '\0'..='\x7F') if c != '\\' && c != '~' => output.push(c as u8),
+            (_, '\0'..='\x7F') => return self.switch(queue, c, Ascii, output),
+            (_, '\u{A5}' | '\u{203E}') => return self.switch(queue, c, Roman, output),
+            _ => {
+                let c = match c {
+                    '\u{2212}' => '\u{FF0D}',
+                    '\u{FF61}'..='\u{FF9F}' => {
+                        let full = tables::ISO_2022_JP_KATAKANA[(u32::from(c) - 0xFF61) as usize];
+                        char::from_u32(u32::from(full)).expect("katakana code point")
+                    }
+                    _ => c,
+                };
+                let Some(pointer) = jis0208_pointer(c) else {
+                    if self.state == Jis0208 {
+                        return self.switch(queue, c, Ascii, output);
+                    }
+                    return Encoded::Error(c);
+                };
+                if self.state != Jis0208 {
+                    return self.switch(queue, c, Jis0208, output);
+                }
+                output
+                    .extend_from_slice(&[(pointer / 94 + 0x21) as u8, (pointer % 94 + 0x21) as u8]);
+            }
+        }
+        Encoded::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::test_support::{decode, encode};
+    use crate::encoding::{EncodeError, EncoderErrorMode, Encoding, ErrorMode};
+
+    #[test]
+    fn shift_jis() {
+        let bytes = b"\x93\xfa\x96{\x8c\xea \xb1\xb2 \\~\x87\x40\xf0\x40";
+        let text = "日本語 ｱｲ \\~①\u{E000}";
+        assert_eq!(decode(Encoding::ShiftJis, bytes), text);
+        assert_eq!(
+            encode(Encoding::ShiftJis, "日本語 ｱｲ ¥‾−①"),
+            b"\x93\xfa\x96{\x8c\xea \xb1\xb2 \\~\x81|\x87\x40"
+        );
+        // NEC-selected IBM extensions encode as the IBM extensions proper.
+        assert_eq!(decode(Encoding::ShiftJis, b"\xed\x40"), "\u{7E8A}");
+        assert_eq!(encode(Encoding::ShiftJis, "\u{7E8A}"), b"\xfa\x5c");
+        // A bad trail byte that is ASCII is reprocessed; a truncated lead is an error.
+        assert_eq!(
+            decode(Encoding::ShiftJis, b"\x81\x20\xa0\x81"),
+            "\u{FFFD} \u{FFFD}\u{FFFD}"
+        );
+        assert_eq!(encode(Encoding::ShiftJis, "\u{E000}é"), b"&#57344;&#233;");
+    }
+
+    #[test]
+    fn euc_jp() {
+        let bytes = b"\xc6\xfc\xcb\xdc\xb8\xec \x8e\xb1 \x8f\xa2\xb7\x8f\xb0\xa1";
+        assert_eq!(decode(Encoding::EucJp, bytes), "日本語 ｱ ～丂");
+        assert_eq!(
+            encode(Encoding::EucJp, "日本語 ｱ ¥"),
+            b"\xc6\xfc\xcb\xdc\xb8\xec \x8e\xb1 \\"
+        );
+        // jis0212 is decode-only.
+        assert_eq!(encode(Encoding::EucJp, "丂"), b"&#19970;");
+        assert_eq!(
+            decode(Encoding::EucJp, b"\xa1\x41\x8f\xa2"),
+            "\u{FFFD}A\u{FFFD}"
+        );
+        assert_eq!(decode(Encoding::EucJp, b"\x8e\xe0\xff"), "\u{FFFD}\u{FFFD}");
+    }
+
+    #[test]
+    fn iso_2022_jp() {
+        let bytes = b"a\x1b$B;~\x1b(J\\\x1b(I+\x1b(B~";
+        assert_eq!(decode(Encoding::Iso2022Jp, bytes), "a時¥ｫ~");
+        assert_eq!(
+            encode(Encoding::Iso2022Jp, "a時¥ｫ~"),
+            b"a\x1b$B;~\x1b(J\\\x1b$B%)\x1b(B~"
+        );
+        assert_eq!(encode(Encoding::Iso2022Jp, "日本"), b"\x1b$BF|K\\\x1b(B");
+        // Escapes with nothing between them, and unknown escapes.
+        assert_eq!(decode(Encoding::Iso2022Jp, b"\x1b(J\x1b(Ba"), "\u{FFFD}a");
+        assert_eq!(
+            decode(Encoding::Iso2022Jp, b"\x1b(Ca\x1b"),
+            "\u{FFFD}(Ca\u{FFFD}"
+        );
+        assert_eq!(decode(Encoding::Iso2022Jp, b"\x1b$B;"), "\u{FFFD}");
+        assert_eq!(decode(Encoding::Iso2022Jp, b"\x1b$B;\x1b(B"), "\u{FFFD}");
+        assert_eq!(
+            encode(Encoding::Iso2022Jp, "\x1b時é"),
+            b"&#65533;\x1b$B;~\x1b(B&#233;"
+        );
+    }
+
+    #[test]
+    fn streams_across_chunks() {
+        let mut decoder = Encoding::Iso2022Jp.new_decoder(ErrorMode::Fatal).unwrap();
+        let mut output = String::new();
+        for byte in b"\x1b$BF|K\\\x1b(B!" {
+            decoder.decode(&[*byte], &mut output, false).unwrap();
+        }
+        decoder.decode(&[], &mut output, true).unwrap();
+        assert_eq!(output, "日本!");
+
+        let mut decoder = Encoding::ShiftJis.new_decoder(ErrorMode::Fatal).unwrap();
+        let mut output = String::new();
+        assert!(decoder.decode(b"ok\x93", &mut output, false).is_ok());
+        let err = decoder.decode(b"", &mut output, true).unwrap_err();
+        assert_eq!(err.encoding(), Encoding::ShiftJis);
+        assert_eq!(output, "ok");
+
+        let mut encoder = Encoding::Iso2022Jp
+            .new_encoder(EncoderErrorMode::Fatal)
+            .unwrap();
+        let mut output = Vec::new();
+        encoder.encode("日", &mut output, false).unwrap();
+        encoder.encode("本", &mut output, false).unwrap();
+        assert_eq!(
+            encoder.encode("😀", &mut output, true),
+            Err(EncodeError {
+                encoding: Encoding::Iso2022Jp,
+                code_point: '😀'
+            })
+        );
+        assert_eq!(output, b"\x1b$BF|K\\\x1b(B");
+    }
+
+    #[test]
+    fn round_trips_jis0208() {
+        for encoding in [Encoding::ShiftJis, Encoding::EucJp, Encoding::Iso2022Jp] {
+            let text: String = super::tables::JIS0208_POINTERS
+                .iter()
+                .map(|&(cp, _)| char::from_u32(u32::from(cp)).unwrap())
+                .collect();
+            let bytes = encode(encoding, &text);
+            assert_eq!(decode(encoding, &bytes), text, "{}", encoding);
+        }
+    }
+}
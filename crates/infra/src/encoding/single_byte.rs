@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+//! The Encoding Standard's single-byte encodings: IBM866, ISO-8859-x, KOI8, macintosh,
+//! windows-874 and windows-125x, and x-mac-cyrillic. Bytes 0x00..=0x7F are ASCII and the
+//! upper half goes through a 128-entry index per encoding, generated into `tables` by
+//! `scripts/gen_single_byte_tables.py`. The codecs keep no state between chunks.
+
+use super::{EncoderErrorMode, Encoding, ErrorMode};
+
+#[rustfmt::skip]
+mod tables;
+
+/// Code points for bytes 0x80..=0xFF; 0 marks a byte without one.
+pub(super) type Index = [u16; 128];
+
+/// The index of a single-byte encoding, or `None` for any other encoding.
+pub(super) fn index(encoding: Encoding) -> Option<&'static Index> {
+    use Encoding::*;
+    Some(match encoding {
+        Ibm866 => &tables::IBM866,
+        Iso8859_2 => &tables::ISO_8859_2,
+        Iso8859_3 => &tables::ISO_8859_3,
+        Iso8859_4 => &tables::ISO_8859_4,
+        Iso8859_5 => &tables::ISO_8859_5,
+        Iso8859_6 => &tables::ISO_8859_6,
+        Iso8859_7 => &tables::ISO_8859_7,
+        // ISO-8859-8-I differs only in how HTML treats text direction.
+        Iso8859_8 | Iso8859_8I => &tables::ISO_8859_8,
+        Iso8859_10 => &tables::ISO_8859_10,
+        Iso8859_13 => &tables::ISO_8859_13,
+        Iso8859_14 => &tables::ISO_8859_14,
+        Iso8859_15 => &tables::ISO_8859_15,
+        Iso8859_16 => &tables::ISO_8859_16,
+        Koi8R => &tables::KOI8_R,
+        Koi8U => &tables::KOI8_U,
+        Macintosh => &tables::MACINTOSH,
+        Windows874 => &tables::WINDOWS_874,
+        Windows1250 => &tables::WINDOWS_1250,
+        Windows1251 => &tables::WINDOWS_1251,
+        Windows1252 => &tables::WINDOWS_1252,
+        Windows1253 => &tables::WINDOWS_1253,
+        Windows1254 => &tables::WINDOWS_1254,
+        Windows1255 => &tables::WINDOWS_1255,
+        Windows1256 => &tables::WINDOWS_1256,
+        Windows1257 => &tables::WINDOWS_1257,
+        Windows1258 => &tables::WINDOWS_1258,
+        XMacCyrillic => &tables::X_MAC_CYRILLIC,
+        _ => return None,
+    })
+}
+
+/// Decode `chunk` through `index`; returns false if a fatal decoder hit an unmapped byte.
+pub(super) fn decode(index: &Index, mode: ErrorMode, chunk: &[u8], output: &mut String) -> bool {
+    output.reserve(chunk.len());
+    for &byte in chunk {
+        if byte.is_ascii() {
+            output.push(char::from(byte));
+            continue;
+        }
+        match index[usize::from(byte - 0x80)] {
+            0 => match mode {
+                ErrorMode::Replacement => output.push(char::REPLACEMENT_CHARACTER),
+                ErrorMode::Fatal => return false,
+            },
+            cp => output.push(char::from_u32(u32::from(cp)).expect("index holds scalar values")),
+        }
+    }
+    true
+}
+
+/// Encode `input` through `index`, returning the code point a fatal encoder could not encode.
+pub(super) fn encode(
+    index: &Index,
+    mode: EncoderErrorMode,
+    input: &str,
+    output: &mut Vec<u8>,
+) -> Result<(), char> {
+    output.reserve(input.len());
+    for c in input.chars() {
+        if c.is_ascii() {
+            output.push(c as u8);
+            continue;
+        }
+        match index.iter().position(|&cp| u32::from(cp) == u32::from(c)) {
+            Some(pointer) => output.push(0x80 + pointer as u8),
+            None => match mode {
+                EncoderErrorMode::Html => {
+                    output.extend_from_slice(format!("&#{};", u32::from(c)).as_bytes())
+                }
+                EncoderErrorMode::Fatal => return Err(c),
+            },
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::test_support::{decode, decode_with, encode, encode_with};
+    use crate::encoding::{EncoderErrorMode, Encoding, ErrorMode};
+
+    #[test]
+    fn decodes_with_whatwg_patches() {
+        let cases: [(Encoding, &[u8], &str); 7] = [
+            (
+                Encoding::Windows1252,
+                b"caf\xe9 \x80 \x93x\x94",
+                "café € “x”",
+            ),
+            (
+                Encoding::Windows1252,
+                b"\x81\x8d\x8f\x90\x9d",
+                "\u{81}\u{8D}\u{8F}\u{90}\u{9D}",
+            ),
+            (Encoding::Koi8U, b"\xae\xbe\xa4", "ўЎє"),
+            (Encoding::Windows1255, b"\xca\xe0", "\u{5BA}א"),
+            (Encoding::Iso8859_8I, b"\xe0\xfd", "א\u{200E}"),
+            (Encoding::Ibm866, b"\x8f\xe0\xa8\xa2\xa5\xe2", "Привет"),
+            (Encoding::Macintosh, b"\xdb\xf0", "€\u{F8FF}"),
+        ];
+        for (encoding, bytes, text) in cases {
+            assert_eq!(decode(encoding, bytes), text, "{}", encoding);
+        }
+    }
+
+    #[test]
+    fn unmapped_bytes_and_code_points() {
+        assert_eq!(decode(Encoding::Windows1253, b"a\xaab"), "a\u{FFFD}b");
+        let err = decode_with(Encoding::Windows1253, b"a\xaab", ErrorMode::Fatal).unwrap_err();
+        assert_eq!(err.encoding(), Encoding::Windows1253);
+        assert_eq!(
+            encode(Encoding::Iso8859_2, "Łódź 😀"),
+            b"\xa3\xf3d\xbc &#128512;"
+        );
+        let err = encode_with(Encoding::Iso8859_2, "Łx€", EncoderErrorMode::Fatal).unwrap_err();
+        assert_eq!(err.code_point(), '€');
+    }
+
+    #[test]
+    fn every_mapped_byte_round_trips() {
+        for encoding in Encoding::ALL {
+            let Some(index) = super::index(encoding) else {
+                continue;
+            };
+            let bytes: Vec<u8> = (0..=0xFF)
+                .filter(|&b| b < 0x80 || index[usize::from(b - 0x80)] != 0)
+                .collect();
+            let text = decode_with(encoding, &bytes, ErrorMode::Fatal).unwrap();
+            let encoded = encode_with(encoding, &text, EncoderErrorMode::Fatal).unwrap();
+            assert_eq!(encoded, bytes, "{}", encoding);
+        }
+    }
+}
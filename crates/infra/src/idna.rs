@@ -0,0 +1,563 @@
+// SPDX-License-Identifier: MIT
+//! IDNA processing per UTS #46 (Unicode IDNA Compatibility Processing).
+//! The URL Standard's "domain to ASCII" runs ToASCII with CheckBidi, CheckJoiners and
+//! nontransitional processing; `domain_to_ascii` packages exactly those settings.
+//! Unicode data lives in the generated `tables` module (see `scripts/gen_idna_tables.py`).
+
+use std::fmt::{Display, Formatter};
+
+use crate::punycode;
+
+mod normalize;
+#[rustfmt::skip]
+mod tables;
+
+/// Unicode version of the compiled-in IDNA, normalization, bidi and joining data.
+pub const UNICODE_VERSION: &str = tables::UNICODE_VERSION;
+
+/// UTS #46 processing flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub check_hyphens: bool,
+    pub check_bidi: bool,
+    pub check_joiners: bool,
+    pub use_std3_ascii_rules: bool,
+    pub transitional_processing: bool,
+    pub verify_dns_length: bool,
+}
+
+impl Default for Config {
+    /// The settings used by the URL Standard's non-strict "domain to ASCII".
+    fn default() -> Self {
+        Config {
+            check_hyphens: false,
+            check_bidi: true,
+            check_joiners: true,
+            use_std3_ascii_rules: false,
+            transitional_processing: false,
+            verify_dns_length: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdnaError {
+    /// A code point is disallowed by the mapping table.
+    DisallowedCodePoint,
+    /// An `xn--` label is not valid Punycode or decodes to an invalid label.
+    InvalidPunycode,
+    /// A label is not in Normalization Form C.
+    NotNfc,
+    /// Hyphen placement rules (CheckHyphens) or an unexpected `xn--` prefix.
+    InvalidHyphen,
+    /// A label starts with a combining mark.
+    LeadingCombiningMark,
+    /// ZERO WIDTH (NON-)JOINER outside the contexts allowed by RFC 5892.
+    InvalidJoiner,
+    /// A label of a bidi domain name violates RFC 5893.
+    InvalidBidi,
+    /// Label or domain length outside DNS limits (VerifyDnsLength).
+    InvalidLength,
+}
+
+impl Display for IdnaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IdnaError::*;
+        match self {
+            DisallowedCodePoint => write!(f, "disallowed code point"),
+            InvalidPunycode => write!(f, "invalid punycode label"),
+            NotNfc => write!(f, "label is not in NFC"),
+            InvalidHyphen => write!(f, "invalid hyphen placement"),
+            LeadingCombiningMark => write!(f, "label starts with a combining mark"),
+            InvalidJoiner => write!(f, "invalid zero width joiner or non-joiner"),
+            InvalidBidi => write!(f, "bidi rule violation"),
+            InvalidLength => write!(f, "invalid DNS length"),
+        }
+    }
+}
+
+impl std::error::Error for IdnaError {}
+
+/// The URL Standard's "domain to ASCII" algorithm.
+/// `be_strict` enables UseSTD3ASCIIRules and VerifyDnsLength.
+pub fn domain_to_ascii(domain: &str, be_strict: bool) -> Result<String, IdnaError> {
+    let is_plain_ascii = domain.is_ascii()
+        && !domain
+            .split('.')
+            .any(|label| has_ace_prefix(label.as_bytes()));
+    if !be_strict && is_plain_ascii {
+        // For ASCII input without "xn--" labels, ToASCII reduces to ASCII lowercasing.
+        return Ok(domain.to_ascii_lowercase());
+    }
+    let config = Config {
+        use_std3_ascii_rules: be_strict,
+        verify_dns_length: be_strict,
+        ..Config::default()
+    };
+    to_ascii(domain, &config)
+}
+
+/// UTS #46 ToASCII. Returns the first error recorded while processing.
+pub fn to_ascii(domain: &str, config: &Config) -> Result<String, IdnaError> {
+    let (labels, error) = process(domain, config);
+    if let Some(error) = error {
+        return Err(error);
+    }
+    let mut out = String::with_capacity(domain.len());
+    for (index, label) in labels.iter().enumerate() {
+        if index > 0 {
+            out.push('.');
+        }
+        if label.iter().all(char::is_ascii) {
+            out.extend(label.iter());
+        } else {
+            out.push_str("xn--");
+            out.push_str(&punycode::encode_chars(label).map_err(|_| IdnaError::InvalidPunycode)?);
+        }
+    }
+    if config.verify_dns_length {
+        verify_dns_length(&out)?;
+    }
+    Ok(out)
+}
+
+/// The URL Standard's "domain to Unicode" algorithm, used to display hosts.
+/// The converted domain is returned even when validation fails, alongside the first error.
+pub fn domain_to_unicode(domain: &str, be_strict: bool) -> (String, Result<(), IdnaError>) {
+    let config = Config {
+        use_std3_ascii_rules: be_strict,
+        ..Config::default()
+    };
+    to_unicode(domain, &config)
+}
+
+/// UTS #46 ToUnicode. Labels that fail to decode are left in their `xn--` form.
+pub fn to_unicode(domain: &str, config: &Config) -> (String, Result<(), IdnaError>) {
+    let (labels, error) = process(domain, config);
+    let labels: Vec<String> = labels.iter().map(|label| label.iter().collect()).collect();
+    (labels.join("."), error.map_or(Ok(()), Err))
+}
+
+/// UTS #46 Processing steps 1-4 (map, normalize, break, convert/validate).
+fn process(domain: &str, config: &Config) -> (Vec<Vec<char>>, Option<IdnaError>) {
+    let mut error = None;
+    let mut record = |e: IdnaError| {
+        error.get_or_insert(e);
+    };
+
+    let mut mapped = Vec::with_capacity(domain.len());
+    for c in domain.chars() {
+        match lookup(c, config) {
+            Mapping::Valid => mapped.push(c),
+            Mapping::Ignored => {}
+            Mapping::Mapped(replacement) => mapped.extend(replacement.chars()),
+            Mapping::Disallowed => {
+                record(IdnaError::DisallowedCodePoint);
+                mapped.push(c);
+            }
+        }
+    }
+    let normalized = normalize::nfc(&mapped);
+
+    let mut labels = Vec::new();
+    for label in normalized.split(|&c| c == '.') {
+        let mut label = label.to_vec();
+        if has_ace_prefix_chars(&label) {
+            if !label.iter().all(char::is_ascii) {
+                record(IdnaError::InvalidPunycode);
+                labels.push(label);
+                continue;
+            }
+            let encoded: String = label[4..].iter().collect();
+            let Ok(decoded) = punycode::decode_to_chars(&encoded) else {
+                record(IdnaError::InvalidPunycode);
+                labels.push(label);
+                continue;
+            };
+            if decoded.is_empty() || decoded.iter().all(char::is_ascii) {
+                record(IdnaError::InvalidPunycode);
+            }
+            label = decoded;
+            // Decoded labels are validated as nontransitional, whatever the configuration.
+            let nontransitional = Config {
+                transitional_processing: false,
+                ..*config
+            };
+            if let Err(e) = validate_label(&label, &nontransitional) {
+                record(e);
+            }
+        } else if let Err(e) = validate_label(&label, config) {
+            record(e);
+        }
+        labels.push(label);
+    }
+
+    let violates_bidi = |label: &Vec<char>| !label.is_empty() && !satisfies_bidi_rule(label);
+    if config.check_bidi && is_bidi_domain(&labels) && labels.iter().any(violates_bidi) {
+        record(IdnaError::InvalidBidi);
+    }
+    (labels, error)
+}
+
+/// UTS #46 section 4.1 validity criteria, except the bidi rule which spans the whole domain.
+fn validate_label(label: &[char], config: &Config) -> Result<(), IdnaError> {
+    if label.is_empty() {
+        return Ok(());
+    }
+    if !normalize::is_nfc(label) {
+        return Err(IdnaError::NotNfc);
+    }
+    if config.check_hyphens {
+        let dashes_at_3_and_4 = label.len() >= 4 && label[2] == '-' && label[3] == '-';
+        let edge_dash = label.first() == Some(&'-') || label.last() == Some(&'-');
+        if dashes_at_3_and_4 || edge_dash {
+            return Err(IdnaError::InvalidHyphen);
+        }
+    } else if has_ace_prefix_chars(label) {
+        return Err(IdnaError::InvalidHyphen);
+    }
+    if label.contains(&'.') {
+        return Err(IdnaError::DisallowedCodePoint);
+    }
+    if is_mark(label[0]) {
+        return Err(IdnaError::LeadingCombiningMark);
+    }
+    for &c in label {
+        let valid = match lookup(c, config) {
+            Mapping::Valid => true,
+            Mapping::Ignored | Mapping::Mapped(_) | Mapping::Disallowed => false,
+        };
+        if !valid {
+            return Err(IdnaError::DisallowedCodePoint);
+        }
+    }
+    if config.check_joiners && !satisfies_context_j(label) {
+        return Err(IdnaError::InvalidJoiner);
+    }
+    Ok(())
+}
+
+fn verify_dns_length(domain: &str) -> Result<(), IdnaError> {
+    let domain = domain.strip_suffix('.').unwrap_or(domain);
+    if domain.is_empty() || domain.len() > 253 {
+        return Err(IdnaError::InvalidLength);
+    }
+    if domain
+        .split('.')
+        .any(|label| label.is_empty() || label.len() > 63)
+    {
+        return Err(IdnaError::InvalidLength);
+    }
+    Ok(())
+}
+
+fn has_ace_prefix(label: &[u8]) -> bool {
+    label.len() >= 4 && label[..4].eq_ignore_ascii_case(b"xn--")
+}
+
+fn has_ace_prefix_chars(label: &[char]) -> bool {
+    label.starts_with(&['x', 'n', '-', '-'])
+}
+
+// ---- Mapping table ----
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Valid,
+    Ignored,
+    Mapped,
+    Deviation,
+    Disallowed,
+    Std3Valid,
+    Std3Mapped,
+}
+
+enum Mapping {
+    Valid,
+    Ignored,
+    Mapped(&'static str),
+    Disallowed,
+}
+
+fn table_entry(c: char) -> (Status, &'static str) {
+    let cp = c as u32;
+    let index = match tables::MAPPING.binary_search_by_key(&cp, |&(first, _, _, _)| first) {
+        Ok(index) => index,
+        Err(insert_at) => insert_at - 1,
+    };
+    let (_, status, offset, len) = tables::MAPPING[index];
+    let start = offset as usize;
+    (status, &tables::MAPPING_DATA[start..start + len as usize])
+}
+
+/// Resolve the status of `c` under `config` (step 1 of UTS #46 processing).
+fn lookup(c: char, config: &Config) -> Mapping {
+    let (status, mapping) = table_entry(c);
+    match status {
+        Status::Valid => Mapping::Valid,
+        Status::Ignored => Mapping::Ignored,
+        Status::Mapped => Mapping::Mapped(mapping),
+        Status::Deviation if config.transitional_processing => Mapping::Mapped(mapping),
+        Status::Deviation => Mapping::Valid,
+        Status::Disallowed => Mapping::Disallowed,
+        Status::Std3Valid if config.use_std3_ascii_rules => Mapping::Disallowed,
+        Status::Std3Valid => Mapping::Valid,
+        Status::Std3Mapped if config.use_std3_ascii_rules => Mapping::Disallowed,
+        Status::Std3Mapped => Mapping::Mapped(mapping),
+    }
+}
+
+// ---- Character properties ----
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+enum BidiClass {
+    L,
+    R,
+    AL,
+    AN,
+    EN,
+    ES,
+    CS,
+    ET,
+    ON,
+    BN,
+    NSM,
+    /// Any class not named by RFC 5893 (whitespace, explicit embeddings, ...).
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoiningType {
+    C,
+    D,
+    L,
+    R,
+    T,
+}
+
+fn find_range<T: Copy>(ranges: &[(u32, u32, T)], c: char) -> Option<T> {
+    let cp = c as u32;
+    let index = ranges.partition_point(|&(_, last, _)| last < cp);
+    ranges
+        .get(index)
+        .filter(|&&(first, _, _)| first <= cp)
+        .map(|&(_, _, value)| value)
+}
+
+fn bidi_class(c: char) -> BidiClass {
+    find_range(tables::BIDI_CLASS, c).unwrap_or(BidiClass::L)
+}
+
+fn joining_type(c: char) -> Option<JoiningType> {
+    find_range(tables::JOINING_TYPE, c)
+}
+
+fn is_mark(c: char) -> bool {
+    let cp = c as u32;
+    let index = tables::MARKS.partition_point(|&(_, last)| last < cp);
+    tables::MARKS
+        .get(index)
+        .is_some_and(|&(first, _)| first <= cp)
+}
+
+fn is_virama(c: char) -> bool {
+    normalize::combining_class(c) == 9
+}
+
+// ---- CONTEXTJ (RFC 5892 Appendix A.1 and A.2) ----
+
+fn satisfies_context_j(label: &[char]) -> bool {
+    for (index, &c) in label.iter().enumerate() {
+        if c != '\u{200C}' && c != '\u{200D}' {
+            continue;
+        }
+        if index > 0 && is_virama(label[index - 1]) {
+            continue;
+        }
+        if c == '\u{200D}' {
+            return false;
+        }
+        // ZWNJ: (Joining_Type:{L,D})(Joining_Type:T)* ZWNJ (Joining_Type:T)*(Joining_Type:{R,D})
+        let before = label[..index]
+            .iter()
+            .rev()
+            .find(|&&b| joining_type(b) != Some(JoiningType::T));
+        let after = label[index + 1..]
+            .iter()
+            .find(|&&a| joining_type(a) != Some(JoiningType::T));
+        let joins_left = before
+            .is_some_and(|&b| matches!(joining_type(b), Some(JoiningType::L | JoiningType::D)));
+        let joins_right = after
+            .is_some_and(|&a| matches!(joining_type(a), Some(JoiningType::R | JoiningType::D)));
+        if !(joins_left && joins_right) {
+            return false;
+        }
+    }
+    true
+}
+
+// ---- Bidi rule (RFC 5893 section 2) ----
+
+fn is_bidi_domain(labels: &[Vec<char>]) -> bool {
+    labels
+        .iter()
+        .flatten()
+        .any(|&c| matches!(bidi_class(c), BidiClass::R | BidiClass::AL | BidiClass::AN))
+}
+
+fn satisfies_bidi_rule(label: &[char]) -> bool {
+    use BidiClass::*;
+    let classes: Vec<BidiClass> = label.iter().map(|&c| bidi_class(c)).collect();
+    let last_non_nsm = classes.iter().rev().find(|&&class| class != NSM).copied();
+    match classes[0] {
+        R | AL => {
+            let allowed = classes
+                .iter()
+                .all(|class| matches!(class, R | AL | AN | EN | ES | CS | ET | ON | BN | NSM));
+            let valid_end = matches!(last_non_nsm, Some(R | AL | EN | AN));
+            let mixed_numbers = classes.contains(&EN) && classes.contains(&AN);
+            allowed && valid_end && !mixed_numbers
+        }
+        L => {
+            let allowed = classes
+                .iter()
+                .all(|class| matches!(class, L | EN | ES | CS | ET | ON | BN | NSM));
+            let valid_end = matches!(last_non_nsm, Some(L | EN));
+            allowed && valid_end
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_fast_path_lowercases() {
+        assert_eq!(
+            domain_to_ascii("EXAMPLE.com", false).unwrap(),
+            "example.com"
+        );
+        assert_eq!(
+            domain_to_ascii("a_b.example", false).unwrap(),
+            "a_b.example"
+        );
+    }
+
+    #[test]
+    fn maps_and_encodes_unicode_labels() {
+        assert_eq!(
+            domain_to_ascii("Bücher.example", false).unwrap(),
+            "xn--bcher-kva.example"
+        );
+        assert_eq!(domain_to_ascii("faß.de", false).unwrap(), "xn--fa-hia.de");
+        assert_eq!(
+            domain_to_ascii(
+                "\u{FF25}\u{FF38}\u{FF21}\u{FF2D}\u{FF30}\u{FF2C}\u{FF25}\u{FF0E}com",
+                false
+            )
+            .unwrap(),
+            "example.com"
+        );
+        assert_eq!(domain_to_ascii("a\u{308}.de", false).unwrap(), "xn--4ca.de");
+        assert_eq!(
+            domain_to_ascii("日本語。ＪＰ", false).unwrap(),
+            "xn--wgv71a119e.jp"
+        );
+        assert_eq!(domain_to_ascii("a\u{AD}b", false).unwrap(), "ab");
+        let transitional = Config {
+            transitional_processing: true,
+            ..Config::default()
+        };
+        assert_eq!(to_ascii("faß.de", &transitional).unwrap(), "fass.de");
+    }
+
+    #[test]
+    fn validates_ace_labels() {
+        assert_eq!(
+            domain_to_ascii("XN--BCHER-KVA.example", false).unwrap(),
+            "xn--bcher-kva.example"
+        );
+        assert_eq!(
+            domain_to_ascii("xn--a.com", false),
+            Err(IdnaError::DisallowedCodePoint)
+        );
+        assert_eq!(
+            domain_to_ascii("xn--abc-.com", false),
+            Err(IdnaError::InvalidPunycode)
+        );
+        assert_eq!(
+            domain_to_ascii("xn--ü.com", false),
+            Err(IdnaError::InvalidPunycode)
+        );
+        assert_eq!(
+            domain_to_ascii("xn--99999999999z", false),
+            Err(IdnaError::InvalidPunycode)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_labels() {
+        assert_eq!(
+            domain_to_ascii("\u{301}a.com", false),
+            Err(IdnaError::LeadingCombiningMark)
+        );
+        assert_eq!(
+            domain_to_ascii("a\u{200C}b", false),
+            Err(IdnaError::InvalidJoiner)
+        );
+        assert!(domain_to_ascii("\u{915}\u{94D}\u{200C}\u{937}", false).is_ok());
+        assert_eq!(
+            domain_to_ascii("\u{5D0}a.com", false),
+            Err(IdnaError::InvalidBidi)
+        );
+        assert_eq!(
+            domain_to_ascii("a\u{5D0}.com", false),
+            Err(IdnaError::InvalidBidi)
+        );
+        assert!(domain_to_ascii("\u{5D0}\u{5D1}.com", false).is_ok());
+        assert_eq!(
+            domain_to_ascii("a\u{E0001}", false),
+            Err(IdnaError::DisallowedCodePoint)
+        );
+    }
+
+    #[test]
+    fn converts_to_unicode() {
+        assert_eq!(
+            domain_to_unicode("xn--bcher-kva.example", false),
+            ("bücher.example".to_string(), Ok(()))
+        );
+        assert_eq!(
+            domain_to_unicode("EXAMPLE.xn--wgv71a119e", false),
+            ("example.日本語".to_string(), Ok(()))
+        );
+        assert_eq!(
+            domain_to_unicode("xn--99999999999z.com", false),
+            (
+                "xn--99999999999z.com".to_string(),
+                Err(IdnaError::InvalidPunycode)
+            )
+        );
+    }
+
+    #[test]
+    fn strict_mode_applies_std3_and_dns_length() {
+        assert_eq!(
+            domain_to_ascii("a_b.example", true),
+            Err(IdnaError::DisallowedCodePoint)
+        );
+        let long_label = "a".repeat(64);
+        assert_eq!(
+            domain_to_ascii(&long_label, true),
+            Err(IdnaError::InvalidLength)
+        );
+        assert!(domain_to_ascii(&long_label, false).is_ok());
+        assert_eq!(
+            domain_to_ascii("example.com.", true).unwrap(),
+            "example.com."
+        );
+    }
+}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+//! Unicode Normalization Form C (UAX #15), as required by UTS #46 processing.
+
+use super::tables::{COMBINING_CLASS, COMPOSITION, DECOMPOSITION, DECOMPOSITION_DATA};
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Canonical_Combining_Class of `c`.
+pub(super) fn combining_class(c: char) -> u8 {
+    let cp = c as u32;
+    COMBINING_CLASS
+        .binary_search_by(|&(first, last, _)| {
+            if last < cp {
+                std::cmp::Ordering::Less
+            } else if first > cp {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .map_or(0, |index| COMBINING_CLASS[index].2)
+}
+
+/// Normalize `input` to NFC.
+pub(super) fn nfc(input: &[char]) -> Vec<char> {
+    let mut decomposed = Vec::with_capacity(input.len());
+    for &c in input {
+        decompose(c, &mut decomposed);
+    }
+    reorder(&mut decomposed);
+    compose(&mut decomposed);
+    decomposed
+}
+
+/// Returns true when `input` is already in NFC.
+pub(super) fn is_nfc(input: &[char]) -> bool {
+    nfc(input) == input
+}
+
+fn decompose(c: char, out: &mut Vec<char>) {
+    let cp = c as u32;
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&cp) {
+        let index = cp - HANGUL_S_BASE;
+        let l = HANGUL_L_BASE + index / HANGUL_N_COUNT;
+        let v = HANGUL_V_BASE + (index % HANGUL_N_COUNT) / HANGUL_T_COUNT;
+        let t = HANGUL_T_BASE + index % HANGUL_T_COUNT;
+        out.extend([l, v].iter().filter_map(|&part| char::from_u32(part)));
+        if t != HANGUL_T_BASE {
+            out.extend(char::from_u32(t));
+        }
+        return;
+    }
+    match DECOMPOSITION.binary_search_by_key(&cp, |&(code_point, _, _)| code_point) {
+        Ok(index) => {
+            let (_, offset, len) = DECOMPOSITION[index];
+            let start = offset as usize;
+            out.extend_from_slice(&DECOMPOSITION_DATA[start..start + len as usize]);
+        }
+        Err(_) => out.push(c),
+    }
+}
+
+/// Canonical ordering: stable-sort each run of non-starters by combining class.
+fn reorder(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < chars.len() && combining_class(chars[end]) != 0 {
+            end += 1;
+        }
+        chars[start..end].sort_by_key(|&c| combining_class(c));
+        start = end;
+    }
+}
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (a, b) = (first as u32, second as u32);
+    if (HANGUL_L_BASE..HANGUL_L_BASE + HANGUL_L_COUNT).contains(&a)
+        && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&b)
+    {
+        let lv = (a - HANGUL_L_BASE) * HANGUL_N_COUNT + (b - HANGUL_V_BASE) * HANGUL_T_COUNT;
+        return char::from_u32(HANGUL_S_BASE + lv);
+    }
+    let is_lv = (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&a)
+        && (a - HANGUL_S_BASE).is_multiple_of(HANGUL_T_COUNT);
+    if is_lv && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&b) {
+        return char::from_u32(a + (b - HANGUL_T_BASE));
+    }
+    COMPOSITION
+        .binary_search_by_key(&(a, b), |&(x, y, _)| (x, y))
+        .ok()
+        .and_then(|index| char::from_u32(COMPOSITION[index].2))
+}
+
+/// Canonical composition over a decomposed, reordered buffer.
+fn compose(chars: &mut Vec<char>) {
+    let mut starter: Option<usize> = None;
+    let mut last_class: Option<u8> = None;
+    let mut write = 0;
+    for read in 0..chars.len() {
+        let c = chars[read];
+        let class = combining_class(c);
+        if let Some(starter_index) = starter {
+            // A character is blocked from the starter by an intervening character of the same
+            // or higher class (or any starter).
+            let blocked = matches!(last_class, Some(last) if last == 0 || last >= class);
+            if !blocked {
+                if let Some(composite) = compose_pair(chars[starter_index], c) {
+                    chars[starter_index] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(write);
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        chars[write] = c;
+        write += 1;
+    }
+    chars.truncate(write);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nfc_str(input: &str) -> String {
+        nfc(&input.chars().collect::<Vec<_>>())
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn composes_and_reorders() {
+        assert_eq!(nfc_str("a\u{308}"), "\u{E4}");
+        assert_eq!(nfc_str("\u{212B}"), "\u{C5}");
+        assert_eq!(nfc_str("a\u{323}\u{302}"), "\u{1EAD}");
+        assert_eq!(nfc_str("a\u{302}\u{323}"), "\u{1EAD}");
+        assert_eq!(nfc_str("\u{1100}\u{1161}\u{11A8}"), "\u{AC01}");
+        assert_eq!(nfc_str("\u{AC01}"), "\u{AC01}");
+        // U+0344 decomposes and must not recompose (composition exclusion).
+        assert_eq!(nfc_str("\u{344}"), "\u{308}\u{301}");
+        assert!(is_nfc(&['a', 'b']));
+        assert!(!is_nfc(&['e', '\u{301}']));
+    }
+}
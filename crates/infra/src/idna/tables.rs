@@ -0,0 +1,3950 @@
+// SPDX-License-Identifier: MIT
+// Generated by crates/infra/scripts/gen_idna_tables.py from Unicode 15.1.0 data.
+// Do not edit by hand; rerun the script instead.
+
+#![allow(clippy::unreadable_literal)]
+
+pub(super) const UNICODE_VERSION: &str = "15.1.0";
+
+use super::Status::{self, *};
+
+/// UTS #46 mapping: (first code point of the run, status, mapping offset, mapping length).
+/// Offsets and lengths are in bytes into `MAPPING_DATA`.
+pub(super) static MAPPING: &[(u32, Status, u16, u8)] = &[
+    (0x0, Std3Valid, 0, 0), (0x1, Std3Valid, 0, 0), (0x2, Std3Valid, 0, 0), (0x3, Std3Valid, 0, 0),
+    (0x4, Std3Valid, 0, 0), (0x5, Std3Valid, 0, 0), (0x6, Std3Valid, 0, 0), (0x7, Std3Valid, 0, 0),
+    (0x8, Std3Valid, 0, 0), (0x9, Std3Valid, 0, 0), (0xA, Std3Valid, 0, 0), (0xB, Std3Valid, 0, 0),
+    (0xC, Std3Valid, 0, 0), (0xD, Std3Valid, 0, 0), (0xE, Std3Valid, 0, 0), (0xF, Std3Valid, 0, 0),
+    (0x10, Std3Valid, 0, 0), (0x11, Std3Valid, 0, 0), (0x12, Std3Valid, 0, 0), (0x13, Std3Valid, 0, 0),
+    (0x14, Std3Valid, 0, 0), (0x15, Std3Valid, 0, 0), (0x16, Std3Valid, 0, 0), (0x17, Std3Valid, 0, 0),
+    (0x18, Std3Valid, 0, 0), (0x19, Std3Valid, 0, 0), (0x1A, Std3Valid, 0, 0), (0x1B, Std3Valid, 0, 0),
+    (0x1C, Std3Valid, 0, 0), (0x1D, Std3Valid, 0, 0), (0x1E, Std3Valid, 0, 0), (0x1F, Std3Valid, 0, 0),
+    (0x20, Std3Valid, 0, 0), (0x21, Std3Valid, 0, 0), (0x22, Std3Valid, 0, 0), (0x23, Std3Valid, 0, 0),
+    (0x24, Std3Valid, 0, 0), (0x25, Std3Valid, 0, 0), (0x26, Std3Valid, 0, 0), (0x27, Std3Valid, 0, 0),
+    (0x28, Std3Valid, 0, 0), (0x29, Std3Valid, 0, 0), (0x2A, Std3Valid, 0, 0), (0x2B, Std3Valid, 0, 0),
+    (0x2C, Std3Valid, 0, 0), (0x2D, Valid, 0, 0), (0x2E, Valid, 0, 0), (0x2F, Std3Valid, 0, 0),
+    (0x30, Valid, 0, 0), (0x31, Valid, 0, 0), (0x32, Valid, 0, 0), (0x33, Valid, 0, 0),
+    (0x34, Valid, 0, 0), (0x35, Valid, 0, 0), (0x36, Valid, 0, 0), (0x37, Valid, 0, 0),
+    (0x38, Valid, 0, 0), (0x39, Valid, 0, 0), (0x3A, Std3Valid, 0, 0), (0x3B, Std3Valid, 0, 0),
+    (0x3C, Std3Valid, 0, 0), (0x3D, Std3Valid, 0, 0), (0x3E, Std3Valid, 0, 0), (0x3F, Std3Valid, 0, 0),
+    (0x40, Std3Valid, 0, 0), (0x41, Mapped, 0, 1), (0x42, Mapped, 1, 1), (0x43, Mapped, 2, 1),
+    (0x44, Mapped, 3, 1), (0x45, Mapped, 4, 1), (0x46, Mapped, 5, 1), (0x47, Mapped, 6, 1),
+    (0x48, Mapped, 7, 1), (0x49, Mapped, 8, 1), (0x4A, Mapped, 9, 1), (0x4B, Mapped, 10, 1),
+    (0x4C, Mapped, 11, 1), (0x4D, Mapped, 12, 1), (0x4E, Mapped, 13, 1), (0x4F, Mapped, 14, 1),
+    (0x50, Mapped, 15, 1), (0x51, Mapped, 16, 1), (0x52, Mapped, 17, 1), (0x53, Mapped, 18, 1),
+    (0x54, Mapped, 19, 1), (0x55, Mapped, 20, 1), (0x56, Mapped, 21, 1), (0x57, Mapped, 22, 1),
+    (0x58, Mapped, 23, 1), (0x59, Mapped, 24, 1), (0x5A, Mapped, 25, 1), (0x5B, Std3Valid, 0, 0),
+    (0x5C, Std3Valid, 0, 0), (0x5D, Std3Valid, 0, 0), (0x5E, Std3Valid, 0, 0), (0x5F, Std3Valid, 0, 0),
+    (0x60, Std3Valid, 0, 0), (0x61, Valid, 0, 0), (0x62, Valid, 0, 0), (0x63, Valid, 0, 0),
+    (0x64, Valid, 0, 0), (0x65, Valid, 0, 0), (0x66, Valid, 0, 0), (0x67, Valid, 0, 0),
+    (0x68, Valid, 0, 0), (0x69, Valid, 0, 0), (0x6A, Valid, 0, 0), (0x6B, Valid, 0, 0),
+    (0x6C, Valid, 0, 0), (0x6D, Valid, 0, 0), (0x6E, Valid, 0, 0), (0x6F, Valid, 0, 0),
+    (0x70, Valid, 0, 0), (0x71, Valid, 0, 0), (0x72, Valid, 0, 0), (0x73, Valid, 0, 0),
+    (0x74, Valid, 0, 0), (0x75, Valid, 0, 0), (0x76, Valid, 0, 0), (0x77, Valid, 0, 0),
+    (0x78, Valid, 0, 0), (0x79, Valid, 0, 0), (0x7A, Valid, 0, 0), (0x7B, Std3Valid, 0, 0),
+    (0x7C, Std3Valid, 0, 0), (0x7D, Std3Valid, 0, 0), (0x7E, Std3Valid, 0, 0), (0x7F, Std3Valid, 0, 0),
+    (0x80, Disallowed, 0, 0), (0x81, Disallowed, 0, 0), (0x82, Disallowed, 0, 0), (0x83, Disallowed, 0, 0),
+    (0x84, Disallowed, 0, 0), (0x85, Disallowed, 0, 0), (0x86, Disallowed, 0, 0), (0x87, Disallowed, 0, 0),
+    (0x88, Disallowed, 0, 0), (0x89, Disallowed, 0, 0), (0x8A, Disallowed, 0, 0), (0x8B, Disallowed, 0, 0),
+    (0x8C, Disallowed, 0, 0), (0x8D, Disallowed, 0, 0), (0x8E, Disallowed, 0, 0), (0x8F, Disallowed, 0, 0),
+    (0x90, Disallowed, 0, 0), (0x91, Disallowed, 0, 0), (0x92, Disallowed, 0, 0), (0x93, Disallowed, 0, 0),
+    (0x94, Disallowed, 0, 0), (0x95, Disallowed, 0, 0), (0x96, Disallowed, 0, 0), (0x97, Disallowed, 0, 0),
+    (0x98, Disallowed, 0, 0), (0x99, Disallowed, 0, 0), (0x9A, Disallowed, 0, 0), (0x9B, Disallowed, 0, 0),
+    (0x9C, Disallowed, 0, 0), (0x9D, Disallowed, 0, 0), (0x9E, Disallowed, 0, 0), (0x9F, Disallowed, 0, 0),
+    (0xA0, Std3Mapped, 26, 1), (0xA1, Valid, 0, 0), (0xA2, Valid, 0, 0), (0xA3, Valid, 0, 0),
+    (0xA4, Valid, 0, 0), (0xA5, Valid, 0, 0), (0xA6, Valid, 0, 0), (0xA7, Valid, 0, 0),
+    (0xA8, Std3Mapped, 27, 3), (0xA9, Valid, 0, 0), (0xAA, Mapped, 0, 1), (0xAB, Valid, 0, 0),
+    (0xAC, Valid, 0, 0), (0xAD, Ignored, 0, 0), (0xAE, Valid, 0, 0), (0xAF, Std3Mapped, 30, 3),
+    (0xB0, Valid, 0, 0), (0xB1, Valid, 0, 0), (0xB2, Mapped, 33, 1), (0xB3, Mapped, 34, 1),
+    (0xB4, Std3Mapped, 35, 3), (0xB5, Mapped, 38, 2), (0xB6, Valid, 0, 0), (0xB7, Valid, 0, 0),
+    (0xB8, Std3Mapped, 40, 3), (0xB9, Mapped, 43, 1), (0xBA, Mapped, 14, 1), (0xBB, Valid, 0, 0),
+    (0xBC, Mapped, 44, 5), (0xBD, Mapped, 49, 5), (0xBE, Mapped, 54, 5), (0xBF, Valid, 0, 0),
+    (0xC0, Mapped, 59, 2), (0xC1, Mapped, 61, 2), (0xC2, Mapped, 63, 2), (0xC3, Mapped, 65, 2),
+    (0xC4, Mapped, 67, 2), (0xC5, Mapped, 69, 2), (0xC6, Mapped, 71, 2), (0xC7, Mapped, 73, 2),
+    (0xC8, Mapped, 75, 2), (0xC9, Mapped, 77, 2), (0xCA, Mapped, 79, 2), (0xCB, Mapped, 81, 2),
+    (0xCC, Mapped, 83, 2), (0xCD, Mapped, 85, 2), (0xCE, Mapped, 87, 2), (0xCF, Mapped, 89, 2),
+    (0xD0, Mapped, 91, 2), (0xD1, Mapped, 93, 2), (0xD2, Mapped, 95, 2), (0xD3, Mapped, 97, 2),
+    (0xD4, Mapped, 99, 2), (0xD5, Mapped, 101, 2), (0xD6, Mapped, 103, 2), (0xD7, Valid, 0, 0),
+    (0xD8, Mapped, 105, 2), (0xD9, Mapped, 107, 2), (0xDA, Mapped, 109, 2), (0xDB, Mapped, 111, 2),
+    (0xDC, Mapped, 113, 2), (0xDD, Mapped, 115, 2), (0xDE, Mapped, 117, 2), (0xDF, Deviation, 119, 2),
+    (0xE0, Valid, 0, 0), (0xE1, Valid, 0, 0), (0xE2, Valid, 0, 0), (0xE3, Valid, 0, 0),
+    (0xE4, Valid, 0, 0), (0xE5, Valid, 0, 0), (0xE6, Valid, 0, 0), (0xE7, Valid, 0, 0),
+    (0xE8, Valid, 0, 0), (0xE9, Valid, 0, 0), (0xEA, Valid, 0, 0), (0xEB, Valid, 0, 0),
+    (0xEC, Valid, 0, 0), (0xED, Valid, 0, 0), (0xEE, Valid, 0, 0), (0xEF, Valid, 0, 0),
+    (0xF0, Valid, 0, 0), (0xF1, Valid, 0, 0), (0xF2, Valid, 0, 0), (0xF3, Valid, 0, 0),
+    (0xF4, Valid, 0, 0), (0xF5, Valid, 0, 0), (0xF6, Valid, 0, 0), (0xF7, Valid, 0, 0),
+    (0xF8, Valid, 0, 0), (0xF9, Valid, 0, 0), (0xFA, Valid, 0, 0), (0xFB, Valid, 0, 0),
+    (0xFC, Valid, 0, 0), (0xFD, Valid, 0, 0), (0xFE, Valid, 0, 0), (0xFF, Valid, 0, 0),
+    (0x100, Mapped, 121, 2), (0x101, Valid, 0, 0), (0x102, Mapped, 123, 2), (0x103, Valid, 0, 0),
+    (0x104, Mapped, 125, 2), (0x105, Valid, 0, 0), (0x106, Mapped, 127, 2), (0x107, Valid, 0, 0),
+    (0x108, Mapped, 129, 2), (0x109, Valid, 0, 0), (0x10A, Mapped, 131, 2), (0x10B, Valid, 0, 0),
+    (0x10C, Mapped, 133, 2), (0x10D, Valid, 0, 0), (0x10E, Mapped, 135, 2), (0x10F, Valid, 0, 0),
+    (0x110, Mapped, 137, 2), (0x111, Valid, 0, 0), (0x112, Mapped, 139, 2), (0x113, Valid, 0, 0),
+    (0x114, Mapped, 141, 2), (0x115, Valid, 0, 0), (0x116, Mapped, 143, 2), (0x117, Valid, 0, 0),
+    (0x118, Mapped, 145, 2), (0x119, Valid, 0, 0), (0x11A, Mapped, 147, 2), (0x11B, Valid, 0, 0),
+    (0x11C, Mapped, 149, 2), (0x11D, Valid, 0, 0), (0x11E, Mapped, 151, 2), (0x11F, Valid, 0, 0),
+    (0x120, Mapped, 153, 2), (0x121, Valid, 0, 0), (0x122, Mapped, 155, 2), (0x123, Valid, 0, 0),
+    (0x124, Mapped, 157, 2), (0x125, Valid, 0, 0), (0x126, Mapped, 159, 2), (0x127, Valid, 0, 0),
+    (0x128, Mapped, 161, 2), (0x129, Valid, 0, 0), (0x12A, Mapped, 163, 2), (0x12B, Valid, 0, 0),
+    (0x12C, Mapped, 165, 2), (0x12D, Valid, 0, 0), (0x12E, Mapped, 167, 2), (0x12F, Valid, 0, 0),
+    (0x130, Mapped, 169, 3), (0x131, Valid, 0, 0), (0x132, Mapped, 172, 2), (0x134, Mapped, 174, 2),
+    (0x135, Valid, 0, 0), (0x136, Mapped, 176, 2), (0x137, Valid, 0, 0), (0x139, Mapped, 178, 2),
+    (0x13A, Valid, 0, 0), (0x13B, Mapped, 180, 2), (0x13C, Valid, 0, 0), (0x13D, Mapped, 182, 2),
+    (0x13E, Valid, 0, 0), (0x13F, Mapped, 184, 3), (0x141, Mapped, 187, 2), (0x142, Valid, 0, 0),
+    (0x143, Mapped, 189, 2), (0x144, Valid, 0, 0), (0x145, Mapped, 191, 2), (0x146, Valid, 0, 0),
+    (0x147, Mapped, 193, 2), (0x148, Valid, 0, 0), (0x149, Mapped, 195, 3), (0x14A, Mapped, 198, 2),
+    (0x14B, Valid, 0, 0), (0x14C, Mapped, 200, 2), (0x14D, Valid, 0, 0), (0x14E, Mapped, 202, 2),
+    (0x14F, Valid, 0, 0), (0x150, Mapped, 204, 2), (0x151, Valid, 0, 0), (0x152, Mapped, 206, 2),
+    (0x153, Valid, 0, 0), (0x154, Mapped, 208, 2), (0x155, Valid, 0, 0), (0x156, Mapped, 210, 2),
+    (0x157, Valid, 0, 0), (0x158, Mapped, 212, 2), (0x159, Valid, 0, 0), (0x15A, Mapped, 214, 2),
+    (0x15B, Valid, 0, 0), (0x15C, Mapped, 216, 2), (0x15D, Valid, 0, 0), (0x15E, Mapped, 218, 2),
+    (0x15F, Valid, 0, 0), (0x160, Mapped, 220, 2), (0x161, Valid, 0, 0), (0x162, Mapped, 222, 2),
+    (0x163, Valid, 0, 0), (0x164, Mapped, 224, 2), (0x165, Valid, 0, 0), (0x166, Mapped, 226, 2),
+    (0x167, Valid, 0, 0), (0x168, Mapped, 228, 2), (0x169, Valid, 0, 0), (0x16A, Mapped, 230, 2),
+    (0x16B, Valid, 0, 0), (0x16C, Mapped, 232, 2), (0x16D, Valid, 0, 0), (0x16E, Mapped, 234, 2),
+    (0x16F, Valid, 0, 0), (0x170, Mapped, 236, 2), (0x171, Valid, 0, 0), (0x172, Mapped, 238, 2),
+    (0x173, Valid, 0, 0), (0x174, Mapped, 240, 2), (0x175, Valid, 0, 0), (0x176, Mapped, 242, 2),
+    (0x177, Valid, 0, 0), (0x178, Mapped, 244, 2), (0x179, Mapped, 246, 2), (0x17A, Valid, 0, 0),
+    (0x17B, Mapped, 248, 2), (0x17C, Valid, 0, 0), (0x17D, Mapped, 250, 2), (0x17E, Valid, 0, 0),
+    (0x17F, Mapped, 18, 1), (0x180, Valid, 0, 0), (0x181, Mapped, 252, 2), (0x182, Mapped, 254, 2),
+    (0x183, Valid, 0, 0), (0x184, Mapped, 256, 2), (0x185, Valid, 0, 0), (0x186, Mapped, 258, 2),
+    (0x187, Mapped, 260, 2), (0x188, Valid, 0, 0), (0x189, Mapped, 262, 2), (0x18A, Mapped, 264, 2),
+    (0x18B, Mapped, 266, 2), (0x18C, Valid, 0, 0), (0x18E, Mapped, 268, 2), (0x18F, Mapped, 270, 2),
+    (0x190, Mapped, 272, 2), (0x191, Mapped, 274, 2), (0x192, Valid, 0, 0), (0x193, Mapped, 276, 2),
+    (0x194, Mapped, 278, 2), (0x195, Valid, 0, 0), (0x196, Mapped, 280, 2), (0x197, Mapped, 282, 2),
+    (0x198, Mapped, 284, 2), (0x199, Valid, 0, 0), (0x19C, Mapped, 286, 2), (0x19D, Mapped, 288, 2),
+    (0x19E, Valid, 0, 0), (0x19F, Mapped, 290, 2), (0x1A0, Mapped, 292, 2), (0x1A1, Valid, 0, 0),
+    (0x1A2, Mapped, 294, 2), (0x1A3, Valid, 0, 0), (0x1A4, Mapped, 296, 2), (0x1A5, Valid, 0, 0),
+    (0x1A6, Mapped, 298, 2), (0x1A7, Mapped, 300, 2), (0x1A8, Valid, 0, 0), (0x1A9, Mapped, 302, 2),
+    (0x1AA, Valid, 0, 0), (0x1AC, Mapped, 304, 2), (0x1AD, Valid, 0, 0), (0x1AE, Mapped, 306, 2),
+    (0x1AF, Mapped, 308, 2), (0x1B0, Valid, 0, 0), (0x1B1, Mapped, 310, 2), (0x1B2, Mapped, 312, 2),
+    (0x1B3, Mapped, 314, 2), (0x1B4, Valid, 0, 0), (0x1B5, Mapped, 316, 2), (0x1B6, Valid, 0, 0),
+    (0x1B7, Mapped, 318, 2), (0x1B8, Mapped, 320, 2), (0x1B9, Valid, 0, 0), (0x1BC, Mapped, 322, 2),
+    (0x1BD, Valid, 0, 0), (0x1C4, Mapped, 324, 3), (0x1C7, Mapped, 327, 2), (0x1CA, Mapped, 329, 2),
+    (0x1CD, Mapped, 331, 2), (0x1CE, Valid, 0, 0), (0x1CF, Mapped, 333, 2), (0x1D0, Valid, 0, 0),
+    (0x1D1, Mapped, 335, 2), (0x1D2, Valid, 0, 0), (0x1D3, Mapped, 337, 2), (0x1D4, Valid, 0, 0),
+    (0x1D5, Mapped, 339, 2), (0x1D6, Valid, 0, 0), (0x1D7, Mapped, 341, 2), (0x1D8, Valid, 0, 0),
+    (0x1D9, Mapped, 343, 2), (0x1DA, Valid, 0, 0), (0x1DB, Mapped, 345, 2), (0x1DC, Valid, 0, 0),
+    (0x1DE, Mapped, 347, 2), (0x1DF, Valid, 0, 0), (0x1E0, Mapped, 349, 2), (0x1E1, Valid, 0, 0),
+    (0x1E2, Mapped, 351, 2), (0x1E3, Valid, 0, 0), (0x1E4, Mapped, 353, 2), (0x1E5, Valid, 0, 0),
+    (0x1E6, Mapped, 355, 2), (0x1E7, Valid, 0, 0), (0x1E8, Mapped, 357, 2), (0x1E9, Valid, 0, 0),
+    (0x1EA, Mapped, 359, 2), (0x1EB, Valid, 0, 0), (0x1EC, Mapped, 361, 2), (0x1ED, Valid, 0, 0),
+    (0x1EE, Mapped, 363, 2), (0x1EF, Valid, 0, 0), (0x1F1, Mapped, 365, 2), (0x1F4, Mapped, 367, 2),
+    (0x1F5, Valid, 0, 0), (0x1F6, Mapped, 369, 2), (0x1F7, Mapped, 371, 2), (0x1F8, Mapped, 373, 2),
+    (0x1F9, Valid, 0, 0), (0x1FA, Mapped, 375, 2), (0x1FB, Valid, 0, 0), (0x1FC, Mapped, 377, 2),
+    (0x1FD, Valid, 0, 0), (0x1FE, Mapped, 379, 2), (0x1FF, Valid, 0, 0), (0x200, Mapped, 381, 2),
+    (0x201, Valid, 0, 0), (0x202, Mapped, 383, 2), (0x203, Valid, 0, 0), (0x204, Mapped, 385, 2),
+    (0x205, Valid, 0, 0), (0x206, Mapped, 387, 2), (0x207, Valid, 0, 0), (0x208, Mapped, 389, 2),
+    (0x209, Valid, 0, 0), (0x20A, Mapped, 391, 2), (0x20B, Valid, 0, 0), (0x20C, Mapped, 393, 2),
+    (0x20D, Valid, 0, 0), (0x20E, Mapped, 395, 2), (0x20F, Valid, 0, 0), (0x210, Mapped, 397, 2),
+    (0x211, Valid, 0, 0), (0x212, Mapped, 399, 2), (0x213, Valid, 0, 0), (0x214, Mapped, 401, 2),
+    (0x215, Valid, 0, 0), (0x216, Mapped, 403, 2), (0x217, Valid, 0, 0), (0x218, Mapped, 405, 2),
+    (0x219, Valid, 0, 0), (0x21A, Mapped, 407, 2), (0x21B, Valid, 0, 0), (0x21C, Mapped, 409, 2),
+    (0x21D, Valid, 0, 0), (0x21E, Mapped, 411, 2), (0x21F, Valid, 0, 0), (0x220, Mapped, 413, 2),
+    (0x221, Valid, 0, 0), (0x222, Mapped, 415, 2), (0x223, Valid, 0, 0), (0x224, Mapped, 417, 2),
+    (0x225, Valid, 0, 0), (0x226, Mapped, 419, 2), (0x227, Valid, 0, 0), (0x228, Mapped, 421, 2),
+    (0x229, Valid, 0, 0), (0x22A, Mapped, 423, 2), (0x22B, Valid, 0, 0), (0x22C, Mapped, 425, 2),
+    (0x22D, Valid, 0, 0), (0x22E, Mapped, 427, 2), (0x22F, Valid, 0, 0), (0x230, Mapped, 429, 2),
+    (0x231, Valid, 0, 0), (0x232, Mapped, 431, 2), (0x233, Valid, 0, 0), (0x23A, Mapped, 433, 3),
+    (0x23B, Mapped, 436, 2), (0x23C, Valid, 0, 0), (0x23D, Mapped, 438, 2), (0x23E, Mapped, 440, 3),
+    (0x23F, Valid, 0, 0), (0x241, Mapped, 443, 2), (0x242, Valid, 0, 0), (0x243, Mapped, 445, 2),
+    (0x244, Mapped, 447, 2), (0x245, Mapped, 449, 2), (0x246, Mapped, 451, 2), (0x247, Valid, 0, 0),
+    (0x248, Mapped, 453, 2), (0x249, Valid, 0, 0), (0x24A, Mapped, 455, 2), (0x24B, Valid, 0, 0),
+    (0x24C, Mapped, 457, 2), (0x24D, Valid, 0, 0), (0x24E, Mapped, 459, 2), (0x24F, Valid, 0, 0),
+    (0x2B0, Mapped, 7, 1), (0x2B1, Mapped, 461, 2), (0x2B2, Mapped, 9, 1), (0x2B3, Mapped, 17, 1),
+    (0x2B4, Mapped, 463, 2), (0x2B5, Mapped, 465, 2), (0x2B6, Mapped, 467, 2), (0x2B7, Mapped, 22, 1),
+    (0x2B8, Mapped, 24, 1), (0x2B9, Valid, 0, 0), (0x2D8, Std3Mapped, 469, 3), (0x2D9, Std3Mapped, 472, 3),
+    (0x2DA, Std3Mapped, 475, 3), (0x2DB, Std3Mapped, 478, 3), (0x2DC, Std3Mapped, 481, 3), (0x2DD, Std3Mapped, 484, 3),
+    (0x2DE, Valid, 0, 0), (0x2E0, Mapped, 278, 2), (0x2E1, Mapped, 11, 1), (0x2E2, Mapped, 18, 1),
+    (0x2E3, Mapped, 23, 1), (0x2E4, Mapped, 487, 2), (0x2E5, Valid, 0, 0), (0x340, Mapped, 489, 2),
+    (0x341, Mapped, 491, 2), (0x342, Valid, 0, 0), (0x343, Mapped, 493, 2), (0x344, Mapped, 495, 4),
+    (0x345, Mapped, 499, 2), (0x346, Valid, 0, 0), (0x34F, Ignored, 0, 0), (0x350, Valid, 0, 0),
+    (0x370, Mapped, 501, 2), (0x371, Valid, 0, 0), (0x372, Mapped, 503, 2), (0x373, Valid, 0, 0),
+    (0x374, Mapped, 505, 2), (0x375, Valid, 0, 0), (0x376, Mapped, 507, 2), (0x377, Valid, 0, 0),
+    (0x378, Disallowed, 0, 0), (0x37A, Std3Mapped, 509, 3), (0x37B, Valid, 0, 0), (0x37E, Std3Mapped, 512, 1),
+    (0x37F, Mapped, 513, 2), (0x380, Disallowed, 0, 0), (0x384, Std3Mapped, 35, 3), (0x385, Std3Mapped, 515, 5),
+    (0x386, Mapped, 520, 2), (0x387, Mapped, 522, 2), (0x388, Mapped, 524, 2), (0x389, Mapped, 526, 2),
+    (0x38A, Mapped, 528, 2), (0x38B, Disallowed, 0, 0), (0x38C, Mapped, 530, 2), (0x38D, Disallowed, 0, 0),
+    (0x38E, Mapped, 532, 2), (0x38F, Mapped, 534, 2), (0x390, Valid, 0, 0), (0x391, Mapped, 536, 2),
+    (0x392, Mapped, 538, 2), (0x393, Mapped, 540, 2), (0x394, Mapped, 542, 2), (0x395, Mapped, 544, 2),
+    (0x396, Mapped, 546, 2), (0x397, Mapped, 548, 2), (0x398, Mapped, 550, 2), (0x399, Mapped, 499, 2),
+    (0x39A, Mapped, 552, 2), (0x39B, Mapped, 554, 2), (0x39C, Mapped, 38, 2), (0x39D, Mapped, 556, 2),
+    (0x39E, Mapped, 558, 2), (0x39F, Mapped, 560, 2), (0x3A0, Mapped, 562, 2), (0x3A1, Mapped, 564, 2),
+    (0x3A2, Disallowed, 0, 0), (0x3A3, Mapped, 566, 2), (0x3A4, Mapped, 568, 2), (0x3A5, Mapped, 570, 2),
+    (0x3A6, Mapped, 572, 2), (0x3A7, Mapped, 574, 2), (0x3A8, Mapped, 576, 2), (0x3A9, Mapped, 578, 2),
+    (0x3AA, Mapped, 580, 2), (0x3AB, Mapped, 582, 2), (0x3AC, Valid, 0, 0), (0x3C2, Deviation, 566, 2),
+    (0x3C3, Valid, 0, 0), (0x3CF, Mapped, 584, 2), (0x3D0, Mapped, 538, 2), (0x3D1, Mapped, 550, 2),
+    (0x3D2, Mapped, 570, 2), (0x3D3, Mapped, 532, 2), (0x3D4, Mapped, 582, 2), (0x3D5, Mapped, 572, 2),
+    (0x3D6, Mapped, 562, 2), (0x3D7, Valid, 0, 0), (0x3D8, Mapped, 586, 2), (0x3D9, Valid, 0, 0),
+    (0x3DA, Mapped, 588, 2), (0x3DB, Valid, 0, 0), (0x3DC, Mapped, 590, 2), (0x3DD, Valid, 0, 0),
+    (0x3DE, Mapped, 592, 2), (0x3DF, Valid, 0, 0), (0x3E0, Mapped, 594, 2), (0x3E1, Valid, 0, 0),
+    (0x3E2, Mapped, 596, 2), (0x3E3, Valid, 0, 0), (0x3E4, Mapped, 598, 2), (0x3E5, Valid, 0, 0),
+    (0x3E6, Mapped, 600, 2), (0x3E7, Valid, 0, 0), (0x3E8, Mapped, 602, 2), (0x3E9, Valid, 0, 0),
+    (0x3EA, Mapped, 604, 2), (0x3EB, Valid, 0, 0), (0x3EC, Mapped, 606, 2), (0x3ED, Valid, 0, 0),
+    (0x3EE, Mapped, 608, 2), (0x3EF, Valid, 0, 0), (0x3F0, Mapped, 552, 2), (0x3F1, Mapped, 564, 2),
+    (0x3F2, Mapped, 566, 2), (0x3F3, Valid, 0, 0), (0x3F4, Mapped, 550, 2), (0x3F5, Mapped, 544, 2),
+    (0x3F6, Valid, 0, 0), (0x3F7, Mapped, 610, 2), (0x3F8, Valid, 0, 0), (0x3F9, Mapped, 566, 2),
+    (0x3FA, Mapped, 612, 2), (0x3FB, Valid, 0, 0), (0x3FD, Mapped, 614, 2), (0x3FE, Mapped, 616, 2),
+    (0x3FF, Mapped, 618, 2), (0x400, Mapped, 620, 2), (0x401, Mapped, 622, 2), (0x402, Mapped, 624, 2),
+    (0x403, Mapped, 626, 2), (0x404, Mapped, 628, 2), (0x405, Mapped, 630, 2), (0x406, Mapped, 632, 2),
+    (0x407, Mapped, 634, 2), (0x408, Mapped, 636, 2), (0x409, Mapped, 638, 2), (0x40A, Mapped, 640, 2),
+    (0x40B, Mapped, 642, 2), (0x40C, Mapped, 644, 2), (0x40D, Mapped, 646, 2), (0x40E, Mapped, 648, 2),
+    (0x40F, Mapped, 650, 2), (0x410, Mapped, 652, 2), (0x411, Mapped, 654, 2), (0x412, Mapped, 656, 2),
+    (0x413, Mapped, 658, 2), (0x414, Mapped, 660, 2), (0x415, Mapped, 662, 2), (0x416, Mapped, 664, 2),
+    (0x417, Mapped, 666, 2), (0x418, Mapped, 668, 2), (0x419, Mapped, 670, 2), (0x41A, Mapped, 672, 2),
+    (0x41B, Mapped, 674, 2), (0x41C, Mapped, 676, 2), (0x41D, Mapped, 678, 2), (0x41E, Mapped, 680, 2),
+    (0x41F, Mapped, 682, 2), (0x420, Mapped, 684, 2), (0x421, Mapped, 686, 2), (0x422, Mapped, 688, 2),
+    (0x423, Mapped, 690, 2), (0x424, Mapped, 692, 2), (0x425, Mapped, 694, 2), (0x426, Mapped, 696, 2),
+    (0x427, Mapped, 698, 2), (0x428, Mapped, 700, 2), (0x429, Mapped, 702, 2), (0x42A, Mapped, 704, 2),
+    (0x42B, Mapped, 706, 2), (0x42C, Mapped, 708, 2), (0x42D, Mapped, 710, 2), (0x42E, Mapped, 712, 2),
+    (0x42F, Mapped, 714, 2), (0x430, Valid, 0, 0), (0x460, Mapped, 716, 2), (0x461, Valid, 0, 0),
+    (0x462, Mapped, 718, 2), (0x463, Valid, 0, 0), (0x464, Mapped, 720, 2), (0x465, Valid, 0, 0),
+    (0x466, Mapped, 722, 2), (0x467, Valid, 0, 0), (0x468, Mapped, 724, 2), (0x469, Valid, 0, 0),
+    (0x46A, Mapped, 726, 2), (0x46B, Valid, 0, 0), (0x46C, Mapped, 728, 2), (0x46D, Valid, 0, 0),
+    (0x46E, Mapped, 730, 2), (0x46F, Valid, 0, 0), (0x470, Mapped, 732, 2), (0x471, Valid, 0, 0),
+    (0x472, Mapped, 734, 2), (0x473, Valid, 0, 0), (0x474, Mapped, 736, 2), (0x475, Valid, 0, 0),
+    (0x476, Mapped, 738, 2), (0x477, Valid, 0, 0), (0x478, Mapped, 740, 2), (0x479, Valid, 0, 0),
+    (0x47A, Mapped, 742, 2), (0x47B, Valid, 0, 0), (0x47C, Mapped, 744, 2), (0x47D, Valid, 0, 0),
+    (0x47E, Mapped, 746, 2), (0x47F, Valid, 0, 0), (0x480, Mapped, 748, 2), (0x481, Valid, 0, 0),
+    (0x48A, Mapped, 750, 2), (0x48B, Valid, 0, 0), (0x48C, Mapped, 752, 2), (0x48D, Valid, 0, 0),
+    (0x48E, Mapped, 754, 2), (0x48F, Valid, 0, 0), (0x490, Mapped, 756, 2), (0x491, Valid, 0, 0),
+    (0x492, Mapped, 758, 2), (0x493, Valid, 0, 0), (0x494, Mapped, 760, 2), (0x495, Valid, 0, 0),
+    (0x496, Mapped, 762, 2), (0x497, Valid, 0, 0), (0x498, Mapped, 764, 2), (0x499, Valid, 0, 0),
+    (0x49A, Mapped, 766, 2), (0x49B, Valid, 0, 0), (0x49C, Mapped, 768, 2), (0x49D, Valid, 0, 0),
+    (0x49E, Mapped, 770, 2), (0x49F, Valid, 0, 0), (0x4A0, Mapped, 772, 2), (0x4A1, Valid, 0, 0),
+    (0x4A2, Mapped, 774, 2), (0x4A3, Valid, 0, 0), (0x4A4, Mapped, 776, 2), (0x4A5, Valid, 0, 0),
+    (0x4A6, Mapped, 778, 2), (0x4A7, Valid, 0, 0), (0x4A8, Mapped, 780, 2), (0x4A9, Valid, 0, 0),
+    (0x4AA, Mapped, 782, 2), (0x4AB, Valid, 0, 0), (0x4AC, Mapped, 784, 2), (0x4AD, Valid, 0, 0),
+    (0x4AE, Mapped, 786, 2), (0x4AF, Valid, 0, 0), (0x4B0, Mapped, 788, 2), (0x4B1, Valid, 0, 0),
+    (0x4B2, Mapped, 790, 2), (0x4B3, Valid, 0, 0), (0x4B4, Mapped, 792, 2), (0x4B5, Valid, 0, 0),
+    (0x4B6, Mapped, 794, 2), (0x4B7, Valid, 0, 0), (0x4B8, Mapped, 796, 2), (0x4B9, Valid, 0, 0),
+    (0x4BA, Mapped, 798, 2), (0x4BB, Valid, 0, 0), (0x4BC, Mapped, 800, 2), (0x4BD, Valid, 0, 0),
+    (0x4BE, Mapped, 802, 2), (0x4BF, Valid, 0, 0), (0x4C0, Disallowed, 0, 0), (0x4C1, Mapped, 804, 2),
+    (0x4C2, Valid, 0, 0), (0x4C3, Mapped, 806, 2), (0x4C4, Valid, 0, 0), (0x4C5, Mapped, 808, 2),
+    (0x4C6, Valid, 0, 0), (0x4C7, Mapped, 810, 2), (0x4C8, Valid, 0, 0), (0x4C9, Mapped, 812, 2),
+    (0x4CA, Valid, 0, 0), (0x4CB, Mapped, 814, 2), (0x4CC, Valid, 0, 0), (0x4CD, Mapped, 816, 2),
+    (0x4CE, Valid, 0, 0), (0x4D0, Mapped, 818, 2), (0x4D1, Valid, 0, 0), (0x4D2, Mapped, 820, 2),
+    (0x4D3, Valid, 0, 0), (0x4D4, Mapped, 822, 2), (0x4D5, Valid, 0, 0), (0x4D6, Mapped, 824, 2),
+    (0x4D7, Valid, 0, 0), (0x4D8, Mapped, 826, 2), (0x4D9, Valid, 0, 0), (0x4DA, Mapped, 828, 2),
+    (0x4DB, Valid, 0, 0), (0x4DC, Mapped, 830, 2), (0x4DD, Valid, 0, 0), (0x4DE, Mapped, 832, 2),
+    (0x4DF, Valid, 0, 0), (0x4E0, Mapped, 834, 2), (0x4E1, Valid, 0, 0), (0x4E2, Mapped, 836, 2),
+    (0x4E3, Valid, 0, 0), (0x4E4, Mapped, 838, 2), (0x4E5, Valid, 0, 0), (0x4E6, Mapped, 840, 2),
+    (0x4E7, Valid, 0, 0), (0x4E8, Mapped, 842, 2), (0x4E9, Valid, 0, 0), (0x4EA, Mapped, 844, 2),
+    (0x4EB, Valid, 0, 0), (0x4EC, Mapped, 846, 2), (0x4ED, Valid, 0, 0), (0x4EE, Mapped, 848, 2),
+    (0x4EF, Valid, 0, 0), (0x4F0, Mapped, 850, 2), (0x4F1, Valid, 0, 0), (0x4F2, Mapped, 852, 2),
+    (0x4F3, Valid, 0, 0), (0x4F4, Mapped, 854, 2), (0x4F5, Valid, 0, 0), (0x4F6, Mapped, 856, 2),
+    (0x4F7, Valid, 0, 0), (0x4F8, Mapped, 858, 2), (0x4F9, Valid, 0, 0), (0x4FA, Mapped, 860, 2),
+    (0x4FB, Valid, 0, 0), (0x4FC, Mapped, 862, 2), (0x4FD, Valid, 0, 0), (0x4FE, Mapped, 864, 2),
+    (0x4FF, Valid, 0, 0), (0x500, Mapped, 866, 2), (0x501, Valid, 0, 0), (0x502, Mapped, 868, 2),
+    (0x503, Valid, 0, 0), (0x504, Mapped, 870, 2), (0x505, Valid, 0, 0), (0x506, Mapped, 872, 2),
+    (0x507, Valid, 0, 0), (0x508, Mapped, 874, 2), (0x509, Valid, 0, 0), (0x50A, Mapped, 876, 2),
+    (0x50B, Valid, 0, 0), (0x50C, Mapped, 878, 2), (0x50D, Valid, 0, 0), (0x50E, Mapped, 880, 2),
+    (0x50F, Valid, 0, 0), (0x510, Mapped, 882, 2), (0x511, Valid, 0, 0), (0x512, Mapped, 884, 2),
+    (0x513, Valid, 0, 0), (0x514, Mapped, 886, 2), (0x515, Valid, 0, 0), (0x516, Mapped, 888, 2),
+    (0x517, Valid, 0, 0), (0x518, Mapped, 890, 2), (0x519, Valid, 0, 0), (0x51A, Mapped, 892, 2),
+    (0x51B, Valid, 0, 0), (0x51C, Mapped, 894, 2), (0x51D, Valid, 0, 0), (0x51E, Mapped, 896, 2),
+    (0x51F, Valid, 0, 0), (0x520, Mapped, 898, 2), (0x521, Valid, 0, 0), (0x522, Mapped, 900, 2),
+    (0x523, Valid, 0, 0), (0x524, Mapped, 902, 2), (0x525, Valid, 0, 0), (0x526, Mapped, 904, 2),
+    (0x527, Valid, 0, 0), (0x528, Mapped, 906, 2), (0x529, Valid, 0, 0), (0x52A, Mapped, 908, 2),
+    (0x52B, Valid, 0, 0), (0x52C, Mapped, 910, 2), (0x52D, Valid, 0, 0), (0x52E, Mapped, 912, 2),
+    (0x52F, Valid, 0, 0), (0x530, Disallowed, 0, 0), (0x531, Mapped, 914, 2), (0x532, Mapped, 916, 2),
+    (0x533, Mapped, 918, 2), (0x534, Mapped, 920, 2), (0x535, Mapped, 922, 2), (0x536, Mapped, 924, 2),
+    (0x537, Mapped, 926, 2), (0x538, Mapped, 928, 2), (0x539, Mapped, 930, 2), (0x53A, Mapped, 932, 2),
+    (0x53B, Mapped, 934, 2), (0x53C, Mapped, 936, 2), (0x53D, Mapped, 938, 2), (0x53E, Mapped, 940, 2),
+    (0x53F, Mapped, 942, 2), (0x540, Mapped, 944, 2), (0x541, Mapped, 946, 2), (0x542, Mapped, 948, 2),
+    (0x543, Mapped, 950, 2), (0x544, Mapped, 952, 2), (0x545, Mapped, 954, 2), (0x546, Mapped, 956, 2),
+    (0x547, Mapped, 958, 2), (0x548, Mapped, 960, 2), (0x549, Mapped, 962, 2), (0x54A, Mapped, 964, 2),
+    (0x54B, Mapped, 966, 2), (0x54C, Mapped, 968, 2), (0x54D, Mapped, 970, 2), (0x54E, Mapped, 972, 2),
+    (0x54F, Mapped, 974, 2), (0x550, Mapped, 976, 2), (0x551, Mapped, 978, 2), (0x552, Mapped, 980, 2),
+    (0x553, Mapped, 982, 2), (0x554, Mapped, 984, 2), (0x555, Mapped, 986, 2), (0x556, Mapped, 988, 2),
+    (0x557, Disallowed, 0, 0), (0x559, Valid, 0, 0), (0x587, Mapped, 990, 4), (0x588, Valid, 0, 0),
+    (0x58B, Disallowed, 0, 0), (0x58D, Valid, 0, 0), (0x590, Disallowed, 0, 0), (0x591, Valid, 0, 0),
+    (0x5C8, Disallowed, 0, 0), (0x5D0, Valid, 0, 0), (0x5EB, Disallowed, 0, 0), (0x5EF, Valid, 0, 0),
+    (0x5F5, Disallowed, 0, 0), (0x606, Valid, 0, 0), (0x61C, Disallowed, 0, 0), (0x61D, Valid, 0, 0),
+    (0x675, Mapped, 994, 4), (0x676, Mapped, 998, 4), (0x677, Mapped, 1002, 4), (0x678, Mapped, 1006, 4),
+    (0x679, Valid, 0, 0), (0x6DD, Disallowed, 0, 0), (0x6DE, Valid, 0, 0), (0x70E, Disallowed, 0, 0),
+    (0x710, Valid, 0, 0), (0x74B, Disallowed, 0, 0), (0x74D, Valid, 0, 0), (0x7B2, Disallowed, 0, 0),
+    (0x7C0, Valid, 0, 0), (0x7FB, Disallowed, 0, 0), (0x7FD, Valid, 0, 0), (0x82E, Disallowed, 0, 0),
+    (0x830, Valid, 0, 0), (0x83F, Disallowed, 0, 0), (0x840, Valid, 0, 0), (0x85C, Disallowed, 0, 0),
+    (0x85E, Valid, 0, 0), (0x85F, Disallowed, 0, 0), (0x860, Valid, 0, 0), (0x86B, Disallowed, 0, 0),
+    (0x870, Valid, 0, 0), (0x88F, Disallowed, 0, 0), (0x898, Valid, 0, 0), (0x8E2, Disallowed, 0, 0),
+    (0x8E3, Valid, 0, 0), (0x958, Mapped, 1010, 6), (0x959, Mapped, 1016, 6), (0x95A, Mapped, 1022, 6),
+    (0x95B, Mapped, 1028, 6), (0x95C, Mapped, 1034, 6), (0x95D, Mapped, 1040, 6), (0x95E, Mapped, 1046, 6),
+    (0x95F, Mapped, 1052, 6), (0x960, Valid, 0, 0), (0x984, Disallowed, 0, 0), (0x985, Valid, 0, 0),
+    (0x98D, Disallowed, 0, 0), (0x98F, Valid, 0, 0), (0x991, Disallowed, 0, 0), (0x993, Valid, 0, 0),
+    (0x9A9, Disallowed, 0, 0), (0x9AA, Valid, 0, 0), (0x9B1, Disallowed, 0, 0), (0x9B2, Valid, 0, 0),
+    (0x9B3, Disallowed, 0, 0), (0x9B6, Valid, 0, 0), (0x9BA, Disallowed, 0, 0), (0x9BC, Valid, 0, 0),
+    (0x9C5, Disallowed, 0, 0), (0x9C7, Valid, 0, 0), (0x9C9, Disallowed, 0, 0), (0x9CB, Valid, 0, 0),
+    (0x9CF, Disallowed, 0, 0), (0x9D7, Valid, 0, 0), (0x9D8, Disallowed, 0, 0), (0x9DC, Mapped, 1058, 6),
+    (0x9DD, Mapped, 1064, 6), (0x9DE, Disallowed, 0, 0), (0x9DF, Mapped, 1070, 6), (0x9E0, Valid, 0, 0),
+    (0x9E4, Disallowed, 0, 0), (0x9E6, Valid, 0, 0), (0x9FF, Disallowed, 0, 0), (0xA01, Valid, 0, 0),
+    (0xA04, Disallowed, 0, 0), (0xA05, Valid, 0, 0), (0xA0B, Disallowed, 0, 0), (0xA0F, Valid, 0, 0),
+    (0xA11, Disallowed, 0, 0), (0xA13, Valid, 0, 0), (0xA29, Disallowed, 0, 0), (0xA2A, Valid, 0, 0),
+    (0xA31, Disallowed, 0, 0), (0xA32, Valid, 0, 0), (0xA33, Mapped, 1076, 6), (0xA34, Disallowed, 0, 0),
+    (0xA35, Valid, 0, 0), (0xA36, Mapped, 1082, 6), (0xA37, Disallowed, 0, 0), (0xA38, Valid, 0, 0),
+    (0xA3A, Disallowed, 0, 0), (0xA3C, Valid, 0, 0), (0xA3D, Disallowed, 0, 0), (0xA3E, Valid, 0, 0),
+    (0xA43, Disallowed, 0, 0), (0xA47, Valid, 0, 0), (0xA49, Disallowed, 0, 0), (0xA4B, Valid, 0, 0),
+    (0xA4E, Disallowed, 0, 0), (0xA51, Valid, 0, 0), (0xA52, Disallowed, 0, 0), (0xA59, Mapped, 1088, 6),
+    (0xA5A, Mapped, 1094, 6), (0xA5B, Mapped, 1100, 6), (0xA5C, Valid, 0, 0), (0xA5D, Disallowed, 0, 0),
+    (0xA5E, Mapped, 1106, 6), (0xA5F, Disallowed, 0, 0), (0xA66, Valid, 0, 0), (0xA77, Disallowed, 0, 0),
+    (0xA81, Valid, 0, 0), (0xA84, Disallowed, 0, 0), (0xA85, Valid, 0, 0), (0xA8E, Disallowed, 0, 0),
+    (0xA8F, Valid, 0, 0), (0xA92, Disallowed, 0, 0), (0xA93, Valid, 0, 0), (0xAA9, Disallowed, 0, 0),
+    (0xAAA, Valid, 0, 0), (0xAB1, Disallowed, 0, 0), (0xAB2, Valid, 0, 0), (0xAB4, Disallowed, 0, 0),
+    (0xAB5, Valid, 0, 0), (0xABA, Disallowed, 0, 0), (0xABC, Valid, 0, 0), (0xAC6, Disallowed, 0, 0),
+    (0xAC7, Valid, 0, 0), (0xACA, Disallowed, 0, 0), (0xACB, Valid, 0, 0), (0xACE, Disallowed, 0, 0),
+    (0xAD0, Valid, 0, 0), (0xAD1, Disallowed, 0, 0), (0xAE0, Valid, 0, 0), (0xAE4, Disallowed, 0, 0),
+    (0xAE6, Valid, 0, 0), (0xAF2, Disallowed, 0, 0), (0xAF9, Valid, 0, 0), (0xB00, Disallowed, 0, 0),
+    (0xB01, Valid, 0, 0), (0xB04, Disallowed, 0, 0), (0xB05, Valid, 0, 0), (0xB0D, Disallowed, 0, 0),
+    (0xB0F, Valid, 0, 0), (0xB11, Disallowed, 0, 0), (0xB13, Valid, 0, 0), (0xB29, Disallowed, 0, 0),
+    (0xB2A, Valid, 0, 0), (0xB31, Disallowed, 0, 0), (0xB32, Valid, 0, 0), (0xB34, Disallowed, 0, 0),
+    (0xB35, Valid, 0, 0), (0xB3A, Disallowed, 0, 0), (0xB3C, Valid, 0, 0), (0xB45, Disallowed, 0, 0),
+    (0xB47, Valid, 0, 0), (0xB49, Disallowed, 0, 0), (0xB4B, Valid, 0, 0), (0xB4E, Disallowed, 0, 0),
+    (0xB55, Valid, 0, 0), (0xB58, Disallowed, 0, 0), (0xB5C, Mapped, 1112, 6), (0xB5D, Mapped, 1118, 6),
+    (0xB5E, Disallowed, 0, 0), (0xB5F, Valid, 0, 0), (0xB64, Disallowed, 0, 0), (0xB66, Valid, 0, 0),
+    (0xB78, Disallowed, 0, 0), (0xB82, Valid, 0, 0), (0xB84, Disallowed, 0, 0), (0xB85, Valid, 0, 0),
+    (0xB8B, Disallowed, 0, 0), (0xB8E, Valid, 0, 0), (0xB91, Disallowed, 0, 0), (0xB92, Valid, 0, 0),
+    (0xB96, Disallowed, 0, 0), (0xB99, Valid, 0, 0), (0xB9B, Disallowed, 0, 0), (0xB9C, Valid, 0, 0),
+    (0xB9D, Disallowed, 0, 0), (0xB9E, Valid, 0, 0), (0xBA0, Disallowed, 0, 0), (0xBA3, Valid, 0, 0),
+    (0xBA5, Disallowed, 0, 0), (0xBA8, Valid, 0, 0), (0xBAB, Disallowed, 0, 0), (0xBAE, Valid, 0, 0),
+    (0xBBA, Disallowed, 0, 0), (0xBBE, Valid, 0, 0), (0xBC3, Disallowed, 0, 0), (0xBC6, Valid, 0, 0),
+    (0xBC9, Disallowed, 0, 0), (0xBCA, Valid, 0, 0), (0xBCE, Disallowed, 0, 0), (0xBD0, Valid, 0, 0),
+    (0xBD1, Disallowed, 0, 0), (0xBD7, Valid, 0, 0), (0xBD8, Disallowed, 0, 0), (0xBE6, Valid, 0, 0),
+    (0xBFB, Disallowed, 0, 0), (0xC00, Valid, 0, 0), (0xC0D, Disallowed, 0, 0), (0xC0E, Valid, 0, 0),
+    (0xC11, Disallowed, 0, 0), (0xC12, Valid, 0, 0), (0xC29, Disallowed, 0, 0), (0xC2A, Valid, 0, 0),
+    (0xC3A, Disallowed, 0, 0), (0xC3C, Valid, 0, 0), (0xC45, Disallowed, 0, 0), (0xC46, Valid, 0, 0),
+    (0xC49, Disallowed, 0, 0), (0xC4A, Valid, 0, 0), (0xC4E, Disallowed, 0, 0), (0xC55, Valid, 0, 0),
+    (0xC57, Disallowed, 0, 0), (0xC58, Valid, 0, 0), (0xC5B, Disallowed, 0, 0), (0xC5D, Valid, 0, 0),
+    (0xC5E, Disallowed, 0, 0), (0xC60, Valid, 0, 0), (0xC64, Disallowed, 0, 0), (0xC66, Valid, 0, 0),
+    (0xC70, Disallowed, 0, 0), (0xC77, Valid, 0, 0), (0xC8D, Disallowed, 0, 0), (0xC8E, Valid, 0, 0),
+    (0xC91, Disallowed, 0, 0), (0xC92, Valid, 0, 0), (0xCA9, Disallowed, 0, 0), (0xCAA, Valid, 0, 0),
+    (0xCB4, Disallowed, 0, 0), (0xCB5, Valid, 0, 0), (0xCBA, Disallowed, 0, 0), (0xCBC, Valid, 0, 0),
+    (0xCC5, Disallowed, 0, 0), (0xCC6, Valid, 0, 0), (0xCC9, Disallowed, 0, 0), (0xCCA, Valid, 0, 0),
+    (0xCCE, Disallowed, 0, 0), (0xCD5, Valid, 0, 0), (0xCD7, Disallowed, 0, 0), (0xCDD, Valid, 0, 0),
+    (0xCDF, Disallowed, 0, 0), (0xCE0, Valid, 0, 0), (0xCE4, Disallowed, 0, 0), (0xCE6, Valid, 0, 0),
+    (0xCF0, Disallowed, 0, 0), (0xCF1, Valid, 0, 0), (0xCF4, Disallowed, 0, 0), (0xD00, Valid, 0, 0),
+    (0xD0D, Disallowed, 0, 0), (0xD0E, Valid, 0, 0), (0xD11, Disallowed, 0, 0), (0xD12, Valid, 0, 0),
+    (0xD45, Disallowed, 0, 0), (0xD46, Valid, 0, 0), (0xD49, Disallowed, 0, 0), (0xD4A, Valid, 0, 0),
+    (0xD50, Disallowed, 0, 0), (0xD54, Valid, 0, 0), (0xD64, Disallowed, 0, 0), (0xD66, Valid, 0, 0),
+    (0xD80, Disallowed, 0, 0), (0xD81, Valid, 0, 0), (0xD84, Disallowed, 0, 0), (0xD85, Valid, 0, 0),
+    (0xD97, Disallowed, 0, 0), (0xD9A, Valid, 0, 0), (0xDB2, Disallowed, 0, 0), (0xDB3, Valid, 0, 0),
+    (0xDBC, Disallowed, 0, 0), (0xDBD, Valid, 0, 0), (0xDBE, Disallowed, 0, 0), (0xDC0, Valid, 0, 0),
+    (0xDC7, Disallowed, 0, 0), (0xDCA, Valid, 0, 0), (0xDCB, Disallowed, 0, 0), (0xDCF, Valid, 0, 0),
+    (0xDD5, Disallowed, 0, 0), (0xDD6, Valid, 0, 0), (0xDD7, Disallowed, 0, 0), (0xDD8, Valid, 0, 0),
+    (0xDE0, Disallowed, 0, 0), (0xDE6, Valid, 0, 0), (0xDF0, Disallowed, 0, 0), (0xDF2, Valid, 0, 0),
+    (0xDF5, Disallowed, 0, 0), (0xE01, Valid, 0, 0), (0xE33, Mapped, 1124, 6), (0xE34, Valid, 0, 0),
+    (0xE3B, Disallowed, 0, 0), (0xE3F, Valid, 0, 0), (0xE5C, Disallowed, 0, 0), (0xE81, Valid, 0, 0),
+    (0xE83, Disallowed, 0, 0), (0xE84, Valid, 0, 0), (0xE85, Disallowed, 0, 0), (0xE86, Valid, 0, 0),
+    (0xE8B, Disallowed, 0, 0), (0xE8C, Valid, 0, 0), (0xEA4, Disallowed, 0, 0), (0xEA5, Valid, 0, 0),
+    (0xEA6, Disallowed, 0, 0), (0xEA7, Valid, 0, 0), (0xEB3, Mapped, 1130, 6), (0xEB4, Valid, 0, 0),
+    (0xEBE, Disallowed, 0, 0), (0xEC0, Valid, 0, 0), (0xEC5, Disallowed, 0, 0), (0xEC6, Valid, 0, 0),
+    (0xEC7, Disallowed, 0, 0), (0xEC8, Valid, 0, 0), (0xECF, Disallowed, 0, 0), (0xED0, Valid, 0, 0),
+    (0xEDA, Disallowed, 0, 0), (0xEDC, Mapped, 1136, 6), (0xEDD, Mapped, 1142, 6), (0xEDE, Valid, 0, 0),
+    (0xEE0, Disallowed, 0, 0), (0xF00, Valid, 0, 0), (0xF0C, Mapped, 1148, 3), (0xF0D, Valid, 0, 0),
+    (0xF43, Mapped, 1151, 6), (0xF44, Valid, 0, 0), (0xF48, Disallowed, 0, 0), (0xF49, Valid, 0, 0),
+    (0xF4D, Mapped, 1157, 6), (0xF4E, Valid, 0, 0), (0xF52, Mapped, 1163, 6), (0xF53, Valid, 0, 0),
+    (0xF57, Mapped, 1169, 6), (0xF58, Valid, 0, 0), (0xF5C, Mapped, 1175, 6), (0xF5D, Valid, 0, 0),
+    (0xF69, Mapped, 1181, 6), (0xF6A, Valid, 0, 0), (0xF6D, Disallowed, 0, 0), (0xF71, Valid, 0, 0),
+    (0xF73, Mapped, 1187, 6), (0xF74, Valid, 0, 0), (0xF75, Mapped, 1193, 6), (0xF76, Mapped, 1199, 6),
+    (0xF77, Mapped, 1205, 9), (0xF78, Mapped, 1214, 6), (0xF79, Mapped, 1220, 9), (0xF7A, Valid, 0, 0),
+    (0xF81, Mapped, 1229, 6), (0xF82, Valid, 0, 0), (0xF93, Mapped, 1235, 6), (0xF94, Valid, 0, 0),
+    (0xF98, Disallowed, 0, 0), (0xF99, Valid, 0, 0), (0xF9D, Mapped, 1241, 6), (0xF9E, Valid, 0, 0),
+    (0xFA2, Mapped, 1247, 6), (0xFA3, Valid, 0, 0), (0xFA7, Mapped, 1253, 6), (0xFA8, Valid, 0, 0),
+    (0xFAC, Mapped, 1259, 6), (0xFAD, Valid, 0, 0), (0xFB9, Mapped, 1265, 6), (0xFBA, Valid, 0, 0),
+    (0xFBD, Disallowed, 0, 0), (0xFBE, Valid, 0, 0), (0xFCD, Disallowed, 0, 0), (0xFCE, Valid, 0, 0),
+    (0xFDB, Disallowed, 0, 0), (0x1000, Valid, 0, 0), (0x10A0, Disallowed, 0, 0), (0x10C7, Mapped, 1271, 3),
+    (0x10C8, Disallowed, 0, 0), (0x10CD, Mapped, 1274, 3), (0x10CE, Disallowed, 0, 0), (0x10D0, Valid, 0, 0),
+    (0x10FC, Mapped, 1277, 3), (0x10FD, Valid, 0, 0), (0x115F, Disallowed, 0, 0), (0x1161, Valid, 0, 0),
+    (0x1249, Disallowed, 0, 0), (0x124A, Valid, 0, 0), (0x124E, Disallowed, 0, 0), (0x1250, Valid, 0, 0),
+    (0x1257, Disallowed, 0, 0), (0x1258, Valid, 0, 0), (0x1259, Disallowed, 0, 0), (0x125A, Valid, 0, 0),
+    (0x125E, Disallowed, 0, 0), (0x1260, Valid, 0, 0), (0x1289, Disallowed, 0, 0), (0x128A, Valid, 0, 0),
+    (0x128E, Disallowed, 0, 0), (0x1290, Valid, 0, 0), (0x12B1, Disallowed, 0, 0), (0x12B2, Valid, 0, 0),
+    (0x12B6, Disallowed, 0, 0), (0x12B8, Valid, 0, 0), (0x12BF, Disallowed, 0, 0), (0x12C0, Valid, 0, 0),
+    (0x12C1, Disallowed, 0, 0), (0x12C2, Valid, 0, 0), (0x12C6, Disallowed, 0, 0), (0x12C8, Valid, 0, 0),
+    (0x12D7, Disallowed, 0, 0), (0x12D8, Valid, 0, 0), (0x1311, Disallowed, 0, 0), (0x1312, Valid, 0, 0),
+    (0x1316, Disallowed, 0, 0), (0x1318, Valid, 0, 0), (0x135B, Disallowed, 0, 0), (0x135D, Valid, 0, 0),
+    (0x137D, Disallowed, 0, 0), (0x1380, Valid, 0, 0), (0x139A, Disallowed, 0, 0), (0x13A0, Valid, 0, 0),
+    (0x13F6, Disallowed, 0, 0), (0x13F8, Mapped, 1280, 3), (0x13F9, Mapped, 1283, 3), (0x13FA, Mapped, 1286, 3),
+    (0x13FB, Mapped, 1289, 3), (0x13FC, Mapped, 1292, 3), (0x13FD, Mapped, 1295, 3), (0x13FE, Disallowed, 0, 0),
+    (0x1400, Valid, 0, 0), (0x1680, Disallowed, 0, 0), (0x1681, Valid, 0, 0), (0x169D, Disallowed, 0, 0),
+    (0x16A0, Valid, 0, 0), (0x16F9, Disallowed, 0, 0), (0x1700, Valid, 0, 0), (0x1716, Disallowed, 0, 0),
+    (0x171F, Valid, 0, 0), (0x1737, Disallowed, 0, 0), (0x1740, Valid, 0, 0), (0x1754, Disallowed, 0, 0),
+    (0x1760, Valid, 0, 0), (0x176D, Disallowed, 0, 0), (0x176E, Valid, 0, 0), (0x1771, Disallowed, 0, 0),
+    (0x1772, Valid, 0, 0), (0x1774, Disallowed, 0, 0), (0x1780, Valid, 0, 0), (0x17B4, Disallowed, 0, 0),
+    (0x17B6, Valid, 0, 0), (0x17DE, Disallowed, 0, 0), (0x17E0, Valid, 0, 0), (0x17EA, Disallowed, 0, 0),
+    (0x17F0, Valid, 0, 0), (0x17FA, Disallowed, 0, 0), (0x1800, Valid, 0, 0), (0x1806, Disallowed, 0, 0),
+    (0x1807, Valid, 0, 0), (0x180B, Ignored, 0, 0), (0x180E, Disallowed, 0, 0), (0x180F, Ignored, 0, 0),
+    (0x1810, Valid, 0, 0), (0x181A, Disallowed, 0, 0), (0x1820, Valid, 0, 0), (0x1879, Disallowed, 0, 0),
+    (0x1880, Valid, 0, 0), (0x18AB, Disallowed, 0, 0), (0x18B0, Valid, 0, 0), (0x18F6, Disallowed, 0, 0),
+    (0x1900, Valid, 0, 0), (0x191F, Disallowed, 0, 0), (0x1920, Valid, 0, 0), (0x192C, Disallowed, 0, 0),
+    (0x1930, Valid, 0, 0), (0x193C, Disallowed, 0, 0), (0x1940, Valid, 0, 0), (0x1941, Disallowed, 0, 0),
+    (0x1944, Valid, 0, 0), (0x196E, Disallowed, 0, 0), (0x1970, Valid, 0, 0), (0x1975, Disallowed, 0, 0),
+    (0x1980, Valid, 0, 0), (0x19AC, Disallowed, 0, 0), (0x19B0, Valid, 0, 0), (0x19CA, Disallowed, 0, 0),
+    (0x19D0, Valid, 0, 0), (0x19DB, Disallowed, 0, 0), (0x19DE, Valid, 0, 0), (0x1A1C, Disallowed, 0, 0),
+    (0x1A1E, Valid, 0, 0), (0x1A5F, Disallowed, 0, 0), (0x1A60, Valid, 0, 0), (0x1A7D, Disallowed, 0, 0),
+    (0x1A7F, Valid, 0, 0), (0x1A8A, Disallowed, 0, 0), (0x1A90, Valid, 0, 0), (0x1A9A, Disallowed, 0, 0),
+    (0x1AA0, Valid, 0, 0), (0x1AAE, Disallowed, 0, 0), (0x1AB0, Valid, 0, 0), (0x1ACF, Disallowed, 0, 0),
+    (0x1B00, Valid, 0, 0), (0x1B4D, Disallowed, 0, 0), (0x1B50, Valid, 0, 0), (0x1B7F, Disallowed, 0, 0),
+    (0x1B80, Valid, 0, 0), (0x1BF4, Disallowed, 0, 0), (0x1BFC, Valid, 0, 0), (0x1C38, Disallowed, 0, 0),
+    (0x1C3B, Valid, 0, 0), (0x1C4A, Disallowed, 0, 0), (0x1C4D, Valid, 0, 0), (0x1C80, Mapped, 656, 2),
+    (0x1C81, Mapped, 660, 2), (0x1C82, Mapped, 680, 2), (0x1C83, Mapped, 686, 2), (0x1C84, Mapped, 688, 2),
+    (0x1C86, Mapped, 704, 2), (0x1C87, Mapped, 718, 2), (0x1C88, Mapped, 1298, 3), (0x1C89, Disallowed, 0, 0),
+    (0x1C90, Mapped, 1301, 3), (0x1C91, Mapped, 1304, 3), (0x1C92, Mapped, 1307, 3), (0x1C93, Mapped, 1310, 3),
+    (0x1C94, Mapped, 1313, 3), (0x1C95, Mapped, 1316, 3), (0x1C96, Mapped, 1319, 3), (0x1C97, Mapped, 1322, 3),
+    (0x1C98, Mapped, 1325, 3), (0x1C99, Mapped, 1328, 3), (0x1C9A, Mapped, 1331, 3), (0x1C9B, Mapped, 1334, 3),
+    (0x1C9C, Mapped, 1277, 3), (0x1C9D, Mapped, 1337, 3), (0x1C9E, Mapped, 1340, 3), (0x1C9F, Mapped, 1343, 3),
+    (0x1CA0, Mapped, 1346, 3), (0x1CA1, Mapped, 1349, 3), (0x1CA2, Mapped, 1352, 3), (0x1CA3, Mapped, 1355, 3),
+    (0x1CA4, Mapped, 1358, 3), (0x1CA5, Mapped, 1361, 3), (0x1CA6, Mapped, 1364, 3), (0x1CA7, Mapped, 1367, 3),
+    (0x1CA8, Mapped, 1370, 3), (0x1CA9, Mapped, 1373, 3), (0x1CAA, Mapped, 1376, 3), (0x1CAB, Mapped, 1379, 3),
+    (0x1CAC, Mapped, 1382, 3), (0x1CAD, Mapped, 1385, 3), (0x1CAE, Mapped, 1388, 3), (0x1CAF, Mapped, 1391, 3),
+    (0x1CB0, Mapped, 1394, 3), (0x1CB1, Mapped, 1397, 3), (0x1CB2, Mapped, 1400, 3), (0x1CB3, Mapped, 1403, 3),
+    (0x1CB4, Mapped, 1406, 3), (0x1CB5, Mapped, 1409, 3), (0x1CB6, Mapped, 1412, 3), (0x1CB7, Mapped, 1415, 3),
+    (0x1CB8, Mapped, 1418, 3), (0x1CB9, Mapped, 1421, 3), (0x1CBA, Mapped, 1424, 3), (0x1CBB, Disallowed, 0, 0),
+    (0x1CBD, Mapped, 1427, 3), (0x1CBE, Mapped, 1430, 3), (0x1CBF, Mapped, 1433, 3), (0x1CC0, Valid, 0, 0),
+    (0x1CC8, Disallowed, 0, 0), (0x1CD0, Valid, 0, 0), (0x1CFB, Disallowed, 0, 0), (0x1D00, Valid, 0, 0),
+    (0x1D2C, Mapped, 0, 1), (0x1D2D, Mapped, 71, 2), (0x1D2E, Mapped, 1, 1), (0x1D2F, Valid, 0, 0),
+    (0x1D30, Mapped, 3, 1), (0x1D31, Mapped, 4, 1), (0x1D32, Mapped, 268, 2), (0x1D33, Mapped, 6, 1),
+    (0x1D34, Mapped, 7, 1), (0x1D35, Mapped, 8, 1), (0x1D36, Mapped, 9, 1), (0x1D37, Mapped, 10, 1),
+    (0x1D38, Mapped, 11, 1), (0x1D39, Mapped, 12, 1), (0x1D3A, Mapped, 13, 1), (0x1D3B, Valid, 0, 0),
+    (0x1D3C, Mapped, 14, 1), (0x1D3D, Mapped, 415, 2), (0x1D3E, Mapped, 15, 1), (0x1D3F, Mapped, 17, 1),
+    (0x1D40, Mapped, 19, 1), (0x1D41, Mapped, 20, 1), (0x1D42, Mapped, 22, 1), (0x1D43, Mapped, 0, 1),
+    (0x1D44, Mapped, 1436, 2), (0x1D45, Mapped, 1438, 2), (0x1D46, Mapped, 1440, 3), (0x1D47, Mapped, 1, 1),
+    (0x1D48, Mapped, 3, 1), (0x1D49, Mapped, 4, 1), (0x1D4A, Mapped, 270, 2), (0x1D4B, Mapped, 272, 2),
+    (0x1D4C, Mapped, 1443, 2), (0x1D4D, Mapped, 6, 1), (0x1D4E, Valid, 0, 0), (0x1D4F, Mapped, 10, 1),
+    (0x1D50, Mapped, 12, 1), (0x1D51, Mapped, 198, 2), (0x1D52, Mapped, 14, 1), (0x1D53, Mapped, 258, 2),
+    (0x1D54, Mapped, 1445, 3), (0x1D55, Mapped, 1448, 3), (0x1D56, Mapped, 15, 1), (0x1D57, Mapped, 19, 1),
+    (0x1D58, Mapped, 20, 1), (0x1D59, Mapped, 1451, 3), (0x1D5A, Mapped, 286, 2), (0x1D5B, Mapped, 21, 1),
+    (0x1D5C, Mapped, 1454, 3), (0x1D5D, Mapped, 538, 2), (0x1D5E, Mapped, 540, 2), (0x1D5F, Mapped, 542, 2),
+    (0x1D60, Mapped, 572, 2), (0x1D61, Mapped, 574, 2), (0x1D62, Mapped, 8, 1), (0x1D63, Mapped, 17, 1),
+    (0x1D64, Mapped, 20, 1), (0x1D65, Mapped, 21, 1), (0x1D66, Mapped, 538, 2), (0x1D67, Mapped, 540, 2),
+    (0x1D68, Mapped, 564, 2), (0x1D69, Mapped, 572, 2), (0x1D6A, Mapped, 574, 2), (0x1D6B, Valid, 0, 0),
+    (0x1D78, Mapped, 678, 2), (0x1D79, Valid, 0, 0), (0x1D9B, Mapped, 1457, 2), (0x1D9C, Mapped, 2, 1),
+    (0x1D9D, Mapped, 1459, 2), (0x1D9E, Mapped, 91, 2), (0x1D9F, Mapped, 1443, 2), (0x1DA0, Mapped, 5, 1),
+    (0x1DA1, Mapped, 1461, 2), (0x1DA2, Mapped, 1463, 2), (0x1DA3, Mapped, 1465, 2), (0x1DA4, Mapped, 282, 2),
+    (0x1DA5, Mapped, 280, 2), (0x1DA6, Mapped, 1467, 2), (0x1DA7, Mapped, 1469, 3), (0x1DA8, Mapped, 1472, 2),
+    (0x1DA9, Mapped, 1474, 2), (0x1DAA, Mapped, 1476, 3), (0x1DAB, Mapped, 1479, 2), (0x1DAC, Mapped, 1481, 2),
+    (0x1DAD, Mapped, 1483, 2), (0x1DAE, Mapped, 288, 2), (0x1DAF, Mapped, 1485, 2), (0x1DB0, Mapped, 1487, 2),
+    (0x1DB1, Mapped, 290, 2), (0x1DB2, Mapped, 1489, 2), (0x1DB3, Mapped, 1491, 2), (0x1DB4, Mapped, 302, 2),
+    (0x1DB5, Mapped, 1493, 2), (0x1DB6, Mapped, 447, 2), (0x1DB7, Mapped, 310, 2), (0x1DB8, Mapped, 1495, 3),
+    (0x1DB9, Mapped, 312, 2), (0x1DBA, Mapped, 449, 2), (0x1DBB, Mapped, 25, 1), (0x1DBC, Mapped, 1498, 2),
+    (0x1DBD, Mapped, 1500, 2), (0x1DBE, Mapped, 318, 2), (0x1DBF, Mapped, 550, 2), (0x1DC0, Valid, 0, 0),
+    (0x1E00, Mapped, 1502, 3), (0x1E01, Valid, 0, 0), (0x1E02, Mapped, 1505, 3), (0x1E03, Valid, 0, 0),
+    (0x1E04, Mapped, 1508, 3), (0x1E05, Valid, 0, 0), (0x1E06, Mapped, 1511, 3), (0x1E07, Valid, 0, 0),
+    (0x1E08, Mapped, 1514, 3), (0x1E09, Valid, 0, 0), (0x1E0A, Mapped, 1517, 3), (0x1E0B, Valid, 0, 0),
+    (0x1E0C, Mapped, 1520, 3), (0x1E0D, Valid, 0, 0), (0x1E0E, Mapped, 1523, 3), (0x1E0F, Valid, 0, 0),
+    (0x1E10, Mapped, 1526, 3), (0x1E11, Valid, 0, 0), (0x1E12, Mapped, 1529, 3), (0x1E13, Valid, 0, 0),
+    (0x1E14, Mapped, 1532, 3), (0x1E15, Valid, 0, 0), (0x1E16, Mapped, 1535, 3), (0x1E17, Valid, 0, 0),
+    (0x1E18, Mapped, 1538, 3), (0x1E19, Valid, 0, 0), (0x1E1A, Mapped, 1541, 3), (0x1E1B, Valid, 0, 0),
+    (0x1E1C, Mapped, 1544, 3), (0x1E1D, Valid, 0, 0), (0x1E1E, Mapped, 1547, 3), (0x1E1F, Valid, 0, 0),
+    (0x1E20, Mapped, 1550, 3), (0x1E21, Valid, 0, 0), (0x1E22, Mapped, 1553, 3), (0x1E23, Valid, 0, 0),
+    (0x1E24, Mapped, 1556, 3), (0x1E25, Valid, 0, 0), (0x1E26, Mapped, 1559, 3), (0x1E27, Valid, 0, 0),
+    (0x1E28, Mapped, 1562, 3), (0x1E29, Valid, 0, 0), (0x1E2A, Mapped, 1565, 3), (0x1E2B, Valid, 0, 0),
+    (0x1E2C, Mapped, 1568, 3), (0x1E2D, Valid, 0, 0), (0x1E2E, Mapped, 1571, 3), (0x1E2F, Valid, 0, 0),
+    (0x1E30, Mapped, 1574, 3), (0x1E31, Valid, 0, 0), (0x1E32, Mapped, 1577, 3), (0x1E33, Valid, 0, 0),
+    (0x1E34, Mapped, 1580, 3), (0x1E35, Valid, 0, 0), (0x1E36, Mapped, 1583, 3), (0x1E37, Valid, 0, 0),
+    (0x1E38, Mapped, 1586, 3), (0x1E39, Valid, 0, 0), (0x1E3A, Mapped, 1589, 3), (0x1E3B, Valid, 0, 0),
+    (0x1E3C, Mapped, 1592, 3), (0x1E3D, Valid, 0, 0), (0x1E3E, Mapped, 1595, 3), (0x1E3F, Valid, 0, 0),
+    (0x1E40, Mapped, 1598, 3), (0x1E41, Valid, 0, 0), (0x1E42, Mapped, 1601, 3), (0x1E43, Valid, 0, 0),
+    (0x1E44, Mapped, 1604, 3), (0x1E45, Valid, 0, 0), (0x1E46, Mapped, 1607, 3), (0x1E47, Valid, 0, 0),
+    (0x1E48, Mapped, 1610, 3), (0x1E49, Valid, 0, 0), (0x1E4A, Mapped, 1613, 3), (0x1E4B, Valid, 0, 0),
+    (0x1E4C, Mapped, 1616, 3), (0x1E4D, Valid, 0, 0), (0x1E4E, Mapped, 1619, 3), (0x1E4F, Valid, 0, 0),
+    (0x1E50, Mapped, 1622, 3), (0x1E51, Valid, 0, 0), (0x1E52, Mapped, 1625, 3), (0x1E53, Valid, 0, 0),
+    (0x1E54, Mapped, 1628, 3), (0x1E55, Valid, 0, 0), (0x1E56, Mapped, 1631, 3), (0x1E57, Valid, 0, 0),
+    (0x1E58, Mapped, 1634, 3), (0x1E59, Valid, 0, 0), (0x1E5A, Mapped, 1637, 3), (0x1E5B, Valid, 0, 0),
+    (0x1E5C, Mapped, 1640, 3), (0x1E5D, Valid, 0, 0), (0x1E5E, Mapped, 1643, 3), (0x1E5F, Valid, 0, 0),
+    (0x1E60, Mapped, 1646, 3), (0x1E61, Valid, 0, 0), (0x1E62, Mapped, 1649, 3), (0x1E63, Valid, 0, 0),
+    (0x1E64, Mapped, 1652, 3), (0x1E65, Valid, 0, 0), (0x1E66, Mapped, 1655, 3), (0x1E67, Valid, 0, 0),
+    (0x1E68, Mapped, 1658, 3), (0x1E69, Valid, 0, 0), (0x1E6A, Mapped, 1661, 3), (0x1E6B, Valid, 0, 0),
+    (0x1E6C, Mapped, 1664, 3), (0x1E6D, Valid, 0, 0), (0x1E6E, Mapped, 1667, 3), (0x1E6F, Valid, 0, 0),
+    (0x1E70, Mapped, 1670, 3), (0x1E71, Valid, 0, 0), (0x1E72, Mapped, 1673, 3), (0x1E73, Valid, 0, 0),
+    (0x1E74, Mapped, 1676, 3), (0x1E75, Valid, 0, 0), (0x1E76, Mapped, 1679, 3), (0x1E77, Valid, 0, 0),
+    (0x1E78, Mapped, 1682, 3), (0x1E79, Valid, 0, 0), (0x1E7A, Mapped, 1685, 3), (0x1E7B, Valid, 0, 0),
+    (0x1E7C, Mapped, 1688, 3), (0x1E7D, Valid, 0, 0), (0x1E7E, Mapped, 1691, 3), (0x1E7F, Valid, 0, 0),
+    (0x1E80, Mapped, 1694, 3), (0x1E81, Valid, 0, 0), (0x1E82, Mapped, 1697, 3), (0x1E83, Valid, 0, 0),
+    (0x1E84, Mapped, 1700, 3), (0x1E85, Valid, 0, 0), (0x1E86, Mapped, 1703, 3), (0x1E87, Valid, 0, 0),
+    (0x1E88, Mapped, 1706, 3), (0x1E89, Valid, 0, 0), (0x1E8A, Mapped, 1709, 3), (0x1E8B, Valid, 0, 0),
+    (0x1E8C, Mapped, 1712, 3), (0x1E8D, Valid, 0, 0), (0x1E8E, Mapped, 1715, 3), (0x1E8F, Valid, 0, 0),
+    (0x1E90, Mapped, 1718, 3), (0x1E91, Valid, 0, 0), (0x1E92, Mapped, 1721, 3), (0x1E93, Valid, 0, 0),
+    (0x1E94, Mapped, 1724, 3), (0x1E95, Valid, 0, 0), (0x1E9A, Mapped, 1727, 3), (0x1E9B, Mapped, 1646, 3),
+    (0x1E9C, Valid, 0, 0), (0x1E9E, Mapped, 1730, 2), (0x1E9F, Valid, 0, 0), (0x1EA0, Mapped, 1732, 3),
+    (0x1EA1, Valid, 0, 0), (0x1EA2, Mapped, 1735, 3), (0x1EA3, Valid, 0, 0), (0x1EA4, Mapped, 1738, 3),
+    (0x1EA5, Valid, 0, 0), (0x1EA6, Mapped, 1741, 3), (0x1EA7, Valid, 0, 0), (0x1EA8, Mapped, 1744, 3),
+    (0x1EA9, Valid, 0, 0), (0x1EAA, Mapped, 1747, 3), (0x1EAB, Valid, 0, 0), (0x1EAC, Mapped, 1750, 3),
+    (0x1EAD, Valid, 0, 0), (0x1EAE, Mapped, 1753, 3), (0x1EAF, Valid, 0, 0), (0x1EB0, Mapped, 1756, 3),
+    (0x1EB1, Valid, 0, 0), (0x1EB2, Mapped, 1759, 3), (0x1EB3, Valid, 0, 0), (0x1EB4, Mapped, 1762, 3),
+    (0x1EB5, Valid, 0, 0), (0x1EB6, Mapped, 1765, 3), (0x1EB7, Valid, 0, 0), (0x1EB8, Mapped, 1768, 3),
+    (0x1EB9, Valid, 0, 0), (0x1EBA, Mapped, 1771, 3), (0x1EBB, Valid, 0, 0), (0x1EBC, Mapped, 1774, 3),
+    (0x1EBD, Valid, 0, 0), (0x1EBE, Mapped, 1777, 3), (0x1EBF, Valid, 0, 0), (0x1EC0, Mapped, 1780, 3),
+    (0x1EC1, Valid, 0, 0), (0x1EC2, Mapped, 1783, 3), (0x1EC3, Valid, 0, 0), (0x1EC4, Mapped, 1786, 3),
+    (0x1EC5, Valid, 0, 0), (0x1EC6, Mapped, 1789, 3), (0x1EC7, Valid, 0, 0), (0x1EC8, Mapped, 1792, 3),
+    (0x1EC9, Valid, 0, 0), (0x1ECA, Mapped, 1795, 3), (0x1ECB, Valid, 0, 0), (0x1ECC, Mapped, 1798, 3),
+    (0x1ECD, Valid, 0, 0), (0x1ECE, Mapped, 1801, 3), (0x1ECF, Valid, 0, 0), (0x1ED0, Mapped, 1804, 3),
+    (0x1ED1, Valid, 0, 0), (0x1ED2, Mapped, 1807, 3), (0x1ED3, Valid, 0, 0), (0x1ED4, Mapped, 1810, 3),
+    (0x1ED5, Valid, 0, 0), (0x1ED6, Mapped, 1813, 3), (0x1ED7, Valid, 0, 0), (0x1ED8, Mapped, 1816, 3),
+    (0x1ED9, Valid, 0, 0), (0x1EDA, Mapped, 1819, 3), (0x1EDB, Valid, 0, 0), (0x1EDC, Mapped, 1822, 3),
+    (0x1EDD, Valid, 0, 0), (0x1EDE, Mapped, 1825, 3), (0x1EDF, Valid, 0, 0), (0x1EE0, Mapped, 1828, 3),
+    (0x1EE1, Valid, 0, 0), (0x1EE2, Mapped, 1831, 3), (0x1EE3, Valid, 0, 0), (0x1EE4, Mapped, 1834, 3),
+    (0x1EE5, Valid, 0, 0), (0x1EE6, Mapped, 1837, 3), (0x1EE7, Valid, 0, 0), (0x1EE8, Mapped, 1840, 3),
+    (0x1EE9, Valid, 0, 0), (0x1EEA, Mapped, 1843, 3), (0x1EEB, Valid, 0, 0), (0x1EEC, Mapped, 1846, 3),
+    (0x1EED, Valid, 0, 0), (0x1EEE, Mapped, 1849, 3), (0x1EEF, Valid, 0, 0), (0x1EF0, Mapped, 1852, 3),
+    (0x1EF1, Valid, 0, 0), (0x1EF2, Mapped, 1855, 3), (0x1EF3, Valid, 0, 0), (0x1EF4, Mapped, 1858, 3),
+    (0x1EF5, Valid, 0, 0), (0x1EF6, Mapped, 1861, 3), (0x1EF7, Valid, 0, 0), (0x1EF8, Mapped, 1864, 3),
+    (0x1EF9, Valid, 0, 0), (0x1EFA, Mapped, 1867, 3), (0x1EFB, Valid, 0, 0), (0x1EFC, Mapped, 1870, 3),
+    (0x1EFD, Valid, 0, 0), (0x1EFE, Mapped, 1873, 3), (0x1EFF, Valid, 0, 0), (0x1F08, Mapped, 1876, 3),
+    (0x1F09, Mapped, 1879, 3), (0x1F0A, Mapped, 1882, 3), (0x1F0B, Mapped, 1885, 3), (0x1F0C, Mapped, 1888, 3),
+    (0x1F0D, Mapped, 1891, 3), (0x1F0E, Mapped, 1894, 3), (0x1F0F, Mapped, 1897, 3), (0x1F10, Valid, 0, 0),
+    (0x1F16, Disallowed, 0, 0), (0x1F18, Mapped, 1900, 3), (0x1F19, Mapped, 1903, 3), (0x1F1A, Mapped, 1906, 3),
+    (0x1F1B, Mapped, 1909, 3), (0x1F1C, Mapped, 1912, 3), (0x1F1D, Mapped, 1915, 3), (0x1F1E, Disallowed, 0, 0),
+    (0x1F20, Valid, 0, 0), (0x1F28, Mapped, 1918, 3), (0x1F29, Mapped, 1921, 3), (0x1F2A, Mapped, 1924, 3),
+    (0x1F2B, Mapped, 1927, 3), (0x1F2C, Mapped, 1930, 3), (0x1F2D, Mapped, 1933, 3), (0x1F2E, Mapped, 1936, 3),
+    (0x1F2F, Mapped, 1939, 3), (0x1F30, Valid, 0, 0), (0x1F38, Mapped, 1942, 3), (0x1F39, Mapped, 1945, 3),
+    (0x1F3A, Mapped, 1948, 3), (0x1F3B, Mapped, 1951, 3), (0x1F3C, Mapped, 1954, 3), (0x1F3D, Mapped, 1957, 3),
+    (0x1F3E, Mapped, 1960, 3), (0x1F3F, Mapped, 1963, 3), (0x1F40, Valid, 0, 0), (0x1F46, Disallowed, 0, 0),
+    (0x1F48, Mapped, 1966, 3), (0x1F49, Mapped, 1969, 3), (0x1F4A, Mapped, 1972, 3), (0x1F4B, Mapped, 1975, 3),
+    (0x1F4C, Mapped, 1978, 3), (0x1F4D, Mapped, 1981, 3), (0x1F4E, Disallowed, 0, 0), (0x1F50, Valid, 0, 0),
+    (0x1F58, Disallowed, 0, 0), (0x1F59, Mapped, 1984, 3), (0x1F5A, Disallowed, 0, 0), (0x1F5B, Mapped, 1987, 3),
+    (0x1F5C, Disallowed, 0, 0), (0x1F5D, Mapped, 1990, 3), (0x1F5E, Disallowed, 0, 0), (0x1F5F, Mapped, 1993, 3),
+    (0x1F60, Valid, 0, 0), (0x1F68, Mapped, 1996, 3), (0x1F69, Mapped, 1999, 3), (0x1F6A, Mapped, 2002, 3),
+    (0x1F6B, Mapped, 2005, 3), (0x1F6C, Mapped, 2008, 3), (0x1F6D, Mapped, 2011, 3), (0x1F6E, Mapped, 2014, 3),
+    (0x1F6F, Mapped, 2017, 3), (0x1F70, Valid, 0, 0), (0x1F71, Mapped, 520, 2), (0x1F72, Valid, 0, 0),
+    (0x1F73, Mapped, 524, 2), (0x1F74, Valid, 0, 0), (0x1F75, Mapped, 526, 2), (0x1F76, Valid, 0, 0),
+    (0x1F77, Mapped, 528, 2), (0x1F78, Valid, 0, 0), (0x1F79, Mapped, 530, 2), (0x1F7A, Valid, 0, 0),
+    (0x1F7B, Mapped, 532, 2), (0x1F7C, Valid, 0, 0), (0x1F7D, Mapped, 534, 2), (0x1F7E, Disallowed, 0, 0),
+    (0x1F80, Mapped, 2020, 5), (0x1F81, Mapped, 2025, 5), (0x1F82, Mapped, 2030, 5), (0x1F83, Mapped, 2035, 5),
+    (0x1F84, Mapped, 2040, 5), (0x1F85, Mapped, 2045, 5), (0x1F86, Mapped, 2050, 5), (0x1F87, Mapped, 2055, 5),
+    (0x1F88, Mapped, 2020, 5), (0x1F89, Mapped, 2025, 5), (0x1F8A, Mapped, 2030, 5), (0x1F8B, Mapped, 2035, 5),
+    (0x1F8C, Mapped, 2040, 5), (0x1F8D, Mapped, 2045, 5), (0x1F8E, Mapped, 2050, 5), (0x1F8F, Mapped, 2055, 5),
+    (0x1F90, Mapped, 2060, 5), (0x1F91, Mapped, 2065, 5), (0x1F92, Mapped, 2070, 5), (0x1F93, Mapped, 2075, 5),
+    (0x1F94, Mapped, 2080, 5), (0x1F95, Mapped, 2085, 5), (0x1F96, Mapped, 2090, 5), (0x1F97, Mapped, 2095, 5),
+    (0x1F98, Mapped, 2060, 5), (0x1F99, Mapped, 2065, 5), (0x1F9A, Mapped, 2070, 5), (0x1F9B, Mapped, 2075, 5),
+    (0x1F9C, Mapped, 2080, 5), (0x1F9D, Mapped, 2085, 5), (0x1F9E, Mapped, 2090, 5), (0x1F9F, Mapped, 2095, 5),
+    (0x1FA0, Mapped, 2100, 5), (0x1FA1, Mapped, 2105, 5), (0x1FA2, Mapped, 2110, 5), (0x1FA3, Mapped, 2115, 5),
+    (0x1FA4, Mapped, 2120, 5), (0x1FA5, Mapped, 2125, 5), (0x1FA6, Mapped, 2130, 5), (0x1FA7, Mapped, 2135, 5),
+    (0x1FA8, Mapped, 2100, 5), (0x1FA9, Mapped, 2105, 5), (0x1FAA, Mapped, 2110, 5), (0x1FAB, Mapped, 2115, 5),
+    (0x1FAC, Mapped, 2120, 5), (0x1FAD, Mapped, 2125, 5), (0x1FAE, Mapped, 2130, 5), (0x1FAF, Mapped, 2135, 5),
+    (0x1FB0, Valid, 0, 0), (0x1FB2, Mapped, 2140, 5), (0x1FB3, Mapped, 2145, 4), (0x1FB4, Mapped, 2149, 4),
+    (0x1FB5, Disallowed, 0, 0), (0x1FB6, Valid, 0, 0), (0x1FB7, Mapped, 2153, 5), (0x1FB8, Mapped, 2158, 3),
+    (0x1FB9, Mapped, 2161, 3), (0x1FBA, Mapped, 2164, 3), (0x1FBB, Mapped, 520, 2), (0x1FBC, Mapped, 2145, 4),
+    (0x1FBD, Std3Mapped, 2167, 3), (0x1FBE, Mapped, 499, 2), (0x1FBF, Std3Mapped, 2167, 3), (0x1FC0, Std3Mapped, 2170, 3),
+    (0x1FC1, Std3Mapped, 2173, 5), (0x1FC2, Mapped, 2178, 5), (0x1FC3, Mapped, 2183, 4), (0x1FC4, Mapped, 2187, 4),
+    (0x1FC5, Disallowed, 0, 0), (0x1FC6, Valid, 0, 0), (0x1FC7, Mapped, 2191, 5), (0x1FC8, Mapped, 2196, 3),
+    (0x1FC9, Mapped, 524, 2), (0x1FCA, Mapped, 2199, 3), (0x1FCB, Mapped, 526, 2), (0x1FCC, Mapped, 2183, 4),
+    (0x1FCD, Std3Mapped, 2202, 5), (0x1FCE, Std3Mapped, 2207, 5), (0x1FCF, Std3Mapped, 2212, 5), (0x1FD0, Valid, 0, 0),
+    (0x1FD3, Mapped, 2217, 2), (0x1FD4, Disallowed, 0, 0), (0x1FD6, Valid, 0, 0), (0x1FD8, Mapped, 2219, 3),
+    (0x1FD9, Mapped, 2222, 3), (0x1FDA, Mapped, 2225, 3), (0x1FDB, Mapped, 528, 2), (0x1FDC, Disallowed, 0, 0),
+    (0x1FDD, Std3Mapped, 2228, 5), (0x1FDE, Std3Mapped, 2233, 5), (0x1FDF, Std3Mapped, 2238, 5), (0x1FE0, Valid, 0, 0),
+    (0x1FE3, Mapped, 2243, 2), (0x1FE4, Valid, 0, 0), (0x1FE8, Mapped, 2245, 3), (0x1FE9, Mapped, 2248, 3),
+    (0x1FEA, Mapped, 2251, 3), (0x1FEB, Mapped, 532, 2), (0x1FEC, Mapped, 2254, 3), (0x1FED, Std3Mapped, 2257, 5),
+    (0x1FEE, Std3Mapped, 515, 5), (0x1FEF, Std3Mapped, 2262, 1), (0x1FF0, Disallowed, 0, 0), (0x1FF2, Mapped, 2263, 5),
+    (0x1FF3, Mapped, 2268, 4), (0x1FF4, Mapped, 2272, 4), (0x1FF5, Disallowed, 0, 0), (0x1FF6, Valid, 0, 0),
+    (0x1FF7, Mapped, 2276, 5), (0x1FF8, Mapped, 2281, 3), (0x1FF9, Mapped, 530, 2), (0x1FFA, Mapped, 2284, 3),
+    (0x1FFB, Mapped, 534, 2), (0x1FFC, Mapped, 2268, 4), (0x1FFD, Std3Mapped, 35, 3), (0x1FFE, Std3Mapped, 2287, 3),
+    (0x1FFF, Disallowed, 0, 0), (0x2000, Std3Mapped, 26, 1), (0x200B, Ignored, 0, 0), (0x200C, Deviation, 2290, 0),
+    (0x200E, Disallowed, 0, 0), (0x2010, Valid, 0, 0), (0x2011, Mapped, 2290, 3), (0x2012, Valid, 0, 0),
+    (0x2017, Std3Mapped, 2293, 3), (0x2018, Valid, 0, 0), (0x2024, Disallowed, 0, 0), (0x2027, Valid, 0, 0),
+    (0x2028, Disallowed, 0, 0), (0x202F, Std3Mapped, 26, 1), (0x2030, Valid, 0, 0), (0x2033, Mapped, 2296, 6),
+    (0x2034, Mapped, 2302, 9), (0x2035, Valid, 0, 0), (0x2036, Mapped, 2311, 6), (0x2037, Mapped, 2317, 9),
+    (0x2038, Valid, 0, 0), (0x203C, Std3Mapped, 2326, 2), (0x203D, Valid, 0, 0), (0x203E, Std3Mapped, 2328, 3),
+    (0x203F, Valid, 0, 0), (0x2047, Std3Mapped, 2331, 2), (0x2048, Std3Mapped, 2333, 2), (0x2049, Std3Mapped, 2335, 2),
+    (0x204A, Valid, 0, 0), (0x2057, Mapped, 2337, 12), (0x2058, Valid, 0, 0), (0x205F, Std3Mapped, 26, 1),
+    (0x2060, Ignored, 0, 0), (0x2061, Disallowed, 0, 0), (0x2064, Ignored, 0, 0), (0x2065, Disallowed, 0, 0),
+    (0x2070, Mapped, 2349, 1), (0x2071, Mapped, 8, 1), (0x2072, Disallowed, 0, 0), (0x2074, Mapped, 2350, 1),
+    (0x2075, Mapped, 2351, 1), (0x2076, Mapped, 2352, 1), (0x2077, Mapped, 2353, 1), (0x2078, Mapped, 2354, 1),
+    (0x2079, Mapped, 2355, 1), (0x207A, Std3Mapped, 2356, 1), (0x207B, Mapped, 2357, 3), (0x207C, Std3Mapped, 2360, 1),
+    (0x207D, Std3Mapped, 2361, 1), (0x207E, Std3Mapped, 2362, 1), (0x207F, Mapped, 13, 1), (0x2080, Mapped, 2349, 1),
+    (0x2081, Mapped, 43, 1), (0x2082, Mapped, 33, 1), (0x2083, Mapped, 34, 1), (0x2084, Mapped, 2350, 1),
+    (0x2085, Mapped, 2351, 1), (0x2086, Mapped, 2352, 1), (0x2087, Mapped, 2353, 1), (0x2088, Mapped, 2354, 1),
+    (0x2089, Mapped, 2355, 1), (0x208A, Std3Mapped, 2356, 1), (0x208B, Mapped, 2357, 3), (0x208C, Std3Mapped, 2360, 1),
+    (0x208D, Std3Mapped, 2361, 1), (0x208E, Std3Mapped, 2362, 1), (0x208F, Disallowed, 0, 0), (0x2090, Mapped, 0, 1),
+    (0x2091, Mapped, 4, 1), (0x2092, Mapped, 14, 1), (0x2093, Mapped, 23, 1), (0x2094, Mapped, 270, 2),
+    (0x2095, Mapped, 7, 1), (0x2096, Mapped, 10, 1), (0x2097, Mapped, 11, 1), (0x2098, Mapped, 12, 1),
+    (0x2099, Mapped, 13, 1), (0x209A, Mapped, 15, 1), (0x209B, Mapped, 18, 1), (0x209C, Mapped, 19, 1),
+    (0x209D, Disallowed, 0, 0), (0x20A0, Valid, 0, 0), (0x20A8, Mapped, 2363, 2), (0x20A9, Valid, 0, 0),
+    (0x20C1, Disallowed, 0, 0), (0x20D0, Valid, 0, 0), (0x20F1, Disallowed, 0, 0), (0x2100, Std3Mapped, 2365, 3),
+    (0x2101, Std3Mapped, 2368, 3), (0x2102, Mapped, 2, 1), (0x2103, Mapped, 2371, 3), (0x2104, Valid, 0, 0),
+    (0x2105, Std3Mapped, 2374, 3), (0x2106, Std3Mapped, 2377, 3), (0x2107, Mapped, 272, 2), (0x2108, Valid, 0, 0),
+    (0x2109, Mapped, 2380, 3), (0x210A, Mapped, 6, 1), (0x210B, Mapped, 7, 1), (0x210F, Mapped, 159, 2),
+    (0x2110, Mapped, 8, 1), (0x2112, Mapped, 11, 1), (0x2114, Valid, 0, 0), (0x2115, Mapped, 13, 1),
+    (0x2116, Mapped, 2383, 2), (0x2117, Valid, 0, 0), (0x2119, Mapped, 15, 1), (0x211A, Mapped, 16, 1),
+    (0x211B, Mapped, 17, 1), (0x211E, Valid, 0, 0), (0x2120, Mapped, 2385, 2), (0x2121, Mapped, 2387, 3),
+    (0x2122, Mapped, 2390, 2), (0x2123, Valid, 0, 0), (0x2124, Mapped, 25, 1), (0x2125, Valid, 0, 0),
+    (0x2126, Mapped, 578, 2), (0x2127, Valid, 0, 0), (0x2128, Mapped, 25, 1), (0x2129, Valid, 0, 0),
+    (0x212A, Mapped, 10, 1), (0x212B, Mapped, 69, 2), (0x212C, Mapped, 1, 1), (0x212D, Mapped, 2, 1),
+    (0x212E, Valid, 0, 0), (0x212F, Mapped, 4, 1), (0x2131, Mapped, 5, 1), (0x2132, Disallowed, 0, 0),
+    (0x2133, Mapped, 12, 1), (0x2134, Mapped, 14, 1), (0x2135, Mapped, 2392, 2), (0x2136, Mapped, 2394, 2),
+    (0x2137, Mapped, 2396, 2), (0x2138, Mapped, 2398, 2), (0x2139, Mapped, 8, 1), (0x213A, Valid, 0, 0),
+    (0x213B, Mapped, 2400, 3), (0x213C, Mapped, 562, 2), (0x213D, Mapped, 540, 2), (0x213F, Mapped, 562, 2),
+    (0x2140, Mapped, 2403, 3), (0x2141, Valid, 0, 0), (0x2145, Mapped, 3, 1), (0x2147, Mapped, 4, 1),
+    (0x2148, Mapped, 8, 1), (0x2149, Mapped, 9, 1), (0x214A, Valid, 0, 0), (0x2150, Mapped, 2406, 5),
+    (0x2151, Mapped, 2411, 5), (0x2152, Mapped, 2416, 6), (0x2153, Mapped, 2422, 5), (0x2154, Mapped, 2427, 5),
+    (0x2155, Mapped, 2432, 5), (0x2156, Mapped, 2437, 5), (0x2157, Mapped, 2442, 5), (0x2158, Mapped, 2447, 5),
+    (0x2159, Mapped, 2452, 5), (0x215A, Mapped, 2457, 5), (0x215B, Mapped, 2462, 5), (0x215C, Mapped, 2467, 5),
+    (0x215D, Mapped, 2472, 5), (0x215E, Mapped, 2477, 5), (0x215F, Mapped, 2482, 4), (0x2160, Mapped, 8, 1),
+    (0x2161, Mapped, 2486, 2), (0x2162, Mapped, 2488, 3), (0x2163, Mapped, 2491, 2), (0x2164, Mapped, 21, 1),
+    (0x2165, Mapped, 2493, 2), (0x2166, Mapped, 2495, 3), (0x2167, Mapped, 2498, 4), (0x2168, Mapped, 2502, 2),
+    (0x2169, Mapped, 23, 1), (0x216A, Mapped, 2504, 2), (0x216B, Mapped, 2506, 3), (0x216C, Mapped, 11, 1),
+    (0x216D, Mapped, 2, 1), (0x216E, Mapped, 3, 1), (0x216F, Mapped, 12, 1), (0x2170, Mapped, 8, 1),
+    (0x2171, Mapped, 2486, 2), (0x2172, Mapped, 2488, 3), (0x2173, Mapped, 2491, 2), (0x2174, Mapped, 21, 1),
+    (0x2175, Mapped, 2493, 2), (0x2176, Mapped, 2495, 3), (0x2177, Mapped, 2498, 4), (0x2178, Mapped, 2502, 2),
+    (0x2179, Mapped, 23, 1), (0x217A, Mapped, 2504, 2), (0x217B, Mapped, 2506, 3), (0x217C, Mapped, 11, 1),
+    (0x217D, Mapped, 2, 1), (0x217E, Mapped, 3, 1), (0x217F, Mapped, 12, 1), (0x2180, Valid, 0, 0),
+    (0x2183, Disallowed, 0, 0), (0x2184, Valid, 0, 0), (0x2189, Mapped, 2509, 5), (0x218A, Valid, 0, 0),
+    (0x218C, Disallowed, 0, 0), (0x2190, Valid, 0, 0), (0x222C, Mapped, 2514, 6), (0x222D, Mapped, 2520, 9),
+    (0x222E, Valid, 0, 0), (0x222F, Mapped, 2529, 6), (0x2230, Mapped, 2535, 9), (0x2231, Valid, 0, 0),
+    (0x2329, Mapped, 2544, 3), (0x232A, Mapped, 2547, 3), (0x232B, Valid, 0, 0), (0x2427, Disallowed, 0, 0),
+    (0x2440, Valid, 0, 0), (0x244B, Disallowed, 0, 0), (0x2460, Mapped, 43, 1), (0x2461, Mapped, 33, 1),
+    (0x2462, Mapped, 34, 1), (0x2463, Mapped, 2350, 1), (0x2464, Mapped, 2351, 1), (0x2465, Mapped, 2352, 1),
+    (0x2466, Mapped, 2353, 1), (0x2467, Mapped, 2354, 1), (0x2468, Mapped, 2355, 1), (0x2469, Mapped, 2550, 2),
+    (0x246A, Mapped, 2552, 2), (0x246B, Mapped, 2554, 2), (0x246C, Mapped, 2556, 2), (0x246D, Mapped, 2558, 2),
+    (0x246E, Mapped, 2560, 2), (0x246F, Mapped, 2562, 2), (0x2470, Mapped, 2564, 2), (0x2471, Mapped, 2566, 2),
+    (0x2472, Mapped, 2568, 2), (0x2473, Mapped, 2570, 2), (0x2474, Std3Mapped, 2572, 3), (0x2475, Std3Mapped, 2575, 3),
+    (0x2476, Std3Mapped, 2578, 3), (0x2477, Std3Mapped, 2581, 3), (0x2478, Std3Mapped, 2584, 3), (0x2479, Std3Mapped, 2587, 3),
+    (0x247A, Std3Mapped, 2590, 3), (0x247B, Std3Mapped, 2593, 3), (0x247C, Std3Mapped, 2596, 3), (0x247D, Std3Mapped, 2599, 4),
+    (0x247E, Std3Mapped, 2603, 4), (0x247F, Std3Mapped, 2607, 4), (0x2480, Std3Mapped, 2611, 4), (0x2481, Std3Mapped, 2615, 4),
+    (0x2482, Std3Mapped, 2619, 4), (0x2483, Std3Mapped, 2623, 4), (0x2484, Std3Mapped, 2627, 4), (0x2485, Std3Mapped, 2631, 4),
+    (0x2486, Std3Mapped, 2635, 4), (0x2487, Std3Mapped, 2639, 4), (0x2488, Disallowed, 0, 0), (0x249C, Std3Mapped, 2643, 3),
+    (0x249D, Std3Mapped, 2646, 3), (0x249E, Std3Mapped, 2649, 3), (0x249F, Std3Mapped, 2652, 3), (0x24A0, Std3Mapped, 2655, 3),
+    (0x24A1, Std3Mapped, 2658, 3), (0x24A2, Std3Mapped, 2661, 3), (0x24A3, Std3Mapped, 2664, 3), (0x24A4, Std3Mapped, 2667, 3),
+    (0x24A5, Std3Mapped, 2670, 3), (0x24A6, Std3Mapped, 2673, 3), (0x24A7, Std3Mapped, 2676, 3), (0x24A8, Std3Mapped, 2679, 3),
+    (0x24A9, Std3Mapped, 2682, 3), (0x24AA, Std3Mapped, 2685, 3), (0x24AB, Std3Mapped, 2688, 3), (0x24AC, Std3Mapped, 2691, 3),
+    (0x24AD, Std3Mapped, 2694, 3), (0x24AE, Std3Mapped, 2697, 3), (0x24AF, Std3Mapped, 2700, 3), (0x24B0, Std3Mapped, 2703, 3),
+    (0x24B1, Std3Mapped, 2706, 3), (0x24B2, Std3Mapped, 2709, 3), (0x24B3, Std3Mapped, 2712, 3), (0x24B4, Std3Mapped, 2715, 3),
+    (0x24B5, Std3Mapped, 2718, 3), (0x24B6, Mapped, 0, 1), (0x24B7, Mapped, 1, 1), (0x24B8, Mapped, 2, 1),
+    (0x24B9, Mapped, 3, 1), (0x24BA, Mapped, 4, 1), (0x24BB, Mapped, 5, 1), (0x24BC, Mapped, 6, 1),
+    (0x24BD, Mapped, 7, 1), (0x24BE, Mapped, 8, 1), (0x24BF, Mapped, 9, 1), (0x24C0, Mapped, 10, 1),
+    (0x24C1, Mapped, 11, 1), (0x24C2, Mapped, 12, 1), (0x24C3, Mapped, 13, 1), (0x24C4, Mapped, 14, 1),
+    (0x24C5, Mapped, 15, 1), (0x24C6, Mapped, 16, 1), (0x24C7, Mapped, 17, 1), (0x24C8, Mapped, 18, 1),
+    (0x24C9, Mapped, 19, 1), (0x24CA, Mapped, 20, 1), (0x24CB, Mapped, 21, 1), (0x24CC, Mapped, 22, 1),
+    (0x24CD, Mapped, 23, 1), (0x24CE, Mapped, 24, 1), (0x24CF, Mapped, 25, 1), (0x24D0, Mapped, 0, 1),
+    (0x24D1, Mapped, 1, 1), (0x24D2, Mapped, 2, 1), (0x24D3, Mapped, 3, 1), (0x24D4, Mapped, 4, 1),
+    (0x24D5, Mapped, 5, 1), (0x24D6, Mapped, 6, 1), (0x24D7, Mapped, 7, 1), (0x24D8, Mapped, 8, 1),
+    (0x24D9, Mapped, 9, 1), (0x24DA, Mapped, 10, 1), (0x24DB, Mapped, 11, 1), (0x24DC, Mapped, 12, 1),
+    (0x24DD, Mapped, 13, 1), (0x24DE, Mapped, 14, 1), (0x24DF, Mapped, 15, 1), (0x24E0, Mapped, 16, 1),
+    (0x24E1, Mapped, 17, 1), (0x24E2, Mapped, 18, 1), (0x24E3, Mapped, 19, 1), (0x24E4, Mapped, 20, 1),
+    (0x24E5, Mapped, 21, 1), (0x24E6, Mapped, 22, 1), (0x24E7, Mapped, 23, 1), (0x24E8, Mapped, 24, 1),
+    (0x24E9, Mapped, 25, 1), (0x24EA, Mapped, 2349, 1), (0x24EB, Valid, 0, 0), (0x2A0C, Mapped, 2721, 12),
+    (0x2A0D, Valid, 0, 0), (0x2A74, Std3Mapped, 2733, 3), (0x2A75, Std3Mapped, 2736, 2), (0x2A76, Std3Mapped, 2738, 3),
+    (0x2A77, Valid, 0, 0), (0x2ADC, Mapped, 2741, 5), (0x2ADD, Valid, 0, 0), (0x2B74, Disallowed, 0, 0),
+    (0x2B76, Valid, 0, 0), (0x2B96, Disallowed, 0, 0), (0x2B97, Valid, 0, 0), (0x2C00, Mapped, 2746, 3),
+    (0x2C01, Mapped, 2749, 3), (0x2C02, Mapped, 2752, 3), (0x2C03, Mapped, 2755, 3), (0x2C04, Mapped, 2758, 3),
+    (0x2C05, Mapped, 2761, 3), (0x2C06, Mapped, 2764, 3), (0x2C07, Mapped, 2767, 3), (0x2C08, Mapped, 2770, 3),
+    (0x2C09, Mapped, 2773, 3), (0x2C0A, Mapped, 2776, 3), (0x2C0B, Mapped, 2779, 3), (0x2C0C, Mapped, 2782, 3),
+    (0x2C0D, Mapped, 2785, 3), (0x2C0E, Mapped, 2788, 3), (0x2C0F, Mapped, 2791, 3), (0x2C10, Mapped, 2794, 3),
+    (0x2C11, Mapped, 2797, 3), (0x2C12, Mapped, 2800, 3), (0x2C13, Mapped, 2803, 3), (0x2C14, Mapped, 2806, 3),
+    (0x2C15, Mapped, 2809, 3), (0x2C16, Mapped, 2812, 3), (0x2C17, Mapped, 2815, 3), (0x2C18, Mapped, 2818, 3),
+    (0x2C19, Mapped, 2821, 3), (0x2C1A, Mapped, 2824, 3), (0x2C1B, Mapped, 2827, 3), (0x2C1C, Mapped, 2830, 3),
+    (0x2C1D, Mapped, 2833, 3), (0x2C1E, Mapped, 2836, 3), (0x2C1F, Mapped, 2839, 3), (0x2C20, Mapped, 2842, 3),
+    (0x2C21, Mapped, 2845, 3), (0x2C22, Mapped, 2848, 3), (0x2C23, Mapped, 2851, 3), (0x2C24, Mapped, 2854, 3),
+    (0x2C25, Mapped, 2857, 3), (0x2C26, Mapped, 2860, 3), (0x2C27, Mapped, 2863, 3), (0x2C28, Mapped, 2866, 3),
+    (0x2C29, Mapped, 2869, 3), (0x2C2A, Mapped, 2872, 3), (0x2C2B, Mapped, 2875, 3), (0x2C2C, Mapped, 2878, 3),
+    (0x2C2D, Mapped, 2881, 3), (0x2C2E, Mapped, 2884, 3), (0x2C2F, Mapped, 2887, 3), (0x2C30, Valid, 0, 0),
+    (0x2C60, Mapped, 2890, 3), (0x2C61, Valid, 0, 0), (0x2C62, Mapped, 2893, 2), (0x2C63, Mapped, 2895, 3),
+    (0x2C64, Mapped, 2898, 2), (0x2C65, Valid, 0, 0), (0x2C67, Mapped, 2900, 3), (0x2C68, Valid, 0, 0),
+    (0x2C69, Mapped, 2903, 3), (0x2C6A, Valid, 0, 0), (0x2C6B, Mapped, 2906, 3), (0x2C6C, Valid, 0, 0),
+    (0x2C6D, Mapped, 1438, 2), (0x2C6E, Mapped, 1481, 2), (0x2C6F, Mapped, 1436, 2), (0x2C70, Mapped, 1457, 2),
+    (0x2C71, Valid, 0, 0), (0x2C72, Mapped, 2909, 3), (0x2C73, Valid, 0, 0), (0x2C75, Mapped, 2912, 3),
+    (0x2C76, Valid, 0, 0), (0x2C7C, Mapped, 9, 1), (0x2C7D, Mapped, 21, 1), (0x2C7E, Mapped, 2915, 2),
+    (0x2C7F, Mapped, 2917, 2), (0x2C80, Mapped, 2919, 3), (0x2C81, Valid, 0, 0), (0x2C82, Mapped, 2922, 3),
+    (0x2C83, Valid, 0, 0), (0x2C84, Mapped, 2925, 3), (0x2C85, Valid, 0, 0), (0x2C86, Mapped, 2928, 3),
+    (0x2C87, Valid, 0, 0), (0x2C88, Mapped, 2931, 3), (0x2C89, Valid, 0, 0), (0x2C8A, Mapped, 2934, 3),
+    (0x2C8B, Valid, 0, 0), (0x2C8C, Mapped, 2937, 3), (0x2C8D, Valid, 0, 0), (0x2C8E, Mapped, 2940, 3),
+    (0x2C8F, Valid, 0, 0), (0x2C90, Mapped, 2943, 3), (0x2C91, Valid, 0, 0), (0x2C92, Mapped, 2946, 3),
+    (0x2C93, Valid, 0, 0), (0x2C94, Mapped, 2949, 3), (0x2C95, Valid, 0, 0), (0x2C96, Mapped, 2952, 3),
+    (0x2C97, Valid, 0, 0), (0x2C98, Mapped, 2955, 3), (0x2C99, Valid, 0, 0), (0x2C9A, Mapped, 2958, 3),
+    (0x2C9B, Valid, 0, 0), (0x2C9C, Mapped, 2961, 3), (0x2C9D, Valid, 0, 0), (0x2C9E, Mapped, 2964, 3),
+    (0x2C9F, Valid, 0, 0), (0x2CA0, Mapped, 2967, 3), (0x2CA1, Valid, 0, 0), (0x2CA2, Mapped, 2970, 3),
+    (0x2CA3, Valid, 0, 0), (0x2CA4, Mapped, 2973, 3), (0x2CA5, Valid, 0, 0), (0x2CA6, Mapped, 2976, 3),
+    (0x2CA7, Valid, 0, 0), (0x2CA8, Mapped, 2979, 3), (0x2CA9, Valid, 0, 0), (0x2CAA, Mapped, 2982, 3),
+    (0x2CAB, Valid, 0, 0), (0x2CAC, Mapped, 2985, 3), (0x2CAD, Valid, 0, 0), (0x2CAE, Mapped, 2988, 3),
+    (0x2CAF, Valid, 0, 0), (0x2CB0, Mapped, 2991, 3), (0x2CB1, Valid, 0, 0), (0x2CB2, Mapped, 2994, 3),
+    (0x2CB3, Valid, 0, 0), (0x2CB4, Mapped, 2997, 3), (0x2CB5, Valid, 0, 0), (0x2CB6, Mapped, 3000, 3),
+    (0x2CB7, Valid, 0, 0), (0x2CB8, Mapped, 3003, 3), (0x2CB9, Valid, 0, 0), (0x2CBA, Mapped, 3006, 3),
+    (0x2CBB, Valid, 0, 0), (0x2CBC, Mapped, 3009, 3), (0x2CBD, Valid, 0, 0), (0x2CBE, Mapped, 3012, 3),
+    (0x2CBF, Valid, 0, 0), (0x2CC0, Mapped, 3015, 3), (0x2CC1, Valid, 0, 0), (0x2CC2, Mapped, 3018, 3),
+    (0x2CC3, Valid, 0, 0), (0x2CC4, Mapped, 3021, 3), (0x2CC5, Valid, 0, 0), (0x2CC6, Mapped, 3024, 3),
+    (0x2CC7, Valid, 0, 0), (0x2CC8, Mapped, 3027, 3), (0x2CC9, Valid, 0, 0), (0x2CCA, Mapped, 3030, 3),
+    (0x2CCB, Valid, 0, 0), (0x2CCC, Mapped, 3033, 3), (0x2CCD, Valid, 0, 0), (0x2CCE, Mapped, 3036, 3),
+    (0x2CCF, Valid, 0, 0), (0x2CD0, Mapped, 3039, 3), (0x2CD1, Valid, 0, 0), (0x2CD2, Mapped, 3042, 3),
+    (0x2CD3, Valid, 0, 0), (0x2CD4, Mapped, 3045, 3), (0x2CD5, Valid, 0, 0), (0x2CD6, Mapped, 3048, 3),
+    (0x2CD7, Valid, 0, 0), (0x2CD8, Mapped, 3051, 3), (0x2CD9, Valid, 0, 0), (0x2CDA, Mapped, 3054, 3),
+    (0x2CDB, Valid, 0, 0), (0x2CDC, Mapped, 3057, 3), (0x2CDD, Valid, 0, 0), (0x2CDE, Mapped, 3060, 3),
+    (0x2CDF, Valid, 0, 0), (0x2CE0, Mapped, 3063, 3), (0x2CE1, Valid, 0, 0), (0x2CE2, Mapped, 3066, 3),
+    (0x2CE3, Valid, 0, 0), (0x2CEB, Mapped, 3069, 3), (0x2CEC, Valid, 0, 0), (0x2CED, Mapped, 3072, 3),
+    (0x2CEE, Valid, 0, 0), (0x2CF2, Mapped, 3075, 3), (0x2CF3, Valid, 0, 0), (0x2CF4, Disallowed, 0, 0),
+    (0x2CF9, Valid, 0, 0), (0x2D26, Disallowed, 0, 0), (0x2D27, Valid, 0, 0), (0x2D28, Disallowed, 0, 0),
+    (0x2D2D, Valid, 0, 0), (0x2D2E, Disallowed, 0, 0), (0x2D30, Valid, 0, 0), (0x2D68, Disallowed, 0, 0),
+    (0x2D6F, Mapped, 3078, 3), (0x2D70, Valid, 0, 0), (0x2D71, Disallowed, 0, 0), (0x2D7F, Valid, 0, 0),
+    (0x2D97, Disallowed, 0, 0), (0x2DA0, Valid, 0, 0), (0x2DA7, Disallowed, 0, 0), (0x2DA8, Valid, 0, 0),
+    (0x2DAF, Disallowed, 0, 0), (0x2DB0, Valid, 0, 0), (0x2DB7, Disallowed, 0, 0), (0x2DB8, Valid, 0, 0),
+    (0x2DBF, Disallowed, 0, 0), (0x2DC0, Valid, 0, 0), (0x2DC7, Disallowed, 0, 0), (0x2DC8, Valid, 0, 0),
+    (0x2DCF, Disallowed, 0, 0), (0x2DD0, Valid, 0, 0), (0x2DD7, Disallowed, 0, 0), (0x2DD8, Valid, 0, 0),
+    (0x2DDF, Disallowed, 0, 0), (0x2DE0, Valid, 0, 0), (0x2E5E, Disallowed, 0, 0), (0x2E80, Valid, 0, 0),
+    (0x2E9A, Disallowed, 0, 0), (0x2E9B, Valid, 0, 0), (0x2E9F, Mapped, 3081, 3), (0x2EA0, Valid, 0, 0),
+    (0x2EF3, Mapped, 3084, 3), (0x2EF4, Disallowed, 0, 0), (0x2F00, Mapped, 3087, 3), (0x2F01, Mapped, 3090, 3),
+    (0x2F02, Mapped, 3093, 3), (0x2F03, Mapped, 3096, 3), (0x2F04, Mapped, 3099, 3), (0x2F05, Mapped, 3102, 3),
+    (0x2F06, Mapped, 3105, 3), (0x2F07, Mapped, 3108, 3), (0x2F08, Mapped, 3111, 3), (0x2F09, Mapped, 3114, 3),
+    (0x2F0A, Mapped, 3117, 3), (0x2F0B, Mapped, 3120, 3), (0x2F0C, Mapped, 3123, 3), (0x2F0D, Mapped, 3126, 3),
+    (0x2F0E, Mapped, 3129, 3), (0x2F0F, Mapped, 3132, 3), (0x2F10, Mapped, 3135, 3), (0x2F11, Mapped, 3138, 3),
+    (0x2F12, Mapped, 3141, 3), (0x2F13, Mapped, 3144, 3), (0x2F14, Mapped, 3147, 3), (0x2F15, Mapped, 3150, 3),
+    (0x2F16, Mapped, 3153, 3), (0x2F17, Mapped, 3156, 3), (0x2F18, Mapped, 3159, 3), (0x2F19, Mapped, 3162, 3),
+    (0x2F1A, Mapped, 3165, 3), (0x2F1B, Mapped, 3168, 3), (0x2F1C, Mapped, 3171, 3), (0x2F1D, Mapped, 3174, 3),
+    (0x2F1E, Mapped, 3177, 3), (0x2F1F, Mapped, 3180, 3), (0x2F20, Mapped, 3183, 3), (0x2F21, Mapped, 3186, 3),
+    (0x2F22, Mapped, 3189, 3), (0x2F23, Mapped, 3192, 3), (0x2F24, Mapped, 3195, 3), (0x2F25, Mapped, 3198, 3),
+    (0x2F26, Mapped, 3201, 3), (0x2F27, Mapped, 3204, 3), (0x2F28, Mapped, 3207, 3), (0x2F29, Mapped, 3210, 3),
+    (0x2F2A, Mapped, 3213, 3), (0x2F2B, Mapped, 3216, 3), (0x2F2C, Mapped, 3219, 3), (0x2F2D, Mapped, 3222, 3),
+    (0x2F2E, Mapped, 3225, 3), (0x2F2F, Mapped, 3228, 3), (0x2F30, Mapped, 3231, 3), (0x2F31, Mapped, 3234, 3),
+    (0x2F32, Mapped, 3237, 3), (0x2F33, Mapped, 3240, 3), (0x2F34, Mapped, 3243, 3), (0x2F35, Mapped, 3246, 3),
+    (0x2F36, Mapped, 3249, 3), (0x2F37, Mapped, 3252, 3), (0x2F38, Mapped, 3255, 3), (0x2F39, Mapped, 3258, 3),
+    (0x2F3A, Mapped, 3261, 3), (0x2F3B, Mapped, 3264, 3), (0x2F3C, Mapped, 3267, 3), (0x2F3D, Mapped, 3270, 3),
+    (0x2F3E, Mapped, 3273, 3), (0x2F3F, Mapped, 3276, 3), (0x2F40, Mapped, 3279, 3), (0x2F41, Mapped, 3282, 3),
+    (0x2F42, Mapped, 3285, 3), (0x2F43, Mapped, 3288, 3), (0x2F44, Mapped, 3291, 3), (0x2F45, Mapped, 3294, 3),
+    (0x2F46, Mapped, 3297, 3), (0x2F47, Mapped, 3300, 3), (0x2F48, Mapped, 3303, 3), (0x2F49, Mapped, 3306, 3),
+    (0x2F4A, Mapped, 3309, 3), (0x2F4B, Mapped, 3312, 3), (0x2F4C, Mapped, 3315, 3), (0x2F4D, Mapped, 3318, 3),
+    (0x2F4E, Mapped, 3321, 3), (0x2F4F, Mapped, 3324, 3), (0x2F50, Mapped, 3327, 3), (0x2F51, Mapped, 3330, 3),
+    (0x2F52, Mapped, 3333, 3), (0x2F53, Mapped, 3336, 3), (0x2F54, Mapped, 3339, 3), (0x2F55, Mapped, 3342, 3),
+    (0x2F56, Mapped, 3345, 3), (0x2F57, Mapped, 3348, 3), (0x2F58, Mapped, 3351, 3), (0x2F59, Mapped, 3354, 3),
+    (0x2F5A, Mapped, 3357, 3), (0x2F5B, Mapped, 3360, 3), (0x2F5C, Mapped, 3363, 3), (0x2F5D, Mapped, 3366, 3),
+    (0x2F5E, Mapped, 3369, 3), (0x2F5F, Mapped, 3372, 3), (0x2F60, Mapped, 3375, 3), (0x2F61, Mapped, 3378, 3),
+    (0x2F62, Mapped, 3381, 3), (0x2F63, Mapped, 3384, 3), (0x2F64, Mapped, 3387, 3), (0x2F65, Mapped, 3390, 3),
+    (0x2F66, Mapped, 3393, 3), (0x2F67, Mapped, 3396, 3), (0x2F68, Mapped, 3399, 3), (0x2F69, Mapped, 3402, 3),
+    (0x2F6A, Mapped, 3405, 3), (0x2F6B, Mapped, 3408, 3), (0x2F6C, Mapped, 3411, 3), (0x2F6D, Mapped, 3414, 3),
+    (0x2F6E, Mapped, 3417, 3), (0x2F6F, Mapped, 3420, 3), (0x2F70, Mapped, 3423, 3), (0x2F71, Mapped, 3426, 3),
+    (0x2F72, Mapped, 3429, 3), (0x2F73, Mapped, 3432, 3), (0x2F74, Mapped, 3435, 3), (0x2F75, Mapped, 3438, 3),
+    (0x2F76, Mapped, 3441, 3), (0x2F77, Mapped, 3444, 3), (0x2F78, Mapped, 3447, 3), (0x2F79, Mapped, 3450, 3),
+    (0x2F7A, Mapped, 3453, 3), (0x2F7B, Mapped, 3456, 3), (0x2F7C, Mapped, 3459, 3), (0x2F7D, Mapped, 3462, 3),
+    (0x2F7E, Mapped, 3465, 3), (0x2F7F, Mapped, 3468, 3), (0x2F80, Mapped, 3471, 3), (0x2F81, Mapped, 3474, 3),
+    (0x2F82, Mapped, 3477, 3), (0x2F83, Mapped, 3480, 3), (0x2F84, Mapped, 3483, 3), (0x2F85, Mapped, 3486, 3),
+    (0x2F86, Mapped, 3489, 3), (0x2F87, Mapped, 3492, 3), (0x2F88, Mapped, 3495, 3), (0x2F89, Mapped, 3498, 3),
+    (0x2F8A, Mapped, 3501, 3), (0x2F8B, Mapped, 3504, 3), (0x2F8C, Mapped, 3507, 3), (0x2F8D, Mapped, 3510, 3),
+    (0x2F8E, Mapped, 3513, 3), (0x2F8F, Mapped, 3516, 3), (0x2F90, Mapped, 3519, 3), (0x2F91, Mapped, 3522, 3),
+    (0x2F92, Mapped, 3525, 3), (0x2F93, Mapped, 3528, 3), (0x2F94, Mapped, 3531, 3), (0x2F95, Mapped, 3534, 3),
+    (0x2F96, Mapped, 3537, 3), (0x2F97, Mapped, 3540, 3), (0x2F98, Mapped, 3543, 3), (0x2F99, Mapped, 3546, 3),
+    (0x2F9A, Mapped, 3549, 3), (0x2F9B, Mapped, 3552, 3), (0x2F9C, Mapped, 3555, 3), (0x2F9D, Mapped, 3558, 3),
+    (0x2F9E, Mapped, 3561, 3), (0x2F9F, Mapped, 3564, 3), (0x2FA0, Mapped, 3567, 3), (0x2FA1, Mapped, 3570, 3),
+    (0x2FA2, Mapped, 3573, 3), (0x2FA3, Mapped, 3576, 3), (0x2FA4, Mapped, 3579, 3), (0x2FA5, Mapped, 3582, 3),
+    (0x2FA6, Mapped, 3585, 3), (0x2FA7, Mapped, 3588, 3), (0x2FA8, Mapped, 3591, 3), (0x2FA9, Mapped, 3594, 3),
+    (0x2FAA, Mapped, 3597, 3), (0x2FAB, Mapped, 3600, 3), (0x2FAC, Mapped, 3603, 3), (0x2FAD, Mapped, 3606, 3),
+    (0x2FAE, Mapped, 3609, 3), (0x2FAF, Mapped, 3612, 3), (0x2FB0, Mapped, 3615, 3), (0x2FB1, Mapped, 3618, 3),
+    (0x2FB2, Mapped, 3621, 3), (0x2FB3, Mapped, 3624, 3), (0x2FB4, Mapped, 3627, 3), (0x2FB5, Mapped, 3630, 3),
+    (0x2FB6, Mapped, 3633, 3), (0x2FB7, Mapped, 3636, 3), (0x2FB8, Mapped, 3639, 3), (0x2FB9, Mapped, 3642, 3),
+    (0x2FBA, Mapped, 3645, 3), (0x2FBB, Mapped, 3648, 3), (0x2FBC, Mapped, 3651, 3), (0x2FBD, Mapped, 3654, 3),
+    (0x2FBE, Mapped, 3657, 3), (0x2FBF, Mapped, 3660, 3), (0x2FC0, Mapped, 3663, 3), (0x2FC1, Mapped, 3666, 3),
+    (0x2FC2, Mapped, 3669, 3), (0x2FC3, Mapped, 3672, 3), (0x2FC4, Mapped, 3675, 3), (0x2FC5, Mapped, 3678, 3),
+    (0x2FC6, Mapped, 3681, 3), (0x2FC7, Mapped, 3684, 3), (0x2FC8, Mapped, 3687, 3), (0x2FC9, Mapped, 3690, 3),
+    (0x2FCA, Mapped, 3693, 3), (0x2FCB, Mapped, 3696, 3), (0x2FCC, Mapped, 3699, 3), (0x2FCD, Mapped, 3702, 3),
+    (0x2FCE, Mapped, 3705, 3), (0x2FCF, Mapped, 3708, 3), (0x2FD0, Mapped, 3711, 3), (0x2FD1, Mapped, 3714, 3),
+    (0x2FD2, Mapped, 3717, 3), (0x2FD3, Mapped, 3720, 3), (0x2FD4, Mapped, 3723, 3), (0x2FD5, Mapped, 3726, 3),
+    (0x2FD6, Disallowed, 0, 0), (0x3000, Std3Mapped, 26, 1), (0x3001, Valid, 0, 0), (0x3002, Mapped, 3729, 1),
+    (0x3003, Valid, 0, 0), (0x3036, Mapped, 3730, 3), (0x3037, Valid, 0, 0), (0x3038, Mapped, 3156, 3),
+    (0x3039, Mapped, 3733, 3), (0x303A, Mapped, 3736, 3), (0x303B, Valid, 0, 0), (0x3040, Disallowed, 0, 0),
+    (0x3041, Valid, 0, 0), (0x3097, Disallowed, 0, 0), (0x3099, Valid, 0, 0), (0x309B, Std3Mapped, 3739, 4),
+    (0x309C, Std3Mapped, 3743, 4), (0x309D, Valid, 0, 0), (0x309F, Mapped, 3747, 6), (0x30A0, Valid, 0, 0),
+    (0x30FF, Mapped, 3753, 6), (0x3100, Disallowed, 0, 0), (0x3105, Valid, 0, 0), (0x3130, Disallowed, 0, 0),
+    (0x3131, Mapped, 3759, 3), (0x3132, Mapped, 3762, 3), (0x3133, Mapped, 3765, 3), (0x3134, Mapped, 3768, 3),
+    (0x3135, Mapped, 3771, 3), (0x3136, Mapped, 3774, 3), (0x3137, Mapped, 3777, 3), (0x3138, Mapped, 3780, 3),
+    (0x3139, Mapped, 3783, 3), (0x313A, Mapped, 3786, 3), (0x313B, Mapped, 3789, 3), (0x313C, Mapped, 3792, 3),
+    (0x313D, Mapped, 3795, 3), (0x313E, Mapped, 3798, 3), (0x313F, Mapped, 3801, 3), (0x3140, Mapped, 3804, 3),
+    (0x3141, Mapped, 3807, 3), (0x3142, Mapped, 3810, 3), (0x3143, Mapped, 3813, 3), (0x3144, Mapped, 3816, 3),
+    (0x3145, Mapped, 3819, 3), (0x3146, Mapped, 3822, 3), (0x3147, Mapped, 3825, 3), (0x3148, Mapped, 3828, 3),
+    (0x3149, Mapped, 3831, 3), (0x314A, Mapped, 3834, 3), (0x314B, Mapped, 3837, 3), (0x314C, Mapped, 3840, 3),
+    (0x314D, Mapped, 3843, 3), (0x314E, Mapped, 3846, 3), (0x314F, Mapped, 3849, 3), (0x3150, Mapped, 3852, 3),
+    (0x3151, Mapped, 3855, 3), (0x3152, Mapped, 3858, 3), (0x3153, Mapped, 3861, 3), (0x3154, Mapped, 3864, 3),
+    (0x3155, Mapped, 3867, 3), (0x3156, Mapped, 3870, 3), (0x3157, Mapped, 3873, 3), (0x3158, Mapped, 3876, 3),
+    (0x3159, Mapped, 3879, 3), (0x315A, Mapped, 3882, 3), (0x315B, Mapped, 3885, 3), (0x315C, Mapped, 3888, 3),
+    (0x315D, Mapped, 3891, 3), (0x315E, Mapped, 3894, 3), (0x315F, Mapped, 3897, 3), (0x3160, Mapped, 3900, 3),
+    (0x3161, Mapped, 3903, 3), (0x3162, Mapped, 3906, 3), (0x3163, Mapped, 3909, 3), (0x3164, Disallowed, 0, 0),
+    (0x3165, Mapped, 3912, 3), (0x3166, Mapped, 3915, 3), (0x3167, Mapped, 3918, 3), (0x3168, Mapped, 3921, 3),
+    (0x3169, Mapped, 3924, 3), (0x316A, Mapped, 3927, 3), (0x316B, Mapped, 3930, 3), (0x316C, Mapped, 3933, 3),
+    (0x316D, Mapped, 3936, 3), (0x316E, Mapped, 3939, 3), (0x316F, Mapped, 3942, 3), (0x3170, Mapped, 3945, 3),
+    (0x3171, Mapped, 3948, 3), (0x3172, Mapped, 3951, 3), (0x3173, Mapped, 3954, 3), (0x3174, Mapped, 3957, 3),
+    (0x3175, Mapped, 3960, 3), (0x3176, Mapped, 3963, 3), (0x3177, Mapped, 3966, 3), (0x3178, Mapped, 3969, 3),
+    (0x3179, Mapped, 3972, 3), (0x317A, Mapped, 3975, 3), (0x317B, Mapped, 3978, 3), (0x317C, Mapped, 3981, 3),
+    (0x317D, Mapped, 3984, 3), (0x317E, Mapped, 3987, 3), (0x317F, Mapped, 3990, 3), (0x3180, Mapped, 3993, 3),
+    (0x3181, Mapped, 3996, 3), (0x3182, Mapped, 3999, 3), (0x3183, Mapped, 4002, 3), (0x3184, Mapped, 4005, 3),
+    (0x3185, Mapped, 4008, 3), (0x3186, Mapped, 4011, 3), (0x3187, Mapped, 4014, 3), (0x3188, Mapped, 4017, 3),
+    (0x3189, Mapped, 4020, 3), (0x318A, Mapped, 4023, 3), (0x318B, Mapped, 4026, 3), (0x318C, Mapped, 4029, 3),
+    (0x318D, Mapped, 4032, 3), (0x318E, Mapped, 4035, 3), (0x318F, Disallowed, 0, 0), (0x3190, Valid, 0, 0),
+    (0x3192, Mapped, 3087, 3), (0x3193, Mapped, 3105, 3), (0x3194, Mapped, 4038, 3), (0x3195, Mapped, 4041, 3),
+    (0x3196, Mapped, 4044, 3), (0x3197, Mapped, 4047, 3), (0x3198, Mapped, 4050, 3), (0x3199, Mapped, 4053, 3),
+    (0x319A, Mapped, 3099, 3), (0x319B, Mapped, 4056, 3), (0x319C, Mapped, 4059, 3), (0x319D, Mapped, 4062, 3),
+    (0x319E, Mapped, 4065, 3), (0x319F, Mapped, 3111, 3), (0x31A0, Valid, 0, 0), (0x31E4, Disallowed, 0, 0),
+    (0x31F0, Valid, 0, 0), (0x3200, Std3Mapped, 4068, 5), (0x3201, Std3Mapped, 4073, 5), (0x3202, Std3Mapped, 4078, 5),
+    (0x3203, Std3Mapped, 4083, 5), (0x3204, Std3Mapped, 4088, 5), (0x3205, Std3Mapped, 4093, 5), (0x3206, Std3Mapped, 4098, 5),
+    (0x3207, Std3Mapped, 4103, 5), (0x3208, Std3Mapped, 4108, 5), (0x3209, Std3Mapped, 4113, 5), (0x320A, Std3Mapped, 4118, 5),
+    (0x320B, Std3Mapped, 4123, 5), (0x320C, Std3Mapped, 4128, 5), (0x320D, Std3Mapped, 4133, 5), (0x320E, Std3Mapped, 4138, 5),
+    (0x320F, Std3Mapped, 4143, 5), (0x3210, Std3Mapped, 4148, 5), (0x3211, Std3Mapped, 4153, 5), (0x3212, Std3Mapped, 4158, 5),
+    (0x3213, Std3Mapped, 4163, 5), (0x3214, Std3Mapped, 4168, 5), (0x3215, Std3Mapped, 4173, 5), (0x3216, Std3Mapped, 4178, 5),
+    (0x3217, Std3Mapped, 4183, 5), (0x3218, Std3Mapped, 4188, 5), (0x3219, Std3Mapped, 4193, 5), (0x321A, Std3Mapped, 4198, 5),
+    (0x321B, Std3Mapped, 4203, 5), (0x321C, Std3Mapped, 4208, 5), (0x321D, Std3Mapped, 4213, 8), (0x321E, Std3Mapped, 4221, 8),
+    (0x321F, Disallowed, 0, 0), (0x3220, Std3Mapped, 4229, 5), (0x3221, Std3Mapped, 4234, 5), (0x3222, Std3Mapped, 4239, 5),
+    (0x3223, Std3Mapped, 4244, 5), (0x3224, Std3Mapped, 4249, 5), (0x3225, Std3Mapped, 4254, 5), (0x3226, Std3Mapped, 4259, 5),
+    (0x3227, Std3Mapped, 4264, 5), (0x3228, Std3Mapped, 4269, 5), (0x3229, Std3Mapped, 4274, 5), (0x322A, Std3Mapped, 4279, 5),
+    (0x322B, Std3Mapped, 4284, 5), (0x322C, Std3Mapped, 4289, 5), (0x322D, Std3Mapped, 4294, 5), (0x322E, Std3Mapped, 4299, 5),
+    (0x322F, Std3Mapped, 4304, 5), (0x3230, Std3Mapped, 4309, 5), (0x3231, Std3Mapped, 4314, 5), (0x3232, Std3Mapped, 4319, 5),
+    (0x3233, Std3Mapped, 4324, 5), (0x3234, Std3Mapped, 4329, 5), (0x3235, Std3Mapped, 4334, 5), (0x3236, Std3Mapped, 4339, 5),
+    (0x3237, Std3Mapped, 4344, 5), (0x3238, Std3Mapped, 4349, 5), (0x3239, Std3Mapped, 4354, 5), (0x323A, Std3Mapped, 4359, 5),
+    (0x323B, Std3Mapped, 4364, 5), (0x323C, Std3Mapped, 4369, 5), (0x323D, Std3Mapped, 4374, 5), (0x323E, Std3Mapped, 4379, 5),
+    (0x323F, Std3Mapped, 4384, 5), (0x3240, Std3Mapped, 4389, 5), (0x3241, Std3Mapped, 4394, 5), (0x3242, Std3Mapped, 4399, 5),
+    (0x3243, Std3Mapped, 4404, 5), (0x3244, Mapped, 4409, 3), (0x3245, Mapped, 4412, 3), (0x3246, Mapped, 3285, 3),
+    (0x3247, Mapped, 4415, 3), (0x3248, Valid, 0, 0), (0x3250, Mapped, 4418, 3), (0x3251, Mapped, 4421, 2),
+    (0x3252, Mapped, 4423, 2), (0x3253, Mapped, 4425, 2), (0x3254, Mapped, 4427, 2), (0x3255, Mapped, 4429, 2),
+    (0x3256, Mapped, 4431, 2), (0x3257, Mapped, 4433, 2), (0x3258, Mapped, 4435, 2), (0x3259, Mapped, 4437, 2),
+    (0x325A, Mapped, 4439, 2), (0x325B, Mapped, 4441, 2), (0x325C, Mapped, 4443, 2), (0x325D, Mapped, 4445, 2),
+    (0x325E, Mapped, 4447, 2), (0x325F, Mapped, 4449, 2), (0x3260, Mapped, 3759, 3), (0x3261, Mapped, 3768, 3),
+    (0x3262, Mapped, 3777, 3), (0x3263, Mapped, 3783, 3), (0x3264, Mapped, 3807, 3), (0x3265, Mapped, 3810, 3),
+    (0x3266, Mapped, 3819, 3), (0x3267, Mapped, 3825, 3), (0x3268, Mapped, 3828, 3), (0x3269, Mapped, 3834, 3),
+    (0x326A, Mapped, 3837, 3), (0x326B, Mapped, 3840, 3), (0x326C, Mapped, 3843, 3), (0x326D, Mapped, 3846, 3),
+    (0x326E, Mapped, 4451, 3), (0x326F, Mapped, 4454, 3), (0x3270, Mapped, 4457, 3), (0x3271, Mapped, 4460, 3),
+    (0x3272, Mapped, 4463, 3), (0x3273, Mapped, 4466, 3), (0x3274, Mapped, 4469, 3), (0x3275, Mapped, 4472, 3),
+    (0x3276, Mapped, 4475, 3), (0x3277, Mapped, 4478, 3), (0x3278, Mapped, 4481, 3), (0x3279, Mapped, 4484, 3),
+    (0x327A, Mapped, 4487, 3), (0x327B, Mapped, 4490, 3), (0x327C, Mapped, 4493, 6), (0x327D, Mapped, 4499, 6),
+    (0x327E, Mapped, 4505, 3), (0x327F, Valid, 0, 0), (0x3280, Mapped, 3087, 3), (0x3281, Mapped, 3105, 3),
+    (0x3282, Mapped, 4038, 3), (0x3283, Mapped, 4041, 3), (0x3284, Mapped, 4508, 3), (0x3285, Mapped, 4511, 3),
+    (0x3286, Mapped, 4514, 3), (0x3287, Mapped, 3120, 3), (0x3288, Mapped, 4517, 3), (0x3289, Mapped, 3156, 3),
+    (0x328A, Mapped, 3306, 3), (0x328B, Mapped, 3342, 3), (0x328C, Mapped, 3339, 3), (0x328D, Mapped, 3309, 3),
+    (0x328E, Mapped, 3585, 3), (0x328F, Mapped, 3180, 3), (0x3290, Mapped, 3300, 3), (0x3291, Mapped, 4520, 3),
+    (0x3292, Mapped, 4523, 3), (0x3293, Mapped, 4526, 3), (0x3294, Mapped, 4529, 3), (0x3295, Mapped, 4532, 3),
+    (0x3296, Mapped, 4535, 3), (0x3297, Mapped, 4538, 3), (0x3298, Mapped, 4541, 3), (0x3299, Mapped, 4544, 3),
+    (0x329A, Mapped, 4547, 3), (0x329B, Mapped, 3198, 3), (0x329C, Mapped, 4550, 3), (0x329D, Mapped, 4553, 3),
+    (0x329E, Mapped, 4556, 3), (0x329F, Mapped, 4559, 3), (0x32A0, Mapped, 4562, 3), (0x32A1, Mapped, 4565, 3),
+    (0x32A2, Mapped, 4568, 3), (0x32A3, Mapped, 4571, 3), (0x32A4, Mapped, 4044, 3), (0x32A5, Mapped, 4047, 3),
+    (0x32A6, Mapped, 4050, 3), (0x32A7, Mapped, 4574, 3), (0x32A8, Mapped, 4577, 3), (0x32A9, Mapped, 4580, 3),
+    (0x32AA, Mapped, 4583, 3), (0x32AB, Mapped, 4586, 3), (0x32AC, Mapped, 4589, 3), (0x32AD, Mapped, 4592, 3),
+    (0x32AE, Mapped, 4595, 3), (0x32AF, Mapped, 4598, 3), (0x32B0, Mapped, 4601, 3), (0x32B1, Mapped, 4604, 2),
+    (0x32B2, Mapped, 4606, 2), (0x32B3, Mapped, 4608, 2), (0x32B4, Mapped, 4610, 2), (0x32B5, Mapped, 4612, 2),
+    (0x32B6, Mapped, 4614, 2), (0x32B7, Mapped, 4616, 2), (0x32B8, Mapped, 4618, 2), (0x32B9, Mapped, 4620, 2),
+    (0x32BA, Mapped, 4622, 2), (0x32BB, Mapped, 4624, 2), (0x32BC, Mapped, 4626, 2), (0x32BD, Mapped, 4628, 2),
+    (0x32BE, Mapped, 4630, 2), (0x32BF, Mapped, 4632, 2), (0x32C0, Mapped, 4634, 4), (0x32C1, Mapped, 4638, 4),
+    (0x32C2, Mapped, 4642, 4), (0x32C3, Mapped, 4646, 4), (0x32C4, Mapped, 4650, 4), (0x32C5, Mapped, 4654, 4),
+    (0x32C6, Mapped, 4658, 4), (0x32C7, Mapped, 4662, 4), (0x32C8, Mapped, 4666, 4), (0x32C9, Mapped, 4670, 5),
+    (0x32CA, Mapped, 4675, 5), (0x32CB, Mapped, 4680, 5), (0x32CC, Mapped, 4685, 2), (0x32CD, Mapped, 4687, 3),
+    (0x32CE, Mapped, 4690, 2), (0x32CF, Mapped, 4692, 3), (0x32D0, Mapped, 4695, 3), (0x32D1, Mapped, 4698, 3),
+    (0x32D2, Mapped, 4701, 3), (0x32D3, Mapped, 4704, 3), (0x32D4, Mapped, 4707, 3), (0x32D5, Mapped, 4710, 3),
+    (0x32D6, Mapped, 4713, 3), (0x32D7, Mapped, 4716, 3), (0x32D8, Mapped, 4719, 3), (0x32D9, Mapped, 4722, 3),
+    (0x32DA, Mapped, 4725, 3), (0x32DB, Mapped, 4728, 3), (0x32DC, Mapped, 4731, 3), (0x32DD, Mapped, 4734, 3),
+    (0x32DE, Mapped, 4737, 3), (0x32DF, Mapped, 4740, 3), (0x32E0, Mapped, 4743, 3), (0x32E1, Mapped, 4746, 3),
+    (0x32E2, Mapped, 4749, 3), (0x32E3, Mapped, 4752, 3), (0x32E4, Mapped, 4755, 3), (0x32E5, Mapped, 4758, 3),
+    (0x32E6, Mapped, 4761, 3), (0x32E7, Mapped, 4764, 3), (0x32E8, Mapped, 4767, 3), (0x32E9, Mapped, 4770, 3),
+    (0x32EA, Mapped, 4773, 3), (0x32EB, Mapped, 4776, 3), (0x32EC, Mapped, 4779, 3), (0x32ED, Mapped, 4782, 3),
+    (0x32EE, Mapped, 4785, 3), (0x32EF, Mapped, 4788, 3), (0x32F0, Mapped, 4791, 3), (0x32F1, Mapped, 4794, 3),
+    (0x32F2, Mapped, 4797, 3), (0x32F3, Mapped, 4800, 3), (0x32F4, Mapped, 4803, 3), (0x32F5, Mapped, 4806, 3),
+    (0x32F6, Mapped, 4809, 3), (0x32F7, Mapped, 4812, 3), (0x32F8, Mapped, 4815, 3), (0x32F9, Mapped, 4818, 3),
+    (0x32FA, Mapped, 4821, 3), (0x32FB, Mapped, 4824, 3), (0x32FC, Mapped, 4827, 3), (0x32FD, Mapped, 4830, 3),
+    (0x32FE, Mapped, 4833, 3), (0x32FF, Mapped, 4836, 6), (0x3300, Mapped, 4842, 12), (0x3301, Mapped, 4854, 12),
+    (0x3302, Mapped, 4866, 12), (0x3303, Mapped, 4878, 9), (0x3304, Mapped, 4887, 12), (0x3305, Mapped, 4899, 9),
+    (0x3306, Mapped, 4908, 9), (0x3307, Mapped, 4917, 15), (0x3308, Mapped, 4932, 12), (0x3309, Mapped, 4944, 9),
+    (0x330A, Mapped, 4953, 9), (0x330B, Mapped, 4962, 9), (0x330C, Mapped, 4971, 12), (0x330D, Mapped, 4983, 12),
+    (0x330E, Mapped, 4995, 9), (0x330F, Mapped, 5004, 9), (0x3310, Mapped, 5013, 6), (0x3311, Mapped, 5019, 9),
+    (0x3312, Mapped, 5028, 12), (0x3313, Mapped, 5040, 12), (0x3314, Mapped, 5052, 6), (0x3315, Mapped, 5058, 15),
+    (0x3316, Mapped, 5073, 18), (0x3317, Mapped, 5091, 15), (0x3318, Mapped, 5106, 9), (0x3319, Mapped, 5115, 15),
+    (0x331A, Mapped, 5130, 15), (0x331B, Mapped, 5145, 12), (0x331C, Mapped, 5157, 9), (0x331D, Mapped, 5166, 9),
+    (0x331E, Mapped, 5175, 9), (0x331F, Mapped, 5184, 12), (0x3320, Mapped, 5196, 15), (0x3321, Mapped, 5211, 12),
+    (0x3322, Mapped, 5223, 9), (0x3323, Mapped, 5232, 9), (0x3324, Mapped, 5241, 9), (0x3325, Mapped, 5250, 6),
+    (0x3326, Mapped, 5256, 6), (0x3327, Mapped, 5262, 6), (0x3328, Mapped, 5268, 6), (0x3329, Mapped, 5274, 9),
+    (0x332A, Mapped, 5283, 9), (0x332B, Mapped, 5292, 15), (0x332C, Mapped, 5307, 9), (0x332D, Mapped, 5316, 12),
+    (0x332E, Mapped, 5328, 15), (0x332F, Mapped, 5343, 9), (0x3330, Mapped, 5352, 6), (0x3331, Mapped, 5358, 6),
+    (0x3332, Mapped, 5364, 15), (0x3333, Mapped, 5379, 12), (0x3334, Mapped, 5391, 15), (0x3335, Mapped, 5406, 9),
+    (0x3336, Mapped, 5415, 15), (0x3337, Mapped, 5430, 6), (0x3338, Mapped, 5436, 9), (0x3339, Mapped, 5445, 9),
+    (0x333A, Mapped, 5454, 9), (0x333B, Mapped, 5463, 9), (0x333C, Mapped, 5472, 9), (0x333D, Mapped, 5481, 12),
+    (0x333E, Mapped, 5493, 9), (0x333F, Mapped, 5502, 6), (0x3340, Mapped, 5508, 9), (0x3341, Mapped, 5517, 9),
+    (0x3342, Mapped, 5526, 9), (0x3343, Mapped, 5535, 12), (0x3344, Mapped, 5547, 9), (0x3345, Mapped, 5556, 9),
+    (0x3346, Mapped, 5565, 9), (0x3347, Mapped, 5574, 15), (0x3348, Mapped, 5589, 12), (0x3349, Mapped, 5601, 6),
+    (0x334A, Mapped, 5607, 15), (0x334B, Mapped, 5622, 6), (0x334C, Mapped, 5628, 12), (0x334D, Mapped, 5640, 12),
+    (0x334E, Mapped, 5652, 9), (0x334F, Mapped, 5661, 9), (0x3350, Mapped, 5670, 9), (0x3351, Mapped, 5679, 12),
+    (0x3352, Mapped, 5691, 6), (0x3353, Mapped, 5697, 9), (0x3354, Mapped, 5706, 12), (0x3355, Mapped, 5718, 6),
+    (0x3356, Mapped, 5724, 15), (0x3357, Mapped, 5739, 9), (0x3358, Mapped, 5748, 4), (0x3359, Mapped, 5752, 4),
+    (0x335A, Mapped, 5756, 4), (0x335B, Mapped, 5760, 4), (0x335C, Mapped, 5764, 4), (0x335D, Mapped, 5768, 4),
+    (0x335E, Mapped, 5772, 4), (0x335F, Mapped, 5776, 4), (0x3360, Mapped, 5780, 4), (0x3361, Mapped, 5784, 4),
+    (0x3362, Mapped, 5788, 5), (0x3363, Mapped, 5793, 5), (0x3364, Mapped, 5798, 5), (0x3365, Mapped, 5803, 5),
+    (0x3366, Mapped, 5808, 5), (0x3367, Mapped, 5813, 5), (0x3368, Mapped, 5818, 5), (0x3369, Mapped, 5823, 5),
+    (0x336A, Mapped, 5828, 5), (0x336B, Mapped, 5833, 5), (0x336C, Mapped, 5838, 5), (0x336D, Mapped, 5843, 5),
+    (0x336E, Mapped, 5848, 5), (0x336F, Mapped, 5853, 5), (0x3370, Mapped, 5858, 5), (0x3371, Mapped, 5863, 3),
+    (0x3372, Mapped, 5866, 2), (0x3373, Mapped, 5868, 2), (0x3374, Mapped, 5870, 3), (0x3375, Mapped, 5873, 2),
+    (0x3376, Mapped, 5875, 2), (0x3377, Mapped, 5877, 2), (0x3378, Mapped, 5879, 3), (0x3379, Mapped, 5882, 3),
+    (0x337A, Mapped, 5885, 2), (0x337B, Mapped, 5887, 6), (0x337C, Mapped, 5893, 6), (0x337D, Mapped, 5899, 6),
+    (0x337E, Mapped, 5905, 6), (0x337F, Mapped, 5911, 12), (0x3380, Mapped, 5923, 2), (0x3381, Mapped, 5925, 2),
+    (0x3382, Mapped, 5927, 3), (0x3383, Mapped, 5930, 2), (0x3384, Mapped, 5932, 2), (0x3385, Mapped, 5934, 2),
+    (0x3386, Mapped, 5936, 2), (0x3387, Mapped, 5938, 2), (0x3388, Mapped, 5940, 3), (0x3389, Mapped, 5943, 4),
+    (0x338A, Mapped, 5947, 2), (0x338B, Mapped, 5949, 2), (0x338C, Mapped, 5951, 3), (0x338D, Mapped, 5954, 3),
+    (0x338E, Mapped, 5957, 2), (0x338F, Mapped, 5959, 2), (0x3390, Mapped, 5961, 2), (0x3391, Mapped, 5963, 3),
+    (0x3392, Mapped, 5966, 3), (0x3393, Mapped, 5969, 3), (0x3394, Mapped, 5972, 3), (0x3395, Mapped, 5975, 3),
+    (0x3396, Mapped, 5978, 2), (0x3397, Mapped, 5980, 2), (0x3398, Mapped, 5982, 2), (0x3399, Mapped, 5984, 2),
+    (0x339A, Mapped, 5986, 2), (0x339B, Mapped, 5988, 3), (0x339C, Mapped, 5991, 2), (0x339D, Mapped, 5993, 2),
+    (0x339E, Mapped, 5995, 2), (0x339F, Mapped, 5997, 3), (0x33A0, Mapped, 6000, 3), (0x33A1, Mapped, 6003, 2),
+    (0x33A2, Mapped, 6005, 3), (0x33A3, Mapped, 6008, 3), (0x33A4, Mapped, 6011, 3), (0x33A5, Mapped, 6014, 2),
+    (0x33A6, Mapped, 6016, 3), (0x33A7, Mapped, 6019, 5), (0x33A8, Mapped, 6024, 6), (0x33A9, Mapped, 5923, 2),
+    (0x33AA, Mapped, 6030, 3), (0x33AB, Mapped, 6033, 3), (0x33AC, Mapped, 6036, 3), (0x33AD, Mapped, 6039, 3),
+    (0x33AE, Mapped, 6042, 7), (0x33AF, Mapped, 6049, 8), (0x33B0, Mapped, 6057, 2), (0x33B1, Mapped, 6059, 2),
+    (0x33B2, Mapped, 6061, 3), (0x33B3, Mapped, 6064, 2), (0x33B4, Mapped, 6066, 2), (0x33B5, Mapped, 6068, 2),
+    (0x33B6, Mapped, 6070, 3), (0x33B7, Mapped, 6073, 2), (0x33B8, Mapped, 6075, 2), (0x33B9, Mapped, 6073, 2),
+    (0x33BA, Mapped, 6077, 2), (0x33BB, Mapped, 6079, 2), (0x33BC, Mapped, 6081, 3), (0x33BD, Mapped, 6084, 2),
+    (0x33BE, Mapped, 6086, 2), (0x33BF, Mapped, 6084, 2), (0x33C0, Mapped, 6088, 3), (0x33C1, Mapped, 6091, 3),
+    (0x33C2, Disallowed, 0, 0), (0x33C3, Mapped, 6094, 2), (0x33C4, Mapped, 6096, 2), (0x33C5, Mapped, 6098, 2),
+    (0x33C6, Mapped, 6100, 6), (0x33C7, Disallowed, 0, 0), (0x33C8, Mapped, 6106, 2), (0x33C9, Mapped, 6108, 2),
+    (0x33CA, Mapped, 6110, 2), (0x33CB, Mapped, 6112, 2), (0x33CC, Mapped, 6114, 2), (0x33CD, Mapped, 6116, 2),
+    (0x33CE, Mapped, 5995, 2), (0x33CF, Mapped, 6118, 2), (0x33D0, Mapped, 6120, 2), (0x33D1, Mapped, 6122, 2),
+    (0x33D2, Mapped, 6124, 3), (0x33D3, Mapped, 6127, 2), (0x33D4, Mapped, 5936, 2), (0x33D5, Mapped, 6129, 3),
+    (0x33D6, Mapped, 6132, 3), (0x33D7, Mapped, 6135, 2), (0x33D8, Disallowed, 0, 0), (0x33D9, Mapped, 6137, 3),
+    (0x33DA, Mapped, 6140, 2), (0x33DB, Mapped, 6142, 2), (0x33DC, Mapped, 6144, 2), (0x33DD, Mapped, 6146, 2),
+    (0x33DE, Mapped, 6148, 5), (0x33DF, Mapped, 6153, 5), (0x33E0, Mapped, 6158, 4), (0x33E1, Mapped, 6162, 4),
+    (0x33E2, Mapped, 6166, 4), (0x33E3, Mapped, 6170, 4), (0x33E4, Mapped, 6174, 4), (0x33E5, Mapped, 6178, 4),
+    (0x33E6, Mapped, 6182, 4), (0x33E7, Mapped, 6186, 4), (0x33E8, Mapped, 6190, 4), (0x33E9, Mapped, 6194, 5),
+    (0x33EA, Mapped, 6199, 5), (0x33EB, Mapped, 6204, 5), (0x33EC, Mapped, 6209, 5), (0x33ED, Mapped, 6214, 5),
+    (0x33EE, Mapped, 6219, 5), (0x33EF, Mapped, 6224, 5), (0x33F0, Mapped, 6229, 5), (0x33F1, Mapped, 6234, 5),
+    (0x33F2, Mapped, 6239, 5), (0x33F3, Mapped, 6244, 5), (0x33F4, Mapped, 6249, 5), (0x33F5, Mapped, 6254, 5),
+    (0x33F6, Mapped, 6259, 5), (0x33F7, Mapped, 6264, 5), (0x33F8, Mapped, 6269, 5), (0x33F9, Mapped, 6274, 5),
+    (0x33FA, Mapped, 6279, 5), (0x33FB, Mapped, 6284, 5), (0x33FC, Mapped, 6289, 5), (0x33FD, Mapped, 6294, 5),
+    (0x33FE, Mapped, 6299, 5), (0x33FF, Mapped, 6304, 3), (0x3400, Valid, 0, 0), (0xA48D, Disallowed, 0, 0),
+    (0xA490, Valid, 0, 0), (0xA4C7, Disallowed, 0, 0), (0xA4D0, Valid, 0, 0), (0xA62C, Disallowed, 0, 0),
+    (0xA640, Mapped, 6307, 3), (0xA641, Valid, 0, 0), (0xA642, Mapped, 6310, 3), (0xA643, Valid, 0, 0),
+    (0xA644, Mapped, 6313, 3), (0xA645, Valid, 0, 0), (0xA646, Mapped, 6316, 3), (0xA647, Valid, 0, 0),
+    (0xA648, Mapped, 6319, 3), (0xA649, Valid, 0, 0), (0xA64A, Mapped, 1298, 3), (0xA64B, Valid, 0, 0),
+    (0xA64C, Mapped, 6322, 3), (0xA64D, Valid, 0, 0), (0xA64E, Mapped, 6325, 3), (0xA64F, Valid, 0, 0),
+    (0xA650, Mapped, 6328, 3), (0xA651, Valid, 0, 0), (0xA652, Mapped, 6331, 3), (0xA653, Valid, 0, 0),
+    (0xA654, Mapped, 6334, 3), (0xA655, Valid, 0, 0), (0xA656, Mapped, 6337, 3), (0xA657, Valid, 0, 0),
+    (0xA658, Mapped, 6340, 3), (0xA659, Valid, 0, 0), (0xA65A, Mapped, 6343, 3), (0xA65B, Valid, 0, 0),
+    (0xA65C, Mapped, 6346, 3), (0xA65D, Valid, 0, 0), (0xA65E, Mapped, 6349, 3), (0xA65F, Valid, 0, 0),
+    (0xA660, Mapped, 6352, 3), (0xA661, Valid, 0, 0), (0xA662, Mapped, 6355, 3), (0xA663, Valid, 0, 0),
+    (0xA664, Mapped, 6358, 3), (0xA665, Valid, 0, 0), (0xA666, Mapped, 6361, 3), (0xA667, Valid, 0, 0),
+    (0xA668, Mapped, 6364, 3), (0xA669, Valid, 0, 0), (0xA66A, Mapped, 6367, 3), (0xA66B, Valid, 0, 0),
+    (0xA66C, Mapped, 6370, 3), (0xA66D, Valid, 0, 0), (0xA680, Mapped, 6373, 3), (0xA681, Valid, 0, 0),
+    (0xA682, Mapped, 6376, 3), (0xA683, Valid, 0, 0), (0xA684, Mapped, 6379, 3), (0xA685, Valid, 0, 0),
+    (0xA686, Mapped, 6382, 3), (0xA687, Valid, 0, 0), (0xA688, Mapped, 6385, 3), (0xA689, Valid, 0, 0),
+    (0xA68A, Mapped, 6388, 3), (0xA68B, Valid, 0, 0), (0xA68C, Mapped, 6391, 3), (0xA68D, Valid, 0, 0),
+    (0xA68E, Mapped, 6394, 3), (0xA68F, Valid, 0, 0), (0xA690, Mapped, 6397, 3), (0xA691, Valid, 0, 0),
+    (0xA692, Mapped, 6400, 3), (0xA693, Valid, 0, 0), (0xA694, Mapped, 6403, 3), (0xA695, Valid, 0, 0),
+    (0xA696, Mapped, 6406, 3), (0xA697, Valid, 0, 0), (0xA698, Mapped, 6409, 3), (0xA699, Valid, 0, 0),
+    (0xA69A, Mapped, 6412, 3), (0xA69B, Valid, 0, 0), (0xA69C, Mapped, 704, 2), (0xA69D, Mapped, 708, 2),
+    (0xA69E, Valid, 0, 0), (0xA6F8, Disallowed, 0, 0), (0xA700, Valid, 0, 0), (0xA722, Mapped, 6415, 3),
+    (0xA723, Valid, 0, 0), (0xA724, Mapped, 6418, 3), (0xA725, Valid, 0, 0), (0xA726, Mapped, 6421, 3),
+    (0xA727, Valid, 0, 0), (0xA728, Mapped, 6424, 3), (0xA729, Valid, 0, 0), (0xA72A, Mapped, 6427, 3),
+    (0xA72B, Valid, 0, 0), (0xA72C, Mapped, 6430, 3), (0xA72D, Valid, 0, 0), (0xA72E, Mapped, 6433, 3),
+    (0xA72F, Valid, 0, 0), (0xA732, Mapped, 6436, 3), (0xA733, Valid, 0, 0), (0xA734, Mapped, 6439, 3),
+    (0xA735, Valid, 0, 0), (0xA736, Mapped, 6442, 3), (0xA737, Valid, 0, 0), (0xA738, Mapped, 6445, 3),
+    (0xA739, Valid, 0, 0), (0xA73A, Mapped, 6448, 3), (0xA73B, Valid, 0, 0), (0xA73C, Mapped, 6451, 3),
+    (0xA73D, Valid, 0, 0), (0xA73E, Mapped, 6454, 3), (0xA73F, Valid, 0, 0), (0xA740, Mapped, 6457, 3),
+    (0xA741, Valid, 0, 0), (0xA742, Mapped, 6460, 3), (0xA743, Valid, 0, 0), (0xA744, Mapped, 6463, 3),
+    (0xA745, Valid, 0, 0), (0xA746, Mapped, 6466, 3), (0xA747, Valid, 0, 0), (0xA748, Mapped, 6469, 3),
+    (0xA749, Valid, 0, 0), (0xA74A, Mapped, 6472, 3), (0xA74B, Valid, 0, 0), (0xA74C, Mapped, 6475, 3),
+    (0xA74D, Valid, 0, 0), (0xA74E, Mapped, 6478, 3), (0xA74F, Valid, 0, 0), (0xA750, Mapped, 6481, 3),
+    (0xA751, Valid, 0, 0), (0xA752, Mapped, 6484, 3), (0xA753, Valid, 0, 0), (0xA754, Mapped, 6487, 3),
+    (0xA755, Valid, 0, 0), (0xA756, Mapped, 6490, 3), (0xA757, Valid, 0, 0), (0xA758, Mapped, 6493, 3),
+    (0xA759, Valid, 0, 0), (0xA75A, Mapped, 6496, 3), (0xA75B, Valid, 0, 0), (0xA75C, Mapped, 6499, 3),
+    (0xA75D, Valid, 0, 0), (0xA75E, Mapped, 6502, 3), (0xA75F, Valid, 0, 0), (0xA760, Mapped, 6505, 3),
+    (0xA761, Valid, 0, 0), (0xA762, Mapped, 6508, 3), (0xA763, Valid, 0, 0), (0xA764, Mapped, 6511, 3),
+    (0xA765, Valid, 0, 0), (0xA766, Mapped, 6514, 3), (0xA767, Valid, 0, 0), (0xA768, Mapped, 6517, 3),
+    (0xA769, Valid, 0, 0), (0xA76A, Mapped, 6520, 3), (0xA76B, Valid, 0, 0), (0xA76C, Mapped, 6523, 3),
+    (0xA76D, Valid, 0, 0), (0xA76E, Mapped, 6526, 3), (0xA76F, Valid, 0, 0), (0xA770, Mapped, 6526, 3),
+    (0xA771, Valid, 0, 0), (0xA779, Mapped, 6529, 3), (0xA77A, Valid, 0, 0), (0xA77B, Mapped, 6532, 3),
+    (0xA77C, Valid, 0, 0), (0xA77D, Mapped, 6535, 3), (0xA77E, Mapped, 6538, 3), (0xA77F, Valid, 0, 0),
+    (0xA780, Mapped, 6541, 3), (0xA781, Valid, 0, 0), (0xA782, Mapped, 6544, 3), (0xA783, Valid, 0, 0),
+    (0xA784, Mapped, 6547, 3), (0xA785, Valid, 0, 0), (0xA786, Mapped, 6550, 3), (0xA787, Valid, 0, 0),
+    (0xA78B, Mapped, 6553, 3), (0xA78C, Valid, 0, 0), (0xA78D, Mapped, 1465, 2), (0xA78E, Valid, 0, 0),
+    (0xA790, Mapped, 6556, 3), (0xA791, Valid, 0, 0), (0xA792, Mapped, 6559, 3), (0xA793, Valid, 0, 0),
+    (0xA796, Mapped, 6562, 3), (0xA797, Valid, 0, 0), (0xA798, Mapped, 6565, 3), (0xA799, Valid, 0, 0),
+    (0xA79A, Mapped, 6568, 3), (0xA79B, Valid, 0, 0), (0xA79C, Mapped, 6571, 3), (0xA79D, Valid, 0, 0),
+    (0xA79E, Mapped, 6574, 3), (0xA79F, Valid, 0, 0), (0xA7A0, Mapped, 6577, 3), (0xA7A1, Valid, 0, 0),
+    (0xA7A2, Mapped, 6580, 3), (0xA7A3, Valid, 0, 0), (0xA7A4, Mapped, 6583, 3), (0xA7A5, Valid, 0, 0),
+    (0xA7A6, Mapped, 6586, 3), (0xA7A7, Valid, 0, 0), (0xA7A8, Mapped, 6589, 3), (0xA7A9, Valid, 0, 0),
+    (0xA7AA, Mapped, 461, 2), (0xA7AB, Mapped, 1443, 2), (0xA7AC, Mapped, 1463, 2), (0xA7AD, Mapped, 6592, 2),
+    (0xA7AE, Mapped, 1467, 2), (0xA7AF, Valid, 0, 0), (0xA7B0, Mapped, 6594, 2), (0xA7B1, Mapped, 6596, 2),
+    (0xA7B2, Mapped, 1472, 2), (0xA7B3, Mapped, 6598, 3), (0xA7B4, Mapped, 6601, 3), (0xA7B5, Valid, 0, 0),
+    (0xA7B6, Mapped, 6604, 3), (0xA7B7, Valid, 0, 0), (0xA7B8, Mapped, 6607, 3), (0xA7B9, Valid, 0, 0),
+    (0xA7BA, Mapped, 6610, 3), (0xA7BB, Valid, 0, 0), (0xA7BC, Mapped, 6613, 3), (0xA7BD, Valid, 0, 0),
+    (0xA7BE, Mapped, 6616, 3), (0xA7BF, Valid, 0, 0), (0xA7C0, Mapped, 6619, 3), (0xA7C1, Valid, 0, 0),
+    (0xA7C2, Mapped, 6622, 3), (0xA7C3, Valid, 0, 0), (0xA7C4, Mapped, 6625, 3), (0xA7C5, Mapped, 1491, 2),
+    (0xA7C6, Mapped, 6628, 3), (0xA7C7, Mapped, 6631, 3), (0xA7C8, Valid, 0, 0), (0xA7C9, Mapped, 6634, 3),
+    (0xA7CA, Valid, 0, 0), (0xA7CB, Disallowed, 0, 0), (0xA7D0, Mapped, 6637, 3), (0xA7D1, Valid, 0, 0),
+    (0xA7D2, Disallowed, 0, 0), (0xA7D3, Valid, 0, 0), (0xA7D4, Disallowed, 0, 0), (0xA7D5, Valid, 0, 0),
+    (0xA7D6, Mapped, 6640, 3), (0xA7D7, Valid, 0, 0), (0xA7D8, Mapped, 6643, 3), (0xA7D9, Valid, 0, 0),
+    (0xA7DA, Disallowed, 0, 0), (0xA7F2, Mapped, 2, 1), (0xA7F3, Mapped, 5, 1), (0xA7F4, Mapped, 16, 1),
+    (0xA7F5, Mapped, 6646, 3), (0xA7F6, Valid, 0, 0), (0xA7F8, Mapped, 159, 2), (0xA7F9, Mapped, 206, 2),
+    (0xA7FA, Valid, 0, 0), (0xA82D, Disallowed, 0, 0), (0xA830, Valid, 0, 0), (0xA83A, Disallowed, 0, 0),
+    (0xA840, Valid, 0, 0), (0xA878, Disallowed, 0, 0), (0xA880, Valid, 0, 0), (0xA8C6, Disallowed, 0, 0),
+    (0xA8CE, Valid, 0, 0), (0xA8DA, Disallowed, 0, 0), (0xA8E0, Valid, 0, 0), (0xA954, Disallowed, 0, 0),
+    (0xA95F, Valid, 0, 0), (0xA97D, Disallowed, 0, 0), (0xA980, Valid, 0, 0), (0xA9CE, Disallowed, 0, 0),
+    (0xA9CF, Valid, 0, 0), (0xA9DA, Disallowed, 0, 0), (0xA9DE, Valid, 0, 0), (0xA9FF, Disallowed, 0, 0),
+    (0xAA00, Valid, 0, 0), (0xAA37, Disallowed, 0, 0), (0xAA40, Valid, 0, 0), (0xAA4E, Disallowed, 0, 0),
+    (0xAA50, Valid, 0, 0), (0xAA5A, Disallowed, 0, 0), (0xAA5C, Valid, 0, 0), (0xAAC3, Disallowed, 0, 0),
+    (0xAADB, Valid, 0, 0), (0xAAF7, Disallowed, 0, 0), (0xAB01, Valid, 0, 0), (0xAB07, Disallowed, 0, 0),
+    (0xAB09, Valid, 0, 0), (0xAB0F, Disallowed, 0, 0), (0xAB11, Valid, 0, 0), (0xAB17, Disallowed, 0, 0),
+    (0xAB20, Valid, 0, 0), (0xAB27, Disallowed, 0, 0), (0xAB28, Valid, 0, 0), (0xAB2F, Disallowed, 0, 0),
+    (0xAB30, Valid, 0, 0), (0xAB5C, Mapped, 6421, 3), (0xAB5D, Mapped, 6649, 3), (0xAB5E, Mapped, 2893, 2),
+    (0xAB5F, Mapped, 6652, 3), (0xAB60, Valid, 0, 0), (0xAB69, Mapped, 6655, 2), (0xAB6A, Valid, 0, 0),
+    (0xAB6C, Disallowed, 0, 0), (0xAB70, Mapped, 6657, 3), (0xAB71, Mapped, 6660, 3), (0xAB72, Mapped, 6663, 3),
+    (0xAB73, Mapped, 6666, 3), (0xAB74, Mapped, 6669, 3), (0xAB75, Mapped, 6672, 3), (0xAB76, Mapped, 6675, 3),
+    (0xAB77, Mapped, 6678, 3), (0xAB78, Mapped, 6681, 3), (0xAB79, Mapped, 6684, 3), (0xAB7A, Mapped, 6687, 3),
+    (0xAB7B, Mapped, 6690, 3), (0xAB7C, Mapped, 6693, 3), (0xAB7D, Mapped, 6696, 3), (0xAB7E, Mapped, 6699, 3),
+    (0xAB7F, Mapped, 6702, 3), (0xAB80, Mapped, 6705, 3), (0xAB81, Mapped, 6708, 3), (0xAB82, Mapped, 6711, 3),
+    (0xAB83, Mapped, 6714, 3), (0xAB84, Mapped, 6717, 3), (0xAB85, Mapped, 6720, 3), (0xAB86, Mapped, 6723, 3),
+    (0xAB87, Mapped, 6726, 3), (0xAB88, Mapped, 6729, 3), (0xAB89, Mapped, 6732, 3), (0xAB8A, Mapped, 6735, 3),
+    (0xAB8B, Mapped, 6738, 3), (0xAB8C, Mapped, 6741, 3), (0xAB8D, Mapped, 6744, 3), (0xAB8E, Mapped, 6747, 3),
+    (0xAB8F, Mapped, 6750, 3), (0xAB90, Mapped, 6753, 3), (0xAB91, Mapped, 6756, 3), (0xAB92, Mapped, 6759, 3),
+    (0xAB93, Mapped, 6762, 3), (0xAB94, Mapped, 6765, 3), (0xAB95, Mapped, 6768, 3), (0xAB96, Mapped, 6771, 3),
+    (0xAB97, Mapped, 6774, 3), (0xAB98, Mapped, 6777, 3), (0xAB99, Mapped, 6780, 3), (0xAB9A, Mapped, 6783, 3),
+    (0xAB9B, Mapped, 6786, 3), (0xAB9C, Mapped, 6789, 3), (0xAB9D, Mapped, 6792, 3), (0xAB9E, Mapped, 6795, 3),
+    (0xAB9F, Mapped, 6798, 3), (0xABA0, Mapped, 6801, 3), (0xABA1, Mapped, 6804, 3), (0xABA2, Mapped, 6807, 3),
+    (0xABA3, Mapped, 6810, 3), (0xABA4, Mapped, 6813, 3), (0xABA5, Mapped, 6816, 3), (0xABA6, Mapped, 6819, 3),
+    (0xABA7, Mapped, 6822, 3), (0xABA8, Mapped, 6825, 3), (0xABA9, Mapped, 6828, 3), (0xABAA, Mapped, 6831, 3),
+    (0xABAB, Mapped, 6834, 3), (0xABAC, Mapped, 6837, 3), (0xABAD, Mapped, 6840, 3), (0xABAE, Mapped, 6843, 3),
+    (0xABAF, Mapped, 6846, 3), (0xABB0, Mapped, 6849, 3), (0xABB1, Mapped, 6852, 3), (0xABB2, Mapped, 6855, 3),
+    (0xABB3, Mapped, 6858, 3), (0xABB4, Mapped, 6861, 3), (0xABB5, Mapped, 6864, 3), (0xABB6, Mapped, 6867, 3),
+    (0xABB7, Mapped, 6870, 3), (0xABB8, Mapped, 6873, 3), (0xABB9, Mapped, 6876, 3), (0xABBA, Mapped, 6879, 3),
+    (0xABBB, Mapped, 6882, 3), (0xABBC, Mapped, 6885, 3), (0xABBD, Mapped, 6888, 3), (0xABBE, Mapped, 6891, 3),
+    (0xABBF, Mapped, 6894, 3), (0xABC0, Valid, 0, 0), (0xABEE, Disallowed, 0, 0), (0xABF0, Valid, 0, 0),
+    (0xABFA, Disallowed, 0, 0), (0xAC00, Valid, 0, 0), (0xD7A4, Disallowed, 0, 0), (0xD7B0, Valid, 0, 0),
+    (0xD7C7, Disallowed, 0, 0), (0xD7CB, Valid, 0, 0), (0xD7FC, Disallowed, 0, 0), (0xF900, Mapped, 6897, 3),
+    (0xF901, Mapped, 6900, 3), (0xF902, Mapped, 3561, 3), (0xF903, Mapped, 6903, 3), (0xF904, Mapped, 6906, 3),
+    (0xF905, Mapped, 6909, 3), (0xF906, Mapped, 6912, 3), (0xF907, Mapped, 3723, 3), (0xF909, Mapped, 6915, 3),
+    (0xF90A, Mapped, 3585, 3), (0xF90B, Mapped, 6918, 3), (0xF90C, Mapped, 6921, 3), (0xF90D, Mapped, 6924, 3),
+    (0xF90E, Mapped, 6927, 3), (0xF90F, Mapped, 6930, 3), (0xF910, Mapped, 6933, 3), (0xF911, Mapped, 6936, 3),
+    (0xF912, Mapped, 6939, 3), (0xF913, Mapped, 6942, 3), (0xF914, Mapped, 6945, 3), (0xF915, Mapped, 6948, 3),
+    (0xF916, Mapped, 6951, 3), (0xF917, Mapped, 6954, 3), (0xF918, Mapped, 6957, 3), (0xF919, Mapped, 6960, 3),
+    (0xF91A, Mapped, 6963, 3), (0xF91B, Mapped, 6966, 3), (0xF91C, Mapped, 6969, 3), (0xF91D, Mapped, 6972, 3),
+    (0xF91E, Mapped, 6975, 3), (0xF91F, Mapped, 6978, 3), (0xF920, Mapped, 6981, 3), (0xF921, Mapped, 6984, 3),
+    (0xF922, Mapped, 6987, 3), (0xF923, Mapped, 6990, 3), (0xF924, Mapped, 6993, 3), (0xF925, Mapped, 6996, 3),
+    (0xF926, Mapped, 6999, 3), (0xF927, Mapped, 7002, 3), (0xF928, Mapped, 7005, 3), (0xF929, Mapped, 7008, 3),
+    (0xF92A, Mapped, 7011, 3), (0xF92B, Mapped, 7014, 3), (0xF92C, Mapped, 7017, 3), (0xF92D, Mapped, 7020, 3),
+    (0xF92E, Mapped, 7023, 3), (0xF92F, Mapped, 7026, 3), (0xF930, Mapped, 7029, 3), (0xF931, Mapped, 7032, 3),
+    (0xF932, Mapped, 7035, 3), (0xF933, Mapped, 7038, 3), (0xF934, Mapped, 3459, 3), (0xF935, Mapped, 7041, 3),
+    (0xF936, Mapped, 7044, 3), (0xF937, Mapped, 7047, 3), (0xF938, Mapped, 7050, 3), (0xF939, Mapped, 7053, 3),
+    (0xF93A, Mapped, 7056, 3), (0xF93B, Mapped, 7059, 3), (0xF93C, Mapped, 7062, 3), (0xF93D, Mapped, 7065, 3),
+    (0xF93E, Mapped, 7068, 3), (0xF93F, Mapped, 7071, 3), (0xF940, Mapped, 3678, 3), (0xF941, Mapped, 7074, 3),
+    (0xF942, Mapped, 7077, 3), (0xF943, Mapped, 7080, 3), (0xF944, Mapped, 7083, 3), (0xF945, Mapped, 7086, 3),
+    (0xF946, Mapped, 7089, 3), (0xF947, Mapped, 7092, 3), (0xF948, Mapped, 7095, 3), (0xF949, Mapped, 7098, 3),
+    (0xF94A, Mapped, 7101, 3), (0xF94B, Mapped, 7104, 3), (0xF94C, Mapped, 7107, 3), (0xF94D, Mapped, 7110, 3),
+    (0xF94E, Mapped, 7113, 3), (0xF94F, Mapped, 7116, 3), (0xF950, Mapped, 7119, 3), (0xF951, Mapped, 7122, 3),
+    (0xF952, Mapped, 7125, 3), (0xF953, Mapped, 7128, 3), (0xF954, Mapped, 7131, 3), (0xF955, Mapped, 7134, 3),
+    (0xF956, Mapped, 7137, 3), (0xF957, Mapped, 7140, 3), (0xF958, Mapped, 7143, 3), (0xF959, Mapped, 7146, 3),
+    (0xF95A, Mapped, 7149, 3), (0xF95B, Mapped, 7152, 3), (0xF95C, Mapped, 6945, 3), (0xF95D, Mapped, 7155, 3),
+    (0xF95E, Mapped, 7158, 3), (0xF95F, Mapped, 7161, 3), (0xF960, Mapped, 7164, 3), (0xF961, Mapped, 7167, 3),
+    (0xF962, Mapped, 7170, 3), (0xF963, Mapped, 7173, 3), (0xF964, Mapped, 7176, 3), (0xF965, Mapped, 7179, 3),
+    (0xF966, Mapped, 7182, 3), (0xF967, Mapped, 7185, 3), (0xF968, Mapped, 7188, 3), (0xF969, Mapped, 7191, 3),
+    (0xF96A, Mapped, 7194, 3), (0xF96B, Mapped, 7197, 3), (0xF96C, Mapped, 7200, 3), (0xF96D, Mapped, 7203, 3),
+    (0xF96E, Mapped, 7206, 3), (0xF96F, Mapped, 7209, 3), (0xF970, Mapped, 7212, 3), (0xF971, Mapped, 3567, 3),
+    (0xF972, Mapped, 7215, 3), (0xF973, Mapped, 7218, 3), (0xF974, Mapped, 7221, 3), (0xF975, Mapped, 7224, 3),
+    (0xF976, Mapped, 7227, 3), (0xF977, Mapped, 7230, 3), (0xF978, Mapped, 7233, 3), (0xF979, Mapped, 7236, 3),
+    (0xF97A, Mapped, 7239, 3), (0xF97B, Mapped, 7242, 3), (0xF97C, Mapped, 7245, 3), (0xF97D, Mapped, 7248, 3),
+    (0xF97E, Mapped, 7251, 3), (0xF97F, Mapped, 7254, 3), (0xF980, Mapped, 7257, 3), (0xF981, Mapped, 3198, 3),
+    (0xF982, Mapped, 7260, 3), (0xF983, Mapped, 7263, 3), (0xF984, Mapped, 7266, 3), (0xF985, Mapped, 7269, 3),
+    (0xF986, Mapped, 7272, 3), (0xF987, Mapped, 7275, 3), (0xF988, Mapped, 7278, 3), (0xF989, Mapped, 7281, 3),
+    (0xF98A, Mapped, 3141, 3), (0xF98B, Mapped, 7284, 3), (0xF98C, Mapped, 7287, 3), (0xF98D, Mapped, 7290, 3),
+    (0xF98E, Mapped, 7293, 3), (0xF98F, Mapped, 7296, 3), (0xF990, Mapped, 7299, 3), (0xF991, Mapped, 7302, 3),
+    (0xF992, Mapped, 7305, 3), (0xF993, Mapped, 7308, 3), (0xF994, Mapped, 7311, 3), (0xF995, Mapped, 7314, 3),
+    (0xF996, Mapped, 7317, 3), (0xF997, Mapped, 7320, 3), (0xF998, Mapped, 7323, 3), (0xF999, Mapped, 7326, 3),
+    (0xF99A, Mapped, 7329, 3), (0xF99B, Mapped, 7332, 3), (0xF99C, Mapped, 7335, 3), (0xF99D, Mapped, 7338, 3),
+    (0xF99E, Mapped, 7341, 3), (0xF99F, Mapped, 7344, 3), (0xF9A0, Mapped, 7347, 3), (0xF9A1, Mapped, 7209, 3),
+    (0xF9A2, Mapped, 7350, 3), (0xF9A3, Mapped, 7353, 3), (0xF9A4, Mapped, 7356, 3), (0xF9A5, Mapped, 7359, 3),
+    (0xF9A6, Mapped, 7362, 3), (0xF9A7, Mapped, 7365, 3), (0xF9A8, Mapped, 7368, 3), (0xF9A9, Mapped, 7371, 3),
+    (0xF9AA, Mapped, 7161, 3), (0xF9AB, Mapped, 7374, 3), (0xF9AC, Mapped, 7377, 3), (0xF9AD, Mapped, 7380, 3),
+    (0xF9AE, Mapped, 7383, 3), (0xF9AF, Mapped, 7386, 3), (0xF9B0, Mapped, 7389, 3), (0xF9B1, Mapped, 7392, 3),
+    (0xF9B2, Mapped, 7395, 3), (0xF9B3, Mapped, 7398, 3), (0xF9B4, Mapped, 7401, 3), (0xF9B5, Mapped, 7404, 3),
+    (0xF9B6, Mapped, 7407, 3), (0xF9B7, Mapped, 7410, 3), (0xF9B8, Mapped, 7413, 3), (0xF9B9, Mapped, 7416, 3),
+    (0xF9BA, Mapped, 7419, 3), (0xF9BB, Mapped, 7422, 3), (0xF9BC, Mapped, 7425, 3), (0xF9BD, Mapped, 7428, 3),
+    (0xF9BE, Mapped, 7431, 3), (0xF9BF, Mapped, 6945, 3), (0xF9C0, Mapped, 7434, 3), (0xF9C1, Mapped, 7437, 3),
+    (0xF9C2, Mapped, 7440, 3), (0xF9C3, Mapped, 7443, 3), (0xF9C4, Mapped, 3720, 3), (0xF9C5, Mapped, 7446, 3),
+    (0xF9C6, Mapped, 7449, 3), (0xF9C7, Mapped, 7452, 3), (0xF9C8, Mapped, 7455, 3), (0xF9C9, Mapped, 7458, 3),
+    (0xF9CA, Mapped, 7461, 3), (0xF9CB, Mapped, 7464, 3), (0xF9CC, Mapped, 7467, 3), (0xF9CD, Mapped, 7470, 3),
+    (0xF9CE, Mapped, 7473, 3), (0xF9CF, Mapped, 7476, 3), (0xF9D0, Mapped, 7479, 3), (0xF9D1, Mapped, 4511, 3),
+    (0xF9D2, Mapped, 7482, 3), (0xF9D3, Mapped, 7485, 3), (0xF9D4, Mapped, 7488, 3), (0xF9D5, Mapped, 7491, 3),
+    (0xF9D6, Mapped, 7494, 3), (0xF9D7, Mapped, 7497, 3), (0xF9D8, Mapped, 7500, 3), (0xF9D9, Mapped, 7503, 3),
+    (0xF9DA, Mapped, 7506, 3), (0xF9DB, Mapped, 7167, 3), (0xF9DC, Mapped, 7509, 3), (0xF9DD, Mapped, 7512, 3),
+    (0xF9DE, Mapped, 7515, 3), (0xF9DF, Mapped, 7518, 3), (0xF9E0, Mapped, 7521, 3), (0xF9E1, Mapped, 7524, 3),
+    (0xF9E2, Mapped, 7527, 3), (0xF9E3, Mapped, 7530, 3), (0xF9E4, Mapped, 7533, 3), (0xF9E5, Mapped, 7536, 3),
+    (0xF9E6, Mapped, 7539, 3), (0xF9E7, Mapped, 7542, 3), (0xF9E8, Mapped, 7545, 3), (0xF9E9, Mapped, 3582, 3),
+    (0xF9EA, Mapped, 7548, 3), (0xF9EB, Mapped, 7551, 3), (0xF9EC, Mapped, 7554, 3), (0xF9ED, Mapped, 7557, 3),
+    (0xF9EE, Mapped, 7560, 3), (0xF9EF, Mapped, 7563, 3), (0xF9F0, Mapped, 7566, 3), (0xF9F1, Mapped, 7569, 3),
+    (0xF9F2, Mapped, 7572, 3), (0xF9F3, Mapped, 7575, 3), (0xF9F4, Mapped, 7578, 3), (0xF9F5, Mapped, 7581, 3),
+    (0xF9F6, Mapped, 7584, 3), (0xF9F7, Mapped, 3435, 3), (0xF9F8, Mapped, 7587, 3), (0xF9F9, Mapped, 7590, 3),
+    (0xF9FA, Mapped, 7593, 3), (0xF9FB, Mapped, 7596, 3), (0xF9FC, Mapped, 7599, 3), (0xF9FD, Mapped, 7602, 3),
+    (0xF9FE, Mapped, 7605, 3), (0xF9FF, Mapped, 7608, 3), (0xFA00, Mapped, 7611, 3), (0xFA01, Mapped, 7614, 3),
+    (0xFA02, Mapped, 7617, 3), (0xFA03, Mapped, 7620, 3), (0xFA04, Mapped, 7623, 3), (0xFA05, Mapped, 7626, 3),
+    (0xFA06, Mapped, 7629, 3), (0xFA07, Mapped, 7632, 3), (0xFA08, Mapped, 3516, 3), (0xFA09, Mapped, 7635, 3),
+    (0xFA0A, Mapped, 3525, 3), (0xFA0B, Mapped, 7638, 3), (0xFA0C, Mapped, 7641, 3), (0xFA0D, Mapped, 7644, 3),
+    (0xFA0E, Valid, 0, 0), (0xFA10, Mapped, 7647, 3), (0xFA11, Valid, 0, 0), (0xFA12, Mapped, 7650, 3),
+    (0xFA13, Valid, 0, 0), (0xFA15, Mapped, 7653, 3), (0xFA16, Mapped, 7656, 3), (0xFA17, Mapped, 7659, 3),
+    (0xFA18, Mapped, 7662, 3), (0xFA19, Mapped, 7665, 3), (0xFA1A, Mapped, 7668, 3), (0xFA1B, Mapped, 7671, 3),
+    (0xFA1C, Mapped, 7674, 3), (0xFA1D, Mapped, 7677, 3), (0xFA1E, Mapped, 3456, 3), (0xFA1F, Valid, 0, 0),
+    (0xFA20, Mapped, 7680, 3), (0xFA21, Valid, 0, 0), (0xFA22, Mapped, 7683, 3), (0xFA23, Valid, 0, 0),
+    (0xFA25, Mapped, 7686, 3), (0xFA26, Mapped, 7689, 3), (0xFA27, Valid, 0, 0), (0xFA2A, Mapped, 7692, 3),
+    (0xFA2B, Mapped, 7695, 3), (0xFA2C, Mapped, 7698, 3), (0xFA2D, Mapped, 7701, 3), (0xFA2E, Mapped, 7704, 3),
+    (0xFA2F, Mapped, 7707, 3), (0xFA30, Mapped, 7710, 3), (0xFA31, Mapped, 7713, 3), (0xFA32, Mapped, 7716, 3),
+    (0xFA33, Mapped, 7719, 3), (0xFA34, Mapped, 7722, 3), (0xFA35, Mapped, 7725, 3), (0xFA36, Mapped, 7728, 3),
+    (0xFA37, Mapped, 7731, 3), (0xFA38, Mapped, 7734, 3), (0xFA39, Mapped, 7737, 3), (0xFA3A, Mapped, 7740, 3),
+    (0xFA3B, Mapped, 7743, 3), (0xFA3C, Mapped, 3219, 3), (0xFA3D, Mapped, 7746, 3), (0xFA3E, Mapped, 7749, 3),
+    (0xFA3F, Mapped, 7752, 3), (0xFA40, Mapped, 7755, 3), (0xFA41, Mapped, 7758, 3), (0xFA42, Mapped, 7761, 3),
+    (0xFA43, Mapped, 7764, 3), (0xFA44, Mapped, 7767, 3), (0xFA45, Mapped, 7770, 3), (0xFA46, Mapped, 7773, 3),
+    (0xFA47, Mapped, 7776, 3), (0xFA48, Mapped, 7779, 3), (0xFA49, Mapped, 7782, 3), (0xFA4A, Mapped, 7785, 3),
+    (0xFA4B, Mapped, 7788, 3), (0xFA4C, Mapped, 4526, 3), (0xFA4D, Mapped, 7791, 3), (0xFA4E, Mapped, 7794, 3),
+    (0xFA4F, Mapped, 7797, 3), (0xFA50, Mapped, 7800, 3), (0xFA51, Mapped, 4538, 3), (0xFA52, Mapped, 7803, 3),
+    (0xFA53, Mapped, 7806, 3), (0xFA54, Mapped, 7809, 3), (0xFA55, Mapped, 7812, 3), (0xFA56, Mapped, 7815, 3),
+    (0xFA57, Mapped, 7317, 3), (0xFA58, Mapped, 7818, 3), (0xFA59, Mapped, 7821, 3), (0xFA5A, Mapped, 7824, 3),
+    (0xFA5B, Mapped, 7827, 3), (0xFA5C, Mapped, 7830, 3), (0xFA5D, Mapped, 7833, 3), (0xFA5F, Mapped, 7836, 3),
+    (0xFA60, Mapped, 7839, 3), (0xFA61, Mapped, 7842, 3), (0xFA62, Mapped, 7845, 3), (0xFA63, Mapped, 7848, 3),
+    (0xFA64, Mapped, 7851, 3), (0xFA65, Mapped, 7854, 3), (0xFA66, Mapped, 7857, 3), (0xFA67, Mapped, 7686, 3),
+    (0xFA68, Mapped, 7860, 3), (0xFA69, Mapped, 7863, 3), (0xFA6A, Mapped, 7866, 3), (0xFA6B, Mapped, 7869, 3),
+    (0xFA6C, Mapped, 7872, 4), (0xFA6D, Mapped, 7876, 3), (0xFA6E, Disallowed, 0, 0), (0xFA70, Mapped, 7879, 3),
+    (0xFA71, Mapped, 7882, 3), (0xFA72, Mapped, 7885, 3), (0xFA73, Mapped, 7888, 3), (0xFA74, Mapped, 7891, 3),
+    (0xFA75, Mapped, 7894, 3), (0xFA76, Mapped, 7897, 3), (0xFA77, Mapped, 7900, 3), (0xFA78, Mapped, 7728, 3),
+    (0xFA79, Mapped, 7903, 3), (0xFA7A, Mapped, 7906, 3), (0xFA7B, Mapped, 7909, 3), (0xFA7C, Mapped, 7647, 3),
+    (0xFA7D, Mapped, 7912, 3), (0xFA7E, Mapped, 7915, 3), (0xFA7F, Mapped, 7918, 3), (0xFA80, Mapped, 7921, 3),
+    (0xFA81, Mapped, 7924, 3), (0xFA82, Mapped, 7927, 3), (0xFA83, Mapped, 7930, 3), (0xFA84, Mapped, 7933, 3),
+    (0xFA85, Mapped, 7936, 3), (0xFA86, Mapped, 7939, 3), (0xFA87, Mapped, 7942, 3), (0xFA88, Mapped, 7945, 3),
+    (0xFA89, Mapped, 7752, 3), (0xFA8A, Mapped, 7948, 3), (0xFA8B, Mapped, 7755, 3), (0xFA8C, Mapped, 7951, 3),
+    (0xFA8D, Mapped, 7954, 3), (0xFA8E, Mapped, 7957, 3), (0xFA8F, Mapped, 7960, 3), (0xFA90, Mapped, 7963, 3),
+    (0xFA91, Mapped, 7650, 3), (0xFA92, Mapped, 7008, 3), (0xFA93, Mapped, 7966, 3), (0xFA94, Mapped, 7969, 3),
+    (0xFA95, Mapped, 3318, 3), (0xFA96, Mapped, 7212, 3), (0xFA97, Mapped, 7461, 3), (0xFA98, Mapped, 7972, 3),
+    (0xFA99, Mapped, 7975, 3), (0xFA9A, Mapped, 7776, 3), (0xFA9B, Mapped, 7978, 3), (0xFA9C, Mapped, 7779, 3),
+    (0xFA9D, Mapped, 7981, 3), (0xFA9E, Mapped, 7984, 3), (0xFA9F, Mapped, 7987, 3), (0xFAA0, Mapped, 7656, 3),
+    (0xFAA1, Mapped, 7990, 3), (0xFAA2, Mapped, 7993, 3), (0xFAA3, Mapped, 7996, 3), (0xFAA4, Mapped, 7999, 3),
+    (0xFAA5, Mapped, 8002, 3), (0xFAA6, Mapped, 7659, 3), (0xFAA7, Mapped, 8005, 3), (0xFAA8, Mapped, 8008, 3),
+    (0xFAA9, Mapped, 8011, 3), (0xFAAA, Mapped, 8014, 3), (0xFAAB, Mapped, 8017, 3), (0xFAAC, Mapped, 8020, 3),
+    (0xFAAD, Mapped, 7815, 3), (0xFAAE, Mapped, 8023, 3), (0xFAAF, Mapped, 8026, 3), (0xFAB0, Mapped, 7317, 3),
+    (0xFAB1, Mapped, 8029, 3), (0xFAB2, Mapped, 7827, 3), (0xFAB3, Mapped, 8032, 3), (0xFAB4, Mapped, 8035, 3),
+    (0xFAB5, Mapped, 8038, 3), (0xFAB6, Mapped, 8041, 3), (0xFAB7, Mapped, 8044, 3), (0xFAB8, Mapped, 7842, 3),
+    (0xFAB9, Mapped, 8047, 3), (0xFABA, Mapped, 7683, 3), (0xFABB, Mapped, 8050, 3), (0xFABC, Mapped, 7845, 3),
+    (0xFABD, Mapped, 7155, 3), (0xFABE, Mapped, 8053, 3), (0xFABF, Mapped, 7848, 3), (0xFAC0, Mapped, 8056, 3),
+    (0xFAC1, Mapped, 7854, 3), (0xFAC2, Mapped, 8059, 3), (0xFAC3, Mapped, 8062, 3), (0xFAC4, Mapped, 8065, 3),
+    (0xFAC5, Mapped, 8068, 3), (0xFAC6, Mapped, 8071, 3), (0xFAC7, Mapped, 7860, 3), (0xFAC8, Mapped, 7674, 3),
+    (0xFAC9, Mapped, 8074, 3), (0xFACA, Mapped, 7863, 3), (0xFACB, Mapped, 8077, 3), (0xFACC, Mapped, 7866, 3),
+    (0xFACD, Mapped, 8080, 3), (0xFACE, Mapped, 3723, 3), (0xFACF, Mapped, 8083, 4), (0xFAD0, Mapped, 8087, 4),
+    (0xFAD1, Mapped, 8091, 4), (0xFAD2, Mapped, 8095, 3), (0xFAD3, Mapped, 8098, 3), (0xFAD4, Mapped, 8101, 3),
+    (0xFAD5, Mapped, 8104, 4), (0xFAD6, Mapped, 8108, 4), (0xFAD7, Mapped, 8112, 4), (0xFAD8, Mapped, 8116, 3),
+    (0xFAD9, Mapped, 8119, 3), (0xFADA, Disallowed, 0, 0), (0xFB00, Mapped, 8122, 2), (0xFB01, Mapped, 8124, 2),
+    (0xFB02, Mapped, 8126, 2), (0xFB03, Mapped, 8128, 3), (0xFB04, Mapped, 8131, 3), (0xFB05, Mapped, 8134, 2),
+    (0xFB07, Disallowed, 0, 0), (0xFB13, Mapped, 8136, 4), (0xFB14, Mapped, 8140, 4), (0xFB15, Mapped, 8144, 4),
+    (0xFB16, Mapped, 8148, 4), (0xFB17, Mapped, 8152, 4), (0xFB18, Disallowed, 0, 0), (0xFB1D, Mapped, 8156, 4),
+    (0xFB1E, Valid, 0, 0), (0xFB1F, Mapped, 8160, 4), (0xFB20, Mapped, 8164, 2), (0xFB21, Mapped, 2392, 2),
+    (0xFB22, Mapped, 2398, 2), (0xFB23, Mapped, 8166, 2), (0xFB24, Mapped, 8168, 2), (0xFB25, Mapped, 8170, 2),
+    (0xFB26, Mapped, 8172, 2), (0xFB27, Mapped, 8174, 2), (0xFB28, Mapped, 8176, 2), (0xFB29, Std3Mapped, 2356, 1),
+    (0xFB2A, Mapped, 8178, 4), (0xFB2B, Mapped, 8182, 4), (0xFB2C, Mapped, 8186, 6), (0xFB2D, Mapped, 8192, 6),
+    (0xFB2E, Mapped, 8198, 4), (0xFB2F, Mapped, 8202, 4), (0xFB30, Mapped, 8206, 4), (0xFB31, Mapped, 8210, 4),
+    (0xFB32, Mapped, 8214, 4), (0xFB33, Mapped, 8218, 4), (0xFB34, Mapped, 8222, 4), (0xFB35, Mapped, 8226, 4),
+    (0xFB36, Mapped, 8230, 4), (0xFB37, Disallowed, 0, 0), (0xFB38, Mapped, 8234, 4), (0xFB39, Mapped, 8238, 4),
+    (0xFB3A, Mapped, 8242, 4), (0xFB3B, Mapped, 8246, 4), (0xFB3C, Mapped, 8250, 4), (0xFB3D, Disallowed, 0, 0),
+    (0xFB3E, Mapped, 8254, 4), (0xFB3F, Disallowed, 0, 0), (0xFB40, Mapped, 8258, 4), (0xFB41, Mapped, 8262, 4),
+    (0xFB42, Disallowed, 0, 0), (0xFB43, Mapped, 8266, 4), (0xFB44, Mapped, 8270, 4), (0xFB45, Disallowed, 0, 0),
+    (0xFB46, Mapped, 8274, 4), (0xFB47, Mapped, 8278, 4), (0xFB48, Mapped, 8282, 4), (0xFB49, Mapped, 8286, 4),
+    (0xFB4A, Mapped, 8290, 4), (0xFB4B, Mapped, 8294, 4), (0xFB4C, Mapped, 8298, 4), (0xFB4D, Mapped, 8302, 4),
+    (0xFB4E, Mapped, 8306, 4), (0xFB4F, Mapped, 8310, 4), (0xFB50, Mapped, 8314, 2), (0xFB52, Mapped, 8316, 2),
+    (0xFB56, Mapped, 8318, 2), (0xFB5A, Mapped, 8320, 2), (0xFB5E, Mapped, 8322, 2), (0xFB62, Mapped, 8324, 2),
+    (0xFB66, Mapped, 8326, 2), (0xFB6A, Mapped, 8328, 2), (0xFB6E, Mapped, 8330, 2), (0xFB72, Mapped, 8332, 2),
+    (0xFB76, Mapped, 8334, 2), (0xFB7A, Mapped, 8336, 2), (0xFB7E, Mapped, 8338, 2), (0xFB82, Mapped, 8340, 2),
+    (0xFB84, Mapped, 8342, 2), (0xFB86, Mapped, 8344, 2), (0xFB88, Mapped, 8346, 2), (0xFB8A, Mapped, 8348, 2),
+    (0xFB8C, Mapped, 8350, 2), (0xFB8E, Mapped, 8352, 2), (0xFB92, Mapped, 8354, 2), (0xFB96, Mapped, 8356, 2),
+    (0xFB9A, Mapped, 8358, 2), (0xFB9E, Mapped, 8360, 2), (0xFBA0, Mapped, 8362, 2), (0xFBA4, Mapped, 8364, 2),
+    (0xFBA6, Mapped, 8366, 2), (0xFBAA, Mapped, 8368, 2), (0xFBAE, Mapped, 8370, 2), (0xFBB0, Mapped, 8372, 2),
+    (0xFBB2, Valid, 0, 0), (0xFBC3, Disallowed, 0, 0), (0xFBD3, Mapped, 8374, 2), (0xFBD7, Mapped, 8376, 2),
+    (0xFBD9, Mapped, 8378, 2), (0xFBDB, Mapped, 8380, 2), (0xFBDD, Mapped, 1002, 4), (0xFBDE, Mapped, 8382, 2),
+    (0xFBE0, Mapped, 8384, 2), (0xFBE2, Mapped, 8386, 2), (0xFBE4, Mapped, 8388, 2), (0xFBE8, Mapped, 8390, 2),
+    (0xFBEA, Mapped, 8392, 4), (0xFBEC, Mapped, 8396, 4), (0xFBEE, Mapped, 8400, 4), (0xFBF0, Mapped, 8404, 4),
+    (0xFBF2, Mapped, 8408, 4), (0xFBF4, Mapped, 8412, 4), (0xFBF6, Mapped, 8416, 4), (0xFBF9, Mapped, 8420, 4),
+    (0xFBFC, Mapped, 8424, 2), (0xFC00, Mapped, 8426, 4), (0xFC01, Mapped, 8430, 4), (0xFC02, Mapped, 8434, 4),
+    (0xFC03, Mapped, 8420, 4), (0xFC04, Mapped, 8438, 4), (0xFC05, Mapped, 8442, 4), (0xFC06, Mapped, 8446, 4),
+    (0xFC07, Mapped, 8450, 4), (0xFC08, Mapped, 8454, 4), (0xFC09, Mapped, 8458, 4), (0xFC0A, Mapped, 8462, 4),
+    (0xFC0B, Mapped, 8466, 4), (0xFC0C, Mapped, 8470, 4), (0xFC0D, Mapped, 8474, 4), (0xFC0E, Mapped, 8478, 4),
+    (0xFC0F, Mapped, 8482, 4), (0xFC10, Mapped, 8486, 4), (0xFC11, Mapped, 8490, 4), (0xFC12, Mapped, 8494, 4),
+    (0xFC13, Mapped, 8498, 4), (0xFC14, Mapped, 8502, 4), (0xFC15, Mapped, 8506, 4), (0xFC16, Mapped, 8510, 4),
+    (0xFC17, Mapped, 8514, 4), (0xFC18, Mapped, 8518, 4), (0xFC19, Mapped, 8522, 4), (0xFC1A, Mapped, 8526, 4),
+    (0xFC1B, Mapped, 8530, 4), (0xFC1C, Mapped, 8534, 4), (0xFC1D, Mapped, 8538, 4), (0xFC1E, Mapped, 8542, 4),
+    (0xFC1F, Mapped, 8546, 4), (0xFC20, Mapped, 8550, 4), (0xFC21, Mapped, 8554, 4), (0xFC22, Mapped, 8558, 4),
+    (0xFC23, Mapped, 8562, 4), (0xFC24, Mapped, 8566, 4), (0xFC25, Mapped, 8570, 4), (0xFC26, Mapped, 8574, 4),
+    (0xFC27, Mapped, 8578, 4), (0xFC28, Mapped, 8582, 4), (0xFC29, Mapped, 8586, 4), (0xFC2A, Mapped, 8590, 4),
+    (0xFC2B, Mapped, 8594, 4), (0xFC2C, Mapped, 8598, 4), (0xFC2D, Mapped, 8602, 4), (0xFC2E, Mapped, 8606, 4),
+    (0xFC2F, Mapped, 8610, 4), (0xFC30, Mapped, 8614, 4), (0xFC31, Mapped, 8618, 4), (0xFC32, Mapped, 8622, 4),
+    (0xFC33, Mapped, 8626, 4), (0xFC34, Mapped, 8630, 4), (0xFC35, Mapped, 8634, 4), (0xFC36, Mapped, 8638, 4),
+    (0xFC37, Mapped, 8642, 4), (0xFC38, Mapped, 8646, 4), (0xFC39, Mapped, 8650, 4), (0xFC3A, Mapped, 8654, 4),
+    (0xFC3B, Mapped, 8658, 4), (0xFC3C, Mapped, 8662, 4), (0xFC3D, Mapped, 8666, 4), (0xFC3E, Mapped, 8670, 4),
+    (0xFC3F, Mapped, 8674, 4), (0xFC40, Mapped, 8678, 4), (0xFC41, Mapped, 8682, 4), (0xFC42, Mapped, 8686, 4),
+    (0xFC43, Mapped, 8690, 4), (0xFC44, Mapped, 8694, 4), (0xFC45, Mapped, 8698, 4), (0xFC46, Mapped, 8702, 4),
+    (0xFC47, Mapped, 8706, 4), (0xFC48, Mapped, 8710, 4), (0xFC49, Mapped, 8714, 4), (0xFC4A, Mapped, 8718, 4),
+    (0xFC4B, Mapped, 8722, 4), (0xFC4C, Mapped, 8726, 4), (0xFC4D, Mapped, 8730, 4), (0xFC4E, Mapped, 8734, 4),
+    (0xFC4F, Mapped, 8738, 4), (0xFC50, Mapped, 8742, 4), (0xFC51, Mapped, 8746, 4), (0xFC52, Mapped, 8750, 4),
+    (0xFC53, Mapped, 8754, 4), (0xFC54, Mapped, 8758, 4), (0xFC55, Mapped, 8762, 4), (0xFC56, Mapped, 8766, 4),
+    (0xFC57, Mapped, 8770, 4), (0xFC58, Mapped, 8774, 4), (0xFC59, Mapped, 8778, 4), (0xFC5A, Mapped, 8782, 4),
+    (0xFC5B, Mapped, 8786, 4), (0xFC5C, Mapped, 8790, 4), (0xFC5D, Mapped, 8794, 4), (0xFC5E, Std3Mapped, 8798, 5),
+    (0xFC5F, Std3Mapped, 8803, 5), (0xFC60, Std3Mapped, 8808, 5), (0xFC61, Std3Mapped, 8813, 5), (0xFC62, Std3Mapped, 8818, 5),
+    (0xFC63, Std3Mapped, 8823, 5), (0xFC64, Mapped, 8828, 4), (0xFC65, Mapped, 8832, 4), (0xFC66, Mapped, 8434, 4),
+    (0xFC67, Mapped, 8836, 4), (0xFC68, Mapped, 8420, 4), (0xFC69, Mapped, 8438, 4), (0xFC6A, Mapped, 8840, 4),
+    (0xFC6B, Mapped, 8844, 4), (0xFC6C, Mapped, 8454, 4), (0xFC6D, Mapped, 8848, 4), (0xFC6E, Mapped, 8458, 4),
+    (0xFC6F, Mapped, 8462, 4), (0xFC70, Mapped, 8852, 4), (0xFC71, Mapped, 8856, 4), (0xFC72, Mapped, 8478, 4),
+    (0xFC73, Mapped, 8860, 4), (0xFC74, Mapped, 8482, 4), (0xFC75, Mapped, 8486, 4), (0xFC76, Mapped, 8864, 4),
+    (0xFC77, Mapped, 8868, 4), (0xFC78, Mapped, 8494, 4), (0xFC79, Mapped, 8872, 4), (0xFC7A, Mapped, 8498, 4),
+    (0xFC7B, Mapped, 8502, 4), (0xFC7C, Mapped, 8618, 4), (0xFC7D, Mapped, 8622, 4), (0xFC7E, Mapped, 8634, 4),
+    (0xFC7F, Mapped, 8638, 4), (0xFC80, Mapped, 8642, 4), (0xFC81, Mapped, 8658, 4), (0xFC82, Mapped, 8662, 4),
+    (0xFC83, Mapped, 8666, 4), (0xFC84, Mapped, 8670, 4), (0xFC85, Mapped, 8686, 4), (0xFC86, Mapped, 8690, 4),
+    (0xFC87, Mapped, 8694, 4), (0xFC88, Mapped, 8876, 4), (0xFC89, Mapped, 8710, 4), (0xFC8A, Mapped, 8880, 4),
+    (0xFC8B, Mapped, 8884, 4), (0xFC8C, Mapped, 8734, 4), (0xFC8D, Mapped, 8888, 4), (0xFC8E, Mapped, 8738, 4),
+    (0xFC8F, Mapped, 8742, 4), (0xFC90, Mapped, 8794, 4), (0xFC91, Mapped, 8892, 4), (0xFC92, Mapped, 8896, 4),
+    (0xFC93, Mapped, 8774, 4), (0xFC94, Mapped, 8900, 4), (0xFC95, Mapped, 8778, 4), (0xFC96, Mapped, 8782, 4),
+    (0xFC97, Mapped, 8426, 4), (0xFC98, Mapped, 8430, 4), (0xFC99, Mapped, 8904, 4), (0xFC9A, Mapped, 8434, 4),
+    (0xFC9B, Mapped, 8908, 4), (0xFC9C, Mapped, 8442, 4), (0xFC9D, Mapped, 8446, 4), (0xFC9E, Mapped, 8450, 4),
+    (0xFC9F, Mapped, 8454, 4), (0xFCA0, Mapped, 8912, 4), (0xFCA1, Mapped, 8466, 4), (0xFCA2, Mapped, 8470, 4),
+    (0xFCA3, Mapped, 8474, 4), (0xFCA4, Mapped, 8478, 4), (0xFCA5, Mapped, 8916, 4), (0xFCA6, Mapped, 8494, 4),
+    (0xFCA7, Mapped, 8506, 4), (0xFCA8, Mapped, 8510, 4), (0xFCA9, Mapped, 8514, 4), (0xFCAA, Mapped, 8518, 4),
+    (0xFCAB, Mapped, 8522, 4), (0xFCAC, Mapped, 8530, 4), (0xFCAD, Mapped, 8534, 4), (0xFCAE, Mapped, 8538, 4),
+    (0xFCAF, Mapped, 8542, 4), (0xFCB0, Mapped, 8546, 4), (0xFCB1, Mapped, 8550, 4), (0xFCB2, Mapped, 8920, 4),
+    (0xFCB3, Mapped, 8554, 4), (0xFCB4, Mapped, 8558, 4), (0xFCB5, Mapped, 8562, 4), (0xFCB6, Mapped, 8566, 4),
+    (0xFCB7, Mapped, 8570, 4), (0xFCB8, Mapped, 8574, 4), (0xFCB9, Mapped, 8582, 4), (0xFCBA, Mapped, 8586, 4),
+    (0xFCBB, Mapped, 8590, 4), (0xFCBC, Mapped, 8594, 4), (0xFCBD, Mapped, 8598, 4), (0xFCBE, Mapped, 8602, 4),
+    (0xFCBF, Mapped, 8606, 4), (0xFCC0, Mapped, 8610, 4), (0xFCC1, Mapped, 8614, 4), (0xFCC2, Mapped, 8626, 4),
+    (0xFCC3, Mapped, 8630, 4), (0xFCC4, Mapped, 8646, 4), (0xFCC5, Mapped, 8650, 4), (0xFCC6, Mapped, 8654, 4),
+    (0xFCC7, Mapped, 8658, 4), (0xFCC8, Mapped, 8662, 4), (0xFCC9, Mapped, 8674, 4), (0xFCCA, Mapped, 8678, 4),
+    (0xFCCB, Mapped, 8682, 4), (0xFCCC, Mapped, 8686, 4), (0xFCCD, Mapped, 8924, 4), (0xFCCE, Mapped, 8698, 4),
+    (0xFCCF, Mapped, 8702, 4), (0xFCD0, Mapped, 8706, 4), (0xFCD1, Mapped, 8710, 4), (0xFCD2, Mapped, 8722, 4),
+    (0xFCD3, Mapped, 8726, 4), (0xFCD4, Mapped, 8730, 4), (0xFCD5, Mapped, 8734, 4), (0xFCD6, Mapped, 8928, 4),
+    (0xFCD7, Mapped, 8746, 4), (0xFCD8, Mapped, 8750, 4), (0xFCD9, Mapped, 8932, 4), (0xFCDA, Mapped, 8762, 4),
+    (0xFCDB, Mapped, 8766, 4), (0xFCDC, Mapped, 8770, 4), (0xFCDD, Mapped, 8774, 4), (0xFCDE, Mapped, 8936, 4),
+    (0xFCDF, Mapped, 8434, 4), (0xFCE0, Mapped, 8908, 4), (0xFCE1, Mapped, 8454, 4), (0xFCE2, Mapped, 8912, 4),
+    (0xFCE3, Mapped, 8478, 4), (0xFCE4, Mapped, 8916, 4), (0xFCE5, Mapped, 8494, 4), (0xFCE6, Mapped, 8940, 4),
+    (0xFCE7, Mapped, 8546, 4), (0xFCE8, Mapped, 8944, 4), (0xFCE9, Mapped, 8948, 4), (0xFCEA, Mapped, 8952, 4),
+    (0xFCEB, Mapped, 8658, 4), (0xFCEC, Mapped, 8662, 4), (0xFCED, Mapped, 8686, 4), (0xFCEE, Mapped, 8734, 4),
+    (0xFCEF, Mapped, 8928, 4), (0xFCF0, Mapped, 8774, 4), (0xFCF1, Mapped, 8936, 4), (0xFCF2, Mapped, 8956, 6),
+    (0xFCF3, Mapped, 8962, 6), (0xFCF4, Mapped, 8968, 6), (0xFCF5, Mapped, 8974, 4), (0xFCF6, Mapped, 8978, 4),
+    (0xFCF7, Mapped, 8982, 4), (0xFCF8, Mapped, 8986, 4), (0xFCF9, Mapped, 8990, 4), (0xFCFA, Mapped, 8994, 4),
+    (0xFCFB, Mapped, 8998, 4), (0xFCFC, Mapped, 9002, 4), (0xFCFD, Mapped, 9006, 4), (0xFCFE, Mapped, 9010, 4),
+    (0xFCFF, Mapped, 9014, 4), (0xFD00, Mapped, 9018, 4), (0xFD01, Mapped, 9022, 4), (0xFD02, Mapped, 9026, 4),
+    (0xFD03, Mapped, 9030, 4), (0xFD04, Mapped, 9034, 4), (0xFD05, Mapped, 9038, 4), (0xFD06, Mapped, 9042, 4),
+    (0xFD07, Mapped, 9046, 4), (0xFD08, Mapped, 9050, 4), (0xFD09, Mapped, 9054, 4), (0xFD0A, Mapped, 9058, 4),
+    (0xFD0B, Mapped, 9062, 4), (0xFD0C, Mapped, 8948, 4), (0xFD0D, Mapped, 9066, 4), (0xFD0E, Mapped, 9070, 4),
+    (0xFD0F, Mapped, 9074, 4), (0xFD10, Mapped, 9078, 4), (0xFD11, Mapped, 8974, 4), (0xFD12, Mapped, 8978, 4),
+    (0xFD13, Mapped, 8982, 4), (0xFD14, Mapped, 8986, 4), (0xFD15, Mapped, 8990, 4), (0xFD16, Mapped, 8994, 4),
+    (0xFD17, Mapped, 8998, 4), (0xFD18, Mapped, 9002, 4), (0xFD19, Mapped, 9006, 4), (0xFD1A, Mapped, 9010, 4),
+    (0xFD1B, Mapped, 9014, 4), (0xFD1C, Mapped, 9018, 4), (0xFD1D, Mapped, 9022, 4), (0xFD1E, Mapped, 9026, 4),
+    (0xFD1F, Mapped, 9030, 4), (0xFD20, Mapped, 9034, 4), (0xFD21, Mapped, 9038, 4), (0xFD22, Mapped, 9042, 4),
+    (0xFD23, Mapped, 9046, 4), (0xFD24, Mapped, 9050, 4), (0xFD25, Mapped, 9054, 4), (0xFD26, Mapped, 9058, 4),
+    (0xFD27, Mapped, 9062, 4), (0xFD28, Mapped, 8948, 4), (0xFD29, Mapped, 9066, 4), (0xFD2A, Mapped, 9070, 4),
+    (0xFD2B, Mapped, 9074, 4), (0xFD2C, Mapped, 9078, 4), (0xFD2D, Mapped, 9054, 4), (0xFD2E, Mapped, 9058, 4),
+    (0xFD2F, Mapped, 9062, 4), (0xFD30, Mapped, 8948, 4), (0xFD31, Mapped, 8944, 4), (0xFD32, Mapped, 8952, 4),
+    (0xFD33, Mapped, 8578, 4), (0xFD34, Mapped, 8534, 4), (0xFD35, Mapped, 8538, 4), (0xFD36, Mapped, 8542, 4),
+    (0xFD37, Mapped, 9054, 4), (0xFD38, Mapped, 9058, 4), (0xFD39, Mapped, 9062, 4), (0xFD3A, Mapped, 8578, 4),
+    (0xFD3B, Mapped, 8582, 4), (0xFD3C, Mapped, 9082, 4), (0xFD3E, Valid, 0, 0), (0xFD50, Mapped, 9086, 6),
+    (0xFD51, Mapped, 9092, 6), (0xFD53, Mapped, 9098, 6), (0xFD54, Mapped, 9104, 6), (0xFD55, Mapped, 9110, 6),
+    (0xFD56, Mapped, 9116, 6), (0xFD57, Mapped, 9122, 6), (0xFD58, Mapped, 9128, 6), (0xFD5A, Mapped, 9134, 6),
+    (0xFD5B, Mapped, 9140, 6), (0xFD5C, Mapped, 9146, 6), (0xFD5D, Mapped, 9152, 6), (0xFD5E, Mapped, 9158, 6),
+    (0xFD5F, Mapped, 9164, 6), (0xFD61, Mapped, 9170, 6), (0xFD62, Mapped, 9176, 6), (0xFD64, Mapped, 9182, 6),
+    (0xFD66, Mapped, 9188, 6), (0xFD67, Mapped, 9194, 6), (0xFD69, Mapped, 9200, 6), (0xFD6A, Mapped, 9206, 6),
+    (0xFD6C, Mapped, 9212, 6), (0xFD6E, Mapped, 9218, 6), (0xFD6F, Mapped, 9224, 6), (0xFD71, Mapped, 9230, 6),
+    (0xFD73, Mapped, 9236, 6), (0xFD74, Mapped, 9242, 6), (0xFD75, Mapped, 9248, 6), (0xFD76, Mapped, 9254, 6),
+    (0xFD78, Mapped, 9260, 6), (0xFD79, Mapped, 9266, 6), (0xFD7A, Mapped, 9272, 6), (0xFD7B, Mapped, 9278, 6),
+    (0xFD7C, Mapped, 9284, 6), (0xFD7E, Mapped, 9290, 6), (0xFD7F, Mapped, 9296, 6), (0xFD80, Mapped, 9302, 6),
+    (0xFD81, Mapped, 9308, 6), (0xFD82, Mapped, 9314, 6), (0xFD83, Mapped, 9320, 6), (0xFD85, Mapped, 9326, 6),
+    (0xFD87, Mapped, 9332, 6), (0xFD89, Mapped, 9338, 6), (0xFD8A, Mapped, 9344, 6), (0xFD8B, Mapped, 9350, 6),
+    (0xFD8C, Mapped, 9356, 6), (0xFD8D, Mapped, 9362, 6), (0xFD8E, Mapped, 9368, 6), (0xFD8F, Mapped, 9374, 6),
+    (0xFD90, Disallowed, 0, 0), (0xFD92, Mapped, 9380, 6), (0xFD93, Mapped, 9386, 6), (0xFD94, Mapped, 9392, 6),
+    (0xFD95, Mapped, 9398, 6), (0xFD96, Mapped, 9404, 6), (0xFD97, Mapped, 9410, 6), (0xFD99, Mapped, 9416, 6),
+    (0xFD9A, Mapped, 9422, 6), (0xFD9B, Mapped, 9428, 6), (0xFD9C, Mapped, 9434, 6), (0xFD9E, Mapped, 9440, 6),
+    (0xFD9F, Mapped, 9446, 6), (0xFDA0, Mapped, 9452, 6), (0xFDA1, Mapped, 9458, 6), (0xFDA2, Mapped, 9464, 6),
+    (0xFDA3, Mapped, 9470, 6), (0xFDA4, Mapped, 9476, 6), (0xFDA5, Mapped, 9482, 6), (0xFDA6, Mapped, 9488, 6),
+    (0xFDA7, Mapped, 9494, 6), (0xFDA8, Mapped, 9500, 6), (0xFDA9, Mapped, 9506, 6), (0xFDAA, Mapped, 9512, 6),
+    (0xFDAB, Mapped, 9518, 6), (0xFDAC, Mapped, 9524, 6), (0xFDAD, Mapped, 9530, 6), (0xFDAE, Mapped, 9536, 6),
+    (0xFDAF, Mapped, 9542, 6), (0xFDB0, Mapped, 9548, 6), (0xFDB1, Mapped, 9554, 6), (0xFDB2, Mapped, 9560, 6),
+    (0xFDB3, Mapped, 9566, 6), (0xFDB4, Mapped, 9290, 6), (0xFDB5, Mapped, 9302, 6), (0xFDB6, Mapped, 9572, 6),
+    (0xFDB7, Mapped, 9578, 6), (0xFDB8, Mapped, 9584, 6), (0xFDB9, Mapped, 9590, 6), (0xFDBA, Mapped, 9596, 6),
+    (0xFDBB, Mapped, 9602, 6), (0xFDBC, Mapped, 9596, 6), (0xFDBD, Mapped, 9584, 6), (0xFDBE, Mapped, 9608, 6),
+    (0xFDBF, Mapped, 9614, 6), (0xFDC0, Mapped, 9620, 6), (0xFDC1, Mapped, 9626, 6), (0xFDC2, Mapped, 9632, 6),
+    (0xFDC3, Mapped, 9602, 6), (0xFDC4, Mapped, 9248, 6), (0xFDC5, Mapped, 9188, 6), (0xFDC6, Mapped, 9638, 6),
+    (0xFDC7, Mapped, 9644, 6), (0xFDC8, Disallowed, 0, 0), (0xFDCF, Valid, 0, 0), (0xFDD0, Disallowed, 0, 0),
+    (0xFDF0, Mapped, 9650, 6), (0xFDF1, Mapped, 9656, 6), (0xFDF2, Mapped, 9662, 8), (0xFDF3, Mapped, 9670, 8),
+    (0xFDF4, Mapped, 9678, 8), (0xFDF5, Mapped, 9686, 8), (0xFDF6, Mapped, 9694, 8), (0xFDF7, Mapped, 9702, 8),
+    (0xFDF8, Mapped, 9710, 8), (0xFDF9, Mapped, 9718, 6), (0xFDFA, Std3Mapped, 9724, 33), (0xFDFB, Std3Mapped, 9757, 15),
+    (0xFDFC, Mapped, 9772, 8), (0xFDFD, Valid, 0, 0), (0xFE00, Ignored, 0, 0), (0xFE10, Std3Mapped, 9780, 1),
+    (0xFE11, Mapped, 9781, 3), (0xFE12, Disallowed, 0, 0), (0xFE13, Std3Mapped, 9784, 1), (0xFE14, Std3Mapped, 512, 1),
+    (0xFE15, Std3Mapped, 9785, 1), (0xFE16, Std3Mapped, 9786, 1), (0xFE17, Mapped, 9787, 3), (0xFE18, Mapped, 9790, 3),
+    (0xFE19, Disallowed, 0, 0), (0xFE20, Valid, 0, 0), (0xFE30, Disallowed, 0, 0), (0xFE31, Mapped, 9793, 3),
+    (0xFE32, Mapped, 9796, 3), (0xFE33, Std3Mapped, 9799, 1), (0xFE35, Std3Mapped, 2361, 1), (0xFE36, Std3Mapped, 2362, 1),
+    (0xFE37, Std3Mapped, 9800, 1), (0xFE38, Std3Mapped, 9801, 1), (0xFE39, Mapped, 9802, 3), (0xFE3A, Mapped, 9805, 3),
+    (0xFE3B, Mapped, 9808, 3), (0xFE3C, Mapped, 9811, 3), (0xFE3D, Mapped, 9814, 3), (0xFE3E, Mapped, 9817, 3),
+    (0xFE3F, Mapped, 2544, 3), (0xFE40, Mapped, 2547, 3), (0xFE41, Mapped, 9820, 3), (0xFE42, Mapped, 9823, 3),
+    (0xFE43, Mapped, 9826, 3), (0xFE44, Mapped, 9829, 3), (0xFE45, Valid, 0, 0), (0xFE47, Std3Mapped, 9832, 1),
+    (0xFE48, Std3Mapped, 9833, 1), (0xFE49, Std3Mapped, 2328, 3), (0xFE4D, Std3Mapped, 9799, 1), (0xFE50, Std3Mapped, 9780, 1),
+    (0xFE51, Mapped, 9781, 3), (0xFE52, Disallowed, 0, 0), (0xFE54, Std3Mapped, 512, 1), (0xFE55, Std3Mapped, 9784, 1),
+    (0xFE56, Std3Mapped, 9786, 1), (0xFE57, Std3Mapped, 9785, 1), (0xFE58, Mapped, 9793, 3), (0xFE59, Std3Mapped, 2361, 1),
+    (0xFE5A, Std3Mapped, 2362, 1), (0xFE5B, Std3Mapped, 9800, 1), (0xFE5C, Std3Mapped, 9801, 1), (0xFE5D, Mapped, 9802, 3),
+    (0xFE5E, Mapped, 9805, 3), (0xFE5F, Std3Mapped, 9834, 1), (0xFE60, Std3Mapped, 9835, 1), (0xFE61, Std3Mapped, 9836, 1),
+    (0xFE62, Std3Mapped, 2356, 1), (0xFE63, Mapped, 9837, 1), (0xFE64, Std3Mapped, 9838, 1), (0xFE65, Std3Mapped, 9839, 1),
+    (0xFE66, Std3Mapped, 2360, 1), (0xFE67, Disallowed, 0, 0), (0xFE68, Std3Mapped, 9840, 1), (0xFE69, Std3Mapped, 9841, 1),
+    (0xFE6A, Std3Mapped, 9842, 1), (0xFE6B, Std3Mapped, 9843, 1), (0xFE6C, Disallowed, 0, 0), (0xFE70, Std3Mapped, 9844, 3),
+    (0xFE71, Mapped, 9847, 4), (0xFE72, Std3Mapped, 9851, 3), (0xFE73, Valid, 0, 0), (0xFE74, Std3Mapped, 9854, 3),
+    (0xFE75, Disallowed, 0, 0), (0xFE76, Std3Mapped, 9857, 3), (0xFE77, Mapped, 9860, 4), (0xFE78, Std3Mapped, 9864, 3),
+    (0xFE79, Mapped, 9867, 4), (0xFE7A, Std3Mapped, 9871, 3), (0xFE7B, Mapped, 9874, 4), (0xFE7C, Std3Mapped, 9878, 3),
+    (0xFE7D, Mapped, 9881, 4), (0xFE7E, Std3Mapped, 9885, 3), (0xFE7F, Mapped, 9888, 4), (0xFE80, Mapped, 9892, 2),
+    (0xFE81, Mapped, 9894, 2), (0xFE83, Mapped, 9896, 2), (0xFE85, Mapped, 9898, 2), (0xFE87, Mapped, 9900, 2),
+    (0xFE89, Mapped, 9902, 2), (0xFE8D, Mapped, 9904, 2), (0xFE8F, Mapped, 9906, 2), (0xFE93, Mapped, 9908, 2),
+    (0xFE95, Mapped, 9910, 2), (0xFE99, Mapped, 9912, 2), (0xFE9D, Mapped, 9914, 2), (0xFEA1, Mapped, 9916, 2),
+    (0xFEA5, Mapped, 9918, 2), (0xFEA9, Mapped, 9920, 2), (0xFEAB, Mapped, 9922, 2), (0xFEAD, Mapped, 9924, 2),
+    (0xFEAF, Mapped, 9926, 2), (0xFEB1, Mapped, 9928, 2), (0xFEB5, Mapped, 9930, 2), (0xFEB9, Mapped, 9932, 2),
+    (0xFEBD, Mapped, 9934, 2), (0xFEC1, Mapped, 9936, 2), (0xFEC5, Mapped, 9938, 2), (0xFEC9, Mapped, 9940, 2),
+    (0xFECD, Mapped, 9942, 2), (0xFED1, Mapped, 9944, 2), (0xFED5, Mapped, 9946, 2), (0xFED9, Mapped, 9948, 2),
+    (0xFEDD, Mapped, 9950, 2), (0xFEE1, Mapped, 9952, 2), (0xFEE5, Mapped, 9954, 2), (0xFEE9, Mapped, 9956, 2),
+    (0xFEED, Mapped, 9958, 2), (0xFEEF, Mapped, 8390, 2), (0xFEF1, Mapped, 9960, 2), (0xFEF5, Mapped, 9962, 4),
+    (0xFEF7, Mapped, 9966, 4), (0xFEF9, Mapped, 9970, 4), (0xFEFB, Mapped, 9974, 4), (0xFEFD, Disallowed, 0, 0),
+    (0xFEFF, Ignored, 0, 0), (0xFF00, Disallowed, 0, 0), (0xFF01, Std3Mapped, 9785, 1), (0xFF02, Std3Mapped, 9978, 1),
+    (0xFF03, Std3Mapped, 9834, 1), (0xFF04, Std3Mapped, 9841, 1), (0xFF05, Std3Mapped, 9842, 1), (0xFF06, Std3Mapped, 9835, 1),
+    (0xFF07, Std3Mapped, 9979, 1), (0xFF08, Std3Mapped, 2361, 1), (0xFF09, Std3Mapped, 2362, 1), (0xFF0A, Std3Mapped, 9836, 1),
+    (0xFF0B, Std3Mapped, 2356, 1), (0xFF0C, Std3Mapped, 9780, 1), (0xFF0D, Mapped, 9837, 1), (0xFF0E, Mapped, 3729, 1),
+    (0xFF0F, Std3Mapped, 9980, 1), (0xFF10, Mapped, 2349, 1), (0xFF11, Mapped, 43, 1), (0xFF12, Mapped, 33, 1),
+    (0xFF13, Mapped, 34, 1), (0xFF14, Mapped, 2350, 1), (0xFF15, Mapped, 2351, 1), (0xFF16, Mapped, 2352, 1),
+    (0xFF17, Mapped, 2353, 1), (0xFF18, Mapped, 2354, 1), (0xFF19, Mapped, 2355, 1), (0xFF1A, Std3Mapped, 9784, 1),
+    (0xFF1B, Std3Mapped, 512, 1), (0xFF1C, Std3Mapped, 9838, 1), (0xFF1D, Std3Mapped, 2360, 1), (0xFF1E, Std3Mapped, 9839, 1),
+    (0xFF1F, Std3Mapped, 9786, 1), (0xFF20, Std3Mapped, 9843, 1), (0xFF21, Mapped, 0, 1), (0xFF22, Mapped, 1, 1),
+    (0xFF23, Mapped, 2, 1), (0xFF24, Mapped, 3, 1), (0xFF25, Mapped, 4, 1), (0xFF26, Mapped, 5, 1),
+    (0xFF27, Mapped, 6, 1), (0xFF28, Mapped, 7, 1), (0xFF29, Mapped, 8, 1), (0xFF2A, Mapped, 9, 1),
+    (0xFF2B, Mapped, 10, 1), (0xFF2C, Mapped, 11, 1), (0xFF2D, Mapped, 12, 1), (0xFF2E, Mapped, 13, 1),
+    (0xFF2F, Mapped, 14, 1), (0xFF30, Mapped, 15, 1), (0xFF31, Mapped, 16, 1), (0xFF32, Mapped, 17, 1),
+    (0xFF33, Mapped, 18, 1), (0xFF34, Mapped, 19, 1), (0xFF35, Mapped, 20, 1), (0xFF36, Mapped, 21, 1),
+    (0xFF37, Mapped, 22, 1), (0xFF38, Mapped, 23, 1), (0xFF39, Mapped, 24, 1), (0xFF3A, Mapped, 25, 1),
+    (0xFF3B, Std3Mapped, 9832, 1), (0xFF3C, Std3Mapped, 9840, 1), (0xFF3D, Std3Mapped, 9833, 1), (0xFF3E, Std3Mapped, 9981, 1),
+    (0xFF3F, Std3Mapped, 9799, 1), (0xFF40, Std3Mapped, 2262, 1), (0xFF41, Mapped, 0, 1), (0xFF42, Mapped, 1, 1),
+    (0xFF43, Mapped, 2, 1), (0xFF44, Mapped, 3, 1), (0xFF45, Mapped, 4, 1), (0xFF46, Mapped, 5, 1),
+    (0xFF47, Mapped, 6, 1), (0xFF48, Mapped, 7, 1), (0xFF49, Mapped, 8, 1), (0xFF4A, Mapped, 9, 1),
+    (0xFF4B, Mapped, 10, 1), (0xFF4C, Mapped, 11, 1), (0xFF4D, Mapped, 12, 1), (0xFF4E, Mapped, 13, 1),
+    (0xFF4F, Mapped, 14, 1), (0xFF50, Mapped, 15, 1), (0xFF51, Mapped, 16, 1), (0xFF52, Mapped, 17, 1),
+    (0xFF53, Mapped, 18, 1), (0xFF54, Mapped, 19, 1), (0xFF55, Mapped, 20, 1), (0xFF56, Mapped, 21, 1),
+    (0xFF57, Mapped, 22, 1), (0xFF58, Mapped, 23, 1), (0xFF59, Mapped, 24, 1), (0xFF5A, Mapped, 25, 1),
+    (0xFF5B, Std3Mapped, 9800, 1), (0xFF5C, Std3Mapped, 9982, 1), (0xFF5D, Std3Mapped, 9801, 1), (0xFF5E, Std3Mapped, 9983, 1),
+    (0xFF5F, Mapped, 9984, 3), (0xFF60, Mapped, 9987, 3), (0xFF61, Mapped, 3729, 1), (0xFF62, Mapped, 9820, 3),
+    (0xFF63, Mapped, 9823, 3), (0xFF64, Mapped, 9781, 3), (0xFF65, Mapped, 9990, 3), (0xFF66, Mapped, 4833, 3),
+    (0xFF67, Mapped, 9993, 3), (0xFF68, Mapped, 9996, 3), (0xFF69, Mapped, 9999, 3), (0xFF6A, Mapped, 10002, 3),
+    (0xFF6B, Mapped, 10005, 3), (0xFF6C, Mapped, 10008, 3), (0xFF6D, Mapped, 10011, 3), (0xFF6E, Mapped, 10014, 3),
+    (0xFF6F, Mapped, 10017, 3), (0xFF70, Mapped, 10020, 3), (0xFF71, Mapped, 4695, 3), (0xFF72, Mapped, 4698, 3),
+    (0xFF73, Mapped, 4701, 3), (0xFF74, Mapped, 4704, 3), (0xFF75, Mapped, 4707, 3), (0xFF76, Mapped, 4710, 3),
+    (0xFF77, Mapped, 4713, 3), (0xFF78, Mapped, 4716, 3), (0xFF79, Mapped, 4719, 3), (0xFF7A, Mapped, 4722, 3),
+    (0xFF7B, Mapped, 4725, 3), (0xFF7C, Mapped, 4728, 3), (0xFF7D, Mapped, 4731, 3), (0xFF7E, Mapped, 4734, 3),
+    (0xFF7F, Mapped, 4737, 3), (0xFF80, Mapped, 4740, 3), (0xFF81, Mapped, 4743, 3), (0xFF82, Mapped, 4746, 3),
+    (0xFF83, Mapped, 4749, 3), (0xFF84, Mapped, 4752, 3), (0xFF85, Mapped, 4755, 3), (0xFF86, Mapped, 4758, 3),
+    (0xFF87, Mapped, 4761, 3), (0xFF88, Mapped, 4764, 3), (0xFF89, Mapped, 4767, 3), (0xFF8A, Mapped, 4770, 3),
+    (0xFF8B, Mapped, 4773, 3), (0xFF8C, Mapped, 4776, 3), (0xFF8D, Mapped, 4779, 3), (0xFF8E, Mapped, 4782, 3),
+    (0xFF8F, Mapped, 4785, 3), (0xFF90, Mapped, 4788, 3), (0xFF91, Mapped, 4791, 3), (0xFF92, Mapped, 4794, 3),
+    (0xFF93, Mapped, 4797, 3), (0xFF94, Mapped, 4800, 3), (0xFF95, Mapped, 4803, 3), (0xFF96, Mapped, 4806, 3),
+    (0xFF97, Mapped, 4809, 3), (0xFF98, Mapped, 4812, 3), (0xFF99, Mapped, 4815, 3), (0xFF9A, Mapped, 4818, 3),
+    (0xFF9B, Mapped, 4821, 3), (0xFF9C, Mapped, 4824, 3), (0xFF9D, Mapped, 10023, 3), (0xFF9E, Mapped, 10026, 3),
+    (0xFF9F, Mapped, 10029, 3), (0xFFA0, Disallowed, 0, 0), (0xFFA1, Mapped, 3759, 3), (0xFFA2, Mapped, 3762, 3),
+    (0xFFA3, Mapped, 3765, 3), (0xFFA4, Mapped, 3768, 3), (0xFFA5, Mapped, 3771, 3), (0xFFA6, Mapped, 3774, 3),
+    (0xFFA7, Mapped, 3777, 3), (0xFFA8, Mapped, 3780, 3), (0xFFA9, Mapped, 3783, 3), (0xFFAA, Mapped, 3786, 3),
+    (0xFFAB, Mapped, 3789, 3), (0xFFAC, Mapped, 3792, 3), (0xFFAD, Mapped, 3795, 3), (0xFFAE, Mapped, 3798, 3),
+    (0xFFAF, Mapped, 3801, 3), (0xFFB0, Mapped, 3804, 3), (0xFFB1, Mapped, 3807, 3), (0xFFB2, Mapped, 3810, 3),
+    (0xFFB3, Mapped, 3813, 3), (0xFFB4, Mapped, 3816, 3), (0xFFB5, Mapped, 3819, 3), (0xFFB6, Mapped, 3822, 3),
+    (0xFFB7, Mapped, 3825, 3), (0xFFB8, Mapped, 3828, 3), (0xFFB9, Mapped, 3831, 3), (0xFFBA, Mapped, 3834, 3),
+    (0xFFBB, Mapped, 3837, 3), (0xFFBC, Mapped, 3840, 3), (0xFFBD, Mapped, 3843, 3), (0xFFBE, Mapped, 3846, 3),
+    (0xFFBF, Disallowed, 0, 0), (0xFFC2, Mapped, 3849, 3), (0xFFC3, Mapped, 3852, 3), (0xFFC4, Mapped, 3855, 3),
+    (0xFFC5, Mapped, 3858, 3), (0xFFC6, Mapped, 3861, 3), (0xFFC7, Mapped, 3864, 3), (0xFFC8, Disallowed, 0, 0),
+    (0xFFCA, Mapped, 3867, 3), (0xFFCB, Mapped, 3870, 3), (0xFFCC, Mapped, 3873, 3), (0xFFCD, Mapped, 3876, 3),
+    (0xFFCE, Mapped, 3879, 3), (0xFFCF, Mapped, 3882, 3), (0xFFD0, Disallowed, 0, 0), (0xFFD2, Mapped, 3885, 3),
+    (0xFFD3, Mapped, 3888, 3), (0xFFD4, Mapped, 3891, 3), (0xFFD5, Mapped, 3894, 3), (0xFFD6, Mapped, 3897, 3),
+    (0xFFD7, Mapped, 3900, 3), (0xFFD8, Disallowed, 0, 0), (0xFFDA, Mapped, 3903, 3), (0xFFDB, Mapped, 3906, 3),
+    (0xFFDC, Mapped, 3909, 3), (0xFFDD, Disallowed, 0, 0), (0xFFE0, Mapped, 10032, 2), (0xFFE1, Mapped, 10034, 2),
+    (0xFFE2, Mapped, 10036, 2), (0xFFE3, Std3Mapped, 30, 3), (0xFFE4, Mapped, 10038, 2), (0xFFE5, Mapped, 10040, 2),
+    (0xFFE6, Mapped, 10042, 3), (0xFFE7, Disallowed, 0, 0), (0xFFE8, Mapped, 10045, 3), (0xFFE9, Mapped, 10048, 3),
+    (0xFFEA, Mapped, 10051, 3), (0xFFEB, Mapped, 10054, 3), (0xFFEC, Mapped, 10057, 3), (0xFFED, Mapped, 10060, 3),
+    (0xFFEE, Mapped, 10063, 3), (0xFFEF, Disallowed, 0, 0), (0x10000, Valid, 0, 0), (0x1000C, Disallowed, 0, 0),
+    (0x1000D, Valid, 0, 0), (0x10027, Disallowed, 0, 0), (0x10028, Valid, 0, 0), (0x1003B, Disallowed, 0, 0),
+    (0x1003C, Valid, 0, 0), (0x1003E, Disallowed, 0, 0), (0x1003F, Valid, 0, 0), (0x1004E, Disallowed, 0, 0),
+    (0x10050, Valid, 0, 0), (0x1005E, Disallowed, 0, 0), (0x10080, Valid, 0, 0), (0x100FB, Disallowed, 0, 0),
+    (0x10100, Valid, 0, 0), (0x10103, Disallowed, 0, 0), (0x10107, Valid, 0, 0), (0x10134, Disallowed, 0, 0),
+    (0x10137, Valid, 0, 0), (0x1018F, Disallowed, 0, 0), (0x10190, Valid, 0, 0), (0x1019D, Disallowed, 0, 0),
+    (0x101A0, Valid, 0, 0), (0x101A1, Disallowed, 0, 0), (0x101D0, Valid, 0, 0), (0x101FE, Disallowed, 0, 0),
+    (0x10280, Valid, 0, 0), (0x1029D, Disallowed, 0, 0), (0x102A0, Valid, 0, 0), (0x102D1, Disallowed, 0, 0),
+    (0x102E0, Valid, 0, 0), (0x102FC, Disallowed, 0, 0), (0x10300, Valid, 0, 0), (0x10324, Disallowed, 0, 0),
+    (0x1032D, Valid, 0, 0), (0x1034B, Disallowed, 0, 0), (0x10350, Valid, 0, 0), (0x1037B, Disallowed, 0, 0),
+    (0x10380, Valid, 0, 0), (0x1039E, Disallowed, 0, 0), (0x1039F, Valid, 0, 0), (0x103C4, Disallowed, 0, 0),
+    (0x103C8, Valid, 0, 0), (0x103D6, Disallowed, 0, 0), (0x10400, Mapped, 10066, 4), (0x10401, Mapped, 10070, 4),
+    (0x10402, Mapped, 10074, 4), (0x10403, Mapped, 10078, 4), (0x10404, Mapped, 10082, 4), (0x10405, Mapped, 10086, 4),
+    (0x10406, Mapped, 10090, 4), (0x10407, Mapped, 10094, 4), (0x10408, Mapped, 10098, 4), (0x10409, Mapped, 10102, 4),
+    (0x1040A, Mapped, 10106, 4), (0x1040B, Mapped, 10110, 4), (0x1040C, Mapped, 10114, 4), (0x1040D, Mapped, 10118, 4),
+    (0x1040E, Mapped, 10122, 4), (0x1040F, Mapped, 10126, 4), (0x10410, Mapped, 10130, 4), (0x10411, Mapped, 10134, 4),
+    (0x10412, Mapped, 10138, 4), (0x10413, Mapped, 10142, 4), (0x10414, Mapped, 10146, 4), (0x10415, Mapped, 10150, 4),
+    (0x10416, Mapped, 10154, 4), (0x10417, Mapped, 10158, 4), (0x10418, Mapped, 10162, 4), (0x10419, Mapped, 10166, 4),
+    (0x1041A, Mapped, 10170, 4), (0x1041B, Mapped, 10174, 4), (0x1041C, Mapped, 10178, 4), (0x1041D, Mapped, 10182, 4),
+    (0x1041E, Mapped, 10186, 4), (0x1041F, Mapped, 10190, 4), (0x10420, Mapped, 10194, 4), (0x10421, Mapped, 10198, 4),
+    (0x10422, Mapped, 10202, 4), (0x10423, Mapped, 10206, 4), (0x10424, Mapped, 10210, 4), (0x10425, Mapped, 10214, 4),
+    (0x10426, Mapped, 10218, 4), (0x10427, Mapped, 10222, 4), (0x10428, Valid, 0, 0), (0x1049E, Disallowed, 0, 0),
+    (0x104A0, Valid, 0, 0), (0x104AA, Disallowed, 0, 0), (0x104B0, Mapped, 10226, 4), (0x104B1, Mapped, 10230, 4),
+    (0x104B2, Mapped, 10234, 4), (0x104B3, Mapped, 10238, 4), (0x104B4, Mapped, 10242, 4), (0x104B5, Mapped, 10246, 4),
+    (0x104B6, Mapped, 10250, 4), (0x104B7, Mapped, 10254, 4), (0x104B8, Mapped, 10258, 4), (0x104B9, Mapped, 10262, 4),
+    (0x104BA, Mapped, 10266, 4), (0x104BB, Mapped, 10270, 4), (0x104BC, Mapped, 10274, 4), (0x104BD, Mapped, 10278, 4),
+    (0x104BE, Mapped, 10282, 4), (0x104BF, Mapped, 10286, 4), (0x104C0, Mapped, 10290, 4), (0x104C1, Mapped, 10294, 4),
+    (0x104C2, Mapped, 10298, 4), (0x104C3, Mapped, 10302, 4), (0x104C4, Mapped, 10306, 4), (0x104C5, Mapped, 10310, 4),
+    (0x104C6, Mapped, 10314, 4), (0x104C7, Mapped, 10318, 4), (0x104C8, Mapped, 10322, 4), (0x104C9, Mapped, 10326, 4),
+    (0x104CA, Mapped, 10330, 4), (0x104CB, Mapped, 10334, 4), (0x104CC, Mapped, 10338, 4), (0x104CD, Mapped, 10342, 4),
+    (0x104CE, Mapped, 10346, 4), (0x104CF, Mapped, 10350, 4), (0x104D0, Mapped, 10354, 4), (0x104D1, Mapped, 10358, 4),
+    (0x104D2, Mapped, 10362, 4), (0x104D3, Mapped, 10366, 4), (0x104D4, Disallowed, 0, 0), (0x104D8, Valid, 0, 0),
+    (0x104FC, Disallowed, 0, 0), (0x10500, Valid, 0, 0), (0x10528, Disallowed, 0, 0), (0x10530, Valid, 0, 0),
+    (0x10564, Disallowed, 0, 0), (0x1056F, Valid, 0, 0), (0x10570, Mapped, 10370, 4), (0x10571, Mapped, 10374, 4),
+    (0x10572, Mapped, 10378, 4), (0x10573, Mapped, 10382, 4), (0x10574, Mapped, 10386, 4), (0x10575, Mapped, 10390, 4),
+    (0x10576, Mapped, 10394, 4), (0x10577, Mapped, 10398, 4), (0x10578, Mapped, 10402, 4), (0x10579, Mapped, 10406, 4),
+    (0x1057A, Mapped, 10410, 4), (0x1057B, Disallowed, 0, 0), (0x1057C, Mapped, 10414, 4), (0x1057D, Mapped, 10418, 4),
+    (0x1057E, Mapped, 10422, 4), (0x1057F, Mapped, 10426, 4), (0x10580, Mapped, 10430, 4), (0x10581, Mapped, 10434, 4),
+    (0x10582, Mapped, 10438, 4), (0x10583, Mapped, 10442, 4), (0x10584, Mapped, 10446, 4), (0x10585, Mapped, 10450, 4),
+    (0x10586, Mapped, 10454, 4), (0x10587, Mapped, 10458, 4), (0x10588, Mapped, 10462, 4), (0x10589, Mapped, 10466, 4),
+    (0x1058A, Mapped, 10470, 4), (0x1058B, Disallowed, 0, 0), (0x1058C, Mapped, 10474, 4), (0x1058D, Mapped, 10478, 4),
+    (0x1058E, Mapped, 10482, 4), (0x1058F, Mapped, 10486, 4), (0x10590, Mapped, 10490, 4), (0x10591, Mapped, 10494, 4),
+    (0x10592, Mapped, 10498, 4), (0x10593, Disallowed, 0, 0), (0x10594, Mapped, 10502, 4), (0x10595, Mapped, 10506, 4),
+    (0x10596, Disallowed, 0, 0), (0x10597, Valid, 0, 0), (0x105A2, Disallowed, 0, 0), (0x105A3, Valid, 0, 0),
+    (0x105B2, Disallowed, 0, 0), (0x105B3, Valid, 0, 0), (0x105BA, Disallowed, 0, 0), (0x105BB, Valid, 0, 0),
+    (0x105BD, Disallowed, 0, 0), (0x10600, Valid, 0, 0), (0x10737, Disallowed, 0, 0), (0x10740, Valid, 0, 0),
+    (0x10756, Disallowed, 0, 0), (0x10760, Valid, 0, 0), (0x10768, Disallowed, 0, 0), (0x10780, Valid, 0, 0),
+    (0x10781, Mapped, 10510, 2), (0x10782, Mapped, 10512, 2), (0x10783, Mapped, 71, 2), (0x10784, Mapped, 10514, 2),
+    (0x10785, Mapped, 252, 2), (0x10786, Disallowed, 0, 0), (0x10787, Mapped, 10516, 2), (0x10788, Mapped, 10518, 3),
+    (0x10789, Mapped, 10521, 2), (0x1078A, Mapped, 10523, 2), (0x1078B, Mapped, 262, 2), (0x1078C, Mapped, 264, 2),
+    (0x1078D, Mapped, 10525, 3), (0x1078E, Mapped, 10528, 2), (0x1078F, Mapped, 10530, 2), (0x10790, Mapped, 10532, 2),
+    (0x10791, Mapped, 10534, 2), (0x10792, Mapped, 10536, 2), (0x10793, Mapped, 276, 2), (0x10794, Mapped, 10538, 2),
+    (0x10795, Mapped, 159, 2), (0x10796, Mapped, 10540, 2), (0x10797, Mapped, 10542, 2), (0x10798, Mapped, 10544, 2),
+    (0x10799, Mapped, 10546, 2), (0x1079A, Mapped, 10548, 2), (0x1079B, Mapped, 6592, 2), (0x1079C, Mapped, 10550, 4),
+    (0x1079D, Mapped, 10554, 3), (0x1079E, Mapped, 10557, 2), (0x1079F, Mapped, 10559, 4), (0x107A0, Mapped, 10563, 2),
+    (0x107A1, Mapped, 10565, 4), (0x107A2, Mapped, 105, 2), (0x107A3, Mapped, 10569, 2), (0x107A4, Mapped, 10571, 2),
+    (0x107A5, Mapped, 16, 1), (0x107A6, Mapped, 10573, 2), (0x107A7, Mapped, 10575, 4), (0x107A8, Mapped, 2898, 2),
+    (0x107A9, Mapped, 10579, 2), (0x107AA, Mapped, 298, 2), (0x107AB, Mapped, 10581, 2), (0x107AC, Mapped, 10583, 2),
+    (0x107AD, Mapped, 10585, 3), (0x107AE, Mapped, 10588, 2), (0x107AF, Mapped, 306, 2), (0x107B0, Mapped, 10590, 3),
+    (0x107B1, Disallowed, 0, 0), (0x107B2, Mapped, 10593, 2), (0x107B3, Mapped, 10595, 2), (0x107B4, Mapped, 10597, 2),
+    (0x107B5, Mapped, 10599, 2), (0x107B6, Mapped, 10601, 2), (0x107B7, Mapped, 10603, 2), (0x107B8, Mapped, 10605, 2),
+    (0x107B9, Mapped, 10607, 4), (0x107BA, Mapped, 10611, 4), (0x107BB, Disallowed, 0, 0), (0x10800, Valid, 0, 0),
+    (0x10806, Disallowed, 0, 0), (0x10808, Valid, 0, 0), (0x10809, Disallowed, 0, 0), (0x1080A, Valid, 0, 0),
+    (0x10836, Disallowed, 0, 0), (0x10837, Valid, 0, 0), (0x10839, Disallowed, 0, 0), (0x1083C, Valid, 0, 0),
+    (0x1083D, Disallowed, 0, 0), (0x1083F, Valid, 0, 0), (0x10856, Disallowed, 0, 0), (0x10857, Valid, 0, 0),
+    (0x1089F, Disallowed, 0, 0), (0x108A7, Valid, 0, 0), (0x108B0, Disallowed, 0, 0), (0x108E0, Valid, 0, 0),
+    (0x108F3, Disallowed, 0, 0), (0x108F4, Valid, 0, 0), (0x108F6, Disallowed, 0, 0), (0x108FB, Valid, 0, 0),
+    (0x1091C, Disallowed, 0, 0), (0x1091F, Valid, 0, 0), (0x1093A, Disallowed, 0, 0), (0x1093F, Valid, 0, 0),
+    (0x10940, Disallowed, 0, 0), (0x10980, Valid, 0, 0), (0x109B8, Disallowed, 0, 0), (0x109BC, Valid, 0, 0),
+    (0x109D0, Disallowed, 0, 0), (0x109D2, Valid, 0, 0), (0x10A04, Disallowed, 0, 0), (0x10A05, Valid, 0, 0),
+    (0x10A07, Disallowed, 0, 0), (0x10A0C, Valid, 0, 0), (0x10A14, Disallowed, 0, 0), (0x10A15, Valid, 0, 0),
+    (0x10A18, Disallowed, 0, 0), (0x10A19, Valid, 0, 0), (0x10A36, Disallowed, 0, 0), (0x10A38, Valid, 0, 0),
+    (0x10A3B, Disallowed, 0, 0), (0x10A3F, Valid, 0, 0), (0x10A49, Disallowed, 0, 0), (0x10A50, Valid, 0, 0),
+    (0x10A59, Disallowed, 0, 0), (0x10A60, Valid, 0, 0), (0x10AA0, Disallowed, 0, 0), (0x10AC0, Valid, 0, 0),
+    (0x10AE7, Disallowed, 0, 0), (0x10AEB, Valid, 0, 0), (0x10AF7, Disallowed, 0, 0), (0x10B00, Valid, 0, 0),
+    (0x10B36, Disallowed, 0, 0), (0x10B39, Valid, 0, 0), (0x10B56, Disallowed, 0, 0), (0x10B58, Valid, 0, 0),
+    (0x10B73, Disallowed, 0, 0), (0x10B78, Valid, 0, 0), (0x10B92, Disallowed, 0, 0), (0x10B99, Valid, 0, 0),
+    (0x10B9D, Disallowed, 0, 0), (0x10BA9, Valid, 0, 0), (0x10BB0, Disallowed, 0, 0), (0x10C00, Valid, 0, 0),
+    (0x10C49, Disallowed, 0, 0), (0x10C80, Mapped, 10615, 4), (0x10C81, Mapped, 10619, 4), (0x10C82, Mapped, 10623, 4),
+    (0x10C83, Mapped, 10627, 4), (0x10C84, Mapped, 10631, 4), (0x10C85, Mapped, 10635, 4), (0x10C86, Mapped, 10639, 4),
+    (0x10C87, Mapped, 10643, 4), (0x10C88, Mapped, 10647, 4), (0x10C89, Mapped, 10651, 4), (0x10C8A, Mapped, 10655, 4),
+    (0x10C8B, Mapped, 10659, 4), (0x10C8C, Mapped, 10663, 4), (0x10C8D, Mapped, 10667, 4), (0x10C8E, Mapped, 10671, 4),
+    (0x10C8F, Mapped, 10675, 4), (0x10C90, Mapped, 10679, 4), (0x10C91, Mapped, 10683, 4), (0x10C92, Mapped, 10687, 4),
+    (0x10C93, Mapped, 10691, 4), (0x10C94, Mapped, 10695, 4), (0x10C95, Mapped, 10699, 4), (0x10C96, Mapped, 10703, 4),
+    (0x10C97, Mapped, 10707, 4), (0x10C98, Mapped, 10711, 4), (0x10C99, Mapped, 10715, 4), (0x10C9A, Mapped, 10719, 4),
+    (0x10C9B, Mapped, 10723, 4), (0x10C9C, Mapped, 10727, 4), (0x10C9D, Mapped, 10731, 4), (0x10C9E, Mapped, 10735, 4),
+    (0x10C9F, Mapped, 10739, 4), (0x10CA0, Mapped, 10743, 4), (0x10CA1, Mapped, 10747, 4), (0x10CA2, Mapped, 10751, 4),
+    (0x10CA3, Mapped, 10755, 4), (0x10CA4, Mapped, 10759, 4), (0x10CA5, Mapped, 10763, 4), (0x10CA6, Mapped, 10767, 4),
+    (0x10CA7, Mapped, 10771, 4), (0x10CA8, Mapped, 10775, 4), (0x10CA9, Mapped, 10779, 4), (0x10CAA, Mapped, 10783, 4),
+    (0x10CAB, Mapped, 10787, 4), (0x10CAC, Mapped, 10791, 4), (0x10CAD, Mapped, 10795, 4), (0x10CAE, Mapped, 10799, 4),
+    (0x10CAF, Mapped, 10803, 4), (0x10CB0, Mapped, 10807, 4), (0x10CB1, Mapped, 10811, 4), (0x10CB2, Mapped, 10815, 4),
+    (0x10CB3, Disallowed, 0, 0), (0x10CC0, Valid, 0, 0), (0x10CF3, Disallowed, 0, 0), (0x10CFA, Valid, 0, 0),
+    (0x10D28, Disallowed, 0, 0), (0x10D30, Valid, 0, 0), (0x10D3A, Disallowed, 0, 0), (0x10E60, Valid, 0, 0),
+    (0x10E7F, Disallowed, 0, 0), (0x10E80, Valid, 0, 0), (0x10EAA, Disallowed, 0, 0), (0x10EAB, Valid, 0, 0),
+    (0x10EAE, Disallowed, 0, 0), (0x10EB0, Valid, 0, 0), (0x10EB2, Disallowed, 0, 0), (0x10EFD, Valid, 0, 0),
+    (0x10F28, Disallowed, 0, 0), (0x10F30, Valid, 0, 0), (0x10F5A, Disallowed, 0, 0), (0x10F70, Valid, 0, 0),
+    (0x10F8A, Disallowed, 0, 0), (0x10FB0, Valid, 0, 0), (0x10FCC, Disallowed, 0, 0), (0x10FE0, Valid, 0, 0),
+    (0x10FF7, Disallowed, 0, 0), (0x11000, Valid, 0, 0), (0x1104E, Disallowed, 0, 0), (0x11052, Valid, 0, 0),
+    (0x11076, Disallowed, 0, 0), (0x1107F, Valid, 0, 0), (0x110BD, Disallowed, 0, 0), (0x110BE, Valid, 0, 0),
+    (0x110C3, Disallowed, 0, 0), (0x110D0, Valid, 0, 0), (0x110E9, Disallowed, 0, 0), (0x110F0, Valid, 0, 0),
+    (0x110FA, Disallowed, 0, 0), (0x11100, Valid, 0, 0), (0x11135, Disallowed, 0, 0), (0x11136, Valid, 0, 0),
+    (0x11148, Disallowed, 0, 0), (0x11150, Valid, 0, 0), (0x11177, Disallowed, 0, 0), (0x11180, Valid, 0, 0),
+    (0x111E0, Disallowed, 0, 0), (0x111E1, Valid, 0, 0), (0x111F5, Disallowed, 0, 0), (0x11200, Valid, 0, 0),
+    (0x11212, Disallowed, 0, 0), (0x11213, Valid, 0, 0), (0x11242, Disallowed, 0, 0), (0x11280, Valid, 0, 0),
+    (0x11287, Disallowed, 0, 0), (0x11288, Valid, 0, 0), (0x11289, Disallowed, 0, 0), (0x1128A, Valid, 0, 0),
+    (0x1128E, Disallowed, 0, 0), (0x1128F, Valid, 0, 0), (0x1129E, Disallowed, 0, 0), (0x1129F, Valid, 0, 0),
+    (0x112AA, Disallowed, 0, 0), (0x112B0, Valid, 0, 0), (0x112EB, Disallowed, 0, 0), (0x112F0, Valid, 0, 0),
+    (0x112FA, Disallowed, 0, 0), (0x11300, Valid, 0, 0), (0x11304, Disallowed, 0, 0), (0x11305, Valid, 0, 0),
+    (0x1130D, Disallowed, 0, 0), (0x1130F, Valid, 0, 0), (0x11311, Disallowed, 0, 0), (0x11313, Valid, 0, 0),
+    (0x11329, Disallowed, 0, 0), (0x1132A, Valid, 0, 0), (0x11331, Disallowed, 0, 0), (0x11332, Valid, 0, 0),
+    (0x11334, Disallowed, 0, 0), (0x11335, Valid, 0, 0), (0x1133A, Disallowed, 0, 0), (0x1133B, Valid, 0, 0),
+    (0x11345, Disallowed, 0, 0), (0x11347, Valid, 0, 0), (0x11349, Disallowed, 0, 0), (0x1134B, Valid, 0, 0),
+    (0x1134E, Disallowed, 0, 0), (0x11350, Valid, 0, 0), (0x11351, Disallowed, 0, 0), (0x11357, Valid, 0, 0),
+    (0x11358, Disallowed, 0, 0), (0x1135D, Valid, 0, 0), (0x11364, Disallowed, 0, 0), (0x11366, Valid, 0, 0),
+    (0x1136D, Disallowed, 0, 0), (0x11370, Valid, 0, 0), (0x11375, Disallowed, 0, 0), (0x11400, Valid, 0, 0),
+    (0x1145C, Disallowed, 0, 0), (0x1145D, Valid, 0, 0), (0x11462, Disallowed, 0, 0), (0x11480, Valid, 0, 0),
+    (0x114C8, Disallowed, 0, 0), (0x114D0, Valid, 0, 0), (0x114DA, Disallowed, 0, 0), (0x11580, Valid, 0, 0),
+    (0x115B6, Disallowed, 0, 0), (0x115B8, Valid, 0, 0), (0x115DE, Disallowed, 0, 0), (0x11600, Valid, 0, 0),
+    (0x11645, Disallowed, 0, 0), (0x11650, Valid, 0, 0), (0x1165A, Disallowed, 0, 0), (0x11660, Valid, 0, 0),
+    (0x1166D, Disallowed, 0, 0), (0x11680, Valid, 0, 0), (0x116BA, Disallowed, 0, 0), (0x116C0, Valid, 0, 0),
+    (0x116CA, Disallowed, 0, 0), (0x11700, Valid, 0, 0), (0x1171B, Disallowed, 0, 0), (0x1171D, Valid, 0, 0),
+    (0x1172C, Disallowed, 0, 0), (0x11730, Valid, 0, 0), (0x11747, Disallowed, 0, 0), (0x11800, Valid, 0, 0),
+    (0x1183C, Disallowed, 0, 0), (0x118A0, Mapped, 10819, 4), (0x118A1, Mapped, 10823, 4), (0x118A2, Mapped, 10827, 4),
+    (0x118A3, Mapped, 10831, 4), (0x118A4, Mapped, 10835, 4), (0x118A5, Mapped, 10839, 4), (0x118A6, Mapped, 10843, 4),
+    (0x118A7, Mapped, 10847, 4), (0x118A8, Mapped, 10851, 4), (0x118A9, Mapped, 10855, 4), (0x118AA, Mapped, 10859, 4),
+    (0x118AB, Mapped, 10863, 4), (0x118AC, Mapped, 10867, 4), (0x118AD, Mapped, 10871, 4), (0x118AE, Mapped, 10875, 4),
+    (0x118AF, Mapped, 10879, 4), (0x118B0, Mapped, 10883, 4), (0x118B1, Mapped, 10887, 4), (0x118B2, Mapped, 10891, 4),
+    (0x118B3, Mapped, 10895, 4), (0x118B4, Mapped, 10899, 4), (0x118B5, Mapped, 10903, 4), (0x118B6, Mapped, 10907, 4),
+    (0x118B7, Mapped, 10911, 4), (0x118B8, Mapped, 10915, 4), (0x118B9, Mapped, 10919, 4), (0x118BA, Mapped, 10923, 4),
+    (0x118BB, Mapped, 10927, 4), (0x118BC, Mapped, 10931, 4), (0x118BD, Mapped, 10935, 4), (0x118BE, Mapped, 10939, 4),
+    (0x118BF, Mapped, 10943, 4), (0x118C0, Valid, 0, 0), (0x118F3, Disallowed, 0, 0), (0x118FF, Valid, 0, 0),
+    (0x11907, Disallowed, 0, 0), (0x11909, Valid, 0, 0), (0x1190A, Disallowed, 0, 0), (0x1190C, Valid, 0, 0),
+    (0x11914, Disallowed, 0, 0), (0x11915, Valid, 0, 0), (0x11917, Disallowed, 0, 0), (0x11918, Valid, 0, 0),
+    (0x11936, Disallowed, 0, 0), (0x11937, Valid, 0, 0), (0x11939, Disallowed, 0, 0), (0x1193B, Valid, 0, 0),
+    (0x11947, Disallowed, 0, 0), (0x11950, Valid, 0, 0), (0x1195A, Disallowed, 0, 0), (0x119A0, Valid, 0, 0),
+    (0x119A8, Disallowed, 0, 0), (0x119AA, Valid, 0, 0), (0x119D8, Disallowed, 0, 0), (0x119DA, Valid, 0, 0),
+    (0x119E5, Disallowed, 0, 0), (0x11A00, Valid, 0, 0), (0x11A48, Disallowed, 0, 0), (0x11A50, Valid, 0, 0),
+    (0x11AA3, Disallowed, 0, 0), (0x11AB0, Valid, 0, 0), (0x11AF9, Disallowed, 0, 0), (0x11B00, Valid, 0, 0),
+    (0x11B0A, Disallowed, 0, 0), (0x11C00, Valid, 0, 0), (0x11C09, Disallowed, 0, 0), (0x11C0A, Valid, 0, 0),
+    (0x11C37, Disallowed, 0, 0), (0x11C38, Valid, 0, 0), (0x11C46, Disallowed, 0, 0), (0x11C50, Valid, 0, 0),
+    (0x11C6D, Disallowed, 0, 0), (0x11C70, Valid, 0, 0), (0x11C90, Disallowed, 0, 0), (0x11C92, Valid, 0, 0),
+    (0x11CA8, Disallowed, 0, 0), (0x11CA9, Valid, 0, 0), (0x11CB7, Disallowed, 0, 0), (0x11D00, Valid, 0, 0),
+    (0x11D07, Disallowed, 0, 0), (0x11D08, Valid, 0, 0), (0x11D0A, Disallowed, 0, 0), (0x11D0B, Valid, 0, 0),
+    (0x11D37, Disallowed, 0, 0), (0x11D3A, Valid, 0, 0), (0x11D3B, Disallowed, 0, 0), (0x11D3C, Valid, 0, 0),
+    (0x11D3E, Disallowed, 0, 0), (0x11D3F, Valid, 0, 0), (0x11D48, Disallowed, 0, 0), (0x11D50, Valid, 0, 0),
+    (0x11D5A, Disallowed, 0, 0), (0x11D60, Valid, 0, 0), (0x11D66, Disallowed, 0, 0), (0x11D67, Valid, 0, 0),
+    (0x11D69, Disallowed, 0, 0), (0x11D6A, Valid, 0, 0), (0x11D8F, Disallowed, 0, 0), (0x11D90, Valid, 0, 0),
+    (0x11D92, Disallowed, 0, 0), (0x11D93, Valid, 0, 0), (0x11D99, Disallowed, 0, 0), (0x11DA0, Valid, 0, 0),
+    (0x11DAA, Disallowed, 0, 0), (0x11EE0, Valid, 0, 0), (0x11EF9, Disallowed, 0, 0), (0x11F00, Valid, 0, 0),
+    (0x11F11, Disallowed, 0, 0), (0x11F12, Valid, 0, 0), (0x11F3B, Disallowed, 0, 0), (0x11F3E, Valid, 0, 0),
+    (0x11F5A, Disallowed, 0, 0), (0x11FB0, Valid, 0, 0), (0x11FB1, Disallowed, 0, 0), (0x11FC0, Valid, 0, 0),
+    (0x11FF2, Disallowed, 0, 0), (0x11FFF, Valid, 0, 0), (0x1239A, Disallowed, 0, 0), (0x12400, Valid, 0, 0),
+    (0x1246F, Disallowed, 0, 0), (0x12470, Valid, 0, 0), (0x12475, Disallowed, 0, 0), (0x12480, Valid, 0, 0),
+    (0x12544, Disallowed, 0, 0), (0x12F90, Valid, 0, 0), (0x12FF3, Disallowed, 0, 0), (0x13000, Valid, 0, 0),
+    (0x13430, Disallowed, 0, 0), (0x13440, Valid, 0, 0), (0x13456, Disallowed, 0, 0), (0x14400, Valid, 0, 0),
+    (0x14647, Disallowed, 0, 0), (0x16800, Valid, 0, 0), (0x16A39, Disallowed, 0, 0), (0x16A40, Valid, 0, 0),
+    (0x16A5F, Disallowed, 0, 0), (0x16A60, Valid, 0, 0), (0x16A6A, Disallowed, 0, 0), (0x16A6E, Valid, 0, 0),
+    (0x16ABF, Disallowed, 0, 0), (0x16AC0, Valid, 0, 0), (0x16ACA, Disallowed, 0, 0), (0x16AD0, Valid, 0, 0),
+    (0x16AEE, Disallowed, 0, 0), (0x16AF0, Valid, 0, 0), (0x16AF6, Disallowed, 0, 0), (0x16B00, Valid, 0, 0),
+    (0x16B46, Disallowed, 0, 0), (0x16B50, Valid, 0, 0), (0x16B5A, Disallowed, 0, 0), (0x16B5B, Valid, 0, 0),
+    (0x16B62, Disallowed, 0, 0), (0x16B63, Valid, 0, 0), (0x16B78, Disallowed, 0, 0), (0x16B7D, Valid, 0, 0),
+    (0x16B90, Disallowed, 0, 0), (0x16E40, Mapped, 10947, 4), (0x16E41, Mapped, 10951, 4), (0x16E42, Mapped, 10955, 4),
+    (0x16E43, Mapped, 10959, 4), (0x16E44, Mapped, 10963, 4), (0x16E45, Mapped, 10967, 4), (0x16E46, Mapped, 10971, 4),
+    (0x16E47, Mapped, 10975, 4), (0x16E48, Mapped, 10979, 4), (0x16E49, Mapped, 10983, 4), (0x16E4A, Mapped, 10987, 4),
+    (0x16E4B, Mapped, 10991, 4), (0x16E4C, Mapped, 10995, 4), (0x16E4D, Mapped, 10999, 4), (0x16E4E, Mapped, 11003, 4),
+    (0x16E4F, Mapped, 11007, 4), (0x16E50, Mapped, 11011, 4), (0x16E51, Mapped, 11015, 4), (0x16E52, Mapped, 11019, 4),
+    (0x16E53, Mapped, 11023, 4), (0x16E54, Mapped, 11027, 4), (0x16E55, Mapped, 11031, 4), (0x16E56, Mapped, 11035, 4),
+    (0x16E57, Mapped, 11039, 4), (0x16E58, Mapped, 11043, 4), (0x16E59, Mapped, 11047, 4), (0x16E5A, Mapped, 11051, 4),
+    (0x16E5B, Mapped, 11055, 4), (0x16E5C, Mapped, 11059, 4), (0x16E5D, Mapped, 11063, 4), (0x16E5E, Mapped, 11067, 4),
+    (0x16E5F, Mapped, 11071, 4), (0x16E60, Valid, 0, 0), (0x16E9B, Disallowed, 0, 0), (0x16F00, Valid, 0, 0),
+    (0x16F4B, Disallowed, 0, 0), (0x16F4F, Valid, 0, 0), (0x16F88, Disallowed, 0, 0), (0x16F8F, Valid, 0, 0),
+    (0x16FA0, Disallowed, 0, 0), (0x16FE0, Valid, 0, 0), (0x16FE5, Disallowed, 0, 0), (0x16FF0, Valid, 0, 0),
+    (0x16FF2, Disallowed, 0, 0), (0x17000, Valid, 0, 0), (0x187F8, Disallowed, 0, 0), (0x18800, Valid, 0, 0),
+    (0x18CD6, Disallowed, 0, 0), (0x18D00, Valid, 0, 0), (0x18D09, Disallowed, 0, 0), (0x1AFF0, Valid, 0, 0),
+    (0x1AFF4, Disallowed, 0, 0), (0x1AFF5, Valid, 0, 0), (0x1AFFC, Disallowed, 0, 0), (0x1AFFD, Valid, 0, 0),
+    (0x1AFFF, Disallowed, 0, 0), (0x1B000, Valid, 0, 0), (0x1B123, Disallowed, 0, 0), (0x1B132, Valid, 0, 0),
+    (0x1B133, Disallowed, 0, 0), (0x1B150, Valid, 0, 0), (0x1B153, Disallowed, 0, 0), (0x1B155, Valid, 0, 0),
+    (0x1B156, Disallowed, 0, 0), (0x1B164, Valid, 0, 0), (0x1B168, Disallowed, 0, 0), (0x1B170, Valid, 0, 0),
+    (0x1B2FC, Disallowed, 0, 0), (0x1BC00, Valid, 0, 0), (0x1BC6B, Disallowed, 0, 0), (0x1BC70, Valid, 0, 0),
+    (0x1BC7D, Disallowed, 0, 0), (0x1BC80, Valid, 0, 0), (0x1BC89, Disallowed, 0, 0), (0x1BC90, Valid, 0, 0),
+    (0x1BC9A, Disallowed, 0, 0), (0x1BC9C, Valid, 0, 0), (0x1BCA0, Ignored, 0, 0), (0x1BCA4, Disallowed, 0, 0),
+    (0x1CF00, Valid, 0, 0), (0x1CF2E, Disallowed, 0, 0), (0x1CF30, Valid, 0, 0), (0x1CF47, Disallowed, 0, 0),
+    (0x1CF50, Valid, 0, 0), (0x1CFC4, Disallowed, 0, 0), (0x1D000, Valid, 0, 0), (0x1D0F6, Disallowed, 0, 0),
+    (0x1D100, Valid, 0, 0), (0x1D127, Disallowed, 0, 0), (0x1D129, Valid, 0, 0), (0x1D15E, Mapped, 11075, 8),
+    (0x1D15F, Mapped, 11083, 8), (0x1D160, Mapped, 11091, 12), (0x1D161, Mapped, 11103, 12), (0x1D162, Mapped, 11115, 12),
+    (0x1D163, Mapped, 11127, 12), (0x1D164, Mapped, 11139, 12), (0x1D165, Valid, 0, 0), (0x1D173, Disallowed, 0, 0),
+    (0x1D17B, Valid, 0, 0), (0x1D1BB, Mapped, 11151, 8), (0x1D1BC, Mapped, 11159, 8), (0x1D1BD, Mapped, 11167, 12),
+    (0x1D1BE, Mapped, 11179, 12), (0x1D1BF, Mapped, 11191, 12), (0x1D1C0, Mapped, 11203, 12), (0x1D1C1, Valid, 0, 0),
+    (0x1D1EB, Disallowed, 0, 0), (0x1D200, Valid, 0, 0), (0x1D246, Disallowed, 0, 0), (0x1D2C0, Valid, 0, 0),
+    (0x1D2D4, Disallowed, 0, 0), (0x1D2E0, Valid, 0, 0), (0x1D2F4, Disallowed, 0, 0), (0x1D300, Valid, 0, 0),
+    (0x1D357, Disallowed, 0, 0), (0x1D360, Valid, 0, 0), (0x1D379, Disallowed, 0, 0), (0x1D400, Mapped, 0, 1),
+    (0x1D401, Mapped, 1, 1), (0x1D402, Mapped, 2, 1), (0x1D403, Mapped, 3, 1), (0x1D404, Mapped, 4, 1),
+    (0x1D405, Mapped, 5, 1), (0x1D406, Mapped, 6, 1), (0x1D407, Mapped, 7, 1), (0x1D408, Mapped, 8, 1),
+    (0x1D409, Mapped, 9, 1), (0x1D40A, Mapped, 10, 1), (0x1D40B, Mapped, 11, 1), (0x1D40C, Mapped, 12, 1),
+    (0x1D40D, Mapped, 13, 1), (0x1D40E, Mapped, 14, 1), (0x1D40F, Mapped, 15, 1), (0x1D410, Mapped, 16, 1),
+    (0x1D411, Mapped, 17, 1), (0x1D412, Mapped, 18, 1), (0x1D413, Mapped, 19, 1), (0x1D414, Mapped, 20, 1),
+    (0x1D415, Mapped, 21, 1), (0x1D416, Mapped, 22, 1), (0x1D417, Mapped, 23, 1), (0x1D418, Mapped, 24, 1),
+    (0x1D419, Mapped, 25, 1), (0x1D41A, Mapped, 0, 1), (0x1D41B, Mapped, 1, 1), (0x1D41C, Mapped, 2, 1),
+    (0x1D41D, Mapped, 3, 1), (0x1D41E, Mapped, 4, 1), (0x1D41F, Mapped, 5, 1), (0x1D420, Mapped, 6, 1),
+    (0x1D421, Mapped, 7, 1), (0x1D422, Mapped, 8, 1), (0x1D423, Mapped, 9, 1), (0x1D424, Mapped, 10, 1),
+    (0x1D425, Mapped, 11, 1), (0x1D426, Mapped, 12, 1), (0x1D427, Mapped, 13, 1), (0x1D428, Mapped, 14, 1),
+    (0x1D429, Mapped, 15, 1), (0x1D42A, Mapped, 16, 1), (0x1D42B, Mapped, 17, 1), (0x1D42C, Mapped, 18, 1),
+    (0x1D42D, Mapped, 19, 1), (0x1D42E, Mapped, 20, 1), (0x1D42F, Mapped, 21, 1), (0x1D430, Mapped, 22, 1),
+    (0x1D431, Mapped, 23, 1), (0x1D432, Mapped, 24, 1), (0x1D433, Mapped, 25, 1), (0x1D434, Mapped, 0, 1),
+    (0x1D435, Mapped, 1, 1), (0x1D436, Mapped, 2, 1), (0x1D437, Mapped, 3, 1), (0x1D438, Mapped, 4, 1),
+    (0x1D439, Mapped, 5, 1), (0x1D43A, Mapped, 6, 1), (0x1D43B, Mapped, 7, 1), (0x1D43C, Mapped, 8, 1),
+    (0x1D43D, Mapped, 9, 1), (0x1D43E, Mapped, 10, 1), (0x1D43F, Mapped, 11, 1), (0x1D440, Mapped, 12, 1),
+    (0x1D441, Mapped, 13, 1), (0x1D442, Mapped, 14, 1), (0x1D443, Mapped, 15, 1), (0x1D444, Mapped, 16, 1),
+    (0x1D445, Mapped, 17, 1), (0x1D446, Mapped, 18, 1), (0x1D447, Mapped, 19, 1), (0x1D448, Mapped, 20, 1),
+    (0x1D449, Mapped, 21, 1), (0x1D44A, Mapped, 22, 1), (0x1D44B, Mapped, 23, 1), (0x1D44C, Mapped, 24, 1),
+    (0x1D44D, Mapped, 25, 1), (0x1D44E, Mapped, 0, 1), (0x1D44F, Mapped, 1, 1), (0x1D450, Mapped, 2, 1),
+    (0x1D451, Mapped, 3, 1), (0x1D452, Mapped, 4, 1), (0x1D453, Mapped, 5, 1), (0x1D454, Mapped, 6, 1),
+    (0x1D455, Disallowed, 0, 0), (0x1D456, Mapped, 8, 1), (0x1D457, Mapped, 9, 1), (0x1D458, Mapped, 10, 1),
+    (0x1D459, Mapped, 11, 1), (0x1D45A, Mapped, 12, 1), (0x1D45B, Mapped, 13, 1), (0x1D45C, Mapped, 14, 1),
+    (0x1D45D, Mapped, 15, 1), (0x1D45E, Mapped, 16, 1), (0x1D45F, Mapped, 17, 1), (0x1D460, Mapped, 18, 1),
+    (0x1D461, Mapped, 19, 1), (0x1D462, Mapped, 20, 1), (0x1D463, Mapped, 21, 1), (0x1D464, Mapped, 22, 1),
+    (0x1D465, Mapped, 23, 1), (0x1D466, Mapped, 24, 1), (0x1D467, Mapped, 25, 1), (0x1D468, Mapped, 0, 1),
+    (0x1D469, Mapped, 1, 1), (0x1D46A, Mapped, 2, 1), (0x1D46B, Mapped, 3, 1), (0x1D46C, Mapped, 4, 1),
+    (0x1D46D, Mapped, 5, 1), (0x1D46E, Mapped, 6, 1), (0x1D46F, Mapped, 7, 1), (0x1D470, Mapped, 8, 1),
+    (0x1D471, Mapped, 9, 1), (0x1D472, Mapped, 10, 1), (0x1D473, Mapped, 11, 1), (0x1D474, Mapped, 12, 1),
+    (0x1D475, Mapped, 13, 1), (0x1D476, Mapped, 14, 1), (0x1D477, Mapped, 15, 1), (0x1D478, Mapped, 16, 1),
+    (0x1D479, Mapped, 17, 1), (0x1D47A, Mapped, 18, 1), (0x1D47B, Mapped, 19, 1), (0x1D47C, Mapped, 20, 1),
+    (0x1D47D, Mapped, 21, 1), (0x1D47E, Mapped, 22, 1), (0x1D47F, Mapped, 23, 1), (0x1D480, Mapped, 24, 1),
+    (0x1D481, Mapped, 25, 1), (0x1D482, Mapped, 0, 1), (0x1D483, Mapped, 1, 1), (0x1D484, Mapped, 2, 1),
+    (0x1D485, Mapped, 3, 1), (0x1D486, Mapped, 4, 1), (0x1D487, Mapped, 5, 1), (0x1D488, Mapped, 6, 1),
+    (0x1D489, Mapped, 7, 1), (0x1D48A, Mapped, 8, 1), (0x1D48B, Mapped, 9, 1), (0x1D48C, Mapped, 10, 1),
+    (0x1D48D, Mapped, 11, 1), (0x1D48E, Mapped, 12, 1), (0x1D48F, Mapped, 13, 1), (0x1D490, Mapped, 14, 1),
+    (0x1D491, Mapped, 15, 1), (0x1D492, Mapped, 16, 1), (0x1D493, Mapped, 17, 1), (0x1D494, Mapped, 18, 1),
+    (0x1D495, Mapped, 19, 1), (0x1D496, Mapped, 20, 1), (0x1D497, Mapped, 21, 1), (0x1D498, Mapped, 22, 1),
+    (0x1D499, Mapped, 23, 1), (0x1D49A, Mapped, 24, 1), (0x1D49B, Mapped, 25, 1), (0x1D49C, Mapped, 0, 1),
+    (0x1D49D, Disallowed, 0, 0), (0x1D49E, Mapped, 2, 1), (0x1D49F, Mapped, 3, 1), (0x1D4A0, Disallowed, 0, 0),
+    (0x1D4A2, Mapped, 6, 1), (0x1D4A3, Disallowed, 0, 0), (0x1D4A5, Mapped, 9, 1), (0x1D4A6, Mapped, 10, 1),
+    (0x1D4A7, Disallowed, 0, 0), (0x1D4A9, Mapped, 13, 1), (0x1D4AA, Mapped, 14, 1), (0x1D4AB, Mapped, 15, 1),
+    (0x1D4AC, Mapped, 16, 1), (0x1D4AD, Disallowed, 0, 0), (0x1D4AE, Mapped, 18, 1), (0x1D4AF, Mapped, 19, 1),
+    (0x1D4B0, Mapped, 20, 1), (0x1D4B1, Mapped, 21, 1), (0x1D4B2, Mapped, 22, 1), (0x1D4B3, Mapped, 23, 1),
+    (0x1D4B4, Mapped, 24, 1), (0x1D4B5, Mapped, 25, 1), (0x1D4B6, Mapped, 0, 1), (0x1D4B7, Mapped, 1, 1),
+    (0x1D4B8, Mapped, 2, 1), (0x1D4B9, Mapped, 3, 1), (0x1D4BA, Disallowed, 0, 0), (0x1D4BB, Mapped, 5, 1),
+    (0x1D4BC, Disallowed, 0, 0), (0x1D4BD, Mapped, 7, 1), (0x1D4BE, Mapped, 8, 1), (0x1D4BF, Mapped, 9, 1),
+    (0x1D4C0, Mapped, 10, 1), (0x1D4C1, Mapped, 11, 1), (0x1D4C2, Mapped, 12, 1), (0x1D4C3, Mapped, 13, 1),
+    (0x1D4C4, Disallowed, 0, 0), (0x1D4C5, Mapped, 15, 1), (0x1D4C6, Mapped, 16, 1), (0x1D4C7, Mapped, 17, 1),
+    (0x1D4C8, Mapped, 18, 1), (0x1D4C9, Mapped, 19, 1), (0x1D4CA, Mapped, 20, 1), (0x1D4CB, Mapped, 21, 1),
+    (0x1D4CC, Mapped, 22, 1), (0x1D4CD, Mapped, 23, 1), (0x1D4CE, Mapped, 24, 1), (0x1D4CF, Mapped, 25, 1),
+    (0x1D4D0, Mapped, 0, 1), (0x1D4D1, Mapped, 1, 1), (0x1D4D2, Mapped, 2, 1), (0x1D4D3, Mapped, 3, 1),
+    (0x1D4D4, Mapped, 4, 1), (0x1D4D5, Mapped, 5, 1), (0x1D4D6, Mapped, 6, 1), (0x1D4D7, Mapped, 7, 1),
+    (0x1D4D8, Mapped, 8, 1), (0x1D4D9, Mapped, 9, 1), (0x1D4DA, Mapped, 10, 1), (0x1D4DB, Mapped, 11, 1),
+    (0x1D4DC, Mapped, 12, 1), (0x1D4DD, Mapped, 13, 1), (0x1D4DE, Mapped, 14, 1), (0x1D4DF, Mapped, 15, 1),
+    (0x1D4E0, Mapped, 16, 1), (0x1D4E1, Mapped, 17, 1), (0x1D4E2, Mapped, 18, 1), (0x1D4E3, Mapped, 19, 1),
+    (0x1D4E4, Mapped, 20, 1), (0x1D4E5, Mapped, 21, 1), (0x1D4E6, Mapped, 22, 1), (0x1D4E7, Mapped, 23, 1),
+    (0x1D4E8, Mapped, 24, 1), (0x1D4E9, Mapped, 25, 1), (0x1D4EA, Mapped, 0, 1), (0x1D4EB, Mapped, 1, 1),
+    (0x1D4EC, Mapped, 2, 1), (0x1D4ED, Mapped, 3, 1), (0x1D4EE, Mapped, 4, 1), (0x1D4EF, Mapped, 5, 1),
+    (0x1D4F0, Mapped, 6, 1), (0x1D4F1, Mapped, 7, 1), (0x1D4F2, Mapped, 8, 1), (0x1D4F3, Mapped, 9, 1),
+    (0x1D4F4, Mapped, 10, 1), (0x1D4F5, Mapped, 11, 1), (0x1D4F6, Mapped, 12, 1), (0x1D4F7, Mapped, 13, 1),
+    (0x1D4F8, Mapped, 14, 1), (0x1D4F9, Mapped, 15, 1), (0x1D4FA, Mapped, 16, 1), (0x1D4FB, Mapped, 17, 1),
+    (0x1D4FC, Mapped, 18, 1), (0x1D4FD, Mapped, 19, 1), (0x1D4FE, Mapped, 20, 1), (0x1D4FF, Mapped, 21, 1),
+    (0x1D500, Mapped, 22, 1), (0x1D501, Mapped, 23, 1), (0x1D502, Mapped, 24, 1), (0x1D503, Mapped, 25, 1),
+    (0x1D504, Mapped, 0, 1), (0x1D505, Mapped, 1, 1), (0x1D506, Disallowed, 0, 0), (0x1D507, Mapped, 3, 1),
+    (0x1D508, Mapped, 4, 1), (0x1D509, Mapped, 5, 1), (0x1D50A, Mapped, 6, 1), (0x1D50B, Disallowed, 0, 0),
+    (0x1D50D, Mapped, 9, 1), (0x1D50E, Mapped, 10, 1), (0x1D50F, Mapped, 11, 1), (0x1D510, Mapped, 12, 1),
+    (0x1D511, Mapped, 13, 1), (0x1D512, Mapped, 14, 1), (0x1D513, Mapped, 15, 1), (0x1D514, Mapped, 16, 1),
+    (0x1D515, Disallowed, 0, 0), (0x1D516, Mapped, 18, 1), (0x1D517, Mapped, 19, 1), (0x1D518, Mapped, 20, 1),
+    (0x1D519, Mapped, 21, 1), (0x1D51A, Mapped, 22, 1), (0x1D51B, Mapped, 23, 1), (0x1D51C, Mapped, 24, 1),
+    (0x1D51D, Disallowed, 0, 0), (0x1D51E, Mapped, 0, 1), (0x1D51F, Mapped, 1, 1), (0x1D520, Mapped, 2, 1),
+    (0x1D521, Mapped, 3, 1), (0x1D522, Mapped, 4, 1), (0x1D523, Mapped, 5, 1), (0x1D524, Mapped, 6, 1),
+    (0x1D525, Mapped, 7, 1), (0x1D526, Mapped, 8, 1), (0x1D527, Mapped, 9, 1), (0x1D528, Mapped, 10, 1),
+    (0x1D529, Mapped, 11, 1), (0x1D52A, Mapped, 12, 1), (0x1D52B, Mapped, 13, 1), (0x1D52C, Mapped, 14, 1),
+    (0x1D52D, Mapped, 15, 1), (0x1D52E, Mapped, 16, 1), (0x1D52F, Mapped, 17, 1), (0x1D530, Mapped, 18, 1),
+    (0x1D531, Mapped, 19, 1), (0x1D532, Mapped, 20, 1), (0x1D533, Mapped, 21, 1), (0x1D534, Mapped, 22, 1),
+    (0x1D535, Mapped, 23, 1), (0x1D536, Mapped, 24, 1), (0x1D537, Mapped, 25, 1), (0x1D538, Mapped, 0, 1),
+    (0x1D539, Mapped, 1, 1), (0x1D53A, Disallowed, 0, 0), (0x1D53B, Mapped, 3, 1), (0x1D53C, Mapped, 4, 1),
+    (0x1D53D, Mapped, 5, 1), (0x1D53E, Mapped, 6, 1), (0x1D53F, Disallowed, 0, 0), (0x1D540, Mapped, 8, 1),
+    (0x1D541, Mapped, 9, 1), (0x1D542, Mapped, 10, 1), (0x1D543, Mapped, 11, 1), (0x1D544, Mapped, 12, 1),
+    (0x1D545, Disallowed, 0, 0), (0x1D546, Mapped, 14, 1), (0x1D547, Disallowed, 0, 0), (0x1D54A, Mapped, 18, 1),
+    (0x1D54B, Mapped, 19, 1), (0x1D54C, Mapped, 20, 1), (0x1D54D, Mapped, 21, 1), (0x1D54E, Mapped, 22, 1),
+    (0x1D54F, Mapped, 23, 1), (0x1D550, Mapped, 24, 1), (0x1D551, Disallowed, 0, 0), (0x1D552, Mapped, 0, 1),
+    (0x1D553, Mapped, 1, 1), (0x1D554, Mapped, 2, 1), (0x1D555, Mapped, 3, 1), (0x1D556, Mapped, 4, 1),
+    (0x1D557, Mapped, 5, 1), (0x1D558, Mapped, 6, 1), (0x1D559, Mapped, 7, 1), (0x1D55A, Mapped, 8, 1),
+    (0x1D55B, Mapped, 9, 1), (0x1D55C, Mapped, 10, 1), (0x1D55D, Mapped, 11, 1), (0x1D55E, Mapped, 12, 1),
+    (0x1D55F, Mapped, 13, 1), (0x1D560, Mapped, 14, 1), (0x1D561, Mapped, 15, 1), (0x1D562, Mapped, 16, 1),
+    (0x1D563, Mapped, 17, 1), (0x1D564, Mapped, 18, 1), (0x1D565, Mapped, 19, 1), (0x1D566, Mapped, 20, 1),
+    (0x1D567, Mapped, 21, 1), (0x1D568, Mapped, 22, 1), (0x1D569, Mapped, 23, 1), (0x1D56A, Mapped, 24, 1),
+    (0x1D56B, Mapped, 25, 1), (0x1D56C, Mapped, 0, 1), (0x1D56D, Mapped, 1, 1), (0x1D56E, Mapped, 2, 1),
+    (0x1D56F, Mapped, 3, 1), (0x1D570, Mapped, 4, 1), (0x1D571, Mapped, 5, 1), (0x1D572, Mapped, 6, 1),
+    (0x1D573, Mapped, 7, 1), (0x1D574, Mapped, 8, 1), (0x1D575, Mapped, 9, 1), (0x1D576, Mapped, 10, 1),
+    (0x1D577, Mapped, 11, 1), (0x1D578, Mapped, 12, 1), (0x1D579, Mapped, 13, 1), (0x1D57A, Mapped, 14, 1),
+    (0x1D57B, Mapped, 15, 1), (0x1D57C, Mapped, 16, 1), (0x1D57D, Mapped, 17, 1), (0x1D57E, Mapped, 18, 1),
+    (0x1D57F, Mapped, 19, 1), (0x1D580, Mapped, 20, 1), (0x1D581, Mapped, 21, 1), (0x1D582, Mapped, 22, 1),
+    (0x1D583, Mapped, 23, 1), (0x1D584, Mapped, 24, 1), (0x1D585, Mapped, 25, 1), (0x1D586, Mapped, 0, 1),
+    (0x1D587, Mapped, 1, 1), (0x1D588, Mapped, 2, 1), (0x1D589, Mapped, 3, 1), (0x1D58A, Mapped, 4, 1),
+    (0x1D58B, Mapped, 5, 1), (0x1D58C, Mapped, 6, 1), (0x1D58D, Mapped, 7, 1), (0x1D58E, Mapped, 8, 1),
+    (0x1D58F, Mapped, 9, 1), (0x1D590, Mapped, 10, 1), (0x1D591, Mapped, 11, 1), (0x1D592, Mapped, 12, 1),
+    (0x1D593, Mapped, 13, 1), (0x1D594, Mapped, 14, 1), (0x1D595, Mapped, 15, 1), (0x1D596, Mapped, 16, 1),
+    (0x1D597, Mapped, 17, 1), (0x1D598, Mapped, 18, 1), (0x1D599, Mapped, 19, 1), (0x1D59A, Mapped, 20, 1),
+    (0x1D59B, Mapped, 21, 1), (0x1D59C, Mapped, 22, 1), (0x1D59D, Mapped, 23, 1), (0x1D59E, Mapped, 24, 1),
+    (0x1D59F, Mapped, 25, 1), (0x1D5A0, Mapped, 0, 1), (0x1D5A1, Mapped, 1, 1), (0x1D5A2, Mapped, 2, 1),
+    (0x1D5A3, Mapped, 3, 1), (0x1D5A4, Mapped, 4, 1), (0x1D5A5, Mapped, 5, 1), (0x1D5A6, Mapped, 6, 1),
+    (0x1D5A7, Mapped, 7, 1), (0x1D5A8, Mapped, 8, 1), (0x1D5A9, Mapped, 9, 1), (0x1D5AA, Mapped, 10, 1),
+    (0x1D5AB, Mapped, 11, 1), (0x1D5AC, Mapped, 12, 1), (0x1D5AD, Mapped, 13, 1), (0x1D5AE, Mapped, 14, 1),
+    (0x1D5AF, Mapped, 15, 1), (0x1D5B0, Mapped, 16, 1), (0x1D5B1, Mapped, 17, 1), (0x1D5B2, Mapped, 18, 1),
+    (0x1D5B3, Mapped, 19, 1), (0x1D5B4, Mapped, 20, 1), (0x1D5B5, Mapped, 21, 1), (0x1D5B6, Mapped, 22, 1),
+    (0x1D5B7, Mapped, 23, 1), (0x1D5B8, Mapped, 24, 1), (0x1D5B9, Mapped, 25, 1), (0x1D5BA, Mapped, 0, 1),
+    (0x1D5BB, Mapped, 1, 1), (0x1D5BC, Mapped, 2, 1), (0x1D5BD, Mapped, 3, 1), (0x1D5BE, Mapped, 4, 1),
+    (0x1D5BF, Mapped, 5, 1), (0x1D5C0, Mapped, 6, 1), (0x1D5C1, Mapped, 7, 1), (0x1D5C2, Mapped, 8, 1),
+    (0x1D5C3, Mapped, 9, 1), (0x1D5C4, Mapped, 10, 1), (0x1D5C5, Mapped, 11, 1), (0x1D5C6, Mapped, 12, 1),
+    (0x1D5C7, Mapped, 13, 1), (0x1D5C8, Mapped, 14, 1), (0x1D5C9, Mapped, 15, 1), (0x1D5CA, Mapped, 16, 1),
+    (0x1D5CB, Mapped, 17, 1), (0x1D5CC, Mapped, 18, 1), (0x1D5CD, Mapped, 19, 1), (0x1D5CE, Mapped, 20, 1),
+    (0x1D5CF, Mapped, 21, 1), (0x1D5D0, Mapped, 22, 1), (0x1D5D1, Mapped, 23, 1), (0x1D5D2, Mapped, 24, 1),
+    (0x1D5D3, Mapped, 25, 1), (0x1D5D4, Mapped, 0, 1), (0x1D5D5, Mapped, 1, 1), (0x1D5D6, Mapped, 2, 1),
+    (0x1D5D7, Mapped, 3, 1), (0x1D5D8, Mapped, 4, 1), (0x1D5D9, Mapped, 5, 1), (0x1D5DA, Mapped, 6, 1),
+    (0x1D5DB, Mapped, 7, 1), (0x1D5DC, Mapped, 8, 1), (0x1D5DD, Mapped, 9, 1), (0x1D5DE, Mapped, 10, 1),
+    (0x1D5DF, Mapped, 11, 1), (0x1D5E0, Mapped, 12, 1), (0x1D5E1, Mapped, 13, 1), (0x1D5E2, Mapped, 14, 1),
+    (0x1D5E3, Mapped, 15, 1), (0x1D5E4, Mapped, 16, 1), (0x1D5E5, Mapped, 17, 1), (0x1D5E6, Mapped, 18, 1),
+    (0x1D5E7, Mapped, 19, 1), (0x1D5E8, Mapped, 20, 1), (0x1D5E9, Mapped, 21, 1), (0x1D5EA, Mapped, 22, 1),
+    (0x1D5EB, Mapped, 23, 1), (0x1D5EC, Mapped, 24, 1), (0x1D5ED, Mapped, 25, 1), (0x1D5EE, Mapped, 0, 1),
+    (0x1D5EF, Mapped, 1, 1), (0x1D5F0, Mapped, 2, 1), (0x1D5F1, Mapped, 3, 1), (0x1D5F2, Mapped, 4, 1),
+    (0x1D5F3, Mapped, 5, 1), (0x1D5F4, Mapped, 6, 1), (0x1D5F5, Mapped, 7, 1), (0x1D5F6, Mapped, 8, 1),
+    (0x1D5F7, Mapped, 9, 1), (0x1D5F8, Mapped, 10, 1), (0x1D5F9, Mapped, 11, 1), (0x1D5FA, Mapped, 12, 1),
+    (0x1D5FB, Mapped, 13, 1), (0x1D5FC, Mapped, 14, 1), (0x1D5FD, Mapped, 15, 1), (0x1D5FE, Mapped, 16, 1),
+    (0x1D5FF, Mapped, 17, 1), (0x1D600, Mapped, 18, 1), (0x1D601, Mapped, 19, 1), (0x1D602, Mapped, 20, 1),
+    (0x1D603, Mapped, 21, 1), (0x1D604, Mapped, 22, 1), (0x1D605, Mapped, 23, 1), (0x1D606, Mapped, 24, 1),
+    (0x1D607, Mapped, 25, 1), (0x1D608, Mapped, 0, 1), (0x1D609, Mapped, 1, 1), (0x1D60A, Mapped, 2, 1),
+    (0x1D60B, Mapped, 3, 1), (0x1D60C, Mapped, 4, 1), (0x1D60D, Mapped, 5, 1), (0x1D60E, Mapped, 6, 1),
+    (0x1D60F, Mapped, 7, 1), (0x1D610, Mapped, 8, 1), (0x1D611, Mapped, 9, 1), (0x1D612, Mapped, 10, 1),
+    (0x1D613, Mapped, 11, 1), (0x1D614, Mapped, 12, 1), (0x1D615, Mapped, 13, 1), (0x1D616, Mapped, 14, 1),
+    (0x1D617, Mapped, 15, 1), (0x1D618, Mapped, 16, 1), (0x1D619, Mapped, 17, 1), (0x1D61A, Mapped, 18, 1),
+    (0x1D61B, Mapped, 19, 1), (0x1D61C, Mapped, 20, 1), (0x1D61D, Mapped, 21, 1), (0x1D61E, Mapped, 22, 1),
+    (0x1D61F, Mapped, 23, 1), (0x1D620, Mapped, 24, 1), (0x1D621, Mapped, 25, 1), (0x1D622, Mapped, 0, 1),
+    (0x1D623, Mapped, 1, 1), (0x1D624, Mapped, 2, 1), (0x1D625, Mapped, 3, 1), (0x1D626, Mapped, 4, 1),
+    (0x1D627, Mapped, 5, 1), (0x1D628, Mapped, 6, 1), (0x1D629, Mapped, 7, 1), (0x1D62A, Mapped, 8, 1),
+    (0x1D62B, Mapped, 9, 1), (0x1D62C, Mapped, 10, 1), (0x1D62D, Mapped, 11, 1), (0x1D62E, Mapped, 12, 1),
+    (0x1D62F, Mapped, 13, 1), (0x1D630, Mapped, 14, 1), (0x1D631, Mapped, 15, 1), (0x1D632, Mapped, 16, 1),
+    (0x1D633, Mapped, 17, 1), (0x1D634, Mapped, 18, 1), (0x1D635, Mapped, 19, 1), (0x1D636, Mapped, 20, 1),
+    (0x1D637, Mapped, 21, 1), (0x1D638, Mapped, 22, 1), (0x1D639, Mapped, 23, 1), (0x1D63A, Mapped, 24, 1),
+    (0x1D63B, Mapped, 25, 1), (0x1D63C, Mapped, 0, 1), (0x1D63D, Mapped, 1, 1), (0x1D63E, Mapped, 2, 1),
+    (0x1D63F, Mapped, 3, 1), (0x1D640, Mapped, 4, 1), (0x1D641, Mapped, 5, 1), (0x1D642, Mapped, 6, 1),
+    (0x1D643, Mapped, 7, 1), (0x1D644, Mapped, 8, 1), (0x1D645, Mapped, 9, 1), (0x1D646, Mapped, 10, 1),
+    (0x1D647, Mapped, 11, 1), (0x1D648, Mapped, 12, 1), (0x1D649, Mapped, 13, 1), (0x1D64A, Mapped, 14, 1),
+    (0x1D64B, Mapped, 15, 1), (0x1D64C, Mapped, 16, 1), (0x1D64D, Mapped, 17, 1), (0x1D64E, Mapped, 18, 1),
+    (0x1D64F, Mapped, 19, 1), (0x1D650, Mapped, 20, 1), (0x1D651, Mapped, 21, 1), (0x1D652, Mapped, 22, 1),
+    (0x1D653, Mapped, 23, 1), (0x1D654, Mapped, 24, 1), (0x1D655, Mapped, 25, 1), (0x1D656, Mapped, 0, 1),
+    (0x1D657, Mapped, 1, 1), (0x1D658, Mapped, 2, 1), (0x1D659, Mapped, 3, 1), (0x1D65A, Mapped, 4, 1),
+    (0x1D65B, Mapped, 5, 1), (0x1D65C, Mapped, 6, 1), (0x1D65D, Mapped, 7, 1), (0x1D65E, Mapped, 8, 1),
+    (0x1D65F, Mapped, 9, 1), (0x1D660, Mapped, 10, 1), (0x1D661, Mapped, 11, 1), (0x1D662, Mapped, 12, 1),
+    (0x1D663, Mapped, 13, 1), (0x1D664, Mapped, 14, 1), (0x1D665, Mapped, 15, 1), (0x1D666, Mapped, 16, 1),
+    (0x1D667, Mapped, 17, 1), (0x1D668, Mapped, 18, 1), (0x1D669, Mapped, 19, 1), (0x1D66A, Mapped, 20, 1),
+    (0x1D66B, Mapped, 21, 1), (0x1D66C, Mapped, 22, 1), (0x1D66D, Mapped, 23, 1), (0x1D66E, Mapped, 24, 1),
+    (0x1D66F, Mapped, 25, 1), (0x1D670, Mapped, 0, 1), (0x1D671, Mapped, 1, 1), (0x1D672, Mapped, 2, 1),
+    (0x1D673, Mapped, 3, 1), (0x1D674, Mapped, 4, 1), (0x1D675, Mapped, 5, 1), (0x1D676, Mapped, 6, 1),
+    (0x1D677, Mapped, 7, 1), (0x1D678, Mapped, 8, 1), (0x1D679, Mapped, 9, 1), (0x1D67A, Mapped, 10, 1),
+    (0x1D67B, Mapped, 11, 1), (0x1D67C, Mapped, 12, 1), (0x1D67D, Mapped, 13, 1), (0x1D67E, Mapped, 14, 1),
+    (0x1D67F, Mapped, 15, 1), (0x1D680, Mapped, 16, 1), (0x1D681, Mapped, 17, 1), (0x1D682, Mapped, 18, 1),
+    (0x1D683, Mapped, 19, 1), (0x1D684, Mapped, 20, 1), (0x1D685, Mapped, 21, 1), (0x1D686, Mapped, 22, 1),
+    (0x1D687, Mapped, 23, 1), (0x1D688, Mapped, 24, 1), (0x1D689, Mapped, 25, 1), (0x1D68A, Mapped, 0, 1),
+    (0x1D68B, Mapped, 1, 1), (0x1D68C, Mapped, 2, 1), (0x1D68D, Mapped, 3, 1), (0x1D68E, Mapped, 4, 1),
+    (0x1D68F, Mapped, 5, 1), (0x1D690, Mapped, 6, 1), (0x1D691, Mapped, 7, 1), (0x1D692, Mapped, 8, 1),
+    (0x1D693, Mapped, 9, 1), (0x1D694, Mapped, 10, 1), (0x1D695, Mapped, 11, 1), (0x1D696, Mapped, 12, 1),
+    (0x1D697, Mapped, 13, 1), (0x1D698, Mapped, 14, 1), (0x1D699, Mapped, 15, 1), (0x1D69A, Mapped, 16, 1),
+    (0x1D69B, Mapped, 17, 1), (0x1D69C, Mapped, 18, 1), (0x1D69D, Mapped, 19, 1), (0x1D69E, Mapped, 20, 1),
+    (0x1D69F, Mapped, 21, 1), (0x1D6A0, Mapped, 22, 1), (0x1D6A1, Mapped, 23, 1), (0x1D6A2, Mapped, 24, 1),
+    (0x1D6A3, Mapped, 25, 1), (0x1D6A4, Mapped, 11215, 2), (0x1D6A5, Mapped, 11217, 2), (0x1D6A6, Disallowed, 0, 0),
+    (0x1D6A8, Mapped, 536, 2), (0x1D6A9, Mapped, 538, 2), (0x1D6AA, Mapped, 540, 2), (0x1D6AB, Mapped, 542, 2),
+    (0x1D6AC, Mapped, 544, 2), (0x1D6AD, Mapped, 546, 2), (0x1D6AE, Mapped, 548, 2), (0x1D6AF, Mapped, 550, 2),
+    (0x1D6B0, Mapped, 499, 2), (0x1D6B1, Mapped, 552, 2), (0x1D6B2, Mapped, 554, 2), (0x1D6B3, Mapped, 38, 2),
+    (0x1D6B4, Mapped, 556, 2), (0x1D6B5, Mapped, 558, 2), (0x1D6B6, Mapped, 560, 2), (0x1D6B7, Mapped, 562, 2),
+    (0x1D6B8, Mapped, 564, 2), (0x1D6B9, Mapped, 550, 2), (0x1D6BA, Mapped, 566, 2), (0x1D6BB, Mapped, 568, 2),
+    (0x1D6BC, Mapped, 570, 2), (0x1D6BD, Mapped, 572, 2), (0x1D6BE, Mapped, 574, 2), (0x1D6BF, Mapped, 576, 2),
+    (0x1D6C0, Mapped, 578, 2), (0x1D6C1, Mapped, 11219, 3), (0x1D6C2, Mapped, 536, 2), (0x1D6C3, Mapped, 538, 2),
+    (0x1D6C4, Mapped, 540, 2), (0x1D6C5, Mapped, 542, 2), (0x1D6C6, Mapped, 544, 2), (0x1D6C7, Mapped, 546, 2),
+    (0x1D6C8, Mapped, 548, 2), (0x1D6C9, Mapped, 550, 2), (0x1D6CA, Mapped, 499, 2), (0x1D6CB, Mapped, 552, 2),
+    (0x1D6CC, Mapped, 554, 2), (0x1D6CD, Mapped, 38, 2), (0x1D6CE, Mapped, 556, 2), (0x1D6CF, Mapped, 558, 2),
+    (0x1D6D0, Mapped, 560, 2), (0x1D6D1, Mapped, 562, 2), (0x1D6D2, Mapped, 564, 2), (0x1D6D3, Mapped, 566, 2),
+    (0x1D6D5, Mapped, 568, 2), (0x1D6D6, Mapped, 570, 2), (0x1D6D7, Mapped, 572, 2), (0x1D6D8, Mapped, 574, 2),
+    (0x1D6D9, Mapped, 576, 2), (0x1D6DA, Mapped, 578, 2), (0x1D6DB, Mapped, 11222, 3), (0x1D6DC, Mapped, 544, 2),
+    (0x1D6DD, Mapped, 550, 2), (0x1D6DE, Mapped, 552, 2), (0x1D6DF, Mapped, 572, 2), (0x1D6E0, Mapped, 564, 2),
+    (0x1D6E1, Mapped, 562, 2), (0x1D6E2, Mapped, 536, 2), (0x1D6E3, Mapped, 538, 2), (0x1D6E4, Mapped, 540, 2),
+    (0x1D6E5, Mapped, 542, 2), (0x1D6E6, Mapped, 544, 2), (0x1D6E7, Mapped, 546, 2), (0x1D6E8, Mapped, 548, 2),
+    (0x1D6E9, Mapped, 550, 2), (0x1D6EA, Mapped, 499, 2), (0x1D6EB, Mapped, 552, 2), (0x1D6EC, Mapped, 554, 2),
+    (0x1D6ED, Mapped, 38, 2), (0x1D6EE, Mapped, 556, 2), (0x1D6EF, Mapped, 558, 2), (0x1D6F0, Mapped, 560, 2),
+    (0x1D6F1, Mapped, 562, 2), (0x1D6F2, Mapped, 564, 2), (0x1D6F3, Mapped, 550, 2), (0x1D6F4, Mapped, 566, 2),
+    (0x1D6F5, Mapped, 568, 2), (0x1D6F6, Mapped, 570, 2), (0x1D6F7, Mapped, 572, 2), (0x1D6F8, Mapped, 574, 2),
+    (0x1D6F9, Mapped, 576, 2), (0x1D6FA, Mapped, 578, 2), (0x1D6FB, Mapped, 11219, 3), (0x1D6FC, Mapped, 536, 2),
+    (0x1D6FD, Mapped, 538, 2), (0x1D6FE, Mapped, 540, 2), (0x1D6FF, Mapped, 542, 2), (0x1D700, Mapped, 544, 2),
+    (0x1D701, Mapped, 546, 2), (0x1D702, Mapped, 548, 2), (0x1D703, Mapped, 550, 2), (0x1D704, Mapped, 499, 2),
+    (0x1D705, Mapped, 552, 2), (0x1D706, Mapped, 554, 2), (0x1D707, Mapped, 38, 2), (0x1D708, Mapped, 556, 2),
+    (0x1D709, Mapped, 558, 2), (0x1D70A, Mapped, 560, 2), (0x1D70B, Mapped, 562, 2), (0x1D70C, Mapped, 564, 2),
+    (0x1D70D, Mapped, 566, 2), (0x1D70F, Mapped, 568, 2), (0x1D710, Mapped, 570, 2), (0x1D711, Mapped, 572, 2),
+    (0x1D712, Mapped, 574, 2), (0x1D713, Mapped, 576, 2), (0x1D714, Mapped, 578, 2), (0x1D715, Mapped, 11222, 3),
+    (0x1D716, Mapped, 544, 2), (0x1D717, Mapped, 550, 2), (0x1D718, Mapped, 552, 2), (0x1D719, Mapped, 572, 2),
+    (0x1D71A, Mapped, 564, 2), (0x1D71B, Mapped, 562, 2), (0x1D71C, Mapped, 536, 2), (0x1D71D, Mapped, 538, 2),
+    (0x1D71E, Mapped, 540, 2), (0x1D71F, Mapped, 542, 2), (0x1D720, Mapped, 544, 2), (0x1D721, Mapped, 546, 2),
+    (0x1D722, Mapped, 548, 2), (0x1D723, Mapped, 550, 2), (0x1D724, Mapped, 499, 2), (0x1D725, Mapped, 552, 2),
+    (0x1D726, Mapped, 554, 2), (0x1D727, Mapped, 38, 2), (0x1D728, Mapped, 556, 2), (0x1D729, Mapped, 558, 2),
+    (0x1D72A, Mapped, 560, 2), (0x1D72B, Mapped, 562, 2), (0x1D72C, Mapped, 564, 2), (0x1D72D, Mapped, 550, 2),
+    (0x1D72E, Mapped, 566, 2), (0x1D72F, Mapped, 568, 2), (0x1D730, Mapped, 570, 2), (0x1D731, Mapped, 572, 2),
+    (0x1D732, Mapped, 574, 2), (0x1D733, Mapped, 576, 2), (0x1D734, Mapped, 578, 2), (0x1D735, Mapped, 11219, 3),
+    (0x1D736, Mapped, 536, 2), (0x1D737, Mapped, 538, 2), (0x1D738, Mapped, 540, 2), (0x1D739, Mapped, 542, 2),
+    (0x1D73A, Mapped, 544, 2), (0x1D73B, Mapped, 546, 2), (0x1D73C, Mapped, 548, 2), (0x1D73D, Mapped, 550, 2),
+    (0x1D73E, Mapped, 499, 2), (0x1D73F, Mapped, 552, 2), (0x1D740, Mapped, 554, 2), (0x1D741, Mapped, 38, 2),
+    (0x1D742, Mapped, 556, 2), (0x1D743, Mapped, 558, 2), (0x1D744, Mapped, 560, 2), (0x1D745, Mapped, 562, 2),
+    (0x1D746, Mapped, 564, 2), (0x1D747, Mapped, 566, 2), (0x1D749, Mapped, 568, 2), (0x1D74A, Mapped, 570, 2),
+    (0x1D74B, Mapped, 572, 2), (0x1D74C, Mapped, 574, 2), (0x1D74D, Mapped, 576, 2), (0x1D74E, Mapped, 578, 2),
+    (0x1D74F, Mapped, 11222, 3), (0x1D750, Mapped, 544, 2), (0x1D751, Mapped, 550, 2), (0x1D752, Mapped, 552, 2),
+    (0x1D753, Mapped, 572, 2), (0x1D754, Mapped, 564, 2), (0x1D755, Mapped, 562, 2), (0x1D756, Mapped, 536, 2),
+    (0x1D757, Mapped, 538, 2), (0x1D758, Mapped, 540, 2), (0x1D759, Mapped, 542, 2), (0x1D75A, Mapped, 544, 2),
+    (0x1D75B, Mapped, 546, 2), (0x1D75C, Mapped, 548, 2), (0x1D75D, Mapped, 550, 2), (0x1D75E, Mapped, 499, 2),
+    (0x1D75F, Mapped, 552, 2), (0x1D760, Mapped, 554, 2), (0x1D761, Mapped, 38, 2), (0x1D762, Mapped, 556, 2),
+    (0x1D763, Mapped, 558, 2), (0x1D764, Mapped, 560, 2), (0x1D765, Mapped, 562, 2), (0x1D766, Mapped, 564, 2),
+    (0x1D767, Mapped, 550, 2), (0x1D768, Mapped, 566, 2), (0x1D769, Mapped, 568, 2), (0x1D76A, Mapped, 570, 2),
+    (0x1D76B, Mapped, 572, 2), (0x1D76C, Mapped, 574, 2), (0x1D76D, Mapped, 576, 2), (0x1D76E, Mapped, 578, 2),
+    (0x1D76F, Mapped, 11219, 3), (0x1D770, Mapped, 536, 2), (0x1D771, Mapped, 538, 2), (0x1D772, Mapped, 540, 2),
+    (0x1D773, Mapped, 542, 2), (0x1D774, Mapped, 544, 2), (0x1D775, Mapped, 546, 2), (0x1D776, Mapped, 548, 2),
+    (0x1D777, Mapped, 550, 2), (0x1D778, Mapped, 499, 2), (0x1D779, Mapped, 552, 2), (0x1D77A, Mapped, 554, 2),
+    (0x1D77B, Mapped, 38, 2), (0x1D77C, Mapped, 556, 2), (0x1D77D, Mapped, 558, 2), (0x1D77E, Mapped, 560, 2),
+    (0x1D77F, Mapped, 562, 2), (0x1D780, Mapped, 564, 2), (0x1D781, Mapped, 566, 2), (0x1D783, Mapped, 568, 2),
+    (0x1D784, Mapped, 570, 2), (0x1D785, Mapped, 572, 2), (0x1D786, Mapped, 574, 2), (0x1D787, Mapped, 576, 2),
+    (0x1D788, Mapped, 578, 2), (0x1D789, Mapped, 11222, 3), (0x1D78A, Mapped, 544, 2), (0x1D78B, Mapped, 550, 2),
+    (0x1D78C, Mapped, 552, 2), (0x1D78D, Mapped, 572, 2), (0x1D78E, Mapped, 564, 2), (0x1D78F, Mapped, 562, 2),
+    (0x1D790, Mapped, 536, 2), (0x1D791, Mapped, 538, 2), (0x1D792, Mapped, 540, 2), (0x1D793, Mapped, 542, 2),
+    (0x1D794, Mapped, 544, 2), (0x1D795, Mapped, 546, 2), (0x1D796, Mapped, 548, 2), (0x1D797, Mapped, 550, 2),
+    (0x1D798, Mapped, 499, 2), (0x1D799, Mapped, 552, 2), (0x1D79A, Mapped, 554, 2), (0x1D79B, Mapped, 38, 2),
+    (0x1D79C, Mapped, 556, 2), (0x1D79D, Mapped, 558, 2), (0x1D79E, Mapped, 560, 2), (0x1D79F, Mapped, 562, 2),
+    (0x1D7A0, Mapped, 564, 2), (0x1D7A1, Mapped, 550, 2), (0x1D7A2, Mapped, 566, 2), (0x1D7A3, Mapped, 568, 2),
+    (0x1D7A4, Mapped, 570, 2), (0x1D7A5, Mapped, 572, 2), (0x1D7A6, Mapped, 574, 2), (0x1D7A7, Mapped, 576, 2),
+    (0x1D7A8, Mapped, 578, 2), (0x1D7A9, Mapped, 11219, 3), (0x1D7AA, Mapped, 536, 2), (0x1D7AB, Mapped, 538, 2),
+    (0x1D7AC, Mapped, 540, 2), (0x1D7AD, Mapped, 542, 2), (0x1D7AE, Mapped, 544, 2), (0x1D7AF, Mapped, 546, 2),
+    (0x1D7B0, Mapped, 548, 2), (0x1D7B1, Mapped, 550, 2), (0x1D7B2, Mapped, 499, 2), (0x1D7B3, Mapped, 552, 2),
+    (0x1D7B4, Mapped, 554, 2), (0x1D7B5, Mapped, 38, 2), (0x1D7B6, Mapped, 556, 2), (0x1D7B7, Mapped, 558, 2),
+    (0x1D7B8, Mapped, 560, 2), (0x1D7B9, Mapped, 562, 2), (0x1D7BA, Mapped, 564, 2), (0x1D7BB, Mapped, 566, 2),
+    (0x1D7BD, Mapped, 568, 2), (0x1D7BE, Mapped, 570, 2), (0x1D7BF, Mapped, 572, 2), (0x1D7C0, Mapped, 574, 2),
+    (0x1D7C1, Mapped, 576, 2), (0x1D7C2, Mapped, 578, 2), (0x1D7C3, Mapped, 11222, 3), (0x1D7C4, Mapped, 544, 2),
+    (0x1D7C5, Mapped, 550, 2), (0x1D7C6, Mapped, 552, 2), (0x1D7C7, Mapped, 572, 2), (0x1D7C8, Mapped, 564, 2),
+    (0x1D7C9, Mapped, 562, 2), (0x1D7CA, Mapped, 590, 2), (0x1D7CC, Disallowed, 0, 0), (0x1D7CE, Mapped, 2349, 1),
+    (0x1D7CF, Mapped, 43, 1), (0x1D7D0, Mapped, 33, 1), (0x1D7D1, Mapped, 34, 1), (0x1D7D2, Mapped, 2350, 1),
+    (0x1D7D3, Mapped, 2351, 1), (0x1D7D4, Mapped, 2352, 1), (0x1D7D5, Mapped, 2353, 1), (0x1D7D6, Mapped, 2354, 1),
+    (0x1D7D7, Mapped, 2355, 1), (0x1D7D8, Mapped, 2349, 1), (0x1D7D9, Mapped, 43, 1), (0x1D7DA, Mapped, 33, 1),
+    (0x1D7DB, Mapped, 34, 1), (0x1D7DC, Mapped, 2350, 1), (0x1D7DD, Mapped, 2351, 1), (0x1D7DE, Mapped, 2352, 1),
+    (0x1D7DF, Mapped, 2353, 1), (0x1D7E0, Mapped, 2354, 1), (0x1D7E1, Mapped, 2355, 1), (0x1D7E2, Mapped, 2349, 1),
+    (0x1D7E3, Mapped, 43, 1), (0x1D7E4, Mapped, 33, 1), (0x1D7E5, Mapped, 34, 1), (0x1D7E6, Mapped, 2350, 1),
+    (0x1D7E7, Mapped, 2351, 1), (0x1D7E8, Mapped, 2352, 1), (0x1D7E9, Mapped, 2353, 1), (0x1D7EA, Mapped, 2354, 1),
+    (0x1D7EB, Mapped, 2355, 1), (0x1D7EC, Mapped, 2349, 1), (0x1D7ED, Mapped, 43, 1), (0x1D7EE, Mapped, 33, 1),
+    (0x1D7EF, Mapped, 34, 1), (0x1D7F0, Mapped, 2350, 1), (0x1D7F1, Mapped, 2351, 1), (0x1D7F2, Mapped, 2352, 1),
+    (0x1D7F3, Mapped, 2353, 1), (0x1D7F4, Mapped, 2354, 1), (0x1D7F5, Mapped, 2355, 1), (0x1D7F6, Mapped, 2349, 1),
+    (0x1D7F7, Mapped, 43, 1), (0x1D7F8, Mapped, 33, 1), (0x1D7F9, Mapped, 34, 1), (0x1D7FA, Mapped, 2350, 1),
+    (0x1D7FB, Mapped, 2351, 1), (0x1D7FC, Mapped, 2352, 1), (0x1D7FD, Mapped, 2353, 1), (0x1D7FE, Mapped, 2354, 1),
+    (0x1D7FF, Mapped, 2355, 1), (0x1D800, Valid, 0, 0), (0x1DA8C, Disallowed, 0, 0), (0x1DA9B, Valid, 0, 0),
+    (0x1DAA0, Disallowed, 0, 0), (0x1DAA1, Valid, 0, 0), (0x1DAB0, Disallowed, 0, 0), (0x1DF00, Valid, 0, 0),
+    (0x1DF1F, Disallowed, 0, 0), (0x1DF25, Valid, 0, 0), (0x1DF2B, Disallowed, 0, 0), (0x1E000, Valid, 0, 0),
+    (0x1E007, Disallowed, 0, 0), (0x1E008, Valid, 0, 0), (0x1E019, Disallowed, 0, 0), (0x1E01B, Valid, 0, 0),
+    (0x1E022, Disallowed, 0, 0), (0x1E023, Valid, 0, 0), (0x1E025, Disallowed, 0, 0), (0x1E026, Valid, 0, 0),
+    (0x1E02B, Disallowed, 0, 0), (0x1E030, Mapped, 652, 2), (0x1E031, Mapped, 654, 2), (0x1E032, Mapped, 656, 2),
+    (0x1E033, Mapped, 658, 2), (0x1E034, Mapped, 660, 2), (0x1E035, Mapped, 662, 2), (0x1E036, Mapped, 664, 2),
+    (0x1E037, Mapped, 666, 2), (0x1E038, Mapped, 668, 2), (0x1E039, Mapped, 672, 2), (0x1E03A, Mapped, 674, 2),
+    (0x1E03B, Mapped, 676, 2), (0x1E03C, Mapped, 680, 2), (0x1E03D, Mapped, 682, 2), (0x1E03E, Mapped, 684, 2),
+    (0x1E03F, Mapped, 686, 2), (0x1E040, Mapped, 688, 2), (0x1E041, Mapped, 690, 2), (0x1E042, Mapped, 692, 2),
+    (0x1E043, Mapped, 694, 2), (0x1E044, Mapped, 696, 2), (0x1E045, Mapped, 698, 2), (0x1E046, Mapped, 700, 2),
+    (0x1E047, Mapped, 706, 2), (0x1E048, Mapped, 710, 2), (0x1E049, Mapped, 712, 2), (0x1E04A, Mapped, 6385, 3),
+    (0x1E04B, Mapped, 826, 2), (0x1E04C, Mapped, 632, 2), (0x1E04D, Mapped, 636, 2), (0x1E04E, Mapped, 842, 2),
+    (0x1E04F, Mapped, 786, 2), (0x1E050, Mapped, 11225, 2), (0x1E051, Mapped, 652, 2), (0x1E052, Mapped, 654, 2),
+    (0x1E053, Mapped, 656, 2), (0x1E054, Mapped, 658, 2), (0x1E055, Mapped, 660, 2), (0x1E056, Mapped, 662, 2),
+    (0x1E057, Mapped, 664, 2), (0x1E058, Mapped, 666, 2), (0x1E059, Mapped, 668, 2), (0x1E05A, Mapped, 672, 2),
+    (0x1E05B, Mapped, 674, 2), (0x1E05C, Mapped, 680, 2), (0x1E05D, Mapped, 682, 2), (0x1E05E, Mapped, 686, 2),
+    (0x1E05F, Mapped, 690, 2), (0x1E060, Mapped, 692, 2), (0x1E061, Mapped, 694, 2), (0x1E062, Mapped, 696, 2),
+    (0x1E063, Mapped, 698, 2), (0x1E064, Mapped, 700, 2), (0x1E065, Mapped, 704, 2), (0x1E066, Mapped, 706, 2),
+    (0x1E067, Mapped, 756, 2), (0x1E068, Mapped, 632, 2), (0x1E069, Mapped, 630, 2), (0x1E06A, Mapped, 650, 2),
+    (0x1E06B, Mapped, 782, 2), (0x1E06C, Mapped, 6328, 3), (0x1E06D, Mapped, 788, 2), (0x1E06E, Disallowed, 0, 0),
+    (0x1E08F, Valid, 0, 0), (0x1E090, Disallowed, 0, 0), (0x1E100, Valid, 0, 0), (0x1E12D, Disallowed, 0, 0),
+    (0x1E130, Valid, 0, 0), (0x1E13E, Disallowed, 0, 0), (0x1E140, Valid, 0, 0), (0x1E14A, Disallowed, 0, 0),
+    (0x1E14E, Valid, 0, 0), (0x1E150, Disallowed, 0, 0), (0x1E290, Valid, 0, 0), (0x1E2AF, Disallowed, 0, 0),
+    (0x1E2C0, Valid, 0, 0), (0x1E2FA, Disallowed, 0, 0), (0x1E2FF, Valid, 0, 0), (0x1E300, Disallowed, 0, 0),
+    (0x1E4D0, Valid, 0, 0), (0x1E4FA, Disallowed, 0, 0), (0x1E7E0, Valid, 0, 0), (0x1E7E7, Disallowed, 0, 0),
+    (0x1E7E8, Valid, 0, 0), (0x1E7EC, Disallowed, 0, 0), (0x1E7ED, Valid, 0, 0), (0x1E7EF, Disallowed, 0, 0),
+    (0x1E7F0, Valid, 0, 0), (0x1E7FF, Disallowed, 0, 0), (0x1E800, Valid, 0, 0), (0x1E8C5, Disallowed, 0, 0),
+    (0x1E8C7, Valid, 0, 0), (0x1E8D7, Disallowed, 0, 0), (0x1E900, Mapped, 11227, 4), (0x1E901, Mapped, 11231, 4),
+    (0x1E902, Mapped, 11235, 4), (0x1E903, Mapped, 11239, 4), (0x1E904, Mapped, 11243, 4), (0x1E905, Mapped, 11247, 4),
+    (0x1E906, Mapped, 11251, 4), (0x1E907, Mapped, 11255, 4), (0x1E908, Mapped, 11259, 4), (0x1E909, Mapped, 11263, 4),
+    (0x1E90A, Mapped, 11267, 4), (0x1E90B, Mapped, 11271, 4), (0x1E90C, Mapped, 11275, 4), (0x1E90D, Mapped, 11279, 4),
+    (0x1E90E, Mapped, 11283, 4), (0x1E90F, Mapped, 11287, 4), (0x1E910, Mapped, 11291, 4), (0x1E911, Mapped, 11295, 4),
+    (0x1E912, Mapped, 11299, 4), (0x1E913, Mapped, 11303, 4), (0x1E914, Mapped, 11307, 4), (0x1E915, Mapped, 11311, 4),
+    (0x1E916, Mapped, 11315, 4), (0x1E917, Mapped, 11319, 4), (0x1E918, Mapped, 11323, 4), (0x1E919, Mapped, 11327, 4),
+    (0x1E91A, Mapped, 11331, 4), (0x1E91B, Mapped, 11335, 4), (0x1E91C, Mapped, 11339, 4), (0x1E91D, Mapped, 11343, 4),
+    (0x1E91E, Mapped, 11347, 4), (0x1E91F, Mapped, 11351, 4), (0x1E920, Mapped, 11355, 4), (0x1E921, Mapped, 11359, 4),
+    (0x1E922, Valid, 0, 0), (0x1E94C, Disallowed, 0, 0), (0x1E950, Valid, 0, 0), (0x1E95A, Disallowed, 0, 0),
+    (0x1E95E, Valid, 0, 0), (0x1E960, Disallowed, 0, 0), (0x1EC71, Valid, 0, 0), (0x1ECB5, Disallowed, 0, 0),
+    (0x1ED01, Valid, 0, 0), (0x1ED3E, Disallowed, 0, 0), (0x1EE00, Mapped, 9904, 2), (0x1EE01, Mapped, 9906, 2),
+    (0x1EE02, Mapped, 9914, 2), (0x1EE03, Mapped, 9920, 2), (0x1EE04, Disallowed, 0, 0), (0x1EE05, Mapped, 9958, 2),
+    (0x1EE06, Mapped, 9926, 2), (0x1EE07, Mapped, 9916, 2), (0x1EE08, Mapped, 9936, 2), (0x1EE09, Mapped, 9960, 2),
+    (0x1EE0A, Mapped, 9948, 2), (0x1EE0B, Mapped, 9950, 2), (0x1EE0C, Mapped, 9952, 2), (0x1EE0D, Mapped, 9954, 2),
+    (0x1EE0E, Mapped, 9928, 2), (0x1EE0F, Mapped, 9940, 2), (0x1EE10, Mapped, 9944, 2), (0x1EE11, Mapped, 9932, 2),
+    (0x1EE12, Mapped, 9946, 2), (0x1EE13, Mapped, 9924, 2), (0x1EE14, Mapped, 9930, 2), (0x1EE15, Mapped, 9910, 2),
+    (0x1EE16, Mapped, 9912, 2), (0x1EE17, Mapped, 9918, 2), (0x1EE18, Mapped, 9922, 2), (0x1EE19, Mapped, 9934, 2),
+    (0x1EE1A, Mapped, 9938, 2), (0x1EE1B, Mapped, 9942, 2), (0x1EE1C, Mapped, 11363, 2), (0x1EE1D, Mapped, 8360, 2),
+    (0x1EE1E, Mapped, 11365, 2), (0x1EE1F, Mapped, 11367, 2), (0x1EE20, Disallowed, 0, 0), (0x1EE21, Mapped, 9906, 2),
+    (0x1EE22, Mapped, 9914, 2), (0x1EE23, Disallowed, 0, 0), (0x1EE24, Mapped, 9956, 2), (0x1EE25, Disallowed, 0, 0),
+    (0x1EE27, Mapped, 9916, 2), (0x1EE28, Disallowed, 0, 0), (0x1EE29, Mapped, 9960, 2), (0x1EE2A, Mapped, 9948, 2),
+    (0x1EE2B, Mapped, 9950, 2), (0x1EE2C, Mapped, 9952, 2), (0x1EE2D, Mapped, 9954, 2), (0x1EE2E, Mapped, 9928, 2),
+    (0x1EE2F, Mapped, 9940, 2), (0x1EE30, Mapped, 9944, 2), (0x1EE31, Mapped, 9932, 2), (0x1EE32, Mapped, 9946, 2),
+    (0x1EE33, Disallowed, 0, 0), (0x1EE34, Mapped, 9930, 2), (0x1EE35, Mapped, 9910, 2), (0x1EE36, Mapped, 9912, 2),
+    (0x1EE37, Mapped, 9918, 2), (0x1EE38, Disallowed, 0, 0), (0x1EE39, Mapped, 9934, 2), (0x1EE3A, Disallowed, 0, 0),
+    (0x1EE3B, Mapped, 9942, 2), (0x1EE3C, Disallowed, 0, 0), (0x1EE42, Mapped, 9914, 2), (0x1EE43, Disallowed, 0, 0),
+    (0x1EE47, Mapped, 9916, 2), (0x1EE48, Disallowed, 0, 0), (0x1EE49, Mapped, 9960, 2), (0x1EE4A, Disallowed, 0, 0),
+    (0x1EE4B, Mapped, 9950, 2), (0x1EE4C, Disallowed, 0, 0), (0x1EE4D, Mapped, 9954, 2), (0x1EE4E, Mapped, 9928, 2),
+    (0x1EE4F, Mapped, 9940, 2), (0x1EE50, Disallowed, 0, 0), (0x1EE51, Mapped, 9932, 2), (0x1EE52, Mapped, 9946, 2),
+    (0x1EE53, Disallowed, 0, 0), (0x1EE54, Mapped, 9930, 2), (0x1EE55, Disallowed, 0, 0), (0x1EE57, Mapped, 9918, 2),
+    (0x1EE58, Disallowed, 0, 0), (0x1EE59, Mapped, 9934, 2), (0x1EE5A, Disallowed, 0, 0), (0x1EE5B, Mapped, 9942, 2),
+    (0x1EE5C, Disallowed, 0, 0), (0x1EE5D, Mapped, 8360, 2), (0x1EE5E, Disallowed, 0, 0), (0x1EE5F, Mapped, 11367, 2),
+    (0x1EE60, Disallowed, 0, 0), (0x1EE61, Mapped, 9906, 2), (0x1EE62, Mapped, 9914, 2), (0x1EE63, Disallowed, 0, 0),
+    (0x1EE64, Mapped, 9956, 2), (0x1EE65, Disallowed, 0, 0), (0x1EE67, Mapped, 9916, 2), (0x1EE68, Mapped, 9936, 2),
+    (0x1EE69, Mapped, 9960, 2), (0x1EE6A, Mapped, 9948, 2), (0x1EE6B, Disallowed, 0, 0), (0x1EE6C, Mapped, 9952, 2),
+    (0x1EE6D, Mapped, 9954, 2), (0x1EE6E, Mapped, 9928, 2), (0x1EE6F, Mapped, 9940, 2), (0x1EE70, Mapped, 9944, 2),
+    (0x1EE71, Mapped, 9932, 2), (0x1EE72, Mapped, 9946, 2), (0x1EE73, Disallowed, 0, 0), (0x1EE74, Mapped, 9930, 2),
+    (0x1EE75, Mapped, 9910, 2), (0x1EE76, Mapped, 9912, 2), (0x1EE77, Mapped, 9918, 2), (0x1EE78, Disallowed, 0, 0),
+    (0x1EE79, Mapped, 9934, 2), (0x1EE7A, Mapped, 9938, 2), (0x1EE7B, Mapped, 9942, 2), (0x1EE7C, Mapped, 11363, 2),
+    (0x1EE7D, Disallowed, 0, 0), (0x1EE7E, Mapped, 11365, 2), (0x1EE7F, Disallowed, 0, 0), (0x1EE80, Mapped, 9904, 2),
+    (0x1EE81, Mapped, 9906, 2), (0x1EE82, Mapped, 9914, 2), (0x1EE83, Mapped, 9920, 2), (0x1EE84, Mapped, 9956, 2),
+    (0x1EE85, Mapped, 9958, 2), (0x1EE86, Mapped, 9926, 2), (0x1EE87, Mapped, 9916, 2), (0x1EE88, Mapped, 9936, 2),
+    (0x1EE89, Mapped, 9960, 2), (0x1EE8A, Disallowed, 0, 0), (0x1EE8B, Mapped, 9950, 2), (0x1EE8C, Mapped, 9952, 2),
+    (0x1EE8D, Mapped, 9954, 2), (0x1EE8E, Mapped, 9928, 2), (0x1EE8F, Mapped, 9940, 2), (0x1EE90, Mapped, 9944, 2),
+    (0x1EE91, Mapped, 9932, 2), (0x1EE92, Mapped, 9946, 2), (0x1EE93, Mapped, 9924, 2), (0x1EE94, Mapped, 9930, 2),
+    (0x1EE95, Mapped, 9910, 2), (0x1EE96, Mapped, 9912, 2), (0x1EE97, Mapped, 9918, 2), (0x1EE98, Mapped, 9922, 2),
+    (0x1EE99, Mapped, 9934, 2), (0x1EE9A, Mapped, 9938, 2), (0x1EE9B, Mapped, 9942, 2), (0x1EE9C, Disallowed, 0, 0),
+    (0x1EEA1, Mapped, 9906, 2), (0x1EEA2, Mapped, 9914, 2), (0x1EEA3, Mapped, 9920, 2), (0x1EEA4, Disallowed, 0, 0),
+    (0x1EEA5, Mapped, 9958, 2), (0x1EEA6, Mapped, 9926, 2), (0x1EEA7, Mapped, 9916, 2), (0x1EEA8, Mapped, 9936, 2),
+    (0x1EEA9, Mapped, 9960, 2), (0x1EEAA, Disallowed, 0, 0), (0x1EEAB, Mapped, 9950, 2), (0x1EEAC, Mapped, 9952, 2),
+    (0x1EEAD, Mapped, 9954, 2), (0x1EEAE, Mapped, 9928, 2), (0x1EEAF, Mapped, 9940, 2), (0x1EEB0, Mapped, 9944, 2),
+    (0x1EEB1, Mapped, 9932, 2), (0x1EEB2, Mapped, 9946, 2), (0x1EEB3, Mapped, 9924, 2), (0x1EEB4, Mapped, 9930, 2),
+    (0x1EEB5, Mapped, 9910, 2), (0x1EEB6, Mapped, 9912, 2), (0x1EEB7, Mapped, 9918, 2), (0x1EEB8, Mapped, 9922, 2),
+    (0x1EEB9, Mapped, 9934, 2), (0x1EEBA, Mapped, 9938, 2), (0x1EEBB, Mapped, 9942, 2), (0x1EEBC, Disallowed, 0, 0),
+    (0x1EEF0, Valid, 0, 0), (0x1EEF2, Disallowed, 0, 0), (0x1F000, Valid, 0, 0), (0x1F02C, Disallowed, 0, 0),
+    (0x1F030, Valid, 0, 0), (0x1F094, Disallowed, 0, 0), (0x1F0A0, Valid, 0, 0), (0x1F0AF, Disallowed, 0, 0),
+    (0x1F0B1, Valid, 0, 0), (0x1F0C0, Disallowed, 0, 0), (0x1F0C1, Valid, 0, 0), (0x1F0D0, Disallowed, 0, 0),
+    (0x1F0D1, Valid, 0, 0), (0x1F0F6, Disallowed, 0, 0), (0x1F101, Std3Mapped, 11369, 2), (0x1F102, Std3Mapped, 11371, 2),
+    (0x1F103, Std3Mapped, 11373, 2), (0x1F104, Std3Mapped, 11375, 2), (0x1F105, Std3Mapped, 11377, 2), (0x1F106, Std3Mapped, 11379, 2),
+    (0x1F107, Std3Mapped, 11381, 2), (0x1F108, Std3Mapped, 11383, 2), (0x1F109, Std3Mapped, 11385, 2), (0x1F10A, Std3Mapped, 11387, 2),
+    (0x1F10B, Valid, 0, 0), (0x1F110, Std3Mapped, 2643, 3), (0x1F111, Std3Mapped, 2646, 3), (0x1F112, Std3Mapped, 2649, 3),
+    (0x1F113, Std3Mapped, 2652, 3), (0x1F114, Std3Mapped, 2655, 3), (0x1F115, Std3Mapped, 2658, 3), (0x1F116, Std3Mapped, 2661, 3),
+    (0x1F117, Std3Mapped, 2664, 3), (0x1F118, Std3Mapped, 2667, 3), (0x1F119, Std3Mapped, 2670, 3), (0x1F11A, Std3Mapped, 2673, 3),
+    (0x1F11B, Std3Mapped, 2676, 3), (0x1F11C, Std3Mapped, 2679, 3), (0x1F11D, Std3Mapped, 2682, 3), (0x1F11E, Std3Mapped, 2685, 3),
+    (0x1F11F, Std3Mapped, 2688, 3), (0x1F120, Std3Mapped, 2691, 3), (0x1F121, Std3Mapped, 2694, 3), (0x1F122, Std3Mapped, 2697, 3),
+    (0x1F123, Std3Mapped, 2700, 3), (0x1F124, Std3Mapped, 2703, 3), (0x1F125, Std3Mapped, 2706, 3), (0x1F126, Std3Mapped, 2709, 3),
+    (0x1F127, Std3Mapped, 2712, 3), (0x1F128, Std3Mapped, 2715, 3), (0x1F129, Std3Mapped, 2718, 3), (0x1F12A, Mapped, 11389, 7),
+    (0x1F12B, Mapped, 2, 1), (0x1F12C, Mapped, 17, 1), (0x1F12D, Mapped, 6098, 2), (0x1F12E, Mapped, 11396, 2),
+    (0x1F12F, Valid, 0, 0), (0x1F130, Mapped, 0, 1), (0x1F131, Mapped, 1, 1), (0x1F132, Mapped, 2, 1),
+    (0x1F133, Mapped, 3, 1), (0x1F134, Mapped, 4, 1), (0x1F135, Mapped, 5, 1), (0x1F136, Mapped, 6, 1),
+    (0x1F137, Mapped, 7, 1), (0x1F138, Mapped, 8, 1), (0x1F139, Mapped, 9, 1), (0x1F13A, Mapped, 10, 1),
+    (0x1F13B, Mapped, 11, 1), (0x1F13C, Mapped, 12, 1), (0x1F13D, Mapped, 13, 1), (0x1F13E, Mapped, 14, 1),
+    (0x1F13F, Mapped, 15, 1), (0x1F140, Mapped, 16, 1), (0x1F141, Mapped, 17, 1), (0x1F142, Mapped, 18, 1),
+    (0x1F143, Mapped, 19, 1), (0x1F144, Mapped, 20, 1), (0x1F145, Mapped, 21, 1), (0x1F146, Mapped, 22, 1),
+    (0x1F147, Mapped, 23, 1), (0x1F148, Mapped, 24, 1), (0x1F149, Mapped, 25, 1), (0x1F14A, Mapped, 11398, 2),
+    (0x1F14B, Mapped, 6073, 2), (0x1F14C, Mapped, 11400, 2), (0x1F14D, Mapped, 119, 2), (0x1F14E, Mapped, 11402, 3),
+    (0x1F14F, Mapped, 11405, 2), (0x1F150, Valid, 0, 0), (0x1F16A, Mapped, 11407, 2), (0x1F16B, Mapped, 11409, 2),
+    (0x1F16C, Mapped, 11411, 2), (0x1F16D, Valid, 0, 0), (0x1F190, Mapped, 11413, 2), (0x1F191, Valid, 0, 0),
+    (0x1F1AE, Disallowed, 0, 0), (0x1F1E6, Valid, 0, 0), (0x1F200, Mapped, 11415, 6), (0x1F201, Mapped, 11421, 6),
+    (0x1F202, Mapped, 4725, 3), (0x1F203, Disallowed, 0, 0), (0x1F210, Mapped, 3276, 3), (0x1F211, Mapped, 11427, 3),
+    (0x1F212, Mapped, 11430, 3), (0x1F213, Mapped, 11433, 3), (0x1F214, Mapped, 3105, 3), (0x1F215, Mapped, 11436, 3),
+    (0x1F216, Mapped, 11439, 3), (0x1F217, Mapped, 4062, 3), (0x1F218, Mapped, 11442, 3), (0x1F219, Mapped, 11445, 3),
+    (0x1F21A, Mapped, 11448, 3), (0x1F21B, Mapped, 7431, 3), (0x1F21C, Mapped, 11451, 3), (0x1F21D, Mapped, 11454, 3),
+    (0x1F21E, Mapped, 11457, 3), (0x1F21F, Mapped, 11460, 3), (0x1F220, Mapped, 11463, 3), (0x1F221, Mapped, 11466, 3),
+    (0x1F222, Mapped, 3384, 3), (0x1F223, Mapped, 11469, 3), (0x1F224, Mapped, 11472, 3), (0x1F225, Mapped, 11475, 3),
+    (0x1F226, Mapped, 11478, 3), (0x1F227, Mapped, 11481, 3), (0x1F228, Mapped, 11484, 3), (0x1F229, Mapped, 3087, 3),
+    (0x1F22A, Mapped, 4038, 3), (0x1F22B, Mapped, 11487, 3), (0x1F22C, Mapped, 4574, 3), (0x1F22D, Mapped, 4047, 3),
+    (0x1F22E, Mapped, 4577, 3), (0x1F22F, Mapped, 11490, 3), (0x1F230, Mapped, 3552, 3), (0x1F231, Mapped, 11493, 3),
+    (0x1F232, Mapped, 11496, 3), (0x1F233, Mapped, 11499, 3), (0x1F234, Mapped, 11502, 3), (0x1F235, Mapped, 11505, 3),
+    (0x1F236, Mapped, 4523, 3), (0x1F237, Mapped, 3306, 3), (0x1F238, Mapped, 11508, 3), (0x1F239, Mapped, 11511, 3),
+    (0x1F23A, Mapped, 11514, 3), (0x1F23B, Mapped, 11517, 3), (0x1F23C, Disallowed, 0, 0), (0x1F240, Mapped, 11520, 9),
+    (0x1F241, Mapped, 11529, 9), (0x1F242, Mapped, 11538, 9), (0x1F243, Mapped, 11547, 9), (0x1F244, Mapped, 11556, 9),
+    (0x1F245, Mapped, 11565, 9), (0x1F246, Mapped, 11574, 9), (0x1F247, Mapped, 11583, 9), (0x1F248, Mapped, 11592, 9),
+    (0x1F249, Disallowed, 0, 0), (0x1F250, Mapped, 11601, 3), (0x1F251, Mapped, 11604, 3), (0x1F252, Disallowed, 0, 0),
+    (0x1F260, Valid, 0, 0), (0x1F266, Disallowed, 0, 0), (0x1F300, Valid, 0, 0), (0x1F6D8, Disallowed, 0, 0),
+    (0x1F6DC, Valid, 0, 0), (0x1F6ED, Disallowed, 0, 0), (0x1F6F0, Valid, 0, 0), (0x1F6FD, Disallowed, 0, 0),
+    (0x1F700, Valid, 0, 0), (0x1F777, Disallowed, 0, 0), (0x1F77B, Valid, 0, 0), (0x1F7DA, Disallowed, 0, 0),
+    (0x1F7E0, Valid, 0, 0), (0x1F7EC, Disallowed, 0, 0), (0x1F7F0, Valid, 0, 0), (0x1F7F1, Disallowed, 0, 0),
+    (0x1F800, Valid, 0, 0), (0x1F80C, Disallowed, 0, 0), (0x1F810, Valid, 0, 0), (0x1F848, Disallowed, 0, 0),
+    (0x1F850, Valid, 0, 0), (0x1F85A, Disallowed, 0, 0), (0x1F860, Valid, 0, 0), (0x1F888, Disallowed, 0, 0),
+    (0x1F890, Valid, 0, 0), (0x1F8AE, Disallowed, 0, 0), (0x1F8B0, Valid, 0, 0), (0x1F8B2, Disallowed, 0, 0),
+    (0x1F900, Valid, 0, 0), (0x1FA54, Disallowed, 0, 0), (0x1FA60, Valid, 0, 0), (0x1FA6E, Disallowed, 0, 0),
+    (0x1FA70, Valid, 0, 0), (0x1FA7D, Disallowed, 0, 0), (0x1FA80, Valid, 0, 0), (0x1FA89, Disallowed, 0, 0),
+    (0x1FA90, Valid, 0, 0), (0x1FABE, Disallowed, 0, 0), (0x1FABF, Valid, 0, 0), (0x1FAC6, Disallowed, 0, 0),
+    (0x1FACE, Valid, 0, 0), (0x1FADC, Disallowed, 0, 0), (0x1FAE0, Valid, 0, 0), (0x1FAE9, Disallowed, 0, 0),
+    (0x1FAF0, Valid, 0, 0), (0x1FAF9, Disallowed, 0, 0), (0x1FB00, Valid, 0, 0), (0x1FB93, Disallowed, 0, 0),
+    (0x1FB94, Valid, 0, 0), (0x1FBCB, Disallowed, 0, 0), (0x1FBF0, Mapped, 2349, 1), (0x1FBF1, Mapped, 43, 1),
+    (0x1FBF2, Mapped, 33, 1), (0x1FBF3, Mapped, 34, 1), (0x1FBF4, Mapped, 2350, 1), (0x1FBF5, Mapped, 2351, 1),
+    (0x1FBF6, Mapped, 2352, 1), (0x1FBF7, Mapped, 2353, 1), (0x1FBF8, Mapped, 2354, 1), (0x1FBF9, Mapped, 2355, 1),
+    (0x1FBFA, Disallowed, 0, 0), (0x20000, Valid, 0, 0), (0x2A6E0, Disallowed, 0, 0), (0x2A700, Valid, 0, 0),
+    (0x2B73A, Disallowed, 0, 0), (0x2B740, Valid, 0, 0), (0x2B81E, Disallowed, 0, 0), (0x2B820, Valid, 0, 0),
+    (0x2CEA2, Disallowed, 0, 0), (0x2CEB0, Valid, 0, 0), (0x2EBE1, Disallowed, 0, 0), (0x2EBF0, Valid, 0, 0),
+    (0x2EE5E, Disallowed, 0, 0), (0x2F800, Mapped, 11607, 3), (0x2F801, Mapped, 11610, 3), (0x2F802, Mapped, 11613, 3),
+    (0x2F803, Mapped, 11616, 4), (0x2F804, Mapped, 11620, 3), (0x2F805, Mapped, 7710, 3), (0x2F806, Mapped, 11623, 3),
+    (0x2F807, Mapped, 11626, 3), (0x2F808, Mapped, 11629, 3), (0x2F809, Mapped, 11632, 3), (0x2F80A, Mapped, 7713, 3),
+    (0x2F80B, Mapped, 11635, 3), (0x2F80C, Mapped, 11638, 3), (0x2F80D, Mapped, 11641, 4), (0x2F80E, Mapped, 7716, 3),
+    (0x2F80F, Mapped, 11645, 3), (0x2F810, Mapped, 11648, 3), (0x2F811, Mapped, 11651, 3), (0x2F812, Mapped, 11654, 4),
+    (0x2F813, Mapped, 11658, 3), (0x2F814, Mapped, 11661, 3), (0x2F815, Mapped, 11457, 3), (0x2F816, Mapped, 11664, 4),
+    (0x2F817, Mapped, 11668, 3), (0x2F818, Mapped, 11671, 3), (0x2F819, Mapped, 11674, 3), (0x2F81A, Mapped, 11677, 3),
+    (0x2F81B, Mapped, 7882, 3), (0x2F81C, Mapped, 11680, 4), (0x2F81D, Mapped, 3135, 3), (0x2F81E, Mapped, 11684, 3),
+    (0x2F81F, Mapped, 11687, 3), (0x2F820, Mapped, 11690, 3), (0x2F821, Mapped, 11693, 3), (0x2F822, Mapped, 11511, 3),
+    (0x2F823, Mapped, 11696, 3), (0x2F824, Mapped, 11699, 3), (0x2F825, Mapped, 7897, 3), (0x2F826, Mapped, 7719, 3),
+    (0x2F827, Mapped, 7722, 3), (0x2F828, Mapped, 7900, 3), (0x2F829, Mapped, 11702, 3), (0x2F82A, Mapped, 11705, 3),
+    (0x2F82B, Mapped, 7173, 3), (0x2F82C, Mapped, 11708, 3), (0x2F82D, Mapped, 7725, 3), (0x2F82E, Mapped, 11711, 3),
+    (0x2F82F, Mapped, 11714, 3), (0x2F830, Mapped, 11717, 3), (0x2F831, Mapped, 11720, 3), (0x2F834, Mapped, 11723, 4),
+    (0x2F835, Mapped, 11727, 3), (0x2F836, Mapped, 11730, 3), (0x2F837, Mapped, 11733, 3), (0x2F838, Mapped, 11736, 4),
+    (0x2F839, Mapped, 11740, 3), (0x2F83A, Mapped, 11743, 3), (0x2F83B, Mapped, 11746, 3), (0x2F83C, Mapped, 11749, 3),
+    (0x2F83D, Mapped, 11752, 3), (0x2F83E, Mapped, 11755, 3), (0x2F83F, Mapped, 11758, 3), (0x2F840, Mapped, 11761, 3),
+    (0x2F841, Mapped, 11764, 3), (0x2F842, Mapped, 11767, 3), (0x2F843, Mapped, 11770, 3), (0x2F844, Mapped, 11773, 3),
+    (0x2F845, Mapped, 11776, 3), (0x2F847, Mapped, 7906, 3), (0x2F848, Mapped, 11779, 3), (0x2F849, Mapped, 11782, 3),
+    (0x2F84A, Mapped, 11785, 3), (0x2F84B, Mapped, 11788, 3), (0x2F84C, Mapped, 7731, 3), (0x2F84D, Mapped, 11791, 3),
+    (0x2F84E, Mapped, 11794, 3), (0x2F84F, Mapped, 11797, 3), (0x2F850, Mapped, 7611, 3), (0x2F851, Mapped, 11800, 3),
+    (0x2F852, Mapped, 11803, 3), (0x2F853, Mapped, 11806, 3), (0x2F854, Mapped, 11809, 3), (0x2F855, Mapped, 11812, 3),
+    (0x2F856, Mapped, 11815, 3), (0x2F857, Mapped, 11818, 3), (0x2F858, Mapped, 11821, 3), (0x2F859, Mapped, 11824, 4),
+    (0x2F85A, Mapped, 11828, 3), (0x2F85B, Mapped, 11831, 3), (0x2F85C, Mapped, 11834, 3), (0x2F85D, Mapped, 11436, 3),
+    (0x2F85E, Mapped, 11837, 3), (0x2F85F, Mapped, 11840, 3), (0x2F860, Mapped, 11843, 4), (0x2F861, Mapped, 11847, 4),
+    (0x2F862, Mapped, 11851, 3), (0x2F863, Mapped, 11854, 3), (0x2F864, Mapped, 11857, 3), (0x2F865, Mapped, 11860, 3),
+    (0x2F866, Mapped, 11863, 3), (0x2F867, Mapped, 11866, 3), (0x2F868, Disallowed, 0, 0), (0x2F869, Mapped, 11869, 3),
+    (0x2F86A, Mapped, 11872, 3), (0x2F86C, Mapped, 11875, 4), (0x2F86D, Mapped, 11879, 3), (0x2F86E, Mapped, 11882, 3),
+    (0x2F86F, Mapped, 7161, 3), (0x2F870, Mapped, 11885, 3), (0x2F871, Mapped, 11888, 4), (0x2F872, Mapped, 11892, 3),
+    (0x2F873, Mapped, 11895, 3), (0x2F874, Disallowed, 0, 0), (0x2F875, Mapped, 3213, 3), (0x2F876, Mapped, 11898, 3),
+    (0x2F877, Mapped, 11901, 3), (0x2F878, Mapped, 3219, 3), (0x2F879, Mapped, 11904, 3), (0x2F87A, Mapped, 11907, 3),
+    (0x2F87B, Mapped, 11910, 4), (0x2F87C, Mapped, 11914, 3), (0x2F87D, Mapped, 11917, 4), (0x2F87E, Mapped, 11921, 3),
+    (0x2F87F, Mapped, 11924, 3), (0x2F880, Mapped, 11927, 3), (0x2F881, Mapped, 11930, 3), (0x2F882, Mapped, 11933, 3),
+    (0x2F883, Mapped, 11936, 3), (0x2F884, Mapped, 11939, 3), (0x2F885, Mapped, 11942, 3), (0x2F886, Mapped, 11945, 3),
+    (0x2F887, Mapped, 11948, 3), (0x2F888, Mapped, 11951, 3), (0x2F889, Mapped, 11954, 4), (0x2F88A, Mapped, 11958, 3),
+    (0x2F88B, Mapped, 11961, 3), (0x2F88C, Mapped, 11964, 3), (0x2F88D, Mapped, 11967, 3), (0x2F88E, Mapped, 7005, 3),
+    (0x2F88F, Mapped, 11970, 4), (0x2F890, Mapped, 3249, 3), (0x2F891, Mapped, 11974, 4), (0x2F893, Mapped, 11978, 3),
+    (0x2F894, Mapped, 11981, 3), (0x2F896, Mapped, 11984, 3), (0x2F897, Mapped, 11987, 4), (0x2F898, Mapped, 11991, 4),
+    (0x2F899, Mapped, 11995, 3), (0x2F89A, Mapped, 11998, 3), (0x2F89B, Mapped, 12001, 3), (0x2F89C, Mapped, 12004, 3),
+    (0x2F89D, Mapped, 12007, 3), (0x2F89E, Mapped, 12010, 3), (0x2F89F, Mapped, 12013, 3), (0x2F8A0, Mapped, 12016, 3),
+    (0x2F8A1, Mapped, 12019, 3), (0x2F8A2, Mapped, 12022, 3), (0x2F8A3, Mapped, 7746, 3), (0x2F8A4, Mapped, 12025, 4),
+    (0x2F8A5, Mapped, 12029, 3), (0x2F8A6, Mapped, 12032, 3), (0x2F8A7, Mapped, 12035, 3), (0x2F8A8, Mapped, 7942, 3),
+    (0x2F8A9, Mapped, 12035, 3), (0x2F8AA, Mapped, 12038, 3), (0x2F8AB, Mapped, 7752, 3), (0x2F8AC, Mapped, 12041, 3),
+    (0x2F8AD, Mapped, 12044, 3), (0x2F8AE, Mapped, 12047, 3), (0x2F8AF, Mapped, 12050, 3), (0x2F8B0, Mapped, 7755, 3),
+    (0x2F8B1, Mapped, 6924, 3), (0x2F8B2, Mapped, 12053, 3), (0x2F8B3, Mapped, 12056, 3), (0x2F8B4, Mapped, 12059, 3),
+    (0x2F8B5, Mapped, 12062, 3), (0x2F8B6, Mapped, 12065, 3), (0x2F8B7, Mapped, 12068, 3), (0x2F8B8, Mapped, 12071, 4),
+    (0x2F8B9, Mapped, 12075, 3), (0x2F8BA, Mapped, 12078, 3), (0x2F8BB, Mapped, 12081, 3), (0x2F8BC, Mapped, 12084, 3),
+    (0x2F8BD, Mapped, 12087, 3), (0x2F8BE, Mapped, 12090, 4), (0x2F8BF, Mapped, 12094, 3), (0x2F8C0, Mapped, 12097, 3),
+    (0x2F8C1, Mapped, 12100, 3), (0x2F8C2, Mapped, 12103, 3), (0x2F8C3, Mapped, 12106, 3), (0x2F8C4, Mapped, 12109, 3),
+    (0x2F8C5, Mapped, 12112, 3), (0x2F8C6, Mapped, 12115, 3), (0x2F8C7, Mapped, 12118, 3), (0x2F8C8, Mapped, 7758, 3),
+    (0x2F8C9, Mapped, 12121, 3), (0x2F8CA, Mapped, 12124, 4), (0x2F8CB, Mapped, 12128, 3), (0x2F8CC, Mapped, 12131, 3),
+    (0x2F8CD, Mapped, 12134, 3), (0x2F8CE, Mapped, 12137, 3), (0x2F8CF, Mapped, 7764, 3), (0x2F8D0, Mapped, 12140, 3),
+    (0x2F8D1, Mapped, 12143, 3), (0x2F8D2, Mapped, 12146, 3), (0x2F8D3, Mapped, 12149, 3), (0x2F8D4, Mapped, 12152, 3),
+    (0x2F8D5, Mapped, 12155, 3), (0x2F8D6, Mapped, 12158, 3), (0x2F8D7, Mapped, 12161, 3), (0x2F8D8, Mapped, 7008, 3),
+    (0x2F8D9, Mapped, 7966, 3), (0x2F8DA, Mapped, 12164, 3), (0x2F8DB, Mapped, 12167, 3), (0x2F8DC, Mapped, 12170, 3),
+    (0x2F8DD, Mapped, 12173, 4), (0x2F8DE, Mapped, 12177, 3), (0x2F8DF, Mapped, 12180, 3), (0x2F8E0, Mapped, 12183, 3),
+    (0x2F8E1, Mapped, 12186, 3), (0x2F8E2, Mapped, 7767, 3), (0x2F8E3, Mapped, 12189, 4), (0x2F8E4, Mapped, 12193, 3),
+    (0x2F8E5, Mapped, 12196, 3), (0x2F8E6, Mapped, 12199, 3), (0x2F8E7, Mapped, 8095, 3), (0x2F8E8, Mapped, 12202, 3),
+    (0x2F8E9, Mapped, 12205, 3), (0x2F8EA, Mapped, 12208, 3), (0x2F8EB, Mapped, 12211, 3), (0x2F8EC, Mapped, 12214, 4),
+    (0x2F8ED, Mapped, 12218, 3), (0x2F8EE, Mapped, 12221, 3), (0x2F8EF, Mapped, 12224, 3), (0x2F8F0, Mapped, 12227, 4),
+    (0x2F8F1, Mapped, 12231, 3), (0x2F8F2, Mapped, 12234, 3), (0x2F8F3, Mapped, 12237, 3), (0x2F8F4, Mapped, 12240, 3),
+    (0x2F8F5, Mapped, 7212, 3), (0x2F8F6, Mapped, 12243, 3), (0x2F8F7, Mapped, 12246, 4), (0x2F8F8, Mapped, 12250, 4),
+    (0x2F8F9, Mapped, 12254, 4), (0x2F8FA, Mapped, 12258, 3), (0x2F8FB, Mapped, 12261, 4), (0x2F8FC, Mapped, 12265, 3),
+    (0x2F8FD, Mapped, 12268, 3), (0x2F8FE, Mapped, 12271, 3), (0x2F8FF, Mapped, 12274, 3), (0x2F900, Mapped, 12277, 3),
+    (0x2F901, Mapped, 7770, 3), (0x2F902, Mapped, 7461, 3), (0x2F903, Mapped, 12280, 3), (0x2F904, Mapped, 12283, 3),
+    (0x2F905, Mapped, 12286, 3), (0x2F906, Mapped, 12289, 4), (0x2F907, Mapped, 12293, 3), (0x2F908, Mapped, 12296, 3),
+    (0x2F909, Mapped, 12299, 3), (0x2F90A, Mapped, 12302, 3), (0x2F90B, Mapped, 7975, 3), (0x2F90C, Mapped, 12305, 3),
+    (0x2F90D, Mapped, 12308, 4), (0x2F90E, Mapped, 12312, 3), (0x2F90F, Mapped, 12315, 3), (0x2F910, Mapped, 12318, 4),
+    (0x2F911, Mapped, 12322, 4), (0x2F912, Mapped, 12326, 3), (0x2F913, Mapped, 12329, 3), (0x2F914, Mapped, 7978, 3),
+    (0x2F915, Mapped, 12332, 3), (0x2F916, Mapped, 12335, 3), (0x2F917, Mapped, 12338, 3), (0x2F918, Mapped, 12341, 3),
+    (0x2F919, Mapped, 12344, 3), (0x2F91A, Mapped, 12347, 3), (0x2F91B, Mapped, 12350, 4), (0x2F91C, Mapped, 12354, 3),
+    (0x2F91D, Mapped, 12357, 4), (0x2F91E, Mapped, 12361, 3), (0x2F91F, Disallowed, 0, 0), (0x2F920, Mapped, 12364, 3),
+    (0x2F921, Mapped, 7984, 3), (0x2F922, Mapped, 12367, 3), (0x2F923, Mapped, 12370, 4), (0x2F924, Mapped, 12374, 3),
+    (0x2F925, Mapped, 12377, 3), (0x2F926, Mapped, 12380, 4), (0x2F927, Mapped, 12384, 4), (0x2F928, Mapped, 12388, 3),
+    (0x2F929, Mapped, 12391, 3), (0x2F92A, Mapped, 12394, 3), (0x2F92B, Mapped, 12397, 3), (0x2F92C, Mapped, 12400, 3),
+    (0x2F92E, Mapped, 12403, 3), (0x2F92F, Mapped, 12406, 3), (0x2F930, Mapped, 7990, 3), (0x2F931, Mapped, 12409, 3),
+    (0x2F932, Mapped, 12412, 3), (0x2F933, Mapped, 12415, 3), (0x2F934, Mapped, 12418, 3), (0x2F935, Mapped, 12421, 4),
+    (0x2F936, Mapped, 12425, 3), (0x2F937, Mapped, 12428, 4), (0x2F938, Mapped, 7170, 3), (0x2F939, Mapped, 12432, 4),
+    (0x2F93A, Mapped, 12436, 3), (0x2F93B, Mapped, 12439, 4), (0x2F93C, Mapped, 12443, 4), (0x2F93D, Mapped, 12447, 4),
+    (0x2F93E, Mapped, 12451, 3), (0x2F93F, Mapped, 12454, 3), (0x2F940, Mapped, 8008, 3), (0x2F941, Mapped, 12457, 4),
+    (0x2F942, Mapped, 12461, 4), (0x2F943, Mapped, 12465, 4), (0x2F944, Mapped, 12469, 4), (0x2F945, Mapped, 12473, 3),
+    (0x2F946, Mapped, 12476, 3), (0x2F948, Mapped, 8011, 3), (0x2F949, Mapped, 8101, 3), (0x2F94A, Mapped, 12479, 3),
+    (0x2F94B, Mapped, 12482, 3), (0x2F94C, Mapped, 12485, 3), (0x2F94D, Mapped, 12488, 4), (0x2F94E, Mapped, 12492, 3),
+    (0x2F94F, Mapped, 7059, 3), (0x2F950, Mapped, 8017, 3), (0x2F951, Mapped, 12495, 3), (0x2F952, Mapped, 12498, 4),
+    (0x2F953, Mapped, 7800, 3), (0x2F954, Mapped, 12502, 4), (0x2F955, Mapped, 12506, 4), (0x2F956, Mapped, 7671, 3),
+    (0x2F957, Mapped, 12510, 3), (0x2F958, Mapped, 12513, 3), (0x2F959, Mapped, 7809, 3), (0x2F95A, Mapped, 12516, 3),
+    (0x2F95B, Mapped, 12519, 3), (0x2F95C, Mapped, 12522, 4), (0x2F95D, Mapped, 12526, 4), (0x2F95F, Disallowed, 0, 0),
+    (0x2F960, Mapped, 12530, 3), (0x2F961, Mapped, 12533, 4), (0x2F962, Mapped, 12537, 3), (0x2F963, Mapped, 12540, 3),
+    (0x2F964, Mapped, 12543, 3), (0x2F965, Mapped, 12546, 4), (0x2F966, Mapped, 12550, 3), (0x2F967, Mapped, 12553, 3),
+    (0x2F968, Mapped, 12556, 3), (0x2F969, Mapped, 12559, 3), (0x2F96A, Mapped, 12562, 3), (0x2F96B, Mapped, 12565, 4),
+    (0x2F96C, Mapped, 12569, 3), (0x2F96D, Mapped, 12572, 3), (0x2F96E, Mapped, 12575, 3), (0x2F96F, Mapped, 12578, 3),
+    (0x2F970, Mapped, 12581, 3), (0x2F971, Mapped, 12584, 3), (0x2F972, Mapped, 12587, 4), (0x2F973, Mapped, 12591, 4),
+    (0x2F974, Mapped, 12595, 3), (0x2F975, Mapped, 12598, 4), (0x2F976, Mapped, 12602, 3), (0x2F977, Mapped, 12605, 4),
+    (0x2F978, Mapped, 12609, 3), (0x2F979, Mapped, 12612, 3), (0x2F97A, Mapped, 7827, 3), (0x2F97B, Mapped, 12615, 4),
+    (0x2F97C, Mapped, 12619, 4), (0x2F97D, Mapped, 12623, 3), (0x2F97E, Mapped, 12626, 4), (0x2F97F, Mapped, 12630, 3),
+    (0x2F980, Mapped, 12633, 4), (0x2F981, Mapped, 12637, 3), (0x2F982, Mapped, 12640, 3), (0x2F983, Mapped, 12643, 3),
+    (0x2F984, Mapped, 12646, 3), (0x2F985, Mapped, 12649, 3), (0x2F986, Mapped, 12652, 3), (0x2F987, Mapped, 12655, 4),
+    (0x2F988, Mapped, 12659, 4), (0x2F989, Mapped, 12663, 4), (0x2F98A, Mapped, 12667, 4), (0x2F98B, Mapped, 11978, 3),
+    (0x2F98C, Mapped, 12671, 3), (0x2F98D, Mapped, 12674, 3), (0x2F98E, Mapped, 12677, 3), (0x2F98F, Mapped, 12680, 3),
+    (0x2F990, Mapped, 12683, 3), (0x2F991, Mapped, 12686, 3), (0x2F992, Mapped, 12689, 3), (0x2F993, Mapped, 12692, 3),
+    (0x2F994, Mapped, 12695, 3), (0x2F995, Mapped, 12698, 3), (0x2F996, Mapped, 12701, 3), (0x2F997, Mapped, 12704, 4),
+    (0x2F998, Mapped, 7221, 3), (0x2F999, Mapped, 12708, 3), (0x2F99A, Mapped, 12711, 3), (0x2F99B, Mapped, 12714, 3),
+    (0x2F99C, Mapped, 12717, 3), (0x2F99D, Mapped, 12720, 3), (0x2F99E, Mapped, 12723, 3), (0x2F99F, Mapped, 7836, 3),
+    (0x2F9A0, Mapped, 12726, 3), (0x2F9A1, Mapped, 12729, 3), (0x2F9A2, Mapped, 12732, 3), (0x2F9A3, Mapped, 12735, 3),
+    (0x2F9A4, Mapped, 12738, 4), (0x2F9A5, Mapped, 12742, 4), (0x2F9A6, Mapped, 12746, 4), (0x2F9A7, Mapped, 12750, 3),
+    (0x2F9A8, Mapped, 12753, 3), (0x2F9A9, Mapped, 12756, 3), (0x2F9AA, Mapped, 12759, 3), (0x2F9AB, Mapped, 12762, 4),
+    (0x2F9AC, Mapped, 12766, 3), (0x2F9AD, Mapped, 12769, 4), (0x2F9AE, Mapped, 12773, 3), (0x2F9AF, Mapped, 12776, 3),
+    (0x2F9B0, Mapped, 12779, 4), (0x2F9B1, Mapped, 12783, 4), (0x2F9B2, Mapped, 12787, 3), (0x2F9B3, Mapped, 12790, 3),
+    (0x2F9B4, Mapped, 7044, 3), (0x2F9B5, Mapped, 12793, 3), (0x2F9B6, Mapped, 12796, 3), (0x2F9B7, Mapped, 12799, 3),
+    (0x2F9B8, Mapped, 12802, 3), (0x2F9B9, Mapped, 12805, 3), (0x2F9BA, Mapped, 12808, 3), (0x2F9BB, Mapped, 8038, 3),
+    (0x2F9BC, Mapped, 12811, 3), (0x2F9BD, Mapped, 12814, 3), (0x2F9BE, Mapped, 12817, 3), (0x2F9BF, Disallowed, 0, 0),
+    (0x2F9C0, Mapped, 12820, 3), (0x2F9C1, Mapped, 12823, 3), (0x2F9C2, Mapped, 12826, 3), (0x2F9C3, Mapped, 12829, 3),
+    (0x2F9C4, Mapped, 3519, 3), (0x2F9C5, Mapped, 12832, 4), (0x2F9C6, Mapped, 12836, 3), (0x2F9C7, Mapped, 12839, 3),
+    (0x2F9C8, Mapped, 12842, 3), (0x2F9C9, Mapped, 12845, 3), (0x2F9CA, Mapped, 12848, 3), (0x2F9CB, Mapped, 12851, 4),
+    (0x2F9CC, Mapped, 12855, 4), (0x2F9CD, Mapped, 12859, 3), (0x2F9CE, Mapped, 12862, 3), (0x2F9CF, Mapped, 12865, 3),
+    (0x2F9D0, Mapped, 8053, 3), (0x2F9D1, Mapped, 8056, 3), (0x2F9D2, Mapped, 3540, 3), (0x2F9D3, Mapped, 12868, 4),
+    (0x2F9D4, Mapped, 12872, 3), (0x2F9D5, Mapped, 12875, 3), (0x2F9D6, Mapped, 12878, 3), (0x2F9D7, Mapped, 12881, 3),
+    (0x2F9D8, Mapped, 12884, 4), (0x2F9D9, Mapped, 12888, 4), (0x2F9DA, Mapped, 12892, 3), (0x2F9DB, Mapped, 12895, 3),
+    (0x2F9DC, Mapped, 12898, 3), (0x2F9DD, Mapped, 12901, 4), (0x2F9DE, Mapped, 12905, 3), (0x2F9DF, Mapped, 8059, 3),
+    (0x2F9E0, Mapped, 12908, 4), (0x2F9E1, Mapped, 12912, 4), (0x2F9E2, Mapped, 12916, 3), (0x2F9E3, Mapped, 12919, 3),
+    (0x2F9E4, Mapped, 12922, 3), (0x2F9E5, Mapped, 12925, 4), (0x2F9E6, Mapped, 12929, 3), (0x2F9E7, Mapped, 12932, 3),
+    (0x2F9E8, Mapped, 12935, 3), (0x2F9E9, Mapped, 12938, 3), (0x2F9EA, Mapped, 12941, 3), (0x2F9EB, Mapped, 12944, 3),
+    (0x2F9EC, Mapped, 12947, 3), (0x2F9ED, Mapped, 12950, 4), (0x2F9EE, Mapped, 12954, 3), (0x2F9EF, Mapped, 12957, 3),
+    (0x2F9F0, Mapped, 12960, 3), (0x2F9F1, Mapped, 12963, 4), (0x2F9F2, Mapped, 12967, 3), (0x2F9F3, Mapped, 12970, 3),
+    (0x2F9F4, Mapped, 12973, 3), (0x2F9F5, Mapped, 12976, 3), (0x2F9F6, Mapped, 12979, 4), (0x2F9F7, Mapped, 12983, 4),
+    (0x2F9F8, Mapped, 12987, 3), (0x2F9F9, Mapped, 12990, 3), (0x2F9FA, Mapped, 12993, 3), (0x2F9FB, Mapped, 12996, 4),
+    (0x2F9FC, Mapped, 13000, 3), (0x2F9FD, Mapped, 13003, 4), (0x2F9FE, Mapped, 8077, 3), (0x2FA00, Mapped, 13007, 3),
+    (0x2FA01, Mapped, 13010, 4), (0x2FA02, Mapped, 13014, 3), (0x2FA03, Mapped, 13017, 3), (0x2FA04, Mapped, 13020, 3),
+    (0x2FA05, Mapped, 13023, 3), (0x2FA06, Mapped, 13026, 3), (0x2FA07, Mapped, 13029, 3), (0x2FA08, Mapped, 13032, 3),
+    (0x2FA09, Mapped, 13035, 4), (0x2FA0A, Mapped, 8080, 3), (0x2FA0B, Mapped, 13039, 3), (0x2FA0C, Mapped, 13042, 3),
+    (0x2FA0D, Mapped, 13045, 3), (0x2FA0E, Mapped, 13048, 3), (0x2FA0F, Mapped, 13051, 3), (0x2FA10, Mapped, 13054, 4),
+    (0x2FA11, Mapped, 13058, 3), (0x2FA12, Mapped, 13061, 4), (0x2FA13, Mapped, 13065, 4), (0x2FA14, Mapped, 13069, 4),
+    (0x2FA15, Mapped, 3684, 3), (0x2FA16, Mapped, 13073, 3), (0x2FA17, Mapped, 3696, 3), (0x2FA18, Mapped, 13076, 3),
+    (0x2FA19, Mapped, 13079, 3), (0x2FA1A, Mapped, 13082, 3), (0x2FA1B, Mapped, 13085, 3), (0x2FA1C, Mapped, 3711, 3),
+    (0x2FA1D, Mapped, 13088, 4), (0x2FA1E, Disallowed, 0, 0), (0x30000, Valid, 0, 0), (0x3134B, Disallowed, 0, 0),
+    (0x31350, Valid, 0, 0), (0x323B0, Disallowed, 0, 0), (0xE0100, Ignored, 0, 0), (0xE01F0, Disallowed, 0, 0),
+];
+
+pub(super) static MAPPING_DATA: &str = "\
+abcdefghijklmnopqrstuvwxyz  \u{308} \u{304}23 \u{301}\u{3BC} \u{327}11\u{2044}41\u{2044}23\u{2044}4\u{E0}\u{E1}\u{E2}\u{E3}\u{E4}\u{E5}\u{E6}\u{E7}\u{E8}\u{E9}\u{EA}\u{EB}\u{EC}\u{ED}\u{EE}\u{EF}\
+\u{F0}\u{F1}\u{F2}\u{F3}\u{F4}\u{F5}\u{F6}\u{F8}\u{F9}\u{FA}\u{FB}\u{FC}\u{FD}\u{FE}ss\u{101}\u{103}\u{105}\u{107}\u{109}\u{10B}\u{10D}\u{10F}\u{111}\u{113}\u{115}\u{117}\u{119}\u{11B}\u{11D}\u{11F}\u{121}\u{123}\u{125}\u{127}\u{129}\u{12B}\u{12D}\u{12F}i\u{307}ij\u{135}\u{137}\u{13A}\u{13C}\u{13E}l\u{B7}\u{142}\u{144}\u{146}\u{148}\u{2BC}n\u{14B}\u{14D}\u{14F}\u{151}\u{153}\u{155}\u{157}\
+\u{159}\u{15B}\u{15D}\u{15F}\u{161}\u{163}\u{165}\u{167}\u{169}\u{16B}\u{16D}\u{16F}\u{171}\u{173}\u{175}\u{177}\u{FF}\u{17A}\u{17C}\u{17E}\u{253}\u{183}\u{185}\u{254}\u{188}\u{256}\u{257}\u{18C}\u{1DD}\u{259}\u{25B}\u{192}\u{260}\u{263}\u{269}\u{268}\u{199}\u{26F}\u{272}\u{275}\u{1A1}\u{1A3}\u{1A5}\u{280}\u{1A8}\u{283}\u{1AD}\u{288}\u{1B0}\u{28A}\u{28B}\u{1B4}\u{1B6}\u{292}\u{1B9}\u{1BD}d\u{17E}ljnj\u{1CE}\u{1D0}\
+\u{1D2}\u{1D4}\u{1D6}\u{1D8}\u{1DA}\u{1DC}\u{1DF}\u{1E1}\u{1E3}\u{1E5}\u{1E7}\u{1E9}\u{1EB}\u{1ED}\u{1EF}dz\u{1F5}\u{195}\u{1BF}\u{1F9}\u{1FB}\u{1FD}\u{1FF}\u{201}\u{203}\u{205}\u{207}\u{209}\u{20B}\u{20D}\u{20F}\u{211}\u{213}\u{215}\u{217}\u{219}\u{21B}\u{21D}\u{21F}\u{19E}\u{223}\u{225}\u{227}\u{229}\u{22B}\u{22D}\u{22F}\u{231}\u{233}\u{2C65}\u{23C}\u{19A}\u{2C66}\u{242}\u{180}\u{289}\u{28C}\u{247}\u{249}\u{24B}\u{24D}\u{24F}\u{266}\
+\u{279}\u{27B}\u{281} \u{306} \u{307} \u{30A} \u{328} \u{303} \u{30B}\u{295}\u{300}\u{301}\u{313}\u{308}\u{301}\u{3B9}\u{371}\u{373}\u{2B9}\u{377} \u{3B9};\u{3F3} \u{308}\u{301}\u{3AC}\u{B7}\u{3AD}\u{3AE}\u{3AF}\u{3CC}\u{3CD}\u{3CE}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3BA}\u{3BB}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{3CA}\
+\u{3CB}\u{3D7}\u{3D9}\u{3DB}\u{3DD}\u{3DF}\u{3E1}\u{3E3}\u{3E5}\u{3E7}\u{3E9}\u{3EB}\u{3ED}\u{3EF}\u{3F8}\u{3FB}\u{37B}\u{37C}\u{37D}\u{450}\u{451}\u{452}\u{453}\u{454}\u{455}\u{456}\u{457}\u{458}\u{459}\u{45A}\u{45B}\u{45C}\u{45D}\u{45E}\u{45F}\u{430}\u{431}\u{432}\u{433}\u{434}\u{435}\u{436}\u{437}\u{438}\u{439}\u{43A}\u{43B}\u{43C}\u{43D}\u{43E}\u{43F}\u{440}\u{441}\u{442}\u{443}\u{444}\u{445}\u{446}\u{447}\u{448}\u{449}\u{44A}\u{44B}\u{44C}\
+\u{44D}\u{44E}\u{44F}\u{461}\u{463}\u{465}\u{467}\u{469}\u{46B}\u{46D}\u{46F}\u{471}\u{473}\u{475}\u{477}\u{479}\u{47B}\u{47D}\u{47F}\u{481}\u{48B}\u{48D}\u{48F}\u{491}\u{493}\u{495}\u{497}\u{499}\u{49B}\u{49D}\u{49F}\u{4A1}\u{4A3}\u{4A5}\u{4A7}\u{4A9}\u{4AB}\u{4AD}\u{4AF}\u{4B1}\u{4B3}\u{4B5}\u{4B7}\u{4B9}\u{4BB}\u{4BD}\u{4BF}\u{4C2}\u{4C4}\u{4C6}\u{4C8}\u{4CA}\u{4CC}\u{4CE}\u{4D1}\u{4D3}\u{4D5}\u{4D7}\u{4D9}\u{4DB}\u{4DD}\u{4DF}\u{4E1}\u{4E3}\
+\u{4E5}\u{4E7}\u{4E9}\u{4EB}\u{4ED}\u{4EF}\u{4F1}\u{4F3}\u{4F5}\u{4F7}\u{4F9}\u{4FB}\u{4FD}\u{4FF}\u{501}\u{503}\u{505}\u{507}\u{509}\u{50B}\u{50D}\u{50F}\u{511}\u{513}\u{515}\u{517}\u{519}\u{51B}\u{51D}\u{51F}\u{521}\u{523}\u{525}\u{527}\u{529}\u{52B}\u{52D}\u{52F}\u{561}\u{562}\u{563}\u{564}\u{565}\u{566}\u{567}\u{568}\u{569}\u{56A}\u{56B}\u{56C}\u{56D}\u{56E}\u{56F}\u{570}\u{571}\u{572}\u{573}\u{574}\u{575}\u{576}\u{577}\u{578}\u{579}\u{57A}\
+\u{57B}\u{57C}\u{57D}\u{57E}\u{57F}\u{580}\u{581}\u{582}\u{583}\u{584}\u{585}\u{586}\u{565}\u{582}\u{627}\u{674}\u{648}\u{674}\u{6C7}\u{674}\u{64A}\u{674}\u{915}\u{93C}\u{916}\u{93C}\u{917}\u{93C}\u{91C}\u{93C}\u{921}\u{93C}\u{922}\u{93C}\u{92B}\u{93C}\u{92F}\u{93C}\u{9A1}\u{9BC}\u{9A2}\u{9BC}\u{9AF}\u{9BC}\u{A32}\u{A3C}\u{A38}\u{A3C}\u{A16}\u{A3C}\u{A17}\u{A3C}\u{A1C}\u{A3C}\u{A2B}\u{A3C}\u{B21}\u{B3C}\u{B22}\u{B3C}\u{E4D}\u{E32}\u{ECD}\u{EB2}\
+\u{EAB}\u{E99}\u{EAB}\u{EA1}\u{F0B}\u{F42}\u{FB7}\u{F4C}\u{FB7}\u{F51}\u{FB7}\u{F56}\u{FB7}\u{F5B}\u{FB7}\u{F40}\u{FB5}\u{F71}\u{F72}\u{F71}\u{F74}\u{FB2}\u{F80}\u{FB2}\u{F71}\u{F80}\u{FB3}\u{F80}\u{FB3}\u{F71}\u{F80}\u{F71}\u{F80}\u{F92}\u{FB7}\u{F9C}\u{FB7}\u{FA1}\u{FB7}\u{FA6}\u{FB7}\u{FAB}\u{FB7}\u{F90}\u{FB5}\u{2D27}\u{2D2D}\u{10DC}\u{13F0}\u{13F1}\u{13F2}\u{13F3}\u{13F4}\u{13F5}\u{A64B}\u{10D0}\u{10D1}\u{10D2}\u{10D3}\u{10D4}\u{10D5}\u{10D6}\u{10D7}\u{10D8}\
+\u{10D9}\u{10DA}\u{10DB}\u{10DD}\u{10DE}\u{10DF}\u{10E0}\u{10E1}\u{10E2}\u{10E3}\u{10E4}\u{10E5}\u{10E6}\u{10E7}\u{10E8}\u{10E9}\u{10EA}\u{10EB}\u{10EC}\u{10ED}\u{10EE}\u{10EF}\u{10F0}\u{10F1}\u{10F2}\u{10F3}\u{10F4}\u{10F5}\u{10F6}\u{10F7}\u{10F8}\u{10F9}\u{10FA}\u{10FD}\u{10FE}\u{10FF}\u{250}\u{251}\u{1D02}\u{25C}\u{1D16}\u{1D17}\u{1D1D}\u{1D25}\u{252}\u{255}\u{25F}\u{261}\u{265}\u{26A}\u{1D7B}\u{29D}\u{26D}\u{1D85}\u{29F}\u{271}\u{270}\u{273}\u{274}\u{278}\u{282}\u{1AB}\u{1D1C}\u{290}\
+\u{291}\u{1E01}\u{1E03}\u{1E05}\u{1E07}\u{1E09}\u{1E0B}\u{1E0D}\u{1E0F}\u{1E11}\u{1E13}\u{1E15}\u{1E17}\u{1E19}\u{1E1B}\u{1E1D}\u{1E1F}\u{1E21}\u{1E23}\u{1E25}\u{1E27}\u{1E29}\u{1E2B}\u{1E2D}\u{1E2F}\u{1E31}\u{1E33}\u{1E35}\u{1E37}\u{1E39}\u{1E3B}\u{1E3D}\u{1E3F}\u{1E41}\u{1E43}\u{1E45}\u{1E47}\u{1E49}\u{1E4B}\u{1E4D}\u{1E4F}\u{1E51}\u{1E53}\u{1E55}\u{1E57}\u{1E59}\u{1E5B}\u{1E5D}\u{1E5F}\u{1E61}\u{1E63}\u{1E65}\u{1E67}\u{1E69}\u{1E6B}\u{1E6D}\u{1E6F}\u{1E71}\u{1E73}\u{1E75}\u{1E77}\u{1E79}\u{1E7B}\u{1E7D}\
+\u{1E7F}\u{1E81}\u{1E83}\u{1E85}\u{1E87}\u{1E89}\u{1E8B}\u{1E8D}\u{1E8F}\u{1E91}\u{1E93}\u{1E95}a\u{2BE}\u{DF}\u{1EA1}\u{1EA3}\u{1EA5}\u{1EA7}\u{1EA9}\u{1EAB}\u{1EAD}\u{1EAF}\u{1EB1}\u{1EB3}\u{1EB5}\u{1EB7}\u{1EB9}\u{1EBB}\u{1EBD}\u{1EBF}\u{1EC1}\u{1EC3}\u{1EC5}\u{1EC7}\u{1EC9}\u{1ECB}\u{1ECD}\u{1ECF}\u{1ED1}\u{1ED3}\u{1ED5}\u{1ED7}\u{1ED9}\u{1EDB}\u{1EDD}\u{1EDF}\u{1EE1}\u{1EE3}\u{1EE5}\u{1EE7}\u{1EE9}\u{1EEB}\u{1EED}\u{1EEF}\u{1EF1}\u{1EF3}\u{1EF5}\u{1EF7}\u{1EF9}\u{1EFB}\u{1EFD}\u{1EFF}\u{1F00}\
+\u{1F01}\u{1F02}\u{1F03}\u{1F04}\u{1F05}\u{1F06}\u{1F07}\u{1F10}\u{1F11}\u{1F12}\u{1F13}\u{1F14}\u{1F15}\u{1F20}\u{1F21}\u{1F22}\u{1F23}\u{1F24}\u{1F25}\u{1F26}\u{1F27}\u{1F30}\u{1F31}\u{1F32}\u{1F33}\u{1F34}\u{1F35}\u{1F36}\u{1F37}\u{1F40}\u{1F41}\u{1F42}\u{1F43}\u{1F44}\u{1F45}\u{1F51}\u{1F53}\u{1F55}\u{1F57}\u{1F60}\u{1F61}\u{1F62}\u{1F63}\u{1F64}\u{1F65}\u{1F66}\u{1F67}\u{1F00}\u{3B9}\u{1F01}\u{3B9}\u{1F02}\u{3B9}\u{1F03}\u{3B9}\u{1F04}\u{3B9}\u{1F05}\u{3B9}\u{1F06}\u{3B9}\u{1F07}\u{3B9}\u{1F20}\
+\u{3B9}\u{1F21}\u{3B9}\u{1F22}\u{3B9}\u{1F23}\u{3B9}\u{1F24}\u{3B9}\u{1F25}\u{3B9}\u{1F26}\u{3B9}\u{1F27}\u{3B9}\u{1F60}\u{3B9}\u{1F61}\u{3B9}\u{1F62}\u{3B9}\u{1F63}\u{3B9}\u{1F64}\u{3B9}\u{1F65}\u{3B9}\u{1F66}\u{3B9}\u{1F67}\u{3B9}\u{1F70}\u{3B9}\u{3B1}\u{3B9}\u{3AC}\u{3B9}\u{1FB6}\u{3B9}\u{1FB0}\u{1FB1}\u{1F70} \u{313} \u{342} \u{308}\u{342}\u{1F74}\u{3B9}\u{3B7}\u{3B9}\u{3AE}\u{3B9}\u{1FC6}\u{3B9}\u{1F72}\u{1F74} \u{313}\u{300} \u{313}\
+\u{301} \u{313}\u{342}\u{390}\u{1FD0}\u{1FD1}\u{1F76} \u{314}\u{300} \u{314}\u{301} \u{314}\u{342}\u{3B0}\u{1FE0}\u{1FE1}\u{1F7A}\u{1FE5} \u{308}\u{300}`\u{1F7C}\u{3B9}\u{3C9}\u{3B9}\u{3CE}\u{3B9}\u{1FF6}\u{3B9}\u{1F78}\u{1F7C} \u{314}\u{2010} \u{333}\u{2032}\u{2032}\u{2032}\u{2032}\u{2032}\u{2035}\u{2035}\u{2035}\u{2035}\u{2035}!! \u{305}???!!?\u{2032}\u{2032}\u{2032}\
+\u{2032}0456789+\u{2212}=()rsa/ca/s\u{B0}cc/oc/u\u{B0}fnosmteltm\u{5D0}\u{5D1}\u{5D2}\u{5D3}fax\u{2211}1\u{2044}71\u{2044}91\u{2044}101\u{2044}32\u{2044}3\
+1\u{2044}52\u{2044}53\u{2044}54\u{2044}51\u{2044}65\u{2044}61\u{2044}83\u{2044}85\u{2044}87\u{2044}81\u{2044}iiiiiivviviiviiiixxixii0\u{2044}3\u{222B}\u{222B}\u{222B}\u{222B}\u{222B}\u{222E}\
+\u{222E}\u{222E}\u{222E}\u{222E}\u{3008}\u{3009}1011121314151617181920(1)(2)(3)(4)(5)(6)(7)(8)(9)(10)(11)(\
+12)(13)(14)(15)(16)(17)(18)(19)(20)(a)(b)(c)(d)(e)(f)(g)(h)(i)(j\
+)(k)(l)(m)(n)(o)(p)(q)(r)(s)(t)(u)(v)(w)(x)(y)(z)\u{222B}\u{222B}\u{222B}\u{222B}::======\u{2ADD}\u{338}\u{2C30}\
+\u{2C31}\u{2C32}\u{2C33}\u{2C34}\u{2C35}\u{2C36}\u{2C37}\u{2C38}\u{2C39}\u{2C3A}\u{2C3B}\u{2C3C}\u{2C3D}\u{2C3E}\u{2C3F}\u{2C40}\u{2C41}\u{2C42}\u{2C43}\u{2C44}\u{2C45}\u{2C46}\u{2C47}\u{2C48}\u{2C49}\u{2C4A}\u{2C4B}\u{2C4C}\u{2C4D}\u{2C4E}\u{2C4F}\u{2C50}\u{2C51}\u{2C52}\u{2C53}\u{2C54}\u{2C55}\u{2C56}\u{2C57}\u{2C58}\u{2C59}\u{2C5A}\u{2C5B}\u{2C5C}\u{2C5D}\u{2C5E}\u{2C5F}\u{2C61}\u{26B}\u{1D7D}\u{27D}\u{2C68}\u{2C6A}\u{2C6C}\u{2C73}\u{2C76}\u{23F}\u{240}\u{2C81}\u{2C83}\u{2C85}\u{2C87}\u{2C89}\u{2C8B}\
+\u{2C8D}\u{2C8F}\u{2C91}\u{2C93}\u{2C95}\u{2C97}\u{2C99}\u{2C9B}\u{2C9D}\u{2C9F}\u{2CA1}\u{2CA3}\u{2CA5}\u{2CA7}\u{2CA9}\u{2CAB}\u{2CAD}\u{2CAF}\u{2CB1}\u{2CB3}\u{2CB5}\u{2CB7}\u{2CB9}\u{2CBB}\u{2CBD}\u{2CBF}\u{2CC1}\u{2CC3}\u{2CC5}\u{2CC7}\u{2CC9}\u{2CCB}\u{2CCD}\u{2CCF}\u{2CD1}\u{2CD3}\u{2CD5}\u{2CD7}\u{2CD9}\u{2CDB}\u{2CDD}\u{2CDF}\u{2CE1}\u{2CE3}\u{2CEC}\u{2CEE}\u{2CF3}\u{2D61}\u{6BCD}\u{9F9F}\u{4E00}\u{4E28}\u{4E36}\u{4E3F}\u{4E59}\u{4E85}\u{4E8C}\u{4EA0}\u{4EBA}\u{513F}\u{5165}\u{516B}\u{5182}\u{5196}\
+\u{51AB}\u{51E0}\u{51F5}\u{5200}\u{529B}\u{52F9}\u{5315}\u{531A}\u{5338}\u{5341}\u{535C}\u{5369}\u{5382}\u{53B6}\u{53C8}\u{53E3}\u{56D7}\u{571F}\u{58EB}\u{5902}\u{590A}\u{5915}\u{5927}\u{5973}\u{5B50}\u{5B80}\u{5BF8}\u{5C0F}\u{5C22}\u{5C38}\u{5C6E}\u{5C71}\u{5DDB}\u{5DE5}\u{5DF1}\u{5DFE}\u{5E72}\u{5E7A}\u{5E7F}\u{5EF4}\u{5EFE}\u{5F0B}\u{5F13}\u{5F50}\u{5F61}\u{5F73}\u{5FC3}\u{6208}\u{6236}\u{624B}\u{652F}\u{6534}\u{6587}\u{6597}\u{65A4}\u{65B9}\u{65E0}\u{65E5}\u{66F0}\u{6708}\u{6728}\u{6B20}\u{6B62}\u{6B79}\
+\u{6BB3}\u{6BCB}\u{6BD4}\u{6BDB}\u{6C0F}\u{6C14}\u{6C34}\u{706B}\u{722A}\u{7236}\u{723B}\u{723F}\u{7247}\u{7259}\u{725B}\u{72AC}\u{7384}\u{7389}\u{74DC}\u{74E6}\u{7518}\u{751F}\u{7528}\u{7530}\u{758B}\u{7592}\u{7676}\u{767D}\u{76AE}\u{76BF}\u{76EE}\u{77DB}\u{77E2}\u{77F3}\u{793A}\u{79B8}\u{79BE}\u{7A74}\u{7ACB}\u{7AF9}\u{7C73}\u{7CF8}\u{7F36}\u{7F51}\u{7F8A}\u{7FBD}\u{8001}\u{800C}\u{8012}\u{8033}\u{807F}\u{8089}\u{81E3}\u{81EA}\u{81F3}\u{81FC}\u{820C}\u{821B}\u{821F}\u{826E}\u{8272}\u{8278}\u{864D}\u{866B}\
+\u{8840}\u{884C}\u{8863}\u{897E}\u{898B}\u{89D2}\u{8A00}\u{8C37}\u{8C46}\u{8C55}\u{8C78}\u{8C9D}\u{8D64}\u{8D70}\u{8DB3}\u{8EAB}\u{8ECA}\u{8F9B}\u{8FB0}\u{8FB5}\u{9091}\u{9149}\u{91C6}\u{91CC}\u{91D1}\u{9577}\u{9580}\u{961C}\u{96B6}\u{96B9}\u{96E8}\u{9751}\u{975E}\u{9762}\u{9769}\u{97CB}\u{97ED}\u{97F3}\u{9801}\u{98A8}\u{98DB}\u{98DF}\u{9996}\u{9999}\u{99AC}\u{9AA8}\u{9AD8}\u{9ADF}\u{9B25}\u{9B2F}\u{9B32}\u{9B3C}\u{9B5A}\u{9CE5}\u{9E75}\u{9E7F}\u{9EA5}\u{9EBB}\u{9EC3}\u{9ECD}\u{9ED1}\u{9EF9}\u{9EFD}\u{9F0E}\
+\u{9F13}\u{9F20}\u{9F3B}\u{9F4A}\u{9F52}\u{9F8D}\u{9F9C}\u{9FA0}.\u{3012}\u{5344}\u{5345} \u{3099} \u{309A}\u{3088}\u{308A}\u{30B3}\u{30C8}\u{1100}\u{1101}\u{11AA}\u{1102}\u{11AC}\u{11AD}\u{1103}\u{1104}\u{1105}\u{11B0}\u{11B1}\u{11B2}\u{11B3}\u{11B4}\u{11B5}\u{111A}\u{1106}\u{1107}\u{1108}\u{1121}\u{1109}\u{110A}\u{110B}\u{110C}\u{110D}\u{110E}\u{110F}\u{1110}\u{1111}\u{1112}\u{1161}\u{1162}\u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116A}\u{116B}\u{116C}\u{116D}\u{116E}\
+\u{116F}\u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}\u{1114}\u{1115}\u{11C7}\u{11C8}\u{11CC}\u{11CE}\u{11D3}\u{11D7}\u{11D9}\u{111C}\u{11DD}\u{11DF}\u{111D}\u{111E}\u{1120}\u{1122}\u{1123}\u{1127}\u{1129}\u{112B}\u{112C}\u{112D}\u{112E}\u{112F}\u{1132}\u{1136}\u{1140}\u{1147}\u{114C}\u{11F1}\u{11F2}\u{1157}\u{1158}\u{1159}\u{1184}\u{1185}\u{1188}\u{1191}\u{1192}\u{1194}\u{119E}\u{11A1}\u{4E09}\u{56DB}\u{4E0A}\u{4E2D}\u{4E0B}\u{7532}\u{4E19}\u{4E01}\u{5929}\u{5730}(\u{1100})(\u{1102}\
+)(\u{1103})(\u{1105})(\u{1106})(\u{1107})(\u{1109})(\u{110B})(\u{110C})(\u{110E})(\u{110F})(\u{1110})(\u{1111})(\u{1112})(\u{AC00})(\u{B098})(\u{B2E4})(\u{B77C})(\u{B9C8})(\u{BC14})(\u{C0AC})(\u{C544})(\u{C790})\
+(\u{CC28})(\u{CE74})(\u{D0C0})(\u{D30C})(\u{D558})(\u{C8FC})(\u{C624}\u{C804})(\u{C624}\u{D6C4})(\u{4E00})(\u{4E8C})(\u{4E09})(\u{56DB})(\u{4E94})(\u{516D})(\u{4E03})(\u{516B})(\u{4E5D})(\u{5341})(\u{6708})(\u{706B})(\u{6C34}\
+)(\u{6728})(\u{91D1})(\u{571F})(\u{65E5})(\u{682A})(\u{6709})(\u{793E})(\u{540D})(\u{7279})(\u{8CA1})(\u{795D})(\u{52B4})(\u{4EE3})(\u{547C})(\u{5B66})(\u{76E3})(\u{4F01})(\u{8CC7})(\u{5354})(\u{796D})(\u{4F11})\
+(\u{81EA})(\u{81F3})\u{554F}\u{5E7C}\u{7B8F}pte212223242526272829303132333435\u{AC00}\u{B098}\u{B2E4}\u{B77C}\u{B9C8}\u{BC14}\u{C0AC}\u{C544}\u{C790}\u{CC28}\u{CE74}\u{D0C0}\u{D30C}\u{D558}\u{CC38}\u{ACE0}\u{C8FC}\u{C758}\u{C6B0}\u{4E94}\u{516D}\u{4E03}\
+\u{4E5D}\u{682A}\u{6709}\u{793E}\u{540D}\u{7279}\u{8CA1}\u{795D}\u{52B4}\u{79D8}\u{7537}\u{9069}\u{512A}\u{5370}\u{6CE8}\u{9805}\u{4F11}\u{5199}\u{6B63}\u{5DE6}\u{53F3}\u{533B}\u{5B97}\u{5B66}\u{76E3}\u{4F01}\u{8CC7}\u{5354}\u{591C}3637383940414243444546474849501\u{6708}2\u{6708}3\
+\u{6708}4\u{6708}5\u{6708}6\u{6708}7\u{6708}8\u{6708}9\u{6708}10\u{6708}11\u{6708}12\u{6708}hgergevltd\u{30A2}\u{30A4}\u{30A6}\u{30A8}\u{30AA}\u{30AB}\u{30AD}\u{30AF}\u{30B1}\u{30B3}\u{30B5}\u{30B7}\u{30B9}\u{30BB}\u{30BD}\u{30BF}\u{30C1}\u{30C4}\u{30C6}\u{30C8}\u{30CA}\u{30CB}\u{30CC}\u{30CD}\u{30CE}\u{30CF}\u{30D2}\u{30D5}\u{30D8}\u{30DB}\u{30DE}\u{30DF}\
+\u{30E0}\u{30E1}\u{30E2}\u{30E4}\u{30E6}\u{30E8}\u{30E9}\u{30EA}\u{30EB}\u{30EC}\u{30ED}\u{30EF}\u{30F0}\u{30F1}\u{30F2}\u{4EE4}\u{548C}\u{30A2}\u{30D1}\u{30FC}\u{30C8}\u{30A2}\u{30EB}\u{30D5}\u{30A1}\u{30A2}\u{30F3}\u{30DA}\u{30A2}\u{30A2}\u{30FC}\u{30EB}\u{30A4}\u{30CB}\u{30F3}\u{30B0}\u{30A4}\u{30F3}\u{30C1}\u{30A6}\u{30A9}\u{30F3}\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C9}\u{30A8}\u{30FC}\u{30AB}\u{30FC}\u{30AA}\u{30F3}\u{30B9}\u{30AA}\u{30FC}\u{30E0}\u{30AB}\u{30A4}\u{30EA}\u{30AB}\u{30E9}\u{30C3}\u{30C8}\
+\u{30AB}\u{30ED}\u{30EA}\u{30FC}\u{30AC}\u{30ED}\u{30F3}\u{30AC}\u{30F3}\u{30DE}\u{30AE}\u{30AC}\u{30AE}\u{30CB}\u{30FC}\u{30AD}\u{30E5}\u{30EA}\u{30FC}\u{30AE}\u{30EB}\u{30C0}\u{30FC}\u{30AD}\u{30ED}\u{30AD}\u{30ED}\u{30B0}\u{30E9}\u{30E0}\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}\u{30B0}\u{30E9}\u{30E0}\u{30B0}\u{30E9}\u{30E0}\u{30C8}\u{30F3}\u{30AF}\u{30EB}\u{30BC}\u{30A4}\u{30ED}\u{30AF}\u{30ED}\u{30FC}\u{30CD}\u{30B1}\u{30FC}\u{30B9}\u{30B3}\u{30EB}\u{30CA}\
+\u{30B3}\u{30FC}\u{30DD}\u{30B5}\u{30A4}\u{30AF}\u{30EB}\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}\u{30B7}\u{30EA}\u{30F3}\u{30B0}\u{30BB}\u{30F3}\u{30C1}\u{30BB}\u{30F3}\u{30C8}\u{30C0}\u{30FC}\u{30B9}\u{30C7}\u{30B7}\u{30C9}\u{30EB}\u{30C8}\u{30F3}\u{30CA}\u{30CE}\u{30CE}\u{30C3}\u{30C8}\u{30CF}\u{30A4}\u{30C4}\u{30D1}\u{30FC}\u{30BB}\u{30F3}\u{30C8}\u{30D1}\u{30FC}\u{30C4}\u{30D0}\u{30FC}\u{30EC}\u{30EB}\u{30D4}\u{30A2}\u{30B9}\u{30C8}\u{30EB}\u{30D4}\u{30AF}\u{30EB}\u{30D4}\u{30B3}\u{30D3}\u{30EB}\u{30D5}\
+\u{30A1}\u{30E9}\u{30C3}\u{30C9}\u{30D5}\u{30A3}\u{30FC}\u{30C8}\u{30D6}\u{30C3}\u{30B7}\u{30A7}\u{30EB}\u{30D5}\u{30E9}\u{30F3}\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}\u{30DA}\u{30BD}\u{30DA}\u{30CB}\u{30D2}\u{30D8}\u{30EB}\u{30C4}\u{30DA}\u{30F3}\u{30B9}\u{30DA}\u{30FC}\u{30B8}\u{30D9}\u{30FC}\u{30BF}\u{30DD}\u{30A4}\u{30F3}\u{30C8}\u{30DC}\u{30EB}\u{30C8}\u{30DB}\u{30F3}\u{30DD}\u{30F3}\u{30C9}\u{30DB}\u{30FC}\u{30EB}\u{30DB}\u{30FC}\u{30F3}\u{30DE}\u{30A4}\u{30AF}\u{30ED}\u{30DE}\u{30A4}\u{30EB}\u{30DE}\
+\u{30C3}\u{30CF}\u{30DE}\u{30EB}\u{30AF}\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}\u{30DF}\u{30AF}\u{30ED}\u{30F3}\u{30DF}\u{30EA}\u{30DF}\u{30EA}\u{30D0}\u{30FC}\u{30EB}\u{30E1}\u{30AC}\u{30E1}\u{30AC}\u{30C8}\u{30F3}\u{30E1}\u{30FC}\u{30C8}\u{30EB}\u{30E4}\u{30FC}\u{30C9}\u{30E4}\u{30FC}\u{30EB}\u{30E6}\u{30A2}\u{30F3}\u{30EA}\u{30C3}\u{30C8}\u{30EB}\u{30EA}\u{30E9}\u{30EB}\u{30D4}\u{30FC}\u{30EB}\u{30FC}\u{30D6}\u{30EB}\u{30EC}\u{30E0}\u{30EC}\u{30F3}\u{30C8}\u{30B2}\u{30F3}\u{30EF}\u{30C3}\u{30C8}0\
+\u{70B9}1\u{70B9}2\u{70B9}3\u{70B9}4\u{70B9}5\u{70B9}6\u{70B9}7\u{70B9}8\u{70B9}9\u{70B9}10\u{70B9}11\u{70B9}12\u{70B9}13\u{70B9}14\u{70B9}15\u{70B9}16\u{70B9}17\u{70B9}18\u{70B9}19\u{70B9}20\u{70B9}21\u{70B9}22\u{70B9}23\u{70B9}24\u{70B9}\
+hpadaaubarovpcdmdm2dm3iu\u{5E73}\u{6210}\u{662D}\u{548C}\u{5927}\u{6B63}\u{660E}\u{6CBB}\u{682A}\u{5F0F}\u{4F1A}\u{793E}pana\u{3BC}amakakbmbgbcalkcalpfnf\u{3BC}\
+f\u{3BC}gmgkghzkhzmhzghzthz\u{3BC}lmldlklfmnm\u{3BC}mmmcmkmmm2cm2m2km2mm3cm3m3km3m\
+\u{2215}sm\u{2215}s2kpampagparadrad\u{2215}srad\u{2215}s2psns\u{3BC}smspvnv\u{3BC}vmvkvpwnw\u{3BC}wmwkwk\u{3C9}m\u{3C9}bqc\
+ccdc\u{2215}kgdbgyhahpinkkktlmlnloglxmilmolphppmprsrsvwbv\u{2215}ma\u{2215}m1\u{65E5}2\u{65E5}3\u{65E5}4\u{65E5}5\
+\u{65E5}6\u{65E5}7\u{65E5}8\u{65E5}9\u{65E5}10\u{65E5}11\u{65E5}12\u{65E5}13\u{65E5}14\u{65E5}15\u{65E5}16\u{65E5}17\u{65E5}18\u{65E5}19\u{65E5}20\u{65E5}21\u{65E5}22\u{65E5}23\u{65E5}24\u{65E5}25\u{65E5}26\u{65E5}27\u{65E5}2\
+8\u{65E5}29\u{65E5}30\u{65E5}31\u{65E5}gal\u{A641}\u{A643}\u{A645}\u{A647}\u{A649}\u{A64D}\u{A64F}\u{A651}\u{A653}\u{A655}\u{A657}\u{A659}\u{A65B}\u{A65D}\u{A65F}\u{A661}\u{A663}\u{A665}\u{A667}\u{A669}\u{A66B}\u{A66D}\u{A681}\u{A683}\u{A685}\u{A687}\u{A689}\u{A68B}\u{A68D}\u{A68F}\u{A691}\u{A693}\u{A695}\u{A697}\u{A699}\u{A69B}\u{A723}\u{A725}\u{A727}\u{A729}\u{A72B}\u{A72D}\u{A72F}\u{A733}\u{A735}\u{A737}\u{A739}\u{A73B}\u{A73D}\u{A73F}\
+\u{A741}\u{A743}\u{A745}\u{A747}\u{A749}\u{A74B}\u{A74D}\u{A74F}\u{A751}\u{A753}\u{A755}\u{A757}\u{A759}\u{A75B}\u{A75D}\u{A75F}\u{A761}\u{A763}\u{A765}\u{A767}\u{A769}\u{A76B}\u{A76D}\u{A76F}\u{A77A}\u{A77C}\u{1D79}\u{A77F}\u{A781}\u{A783}\u{A785}\u{A787}\u{A78C}\u{A791}\u{A793}\u{A797}\u{A799}\u{A79B}\u{A79D}\u{A79F}\u{A7A1}\u{A7A3}\u{A7A5}\u{A7A7}\u{A7A9}\u{26C}\u{29E}\u{287}\u{AB53}\u{A7B5}\u{A7B7}\u{A7B9}\u{A7BB}\u{A7BD}\u{A7BF}\u{A7C1}\u{A7C3}\u{A794}\u{1D8E}\u{A7C8}\u{A7CA}\u{A7D1}\u{A7D7}\u{A7D9}\
+\u{A7F6}\u{AB37}\u{AB52}\u{28D}\u{13A0}\u{13A1}\u{13A2}\u{13A3}\u{13A4}\u{13A5}\u{13A6}\u{13A7}\u{13A8}\u{13A9}\u{13AA}\u{13AB}\u{13AC}\u{13AD}\u{13AE}\u{13AF}\u{13B0}\u{13B1}\u{13B2}\u{13B3}\u{13B4}\u{13B5}\u{13B6}\u{13B7}\u{13B8}\u{13B9}\u{13BA}\u{13BB}\u{13BC}\u{13BD}\u{13BE}\u{13BF}\u{13C0}\u{13C1}\u{13C2}\u{13C3}\u{13C4}\u{13C5}\u{13C6}\u{13C7}\u{13C8}\u{13C9}\u{13CA}\u{13CB}\u{13CC}\u{13CD}\u{13CE}\u{13CF}\u{13D0}\u{13D1}\u{13D2}\u{13D3}\u{13D4}\u{13D5}\u{13D6}\u{13D7}\u{13D8}\u{13D9}\u{13DA}\u{13DB}\
+\u{13DC}\u{13DD}\u{13DE}\u{13DF}\u{13E0}\u{13E1}\u{13E2}\u{13E3}\u{13E4}\u{13E5}\u{13E6}\u{13E7}\u{13E8}\u{13E9}\u{13EA}\u{13EB}\u{13EC}\u{13ED}\u{13EE}\u{13EF}\u{8C48}\u{66F4}\u{8CC8}\u{6ED1}\u{4E32}\u{53E5}\u{5951}\u{5587}\u{5948}\u{61F6}\u{7669}\u{7F85}\u{863F}\u{87BA}\u{88F8}\u{908F}\u{6A02}\u{6D1B}\u{70D9}\u{73DE}\u{843D}\u{916A}\u{99F1}\u{4E82}\u{5375}\u{6B04}\u{721B}\u{862D}\u{9E1E}\u{5D50}\u{6FEB}\u{85CD}\u{8964}\u{62C9}\u{81D8}\u{881F}\u{5ECA}\u{6717}\u{6D6A}\u{72FC}\u{90CE}\u{4F86}\u{51B7}\u{52DE}\
+\u{64C4}\u{6AD3}\u{7210}\u{76E7}\u{8606}\u{865C}\u{8DEF}\u{9732}\u{9B6F}\u{9DFA}\u{788C}\u{797F}\u{7DA0}\u{83C9}\u{9304}\u{8AD6}\u{58DF}\u{5F04}\u{7C60}\u{807E}\u{7262}\u{78CA}\u{8CC2}\u{96F7}\u{58D8}\u{5C62}\u{6A13}\u{6DDA}\u{6F0F}\u{7D2F}\u{7E37}\u{964B}\u{52D2}\u{808B}\u{51DC}\u{51CC}\u{7A1C}\u{7DBE}\u{83F1}\u{9675}\u{8B80}\u{62CF}\u{8AFE}\u{4E39}\u{5BE7}\u{6012}\u{7387}\u{7570}\u{5317}\u{78FB}\u{4FBF}\u{5FA9}\u{4E0D}\u{6CCC}\u{6578}\u{7D22}\u{53C3}\u{585E}\u{7701}\u{8449}\u{8AAA}\u{6BBA}\u{6C88}\u{62FE}\
+\u{82E5}\u{63A0}\u{7565}\u{4EAE}\u{5169}\u{51C9}\u{6881}\u{7CE7}\u{826F}\u{8AD2}\u{91CF}\u{52F5}\u{5442}\u{5EEC}\u{65C5}\u{6FFE}\u{792A}\u{95AD}\u{9A6A}\u{9E97}\u{9ECE}\u{66C6}\u{6B77}\u{8F62}\u{5E74}\u{6190}\u{6200}\u{649A}\u{6F23}\u{7149}\u{7489}\u{79CA}\u{7DF4}\u{806F}\u{8F26}\u{84EE}\u{9023}\u{934A}\u{5217}\u{52A3}\u{54BD}\u{70C8}\u{88C2}\u{5EC9}\u{5FF5}\u{637B}\u{6BAE}\u{7C3E}\u{7375}\u{4EE4}\u{56F9}\u{5DBA}\u{601C}\u{73B2}\u{7469}\u{7F9A}\u{8046}\u{9234}\u{96F6}\u{9748}\u{9818}\u{4F8B}\u{79AE}\u{91B4}\
+\u{96B8}\u{60E1}\u{4E86}\u{50DA}\u{5BEE}\u{5C3F}\u{6599}\u{71CE}\u{7642}\u{84FC}\u{907C}\u{6688}\u{962E}\u{5289}\u{677B}\u{67F3}\u{6D41}\u{6E9C}\u{7409}\u{7559}\u{786B}\u{7D10}\u{985E}\u{622E}\u{9678}\u{502B}\u{5D19}\u{6DEA}\u{8F2A}\u{5F8B}\u{6144}\u{6817}\u{9686}\u{5229}\u{540F}\u{5C65}\u{6613}\u{674E}\u{68A8}\u{6CE5}\u{7406}\u{75E2}\u{7F79}\u{88CF}\u{88E1}\u{96E2}\u{533F}\u{6EBA}\u{541D}\u{71D0}\u{7498}\u{85FA}\u{96A3}\u{9C57}\u{9E9F}\u{6797}\u{6DCB}\u{81E8}\u{7B20}\u{7C92}\u{72C0}\u{7099}\u{8B58}\u{4EC0}\
+\u{8336}\u{523A}\u{5207}\u{5EA6}\u{62D3}\u{7CD6}\u{5B85}\u{6D1E}\u{66B4}\u{8F3B}\u{964D}\u{5ED3}\u{5140}\u{55C0}\u{585A}\u{6674}\u{51DE}\u{732A}\u{76CA}\u{793C}\u{795E}\u{7965}\u{798F}\u{9756}\u{7CBE}\u{8612}\u{8AF8}\u{9038}\u{90FD}\u{98EF}\u{98FC}\u{9928}\u{9DB4}\u{90DE}\u{96B7}\u{4FAE}\u{50E7}\u{514D}\u{52C9}\u{52E4}\u{5351}\u{559D}\u{5606}\u{5668}\u{5840}\u{58A8}\u{5C64}\u{6094}\u{6168}\u{618E}\u{61F2}\u{654F}\u{65E2}\u{6691}\u{6885}\u{6D77}\u{6E1A}\u{6F22}\u{716E}\u{722B}\u{7422}\u{7891}\u{7949}\u{7948}\
+\u{7950}\u{7956}\u{798D}\u{798E}\u{7A40}\u{7A81}\u{7BC0}\u{7E09}\u{7E41}\u{7F72}\u{8005}\u{81ED}\u{8279}\u{8457}\u{8910}\u{8996}\u{8B01}\u{8B39}\u{8CD3}\u{8D08}\u{8FB6}\u{96E3}\u{97FF}\u{983B}\u{6075}\u{242EE}\u{8218}\u{4E26}\u{51B5}\u{5168}\u{4F80}\u{5145}\u{5180}\u{52C7}\u{52FA}\u{5555}\u{5599}\u{55E2}\u{58B3}\u{5944}\u{5954}\u{5A62}\u{5B28}\u{5ED2}\u{5ED9}\u{5F69}\u{5FAD}\u{60D8}\u{614E}\u{6108}\u{6160}\u{6234}\u{63C4}\u{641C}\u{6452}\u{6556}\u{671B}\u{6756}\u{6EDB}\u{6ECB}\u{701E}\u{77A7}\u{7235}\u{72AF}\
+\u{7471}\u{7506}\u{753B}\u{761D}\u{761F}\u{76DB}\u{76F4}\u{774A}\u{7740}\u{78CC}\u{7AB1}\u{7C7B}\u{7D5B}\u{7F3E}\u{8352}\u{83EF}\u{8779}\u{8941}\u{8986}\u{8ABF}\u{8ACB}\u{8AED}\u{8B8A}\u{8F38}\u{9072}\u{9199}\u{9276}\u{967C}\u{97DB}\u{980B}\u{9B12}\u{2284A}\u{22844}\u{233D5}\u{3B9D}\u{4018}\u{4039}\u{25249}\u{25CD0}\u{27ED3}\u{9F43}\u{9F8E}fffiflffifflst\u{574}\u{576}\u{574}\u{565}\u{574}\u{56B}\u{57E}\u{576}\
+\u{574}\u{56D}\u{5D9}\u{5B4}\u{5F2}\u{5B7}\u{5E2}\u{5D4}\u{5DB}\u{5DC}\u{5DD}\u{5E8}\u{5EA}\u{5E9}\u{5C1}\u{5E9}\u{5C2}\u{5E9}\u{5BC}\u{5C1}\u{5E9}\u{5BC}\u{5C2}\u{5D0}\u{5B7}\u{5D0}\u{5B8}\u{5D0}\u{5BC}\u{5D1}\u{5BC}\u{5D2}\u{5BC}\u{5D3}\u{5BC}\u{5D4}\u{5BC}\u{5D5}\u{5BC}\u{5D6}\u{5BC}\u{5D8}\u{5BC}\u{5D9}\u{5BC}\u{5DA}\u{5BC}\u{5DB}\u{5BC}\u{5DC}\u{5BC}\u{5DE}\u{5BC}\u{5E0}\u{5BC}\u{5E1}\u{5BC}\u{5E3}\u{5BC}\u{5E4}\u{5BC}\u{5E6}\u{5BC}\u{5E7}\
+\u{5BC}\u{5E8}\u{5BC}\u{5E9}\u{5BC}\u{5EA}\u{5BC}\u{5D5}\u{5B9}\u{5D1}\u{5BF}\u{5DB}\u{5BF}\u{5E4}\u{5BF}\u{5D0}\u{5DC}\u{671}\u{67B}\u{67E}\u{680}\u{67A}\u{67F}\u{679}\u{6A4}\u{6A6}\u{684}\u{683}\u{686}\u{687}\u{68D}\u{68C}\u{68E}\u{688}\u{698}\u{691}\u{6A9}\u{6AF}\u{6B3}\u{6B1}\u{6BA}\u{6BB}\u{6C0}\u{6C1}\u{6BE}\u{6D2}\u{6D3}\u{6AD}\u{6C7}\u{6C6}\u{6C8}\u{6CB}\u{6C5}\u{6C9}\u{6D0}\u{649}\u{626}\u{627}\u{626}\u{6D5}\u{626}\u{648}\u{626}\u{6C7}\
+\u{626}\u{6C6}\u{626}\u{6C8}\u{626}\u{6D0}\u{626}\u{649}\u{6CC}\u{626}\u{62C}\u{626}\u{62D}\u{626}\u{645}\u{626}\u{64A}\u{628}\u{62C}\u{628}\u{62D}\u{628}\u{62E}\u{628}\u{645}\u{628}\u{649}\u{628}\u{64A}\u{62A}\u{62C}\u{62A}\u{62D}\u{62A}\u{62E}\u{62A}\u{645}\u{62A}\u{649}\u{62A}\u{64A}\u{62B}\u{62C}\u{62B}\u{645}\u{62B}\u{649}\u{62B}\u{64A}\u{62C}\u{62D}\u{62C}\u{645}\u{62D}\u{62C}\u{62D}\u{645}\u{62E}\u{62C}\u{62E}\u{62D}\u{62E}\u{645}\u{633}\
+\u{62C}\u{633}\u{62D}\u{633}\u{62E}\u{633}\u{645}\u{635}\u{62D}\u{635}\u{645}\u{636}\u{62C}\u{636}\u{62D}\u{636}\u{62E}\u{636}\u{645}\u{637}\u{62D}\u{637}\u{645}\u{638}\u{645}\u{639}\u{62C}\u{639}\u{645}\u{63A}\u{62C}\u{63A}\u{645}\u{641}\u{62C}\u{641}\u{62D}\u{641}\u{62E}\u{641}\u{645}\u{641}\u{649}\u{641}\u{64A}\u{642}\u{62D}\u{642}\u{645}\u{642}\u{649}\u{642}\u{64A}\u{643}\u{627}\u{643}\u{62C}\u{643}\u{62D}\u{643}\u{62E}\u{643}\u{644}\u{643}\
+\u{645}\u{643}\u{649}\u{643}\u{64A}\u{644}\u{62C}\u{644}\u{62D}\u{644}\u{62E}\u{644}\u{645}\u{644}\u{649}\u{644}\u{64A}\u{645}\u{62C}\u{645}\u{62D}\u{645}\u{62E}\u{645}\u{645}\u{645}\u{649}\u{645}\u{64A}\u{646}\u{62C}\u{646}\u{62D}\u{646}\u{62E}\u{646}\u{645}\u{646}\u{649}\u{646}\u{64A}\u{647}\u{62C}\u{647}\u{645}\u{647}\u{649}\u{647}\u{64A}\u{64A}\u{62C}\u{64A}\u{62D}\u{64A}\u{62E}\u{64A}\u{645}\u{64A}\u{649}\u{64A}\u{64A}\u{630}\u{670}\u{631}\
+\u{670}\u{649}\u{670} \u{64C}\u{651} \u{64D}\u{651} \u{64E}\u{651} \u{64F}\u{651} \u{650}\u{651} \u{651}\u{670}\u{626}\u{631}\u{626}\u{632}\u{626}\u{646}\u{628}\u{631}\u{628}\u{632}\u{628}\u{646}\u{62A}\u{631}\u{62A}\u{632}\u{62A}\u{646}\u{62B}\u{631}\u{62B}\u{632}\u{62B}\u{646}\u{645}\u{627}\u{646}\u{631}\u{646}\u{632}\u{646}\u{646}\u{64A}\u{631}\u{64A}\u{632}\u{64A}\u{646}\u{626}\u{62E}\u{626}\u{647}\u{628}\
+\u{647}\u{62A}\u{647}\u{635}\u{62E}\u{644}\u{647}\u{646}\u{647}\u{647}\u{670}\u{64A}\u{647}\u{62B}\u{647}\u{633}\u{647}\u{634}\u{645}\u{634}\u{647}\u{640}\u{64E}\u{651}\u{640}\u{64F}\u{651}\u{640}\u{650}\u{651}\u{637}\u{649}\u{637}\u{64A}\u{639}\u{649}\u{639}\u{64A}\u{63A}\u{649}\u{63A}\u{64A}\u{633}\u{649}\u{633}\u{64A}\u{634}\u{649}\u{634}\u{64A}\u{62D}\u{649}\u{62D}\u{64A}\u{62C}\u{649}\u{62C}\u{64A}\u{62E}\u{649}\u{62E}\u{64A}\u{635}\u{649}\
+\u{635}\u{64A}\u{636}\u{649}\u{636}\u{64A}\u{634}\u{62C}\u{634}\u{62D}\u{634}\u{62E}\u{634}\u{631}\u{633}\u{631}\u{635}\u{631}\u{636}\u{631}\u{627}\u{64B}\u{62A}\u{62C}\u{645}\u{62A}\u{62D}\u{62C}\u{62A}\u{62D}\u{645}\u{62A}\u{62E}\u{645}\u{62A}\u{645}\u{62C}\u{62A}\u{645}\u{62D}\u{62A}\u{645}\u{62E}\u{62C}\u{645}\u{62D}\u{62D}\u{645}\u{64A}\u{62D}\u{645}\u{649}\u{633}\u{62D}\u{62C}\u{633}\u{62C}\u{62D}\u{633}\u{62C}\u{649}\u{633}\u{645}\u{62D}\
+\u{633}\u{645}\u{62C}\u{633}\u{645}\u{645}\u{635}\u{62D}\u{62D}\u{635}\u{645}\u{645}\u{634}\u{62D}\u{645}\u{634}\u{62C}\u{64A}\u{634}\u{645}\u{62E}\u{634}\u{645}\u{645}\u{636}\u{62D}\u{649}\u{636}\u{62E}\u{645}\u{637}\u{645}\u{62D}\u{637}\u{645}\u{645}\u{637}\u{645}\u{64A}\u{639}\u{62C}\u{645}\u{639}\u{645}\u{645}\u{639}\u{645}\u{649}\u{63A}\u{645}\u{645}\u{63A}\u{645}\u{64A}\u{63A}\u{645}\u{649}\u{641}\u{62E}\u{645}\u{642}\u{645}\u{62D}\u{642}\
+\u{645}\u{645}\u{644}\u{62D}\u{645}\u{644}\u{62D}\u{64A}\u{644}\u{62D}\u{649}\u{644}\u{62C}\u{62C}\u{644}\u{62E}\u{645}\u{644}\u{645}\u{62D}\u{645}\u{62D}\u{62C}\u{645}\u{62D}\u{645}\u{645}\u{62D}\u{64A}\u{645}\u{62C}\u{62D}\u{645}\u{62C}\u{645}\u{645}\u{62E}\u{62C}\u{645}\u{62E}\u{645}\u{645}\u{62C}\u{62E}\u{647}\u{645}\u{62C}\u{647}\u{645}\u{645}\u{646}\u{62D}\u{645}\u{646}\u{62D}\u{649}\u{646}\u{62C}\u{645}\u{646}\u{62C}\u{649}\u{646}\u{645}\
+\u{64A}\u{646}\u{645}\u{649}\u{64A}\u{645}\u{645}\u{628}\u{62E}\u{64A}\u{62A}\u{62C}\u{64A}\u{62A}\u{62C}\u{649}\u{62A}\u{62E}\u{64A}\u{62A}\u{62E}\u{649}\u{62A}\u{645}\u{64A}\u{62A}\u{645}\u{649}\u{62C}\u{645}\u{64A}\u{62C}\u{62D}\u{649}\u{62C}\u{645}\u{649}\u{633}\u{62E}\u{649}\u{635}\u{62D}\u{64A}\u{634}\u{62D}\u{64A}\u{636}\u{62D}\u{64A}\u{644}\u{62C}\u{64A}\u{644}\u{645}\u{64A}\u{64A}\u{62D}\u{64A}\u{64A}\u{62C}\u{64A}\u{64A}\u{645}\u{64A}\
+\u{645}\u{645}\u{64A}\u{642}\u{645}\u{64A}\u{646}\u{62D}\u{64A}\u{639}\u{645}\u{64A}\u{643}\u{645}\u{64A}\u{646}\u{62C}\u{62D}\u{645}\u{62E}\u{64A}\u{644}\u{62C}\u{645}\u{643}\u{645}\u{645}\u{62C}\u{62D}\u{64A}\u{62D}\u{62C}\u{64A}\u{645}\u{62C}\u{64A}\u{641}\u{645}\u{64A}\u{628}\u{62D}\u{64A}\u{633}\u{62E}\u{64A}\u{646}\u{62C}\u{64A}\u{635}\u{644}\u{6D2}\u{642}\u{644}\u{6D2}\u{627}\u{644}\u{644}\u{647}\u{627}\u{643}\u{628}\u{631}\u{645}\u{62D}\
+\u{645}\u{62F}\u{635}\u{644}\u{639}\u{645}\u{631}\u{633}\u{648}\u{644}\u{639}\u{644}\u{64A}\u{647}\u{648}\u{633}\u{644}\u{645}\u{635}\u{644}\u{649}\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64A}\u{647} \u{648}\u{633}\u{644}\u{645}\u{62C}\u{644} \u{62C}\u{644}\u{627}\u{644}\u{647}\u{631}\u{6CC}\u{627}\u{644},\u{3001}:!?\u{3016}\u{3017}\u{2014}\u{2013}_{}\u{3014}\
+\u{3015}\u{3010}\u{3011}\u{300A}\u{300B}\u{300C}\u{300D}\u{300E}\u{300F}[]#&*-<>\u{5C}$%@ \u{64B}\u{640}\u{64B} \u{64C} \u{64D} \u{64E}\u{640}\u{64E} \u{64F}\u{640}\u{64F} \u{650}\u{640}\u{650} \u{651}\u{640}\u{651} \u{652}\u{640}\u{652}\u{621}\u{622}\u{623}\u{624}\u{625}\u{626}\u{627}\u{628}\u{629}\u{62A}\u{62B}\u{62C}\u{62D}\u{62E}\u{62F}\
+\u{630}\u{631}\u{632}\u{633}\u{634}\u{635}\u{636}\u{637}\u{638}\u{639}\u{63A}\u{641}\u{642}\u{643}\u{644}\u{645}\u{646}\u{647}\u{648}\u{64A}\u{644}\u{622}\u{644}\u{623}\u{644}\u{625}\u{644}\u{627}\u{22}'/^|~\u{2985}\u{2986}\u{30FB}\u{30A1}\u{30A3}\u{30A5}\u{30A7}\u{30A9}\u{30E3}\u{30E5}\u{30E7}\u{30C3}\u{30FC}\u{30F3}\u{3099}\u{309A}\u{A2}\u{A3}\u{AC}\u{A6}\u{A5}\u{20A9}\u{2502}\u{2190}\u{2191}\u{2192}\u{2193}\u{25A0}\u{25CB}\u{10428}\
+\u{10429}\u{1042A}\u{1042B}\u{1042C}\u{1042D}\u{1042E}\u{1042F}\u{10430}\u{10431}\u{10432}\u{10433}\u{10434}\u{10435}\u{10436}\u{10437}\u{10438}\u{10439}\u{1043A}\u{1043B}\u{1043C}\u{1043D}\u{1043E}\u{1043F}\u{10440}\u{10441}\u{10442}\u{10443}\u{10444}\u{10445}\u{10446}\u{10447}\u{10448}\u{10449}\u{1044A}\u{1044B}\u{1044C}\u{1044D}\u{1044E}\u{1044F}\u{104D8}\u{104D9}\u{104DA}\u{104DB}\u{104DC}\u{104DD}\u{104DE}\u{104DF}\u{104E0}\u{104E1}\u{104E2}\u{104E3}\u{104E4}\u{104E5}\u{104E6}\u{104E7}\u{104E8}\u{104E9}\u{104EA}\u{104EB}\u{104EC}\u{104ED}\u{104EE}\u{104EF}\u{104F0}\
+\u{104F1}\u{104F2}\u{104F3}\u{104F4}\u{104F5}\u{104F6}\u{104F7}\u{104F8}\u{104F9}\u{104FA}\u{104FB}\u{10597}\u{10598}\u{10599}\u{1059A}\u{1059B}\u{1059C}\u{1059D}\u{1059E}\u{1059F}\u{105A0}\u{105A1}\u{105A3}\u{105A4}\u{105A5}\u{105A6}\u{105A7}\u{105A8}\u{105A9}\u{105AA}\u{105AB}\u{105AC}\u{105AD}\u{105AE}\u{105AF}\u{105B0}\u{105B1}\u{105B3}\u{105B4}\u{105B5}\u{105B6}\u{105B7}\u{105B8}\u{105B9}\u{105BB}\u{105BC}\u{2D0}\u{2D1}\u{299}\u{2A3}\u{AB66}\u{2A5}\u{2A4}\u{1D91}\u{258}\u{25E}\u{2A9}\u{264}\u{262}\u{29B}\u{29C}\u{267}\u{284}\u{2AA}\
+\u{2AB}\u{1DF04}\u{A78E}\u{26E}\u{1DF05}\u{28E}\u{1DF06}\u{276}\u{277}\u{27A}\u{1DF08}\u{27E}\u{2A8}\u{2A6}\u{AB67}\u{2A7}\u{2C71}\u{28F}\u{2A1}\u{2A2}\u{298}\u{1C0}\u{1C1}\u{1C2}\u{1DF0A}\u{1DF1E}\u{10CC0}\u{10CC1}\u{10CC2}\u{10CC3}\u{10CC4}\u{10CC5}\u{10CC6}\u{10CC7}\u{10CC8}\u{10CC9}\u{10CCA}\u{10CCB}\u{10CCC}\u{10CCD}\u{10CCE}\u{10CCF}\u{10CD0}\u{10CD1}\u{10CD2}\u{10CD3}\u{10CD4}\u{10CD5}\u{10CD6}\u{10CD7}\u{10CD8}\u{10CD9}\u{10CDA}\u{10CDB}\u{10CDC}\u{10CDD}\u{10CDE}\u{10CDF}\u{10CE0}\u{10CE1}\u{10CE2}\u{10CE3}\u{10CE4}\u{10CE5}\
+\u{10CE6}\u{10CE7}\u{10CE8}\u{10CE9}\u{10CEA}\u{10CEB}\u{10CEC}\u{10CED}\u{10CEE}\u{10CEF}\u{10CF0}\u{10CF1}\u{10CF2}\u{118C0}\u{118C1}\u{118C2}\u{118C3}\u{118C4}\u{118C5}\u{118C6}\u{118C7}\u{118C8}\u{118C9}\u{118CA}\u{118CB}\u{118CC}\u{118CD}\u{118CE}\u{118CF}\u{118D0}\u{118D1}\u{118D2}\u{118D3}\u{118D4}\u{118D5}\u{118D6}\u{118D7}\u{118D8}\u{118D9}\u{118DA}\u{118DB}\u{118DC}\u{118DD}\u{118DE}\u{118DF}\u{16E60}\u{16E61}\u{16E62}\u{16E63}\u{16E64}\u{16E65}\u{16E66}\u{16E67}\u{16E68}\u{16E69}\u{16E6A}\u{16E6B}\u{16E6C}\u{16E6D}\u{16E6E}\u{16E6F}\u{16E70}\u{16E71}\u{16E72}\
+\u{16E73}\u{16E74}\u{16E75}\u{16E76}\u{16E77}\u{16E78}\u{16E79}\u{16E7A}\u{16E7B}\u{16E7C}\u{16E7D}\u{16E7E}\u{16E7F}\u{1D157}\u{1D165}\u{1D158}\u{1D165}\u{1D158}\u{1D165}\u{1D16E}\u{1D158}\u{1D165}\u{1D16F}\u{1D158}\u{1D165}\u{1D170}\u{1D158}\u{1D165}\u{1D171}\u{1D158}\u{1D165}\u{1D172}\u{1D1B9}\u{1D165}\u{1D1BA}\u{1D165}\u{1D1B9}\u{1D165}\u{1D16E}\u{1D1BA}\u{1D165}\u{1D16E}\u{1D1B9}\u{1D165}\u{1D16F}\u{1D1BA}\u{1D165}\u{1D16F}\u{131}\u{237}\u{2207}\u{2202}\u{4CF}\u{1E922}\u{1E923}\u{1E924}\u{1E925}\u{1E926}\u{1E927}\u{1E928}\u{1E929}\u{1E92A}\u{1E92B}\u{1E92C}\
+\u{1E92D}\u{1E92E}\u{1E92F}\u{1E930}\u{1E931}\u{1E932}\u{1E933}\u{1E934}\u{1E935}\u{1E936}\u{1E937}\u{1E938}\u{1E939}\u{1E93A}\u{1E93B}\u{1E93C}\u{1E93D}\u{1E93E}\u{1E93F}\u{1E940}\u{1E941}\u{1E942}\u{1E943}\u{66E}\u{6A1}\u{66F}0,1,2,3,4,5,6,7,8,9,\u{3014}s\u{3015}wzhvsdppvwcmcmd\
+mrdj\u{307B}\u{304B}\u{30B3}\u{30B3}\u{5B57}\u{53CC}\u{30C7}\u{591A}\u{89E3}\u{4EA4}\u{6620}\u{7121}\u{524D}\u{5F8C}\u{518D}\u{65B0}\u{521D}\u{7D42}\u{8CA9}\u{58F0}\u{5439}\u{6F14}\u{6295}\u{6355}\u{904A}\u{6307}\u{6253}\u{7981}\u{7A7A}\u{5408}\u{6E80}\u{7533}\u{5272}\u{55B6}\u{914D}\u{3014}\u{672C}\u{3015}\u{3014}\u{4E09}\u{3015}\u{3014}\u{4E8C}\u{3015}\u{3014}\u{5B89}\u{3015}\u{3014}\u{70B9}\u{3015}\u{3014}\u{6253}\u{3015}\u{3014}\u{76D7}\u{3015}\u{3014}\u{52DD}\u{3015}\u{3014}\
+\u{6557}\u{3015}\u{5F97}\u{53EF}\u{4E3D}\u{4E38}\u{4E41}\u{20122}\u{4F60}\u{4FBB}\u{5002}\u{507A}\u{5099}\u{50CF}\u{349E}\u{2063A}\u{5154}\u{5164}\u{5177}\u{2051C}\u{34B9}\u{5167}\u{2054B}\u{5197}\u{51A4}\u{4ECC}\u{51AC}\u{291DF}\u{5203}\u{34DF}\u{523B}\u{5246}\u{5277}\u{3515}\u{5305}\u{5306}\u{5349}\u{535A}\u{5373}\u{537D}\u{537F}\u{20A2C}\u{7070}\u{53CA}\u{53DF}\u{20B63}\u{53EB}\u{53F1}\u{5406}\u{549E}\u{5438}\u{5448}\u{5468}\u{54A2}\u{54F6}\u{5510}\u{5553}\u{5563}\u{5584}\u{55AB}\u{55B3}\u{55C2}\u{5716}\u{5717}\
+\u{5651}\u{5674}\u{58EE}\u{57CE}\u{57F4}\u{580D}\u{578B}\u{5832}\u{5831}\u{58AC}\u{214E4}\u{58F2}\u{58F7}\u{5906}\u{5922}\u{5962}\u{216A8}\u{216EA}\u{59EC}\u{5A1B}\u{5A27}\u{59D8}\u{5A66}\u{36EE}\u{5B08}\u{5B3E}\u{219C8}\u{5BC3}\u{5BD8}\u{5BF3}\u{21B18}\u{5BFF}\u{5C06}\u{3781}\u{5C60}\u{5CC0}\u{5C8D}\u{21DE4}\u{5D43}\u{21DE6}\u{5D6E}\u{5D6B}\u{5D7C}\u{5DE1}\u{5DE2}\u{382F}\u{5DFD}\u{5E28}\u{5E3D}\u{5E69}\u{3862}\u{22183}\u{387C}\u{5EB0}\u{5EB3}\u{5EB6}\u{2A392}\u{22331}\u{8201}\u{5F22}\u{38C7}\u{232B8}\u{261DA}\u{5F62}\
+\u{5F6B}\u{38E3}\u{5F9A}\u{5FCD}\u{5FD7}\u{5FF9}\u{6081}\u{393A}\u{391C}\u{226D4}\u{60C7}\u{6148}\u{614C}\u{617A}\u{61B2}\u{61A4}\u{61AF}\u{61DE}\u{6210}\u{621B}\u{625D}\u{62B1}\u{62D4}\u{6350}\u{22B0C}\u{633D}\u{62FC}\u{6368}\u{6383}\u{63E4}\u{22BF1}\u{6422}\u{63C5}\u{63A9}\u{3A2E}\u{6469}\u{647E}\u{649D}\u{6477}\u{3A6C}\u{656C}\u{2300A}\u{65E3}\u{66F8}\u{6649}\u{3B19}\u{3B08}\u{3AE4}\u{5192}\u{5195}\u{6700}\u{669C}\u{80AD}\u{43D9}\u{6721}\u{675E}\u{6753}\u{233C3}\u{3B49}\u{67FA}\u{6785}\u{6852}\u{2346D}\u{688E}\
+\u{681F}\u{6914}\u{6942}\u{69A3}\u{69EA}\u{6AA8}\u{236A3}\u{6ADB}\u{3C18}\u{6B21}\u{238A7}\u{6B54}\u{3C4E}\u{6B72}\u{6B9F}\u{6BBB}\u{23A8D}\u{21D0B}\u{23AFA}\u{6C4E}\u{23CBC}\u{6CBF}\u{6CCD}\u{6C67}\u{6D16}\u{6D3E}\u{6D69}\u{6D78}\u{6D85}\u{23D1E}\u{6D34}\u{6E2F}\u{6E6E}\u{3D33}\u{6EC7}\u{23ED1}\u{6DF9}\u{6F6E}\u{23F5E}\u{23F8E}\u{6FC6}\u{7039}\u{701B}\u{3D96}\u{704A}\u{707D}\u{7077}\u{70AD}\u{20525}\u{7145}\u{24263}\u{719C}\u{7228}\u{7250}\u{24608}\u{7280}\u{7295}\u{24735}\u{24814}\u{737A}\u{738B}\u{3EAC}\u{73A5}\u{3EB8}\
+\u{7447}\u{745C}\u{7485}\u{74CA}\u{3F1B}\u{7524}\u{24C36}\u{753E}\u{24C92}\u{2219F}\u{7610}\u{24FA1}\u{24FB8}\u{25044}\u{3FFC}\u{4008}\u{250F3}\u{250F2}\u{25119}\u{25133}\u{771E}\u{771F}\u{778B}\u{4046}\u{4096}\u{2541D}\u{784E}\u{40E3}\u{25626}\u{2569A}\u{256C5}\u{79EB}\u{412F}\u{7A4A}\u{7A4F}\u{2597C}\u{25AA7}\u{4202}\u{25BAB}\u{7BC6}\u{7BC9}\u{4227}\u{25C80}\u{7CD2}\u{42A0}\u{7CE8}\u{7CE3}\u{7D00}\u{25F86}\u{7D63}\u{4301}\u{7DC7}\u{7E02}\u{7E45}\u{4334}\u{26228}\u{26247}\u{4359}\u{262D9}\u{7F7A}\u{2633E}\u{7F95}\u{7FFA}\u{264DA}\
+\u{26523}\u{8060}\u{265A8}\u{8070}\u{2335F}\u{43D5}\u{80B2}\u{8103}\u{440B}\u{813E}\u{5AB5}\u{267A7}\u{267B5}\u{23393}\u{2339C}\u{8204}\u{8F9E}\u{446B}\u{8291}\u{828B}\u{829D}\u{52B3}\u{82B1}\u{82B3}\u{82BD}\u{82E6}\u{26B3C}\u{831D}\u{8363}\u{83AD}\u{8323}\u{83BD}\u{83E7}\u{8353}\u{83CA}\u{83CC}\u{83DC}\u{26C36}\u{26D6B}\u{26CD5}\u{452B}\u{84F1}\u{84F3}\u{8516}\u{273CA}\u{8564}\u{26F2C}\u{455D}\u{4561}\u{26FB1}\u{270D2}\u{456B}\u{8650}\u{8667}\u{8669}\u{86A9}\u{8688}\u{870E}\u{86E2}\u{8728}\u{876B}\u{8786}\u{87E1}\u{8801}\
+\u{45F9}\u{8860}\u{27667}\u{88D7}\u{88DE}\u{4635}\u{88FA}\u{34BB}\u{278AE}\u{27966}\u{46BE}\u{46C7}\u{8AA0}\u{27CA8}\u{8CAB}\u{8CC1}\u{8D1B}\u{8D77}\u{27F2F}\u{20804}\u{8DCB}\u{8DBC}\u{8DF0}\u{208DE}\u{8ED4}\u{285D2}\u{285ED}\u{9094}\u{90F1}\u{9111}\u{2872E}\u{911B}\u{9238}\u{92D7}\u{92D8}\u{927C}\u{93F9}\u{9415}\u{28BFA}\u{958B}\u{4995}\u{95B7}\u{28D77}\u{49E6}\u{96C3}\u{5DB2}\u{9723}\u{29145}\u{2921A}\u{4A6E}\u{4A76}\u{97E0}\u{2940A}\u{4AB2}\u{29496}\u{9829}\u{295B6}\u{98E2}\u{4B33}\u{9929}\u{99A7}\u{99C2}\u{99FE}\u{4BCE}\
+\u{29B30}\u{9C40}\u{9CFD}\u{4CCE}\u{4CED}\u{9D67}\u{2A0CE}\u{4CF8}\u{2A105}\u{2A20E}\u{2A291}\u{4D56}\u{9EFE}\u{9F05}\u{9F0F}\u{9F16}\u{2A600}\
+";
+
+/// Non-zero canonical combining classes: (first, last, class).
+pub(super) static COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216), (0x31C, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230), (0x34D, 0x34E, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36F, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230), (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18), (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65B, 230), (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230), (0x6ED, 0x6ED, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230), (0x73E, 0x73E, 220), (0x73F, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230), (0x7FD, 0x7FD, 220), (0x816, 0x819, 230),
+    (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x898, 0x898, 230), (0x899, 0x89B, 220), (0x89C, 0x89F, 230), (0x8CA, 0x8CE, 230),
+    (0x8CF, 0x8D3, 220), (0x8D4, 0x8E1, 230), (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220), (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7), (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7),
+    (0xACD, 0xACD, 9), (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7), (0xC4D, 0xC4D, 9), (0xC55, 0xC55, 84), (0xC56, 0xC56, 91),
+    (0xCBC, 0xCBC, 7), (0xCCD, 0xCCD, 9), (0xD3B, 0xD3C, 9), (0xD4D, 0xD4D, 9),
+    (0xDCA, 0xDCA, 9), (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9), (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118), (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122), (0xF18, 0xF19, 220),
+    (0xF35, 0xF35, 220), (0xF37, 0xF37, 220), (0xF39, 0xF39, 216), (0xF71, 0xF71, 129),
+    (0xF72, 0xF72, 130), (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130), (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230), (0xF84, 0xF84, 9), (0xF86, 0xF87, 230), (0xFC6, 0xFC6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ACE, 230), (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220), (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230), (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228), (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8), (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9), (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230), (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220), (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230), (0x10EAB, 0x10EAC, 230),
+    (0x10EFD, 0x10EFF, 220), (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230), (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220),
+    (0x10F84, 0x10F84, 230), (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9),
+    (0x1107F, 0x1107F, 9), (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230),
+    (0x11133, 0x11134, 9), (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7),
+    (0x11235, 0x11235, 9), (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9),
+    (0x1133B, 0x1133C, 7), (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230),
+    (0x11442, 0x11442, 9), (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7), (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9), (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7), (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9), (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7), (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x11F41, 0x11F42, 9),
+    (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230), (0x16FF0, 0x16FF1, 6), (0x1BC9E, 0x1BC9E, 1),
+    (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1), (0x1D16D, 0x1D16D, 226), (0x1D16E, 0x1D172, 216),
+    (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230), (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230),
+    (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230), (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230), (0x1E08F, 0x1E08F, 230), (0x1E130, 0x1E136, 230),
+    (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E4EC, 0x1E4ED, 232), (0x1E4EE, 0x1E4EE, 220),
+    (0x1E4EF, 0x1E4EF, 230), (0x1E8D0, 0x1E8D6, 220), (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+/// Full canonical decompositions: (code point, offset, length) into `DECOMPOSITION_DATA`.
+pub(super) static DECOMPOSITION: &[(u32, u16, u8)] = &[
+    (0xC0, 0, 2), (0xC1, 2, 2), (0xC2, 4, 2), (0xC3, 6, 2),
+    (0xC4, 8, 2), (0xC5, 10, 2), (0xC7, 12, 2), (0xC8, 14, 2),
+    (0xC9, 16, 2), (0xCA, 18, 2), (0xCB, 20, 2), (0xCC, 22, 2),
+    (0xCD, 24, 2), (0xCE, 26, 2), (0xCF, 28, 2), (0xD1, 30, 2),
+    (0xD2, 32, 2), (0xD3, 34, 2), (0xD4, 36, 2), (0xD5, 38, 2),
+    (0xD6, 40, 2), (0xD9, 42, 2), (0xDA, 44, 2), (0xDB, 46, 2),
+    (0xDC, 48, 2), (0xDD, 50, 2), (0xE0, 52, 2), (0xE1, 54, 2),
+    (0xE2, 56, 2), (0xE3, 58, 2), (0xE4, 60, 2), (0xE5, 62, 2),
+    (0xE7, 64, 2), (0xE8, 66, 2), (0xE9, 68, 2), (0xEA, 70, 2),
+    (0xEB, 72, 2), (0xEC, 74, 2), (0xED, 76, 2), (0xEE, 78, 2),
+    (0xEF, 80, 2), (0xF1, 82, 2), (0xF2, 84, 2), (0xF3, 86, 2),
+    (0xF4, 88, 2), (0xF5, 90, 2), (0xF6, 92, 2), (0xF9, 94, 2),
+    (0xFA, 96, 2), (0xFB, 98, 2), (0xFC, 100, 2), (0xFD, 102, 2),
+    (0xFF, 104, 2), (0x100, 106, 2), (0x101, 108, 2), (0x102, 110, 2),
+    (0x103, 112, 2), (0x104, 114, 2), (0x105, 116, 2), (0x106, 118, 2),
+    (0x107, 120, 2), (0x108, 122, 2), (0x109, 124, 2), (0x10A, 126, 2),
+    (0x10B, 128, 2), (0x10C, 130, 2), (0x10D, 132, 2), (0x10E, 134, 2),
+    (0x10F, 136, 2), (0x112, 138, 2), (0x113, 140, 2), (0x114, 142, 2),
+    (0x115, 144, 2), (0x116, 146, 2), (0x117, 148, 2), (0x118, 150, 2),
+    (0x119, 152, 2), (0x11A, 154, 2), (0x11B, 156, 2), (0x11C, 158, 2),
+    (0x11D, 160, 2), (0x11E, 162, 2), (0x11F, 164, 2), (0x120, 166, 2),
+    (0x121, 168, 2), (0x122, 170, 2), (0x123, 172, 2), (0x124, 174, 2),
+    (0x125, 176, 2), (0x128, 178, 2), (0x129, 180, 2), (0x12A, 182, 2),
+    (0x12B, 184, 2), (0x12C, 186, 2), (0x12D, 188, 2), (0x12E, 190, 2),
+    (0x12F, 192, 2), (0x130, 194, 2), (0x134, 196, 2), (0x135, 198, 2),
+    (0x136, 200, 2), (0x137, 202, 2), (0x139, 204, 2), (0x13A, 206, 2),
+    (0x13B, 208, 2), (0x13C, 210, 2), (0x13D, 212, 2), (0x13E, 214, 2),
+    (0x143, 216, 2), (0x144, 218, 2), (0x145, 220, 2), (0x146, 222, 2),
+    (0x147, 224, 2), (0x148, 226, 2), (0x14C, 228, 2), (0x14D, 230, 2),
+    (0x14E, 232, 2), (0x14F, 234, 2), (0x150, 236, 2), (0x151, 238, 2),
+    (0x154, 240, 2), (0x155, 242, 2), (0x156, 244, 2), (0x157, 246, 2),
+    (0x158, 248, 2), (0x159, 250, 2), (0x15A, 252, 2), (0x15B, 254, 2),
+    (0x15C, 256, 2), (0x15D, 258, 2), (0x15E, 260, 2), (0x15F, 262, 2),
+    (0x160, 264, 2), (0x161, 266, 2), (0x162, 268, 2), (0x163, 270, 2),
+    (0x164, 272, 2), (0x165, 274, 2), (0x168, 276, 2), (0x169, 278, 2),
+    (0x16A, 280, 2), (0x16B, 282, 2), (0x16C, 284, 2), (0x16D, 286, 2),
+    (0x16E, 288, 2), (0x16F, 290, 2), (0x170, 292, 2), (0x171, 294, 2),
+    (0x172, 296, 2), (0x173, 298, 2), (0x174, 300, 2), (0x175, 302, 2),
+    (0x176, 304, 2), (0x177, 306, 2), (0x178, 308, 2), (0x179, 310, 2),
+    (0x17A, 312, 2), (0x17B, 314, 2), (0x17C, 316, 2), (0x17D, 318, 2),
+    (0x17E, 320, 2), (0x1A0, 322, 2), (0x1A1, 324, 2), (0x1AF, 326, 2),
+    (0x1B0, 328, 2), (0x1CD, 330, 2), (0x1CE, 332, 2), (0x1CF, 334, 2),
+    (0x1D0, 336, 2), (0x1D1, 338, 2), (0x1D2, 340, 2), (0x1D3, 342, 2),
+    (0x1D4, 344, 2), (0x1D5, 346, 3), (0x1D6, 349, 3), (0x1D7, 352, 3),
+    (0x1D8, 355, 3), (0x1D9, 358, 3), (0x1DA, 361, 3), (0x1DB, 364, 3),
+    (0x1DC, 367, 3), (0x1DE, 370, 3), (0x1DF, 373, 3), (0x1E0, 376, 3),
+    (0x1E1, 379, 3), (0x1E2, 382, 2), (0x1E3, 384, 2), (0x1E6, 386, 2),
+    (0x1E7, 388, 2), (0x1E8, 390, 2), (0x1E9, 392, 2), (0x1EA, 394, 2),
+    (0x1EB, 396, 2), (0x1EC, 398, 3), (0x1ED, 401, 3), (0x1EE, 404, 2),
+    (0x1EF, 406, 2), (0x1F0, 408, 2), (0x1F4, 410, 2), (0x1F5, 412, 2),
+    (0x1F8, 414, 2), (0x1F9, 416, 2), (0x1FA, 418, 3), (0x1FB, 421, 3),
+    (0x1FC, 424, 2), (0x1FD, 426, 2), (0x1FE, 428, 2), (0x1FF, 430, 2),
+    (0x200, 432, 2), (0x201, 434, 2), (0x202, 436, 2), (0x203, 438, 2),
+    (0x204, 440, 2), (0x205, 442, 2), (0x206, 444, 2), (0x207, 446, 2),
+    (0x208, 448, 2), (0x209, 450, 2), (0x20A, 452, 2), (0x20B, 454, 2),
+    (0x20C, 456, 2), (0x20D, 458, 2), (0x20E, 460, 2), (0x20F, 462, 2),
+    (0x210, 464, 2), (0x211, 466, 2), (0x212, 468, 2), (0x213, 470, 2),
+    (0x214, 472, 2), (0x215, 474, 2), (0x216, 476, 2), (0x217, 478, 2),
+    (0x218, 480, 2), (0x219, 482, 2), (0x21A, 484, 2), (0x21B, 486, 2),
+    (0x21E, 488, 2), (0x21F, 490, 2), (0x226, 492, 2), (0x227, 494, 2),
+    (0x228, 496, 2), (0x229, 498, 2), (0x22A, 500, 3), (0x22B, 503, 3),
+    (0x22C, 506, 3), (0x22D, 509, 3), (0x22E, 512, 2), (0x22F, 514, 2),
+    (0x230, 516, 3), (0x231, 519, 3), (0x232, 522, 2), (0x233, 524, 2),
+    (0x340, 526, 1), (0x341, 527, 1), (0x343, 528, 1), (0x344, 529, 2),
+    (0x374, 531, 1), (0x37E, 532, 1), (0x385, 533, 2), (0x386, 535, 2),
+    (0x387, 537, 1), (0x388, 538, 2), (0x389, 540, 2), (0x38A, 542, 2),
+    (0x38C, 544, 2), (0x38E, 546, 2), (0x38F, 548, 2), (0x390, 550, 3),
+    (0x3AA, 553, 2), (0x3AB, 555, 2), (0x3AC, 557, 2), (0x3AD, 559, 2),
+    (0x3AE, 561, 2), (0x3AF, 563, 2), (0x3B0, 565, 3), (0x3CA, 568, 2),
+    (0x3CB, 570, 2), (0x3CC, 572, 2), (0x3CD, 574, 2), (0x3CE, 576, 2),
+    (0x3D3, 578, 2), (0x3D4, 580, 2), (0x400, 582, 2), (0x401, 584, 2),
+    (0x403, 586, 2), (0x407, 588, 2), (0x40C, 590, 2), (0x40D, 592, 2),
+    (0x40E, 594, 2), (0x419, 596, 2), (0x439, 598, 2), (0x450, 600, 2),
+    (0x451, 602, 2), (0x453, 604, 2), (0x457, 606, 2), (0x45C, 608, 2),
+    (0x45D, 610, 2), (0x45E, 612, 2), (0x476, 614, 2), (0x477, 616, 2),
+    (0x4C1, 618, 2), (0x4C2, 620, 2), (0x4D0, 622, 2), (0x4D1, 624, 2),
+    (0x4D2, 626, 2), (0x4D3, 628, 2), (0x4D6, 630, 2), (0x4D7, 632, 2),
+    (0x4DA, 634, 2), (0x4DB, 636, 2), (0x4DC, 638, 2), (0x4DD, 640, 2),
+    (0x4DE, 642, 2), (0x4DF, 644, 2), (0x4E2, 646, 2), (0x4E3, 648, 2),
+    (0x4E4, 650, 2), (0x4E5, 652, 2), (0x4E6, 654, 2), (0x4E7, 656, 2),
+    (0x4EA, 658, 2), (0x4EB, 660, 2), (0x4EC, 662, 2), (0x4ED, 664, 2),
+    (0x4EE, 666, 2), (0x4EF, 668, 2), (0x4F0, 670, 2), (0x4F1, 672, 2),
+    (0x4F2, 674, 2), (0x4F3, 676, 2), (0x4F4, 678, 2), (0x4F5, 680, 2),
+    (0x4F8, 682, 2), (0x4F9, 684, 2), (0x622, 686, 2), (0x623, 688, 2),
+    (0x624, 690, 2), (0x625, 692, 2), (0x626, 694, 2), (0x6C0, 696, 2),
+    (0x6C2, 698, 2), (0x6D3, 700, 2), (0x929, 702, 2), (0x931, 704, 2),
+    (0x934, 706, 2), (0x958, 708, 2), (0x959, 710, 2), (0x95A, 712, 2),
+    (0x95B, 714, 2), (0x95C, 716, 2), (0x95D, 718, 2), (0x95E, 720, 2),
+    (0x95F, 722, 2), (0x9CB, 724, 2), (0x9CC, 726, 2), (0x9DC, 728, 2),
+    (0x9DD, 730, 2), (0x9DF, 732, 2), (0xA33, 734, 2), (0xA36, 736, 2),
+    (0xA59, 738, 2), (0xA5A, 740, 2), (0xA5B, 742, 2), (0xA5E, 744, 2),
+    (0xB48, 746, 2), (0xB4B, 748, 2), (0xB4C, 750, 2), (0xB5C, 752, 2),
+    (0xB5D, 754, 2), (0xB94, 756, 2), (0xBCA, 758, 2), (0xBCB, 760, 2),
+    (0xBCC, 762, 2), (0xC48, 764, 2), (0xCC0, 766, 2), (0xCC7, 768, 2),
+    (0xCC8, 770, 2), (0xCCA, 772, 2), (0xCCB, 774, 3), (0xD4A, 777, 2),
+    (0xD4B, 779, 2), (0xD4C, 781, 2), (0xDDA, 783, 2), (0xDDC, 785, 2),
+    (0xDDD, 787, 3), (0xDDE, 790, 2), (0xF43, 792, 2), (0xF4D, 794, 2),
+    (0xF52, 796, 2), (0xF57, 798, 2), (0xF5C, 800, 2), (0xF69, 802, 2),
+    (0xF73, 804, 2), (0xF75, 806, 2), (0xF76, 808, 2), (0xF78, 810, 2),
+    (0xF81, 812, 2), (0xF93, 814, 2), (0xF9D, 816, 2), (0xFA2, 818, 2),
+    (0xFA7, 820, 2), (0xFAC, 822, 2), (0xFB9, 824, 2), (0x1026, 826, 2),
+    (0x1B06, 828, 2), (0x1B08, 830, 2), (0x1B0A, 832, 2), (0x1B0C, 834, 2),
+    (0x1B0E, 836, 2), (0x1B12, 838, 2), (0x1B3B, 840, 2), (0x1B3D, 842, 2),
+    (0x1B40, 844, 2), (0x1B41, 846, 2), (0x1B43, 848, 2), (0x1E00, 850, 2),
+    (0x1E01, 852, 2), (0x1E02, 854, 2), (0x1E03, 856, 2), (0x1E04, 858, 2),
+    (0x1E05, 860, 2), (0x1E06, 862, 2), (0x1E07, 864, 2), (0x1E08, 866, 3),
+    (0x1E09, 869, 3), (0x1E0A, 872, 2), (0x1E0B, 874, 2), (0x1E0C, 876, 2),
+    (0x1E0D, 878, 2), (0x1E0E, 880, 2), (0x1E0F, 882, 2), (0x1E10, 884, 2),
+    (0x1E11, 886, 2), (0x1E12, 888, 2), (0x1E13, 890, 2), (0x1E14, 892, 3),
+    (0x1E15, 895, 3), (0x1E16, 898, 3), (0x1E17, 901, 3), (0x1E18, 904, 2),
+    (0x1E19, 906, 2), (0x1E1A, 908, 2), (0x1E1B, 910, 2), (0x1E1C, 912, 3),
+    (0x1E1D, 915, 3), (0x1E1E, 918, 2), (0x1E1F, 920, 2), (0x1E20, 922, 2),
+    (0x1E21, 924, 2), (0x1E22, 926, 2), (0x1E23, 928, 2), (0x1E24, 930, 2),
+    (0x1E25, 932, 2), (0x1E26, 934, 2), (0x1E27, 936, 2), (0x1E28, 938, 2),
+    (0x1E29, 940, 2), (0x1E2A, 942, 2), (0x1E2B, 944, 2), (0x1E2C, 946, 2),
+    (0x1E2D, 948, 2), (0x1E2E, 950, 3), (0x1E2F, 953, 3), (0x1E30, 956, 2),
+    (0x1E31, 958, 2), (0x1E32, 960, 2), (0x1E33, 962, 2), (0x1E34, 964, 2),
+    (0x1E35, 966, 2), (0x1E36, 968, 2), (0x1E37, 970, 2), (0x1E38, 972, 3),
+    (0x1E39, 975, 3), (0x1E3A, 978, 2), (0x1E3B, 980, 2), (0x1E3C, 982, 2),
+    (0x1E3D, 984, 2), (0x1E3E, 986, 2), (0x1E3F, 988, 2), (0x1E40, 990, 2),
+    (0x1E41, 992, 2), (0x1E42, 994, 2), (0x1E43, 996, 2), (0x1E44, 998, 2),
+    (0x1E45, 1000, 2), (0x1E46, 1002, 2), (0x1E47, 1004, 2), (0x1E48, 1006, 2),
+    (0x1E49, 1008, 2), (0x1E4A, 1010, 2), (0x1E4B, 1012, 2), (0x1E4C, 1014, 3),
+    (0x1E4D, 1017, 3), (0x1E4E, 1020, 3), (0x1E4F, 1023, 3), (0x1E50, 1026, 3),
+    (0x1E51, 1029, 3), (0x1E52, 1032, 3), (0x1E53, 1035, 3), (0x1E54, 1038, 2),
+    (0x1E55, 1040, 2), (0x1E56, 1042, 2), (0x1E57, 1044, 2), (0x1E58, 1046, 2),
+    (0x1E59, 1048, 2), (0x1E5A, 1050, 2), (0x1E5B, 1052, 2), (0x1E5C, 1054, 3),
+    (0x1E5D, 1057, 3), (0x1E5E, 1060, 2), (0x1E5F, 1062, 2), (0x1E60, 1064, 2),
+    (0x1E61, 1066, 2), (0x1E62, 1068, 2), (0x1E63, 1070, 2), (0x1E64, 1072, 3),
+    (0x1E65, 1075, 3), (0x1E66, 1078, 3), (0x1E67, 1081, 3), (0x1E68, 1084, 3),
+    (0x1E69, 1087, 3), (0x1E6A, 1090, 2), (0x1E6B, 1092, 2), (0x1E6C, 1094, 2),
+    (0x1E6D, 1096, 2), (0x1E6E, 1098, 2), (0x1E6F, 1100, 2), (0x1E70, 1102, 2),
+    (0x1E71, 1104, 2), (0x1E72, 1106, 2), (0x1E73, 1108, 2), (0x1E74, 1110, 2),
+    (0x1E75, 1112, 2), (0x1E76, 1114, 2), (0x1E77, 1116, 2), (0x1E78, 1118, 3),
+    (0x1E79, 1121, 3), (0x1E7A, 1124, 3), (0x1E7B, 1127, 3), (0x1E7C, 1130, 2),
+    (0x1E7D, 1132, 2), (0x1E7E, 1134, 2), (0x1E7F, 1136, 2), (0x1E80, 1138, 2),
+    (0x1E81, 1140, 2), (0x1E82, 1142, 2), (0x1E83, 1144, 2), (0x1E84, 1146, 2),
+    (0x1E85, 1148, 2), (0x1E86, 1150, 2), (0x1E87, 1152, 2), (0x1E88, 1154, 2),
+    (0x1E89, 1156, 2), (0x1E8A, 1158, 2), (0x1E8B, 1160, 2), (0x1E8C, 1162, 2),
+    (0x1E8D, 1164, 2), (0x1E8E, 1166, 2), (0x1E8F, 1168, 2), (0x1E90, 1170, 2),
+    (0x1E91, 1172, 2), (0x1E92, 1174, 2), (0x1E93, 1176, 2), (0x1E94, 1178, 2),
+    (0x1E95, 1180, 2), (0x1E96, 1182, 2), (0x1E97, 1184, 2), (0x1E98, 1186, 2),
+    (0x1E99, 1188, 2), (0x1E9B, 1190, 2), (0x1EA0, 1192, 2), (0x1EA1, 1194, 2),
+    (0x1EA2, 1196, 2), (0x1EA3, 1198, 2), (0x1EA4, 1200, 3), (0x1EA5, 1203, 3),
+    (0x1EA6, 1206, 3), (0x1EA7, 1209, 3), (0x1EA8, 1212, 3), (0x1EA9, 1215, 3),
+    (0x1EAA, 1218, 3), (0x1EAB, 1221, 3), (0x1EAC, 1224, 3), (0x1EAD, 1227, 3),
+    (0x1EAE, 1230, 3), (0x1EAF, 1233, 3), (0x1EB0, 1236, 3), (0x1EB1, 1239, 3),
+    (0x1EB2, 1242, 3), (0x1EB3, 1245, 3), (0x1EB4, 1248, 3), (0x1EB5, 1251, 3),
+    (0x1EB6, 1254, 3), (0x1EB7, 1257, 3), (0x1EB8, 1260, 2), (0x1EB9, 1262, 2),
+    (0x1EBA, 1264, 2), (0x1EBB, 1266, 2), (0x1EBC, 1268, 2), (0x1EBD, 1270, 2),
+    (0x1EBE, 1272, 3), (0x1EBF, 1275, 3), (0x1EC0, 1278, 3), (0x1EC1, 1281, 3),
+    (0x1EC2, 1284, 3), (0x1EC3, 1287, 3), (0x1EC4, 1290, 3), (0x1EC5, 1293, 3),
+    (0x1EC6, 1296, 3), (0x1EC7, 1299, 3), (0x1EC8, 1302, 2), (0x1EC9, 1304, 2),
+    (0x1ECA, 1306, 2), (0x1ECB, 1308, 2), (0x1ECC, 1310, 2), (0x1ECD, 1312, 2),
+    (0x1ECE, 1314, 2), (0x1ECF, 1316, 2), (0x1ED0, 1318, 3), (0x1ED1, 1321, 3),
+    (0x1ED2, 1324, 3), (0x1ED3, 1327, 3), (0x1ED4, 1330, 3), (0x1ED5, 1333, 3),
+    (0x1ED6, 1336, 3), (0x1ED7, 1339, 3), (0x1ED8, 1342, 3), (0x1ED9, 1345, 3),
+    (0x1EDA, 1348, 3), (0x1EDB, 1351, 3), (0x1EDC, 1354, 3), (0x1EDD, 1357, 3),
+    (0x1EDE, 1360, 3), (0x1EDF, 1363, 3), (0x1EE0, 1366, 3), (0x1EE1, 1369, 3),
+    (0x1EE2, 1372, 3), (0x1EE3, 1375, 3), (0x1EE4, 1378, 2), (0x1EE5, 1380, 2),
+    (0x1EE6, 1382, 2), (0x1EE7, 1384, 2), (0x1EE8, 1386, 3), (0x1EE9, 1389, 3),
+    (0x1EEA, 1392, 3), (0x1EEB, 1395, 3), (0x1EEC, 1398, 3), (0x1EED, 1401, 3),
+    (0x1EEE, 1404, 3), (0x1EEF, 1407, 3), (0x1EF0, 1410, 3), (0x1EF1, 1413, 3),
+    (0x1EF2, 1416, 2), (0x1EF3, 1418, 2), (0x1EF4, 1420, 2), (0x1EF5, 1422, 2),
+    (0x1EF6, 1424, 2), (0x1EF7, 1426, 2), (0x1EF8, 1428, 2), (0x1EF9, 1430, 2),
+    (0x1F00, 1432, 2), (0x1F01, 1434, 2), (0x1F02, 1436, 3), (0x1F03, 1439, 3),
+    (0x1F04, 1442, 3), (0x1F05, 1445, 3), (0x1F06, 1448, 3), (0x1F07, 1451, 3),
+    (0x1F08, 1454, 2), (0x1F09, 1456, 2), (0x1F0A, 1458, 3), (0x1F0B, 1461, 3),
+    (0x1F0C, 1464, 3), (0x1F0D, 1467, 3), (0x1F0E, 1470, 3), (0x1F0F, 1473, 3),
+    (0x1F10, 1476, 2), (0x1F11, 1478, 2), (0x1F12, 1480, 3), (0x1F13, 1483, 3),
+    (0x1F14, 1486, 3), (0x1F15, 1489, 3), (0x1F18, 1492, 2), (0x1F19, 1494, 2),
+    (0x1F1A, 1496, 3), (0x1F1B, 1499, 3), (0x1F1C, 1502, 3), (0x1F1D, 1505, 3),
+    (0x1F20, 1508, 2), (0x1F21, 1510, 2), (0x1F22, 1512, 3), (0x1F23, 1515, 3),
+    (0x1F24, 1518, 3), (0x1F25, 1521, 3), (0x1F26, 1524, 3), (0x1F27, 1527, 3),
+    (0x1F28, 1530, 2), (0x1F29, 1532, 2), (0x1F2A, 1534, 3), (0x1F2B, 1537, 3),
+    (0x1F2C, 1540, 3), (0x1F2D, 1543, 3), (0x1F2E, 1546, 3), (0x1F2F, 1549, 3),
+    (0x1F30, 1552, 2), (0x1F31, 1554, 2), (0x1F32, 1556, 3), (0x1F33, 1559, 3),
+    (0x1F34, 1562, 3), (0x1F35, 1565, 3), (0x1F36, 1568, 3), (0x1F37, 1571, 3),
+    (0x1F38, 1574, 2), (0x1F39, 1576, 2), (0x1F3A, 1578, 3), (0x1F3B, 1581, 3),
+    (0x1F3C, 1584, 3), (0x1F3D, 1587, 3), (0x1F3E, 1590, 3), (0x1F3F, 1593, 3),
+    (0x1F40, 1596, 2), (0x1F41, 1598, 2), (0x1F42, 1600, 3), (0x1F43, 1603, 3),
+    (0x1F44, 1606, 3), (0x1F45, 1609, 3), (0x1F48, 1612, 2), (0x1F49, 1614, 2),
+    (0x1F4A, 1616, 3), (0x1F4B, 1619, 3), (0x1F4C, 1622, 3), (0x1F4D, 1625, 3),
+    (0x1F50, 1628, 2), (0x1F51, 1630, 2), (0x1F52, 1632, 3), (0x1F53, 1635, 3),
+    (0x1F54, 1638, 3), (0x1F55, 1641, 3), (0x1F56, 1644, 3), (0x1F57, 1647, 3),
+    (0x1F59, 1650, 2), (0x1F5B, 1652, 3), (0x1F5D, 1655, 3), (0x1F5F, 1658, 3),
+    (0x1F60, 1661, 2), (0x1F61, 1663, 2), (0x1F62, 1665, 3), (0x1F63, 1668, 3),
+    (0x1F64, 1671, 3), (0x1F65, 1674, 3), (0x1F66, 1677, 3), (0x1F67, 1680, 3),
+    (0x1F68, 1683, 2), (0x1F69, 1685, 2), (0x1F6A, 1687, 3), (0x1F6B, 1690, 3),
+    (0x1F6C, 1693, 3), (0x1F6D, 1696, 3), (0x1F6E, 1699, 3), (0x1F6F, 1702, 3),
+    (0x1F70, 1705, 2), (0x1F71, 1707, 2), (0x1F72, 1709, 2), (0x1F73, 1711, 2),
+    (0x1F74, 1713, 2), (0x1F75, 1715, 2), (0x1F76, 1717, 2), (0x1F77, 1719, 2),
+    (0x1F78, 1721, 2), (0x1F79, 1723, 2), (0x1F7A, 1725, 2), (0x1F7B, 1727, 2),
+    (0x1F7C, 1729, 2), (0x1F7D, 1731, 2), (0x1F80, 1733, 3), (0x1F81, 1736, 3),
+    (0x1F82, 1739, 4), (0x1F83, 1743, 4), (0x1F84, 1747, 4), (0x1F85, 1751, 4),
+    (0x1F86, 1755, 4), (0x1F87, 1759, 4), (0x1F88, 1763, 3), (0x1F89, 1766, 3),
+    (0x1F8A, 1769, 4), (0x1F8B, 1773, 4), (0x1F8C, 1777, 4), (0x1F8D, 1781, 4),
+    (0x1F8E, 1785, 4), (0x1F8F, 1789, 4), (0x1F90, 1793, 3), (0x1F91, 1796, 3),
+    (0x1F92, 1799, 4), (0x1F93, 1803, 4), (0x1F94, 1807, 4), (0x1F95, 1811, 4),
+    (0x1F96, 1815, 4), (0x1F97, 1819, 4), (0x1F98, 1823, 3), (0x1F99, 1826, 3),
+    (0x1F9A, 1829, 4), (0x1F9B, 1833, 4), (0x1F9C, 1837, 4), (0x1F9D, 1841, 4),
+    (0x1F9E, 1845, 4), (0x1F9F, 1849, 4), (0x1FA0, 1853, 3), (0x1FA1, 1856, 3),
+    (0x1FA2, 1859, 4), (0x1FA3, 1863, 4), (0x1FA4, 1867, 4), (0x1FA5, 1871, 4),
+    (0x1FA6, 1875, 4), (0x1FA7, 1879, 4), (0x1FA8, 1883, 3), (0x1FA9, 1886, 3),
+    (0x1FAA, 1889, 4), (0x1FAB, 1893, 4), (0x1FAC, 1897, 4), (0x1FAD, 1901, 4),
+    (0x1FAE, 1905, 4), (0x1FAF, 1909, 4), (0x1FB0, 1913, 2), (0x1FB1, 1915, 2),
+    (0x1FB2, 1917, 3), (0x1FB3, 1920, 2), (0x1FB4, 1922, 3), (0x1FB6, 1925, 2),
+    (0x1FB7, 1927, 3), (0x1FB8, 1930, 2), (0x1FB9, 1932, 2), (0x1FBA, 1934, 2),
+    (0x1FBB, 1936, 2), (0x1FBC, 1938, 2), (0x1FBE, 1940, 1), (0x1FC1, 1941, 2),
+    (0x1FC2, 1943, 3), (0x1FC3, 1946, 2), (0x1FC4, 1948, 3), (0x1FC6, 1951, 2),
+    (0x1FC7, 1953, 3), (0x1FC8, 1956, 2), (0x1FC9, 1958, 2), (0x1FCA, 1960, 2),
+    (0x1FCB, 1962, 2), (0x1FCC, 1964, 2), (0x1FCD, 1966, 2), (0x1FCE, 1968, 2),
+    (0x1FCF, 1970, 2), (0x1FD0, 1972, 2), (0x1FD1, 1974, 2), (0x1FD2, 1976, 3),
+    (0x1FD3, 1979, 3), (0x1FD6, 1982, 2), (0x1FD7, 1984, 3), (0x1FD8, 1987, 2),
+    (0x1FD9, 1989, 2), (0x1FDA, 1991, 2), (0x1FDB, 1993, 2), (0x1FDD, 1995, 2),
+    (0x1FDE, 1997, 2), (0x1FDF, 1999, 2), (0x1FE0, 2001, 2), (0x1FE1, 2003, 2),
+    (0x1FE2, 2005, 3), (0x1FE3, 2008, 3), (0x1FE4, 2011, 2), (0x1FE5, 2013, 2),
+    (0x1FE6, 2015, 2), (0x1FE7, 2017, 3), (0x1FE8, 2020, 2), (0x1FE9, 2022, 2),
+    (0x1FEA, 2024, 2), (0x1FEB, 2026, 2), (0x1FEC, 2028, 2), (0x1FED, 2030, 2),
+    (0x1FEE, 2032, 2), (0x1FEF, 2034, 1), (0x1FF2, 2035, 3), (0x1FF3, 2038, 2),
+    (0x1FF4, 2040, 3), (0x1FF6, 2043, 2), (0x1FF7, 2045, 3), (0x1FF8, 2048, 2),
+    (0x1FF9, 2050, 2), (0x1FFA, 2052, 2), (0x1FFB, 2054, 2), (0x1FFC, 2056, 2),
+    (0x1FFD, 2058, 1), (0x2000, 2059, 1), (0x2001, 2060, 1), (0x2126, 2061, 1),
+    (0x212A, 2062, 1), (0x212B, 2063, 2), (0x219A, 2065, 2), (0x219B, 2067, 2),
+    (0x21AE, 2069, 2), (0x21CD, 2071, 2), (0x21CE, 2073, 2), (0x21CF, 2075, 2),
+    (0x2204, 2077, 2), (0x2209, 2079, 2), (0x220C, 2081, 2), (0x2224, 2083, 2),
+    (0x2226, 2085, 2), (0x2241, 2087, 2), (0x2244, 2089, 2), (0x2247, 2091, 2),
+    (0x2249, 2093, 2), (0x2260, 2095, 2), (0x2262, 2097, 2), (0x226D, 2099, 2),
+    (0x226E, 2101, 2), (0x226F, 2103, 2), (0x2270, 2105, 2), (0x2271, 2107, 2),
+    (0x2274, 2109, 2), (0x2275, 2111, 2), (0x2278, 2113, 2), (0x2279, 2115, 2),
+    (0x2280, 2117, 2), (0x2281, 2119, 2), (0x2284, 2121, 2), (0x2285, 2123, 2),
+    (0x2288, 2125, 2), (0x2289, 2127, 2), (0x22AC, 2129, 2), (0x22AD, 2131, 2),
+    (0x22AE, 2133, 2), (0x22AF, 2135, 2), (0x22E0, 2137, 2), (0x22E1, 2139, 2),
+    (0x22E2, 2141, 2), (0x22E3, 2143, 2), (0x22EA, 2145, 2), (0x22EB, 2147, 2),
+    (0x22EC, 2149, 2), (0x22ED, 2151, 2), (0x2329, 2153, 1), (0x232A, 2154, 1),
+    (0x2ADC, 2155, 2), (0x304C, 2157, 2), (0x304E, 2159, 2), (0x3050, 2161, 2),
+    (0x3052, 2163, 2), (0x3054, 2165, 2), (0x3056, 2167, 2), (0x3058, 2169, 2),
+    (0x305A, 2171, 2), (0x305C, 2173, 2), (0x305E, 2175, 2), (0x3060, 2177, 2),
+    (0x3062, 2179, 2), (0x3065, 2181, 2), (0x3067, 2183, 2), (0x3069, 2185, 2),
+    (0x3070, 2187, 2), (0x3071, 2189, 2), (0x3073, 2191, 2), (0x3074, 2193, 2),
+    (0x3076, 2195, 2), (0x3077, 2197, 2), (0x3079, 2199, 2), (0x307A, 2201, 2),
+    (0x307C, 2203, 2), (0x307D, 2205, 2), (0x3094, 2207, 2), (0x309E, 2209, 2),
+    (0x30AC, 2211, 2), (0x30AE, 2213, 2), (0x30B0, 2215, 2), (0x30B2, 2217, 2),
+    (0x30B4, 2219, 2), (0x30B6, 2221, 2), (0x30B8, 2223, 2), (0x30BA, 2225, 2),
+    (0x30BC, 2227, 2), (0x30BE, 2229, 2), (0x30C0, 2231, 2), (0x30C2, 2233, 2),
+    (0x30C5, 2235, 2), (0x30C7, 2237, 2), (0x30C9, 2239, 2), (0x30D0, 2241, 2),
+    (0x30D1, 2243, 2), (0x30D3, 2245, 2), (0x30D4, 2247, 2), (0x30D6, 2249, 2),
+    (0x30D7, 2251, 2), (0x30D9, 2253, 2), (0x30DA, 2255, 2), (0x30DC, 2257, 2),
+    (0x30DD, 2259, 2), (0x30F4, 2261, 2), (0x30F7, 2263, 2), (0x30F8, 2265, 2),
+    (0x30F9, 2267, 2), (0x30FA, 2269, 2), (0x30FE, 2271, 2), (0xF900, 2273, 1),
+    (0xF901, 2274, 1), (0xF902, 2275, 1), (0xF903, 2276, 1), (0xF904, 2277, 1),
+    (0xF905, 2278, 1), (0xF906, 2279, 1), (0xF907, 2280, 1), (0xF908, 2281, 1),
+    (0xF909, 2282, 1), (0xF90A, 2283, 1), (0xF90B, 2284, 1), (0xF90C, 2285, 1),
+    (0xF90D, 2286, 1), (0xF90E, 2287, 1), (0xF90F, 2288, 1), (0xF910, 2289, 1),
+    (0xF911, 2290, 1), (0xF912, 2291, 1), (0xF913, 2292, 1), (0xF914, 2293, 1),
+    (0xF915, 2294, 1), (0xF916, 2295, 1), (0xF917, 2296, 1), (0xF918, 2297, 1),
+    (0xF919, 2298, 1), (0xF91A, 2299, 1), (0xF91B, 2300, 1), (0xF91C, 2301, 1),
+    (0xF91D, 2302, 1), (0xF91E, 2303, 1), (0xF91F, 2304, 1), (0xF920, 2305, 1),
+    (0xF921, 2306, 1), (0xF922, 2307, 1), (0xF923, 2308, 1), (0xF924, 2309, 1),
+    (0xF925, 2310, 1), (0xF926, 2311, 1), (0xF927, 2312, 1), (0xF928, 2313, 1),
+    (0xF929, 2314, 1), (0xF92A, 2315, 1), (0xF92B, 2316, 1), (0xF92C, 2317, 1),
+    (0xF92D, 2318, 1), (0xF92E, 2319, 1), (0xF92F, 2320, 1), (0xF930, 2321, 1),
+    (0xF931, 2322, 1), (0xF932, 2323, 1), (0xF933, 2324, 1), (0xF934, 2325, 1),
+    (0xF935, 2326, 1), (0xF936, 2327, 1), (0xF937, 2328, 1), (0xF938, 2329, 1),
+    (0xF939, 2330, 1), (0xF93A, 2331, 1), (0xF93B, 2332, 1), (0xF93C, 2333, 1),
+    (0xF93D, 2334, 1), (0xF93E, 2335, 1), (0xF93F, 2336, 1), (0xF940, 2337, 1),
+    (0xF941, 2338, 1), (0xF942, 2339, 1), (0xF943, 2340, 1), (0xF944, 2341, 1),
+    (0xF945, 2342, 1), (0xF946, 2343, 1), (0xF947, 2344, 1), (0xF948, 2345, 1),
+    (0xF949, 2346, 1), (0xF94A, 2347, 1), (0xF94B, 2348, 1), (0xF94C, 2349, 1),
+    (0xF94D, 2350, 1), (0xF94E, 2351, 1), (0xF94F, 2352, 1), (0xF950, 2353, 1),
+    (0xF951, 2354, 1), (0xF952, 2355, 1), (0xF953, 2356, 1), (0xF954, 2357, 1),
+    (0xF955, 2358, 1), (0xF956, 2359, 1), (0xF957, 2360, 1), (0xF958, 2361, 1),
+    (0xF959, 2362, 1), (0xF95A, 2363, 1), (0xF95B, 2364, 1), (0xF95C, 2365, 1),
+    (0xF95D, 2366, 1), (0xF95E, 2367, 1), (0xF95F, 2368, 1), (0xF960, 2369, 1),
+    (0xF961, 2370, 1), (0xF962, 2371, 1), (0xF963, 2372, 1), (0xF964, 2373, 1),
+    (0xF965, 2374, 1), (0xF966, 2375, 1), (0xF967, 2376, 1), (0xF968, 2377, 1),
+    (0xF969, 2378, 1), (0xF96A, 2379, 1), (0xF96B, 2380, 1), (0xF96C, 2381, 1),
+    (0xF96D, 2382, 1), (0xF96E, 2383, 1), (0xF96F, 2384, 1), (0xF970, 2385, 1),
+    (0xF971, 2386, 1), (0xF972, 2387, 1), (0xF973, 2388, 1), (0xF974, 2389, 1),
+    (0xF975, 2390, 1), (0xF976, 2391, 1), (0xF977, 2392, 1), (0xF978, 2393, 1),
+    (0xF979, 2394, 1), (0xF97A, 2395, 1), (0xF97B, 2396, 1), (0xF97C, 2397, 1),
+    (0xF97D, 2398, 1), (0xF97E, 2399, 1), (0xF97F, 2400, 1), (0xF980, 2401, 1),
+    (0xF981, 2402, 1), (0xF982, 2403, 1), (0xF983, 2404, 1), (0xF984, 2405, 1),
+    (0xF985, 2406, 1), (0xF986, 2407, 1), (0xF987, 2408, 1), (0xF988, 2409, 1),
+    (0xF989, 2410, 1), (0xF98A, 2411, 1), (0xF98B, 2412, 1), (0xF98C, 2413, 1),
+    (0xF98D, 2414, 1), (0xF98E, 2415, 1), (0xF98F, 2416, 1), (0xF990, 2417, 1),
+    (0xF991, 2418, 1), (0xF992, 2419, 1), (0xF993, 2420, 1), (0xF994, 2421, 1),
+    (0xF995, 2422, 1), (0xF996, 2423, 1), (0xF997, 2424, 1), (0xF998, 2425, 1),
+    (0xF999, 2426, 1), (0xF99A, 2427, 1), (0xF99B, 2428, 1), (0xF99C, 2429, 1),
+    (0xF99D, 2430, 1), (0xF99E, 2431, 1), (0xF99F, 2432, 1), (0xF9A0, 2433, 1),
+    (0xF9A1, 2434, 1), (0xF9A2, 2435, 1), (0xF9A3, 2436, 1), (0xF9A4, 2437, 1),
+    (0xF9A5, 2438, 1), (0xF9A6, 2439, 1), (0xF9A7, 2440, 1), (0xF9A8, 2441, 1),
+    (0xF9A9, 2442, 1), (0xF9AA, 2443, 1), (0xF9AB, 2444, 1), (0xF9AC, 2445, 1),
+    (0xF9AD, 2446, 1), (0xF9AE, 2447, 1), (0xF9AF, 2448, 1), (0xF9B0, 2449, 1),
+    (0xF9B1, 2450, 1), (0xF9B2, 2451, 1), (0xF9B3, 2452, 1), (0xF9B4, 2453, 1),
+    (0xF9B5, 2454, 1), (0xF9B6, 2455, 1), (0xF9B7, 2456, 1), (0xF9B8, 2457, 1),
+    (0xF9B9, 2458, 1), (0xF9BA, 2459, 1), (0xF9BB, 2460, 1), (0xF9BC, 2461, 1),
+    (0xF9BD, 2462, 1), (0xF9BE, 2463, 1), (0xF9BF, 2464, 1), (0xF9C0, 2465, 1),
+    (0xF9C1, 2466, 1), (0xF9C2, 2467, 1), (0xF9C3, 2468, 1), (0xF9C4, 2469, 1),
+    (0xF9C5, 2470, 1), (0xF9C6, 2471, 1), (0xF9C7, 2472, 1), (0xF9C8, 2473, 1),
+    (0xF9C9, 2474, 1), (0xF9CA, 2475, 1), (0xF9CB, 2476, 1), (0xF9CC, 2477, 1),
+    (0xF9CD, 2478, 1), (0xF9CE, 2479, 1), (0xF9CF, 2480, 1), (0xF9D0, 2481, 1),
+    (0xF9D1, 2482, 1), (0xF9D2, 2483, 1), (0xF9D3, 2484, 1), (0xF9D4, 2485, 1),
+    (0xF9D5, 2486, 1), (0xF9D6, 2487, 1), (0xF9D7, 2488, 1), (0xF9D8, 2489, 1),
+    (0xF9D9, 2490, 1), (0xF9DA, 2491, 1), (0xF9DB, 2492, 1), (0xF9DC, 2493, 1),
+    (0xF9DD, 2494, 1), (0xF9DE, 2495, 1), (0xF9DF, 2496, 1), (0xF9E0, 2497, 1),
+    (0xF9E1, 2498, 1), (0xF9E2, 2499, 1), (0xF9E3, 2500, 1), (0xF9E4, 2501, 1),
+    (0xF9E5, 2502, 1), (0xF9E6, 2503, 1), (0xF9E7, 2504, 1), (0xF9E8, 2505, 1),
+    (0xF9E9, 2506, 1), (0xF9EA, 2507, 1), (0xF9EB, 2508, 1), (0xF9EC, 2509, 1),
+    (0xF9ED, 2510, 1), (0xF9EE, 2511, 1), (0xF9EF, 2512, 1), (0xF9F0, 2513, 1),
+    (0xF9F1, 2514, 1), (0xF9F2, 2515, 1), (0xF9F3, 2516, 1), (0xF9F4, 2517, 1),
+    (0xF9F5, 2518, 1), (0xF9F6, 2519, 1), (0xF9F7, 2520, 1), (0xF9F8, 2521, 1),
+    (0xF9F9, 2522, 1), (0xF9FA, 2523, 1), (0xF9FB, 2524, 1), (0xF9FC, 2525, 1),
+    (0xF9FD, 2526, 1), (0xF9FE, 2527, 1), (0xF9FF, 2528, 1), (0xFA00, 2529, 1),
+    (0xFA01, 2530, 1), (0xFA02, 2531, 1), (0xFA03, 2532, 1), (0xFA04, 2533, 1),
+    (0xFA05, 2534, 1), (0xFA06, 2535, 1), (0xFA07, 2536, 1), (0xFA08, 2537, 1),
+    (0xFA09, 2538, 1), (0xFA0A, 2539, 1), (0xFA0B, 2540, 1), (0xFA0C, 2541, 1),
+    (0xFA0D, 2542, 1), (0xFA10, 2543, 1), (0xFA12, 2544, 1), (0xFA15, 2545, 1),
+    (0xFA16, 2546, 1), (0xFA17, 2547, 1), (0xFA18, 2548, 1), (0xFA19, 2549, 1),
+    (0xFA1A, 2550, 1), (0xFA1B, 2551, 1), (0xFA1C, 2552, 1), (0xFA1D, 2553, 1),
+    (0xFA1E, 2554, 1), (0xFA20, 2555, 1), (0xFA22, 2556, 1), (0xFA25, 2557, 1),
+    (0xFA26, 2558, 1), (0xFA2A, 2559, 1), (0xFA2B, 2560, 1), (0xFA2C, 2561, 1),
+    (0xFA2D, 2562, 1), (0xFA2E, 2563, 1), (0xFA2F, 2564, 1), (0xFA30, 2565, 1),
+    (0xFA31, 2566, 1), (0xFA32, 2567, 1), (0xFA33, 2568, 1), (0xFA34, 2569, 1),
+    (0xFA35, 2570, 1), (0xFA36, 2571, 1), (0xFA37, 2572, 1), (0xFA38, 2573, 1),
+    (0xFA39, 2574, 1), (0xFA3A, 2575, 1), (0xFA3B, 2576, 1), (0xFA3C, 2577, 1),
+    (0xFA3D, 2578, 1), (0xFA3E, 2579, 1), (0xFA3F, 2580, 1), (0xFA40, 2581, 1),
+    (0xFA41, 2582, 1), (0xFA42, 2583, 1), (0xFA43, 2584, 1), (0xFA44, 2585, 1),
+    (0xFA45, 2586, 1), (0xFA46, 2587, 1), (0xFA47, 2588, 1), (0xFA48, 2589, 1),
+    (0xFA49, 2590, 1), (0xFA4A, 2591, 1), (0xFA4B, 2592, 1), (0xFA4C, 2593, 1),
+    (0xFA4D, 2594, 1), (0xFA4E, 2595, 1), (0xFA4F, 2596, 1), (0xFA50, 2597, 1),
+    (0xFA51, 2598, 1), (0xFA52, 2599, 1), (0xFA53, 2600, 1), (0xFA54, 2601, 1),
+    (0xFA55, 2602, 1), (0xFA56, 2603, 1), (0xFA57, 2604, 1), (0xFA58, 2605, 1),
+    (0xFA59, 2606, 1), (0xFA5A, 2607, 1), (0xFA5B, 2608, 1), (0xFA5C, 2609, 1),
+    (0xFA5D, 2610, 1), (0xFA5E, 2611, 1), (0xFA5F, 2612, 1), (0xFA60, 2613, 1),
+    (0xFA61, 2614, 1), (0xFA62, 2615, 1), (0xFA63, 2616, 1), (0xFA64, 2617, 1),
+    (0xFA65, 2618, 1), (0xFA66, 2619, 1), (0xFA67, 2620, 1), (0xFA68, 2621, 1),
+    (0xFA69, 2622, 1), (0xFA6A, 2623, 1), (0xFA6B, 2624, 1), (0xFA6C, 2625, 1),
+    (0xFA6D, 2626, 1), (0xFA70, 2627, 1), (0xFA71, 2628, 1), (0xFA72, 2629, 1),
+    (0xFA73, 2630, 1), (0xFA74, 2631, 1), (0xFA75, 2632, 1), (0xFA76, 2633, 1),
+    (0xFA77, 2634, 1), (0xFA78, 2635, 1), (0xFA79, 2636, 1), (0xFA7A, 2637, 1),
+    (0xFA7B, 2638, 1), (0xFA7C, 2639, 1), (0xFA7D, 2640, 1), (0xFA7E, 2641, 1),
+    (0xFA7F, 2642, 1), (0xFA80, 2643, 1), (0xFA81, 2644, 1), (0xFA82, 2645, 1),
+    (0xFA83, 2646, 1), (0xFA84, 2647, 1), (0xFA85, 2648, 1), (0xFA86, 2649, 1),
+    (0xFA87, 2650, 1), (0xFA88, 2651, 1), (0xFA89, 2652, 1), (0xFA8A, 2653, 1),
+    (0xFA8B, 2654, 1), (0xFA8C, 2655, 1), (0xFA8D, 2656, 1), (0xFA8E, 2657, 1),
+    (0xFA8F, 2658, 1), (0xFA90, 2659, 1), (0xFA91, 2660, 1), (0xFA92, 2661, 1),
+    (0xFA93, 2662, 1), (0xFA94, 2663, 1), (0xFA95, 2664, 1), (0xFA96, 2665, 1),
+    (0xFA97, 2666, 1), (0xFA98, 2667, 1), (0xFA99, 2668, 1), (0xFA9A, 2669, 1),
+    (0xFA9B, 2670, 1), (0xFA9C, 2671, 1), (0xFA9D, 2672, 1), (0xFA9E, 2673, 1),
+    (0xFA9F, 2674, 1), (0xFAA0, 2675, 1), (0xFAA1, 2676, 1), (0xFAA2, 2677, 1),
+    (0xFAA3, 2678, 1), (0xFAA4, 2679, 1), (0xFAA5, 2680, 1), (0xFAA6, 2681, 1),
+    (0xFAA7, 2682, 1), (0xFAA8, 2683, 1), (0xFAA9, 2684, 1), (0xFAAA, 2685, 1),
+    (0xFAAB, 2686, 1), (0xFAAC, 2687, 1), (0xFAAD, 2688, 1), (0xFAAE, 2689, 1),
+    (0xFAAF, 2690, 1), (0xFAB0, 2691, 1), (0xFAB1, 2692, 1), (0xFAB2, 2693, 1),
+    (0xFAB3, 2694, 1), (0xFAB4, 2695, 1), (0xFAB5, 2696, 1), (0xFAB6, 2697, 1),
+    (0xFAB7, 2698, 1), (0xFAB8, 2699, 1), (0xFAB9, 2700, 1), (0xFABA, 2701, 1),
+    (0xFABB, 2702, 1), (0xFABC, 2703, 1), (0xFABD, 2704, 1), (0xFABE, 2705, 1),
+    (0xFABF, 2706, 1), (0xFAC0, 2707, 1), (0xFAC1, 2708, 1), (0xFAC2, 2709, 1),
+    (0xFAC3, 2710, 1), (0xFAC4, 2711, 1), (0xFAC5, 2712, 1), (0xFAC6, 2713, 1),
+    (0xFAC7, 2714, 1), (0xFAC8, 2715, 1), (0xFAC9, 2716, 1), (0xFACA, 2717, 1),
+    (0xFACB, 2718, 1), (0xFACC, 2719, 1), (0xFACD, 2720, 1), (0xFACE, 2721, 1),
+    (0xFACF, 2722, 1), (0xFAD0, 2723, 1), (0xFAD1, 2724, 1), (0xFAD2, 2725, 1),
+    (0xFAD3, 2726, 1), (0xFAD4, 2727, 1), (0xFAD5, 2728, 1), (0xFAD6, 2729, 1),
+    (0xFAD7, 2730, 1), (0xFAD8, 2731, 1), (0xFAD9, 2732, 1), (0xFB1D, 2733, 2),
+    (0xFB1F, 2735, 2), (0xFB2A, 2737, 2), (0xFB2B, 2739, 2), (0xFB2C, 2741, 3),
+    (0xFB2D, 2744, 3), (0xFB2E, 2747, 2), (0xFB2F, 2749, 2), (0xFB30, 2751, 2),
+    (0xFB31, 2753, 2), (0xFB32, 2755, 2), (0xFB33, 2757, 2), (0xFB34, 2759, 2),
+    (0xFB35, 2761, 2), (0xFB36, 2763, 2), (0xFB38, 2765, 2), (0xFB39, 2767, 2),
+    (0xFB3A, 2769, 2), (0xFB3B, 2771, 2), (0xFB3C, 2773, 2), (0xFB3E, 2775, 2),
+    (0xFB40, 2777, 2), (0xFB41, 2779, 2), (0xFB43, 2781, 2), (0xFB44, 2783, 2),
+    (0xFB46, 2785, 2), (0xFB47, 2787, 2), (0xFB48, 2789, 2), (0xFB49, 2791, 2),
+    (0xFB4A, 2793, 2), (0xFB4B, 2795, 2), (0xFB4C, 2797, 2), (0xFB4D, 2799, 2),
+    (0xFB4E, 2801, 2), (0x1109A, 2803, 2), (0x1109C, 2805, 2), (0x110AB, 2807, 2),
+    (0x1112E, 2809, 2), (0x1112F, 2811, 2), (0x1134B, 2813, 2), (0x1134C, 2815, 2),
+    (0x114BB, 2817, 2), (0x114BC, 2819, 2), (0x114BE, 2821, 2), (0x115BA, 2823, 2),
+    (0x115BB, 2825, 2), (0x11938, 2827, 2), (0x1D15E, 2829, 2), (0x1D15F, 2831, 2),
+    (0x1D160, 2833, 3), (0x1D161, 2836, 3), (0x1D162, 2839, 3), (0x1D163, 2842, 3),
+    (0x1D164, 2845, 3), (0x1D1BB, 2848, 2), (0x1D1BC, 2850, 2), (0x1D1BD, 2852, 3),
+    (0x1D1BE, 2855, 3), (0x1D1BF, 2858, 3), (0x1D1C0, 2861, 3), (0x2F800, 2864, 1),
+    (0x2F801, 2865, 1), (0x2F802, 2866, 1), (0x2F803, 2867, 1), (0x2F804, 2868, 1),
+    (0x2F805, 2869, 1), (0x2F806, 2870, 1), (0x2F807, 2871, 1), (0x2F808, 2872, 1),
+    (0x2F809, 2873, 1), (0x2F80A, 2874, 1), (0x2F80B, 2875, 1), (0x2F80C, 2876, 1),
+    (0x2F80D, 2877, 1), (0x2F80E, 2878, 1), (0x2F80F, 2879, 1), (0x2F810, 2880, 1),
+    (0x2F811, 2881, 1), (0x2F812, 2882, 1), (0x2F813, 2883, 1), (0x2F814, 2884, 1),
+    (0x2F815, 2885, 1), (0x2F816, 2886, 1), (0x2F817, 2887, 1), (0x2F818, 2888, 1),
+    (0x2F819, 2889, 1), (0x2F81A, 2890, 1), (0x2F81B, 2891, 1), (0x2F81C, 2892, 1),
+    (0x2F81D, 2893, 1), (0x2F81E, 2894, 1), (0x2F81F, 2895, 1), (0x2F820, 2896, 1),
+    (0x2F821, 2897, 1), (0x2F822, 2898, 1), (0x2F823, 2899, 1), (0x2F824, 2900, 1),
+    (0x2F825, 2901, 1), (0x2F826, 2902, 1), (0x2F827, 2903, 1), (0x2F828, 2904, 1),
+    (0x2F829, 2905, 1), (0x2F82A, 2906, 1), (0x2F82B, 2907, 1), (0x2F82C, 2908, 1),
+    (0x2F82D, 2909, 1), (0x2F82E, 2910, 1), (0x2F82F, 2911, 1), (0x2F830, 2912, 1),
+    (0x2F831, 2913, 1), (0x2F832, 2914, 1), (0x2F833, 2915, 1), (0x2F834, 2916, 1),
+    (0x2F835, 2917, 1), (0x2F836, 2918, 1), (0x2F837, 2919, 1), (0x2F838, 2920, 1),
+    (0x2F839, 2921, 1), (0x2F83A, 2922, 1), (0x2F83B, 2923, 1), (0x2F83C, 2924, 1),
+    (0x2F83D, 2925, 1), (0x2F83E, 2926, 1), (0x2F83F, 2927, 1), (0x2F840, 2928, 1),
+    (0x2F841, 2929, 1), (0x2F842, 2930, 1), (0x2F843, 2931, 1), (0x2F844, 2932, 1),
+    (0x2F845, 2933, 1), (0x2F846, 2934, 1), (0x2F847, 2935, 1), (0x2F848, 2936, 1),
+    (0x2F849, 2937, 1), (0x2F84A, 2938, 1), (0x2F84B, 2939, 1), (0x2F84C, 2940, 1),
+    (0x2F84D, 2941, 1), (0x2F84E, 2942, 1), (0x2F84F, 2943, 1), (0x2F850, 2944, 1),
+    (0x2F851, 2945, 1), (0x2F852, 2946, 1), (0x2F853, 2947, 1), (0x2F854, 2948, 1),
+    (0x2F855, 2949, 1), (0x2F856, 2950, 1), (0x2F857, 2951, 1), (0x2F858, 2952, 1),
+    (0x2F859, 2953, 1), (0x2F85A, 2954, 1), (0x2F85B, 2955, 1), (0x2F85C, 2956, 1),
+    (0x2F85D, 2957, 1), (0x2F85E, 2958, 1), (0x2F85F, 2959, 1), (0x2F860, 2960, 1),
+    (0x2F861, 2961, 1), (0x2F862, 2962, 1), (0x2F863, 2963, 1), (0x2F864, 2964, 1),
+    (0x2F865, 2965, 1), (0x2F866, 2966, 1), (0x2F867, 2967, 1), (0x2F868, 2968, 1),
+    (0x2F869, 2969, 1), (0x2F86A, 2970, 1), (0x2F86B, 2971, 1), (0x2F86C, 2972, 1),
+    (0x2F86D, 2973, 1), (0x2F86E, 2974, 1), (0x2F86F, 2975, 1), (0x2F870, 2976, 1),
+    (0x2F871, 2977, 1), (0x2F872, 2978, 1), (0x2F873, 2979, 1), (0x2F874, 2980, 1),
+    (0x2F875, 2981, 1), (0x2F876, 2982, 1), (0x2F877, 2983, 1), (0x2F878, 2984, 1),
+    (0x2F879, 2985, 1), (0x2F87A, 2986, 1), (0x2F87B, 2987, 1), (0x2F87C, 2988, 1),
+    (0x2F87D, 2989, 1), (0x2F87E, 2990, 1), (0x2F87F, 2991, 1), (0x2F880, 2992, 1),
+    (0x2F881, 2993, 1), (0x2F882, 2994, 1), (0x2F883, 2995, 1), (0x2F884, 2996, 1),
+    (0x2F885, 2997, 1), (0x2F886, 2998, 1), (0x2F887, 2999, 1), (0x2F888, 3000, 1),
+    (0x2F889, 3001, 1), (0x2F88A, 3002, 1), (0x2F88B, 3003, 1), (0x2F88C, 3004, 1),
+    (0x2F88D, 3005, 1), (0x2F88E, 3006, 1), (0x2F88F, 3007, 1), (0x2F890, 3008, 1),
+    (0x2F891, 3009, 1), (0x2F892, 3010, 1), (0x2F893, 3011, 1), (0x2F894, 3012, 1),
+    (0x2F895, 3013, 1), (0x2F896, 3014, 1), (0x2F897, 3015, 1), (0x2F898, 3016, 1),
+    (0x2F899, 3017, 1), (0x2F89A, 3018, 1), (0x2F89B, 3019, 1), (0x2F89C, 3020, 1),
+    (0x2F89D, 3021, 1), (0x2F89E, 3022, 1), (0x2F89F, 3023, 1), (0x2F8A0, 3024, 1),
+    (0x2F8A1, 3025, 1), (0x2F8A2, 3026, 1), (0x2F8A3, 3027, 1), (0x2F8A4, 3028, 1),
+    (0x2F8A5, 3029, 1), (0x2F8A6, 3030, 1), (0x2F8A7, 3031, 1), (0x2F8A8, 3032, 1),
+    (0x2F8A9, 3033, 1), (0x2F8AA, 3034, 1), (0x2F8AB, 3035, 1), (0x2F8AC, 3036, 1),
+    (0x2F8AD, 3037, 1), (0x2F8AE, 3038, 1), (0x2F8AF, 3039, 1), (0x2F8B0, 3040, 1),
+    (0x2F8B1, 3041, 1), (0x2F8B2, 3042, 1), (0x2F8B3, 3043, 1), (0x2F8B4, 3044, 1),
+    (0x2F8B5, 3045, 1), (0x2F8B6, 3046, 1), (0x2F8B7, 3047, 1), (0x2F8B8, 3048, 1),
+    (0x2F8B9, 3049, 1), (0x2F8BA, 3050, 1), (0x2F8BB, 3051, 1), (0x2F8BC, 3052, 1),
+    (0x2F8BD, 3053, 1), (0x2F8BE, 3054, 1), (0x2F8BF, 3055, 1), (0x2F8C0, 3056, 1),
+    (0x2F8C1, 3057, 1), (0x2F8C2, 3058, 1), (0x2F8C3, 3059, 1), (0x2F8C4, 3060, 1),
+    (0x2F8C5, 3061, 1), (0x2F8C6, 3062, 1), (0x2F8C7, 3063, 1), (0x2F8C8, 3064, 1),
+    (0x2F8C9, 3065, 1), (0x2F8CA, 3066, 1), (0x2F8CB, 3067, 1), (0x2F8CC, 3068, 1),
+    (0x2F8CD, 3069, 1), (0x2F8CE, 3070, 1), (0x2F8CF, 3071, 1), (0x2F8D0, 3072, 1),
+    (0x2F8D1, 3073, 1), (0x2F8D2, 3074, 1), (0x2F8D3, 3075, 1), (0x2F8D4, 3076, 1),
+    (0x2F8D5, 3077, 1), (0x2F8D6, 3078, 1), (0x2F8D7, 3079, 1), (0x2F8D8, 3080, 1),
+    (0x2F8D9, 3081, 1), (0x2F8DA, 3082, 1), (0x2F8DB, 3083, 1), (0x2F8DC, 3084, 1),
+    (0x2F8DD, 3085, 1), (0x2F8DE, 3086, 1), (0x2F8DF, 3087, 1), (0x2F8E0, 3088, 1),
+    (0x2F8E1, 3089, 1), (0x2F8E2, 3090, 1), (0x2F8E3, 3091, 1), (0x2F8E4, 3092, 1),
+    (0x2F8E5, 3093, 1), (0x2F8E6, 3094, 1), (0x2F8E7, 3095, 1), (0x2F8E8, 3096, 1),
+    (0x2F8E9, 3097, 1), (0x2F8EA, 3098, 1), (0x2F8EB, 3099, 1), (0x2F8EC, 3100, 1),
+    (0x2F8ED, 3101, 1), (0x2F8EE, 3102, 1), (0x2F8EF, 3103, 1), (0x2F8F0, 3104, 1),
+    (0x2F8F1, 3105, 1), (0x2F8F2, 3106, 1), (0x2F8F3, 3107, 1), (0x2F8F4, 3108, 1),
+    (0x2F8F5, 3109, 1), (0x2F8F6, 3110, 1), (0x2F8F7, 3111, 1), (0x2F8F8, 3112, 1),
+    (0x2F8F9, 3113, 1), (0x2F8FA, 3114, 1), (0x2F8FB, 3115, 1), (0x2F8FC, 3116, 1),
+    (0x2F8FD, 3117, 1), (0x2F8FE, 3118, 1), (0x2F8FF, 3119, 1), (0x2F900, 3120, 1),
+    (0x2F901, 3121, 1), (0x2F902, 3122, 1), (0x2F903, 3123, 1), (0x2F904, 3124, 1),
+    (0x2F905, 3125, 1), (0x2F906, 3126, 1), (0x2F907, 3127, 1), (0x2F908, 3128, 1),
+    (0x2F909, 3129, 1), (0x2F90A, 3130, 1), (0x2F90B, 3131, 1), (0x2F90C, 3132, 1),
+    (0x2F90D, 3133, 1), (0x2F90E, 3134, 1), (0x2F90F, 3135, 1), (0x2F910, 3136, 1),
+    (0x2F911, 3137, 1), (0x2F912, 3138, 1), (0x2F913, 3139, 1), (0x2F914, 3140, 1),
+    (0x2F915, 3141, 1), (0x2F916, 3142, 1), (0x2F917, 3143, 1), (0x2F918, 3144, 1),
+    (0x2F919, 3145, 1), (0x2F91A, 3146, 1), (0x2F91B, 3147, 1), (0x2F91C, 3148, 1),
+    (0x2F91D, 3149, 1), (0x2F91E, 3150, 1), (0x2F91F, 3151, 1), (0x2F920, 3152, 1),
+    (0x2F921, 3153, 1), (0x2F922, 3154, 1), (0x2F923, 3155, 1), (0x2F924, 3156, 1),
+    (0x2F925, 3157, 1), (0x2F926, 3158, 1), (0x2F927, 3159, 1), (0x2F928, 3160, 1),
+    (0x2F929, 3161, 1), (0x2F92A, 3162, 1), (0x2F92B, 3163, 1), (0x2F92C, 3164, 1),
+    (0x2F92D, 3165, 1), (0x2F92E, 3166, 1), (0x2F92F, 3167, 1), (0x2F930, 3168, 1),
+    (0x2F931, 3169, 1), (0x2F932, 3170, 1), (0x2F933, 3171, 1), (0x2F934, 3172, 1),
+    (0x2F935, 3173, 1), (0x2F936, 3174, 1), (0x2F937, 3175, 1), (0x2F938, 3176, 1),
+    (0x2F939, 3177, 1), (0x2F93A, 3178, 1), (0x2F93B, 3179, 1), (0x2F93C, 3180, 1),
+    (0x2F93D, 3181, 1), (0x2F93E, 3182, 1), (0x2F93F, 3183, 1), (0x2F940, 3184, 1),
+    (0x2F941, 3185, 1), (0x2F942, 3186, 1), (0x2F943, 3187, 1), (0x2F944, 3188, 1),
+    (0x2F945, 3189, 1), (0x2F946, 3190, 1), (0x2F947, 3191, 1), (0x2F948, 3192, 1),
+    (0x2F949, 3193, 1), (0x2F94A, 3194, 1), (0x2F94B, 3195, 1), (0x2F94C, 3196, 1),
+    (0x2F94D, 3197, 1), (0x2F94E, 3198, 1), (0x2F94F, 3199, 1), (0x2F950, 3200, 1),
+    (0x2F951, 3201, 1), (0x2F952, 3202, 1), (0x2F953, 3203, 1), (0x2F954, 3204, 1),
+    (0x2F955, 3205, 1), (0x2F956, 3206, 1), (0x2F957, 3207, 1), (0x2F958, 3208, 1),
+    (0x2F959, 3209, 1), (0x2F95A, 3210, 1), (0x2F95B, 3211, 1), (0x2F95C, 3212, 1),
+    (0x2F95D, 3213, 1), (0x2F95E, 3214, 1), (0x2F95F, 3215, 1), (0x2F960, 3216, 1),
+    (0x2F961, 3217, 1), (0x2F962, 3218, 1), (0x2F963, 3219, 1), (0x2F964, 3220, 1),
+    (0x2F965, 3221, 1), (0x2F966, 3222, 1), (0x2F967, 3223, 1), (0x2F968, 3224, 1),
+    (0x2F969, 3225, 1), (0x2F96A, 3226, 1), (0x2F96B, 3227, 1), (0x2F96C, 3228, 1),
+    (0x2F96D, 3229, 1), (0x2F96E, 3230, 1), (0x2F96F, 3231, 1), (0x2F970, 3232, 1),
+    (0x2F971, 3233, 1), (0x2F972, 3234, 1), (0x2F973, 3235, 1), (0x2F974, 3236, 1),
+    (0x2F975, 3237, 1), (0x2F976, 3238, 1), (0x2F977, 3239, 1), (0x2F978, 3240, 1),
+    (0x2F979, 3241, 1), (0x2F97A, 3242, 1), (0x2F97B, 3243, 1), (0x2F97C, 3244, 1),
+    (0x2F97D, 3245, 1), (0x2F97E, 3246, 1), (0x2F97F, 3247, 1), (0x2F980, 3248, 1),
+    (0x2F981, 3249, 1), (0x2F982, 3250, 1), (0x2F983, 3251, 1), (0x2F984, 3252, 1),
+    (0x2F985, 3253, 1), (0x2F986, 3254, 1), (0x2F987, 3255, 1), (0x2F988, 3256, 1),
+    (0x2F989, 3257, 1), (0x2F98A, 3258, 1), (0x2F98B, 3259, 1), (0x2F98C, 3260, 1),
+    (0x2F98D, 3261, 1), (0x2F98E, 3262, 1), (0x2F98F, 3263, 1), (0x2F990, 3264, 1),
+    (0x2F991, 3265, 1), (0x2F992, 3266, 1), (0x2F993, 3267, 1), (0x2F994, 3268, 1),
+    (0x2F995, 3269, 1), (0x2F996, 3270, 1), (0x2F997, 3271, 1), (0x2F998, 3272, 1),
+    (0x2F999, 3273, 1), (0x2F99A, 3274, 1), (0x2F99B, 3275, 1), (0x2F99C, 3276, 1),
+    (0x2F99D, 3277, 1), (0x2F99E, 3278, 1), (0x2F99F, 3279, 1), (0x2F9A0, 3280, 1),
+    (0x2F9A1, 3281, 1), (0x2F9A2, 3282, 1), (0x2F9A3, 3283, 1), (0x2F9A4, 3284, 1),
+    (0x2F9A5, 3285, 1), (0x2F9A6, 3286, 1), (0x2F9A7, 3287, 1), (0x2F9A8, 3288, 1),
+    (0x2F9A9, 3289, 1), (0x2F9AA, 3290, 1), (0x2F9AB, 3291, 1), (0x2F9AC, 3292, 1),
+    (0x2F9AD, 3293, 1), (0x2F9AE, 3294, 1), (0x2F9AF, 3295, 1), (0x2F9B0, 3296, 1),
+    (0x2F9B1, 3297, 1), (0x2F9B2, 3298, 1), (0x2F9B3, 3299, 1), (0x2F9B4, 3300, 1),
+    (0x2F9B5, 3301, 1), (0x2F9B6, 3302, 1), (0x2F9B7, 3303, 1), (0x2F9B8, 3304, 1),
+    (0x2F9B9, 3305, 1), (0x2F9BA, 3306, 1), (0x2F9BB, 3307, 1), (0x2F9BC, 3308, 1),
+    (0x2F9BD, 3309, 1), (0x2F9BE, 3310, 1), (0x2F9BF, 3311, 1), (0x2F9C0, 3312, 1),
+    (0x2F9C1, 3313, 1), (0x2F9C2, 3314, 1), (0x2F9C3, 3315, 1), (0x2F9C4, 3316, 1),
+    (0x2F9C5, 3317, 1), (0x2F9C6, 3318, 1), (0x2F9C7, 3319, 1), (0x2F9C8, 3320, 1),
+    (0x2F9C9, 3321, 1), (0x2F9CA, 3322, 1), (0x2F9CB, 3323, 1), (0x2F9CC, 3324, 1),
+    (0x2F9CD, 3325, 1), (0x2F9CE, 3326, 1), (0x2F9CF, 3327, 1), (0x2F9D0, 3328, 1),
+    (0x2F9D1, 3329, 1), (0x2F9D2, 3330, 1), (0x2F9D3, 3331, 1), (0x2F9D4, 3332, 1),
+    (0x2F9D5, 3333, 1), (0x2F9D6, 3334, 1), (0x2F9D7, 3335, 1), (0x2F9D8, 3336, 1),
+    (0x2F9D9, 3337, 1), (0x2F9DA, 3338, 1), (0x2F9DB, 3339, 1), (0x2F9DC, 3340, 1),
+    (0x2F9DD, 3341, 1), (0x2F9DE, 3342, 1), (0x2F9DF, 3343, 1), (0x2F9E0, 3344, 1),
+    (0x2F9E1, 3345, 1), (0x2F9E2, 3346, 1), (0x2F9E3, 3347, 1), (0x2F9E4, 3348, 1),
+    (0x2F9E5, 3349, 1), (0x2F9E6, 3350, 1), (0x2F9E7, 3351, 1), (0x2F9E8, 3352, 1),
+    (0x2F9E9, 3353, 1), (0x2F9EA, 3354, 1), (0x2F9EB, 3355, 1), (0x2F9EC, 3356, 1),
+    (0x2F9ED, 3357, 1), (0x2F9EE, 3358, 1), (0x2F9EF, 3359, 1), (0x2F9F0, 3360, 1),
+    (0x2F9F1, 3361, 1), (0x2F9F2, 3362, 1), (0x2F9F3, 3363, 1), (0x2F9F4, 3364, 1),
+    (0x2F9F5, 3365, 1), (0x2F9F6, 3366, 1), (0x2F9F7, 3367, 1), (0x2F9F8, 3368, 1),
+    (0x2F9F9, 3369, 1), (0x2F9FA, 3370, 1), (0x2F9FB, 3371, 1), (0x2F9FC, 3372, 1),
+    (0x2F9FD, 3373, 1), (0x2F9FE, 3374, 1), (0x2F9FF, 3375, 1), (0x2FA00, 3376, 1),
+    (0x2FA01, 3377, 1), (0x2FA02, 3378, 1), (0x2FA03, 3379, 1), (0x2FA04, 3380, 1),
+    (0x2FA05, 3381, 1), (0x2FA06, 3382, 1), (0x2FA07, 3383, 1), (0x2FA08, 3384, 1),
+    (0x2FA09, 3385, 1), (0x2FA0A, 3386, 1), (0x2FA0B, 3387, 1), (0x2FA0C, 3388, 1),
+    (0x2FA0D, 3389, 1), (0x2FA0E, 3390, 1), (0x2FA0F, 3391, 1), (0x2FA10, 3392, 1),
+    (0x2FA11, 3393, 1), (0x2FA12, 3394, 1), (0x2FA13, 3395, 1), (0x2FA14, 3396, 1),
+    (0x2FA15, 3397, 1), (0x2FA16, 3398, 1), (0x2FA17, 3399, 1), (0x2FA18, 3400, 1),
+    (0x2FA19, 3401, 1), (0x2FA1A, 3402, 1), (0x2FA1B, 3403, 1), (0x2FA1C, 3404, 1),
+    (0x2FA1D, 3405, 1),
+];
+
+pub(super) static DECOMPOSITION_DATA: &[char] = &[
+    '\u{41}', '\u{300}', '\u{41}', '\u{301}', '\u{41}', '\u{302}', '\u{41}', '\u{303}',
+    '\u{41}', '\u{308}', '\u{41}', '\u{30A}', '\u{43}', '\u{327}', '\u{45}', '\u{300}',
+    '\u{45}', '\u{301}', '\u{45}', '\u{302}', '\u{45}', '\u{308}', '\u{49}', '\u{300}',
+    '\u{49}', '\u{301}', '\u{49}', '\u{302}', '\u{49}', '\u{308}', '\u{4E}', '\u{303}',
+    '\u{4F}', '\u{300}', '\u{4F}', '\u{301}', '\u{4F}', '\u{302}', '\u{4F}', '\u{303}',
+    '\u{4F}', '\u{308}', '\u{55}', '\u{300}', '\u{55}', '\u{301}', '\u{55}', '\u{302}',
+    '\u{55}', '\u{308}', '\u{59}', '\u{301}', '\u{61}', '\u{300}', '\u{61}', '\u{301}',
+    '\u{61}', '\u{302}', '\u{61}', '\u{303}', '\u{61}', '\u{308}', '\u{61}', '\u{30A}',
+    '\u{63}', '\u{327}', '\u{65}', '\u{300}', '\u{65}', '\u{301}', '\u{65}', '\u{302}',
+    '\u{65}', '\u{308}', '\u{69}', '\u{300}', '\u{69}', '\u{301}', '\u{69}', '\u{302}',
+    '\u{69}', '\u{308}', '\u{6E}', '\u{303}', '\u{6F}', '\u{300}', '\u{6F}', '\u{301}',
+    '\u{6F}', '\u{302}', '\u{6F}', '\u{303}', '\u{6F}', '\u{308}', '\u{75}', '\u{300}',
+    '\u{75}', '\u{301}', '\u{75}', '\u{302}', '\u{75}', '\u{308}', '\u{79}', '\u{301}',
+    '\u{79}', '\u{308}', '\u{41}', '\u{304}', '\u{61}', '\u{304}', '\u{41}', '\u{306}',
+    '\u{61}', '\u{306}', '\u{41}', '\u{328}', '\u{61}', '\u{328}', '\u{43}', '\u{301}',
+    '\u{63}', '\u{301}', '\u{43}', '\u{302}', '\u{63}', '\u{302}', '\u{43}', '\u{307}',
+    '\u{63}', '\u{307}', '\u{43}', '\u{30C}', '\u{63}', '\u{30C}', '\u{44}', '\u{30C}',
+    '\u{64}', '\u{30C}', '\u{45}', '\u{304}', '\u{65}', '\u{304}', '\u{45}', '\u{306}',
+    '\u{65}', '\u{306}', '\u{45}', '\u{307}', '\u{65}', '\u{307}', '\u{45}', '\u{328}',
+    '\u{65}', '\u{328}', '\u{45}', '\u{30C}', '\u{65}', '\u{30C}', '\u{47}', '\u{302}',
+    '\u{67}', '\u{302}', '\u{47}', '\u{306}', '\u{67}', '\u{306}', '\u{47}', '\u{307}',
+    '\u{67}', '\u{307}', '\u{47}', '\u{327}', '\u{67}', '\u{327}', '\u{48}', '\u{302}',
+    '\u{68}', '\u{302}', '\u{49}', '\u{303}', '\u{69}', '\u{303}', '\u{49}', '\u{304}',
+    '\u{69}', '\u{304}', '\u{49}', '\u{306}', '\u{69}', '\u{306}', '\u{49}', '\u{328}',
+    '\u{69}', '\u{328}', '\u{49}', '\u{307}', '\u{4A}', '\u{302}', '\u{6A}', '\u{302}',
+    '\u{4B}', '\u{327}', '\u{6B}', '\u{327}', '\u{4C}', '\u{301}', '\u{6C}', '\u{301}',
+    '\u{4C}', '\u{327}', '\u{6C}', '\u{327}', '\u{4C}', '\u{30C}', '\u{6C}', '\u{30C}',
+    '\u{4E}', '\u{301}', '\u{6E}', '\u{301}', '\u{4E}', '\u{327}', '\u{6E}', '\u{327}',
+    '\u{4E}', '\u{30C}', '\u{6E}', '\u{30C}', '\u{4F}', '\u{304}', '\u{6F}', '\u{304}',
+    '\u{4F}', '\u{306}', '\u{6F}', '\u{306}', '\u{4F}', '\u{30B}', '\u{6F}', '\u{30B}',
+    '\u{52}', '\u{301}', '\u{72}', '\u{301}', '\u{52}', '\u{327}', '\u{72}', '\u{327}',
+    '\u{52}', '\u{30C}', '\u{72}', '\u{30C}', '\u{53}', '\u{301}', '\u{73}', '\u{301}',
+    '\u{53}', '\u{302}', '\u{73}', '\u{302}', '\u{53}', '\u{327}', '\u{73}', '\u{327}',
+    '\u{53}', '\u{30C}', '\u{73}', '\u{30C}', '\u{54}', '\u{327}', '\u{74}', '\u{327}',
+    '\u{54}', '\u{30C}', '\u{74}', '\u{30C}', '\u{55}', '\u{303}', '\u{75}', '\u{303}',
+    '\u{55}', '\u{304}', '\u{75}', '\u{304}', '\u{55}', '\u{306}', '\u{75}', '\u{306}',
+    '\u{55}', '\u{30A}', '\u{75}', '\u{30A}', '\u{55}', '\u{30B}', '\u{75}', '\u{30B}',
+    '\u{55}', '\u{328}', '\u{75}', '\u{328}', '\u{57}', '\u{302}', '\u{77}', '\u{302}',
+    '\u{59}', '\u{302}', '\u{79}', '\u{302}', '\u{59}', '\u{308}', '\u{5A}', '\u{301}',
+    '\u{7A}', '\u{301}', '\u{5A}', '\u{307}', '\u{7A}', '\u{307}', '\u{5A}', '\u{30C}',
+    '\u{7A}', '\u{30C}', '\u{4F}', '\u{31B}', '\u{6F}', '\u{31B}', '\u{55}', '\u{31B}',
+    '\u{75}', '\u{31B}', '\u{41}', '\u{30C}', '\u{61}', '\u{30C}', '\u{49}', '\u{30C}',
+    '\u{69}', '\u{30C}', '\u{4F}', '\u{30C}', '\u{6F}', '\u{30C}', '\u{55}', '\u{30C}',
+    '\u{75}', '\u{30C}', '\u{55}', '\u{308}', '\u{304}', '\u{75}', '\u{308}', '\u{304}',
+    '\u{55}', '\u{308}', '\u{301}', '\u{75}', '\u{308}', '\u{301}', '\u{55}', '\u{308}',
+    '\u{30C}', '\u{75}', '\u{308}', '\u{30C}', '\u{55}', '\u{308}', '\u{300}', '\u{75}',
+    '\u{308}', '\u{300}', '\u{41}', '\u{308}', '\u{304}', '\u{61}', '\u{308}', '\u{304}',
+    '\u{41}', '\u{307}', '\u{304}', '\u{61}', '\u{307}', '\u{304}', '\u{C6}', '\u{304}',
+    '\u{E6}', '\u{304}', '\u{47}', '\u{30C}', '\u{67}', '\u{30C}', '\u{4B}', '\u{30C}',
+    '\u{6B}', '\u{30C}', '\u{4F}', '\u{328}', '\u{6F}', '\u{328}', '\u{4F}', '\u{328}',
+    '\u{304}', '\u{6F}', '\u{328}', '\u{304}', '\u{1B7}', '\u{30C}', '\u{292}', '\u{30C}',
+    '\u{6A}', '\u{30C}', '\u{47}', '\u{301}', '\u{67}', '\u{301}', '\u{4E}', '\u{300}',
+    '\u{6E}', '\u{300}', '\u{41}', '\u{30A}', '\u{301}', '\u{61}', '\u{30A}', '\u{301}',
+    '\u{C6}', '\u{301}', '\u{E6}', '\u{301}', '\u{D8}', '\u{301}', '\u{F8}', '\u{301}',
+    '\u{41}', '\u{30F}', '\u{61}', '\u{30F}', '\u{41}', '\u{311}', '\u{61}', '\u{311}',
+    '\u{45}', '\u{30F}', '\u{65}', '\u{30F}', '\u{45}', '\u{311}', '\u{65}', '\u{311}',
+    '\u{49}', '\u{30F}', '\u{69}', '\u{30F}', '\u{49}', '\u{311}', '\u{69}', '\u{311}',
+    '\u{4F}', '\u{30F}', '\u{6F}', '\u{30F}', '\u{4F}', '\u{311}', '\u{6F}', '\u{311}',
+    '\u{52}', '\u{30F}', '\u{72}', '\u{30F}', '\u{52}', '\u{311}', '\u{72}', '\u{311}',
+    '\u{55}', '\u{30F}', '\u{75}', '\u{30F}', '\u{55}', '\u{311}', '\u{75}', '\u{311}',
+    '\u{53}', '\u{326}', '\u{73}', '\u{326}', '\u{54}', '\u{326}', '\u{74}', '\u{326}',
+    '\u{48}', '\u{30C}', '\u{68}', '\u{30C}', '\u{41}', '\u{307}', '\u{61}', '\u{307}',
+    '\u{45}', '\u{327}', '\u{65}', '\u{327}', '\u{4F}', '\u{308}', '\u{304}', '\u{6F}',
+    '\u{308}', '\u{304}', '\u{4F}', '\u{303}', '\u{304}', '\u{6F}', '\u{303}', '\u{304}',
+    '\u{4F}', '\u{307}', '\u{6F}', '\u{307}', '\u{4F}', '\u{307}', '\u{304}', '\u{6F}',
+    '\u{307}', '\u{304}', '\u{59}', '\u{304}', '\u{79}', '\u{304}', '\u{300}', '\u{301}',
+    '\u{313}', '\u{308}', '\u{301}', '\u{2B9}', '\u{3B}', '\u{A8}', '\u{301}', '\u{391}',
+    '\u{301}', '\u{B7}', '\u{395}', '\u{301}', '\u{397}', '\u{301}', '\u{399}', '\u{301}',
+    '\u{39F}', '\u{301}', '\u{3A5}', '\u{301}', '\u{3A9}', '\u{301}', '\u{3B9}', '\u{308}',
+    '\u{301}', '\u{399}', '\u{308}', '\u{3A5}', '\u{308}', '\u{3B1}', '\u{301}', '\u{3B5}',
+    '\u{301}', '\u{3B7}', '\u{301}', '\u{3B9}', '\u{301}', '\u{3C5}', '\u{308}', '\u{301}',
+    '\u{3B9}', '\u{308}', '\u{3C5}', '\u{308}', '\u{3BF}', '\u{301}', '\u{3C5}', '\u{301}',
+    '\u{3C9}', '\u{301}', '\u{3D2}', '\u{301}', '\u{3D2}', '\u{308}', '\u{415}', '\u{300}',
+    '\u{415}', '\u{308}', '\u{413}', '\u{301}', '\u{406}', '\u{308}', '\u{41A}', '\u{301}',
+    '\u{418}', '\u{300}', '\u{423}', '\u{306}', '\u{418}', '\u{306}', '\u{438}', '\u{306}',
+    '\u{435}', '\u{300}', '\u{435}', '\u{308}', '\u{433}', '\u{301}', '\u{456}', '\u{308}',
+    '\u{43A}', '\u{301}', '\u{438}', '\u{300}', '\u{443}', '\u{306}', '\u{474}', '\u{30F}',
+    '\u{475}', '\u{30F}', '\u{416}', '\u{306}', '\u{436}', '\u{306}', '\u{410}', '\u{306}',
+    '\u{430}', '\u{306}', '\u{410}', '\u{308}', '\u{430}', '\u{308}', '\u{415}', '\u{306}',
+    '\u{435}', '\u{306}', '\u{4D8}', '\u{308}', '\u{4D9}', '\u{308}', '\u{416}', '\u{308}',
+    '\u{436}', '\u{308}', '\u{417}', '\u{308}', '\u{437}', '\u{308}', '\u{418}', '\u{304}',
+    '\u{438}', '\u{304}', '\u{418}', '\u{308}', '\u{438}', '\u{308}', '\u{41E}', '\u{308}',
+    '\u{43E}', '\u{308}', '\u{4E8}', '\u{308}', '\u{4E9}', '\u{308}', '\u{42D}', '\u{308}',
+    '\u{44D}', '\u{308}', '\u{423}', '\u{304}', '\u{443}', '\u{304}', '\u{423}', '\u{308}',
+    '\u{443}', '\u{308}', '\u{423}', '\u{30B}', '\u{443}', '\u{30B}', '\u{427}', '\u{308}',
+    '\u{447}', '\u{308}', '\u{42B}', '\u{308}', '\u{44B}', '\u{308}', '\u{627}', '\u{653}',
+    '\u{627}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{64A}', '\u{654}',
+    '\u{6D5}', '\u{654}', '\u{6C1}', '\u{654}', '\u{6D2}', '\u{654}', '\u{928}', '\u{93C}',
+    '\u{930}', '\u{93C}', '\u{933}', '\u{93C}', '\u{915}', '\u{93C}', '\u{916}', '\u{93C}',
+    '\u{917}', '\u{93C}', '\u{91C}', '\u{93C}', '\u{921}', '\u{93C}', '\u{922}', '\u{93C}',
+    '\u{92B}', '\u{93C}', '\u{92F}', '\u{93C}', '\u{9C7}', '\u{9BE}', '\u{9C7}', '\u{9D7}',
+    '\u{9A1}', '\u{9BC}', '\u{9A2}', '\u{9BC}', '\u{9AF}', '\u{9BC}', '\u{A32}', '\u{A3C}',
+    '\u{A38}', '\u{A3C}', '\u{A16}', '\u{A3C}', '\u{A17}', '\u{A3C}', '\u{A1C}', '\u{A3C}',
+    '\u{A2B}', '\u{A3C}', '\u{B47}', '\u{B56}', '\u{B47}', '\u{B3E}', '\u{B47}', '\u{B57}',
+    '\u{B21}', '\u{B3C}', '\u{B22}', '\u{B3C}', '\u{B92}', '\u{BD7}', '\u{BC6}', '\u{BBE}',
+    '\u{BC7}', '\u{BBE}', '\u{BC6}', '\u{BD7}', '\u{C46}', '\u{C56}', '\u{CBF}', '\u{CD5}',
+    '\u{CC6}', '\u{CD5}', '\u{CC6}', '\u{CD6}', '\u{CC6}', '\u{CC2}', '\u{CC6}', '\u{CC2}',
+    '\u{CD5}', '\u{D46}', '\u{D3E}', '\u{D47}', '\u{D3E}', '\u{D46}', '\u{D57}', '\u{DD9}',
+    '\u{DCA}', '\u{DD9}', '\u{DCF}', '\u{DD9}', '\u{DCF}', '\u{DCA}', '\u{DD9}', '\u{DDF}',
+    '\u{F42}', '\u{FB7}', '\u{F4C}', '\u{FB7}', '\u{F51}', '\u{FB7}', '\u{F56}', '\u{FB7}',
+    '\u{F5B}', '\u{FB7}', '\u{F40}', '\u{FB5}', '\u{F71}', '\u{F72}', '\u{F71}', '\u{F74}',
+    '\u{FB2}', '\u{F80}', '\u{FB3}', '\u{F80}', '\u{F71}', '\u{F80}', '\u{F92}', '\u{FB7}',
+    '\u{F9C}', '\u{FB7}', '\u{FA1}', '\u{FB7}', '\u{FA6}', '\u{FB7}', '\u{FAB}', '\u{FB7}',
+    '\u{F90}', '\u{FB5}', '\u{1025}', '\u{102E}', '\u{1B05}', '\u{1B35}', '\u{1B07}', '\u{1B35}',
+    '\u{1B09}', '\u{1B35}', '\u{1B0B}', '\u{1B35}', '\u{1B0D}', '\u{1B35}', '\u{1B11}', '\u{1B35}',
+    '\u{1B3A}', '\u{1B35}', '\u{1B3C}', '\u{1B35}', '\u{1B3E}', '\u{1B35}', '\u{1B3F}', '\u{1B35}',
+    '\u{1B42}', '\u{1B35}', '\u{41}', '\u{325}', '\u{61}', '\u{325}', '\u{42}', '\u{307}',
+    '\u{62}', '\u{307}', '\u{42}', '\u{323}', '\u{62}', '\u{323}', '\u{42}', '\u{331}',
+    '\u{62}', '\u{331}', '\u{43}', '\u{327}', '\u{301}', '\u{63}', '\u{327}', '\u{301}',
+    '\u{44}', '\u{307}', '\u{64}', '\u{307}', '\u{44}', '\u{323}', '\u{64}', '\u{323}',
+    '\u{44}', '\u{331}', '\u{64}', '\u{331}', '\u{44}', '\u{327}', '\u{64}', '\u{327}',
+    '\u{44}', '\u{32D}', '\u{64}', '\u{32D}', '\u{45}', '\u{304}', '\u{300}', '\u{65}',
+    '\u{304}', '\u{300}', '\u{45}', '\u{304}', '\u{301}', '\u{65}', '\u{304}', '\u{301}',
+    '\u{45}', '\u{32D}', '\u{65}', '\u{32D}', '\u{45}', '\u{330}', '\u{65}', '\u{330}',
+    '\u{45}', '\u{327}', '\u{306}', '\u{65}', '\u{327}', '\u{306}', '\u{46}', '\u{307}',
+    '\u{66}', '\u{307}', '\u{47}', '\u{304}', '\u{67}', '\u{304}', '\u{48}', '\u{307}',
+    '\u{68}', '\u{307}', '\u{48}', '\u{323}', '\u{68}', '\u{323}', '\u{48}', '\u{308}',
+    '\u{68}', '\u{308}', '\u{48}', '\u{327}', '\u{68}', '\u{327}', '\u{48}', '\u{32E}',
+    '\u{68}', '\u{32E}', '\u{49}', '\u{330}', '\u{69}', '\u{330}', '\u{49}', '\u{308}',
+    '\u{301}', '\u{69}', '\u{308}', '\u{301}', '\u{4B}', '\u{301}', '\u{6B}', '\u{301}',
+    '\u{4B}', '\u{323}', '\u{6B}', '\u{323}', '\u{4B}', '\u{331}', '\u{6B}', '\u{331}',
+    '\u{4C}', '\u{323}', '\u{6C}', '\u{323}', '\u{4C}', '\u{323}', '\u{304}', '\u{6C}',
+    '\u{323}', '\u{304}', '\u{4C}', '\u{331}', '\u{6C}', '\u{331}', '\u{4C}', '\u{32D}',
+    '\u{6C}', '\u{32D}', '\u{4D}', '\u{301}', '\u{6D}', '\u{301}', '\u{4D}', '\u{307}',
+    '\u{6D}', '\u{307}', '\u{4D}', '\u{323}', '\u{6D}', '\u{323}', '\u{4E}', '\u{307}',
+    '\u{6E}', '\u{307}', '\u{4E}', '\u{323}', '\u{6E}', '\u{323}', '\u{4E}', '\u{331}',
+    '\u{6E}', '\u{331}', '\u{4E}', '\u{32D}', '\u{6E}', '\u{32D}', '\u{4F}', '\u{303}',
+    '\u{301}', '\u{6F}', '\u{303}', '\u{301}', '\u{4F}', '\u{303}', '\u{308}', '\u{6F}',
+    '\u{303}', '\u{308}', '\u{4F}', '\u{304}', '\u{300}', '\u{6F}', '\u{304}', '\u{300}',
+    '\u{4F}', '\u{304}', '\u{301}', '\u{6F}', '\u{304}', '\u{301}', '\u{50}', '\u{301}',
+    '\u{70}', '\u{301}', '\u{50}', '\u{307}', '\u{70}', '\u{307}', '\u{52}', '\u{307}',
+    '\u{72}', '\u{307}', '\u{52}', '\u{323}', '\u{72}', '\u{323}', '\u{52}', '\u{323}',
+    '\u{304}', '\u{72}', '\u{323}', '\u{304}', '\u{52}', '\u{331}', '\u{72}', '\u{331}',
+    '\u{53}', '\u{307}', '\u{73}', '\u{307}', '\u{53}', '\u{323}', '\u{73}', '\u{323}',
+    '\u{53}', '\u{301}', '\u{307}', '\u{73}', '\u{301}', '\u{307}', '\u{53}', '\u{30C}',
+    '\u{307}', '\u{73}', '\u{30C}', '\u{307}', '\u{53}', '\u{323}', '\u{307}', '\u{73}',
+    '\u{323}', '\u{307}', '\u{54}', '\u{307}', '\u{74}', '\u{307}', '\u{54}', '\u{323}',
+    '\u{74}', '\u{323}', '\u{54}', '\u{331}', '\u{74}', '\u{331}', '\u{54}', '\u{32D}',
+    '\u{74}', '\u{32D}', '\u{55}', '\u{324}', '\u{75}', '\u{324}', '\u{55}', '\u{330}',
+    '\u{75}', '\u{330}', '\u{55}', '\u{32D}', '\u{75}', '\u{32D}', '\u{55}', '\u{303}',
+    '\u{301}', '\u{75}', '\u{303}', '\u{301}', '\u{55}', '\u{304}', '\u{308}', '\u{75}',
+    '\u{304}', '\u{308}', '\u{56}', '\u{303}', '\u{76}', '\u{303}', '\u{56}', '\u{323}',
+    '\u{76}', '\u{323}', '\u{57}', '\u{300}', '\u{77}', '\u{300}', '\u{57}', '\u{301}',
+    '\u{77}', '\u{301}', '\u{57}', '\u{308}', '\u{77}', '\u{308}', '\u{57}', '\u{307}',
+    '\u{77}', '\u{307}', '\u{57}', '\u{323}', '\u{77}', '\u{323}', '\u{58}', '\u{307}',
+    '\u{78}', '\u{307}', '\u{58}', '\u{308}', '\u{78}', '\u{308}', '\u{59}', '\u{307}',
+    '\u{79}', '\u{307}', '\u{5A}', '\u{302}', '\u{7A}', '\u{302}', '\u{5A}', '\u{323}',
+    '\u{7A}', '\u{323}', '\u{5A}', '\u{331}', '\u{7A}', '\u{331}', '\u{68}', '\u{331}',
+    '\u{74}', '\u{308}', '\u{77}', '\u{30A}', '\u{79}', '\u{30A}', '\u{17F}', '\u{307}',
+    '\u{41}', '\u{323}', '\u{61}', '\u{323}', '\u{41}', '\u{309}', '\u{61}', '\u{309}',
+    '\u{41}', '\u{302}', '\u{301}', '\u{61}', '\u{302}', '\u{301}', '\u{41}', '\u{302}',
+    '\u{300}', '\u{61}', '\u{302}', '\u{300}', '\u{41}', '\u{302}', '\u{309}', '\u{61}',
+    '\u{302}', '\u{309}', '\u{41}', '\u{302}', '\u{303}', '\u{61}', '\u{302}', '\u{303}',
+    '\u{41}', '\u{323}', '\u{302}', '\u{61}', '\u{323}', '\u{302}', '\u{41}', '\u{306}',
+    '\u{301}', '\u{61}', '\u{306}', '\u{301}', '\u{41}', '\u{306}', '\u{300}', '\u{61}',
+    '\u{306}', '\u{300}', '\u{41}', '\u{306}', '\u{309}', '\u{61}', '\u{306}', '\u{309}',
+    '\u{41}', '\u{306}', '\u{303}', '\u{61}', '\u{306}', '\u{303}', '\u{41}', '\u{323}',
+    '\u{306}', '\u{61}', '\u{323}', '\u{306}', '\u{45}', '\u{323}', '\u{65}', '\u{323}',
+    '\u{45}', '\u{309}', '\u{65}', '\u{309}', '\u{45}', '\u{303}', '\u{65}', '\u{303}',
+    '\u{45}', '\u{302}', '\u{301}', '\u{65}', '\u{302}', '\u{301}', '\u{45}', '\u{302}',
+    '\u{300}', '\u{65}', '\u{302}', '\u{300}', '\u{45}', '\u{302}', '\u{309}', '\u{65}',
+    '\u{302}', '\u{309}', '\u{45}', '\u{302}', '\u{303}', '\u{65}', '\u{302}', '\u{303}',
+    '\u{45}', '\u{323}', '\u{302}', '\u{65}', '\u{323}', '\u{302}', '\u{49}', '\u{309}',
+    '\u{69}', '\u{309}', '\u{49}', '\u{323}', '\u{69}', '\u{323}', '\u{4F}', '\u{323}',
+    '\u{6F}', '\u{323}', '\u{4F}', '\u{309}', '\u{6F}', '\u{309}', '\u{4F}', '\u{302}',
+    '\u{301}', '\u{6F}', '\u{302}', '\u{301}', '\u{4F}', '\u{302}', '\u{300}', '\u{6F}',
+    '\u{302}', '\u{300}', '\u{4F}', '\u{302}', '\u{309}', '\u{6F}', '\u{302}', '\u{309}',
+    '\u{4F}', '\u{302}', '\u{303}', '\u{6F}', '\u{302}', '\u{303}', '\u{4F}', '\u{323}',
+    '\u{302}', '\u{6F}', '\u{323}', '\u{302}', '\u{4F}', '\u{31B}', '\u{301}', '\u{6F}',
+    '\u{31B}', '\u{301}', '\u{4F}', '\u{31B}', '\u{300}', '\u{6F}', '\u{31B}', '\u{300}',
+    '\u{4F}', '\u{31B}', '\u{309}', '\u{6F}', '\u{31B}', '\u{309}', '\u{4F}', '\u{31B}',
+    '\u{303}', '\u{6F}', '\u{31B}', '\u{303}', '\u{4F}', '\u{31B}', '\u{323}', '\u{6F}',
+    '\u{31B}', '\u{323}', '\u{55}', '\u{323}', '\u{75}', '\u{323}', '\u{55}', '\u{309}',
+    '\u{75}', '\u{309}', '\u{55}', '\u{31B}', '\u{301}', '\u{75}', '\u{31B}', '\u{301}',
+    '\u{55}', '\u{31B}', '\u{300}', '\u{75}', '\u{31B}', '\u{300}', '\u{55}', '\u{31B}',
+    '\u{309}', '\u{75}', '\u{31B}', '\u{309}', '\u{55}', '\u{31B}', '\u{303}', '\u{75}',
+    '\u{31B}', '\u{303}', '\u{55}', '\u{31B}', '\u{323}', '\u{75}', '\u{31B}', '\u{323}',
+    '\u{59}', '\u{300}', '\u{79}', '\u{300}', '\u{59}', '\u{323}', '\u{79}', '\u{323}',
+    '\u{59}', '\u{309}', '\u{79}', '\u{309}', '\u{59}', '\u{303}', '\u{79}', '\u{303}',
+    '\u{3B1}', '\u{313}', '\u{3B1}', '\u{314}', '\u{3B1}', '\u{313}', '\u{300}', '\u{3B1}',
+    '\u{314}', '\u{300}', '\u{3B1}', '\u{313}', '\u{301}', '\u{3B1}', '\u{314}', '\u{301}',
+    '\u{3B1}', '\u{313}', '\u{342}', '\u{3B1}', '\u{314}', '\u{342}', '\u{391}', '\u{313}',
+    '\u{391}', '\u{314}', '\u{391}', '\u{313}', '\u{300}', '\u{391}', '\u{314}', '\u{300}',
+    '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}', '\u{301}', '\u{391}', '\u{313}',
+    '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3B5}', '\u{313}', '\u{3B5}', '\u{314}',
+    '\u{3B5}', '\u{313}', '\u{300}', '\u{3B5}', '\u{314}', '\u{300}', '\u{3B5}', '\u{313}',
+    '\u{301}', '\u{3B5}', '\u{314}', '\u{301}', '\u{395}', '\u{313}', '\u{395}', '\u{314}',
+    '\u{395}', '\u{313}', '\u{300}', '\u{395}', '\u{314}', '\u{300}', '\u{395}', '\u{313}',
+    '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3B7}', '\u{313}', '\u{3B7}', '\u{314}',
+    '\u{3B7}', '\u{313}', '\u{300}', '\u{3B7}', '\u{314}', '\u{300}', '\u{3B7}', '\u{313}',
+    '\u{301}', '\u{3B7}', '\u{314}', '\u{301}', '\u{3B7}', '\u{313}', '\u{342}', '\u{3B7}',
+    '\u{314}', '\u{342}', '\u{397}', '\u{313}', '\u{397}', '\u{314}', '\u{397}', '\u{313}',
+    '\u{300}', '\u{397}', '\u{314}', '\u{300}', '\u{397}', '\u{313}', '\u{301}', '\u{397}',
+    '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}', '\u{397}', '\u{314}', '\u{342}',
+    '\u{3B9}', '\u{313}', '\u{3B9}', '\u{314}', '\u{3B9}', '\u{313}', '\u{300}', '\u{3B9}',
+    '\u{314}', '\u{300}', '\u{3B9}', '\u{313}', '\u{301}', '\u{3B9}', '\u{314}', '\u{301}',
+    '\u{3B9}', '\u{313}', '\u{342}', '\u{3B9}', '\u{314}', '\u{342}', '\u{399}', '\u{313}',
+    '\u{399}', '\u{314}', '\u{399}', '\u{313}', '\u{300}', '\u{399}', '\u{314}', '\u{300}',
+    '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}', '\u{301}', '\u{399}', '\u{313}',
+    '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3BF}', '\u{313}', '\u{3BF}', '\u{314}',
+    '\u{3BF}', '\u{313}', '\u{300}', '\u{3BF}', '\u{314}', '\u{300}', '\u{3BF}', '\u{313}',
+    '\u{301}', '\u{3BF}', '\u{314}', '\u{301}', '\u{39F}', '\u{313}', '\u{39F}', '\u{314}',
+    '\u{39F}', '\u{313}', '\u{300}', '\u{39F}', '\u{314}', '\u{300}', '\u{39F}', '\u{313}',
+    '\u{301}', '\u{39F}', '\u{314}', '\u{301}', '\u{3C5}', '\u{313}', '\u{3C5}', '\u{314}',
+    '\u{3C5}', '\u{313}', '\u{300}', '\u{3C5}', '\u{314}', '\u{300}', '\u{3C5}', '\u{313}',
+    '\u{301}', '\u{3C5}', '\u{314}', '\u{301}', '\u{3C5}', '\u{313}', '\u{342}', '\u{3C5}',
+    '\u{314}', '\u{342}', '\u{3A5}', '\u{314}', '\u{3A5}', '\u{314}', '\u{300}', '\u{3A5}',
+    '\u{314}', '\u{301}', '\u{3A5}', '\u{314}', '\u{342}', '\u{3C9}', '\u{313}', '\u{3C9}',
+    '\u{314}', '\u{3C9}', '\u{313}', '\u{300}', '\u{3C9}', '\u{314}', '\u{300}', '\u{3C9}',
+    '\u{313}', '\u{301}', '\u{3C9}', '\u{314}', '\u{301}', '\u{3C9}', '\u{313}', '\u{342}',
+    '\u{3C9}', '\u{314}', '\u{342}', '\u{3A9}', '\u{313}', '\u{3A9}', '\u{314}', '\u{3A9}',
+    '\u{313}', '\u{300}', '\u{3A9}', '\u{314}', '\u{300}', '\u{3A9}', '\u{313}', '\u{301}',
+    '\u{3A9}', '\u{314}', '\u{301}', '\u{3A9}', '\u{313}', '\u{342}', '\u{3A9}', '\u{314}',
+    '\u{342}', '\u{3B1}', '\u{300}', '\u{3B1}', '\u{301}', '\u{3B5}', '\u{300}', '\u{3B5}',
+    '\u{301}', '\u{3B7}', '\u{300}', '\u{3B7}', '\u{301}', '\u{3B9}', '\u{300}', '\u{3B9}',
+    '\u{301}', '\u{3BF}', '\u{300}', '\u{3BF}', '\u{301}', '\u{3C5}', '\u{300}', '\u{3C5}',
+    '\u{301}', '\u{3C9}', '\u{300}', '\u{3C9}', '\u{301}', '\u{3B1}', '\u{313}', '\u{345}',
+    '\u{3B1}', '\u{314}', '\u{345}', '\u{3B1}', '\u{313}', '\u{300}', '\u{345}', '\u{3B1}',
+    '\u{314}', '\u{300}', '\u{345}', '\u{3B1}', '\u{313}', '\u{301}', '\u{345}', '\u{3B1}',
+    '\u{314}', '\u{301}', '\u{345}', '\u{3B1}', '\u{313}', '\u{342}', '\u{345}', '\u{3B1}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}', '\u{345}', '\u{391}', '\u{314}',
+    '\u{345}', '\u{391}', '\u{313}', '\u{300}', '\u{345}', '\u{391}', '\u{314}', '\u{300}',
+    '\u{345}', '\u{391}', '\u{313}', '\u{301}', '\u{345}', '\u{391}', '\u{314}', '\u{301}',
+    '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}', '\u{391}', '\u{314}', '\u{342}',
+    '\u{345}', '\u{3B7}', '\u{313}', '\u{345}', '\u{3B7}', '\u{314}', '\u{345}', '\u{3B7}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3B7}', '\u{314}', '\u{300}', '\u{345}', '\u{3B7}',
+    '\u{313}', '\u{301}', '\u{345}', '\u{3B7}', '\u{314}', '\u{301}', '\u{345}', '\u{3B7}',
+    '\u{313}', '\u{342}', '\u{345}', '\u{3B7}', '\u{314}', '\u{342}', '\u{345}', '\u{397}',
+    '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}', '\u{397}', '\u{313}', '\u{300}',
+    '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}', '\u{397}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}', '\u{397}', '\u{313}', '\u{342}',
+    '\u{345}', '\u{397}', '\u{314}', '\u{342}', '\u{345}', '\u{3C9}', '\u{313}', '\u{345}',
+    '\u{3C9}', '\u{314}', '\u{345}', '\u{3C9}', '\u{313}', '\u{300}', '\u{345}', '\u{3C9}',
+    '\u{314}', '\u{300}', '\u{345}', '\u{3C9}', '\u{313}', '\u{301}', '\u{345}', '\u{3C9}',
+    '\u{314}', '\u{301}', '\u{345}', '\u{3C9}', '\u{313}', '\u{342}', '\u{345}', '\u{3C9}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3A9}', '\u{313}', '\u{345}', '\u{3A9}', '\u{314}',
+    '\u{345}', '\u{3A9}', '\u{313}', '\u{300}', '\u{345}', '\u{3A9}', '\u{314}', '\u{300}',
+    '\u{345}', '\u{3A9}', '\u{313}', '\u{301}', '\u{345}', '\u{3A9}', '\u{314}', '\u{301}',
+    '\u{345}', '\u{3A9}', '\u{313}', '\u{342}', '\u{345}', '\u{3A9}', '\u{314}', '\u{342}',
+    '\u{345}', '\u{3B1}', '\u{306}', '\u{3B1}', '\u{304}', '\u{3B1}', '\u{300}', '\u{345}',
+    '\u{3B1}', '\u{345}', '\u{3B1}', '\u{301}', '\u{345}', '\u{3B1}', '\u{342}', '\u{3B1}',
+    '\u{342}', '\u{345}', '\u{391}', '\u{306}', '\u{391}', '\u{304}', '\u{391}', '\u{300}',
+    '\u{391}', '\u{301}', '\u{391}', '\u{345}', '\u{3B9}', '\u{A8}', '\u{342}', '\u{3B7}',
+    '\u{300}', '\u{345}', '\u{3B7}', '\u{345}', '\u{3B7}', '\u{301}', '\u{345}', '\u{3B7}',
+    '\u{342}', '\u{3B7}', '\u{342}', '\u{345}', '\u{395}', '\u{300}', '\u{395}', '\u{301}',
+    '\u{397}', '\u{300}', '\u{397}', '\u{301}', '\u{397}', '\u{345}', '\u{1FBF}', '\u{300}',
+    '\u{1FBF}', '\u{301}', '\u{1FBF}', '\u{342}', '\u{3B9}', '\u{306}', '\u{3B9}', '\u{304}',
+    '\u{3B9}', '\u{308}', '\u{300}', '\u{3B9}', '\u{308}', '\u{301}', '\u{3B9}', '\u{342}',
+    '\u{3B9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}', '\u{399}', '\u{304}', '\u{399}',
+    '\u{300}', '\u{399}', '\u{301}', '\u{1FFE}', '\u{300}', '\u{1FFE}', '\u{301}', '\u{1FFE}',
+    '\u{342}', '\u{3C5}', '\u{306}', '\u{3C5}', '\u{304}', '\u{3C5}', '\u{308}', '\u{300}',
+    '\u{3C5}', '\u{308}', '\u{301}', '\u{3C1}', '\u{313}', '\u{3C1}', '\u{314}', '\u{3C5}',
+    '\u{342}', '\u{3C5}', '\u{308}', '\u{342}', '\u{3A5}', '\u{306}', '\u{3A5}', '\u{304}',
+    '\u{3A5}', '\u{300}', '\u{3A5}', '\u{301}', '\u{3A1}', '\u{314}', '\u{A8}', '\u{300}',
+    '\u{A8}', '\u{301}', '\u{60}', '\u{3C9}', '\u{300}', '\u{345}', '\u{3C9}', '\u{345}',
+    '\u{3C9}', '\u{301}', '\u{345}', '\u{3C9}', '\u{342}', '\u{3C9}', '\u{342}', '\u{345}',
+    '\u{39F}', '\u{300}', '\u{39F}', '\u{301}', '\u{3A9}', '\u{300}', '\u{3A9}', '\u{301}',
+    '\u{3A9}', '\u{345}', '\u{B4}', '\u{2002}', '\u{2003}', '\u{3A9}', '\u{4B}', '\u{41}',
+    '\u{30A}', '\u{2190}', '\u{338}', '\u{2192}', '\u{338}', '\u{2194}', '\u{338}', '\u{21D0}',
+    '\u{338}', '\u{21D4}', '\u{338}', '\u{21D2}', '\u{338}', '\u{2203}', '\u{338}', '\u{2208}',
+    '\u{338}', '\u{220B}', '\u{338}', '\u{2223}', '\u{338}', '\u{2225}', '\u{338}', '\u{223C}',
+    '\u{338}', '\u{2243}', '\u{338}', '\u{2245}', '\u{338}', '\u{2248}', '\u{338}', '\u{3D}',
+    '\u{338}', '\u{2261}', '\u{338}', '\u{224D}', '\u{338}', '\u{3C}', '\u{338}', '\u{3E}',
+    '\u{338}', '\u{2264}', '\u{338}', '\u{2265}', '\u{338}', '\u{2272}', '\u{338}', '\u{2273}',
+    '\u{338}', '\u{2276}', '\u{338}', '\u{2277}', '\u{338}', '\u{227A}', '\u{338}', '\u{227B}',
+    '\u{338}', '\u{2282}', '\u{338}', '\u{2283}', '\u{338}', '\u{2286}', '\u{338}', '\u{2287}',
+    '\u{338}', '\u{22A2}', '\u{338}', '\u{22A8}', '\u{338}', '\u{22A9}', '\u{338}', '\u{22AB}',
+    '\u{338}', '\u{227C}', '\u{338}', '\u{227D}', '\u{338}', '\u{2291}', '\u{338}', '\u{2292}',
+    '\u{338}', '\u{22B2}', '\u{338}', '\u{22B3}', '\u{338}', '\u{22B4}', '\u{338}', '\u{22B5}',
+    '\u{338}', '\u{3008}', '\u{3009}', '\u{2ADD}', '\u{338}', '\u{304B}', '\u{3099}', '\u{304D}',
+    '\u{3099}', '\u{304F}', '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}', '\u{3099}', '\u{3055}',
+    '\u{3099}', '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}', '\u{305B}', '\u{3099}', '\u{305D}',
+    '\u{3099}', '\u{305F}', '\u{3099}', '\u{3061}', '\u{3099}', '\u{3064}', '\u{3099}', '\u{3066}',
+    '\u{3099}', '\u{3068}', '\u{3099}', '\u{306F}', '\u{3099}', '\u{306F}', '\u{309A}', '\u{3072}',
+    '\u{3099}', '\u{3072}', '\u{309A}', '\u{3075}', '\u{3099}', '\u{3075}', '\u{309A}', '\u{3078}',
+    '\u{3099}', '\u{3078}', '\u{309A}', '\u{307B}', '\u{3099}', '\u{307B}', '\u{309A}', '\u{3046}',
+    '\u{3099}', '\u{309D}', '\u{3099}', '\u{30AB}', '\u{3099}', '\u{30AD}', '\u{3099}', '\u{30AF}',
+    '\u{3099}', '\u{30B1}', '\u{3099}', '\u{30B3}', '\u{3099}', '\u{30B5}', '\u{3099}', '\u{30B7}',
+    '\u{3099}', '\u{30B9}', '\u{3099}', '\u{30BB}', '\u{3099}', '\u{30BD}', '\u{3099}', '\u{30BF}',
+    '\u{3099}', '\u{30C1}', '\u{3099}', '\u{30C4}', '\u{3099}', '\u{30C6}', '\u{3099}', '\u{30C8}',
+    '\u{3099}', '\u{30CF}', '\u{3099}', '\u{30CF}', '\u{309A}', '\u{30D2}', '\u{3099}', '\u{30D2}',
+    '\u{309A}', '\u{30D5}', '\u{3099}', '\u{30D5}', '\u{309A}', '\u{30D8}', '\u{3099}', '\u{30D8}',
+    '\u{309A}', '\u{30DB}', '\u{3099}', '\u{30DB}', '\u{309A}', '\u{30A6}', '\u{3099}', '\u{30EF}',
+    '\u{3099}', '\u{30F0}', '\u{3099}', '\u{30F1}', '\u{3099}', '\u{30F2}', '\u{3099}', '\u{30FD}',
+    '\u{3099}', '\u{8C48}', '\u{66F4}', '\u{8ECA}', '\u{8CC8}', '\u{6ED1}', '\u{4E32}', '\u{53E5}',
+    '\u{9F9C}', '\u{9F9C}', '\u{5951}', '\u{91D1}', '\u{5587}', '\u{5948}', '\u{61F6}', '\u{7669}',
+    '\u{7F85}', '\u{863F}', '\u{87BA}', '\u{88F8}', '\u{908F}', '\u{6A02}', '\u{6D1B}', '\u{70D9}',
+    '\u{73DE}', '\u{843D}', '\u{916A}', '\u{99F1}', '\u{4E82}', '\u{5375}', '\u{6B04}', '\u{721B}',
+    '\u{862D}', '\u{9E1E}', '\u{5D50}', '\u{6FEB}', '\u{85CD}', '\u{8964}', '\u{62C9}', '\u{81D8}',
+    '\u{881F}', '\u{5ECA}', '\u{6717}', '\u{6D6A}', '\u{72FC}', '\u{90CE}', '\u{4F86}', '\u{51B7}',
+    '\u{52DE}', '\u{64C4}', '\u{6AD3}', '\u{7210}', '\u{76E7}', '\u{8001}', '\u{8606}', '\u{865C}',
+    '\u{8DEF}', '\u{9732}', '\u{9B6F}', '\u{9DFA}', '\u{788C}', '\u{797F}', '\u{7DA0}', '\u{83C9}',
+    '\u{9304}', '\u{9E7F}', '\u{8AD6}', '\u{58DF}', '\u{5F04}', '\u{7C60}', '\u{807E}', '\u{7262}',
+    '\u{78CA}', '\u{8CC2}', '\u{96F7}', '\u{58D8}', '\u{5C62}', '\u{6A13}', '\u{6DDA}', '\u{6F0F}',
+    '\u{7D2F}', '\u{7E37}', '\u{964B}', '\u{52D2}', '\u{808B}', '\u{51DC}', '\u{51CC}', '\u{7A1C}',
+    '\u{7DBE}', '\u{83F1}', '\u{9675}', '\u{8B80}', '\u{62CF}', '\u{6A02}', '\u{8AFE}', '\u{4E39}',
+    '\u{5BE7}', '\u{6012}', '\u{7387}', '\u{7570}', '\u{5317}', '\u{78FB}', '\u{4FBF}', '\u{5FA9}',
+    '\u{4E0D}', '\u{6CCC}', '\u{6578}', '\u{7D22}', '\u{53C3}', '\u{585E}', '\u{7701}', '\u{8449}',
+    '\u{8AAA}', '\u{6BBA}', '\u{8FB0}', '\u{6C88}', '\u{62FE}', '\u{82E5}', '\u{63A0}', '\u{7565}',
+    '\u{4EAE}', '\u{5169}', '\u{51C9}', '\u{6881}', '\u{7CE7}', '\u{826F}', '\u{8AD2}', '\u{91CF}',
+    '\u{52F5}', '\u{5442}', '\u{5973}', '\u{5EEC}', '\u{65C5}', '\u{6FFE}', '\u{792A}', '\u{95AD}',
+    '\u{9A6A}', '\u{9E97}', '\u{9ECE}', '\u{529B}', '\u{66C6}', '\u{6B77}', '\u{8F62}', '\u{5E74}',
+    '\u{6190}', '\u{6200}', '\u{649A}', '\u{6F23}', '\u{7149}', '\u{7489}', '\u{79CA}', '\u{7DF4}',
+    '\u{806F}', '\u{8F26}', '\u{84EE}', '\u{9023}', '\u{934A}', '\u{5217}', '\u{52A3}', '\u{54BD}',
+    '\u{70C8}', '\u{88C2}', '\u{8AAA}', '\u{5EC9}', '\u{5FF5}', '\u{637B}', '\u{6BAE}', '\u{7C3E}',
+    '\u{7375}', '\u{4EE4}', '\u{56F9}', '\u{5BE7}', '\u{5DBA}', '\u{601C}', '\u{73B2}', '\u{7469}',
+    '\u{7F9A}', '\u{8046}', '\u{9234}', '\u{96F6}', '\u{9748}', '\u{9818}', '\u{4F8B}', '\u{79AE}',
+    '\u{91B4}', '\u{96B8}', '\u{60E1}', '\u{4E86}', '\u{50DA}', '\u{5BEE}', '\u{5C3F}', '\u{6599}',
+    '\u{6A02}', '\u{71CE}', '\u{7642}', '\u{84FC}', '\u{907C}', '\u{9F8D}', '\u{6688}', '\u{962E}',
+    '\u{5289}', '\u{677B}', '\u{67F3}', '\u{6D41}', '\u{6E9C}', '\u{7409}', '\u{7559}', '\u{786B}',
+    '\u{7D10}', '\u{985E}', '\u{516D}', '\u{622E}', '\u{9678}', '\u{502B}', '\u{5D19}', '\u{6DEA}',
+    '\u{8F2A}', '\u{5F8B}', '\u{6144}', '\u{6817}', '\u{7387}', '\u{9686}', '\u{5229}', '\u{540F}',
+    '\u{5C65}', '\u{6613}', '\u{674E}', '\u{68A8}', '\u{6CE5}', '\u{7406}', '\u{75E2}', '\u{7F79}',
+    '\u{88CF}', '\u{88E1}', '\u{91CC}', '\u{96E2}', '\u{533F}', '\u{6EBA}', '\u{541D}', '\u{71D0}',
+    '\u{7498}', '\u{85FA}', '\u{96A3}', '\u{9C57}', '\u{9E9F}', '\u{6797}', '\u{6DCB}', '\u{81E8}',
+    '\u{7ACB}', '\u{7B20}', '\u{7C92}', '\u{72C0}', '\u{7099}', '\u{8B58}', '\u{4EC0}', '\u{8336}',
+    '\u{523A}', '\u{5207}', '\u{5EA6}', '\u{62D3}', '\u{7CD6}', '\u{5B85}', '\u{6D1E}', '\u{66B4}',
+    '\u{8F3B}', '\u{884C}', '\u{964D}', '\u{898B}', '\u{5ED3}', '\u{5140}', '\u{55C0}', '\u{585A}',
+    '\u{6674}', '\u{51DE}', '\u{732A}', '\u{76CA}', '\u{793C}', '\u{795E}', '\u{7965}', '\u{798F}',
+    '\u{9756}', '\u{7CBE}', '\u{7FBD}', '\u{8612}', '\u{8AF8}', '\u{9038}', '\u{90FD}', '\u{98EF}',
+    '\u{98FC}', '\u{9928}', '\u{9DB4}', '\u{90DE}', '\u{96B7}', '\u{4FAE}', '\u{50E7}', '\u{514D}',
+    '\u{52C9}', '\u{52E4}', '\u{5351}', '\u{559D}', '\u{5606}', '\u{5668}', '\u{5840}', '\u{58A8}',
+    '\u{5C64}', '\u{5C6E}', '\u{6094}', '\u{6168}', '\u{618E}', '\u{61F2}', '\u{654F}', '\u{65E2}',
+    '\u{6691}', '\u{6885}', '\u{6D77}', '\u{6E1A}', '\u{6F22}', '\u{716E}', '\u{722B}', '\u{7422}',
+    '\u{7891}', '\u{793E}', '\u{7949}', '\u{7948}', '\u{7950}', '\u{7956}', '\u{795D}', '\u{798D}',
+    '\u{798E}', '\u{7A40}', '\u{7A81}', '\u{7BC0}', '\u{7DF4}', '\u{7E09}', '\u{7E41}', '\u{7F72}',
+    '\u{8005}', '\u{81ED}', '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8B01}',
+    '\u{8B39}', '\u{8CD3}', '\u{8D08}', '\u{8FB6}', '\u{9038}', '\u{96E3}', '\u{97FF}', '\u{983B}',
+    '\u{6075}', '\u{242EE}', '\u{8218}', '\u{4E26}', '\u{51B5}', '\u{5168}', '\u{4F80}', '\u{5145}',
+    '\u{5180}', '\u{52C7}', '\u{52FA}', '\u{559D}', '\u{5555}', '\u{5599}', '\u{55E2}', '\u{585A}',
+    '\u{58B3}', '\u{5944}', '\u{5954}', '\u{5A62}', '\u{5B28}', '\u{5ED2}', '\u{5ED9}', '\u{5F69}',
+    '\u{5FAD}', '\u{60D8}', '\u{614E}', '\u{6108}', '\u{618E}', '\u{6160}', '\u{61F2}', '\u{6234}',
+    '\u{63C4}', '\u{641C}', '\u{6452}', '\u{6556}', '\u{6674}', '\u{6717}', '\u{671B}', '\u{6756}',
+    '\u{6B79}', '\u{6BBA}', '\u{6D41}', '\u{6EDB}', '\u{6ECB}', '\u{6F22}', '\u{701E}', '\u{716E}',
+    '\u{77A7}', '\u{7235}', '\u{72AF}', '\u{732A}', '\u{7471}', '\u{7506}', '\u{753B}', '\u{761D}',
+    '\u{761F}', '\u{76CA}', '\u{76DB}', '\u{76F4}', '\u{774A}', '\u{7740}', '\u{78CC}', '\u{7AB1}',
+    '\u{7BC0}', '\u{7C7B}', '\u{7D5B}', '\u{7DF4}', '\u{7F3E}', '\u{8005}', '\u{8352}', '\u{83EF}',
+    '\u{8779}', '\u{8941}', '\u{8986}', '\u{8996}', '\u{8ABF}', '\u{8AF8}', '\u{8ACB}', '\u{8B01}',
+    '\u{8AFE}', '\u{8AED}', '\u{8B39}', '\u{8B8A}', '\u{8D08}', '\u{8F38}', '\u{9072}', '\u{9199}',
+    '\u{9276}', '\u{967C}', '\u{96E3}', '\u{9756}', '\u{97DB}', '\u{97FF}', '\u{980B}', '\u{983B}',
+    '\u{9B12}', '\u{9F9C}', '\u{2284A}', '\u{22844}', '\u{233D5}', '\u{3B9D}', '\u{4018}', '\u{4039}',
+    '\u{25249}', '\u{25CD0}', '\u{27ED3}', '\u{9F43}', '\u{9F8E}', '\u{5D9}', '\u{5B4}', '\u{5F2}',
+    '\u{5B7}', '\u{5E9}', '\u{5C1}', '\u{5E9}', '\u{5C2}', '\u{5E9}', '\u{5BC}', '\u{5C1}',
+    '\u{5E9}', '\u{5BC}', '\u{5C2}', '\u{5D0}', '\u{5B7}', '\u{5D0}', '\u{5B8}', '\u{5D0}',
+    '\u{5BC}', '\u{5D1}', '\u{5BC}', '\u{5D2}', '\u{5BC}', '\u{5D3}', '\u{5BC}', '\u{5D4}',
+    '\u{5BC}', '\u{5D5}', '\u{5BC}', '\u{5D6}', '\u{5BC}', '\u{5D8}', '\u{5BC}', '\u{5D9}',
+    '\u{5BC}', '\u{5DA}', '\u{5BC}', '\u{5DB}', '\u{5BC}', '\u{5DC}', '\u{5BC}', '\u{5DE}',
+    '\u{5BC}', '\u{5E0}', '\u{5BC}', '\u{5E1}', '\u{5BC}', '\u{5E3}', '\u{5BC}', '\u{5E4}',
+    '\u{5BC}', '\u{5E6}', '\u{5BC}', '\u{5E7}', '\u{5BC}', '\u{5E8}', '\u{5BC}', '\u{5E9}',
+    '\u{5BC}', '\u{5EA}', '\u{5BC}', '\u{5D5}', '\u{5B9}', '\u{5D1}', '\u{5BF}', '\u{5DB}',
+    '\u{5BF}', '\u{5E4}', '\u{5BF}', '\u{11099}', '\u{110BA}', '\u{1109B}', '\u{110BA}', '\u{110A5}',
+    '\u{110BA}', '\u{11131}', '\u{11127}', '\u{11132}', '\u{11127}', '\u{11347}', '\u{1133E}', '\u{11347}',
+    '\u{11357}', '\u{114B9}', '\u{114BA}', '\u{114B9}', '\u{114B0}', '\u{114B9}', '\u{114BD}', '\u{115B8}',
+    '\u{115AF}', '\u{115B9}', '\u{115AF}', '\u{11935}', '\u{11930}', '\u{1D157}', '\u{1D165}', '\u{1D158}',
+    '\u{1D165}', '\u{1D158}', '\u{1D165}', '\u{1D16E}', '\u{1D158}', '\u{1D165}', '\u{1D16F}', '\u{1D158}',
+    '\u{1D165}', '\u{1D170}', '\u{1D158}', '\u{1D165}', '\u{1D171}', '\u{1D158}', '\u{1D165}', '\u{1D172}',
+    '\u{1D1B9}', '\u{1D165}', '\u{1D1BA}', '\u{1D165}', '\u{1D1B9}', '\u{1D165}', '\u{1D16E}', '\u{1D1BA}',
+    '\u{1D165}', '\u{1D16E}', '\u{1D1B9}', '\u{1D165}', '\u{1D16F}', '\u{1D1BA}', '\u{1D165}', '\u{1D16F}',
+    '\u{4E3D}', '\u{4E38}', '\u{4E41}', '\u{20122}', '\u{4F60}', '\u{4FAE}', '\u{4FBB}', '\u{5002}',
+    '\u{507A}', '\u{5099}', '\u{50E7}', '\u{50CF}', '\u{349E}', '\u{2063A}', '\u{514D}', '\u{5154}',
+    '\u{5164}', '\u{5177}', '\u{2051C}', '\u{34B9}', '\u{5167}', '\u{518D}', '\u{2054B}', '\u{5197}',
+    '\u{51A4}', '\u{4ECC}', '\u{51AC}', '\u{51B5}', '\u{291DF}', '\u{51F5}', '\u{5203}', '\u{34DF}',
+    '\u{523B}', '\u{5246}', '\u{5272}', '\u{5277}', '\u{3515}', '\u{52C7}', '\u{52C9}', '\u{52E4}',
+    '\u{52FA}', '\u{5305}', '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}', '\u{535A}', '\u{5373}',
+    '\u{537D}', '\u{537F}', '\u{537F}', '\u{537F}', '\u{20A2C}', '\u{7070}', '\u{53CA}', '\u{53DF}',
+    '\u{20B63}', '\u{53EB}', '\u{53F1}', '\u{5406}', '\u{549E}', '\u{5438}', '\u{5448}', '\u{5468}',
+    '\u{54A2}', '\u{54F6}', '\u{5510}', '\u{5553}', '\u{5563}', '\u{5584}', '\u{5584}', '\u{5599}',
+    '\u{55AB}', '\u{55B3}', '\u{55C2}', '\u{5716}', '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}',
+    '\u{5207}', '\u{58EE}', '\u{57CE}', '\u{57F4}', '\u{580D}', '\u{578B}', '\u{5832}', '\u{5831}',
+    '\u{58AC}', '\u{214E4}', '\u{58F2}', '\u{58F7}', '\u{5906}', '\u{591A}', '\u{5922}', '\u{5962}',
+    '\u{216A8}', '\u{216EA}', '\u{59EC}', '\u{5A1B}', '\u{5A27}', '\u{59D8}', '\u{5A66}', '\u{36EE}',
+    '\u{36FC}', '\u{5B08}', '\u{5B3E}', '\u{5B3E}', '\u{219C8}', '\u{5BC3}', '\u{5BD8}', '\u{5BE7}',
+    '\u{5BF3}', '\u{21B18}', '\u{5BFF}', '\u{5C06}', '\u{5F53}', '\u{5C22}', '\u{3781}', '\u{5C60}',
+    '\u{5C6E}', '\u{5CC0}', '\u{5C8D}', '\u{21DE4}', '\u{5D43}', '\u{21DE6}', '\u{5D6E}', '\u{5D6B}',
+    '\u{5D7C}', '\u{5DE1}', '\u{5DE2}', '\u{382F}', '\u{5DFD}', '\u{5E28}', '\u{5E3D}', '\u{5E69}',
+    '\u{3862}', '\u{22183}', '\u{387C}', '\u{5EB0}', '\u{5EB3}', '\u{5EB6}', '\u{5ECA}', '\u{2A392}',
+    '\u{5EFE}', '\u{22331}', '\u{22331}', '\u{8201}', '\u{5F22}', '\u{5F22}', '\u{38C7}', '\u{232B8}',
+    '\u{261DA}', '\u{5F62}', '\u{5F6B}', '\u{38E3}', '\u{5F9A}', '\u{5FCD}', '\u{5FD7}', '\u{5FF9}',
+    '\u{6081}', '\u{393A}', '\u{391C}', '\u{6094}', '\u{226D4}', '\u{60C7}', '\u{6148}', '\u{614C}',
+    '\u{614E}', '\u{614C}', '\u{617A}', '\u{618E}', '\u{61B2}', '\u{61A4}', '\u{61AF}', '\u{61DE}',
+    '\u{61F2}', '\u{61F6}', '\u{6210}', '\u{621B}', '\u{625D}', '\u{62B1}', '\u{62D4}', '\u{6350}',
+    '\u{22B0C}', '\u{633D}', '\u{62FC}', '\u{6368}', '\u{6383}', '\u{63E4}', '\u{22BF1}', '\u{6422}',
+    '\u{63C5}', '\u{63A9}', '\u{3A2E}', '\u{6469}', '\u{647E}', '\u{649D}', '\u{6477}', '\u{3A6C}',
+    '\u{654F}', '\u{656C}', '\u{2300A}', '\u{65E3}', '\u{66F8}', '\u{6649}', '\u{3B19}', '\u{6691}',
+    '\u{3B08}', '\u{3AE4}', '\u{5192}', '\u{5195}', '\u{6700}', '\u{669C}', '\u{80AD}', '\u{43D9}',
+    '\u{6717}', '\u{671B}', '\u{6721}', '\u{675E}', '\u{6753}', '\u{233C3}', '\u{3B49}', '\u{67FA}',
+    '\u{6785}', '\u{6852}', '\u{6885}', '\u{2346D}', '\u{688E}', '\u{681F}', '\u{6914}', '\u{3B9D}',
+    '\u{6942}', '\u{69A3}', '\u{69EA}', '\u{6AA8}', '\u{236A3}', '\u{6ADB}', '\u{3C18}', '\u{6B21}',
+    '\u{238A7}', '\u{6B54}', '\u{3C4E}', '\u{6B72}', '\u{6B9F}', '\u{6BBA}', '\u{6BBB}', '\u{23A8D}',
+    '\u{21D0B}', '\u{23AFA}', '\u{6C4E}', '\u{23CBC}', '\u{6CBF}', '\u{6CCD}', '\u{6C67}', '\u{6D16}',
+    '\u{6D3E}', '\u{6D77}', '\u{6D41}', '\u{6D69}', '\u{6D78}', '\u{6D85}', '\u{23D1E}', '\u{6D34}',
+    '\u{6E2F}', '\u{6E6E}', '\u{3D33}', '\u{6ECB}', '\u{6EC7}', '\u{23ED1}', '\u{6DF9}', '\u{6F6E}',
+    '\u{23F5E}', '\u{23F8E}', '\u{6FC6}', '\u{7039}', '\u{701E}', '\u{701B}', '\u{3D96}', '\u{704A}',
+    '\u{707D}', '\u{7077}', '\u{70AD}', '\u{20525}', '\u{7145}', '\u{24263}', '\u{719C}', '\u{243AB}',
+    '\u{7228}', '\u{7235}', '\u{7250}', '\u{24608}', '\u{7280}', '\u{7295}', '\u{24735}', '\u{24814}',
+    '\u{737A}', '\u{738B}', '\u{3EAC}', '\u{73A5}', '\u{3EB8}', '\u{3EB8}', '\u{7447}', '\u{745C}',
+    '\u{7471}', '\u{7485}', '\u{74CA}', '\u{3F1B}', '\u{7524}', '\u{24C36}', '\u{753E}', '\u{24C92}',
+    '\u{7570}', '\u{2219F}', '\u{7610}', '\u{24FA1}', '\u{24FB8}', '\u{25044}', '\u{3FFC}', '\u{4008}',
+    '\u{76F4}', '\u{250F3}', '\u{250F2}', '\u{25119}', '\u{25133}', '\u{771E}', '\u{771F}', '\u{771F}',
+    '\u{774A}', '\u{4039}', '\u{778B}', '\u{4046}', '\u{4096}', '\u{2541D}', '\u{784E}', '\u{788C}',
+    '\u{78CC}', '\u{40E3}', '\u{25626}', '\u{7956}', '\u{2569A}', '\u{256C5}', '\u{798F}', '\u{79EB}',
+    '\u{412F}', '\u{7A40}', '\u{7A4A}', '\u{7A4F}', '\u{2597C}', '\u{25AA7}', '\u{25AA7}', '\u{7AEE}',
+    '\u{4202}', '\u{25BAB}', '\u{7BC6}', '\u{7BC9}', '\u{4227}', '\u{25C80}', '\u{7CD2}', '\u{42A0}',
+    '\u{7CE8}', '\u{7CE3}', '\u{7D00}', '\u{25F86}', '\u{7D63}', '\u{4301}', '\u{7DC7}', '\u{7E02}',
+    '\u{7E45}', '\u{4334}', '\u{26228}', '\u{26247}', '\u{4359}', '\u{262D9}', '\u{7F7A}', '\u{2633E}',
+    '\u{7F95}', '\u{7FFA}', '\u{8005}', '\u{264DA}', '\u{26523}', '\u{8060}', '\u{265A8}', '\u{8070}',
+    '\u{2335F}', '\u{43D5}', '\u{80B2}', '\u{8103}', '\u{440B}', '\u{813E}', '\u{5AB5}', '\u{267A7}',
+    '\u{267B5}', '\u{23393}', '\u{2339C}', '\u{8201}', '\u{8204}', '\u{8F9E}', '\u{446B}', '\u{8291}',
+    '\u{828B}', '\u{829D}', '\u{52B3}', '\u{82B1}', '\u{82B3}', '\u{82BD}', '\u{82E6}', '\u{26B3C}',
+    '\u{82E5}', '\u{831D}', '\u{8363}', '\u{83AD}', '\u{8323}', '\u{83BD}', '\u{83E7}', '\u{8457}',
+    '\u{8353}', '\u{83CA}', '\u{83CC}', '\u{83DC}', '\u{26C36}', '\u{26D6B}', '\u{26CD5}', '\u{452B}',
+    '\u{84F1}', '\u{84F3}', '\u{8516}', '\u{273CA}', '\u{8564}', '\u{26F2C}', '\u{455D}', '\u{4561}',
+    '\u{26FB1}', '\u{270D2}', '\u{456B}', '\u{8650}', '\u{865C}', '\u{8667}', '\u{8669}', '\u{86A9}',
+    '\u{8688}', '\u{870E}', '\u{86E2}', '\u{8779}', '\u{8728}', '\u{876B}', '\u{8786}', '\u{45D7}',
+    '\u{87E1}', '\u{8801}', '\u{45F9}', '\u{8860}', '\u{8863}', '\u{27667}', '\u{88D7}', '\u{88DE}',
+    '\u{4635}', '\u{88FA}', '\u{34BB}', '\u{278AE}', '\u{27966}', '\u{46BE}', '\u{46C7}', '\u{8AA0}',
+    '\u{8AED}', '\u{8B8A}', '\u{8C55}', '\u{27CA8}', '\u{8CAB}', '\u{8CC1}', '\u{8D1B}', '\u{8D77}',
+    '\u{27F2F}', '\u{20804}', '\u{8DCB}', '\u{8DBC}', '\u{8DF0}', '\u{208DE}', '\u{8ED4}', '\u{8F38}',
+    '\u{285D2}', '\u{285ED}', '\u{9094}', '\u{90F1}', '\u{9111}', '\u{2872E}', '\u{911B}', '\u{9238}',
+    '\u{92D7}', '\u{92D8}', '\u{927C}', '\u{93F9}', '\u{9415}', '\u{28BFA}', '\u{958B}', '\u{4995}',
+    '\u{95B7}', '\u{28D77}', '\u{49E6}', '\u{96C3}', '\u{5DB2}', '\u{9723}', '\u{29145}', '\u{2921A}',
+    '\u{4A6E}', '\u{4A76}', '\u{97E0}', '\u{2940A}', '\u{4AB2}', '\u{29496}', '\u{980B}', '\u{980B}',
+    '\u{9829}', '\u{295B6}', '\u{98E2}', '\u{4B33}', '\u{9929}', '\u{99A7}', '\u{99C2}', '\u{99FE}',
+    '\u{4BCE}', '\u{29B30}', '\u{9B12}', '\u{9C40}', '\u{9CFD}', '\u{4CCE}', '\u{4CED}', '\u{9D67}',
+    '\u{2A0CE}', '\u{4CF8}', '\u{2A105}', '\u{2A20E}', '\u{2A291}', '\u{9EBB}', '\u{4D56}', '\u{9EF9}',
+    '\u{9EFE}', '\u{9F05}', '\u{9F0F}', '\u{9F16}', '\u{9F3B}', '\u{2A600}',
+];
+
+/// Canonical composition pairs sorted by (first, second): (first, second, composite).
+pub(super) static COMPOSITION: &[(u32, u32, u32)] = &[
+    (0x3C, 0x338, 0x226E), (0x3D, 0x338, 0x2260), (0x3E, 0x338, 0x226F),
+    (0x41, 0x300, 0xC0), (0x41, 0x301, 0xC1), (0x41, 0x302, 0xC2),
+    (0x41, 0x303, 0xC3), (0x41, 0x304, 0x100), (0x41, 0x306, 0x102),
+    (0x41, 0x307, 0x226), (0x41, 0x308, 0xC4), (0x41, 0x309, 0x1EA2),
+    (0x41, 0x30A, 0xC5), (0x41, 0x30C, 0x1CD), (0x41, 0x30F, 0x200),
+    (0x41, 0x311, 0x202), (0x41, 0x323, 0x1EA0), (0x41, 0x325, 0x1E00),
+    (0x41, 0x328, 0x104), (0x42, 0x307, 0x1E02), (0x42, 0x323, 0x1E04),
+    (0x42, 0x331, 0x1E06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10A), (0x43, 0x30C, 0x10C), (0x43, 0x327, 0xC7),
+    (0x44, 0x307, 0x1E0A), (0x44, 0x30C, 0x10E), (0x44, 0x323, 0x1E0C),
+    (0x44, 0x327, 0x1E10), (0x44, 0x32D, 0x1E12), (0x44, 0x331, 0x1E0E),
+    (0x45, 0x300, 0xC8), (0x45, 0x301, 0xC9), (0x45, 0x302, 0xCA),
+    (0x45, 0x303, 0x1EBC), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114),
+    (0x45, 0x307, 0x116), (0x45, 0x308, 0xCB), (0x45, 0x309, 0x1EBA),
+    (0x45, 0x30C, 0x11A), (0x45, 0x30F, 0x204), (0x45, 0x311, 0x206),
+    (0x45, 0x323, 0x1EB8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118),
+    (0x45, 0x32D, 0x1E18), (0x45, 0x330, 0x1E1A), (0x46, 0x307, 0x1E1E),
+    (0x47, 0x301, 0x1F4), (0x47, 0x302, 0x11C), (0x47, 0x304, 0x1E20),
+    (0x47, 0x306, 0x11E), (0x47, 0x307, 0x120), (0x47, 0x30C, 0x1E6),
+    (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1E22),
+    (0x48, 0x308, 0x1E26), (0x48, 0x30C, 0x21E), (0x48, 0x323, 0x1E24),
+    (0x48, 0x327, 0x1E28), (0x48, 0x32E, 0x1E2A), (0x49, 0x300, 0xCC),
+    (0x49, 0x301, 0xCD), (0x49, 0x302, 0xCE), (0x49, 0x303, 0x128),
+    (0x49, 0x304, 0x12A), (0x49, 0x306, 0x12C), (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xCF), (0x49, 0x309, 0x1EC8), (0x49, 0x30C, 0x1CF),
+    (0x49, 0x30F, 0x208), (0x49, 0x311, 0x20A), (0x49, 0x323, 0x1ECA),
+    (0x49, 0x328, 0x12E), (0x49, 0x330, 0x1E2C), (0x4A, 0x302, 0x134),
+    (0x4B, 0x301, 0x1E30), (0x4B, 0x30C, 0x1E8), (0x4B, 0x323, 0x1E32),
+    (0x4B, 0x327, 0x136), (0x4B, 0x331, 0x1E34), (0x4C, 0x301, 0x139),
+    (0x4C, 0x30C, 0x13D), (0x4C, 0x323, 0x1E36), (0x4C, 0x327, 0x13B),
+    (0x4C, 0x32D, 0x1E3C), (0x4C, 0x331, 0x1E3A), (0x4D, 0x301, 0x1E3E),
+    (0x4D, 0x307, 0x1E40), (0x4D, 0x323, 0x1E42), (0x4E, 0x300, 0x1F8),
+    (0x4E, 0x301, 0x143), (0x4E, 0x303, 0xD1), (0x4E, 0x307, 0x1E44),
+    (0x4E, 0x30C, 0x147), (0x4E, 0x323, 0x1E46), (0x4E, 0x327, 0x145),
+    (0x4E, 0x32D, 0x1E4A), (0x4E, 0x331, 0x1E48), (0x4F, 0x300, 0xD2),
+    (0x4F, 0x301, 0xD3), (0x4F, 0x302, 0xD4), (0x4F, 0x303, 0xD5),
+    (0x4F, 0x304, 0x14C), (0x4F, 0x306, 0x14E), (0x4F, 0x307, 0x22E),
+    (0x4F, 0x308, 0xD6), (0x4F, 0x309, 0x1ECE), (0x4F, 0x30B, 0x150),
+    (0x4F, 0x30C, 0x1D1), (0x4F, 0x30F, 0x20C), (0x4F, 0x311, 0x20E),
+    (0x4F, 0x31B, 0x1A0), (0x4F, 0x323, 0x1ECC), (0x4F, 0x328, 0x1EA),
+    (0x50, 0x301, 0x1E54), (0x50, 0x307, 0x1E56), (0x52, 0x301, 0x154),
+    (0x52, 0x307, 0x1E58), (0x52, 0x30C, 0x158), (0x52, 0x30F, 0x210),
+    (0x52, 0x311, 0x212), (0x52, 0x323, 0x1E5A), (0x52, 0x327, 0x156),
+    (0x52, 0x331, 0x1E5E), (0x53, 0x301, 0x15A), (0x53, 0x302, 0x15C),
+    (0x53, 0x307, 0x1E60), (0x53, 0x30C, 0x160), (0x53, 0x323, 0x1E62),
+    (0x53, 0x326, 0x218), (0x53, 0x327, 0x15E), (0x54, 0x307, 0x1E6A),
+    (0x54, 0x30C, 0x164), (0x54, 0x323, 0x1E6C), (0x54, 0x326, 0x21A),
+    (0x54, 0x327, 0x162), (0x54, 0x32D, 0x1E70), (0x54, 0x331, 0x1E6E),
+    (0x55, 0x300, 0xD9), (0x55, 0x301, 0xDA), (0x55, 0x302, 0xDB),
+    (0x55, 0x303, 0x168), (0x55, 0x304, 0x16A), (0x55, 0x306, 0x16C),
+    (0x55, 0x308, 0xDC), (0x55, 0x309, 0x1EE6), (0x55, 0x30A, 0x16E),
+    (0x55, 0x30B, 0x170), (0x55, 0x30C, 0x1D3), (0x55, 0x30F, 0x214),
+    (0x55, 0x311, 0x216), (0x55, 0x31B, 0x1AF), (0x55, 0x323, 0x1EE4),
+    (0x55, 0x324, 0x1E72), (0x55, 0x328, 0x172), (0x55, 0x32D, 0x1E76),
+    (0x55, 0x330, 0x1E74), (0x56, 0x303, 0x1E7C), (0x56, 0x323, 0x1E7E),
+    (0x57, 0x300, 0x1E80), (0x57, 0x301, 0x1E82), (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1E86), (0x57, 0x308, 0x1E84), (0x57, 0x323, 0x1E88),
+    (0x58, 0x307, 0x1E8A), (0x58, 0x308, 0x1E8C), (0x59, 0x300, 0x1EF2),
+    (0x59, 0x301, 0xDD), (0x59, 0x302, 0x176), (0x59, 0x303, 0x1EF8),
+    (0x59, 0x304, 0x232), (0x59, 0x307, 0x1E8E), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1EF6), (0x59, 0x323, 0x1EF4), (0x5A, 0x301, 0x179),
+    (0x5A, 0x302, 0x1E90), (0x5A, 0x307, 0x17B), (0x5A, 0x30C, 0x17D),
+    (0x5A, 0x323, 0x1E92), (0x5A, 0x331, 0x1E94), (0x61, 0x300, 0xE0),
+    (0x61, 0x301, 0xE1), (0x61, 0x302, 0xE2), (0x61, 0x303, 0xE3),
+    (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227),
+    (0x61, 0x308, 0xE4), (0x61, 0x309, 0x1EA3), (0x61, 0x30A, 0xE5),
+    (0x61, 0x30C, 0x1CE), (0x61, 0x30F, 0x201), (0x61, 0x311, 0x203),
+    (0x61, 0x323, 0x1EA1), (0x61, 0x325, 0x1E01), (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1E03), (0x62, 0x323, 0x1E05), (0x62, 0x331, 0x1E07),
+    (0x63, 0x301, 0x107), (0x63, 0x302, 0x109), (0x63, 0x307, 0x10B),
+    (0x63, 0x30C, 0x10D), (0x63, 0x327, 0xE7), (0x64, 0x307, 0x1E0B),
+    (0x64, 0x30C, 0x10F), (0x64, 0x323, 0x1E0D), (0x64, 0x327, 0x1E11),
+    (0x64, 0x32D, 0x1E13), (0x64, 0x331, 0x1E0F), (0x65, 0x300, 0xE8),
+    (0x65, 0x301, 0xE9), (0x65, 0x302, 0xEA), (0x65, 0x303, 0x1EBD),
+    (0x65, 0x304, 0x113), (0x65, 0x306, 0x115), (0x65, 0x307, 0x117),
+    (0x65, 0x308, 0xEB), (0x65, 0x309, 0x1EBB), (0x65, 0x30C, 0x11B),
+    (0x65, 0x30F, 0x205), (0x65, 0x311, 0x207), (0x65, 0x323, 0x1EB9),
+    (0x65, 0x327, 0x229), (0x65, 0x328, 0x119), (0x65, 0x32D, 0x1E19),
+    (0x65, 0x330, 0x1E1B), (0x66, 0x307, 0x1E1F), (0x67, 0x301, 0x1F5),
+    (0x67, 0x302, 0x11D), (0x67, 0x304, 0x1E21), (0x67, 0x306, 0x11F),
+    (0x67, 0x307, 0x121), (0x67, 0x30C, 0x1E7), (0x67, 0x327, 0x123),
+    (0x68, 0x302, 0x125), (0x68, 0x307, 0x1E23), (0x68, 0x308, 0x1E27),
+    (0x68, 0x30C, 0x21F), (0x68, 0x323, 0x1E25), (0x68, 0x327, 0x1E29),
+    (0x68, 0x32E, 0x1E2B), (0x68, 0x331, 0x1E96), (0x69, 0x300, 0xEC),
+    (0x69, 0x301, 0xED), (0x69, 0x302, 0xEE), (0x69, 0x303, 0x129),
+    (0x69, 0x304, 0x12B), (0x69, 0x306, 0x12D), (0x69, 0x308, 0xEF),
+    (0x69, 0x309, 0x1EC9), (0x69, 0x30C, 0x1D0), (0x69, 0x30F, 0x209),
+    (0x69, 0x311, 0x20B), (0x69, 0x323, 0x1ECB), (0x69, 0x328, 0x12F),
+    (0x69, 0x330, 0x1E2D), (0x6A, 0x302, 0x135), (0x6A, 0x30C, 0x1F0),
+    (0x6B, 0x301, 0x1E31), (0x6B, 0x30C, 0x1E9), (0x6B, 0x323, 0x1E33),
+    (0x6B, 0x327, 0x137), (0x6B, 0x331, 0x1E35), (0x6C, 0x301, 0x13A),
+    (0x6C, 0x30C, 0x13E), (0x6C, 0x323, 0x1E37), (0x6C, 0x327, 0x13C),
+    (0x6C, 0x32D, 0x1E3D), (0x6C, 0x331, 0x1E3B), (0x6D, 0x301, 0x1E3F),
+    (0x6D, 0x307, 0x1E41), (0x6D, 0x323, 0x1E43), (0x6E, 0x300, 0x1F9),
+    (0x6E, 0x301, 0x144), (0x6E, 0x303, 0xF1), (0x6E, 0x307, 0x1E45),
+    (0x6E, 0x30C, 0x148), (0x6E, 0x323, 0x1E47), (0x6E, 0x327, 0x146),
+    (0x6E, 0x32D, 0x1E4B), (0x6E, 0x331, 0x1E49), (0x6F, 0x300, 0xF2),
+    (0x6F, 0x301, 0xF3), (0x6F, 0x302, 0xF4), (0x6F, 0x303, 0xF5),
+    (0x6F, 0x304, 0x14D), (0x6F, 0x306, 0x14F), (0x6F, 0x307, 0x22F),
+    (0x6F, 0x308, 0xF6), (0x6F, 0x309, 0x1ECF), (0x6F, 0x30B, 0x151),
+    (0x6F, 0x30C, 0x1D2), (0x6F, 0x30F, 0x20D), (0x6F, 0x311, 0x20F),
+    (0x6F, 0x31B, 0x1A1), (0x6F, 0x323, 0x1ECD), (0x6F, 0x328, 0x1EB),
+    (0x70, 0x301, 0x1E55), (0x70, 0x307, 0x1E57), (0x72, 0x301, 0x155),
+    (0x72, 0x307, 0x1E59), (0x72, 0x30C, 0x159), (0x72, 0x30F, 0x211),
+    (0x72, 0x311, 0x213), (0x72, 0x323, 0x1E5B), (0x72, 0x327, 0x157),
+    (0x72, 0x331, 0x1E5F), (0x73, 0x301, 0x15B), (0x73, 0x302, 0x15D),
+    (0x73, 0x307, 0x1E61), (0x73, 0x30C, 0x161), (0x73, 0x323, 0x1E63),
+    (0x73, 0x326, 0x219), (0x73, 0x327, 0x15F), (0x74, 0x307, 0x1E6B),
+    (0x74, 0x308, 0x1E97), (0x74, 0x30C, 0x165), (0x74, 0x323, 0x1E6D),
+    (0x74, 0x326, 0x21B), (0x74, 0x327, 0x163), (0x74, 0x32D, 0x1E71),
+    (0x74, 0x331, 0x1E6F), (0x75, 0x300, 0xF9), (0x75, 0x301, 0xFA),
+    (0x75, 0x302, 0xFB), (0x75, 0x303, 0x169), (0x75, 0x304, 0x16B),
+    (0x75, 0x306, 0x16D), (0x75, 0x308, 0xFC), (0x75, 0x309, 0x1EE7),
+    (0x75, 0x30A, 0x16F), (0x75, 0x30B, 0x171), (0x75, 0x30C, 0x1D4),
+    (0x75, 0x30F, 0x215), (0x75, 0x311, 0x217), (0x75, 0x31B, 0x1B0),
+    (0x75, 0x323, 0x1EE5), (0x75, 0x324, 0x1E73), (0x75, 0x328, 0x173),
+    (0x75, 0x32D, 0x1E77), (0x75, 0x330, 0x1E75), (0x76, 0x303, 0x1E7D),
+    (0x76, 0x323, 0x1E7F), (0x77, 0x300, 0x1E81), (0x77, 0x301, 0x1E83),
+    (0x77, 0x302, 0x175), (0x77, 0x307, 0x1E87), (0x77, 0x308, 0x1E85),
+    (0x77, 0x30A, 0x1E98), (0x77, 0x323, 0x1E89), (0x78, 0x307, 0x1E8B),
+    (0x78, 0x308, 0x1E8D), (0x79, 0x300, 0x1EF3), (0x79, 0x301, 0xFD),
+    (0x79, 0x302, 0x177), (0x79, 0x303, 0x1EF9), (0x79, 0x304, 0x233),
+    (0x79, 0x307, 0x1E8F), (0x79, 0x308, 0xFF), (0x79, 0x309, 0x1EF7),
+    (0x79, 0x30A, 0x1E99), (0x79, 0x323, 0x1EF5), (0x7A, 0x301, 0x17A),
+    (0x7A, 0x302, 0x1E91), (0x7A, 0x307, 0x17C), (0x7A, 0x30C, 0x17E),
+    (0x7A, 0x323, 0x1E93), (0x7A, 0x331, 0x1E95), (0xA8, 0x300, 0x1FED),
+    (0xA8, 0x301, 0x385), (0xA8, 0x342, 0x1FC1), (0xC2, 0x300, 0x1EA6),
+    (0xC2, 0x301, 0x1EA4), (0xC2, 0x303, 0x1EAA), (0xC2, 0x309, 0x1EA8),
+    (0xC4, 0x304, 0x1DE), (0xC5, 0x301, 0x1FA), (0xC6, 0x301, 0x1FC),
+    (0xC6, 0x304, 0x1E2), (0xC7, 0x301, 0x1E08), (0xCA, 0x300, 0x1EC0),
+    (0xCA, 0x301, 0x1EBE), (0xCA, 0x303, 0x1EC4), (0xCA, 0x309, 0x1EC2),
+    (0xCF, 0x301, 0x1E2E), (0xD4, 0x300, 0x1ED2), (0xD4, 0x301, 0x1ED0),
+    (0xD4, 0x303, 0x1ED6), (0xD4, 0x309, 0x1ED4), (0xD5, 0x301, 0x1E4C),
+    (0xD5, 0x304, 0x22C), (0xD5, 0x308, 0x1E4E), (0xD6, 0x304, 0x22A),
+    (0xD8, 0x301, 0x1FE), (0xDC, 0x300, 0x1DB), (0xDC, 0x301, 0x1D7),
+    (0xDC, 0x304, 0x1D5), (0xDC, 0x30C, 0x1D9), (0xE2, 0x300, 0x1EA7),
+    (0xE2, 0x301, 0x1EA5), (0xE2, 0x303, 0x1EAB), (0xE2, 0x309, 0x1EA9),
+    (0xE4, 0x304, 0x1DF), (0xE5, 0x301, 0x1FB), (0xE6, 0x301, 0x1FD),
+    (0xE6, 0x304, 0x1E3), (0xE7, 0x301, 0x1E09), (0xEA, 0x300, 0x1EC1),
+    (0xEA, 0x301, 0x1EBF), (0xEA, 0x303, 0x1EC5), (0xEA, 0x309, 0x1EC3),
+    (0xEF, 0x301, 0x1E2F), (0xF4, 0x300, 0x1ED3), (0xF4, 0x301, 0x1ED1),
+    (0xF4, 0x303, 0x1ED7), (0xF4, 0x309, 0x1ED5), (0xF5, 0x301, 0x1E4D),
+    (0xF5, 0x304, 0x22D), (0xF5, 0x308, 0x1E4F), (0xF6, 0x304, 0x22B),
+    (0xF8, 0x301, 0x1FF), (0xFC, 0x300, 0x1DC), (0xFC, 0x301, 0x1D8),
+    (0xFC, 0x304, 0x1D6), (0xFC, 0x30C, 0x1DA), (0x102, 0x300, 0x1EB0),
+    (0x102, 0x301, 0x1EAE), (0x102, 0x303, 0x1EB4), (0x102, 0x309, 0x1EB2),
+    (0x103, 0x300, 0x1EB1), (0x103, 0x301, 0x1EAF), (0x103, 0x303, 0x1EB5),
+    (0x103, 0x309, 0x1EB3), (0x112, 0x300, 0x1E14), (0x112, 0x301, 0x1E16),
+    (0x113, 0x300, 0x1E15), (0x113, 0x301, 0x1E17), (0x14C, 0x300, 0x1E50),
+    (0x14C, 0x301, 0x1E52), (0x14D, 0x300, 0x1E51), (0x14D, 0x301, 0x1E53),
+    (0x15A, 0x307, 0x1E64), (0x15B, 0x307, 0x1E65), (0x160, 0x307, 0x1E66),
+    (0x161, 0x307, 0x1E67), (0x168, 0x301, 0x1E78), (0x169, 0x301, 0x1E79),
+    (0x16A, 0x308, 0x1E7A), (0x16B, 0x308, 0x1E7B), (0x17F, 0x307, 0x1E9B),
+    (0x1A0, 0x300, 0x1EDC), (0x1A0, 0x301, 0x1EDA), (0x1A0, 0x303, 0x1EE0),
+    (0x1A0, 0x309, 0x1EDE), (0x1A0, 0x323, 0x1EE2), (0x1A1, 0x300, 0x1EDD),
+    (0x1A1, 0x301, 0x1EDB), (0x1A1, 0x303, 0x1EE1), (0x1A1, 0x309, 0x1EDF),
+    (0x1A1, 0x323, 0x1EE3), (0x1AF, 0x300, 0x1EEA), (0x1AF, 0x301, 0x1EE8),
+    (0x1AF, 0x303, 0x1EEE), (0x1AF, 0x309, 0x1EEC), (0x1AF, 0x323, 0x1EF0),
+    (0x1B0, 0x300, 0x1EEB), (0x1B0, 0x301, 0x1EE9), (0x1B0, 0x303, 0x1EEF),
+    (0x1B0, 0x309, 0x1EED), (0x1B0, 0x323, 0x1EF1), (0x1B7, 0x30C, 0x1EE),
+    (0x1EA, 0x304, 0x1EC), (0x1EB, 0x304, 0x1ED), (0x226, 0x304, 0x1E0),
+    (0x227, 0x304, 0x1E1), (0x228, 0x306, 0x1E1C), (0x229, 0x306, 0x1E1D),
+    (0x22E, 0x304, 0x230), (0x22F, 0x304, 0x231), (0x292, 0x30C, 0x1EF),
+    (0x391, 0x300, 0x1FBA), (0x391, 0x301, 0x386), (0x391, 0x304, 0x1FB9),
+    (0x391, 0x306, 0x1FB8), (0x391, 0x313, 0x1F08), (0x391, 0x314, 0x1F09),
+    (0x391, 0x345, 0x1FBC), (0x395, 0x300, 0x1FC8), (0x395, 0x301, 0x388),
+    (0x395, 0x313, 0x1F18), (0x395, 0x314, 0x1F19), (0x397, 0x300, 0x1FCA),
+    (0x397, 0x301, 0x389), (0x397, 0x313, 0x1F28), (0x397, 0x314, 0x1F29),
+    (0x397, 0x345, 0x1FCC), (0x399, 0x300, 0x1FDA), (0x399, 0x301, 0x38A),
+    (0x399, 0x304, 0x1FD9), (0x399, 0x306, 0x1FD8), (0x399, 0x308, 0x3AA),
+    (0x399, 0x313, 0x1F38), (0x399, 0x314, 0x1F39), (0x39F, 0x300, 0x1FF8),
+    (0x39F, 0x301, 0x38C), (0x39F, 0x313, 0x1F48), (0x39F, 0x314, 0x1F49),
+    (0x3A1, 0x314, 0x1FEC), (0x3A5, 0x300, 0x1FEA), (0x3A5, 0x301, 0x38E),
+    (0x3A5, 0x304, 0x1FE9), (0x3A5, 0x306, 0x1FE8), (0x3A5, 0x308, 0x3AB),
+    (0x3A5, 0x314, 0x1F59), (0x3A9, 0x300, 0x1FFA), (0x3A9, 0x301, 0x38F),
+    (0x3A9, 0x313, 0x1F68), (0x3A9, 0x314, 0x1F69), (0x3A9, 0x345, 0x1FFC),
+    (0x3AC, 0x345, 0x1FB4), (0x3AE, 0x345, 0x1FC4), (0x3B1, 0x300, 0x1F70),
+    (0x3B1, 0x301, 0x3AC), (0x3B1, 0x304, 0x1FB1), (0x3B1, 0x306, 0x1FB0),
+    (0x3B1, 0x313, 0x1F00), (0x3B1, 0x314, 0x1F01), (0x3B1, 0x342, 0x1FB6),
+    (0x3B1, 0x345, 0x1FB3), (0x3B5, 0x300, 0x1F72), (0x3B5, 0x301, 0x3AD),
+    (0x3B5, 0x313, 0x1F10), (0x3B5, 0x314, 0x1F11), (0x3B7, 0x300, 0x1F74),
+    (0x3B7, 0x301, 0x3AE), (0x3B7, 0x313, 0x1F20), (0x3B7, 0x314, 0x1F21),
+    (0x3B7, 0x342, 0x1FC6), (0x3B7, 0x345, 0x1FC3), (0x3B9, 0x300, 0x1F76),
+    (0x3B9, 0x301, 0x3AF), (0x3B9, 0x304, 0x1FD1), (0x3B9, 0x306, 0x1FD0),
+    (0x3B9, 0x308, 0x3CA), (0x3B9, 0x313, 0x1F30), (0x3B9, 0x314, 0x1F31),
+    (0x3B9, 0x342, 0x1FD6), (0x3BF, 0x300, 0x1F78), (0x3BF, 0x301, 0x3CC),
+    (0x3BF, 0x313, 0x1F40), (0x3BF, 0x314, 0x1F41), (0x3C1, 0x313, 0x1FE4),
+    (0x3C1, 0x314, 0x1FE5), (0x3C5, 0x300, 0x1F7A), (0x3C5, 0x301, 0x3CD),
+    (0x3C5, 0x304, 0x1FE1), (0x3C5, 0x306, 0x1FE0), (0x3C5, 0x308, 0x3CB),
+    (0x3C5, 0x313, 0x1F50), (0x3C5, 0x314, 0x1F51), (0x3C5, 0x342, 0x1FE6),
+    (0x3C9, 0x300, 0x1F7C), (0x3C9, 0x301, 0x3CE), (0x3C9, 0x313, 0x1F60),
+    (0x3C9, 0x314, 0x1F61), (0x3C9, 0x342, 0x1FF6), (0x3C9, 0x345, 0x1FF3),
+    (0x3CA, 0x300, 0x1FD2), (0x3CA, 0x301, 0x390), (0x3CA, 0x342, 0x1FD7),
+    (0x3CB, 0x300, 0x1FE2), (0x3CB, 0x301, 0x3B0), (0x3CB, 0x342, 0x1FE7),
+    (0x3CE, 0x345, 0x1FF4), (0x3D2, 0x301, 0x3D3), (0x3D2, 0x308, 0x3D4),
+    (0x406, 0x308, 0x407), (0x410, 0x306, 0x4D0), (0x410, 0x308, 0x4D2),
+    (0x413, 0x301, 0x403), (0x415, 0x300, 0x400), (0x415, 0x306, 0x4D6),
+    (0x415, 0x308, 0x401), (0x416, 0x306, 0x4C1), (0x416, 0x308, 0x4DC),
+    (0x417, 0x308, 0x4DE), (0x418, 0x300, 0x40D), (0x418, 0x304, 0x4E2),
+    (0x418, 0x306, 0x419), (0x418, 0x308, 0x4E4), (0x41A, 0x301, 0x40C),
+    (0x41E, 0x308, 0x4E6), (0x423, 0x304, 0x4EE), (0x423, 0x306, 0x40E),
+    (0x423, 0x308, 0x4F0), (0x423, 0x30B, 0x4F2), (0x427, 0x308, 0x4F4),
+    (0x42B, 0x308, 0x4F8), (0x42D, 0x308, 0x4EC), (0x430, 0x306, 0x4D1),
+    (0x430, 0x308, 0x4D3), (0x433, 0x301, 0x453), (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4D7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4C2),
+    (0x436, 0x308, 0x4DD), (0x437, 0x308, 0x4DF), (0x438, 0x300, 0x45D),
+    (0x438, 0x304, 0x4E3), (0x438, 0x306, 0x439), (0x438, 0x308, 0x4E5),
+    (0x43A, 0x301, 0x45C), (0x43E, 0x308, 0x4E7), (0x443, 0x304, 0x4EF),
+    (0x443, 0x306, 0x45E), (0x443, 0x308, 0x4F1), (0x443, 0x30B, 0x4F3),
+    (0x447, 0x308, 0x4F5), (0x44B, 0x308, 0x4F9), (0x44D, 0x308, 0x4ED),
+    (0x456, 0x308, 0x457), (0x474, 0x30F, 0x476), (0x475, 0x30F, 0x477),
+    (0x4D8, 0x308, 0x4DA), (0x4D9, 0x308, 0x4DB), (0x4E8, 0x308, 0x4EA),
+    (0x4E9, 0x308, 0x4EB), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623),
+    (0x627, 0x655, 0x625), (0x648, 0x654, 0x624), (0x64A, 0x654, 0x626),
+    (0x6C1, 0x654, 0x6C2), (0x6D2, 0x654, 0x6D3), (0x6D5, 0x654, 0x6C0),
+    (0x928, 0x93C, 0x929), (0x930, 0x93C, 0x931), (0x933, 0x93C, 0x934),
+    (0x9C7, 0x9BE, 0x9CB), (0x9C7, 0x9D7, 0x9CC), (0xB47, 0xB3E, 0xB4B),
+    (0xB47, 0xB56, 0xB48), (0xB47, 0xB57, 0xB4C), (0xB92, 0xBD7, 0xB94),
+    (0xBC6, 0xBBE, 0xBCA), (0xBC6, 0xBD7, 0xBCC), (0xBC7, 0xBBE, 0xBCB),
+    (0xC46, 0xC56, 0xC48), (0xCBF, 0xCD5, 0xCC0), (0xCC6, 0xCC2, 0xCCA),
+    (0xCC6, 0xCD5, 0xCC7), (0xCC6, 0xCD6, 0xCC8), (0xCCA, 0xCD5, 0xCCB),
+    (0xD46, 0xD3E, 0xD4A), (0xD46, 0xD57, 0xD4C), (0xD47, 0xD3E, 0xD4B),
+    (0xDD9, 0xDCA, 0xDDA), (0xDD9, 0xDCF, 0xDDC), (0xDD9, 0xDDF, 0xDDE),
+    (0xDDC, 0xDCA, 0xDDD), (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E), (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40), (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x304, 0x1E38), (0x1E37, 0x304, 0x1E39),
+    (0x1E5A, 0x304, 0x1E5C), (0x1E5B, 0x304, 0x1E5D), (0x1E62, 0x307, 0x1E68),
+    (0x1E63, 0x307, 0x1E69), (0x1EA0, 0x302, 0x1EAC), (0x1EA0, 0x306, 0x1EB6),
+    (0x1EA1, 0x302, 0x1EAD), (0x1EA1, 0x306, 0x1EB7), (0x1EB8, 0x302, 0x1EC6),
+    (0x1EB9, 0x302, 0x1EC7), (0x1ECC, 0x302, 0x1ED8), (0x1ECD, 0x302, 0x1ED9),
+    (0x1F00, 0x300, 0x1F02), (0x1F00, 0x301, 0x1F04), (0x1F00, 0x342, 0x1F06),
+    (0x1F00, 0x345, 0x1F80), (0x1F01, 0x300, 0x1F03), (0x1F01, 0x301, 0x1F05),
+    (0x1F01, 0x342, 0x1F07), (0x1F01, 0x345, 0x1F81), (0x1F02, 0x345, 0x1F82),
+    (0x1F03, 0x345, 0x1F83), (0x1F04, 0x345, 0x1F84), (0x1F05, 0x345, 0x1F85),
+    (0x1F06, 0x345, 0x1F86), (0x1F07, 0x345, 0x1F87), (0x1F08, 0x300, 0x1F0A),
+    (0x1F08, 0x301, 0x1F0C), (0x1F08, 0x342, 0x1F0E), (0x1F08, 0x345, 0x1F88),
+    (0x1F09, 0x300, 0x1F0B), (0x1F09, 0x301, 0x1F0D), (0x1F09, 0x342, 0x1F0F),
+    (0x1F09, 0x345, 0x1F89), (0x1F0A, 0x345, 0x1F8A), (0x1F0B, 0x345, 0x1F8B),
+    (0x1F0C, 0x345, 0x1F8C), (0x1F0D, 0x345, 0x1F8D), (0x1F0E, 0x345, 0x1F8E),
+    (0x1F0F, 0x345, 0x1F8F), (0x1F10, 0x300, 0x1F12), (0x1F10, 0x301, 0x1F14),
+    (0x1F11, 0x300, 0x1F13), (0x1F11, 0x301, 0x1F15), (0x1F18, 0x300, 0x1F1A),
+    (0x1F18, 0x301, 0x1F1C), (0x1F19, 0x300, 0x1F1B), (0x1F19, 0x301, 0x1F1D),
+    (0x1F20, 0x300, 0x1F22), (0x1F20, 0x301, 0x1F24), (0x1F20, 0x342, 0x1F26),
+    (0x1F20, 0x345, 0x1F90), (0x1F21, 0x300, 0x1F23), (0x1F21, 0x301, 0x1F25),
+    (0x1F21, 0x342, 0x1F27), (0x1F21, 0x345, 0x1F91), (0x1F22, 0x345, 0x1F92),
+    (0x1F23, 0x345, 0x1F93), (0x1F24, 0x345, 0x1F94), (0x1F25, 0x345, 0x1F95),
+    (0x1F26, 0x345, 0x1F96), (0x1F27, 0x345, 0x1F97), (0x1F28, 0x300, 0x1F2A),
+    (0x1F28, 0x301, 0x1F2C), (0x1F28, 0x342, 0x1F2E), (0x1F28, 0x345, 0x1F98),
+    (0x1F29, 0x300, 0x1F2B), (0x1F29, 0x301, 0x1F2D), (0x1F29, 0x342, 0x1F2F),
+    (0x1F29, 0x345, 0x1F99), (0x1F2A, 0x345, 0x1F9A), (0x1F2B, 0x345, 0x1F9B),
+    (0x1F2C, 0x345, 0x1F9C), (0x1F2D, 0x345, 0x1F9D), (0x1F2E, 0x345, 0x1F9E),
+    (0x1F2F, 0x345, 0x1F9F), (0x1F30, 0x300, 0x1F32), (0x1F30, 0x301, 0x1F34),
+    (0x1F30, 0x342, 0x1F36), (0x1F31, 0x300, 0x1F33), (0x1F31, 0x301, 0x1F35),
+    (0x1F31, 0x342, 0x1F37), (0x1F38, 0x300, 0x1F3A), (0x1F38, 0x301, 0x1F3C),
+    (0x1F38, 0x342, 0x1F3E), (0x1F39, 0x300, 0x1F3B), (0x1F39, 0x301, 0x1F3D),
+    (0x1F39, 0x342, 0x1F3F), (0x1F40, 0x300, 0x1F42), (0x1F40, 0x301, 0x1F44),
+    (0x1F41, 0x300, 0x1F43), (0x1F41, 0x301, 0x1F45), (0x1F48, 0x300, 0x1F4A),
+    (0x1F48, 0x301, 0x1F4C), (0x1F49, 0x300, 0x1F4B), (0x1F49, 0x301, 0x1F4D),
+    (0x1F50, 0x300, 0x1F52), (0x1F50, 0x301, 0x1F54), (0x1F50, 0x342, 0x1F56),
+    (0x1F51, 0x300, 0x1F53), (0x1F51, 0x301, 0x1F55), (0x1F51, 0x342, 0x1F57),
+    (0x1F59, 0x300, 0x1F5B), (0x1F59, 0x301, 0x1F5D), (0x1F59, 0x342, 0x1F5F),
+    (0x1F60, 0x300, 0x1F62), (0x1F60, 0x301, 0x1F64), (0x1F60, 0x342, 0x1F66),
+    (0x1F60, 0x345, 0x1FA0), (0x1F61, 0x300, 0x1F63), (0x1F61, 0x301, 0x1F65),
+    (0x1F61, 0x342, 0x1F67), (0x1F61, 0x345, 0x1FA1), (0x1F62, 0x345, 0x1FA2),
+    (0x1F63, 0x345, 0x1FA3), (0x1F64, 0x345, 0x1FA4), (0x1F65, 0x345, 0x1FA5),
+    (0x1F66, 0x345, 0x1FA6), (0x1F67, 0x345, 0x1FA7), (0x1F68, 0x300, 0x1F6A),
+    (0x1F68, 0x301, 0x1F6C), (0x1F68, 0x342, 0x1F6E), (0x1F68, 0x345, 0x1FA8),
+    (0x1F69, 0x300, 0x1F6B), (0x1F69, 0x301, 0x1F6D), (0x1F69, 0x342, 0x1F6F),
+    (0x1F69, 0x345, 0x1FA9), (0x1F6A, 0x345, 0x1FAA), (0x1F6B, 0x345, 0x1FAB),
+    (0x1F6C, 0x345, 0x1FAC), (0x1F6D, 0x345, 0x1FAD), (0x1F6E, 0x345, 0x1FAE),
+    (0x1F6F, 0x345, 0x1FAF), (0x1F70, 0x345, 0x1FB2), (0x1F74, 0x345, 0x1FC2),
+    (0x1F7C, 0x345, 0x1FF2), (0x1FB6, 0x345, 0x1FB7), (0x1FBF, 0x300, 0x1FCD),
+    (0x1FBF, 0x301, 0x1FCE), (0x1FBF, 0x342, 0x1FCF), (0x1FC6, 0x345, 0x1FC7),
+    (0x1FF6, 0x345, 0x1FF7), (0x1FFE, 0x300, 0x1FDD), (0x1FFE, 0x301, 0x1FDE),
+    (0x1FFE, 0x342, 0x1FDF), (0x2190, 0x338, 0x219A), (0x2192, 0x338, 0x219B),
+    (0x2194, 0x338, 0x21AE), (0x21D0, 0x338, 0x21CD), (0x21D2, 0x338, 0x21CF),
+    (0x21D4, 0x338, 0x21CE), (0x2203, 0x338, 0x2204), (0x2208, 0x338, 0x2209),
+    (0x220B, 0x338, 0x220C), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223C, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247),
+    (0x2248, 0x338, 0x2249), (0x224D, 0x338, 0x226D), (0x2261, 0x338, 0x2262),
+    (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271), (0x2272, 0x338, 0x2274),
+    (0x2273, 0x338, 0x2275), (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279),
+    (0x227A, 0x338, 0x2280), (0x227B, 0x338, 0x2281), (0x227C, 0x338, 0x22E0),
+    (0x227D, 0x338, 0x22E1), (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285),
+    (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289), (0x2291, 0x338, 0x22E2),
+    (0x2292, 0x338, 0x22E3), (0x22A2, 0x338, 0x22AC), (0x22A8, 0x338, 0x22AD),
+    (0x22A9, 0x338, 0x22AE), (0x22AB, 0x338, 0x22AF), (0x22B2, 0x338, 0x22EA),
+    (0x22B3, 0x338, 0x22EB), (0x22B4, 0x338, 0x22EC), (0x22B5, 0x338, 0x22ED),
+    (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C), (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C), (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065), (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074), (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077), (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C), (0x307B, 0x309A, 0x307D), (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6), (0x30B7, 0x3099, 0x30B8), (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE), (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2), (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3), (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9), (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8), (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE), (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E), (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B), (0x11347, 0x11357, 0x1134C), (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB), (0x11935, 0x11930, 0x11938),
+];
+
+/// Code point ranges with General_Category=Mark.
+pub(super) static MARKS: &[(u32, u32)] = &[
+    (0x300, 0x36F), (0x483, 0x489), (0x591, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2),
+    (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x610, 0x61A), (0x64B, 0x65F), (0x670, 0x670),
+    (0x6D6, 0x6DC), (0x6DF, 0x6E4), (0x6E7, 0x6E8), (0x6EA, 0x6ED), (0x711, 0x711),
+    (0x730, 0x74A), (0x7A6, 0x7B0), (0x7EB, 0x7F3), (0x7FD, 0x7FD), (0x816, 0x819),
+    (0x81B, 0x823), (0x825, 0x827), (0x829, 0x82D), (0x859, 0x85B), (0x898, 0x89F),
+    (0x8CA, 0x8E1), (0x8E3, 0x903), (0x93A, 0x93C), (0x93E, 0x94F), (0x951, 0x957),
+    (0x962, 0x963), (0x981, 0x983), (0x9BC, 0x9BC), (0x9BE, 0x9C4), (0x9C7, 0x9C8),
+    (0x9CB, 0x9CD), (0x9D7, 0x9D7), (0x9E2, 0x9E3), (0x9FE, 0x9FE), (0xA01, 0xA03),
+    (0xA3C, 0xA3C), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D), (0xA51, 0xA51),
+    (0xA70, 0xA71), (0xA75, 0xA75), (0xA81, 0xA83), (0xABC, 0xABC), (0xABE, 0xAC5),
+    (0xAC7, 0xAC9), (0xACB, 0xACD), (0xAE2, 0xAE3), (0xAFA, 0xAFF), (0xB01, 0xB03),
+    (0xB3C, 0xB3C), (0xB3E, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57),
+    (0xB62, 0xB63), (0xB82, 0xB82), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD),
+    (0xBD7, 0xBD7), (0xC00, 0xC04), (0xC3C, 0xC3C), (0xC3E, 0xC44), (0xC46, 0xC48),
+    (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC62, 0xC63), (0xC81, 0xC83), (0xCBC, 0xCBC),
+    (0xCBE, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCE2, 0xCE3),
+    (0xCF3, 0xCF3), (0xD00, 0xD03), (0xD3B, 0xD3C), (0xD3E, 0xD44), (0xD46, 0xD48),
+    (0xD4A, 0xD4D), (0xD57, 0xD57), (0xD62, 0xD63), (0xD81, 0xD83), (0xDCA, 0xDCA),
+    (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDF2, 0xDF3), (0xE31, 0xE31),
+    (0xE34, 0xE3A), (0xE47, 0xE4E), (0xEB1, 0xEB1), (0xEB4, 0xEBC), (0xEC8, 0xECE),
+    (0xF18, 0xF19), (0xF35, 0xF35), (0xF37, 0xF37), (0xF39, 0xF39), (0xF3E, 0xF3F),
+    (0xF71, 0xF84), (0xF86, 0xF87), (0xF8D, 0xF97), (0xF99, 0xFBC), (0xFC6, 0xFC6),
+    (0x102B, 0x103E), (0x1056, 0x1059), (0x105E, 0x1060), (0x1062, 0x1064), (0x1067, 0x106D),
+    (0x1071, 0x1074), (0x1082, 0x108D), (0x108F, 0x108F), (0x109A, 0x109D), (0x135D, 0x135F),
+    (0x1712, 0x1715), (0x1732, 0x1734), (0x1752, 0x1753), (0x1772, 0x1773), (0x17B4, 0x17D3),
+    (0x17DD, 0x17DD), (0x180B, 0x180D), (0x180F, 0x180F), (0x1885, 0x1886), (0x18A9, 0x18A9),
+    (0x1920, 0x192B), (0x1930, 0x193B), (0x1A17, 0x1A1B), (0x1A55, 0x1A5E), (0x1A60, 0x1A7C),
+    (0x1A7F, 0x1A7F), (0x1AB0, 0x1ACE), (0x1B00, 0x1B04), (0x1B34, 0x1B44), (0x1B6B, 0x1B73),
+    (0x1B80, 0x1B82), (0x1BA1, 0x1BAD), (0x1BE6, 0x1BF3), (0x1C24, 0x1C37), (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE8), (0x1CED, 0x1CED), (0x1CF4, 0x1CF4), (0x1CF7, 0x1CF9), (0x1DC0, 0x1DFF),
+    (0x20D0, 0x20F0), (0x2CEF, 0x2CF1), (0x2D7F, 0x2D7F), (0x2DE0, 0x2DFF), (0x302A, 0x302F),
+    (0x3099, 0x309A), (0xA66F, 0xA672), (0xA674, 0xA67D), (0xA69E, 0xA69F), (0xA6F0, 0xA6F1),
+    (0xA802, 0xA802), (0xA806, 0xA806), (0xA80B, 0xA80B), (0xA823, 0xA827), (0xA82C, 0xA82C),
+    (0xA880, 0xA881), (0xA8B4, 0xA8C5), (0xA8E0, 0xA8F1), (0xA8FF, 0xA8FF), (0xA926, 0xA92D),
+    (0xA947, 0xA953), (0xA980, 0xA983), (0xA9B3, 0xA9C0), (0xA9E5, 0xA9E5), (0xAA29, 0xAA36),
+    (0xAA43, 0xAA43), (0xAA4C, 0xAA4D), (0xAA7B, 0xAA7D), (0xAAB0, 0xAAB0), (0xAAB2, 0xAAB4),
+    (0xAAB7, 0xAAB8), (0xAABE, 0xAABF), (0xAAC1, 0xAAC1), (0xAAEB, 0xAAEF), (0xAAF5, 0xAAF6),
+    (0xABE3, 0xABEA), (0xABEC, 0xABED), (0xFB1E, 0xFB1E), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F),
+    (0x101FD, 0x101FD), (0x102E0, 0x102E0), (0x10376, 0x1037A), (0x10A01, 0x10A03), (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10AE5, 0x10AE6), (0x10D24, 0x10D27),
+    (0x10EAB, 0x10EAC), (0x10EFD, 0x10EFF), (0x10F46, 0x10F50), (0x10F82, 0x10F85), (0x11000, 0x11002),
+    (0x11038, 0x11046), (0x11070, 0x11070), (0x11073, 0x11074), (0x1107F, 0x11082), (0x110B0, 0x110BA),
+    (0x110C2, 0x110C2), (0x11100, 0x11102), (0x11127, 0x11134), (0x11145, 0x11146), (0x11173, 0x11173),
+    (0x11180, 0x11182), (0x111B3, 0x111C0), (0x111C9, 0x111CC), (0x111CE, 0x111CF), (0x1122C, 0x11237),
+    (0x1123E, 0x1123E), (0x11241, 0x11241), (0x112DF, 0x112EA), (0x11300, 0x11303), (0x1133B, 0x1133C),
+    (0x1133E, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11357, 0x11357), (0x11362, 0x11363),
+    (0x11366, 0x1136C), (0x11370, 0x11374), (0x11435, 0x11446), (0x1145E, 0x1145E), (0x114B0, 0x114C3),
+    (0x115AF, 0x115B5), (0x115B8, 0x115C0), (0x115DC, 0x115DD), (0x11630, 0x11640), (0x116AB, 0x116B7),
+    (0x1171D, 0x1172B), (0x1182C, 0x1183A), (0x11930, 0x11935), (0x11937, 0x11938), (0x1193B, 0x1193E),
+    (0x11940, 0x11940), (0x11942, 0x11943), (0x119D1, 0x119D7), (0x119DA, 0x119E0), (0x119E4, 0x119E4),
+    (0x11A01, 0x11A0A), (0x11A33, 0x11A39), (0x11A3B, 0x11A3E), (0x11A47, 0x11A47), (0x11A51, 0x11A5B),
+    (0x11A8A, 0x11A99), (0x11C2F, 0x11C36), (0x11C38, 0x11C3F), (0x11C92, 0x11CA7), (0x11CA9, 0x11CB6),
+    (0x11D31, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D45), (0x11D47, 0x11D47),
+    (0x11D8A, 0x11D8E), (0x11D90, 0x11D91), (0x11D93, 0x11D97), (0x11EF3, 0x11EF6), (0x11F00, 0x11F01),
+    (0x11F03, 0x11F03), (0x11F34, 0x11F3A), (0x11F3E, 0x11F42), (0x13440, 0x13440), (0x13447, 0x13455),
+    (0x16AF0, 0x16AF4), (0x16B30, 0x16B36), (0x16F4F, 0x16F4F), (0x16F51, 0x16F87), (0x16F8F, 0x16F92),
+    (0x16FE4, 0x16FE4), (0x16FF0, 0x16FF1), (0x1BC9D, 0x1BC9E), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46),
+    (0x1D165, 0x1D169), (0x1D16D, 0x1D172), (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD),
+    (0x1D242, 0x1D244), (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84),
+    (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF), (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E08F, 0x1E08F), (0x1E130, 0x1E136), (0x1E2AE, 0x1E2AE),
+    (0x1E2EC, 0x1E2EF), (0x1E4EC, 0x1E4EF), (0x1E8D0, 0x1E8D6), (0x1E944, 0x1E94A), (0xE0100, 0xE01EF),
+];
+
+use super::BidiClass::{self, *};
+
+/// Bidi_Class runs other than L: (first, last, class).
+pub(super) static BIDI_CLASS: &[(u32, u32, BidiClass)] = &[
+    (0x0, 0x8, BN), (0x9, 0xD, Other), (0xE, 0x1B, BN), (0x1C, 0x20, Other),
+    (0x21, 0x22, ON), (0x23, 0x25, ET), (0x26, 0x2A, ON), (0x2B, 0x2B, ES),
+    (0x2C, 0x2C, CS), (0x2D, 0x2D, ES), (0x2E, 0x2F, CS), (0x30, 0x39, EN),
+    (0x3A, 0x3A, CS), (0x3B, 0x40, ON), (0x5B, 0x60, ON), (0x7B, 0x7E, ON),
+    (0x7F, 0x84, BN), (0x85, 0x85, Other), (0x86, 0x9F, BN), (0xA0, 0xA0, CS),
+    (0xA1, 0xA1, ON), (0xA2, 0xA5, ET), (0xA6, 0xA9, ON), (0xAB, 0xAC, ON),
+    (0xAD, 0xAD, BN), (0xAE, 0xAF, ON), (0xB0, 0xB1, ET), (0xB2, 0xB3, EN),
+    (0xB4, 0xB4, ON), (0xB6, 0xB8, ON), (0xB9, 0xB9, EN), (0xBB, 0xBF, ON),
+    (0xD7, 0xD7, ON), (0xF7, 0xF7, ON), (0x2B9, 0x2BA, ON), (0x2C2, 0x2CF, ON),
+    (0x2D2, 0x2DF, ON), (0x2E5, 0x2ED, ON), (0x2EF, 0x2FF, ON), (0x300, 0x36F, NSM),
+    (0x374, 0x375, ON), (0x37E, 0x37E, ON), (0x384, 0x385, ON), (0x387, 0x387, ON),
+    (0x3F6, 0x3F6, ON), (0x483, 0x489, NSM), (0x58A, 0x58A, ON), (0x58D, 0x58E, ON),
+    (0x58F, 0x58F, ET), (0x591, 0x5BD, NSM), (0x5BE, 0x5BE, R), (0x5BF, 0x5BF, NSM),
+    (0x5C0, 0x5C0, R), (0x5C1, 0x5C2, NSM), (0x5C3, 0x5C3, R), (0x5C4, 0x5C5, NSM),
+    (0x5C6, 0x5C6, R), (0x5C7, 0x5C7, NSM), (0x5D0, 0x5EA, R), (0x5EF, 0x5F4, R),
+    (0x600, 0x605, AN), (0x606, 0x607, ON), (0x608, 0x608, AL), (0x609, 0x60A, ET),
+    (0x60B, 0x60B, AL), (0x60C, 0x60C, CS), (0x60D, 0x60D, AL), (0x60E, 0x60F, ON),
+    (0x610, 0x61A, NSM), (0x61B, 0x64A, AL), (0x64B, 0x65F, NSM), (0x660, 0x669, AN),
+    (0x66A, 0x66A, ET), (0x66B, 0x66C, AN), (0x66D, 0x66F, AL), (0x670, 0x670, NSM),
+    (0x671, 0x6D5, AL), (0x6D6, 0x6DC, NSM), (0x6DD, 0x6DD, AN), (0x6DE, 0x6DE, ON),
+    (0x6DF, 0x6E4, NSM), (0x6E5, 0x6E6, AL), (0x6E7, 0x6E8, NSM), (0x6E9, 0x6E9, ON),
+    (0x6EA, 0x6ED, NSM), (0x6EE, 0x6EF, AL), (0x6F0, 0x6F9, EN), (0x6FA, 0x70D, AL),
+    (0x70F, 0x710, AL), (0x711, 0x711, NSM), (0x712, 0x72F, AL), (0x730, 0x74A, NSM),
+    (0x74D, 0x7A5, AL), (0x7A6, 0x7B0, NSM), (0x7B1, 0x7B1, AL), (0x7C0, 0x7EA, R),
+    (0x7EB, 0x7F3, NSM), (0x7F4, 0x7F5, R), (0x7F6, 0x7F9, ON), (0x7FA, 0x7FA, R),
+    (0x7FD, 0x7FD, NSM), (0x7FE, 0x815, R), (0x816, 0x819, NSM), (0x81A, 0x81A, R),
+    (0x81B, 0x823, NSM), (0x824, 0x824, R), (0x825, 0x827, NSM), (0x828, 0x828, R),
+    (0x829, 0x82D, NSM), (0x830, 0x83E, R), (0x840, 0x858, R), (0x859, 0x85B, NSM),
+    (0x85E, 0x85E, R), (0x860, 0x86A, AL), (0x870, 0x88E, AL), (0x890, 0x891, AN),
+    (0x898, 0x89F, NSM), (0x8A0, 0x8C9, AL), (0x8CA, 0x8E1, NSM), (0x8E2, 0x8E2, AN),
+    (0x8E3, 0x902, NSM), (0x93A, 0x93A, NSM), (0x93C, 0x93C, NSM), (0x941, 0x948, NSM),
+    (0x94D, 0x94D, NSM), (0x951, 0x957, NSM), (0x962, 0x963, NSM), (0x981, 0x981, NSM),
+    (0x9BC, 0x9BC, NSM), (0x9C1, 0x9C4, NSM), (0x9CD, 0x9CD, NSM), (0x9E2, 0x9E3, NSM),
+    (0x9F2, 0x9F3, ET), (0x9FB, 0x9FB, ET), (0x9FE, 0x9FE, NSM), (0xA01, 0xA02, NSM),
+    (0xA3C, 0xA3C, NSM), (0xA41, 0xA42, NSM), (0xA47, 0xA48, NSM), (0xA4B, 0xA4D, NSM),
+    (0xA51, 0xA51, NSM), (0xA70, 0xA71, NSM), (0xA75, 0xA75, NSM), (0xA81, 0xA82, NSM),
+    (0xABC, 0xABC, NSM), (0xAC1, 0xAC5, NSM), (0xAC7, 0xAC8, NSM), (0xACD, 0xACD, NSM),
+    (0xAE2, 0xAE3, NSM), (0xAF1, 0xAF1, ET), (0xAFA, 0xAFF, NSM), (0xB01, 0xB01, NSM),
+    (0xB3C, 0xB3C, NSM), (0xB3F, 0xB3F, NSM), (0xB41, 0xB44, NSM), (0xB4D, 0xB4D, NSM),
+    (0xB55, 0xB56, NSM), (0xB62, 0xB63, NSM), (0xB82, 0xB82, NSM), (0xBC0, 0xBC0, NSM),
+    (0xBCD, 0xBCD, NSM), (0xBF3, 0xBF8, ON), (0xBF9, 0xBF9, ET), (0xBFA, 0xBFA, ON),
+    (0xC00, 0xC00, NSM), (0xC04, 0xC04, NSM), (0xC3C, 0xC3C, NSM), (0xC3E, 0xC40, NSM),
+    (0xC46, 0xC48, NSM), (0xC4A, 0xC4D, NSM), (0xC55, 0xC56, NSM), (0xC62, 0xC63, NSM),
+    (0xC78, 0xC7E, ON), (0xC81, 0xC81, NSM), (0xCBC, 0xCBC, NSM), (0xCCC, 0xCCD, NSM),
+    (0xCE2, 0xCE3, NSM), (0xD00, 0xD01, NSM), (0xD3B, 0xD3C, NSM), (0xD41, 0xD44, NSM),
+    (0xD4D, 0xD4D, NSM), (0xD62, 0xD63, NSM), (0xD81, 0xD81, NSM), (0xDCA, 0xDCA, NSM),
+    (0xDD2, 0xDD4, NSM), (0xDD6, 0xDD6, NSM), (0xE31, 0xE31, NSM), (0xE34, 0xE3A, NSM),
+    (0xE3F, 0xE3F, ET), (0xE47, 0xE4E, NSM), (0xEB1, 0xEB1, NSM), (0xEB4, 0xEBC, NSM),
+    (0xEC8, 0xECE, NSM), (0xF18, 0xF19, NSM), (0xF35, 0xF35, NSM), (0xF37, 0xF37, NSM),
+    (0xF39, 0xF39, NSM), (0xF3A, 0xF3D, ON), (0xF71, 0xF7E, NSM), (0xF80, 0xF84, NSM),
+    (0xF86, 0xF87, NSM), (0xF8D, 0xF97, NSM), (0xF99, 0xFBC, NSM), (0xFC6, 0xFC6, NSM),
+    (0x102D, 0x1030, NSM), (0x1032, 0x1037, NSM), (0x1039, 0x103A, NSM), (0x103D, 0x103E, NSM),
+    (0x1058, 0x1059, NSM), (0x105E, 0x1060, NSM), (0x1071, 0x1074, NSM), (0x1082, 0x1082, NSM),
+    (0x1085, 0x1086, NSM), (0x108D, 0x108D, NSM), (0x109D, 0x109D, NSM), (0x135D, 0x135F, NSM),
+    (0x1390, 0x1399, ON), (0x1400, 0x1400, ON), (0x1680, 0x1680, Other), (0x169B, 0x169C, ON),
+    (0x1712, 0x1714, NSM), (0x1732, 0x1733, NSM), (0x1752, 0x1753, NSM), (0x1772, 0x1773, NSM),
+    (0x17B4, 0x17B5, NSM), (0x17B7, 0x17BD, NSM), (0x17C6, 0x17C6, NSM), (0x17C9, 0x17D3, NSM),
+    (0x17DB, 0x17DB, ET), (0x17DD, 0x17DD, NSM), (0x17F0, 0x17F9, ON), (0x1800, 0x180A, ON),
+    (0x180B, 0x180D, NSM), (0x180E, 0x180E, BN), (0x180F, 0x180F, NSM), (0x1885, 0x1886, NSM),
+    (0x18A9, 0x18A9, NSM), (0x1920, 0x1922, NSM), (0x1927, 0x1928, NSM), (0x1932, 0x1932, NSM),
+    (0x1939, 0x193B, NSM), (0x1940, 0x1940, ON), (0x1944, 0x1945, ON), (0x19DE, 0x19FF, ON),
+    (0x1A17, 0x1A18, NSM), (0x1A1B, 0x1A1B, NSM), (0x1A56, 0x1A56, NSM), (0x1A58, 0x1A5E, NSM),
+    (0x1A60, 0x1A60, NSM), (0x1A62, 0x1A62, NSM), (0x1A65, 0x1A6C, NSM), (0x1A73, 0x1A7C, NSM),
+    (0x1A7F, 0x1A7F, NSM), (0x1AB0, 0x1ACE, NSM), (0x1B00, 0x1B03, NSM), (0x1B34, 0x1B34, NSM),
+    (0x1B36, 0x1B3A, NSM), (0x1B3C, 0x1B3C, NSM), (0x1B42, 0x1B42, NSM), (0x1B6B, 0x1B73, NSM),
+    (0x1B80, 0x1B81, NSM), (0x1BA2, 0x1BA5, NSM), (0x1BA8, 0x1BA9, NSM), (0x1BAB, 0x1BAD, NSM),
+    (0x1BE6, 0x1BE6, NSM), (0x1BE8, 0x1BE9, NSM), (0x1BED, 0x1BED, NSM), (0x1BEF, 0x1BF1, NSM),
+    (0x1C2C, 0x1C33, NSM), (0x1C36, 0x1C37, NSM), (0x1CD0, 0x1CD2, NSM), (0x1CD4, 0x1CE0, NSM),
+    (0x1CE2, 0x1CE8, NSM), (0x1CED, 0x1CED, NSM), (0x1CF4, 0x1CF4, NSM), (0x1CF8, 0x1CF9, NSM),
+    (0x1DC0, 0x1DFF, NSM), (0x1FBD, 0x1FBD, ON), (0x1FBF, 0x1FC1, ON), (0x1FCD, 0x1FCF, ON),
+    (0x1FDD, 0x1FDF, ON), (0x1FED, 0x1FEF, ON), (0x1FFD, 0x1FFE, ON), (0x2000, 0x200A, Other),
+    (0x200B, 0x200D, BN), (0x200F, 0x200F, R), (0x2010, 0x2027, ON), (0x2028, 0x202E, Other),
+    (0x202F, 0x202F, CS), (0x2030, 0x2034, ET), (0x2035, 0x2043, ON), (0x2044, 0x2044, CS),
+    (0x2045, 0x205E, ON), (0x205F, 0x205F, Other), (0x2060, 0x2064, BN), (0x2066, 0x2069, Other),
+    (0x206A, 0x206F, BN), (0x2070, 0x2070, EN), (0x2074, 0x2079, EN), (0x207A, 0x207B, ES),
+    (0x207C, 0x207E, ON), (0x2080, 0x2089, EN), (0x208A, 0x208B, ES), (0x208C, 0x208E, ON),
+    (0x20A0, 0x20C0, ET), (0x20D0, 0x20F0, NSM), (0x2100, 0x2101, ON), (0x2103, 0x2106, ON),
+    (0x2108, 0x2109, ON), (0x2114, 0x2114, ON), (0x2116, 0x2118, ON), (0x211E, 0x2123, ON),
+    (0x2125, 0x2125, ON), (0x2127, 0x2127, ON), (0x2129, 0x2129, ON), (0x212E, 0x212E, ET),
+    (0x213A, 0x213B, ON), (0x2140, 0x2144, ON), (0x214A, 0x214D, ON), (0x2150, 0x215F, ON),
+    (0x2189, 0x218B, ON), (0x2190, 0x2211, ON), (0x2212, 0x2212, ES), (0x2213, 0x2213, ET),
+    (0x2214, 0x2335, ON), (0x237B, 0x2394, ON), (0x2396, 0x2426, ON), (0x2440, 0x244A, ON),
+    (0x2460, 0x2487, ON), (0x2488, 0x249B, EN), (0x24EA, 0x26AB, ON), (0x26AD, 0x27FF, ON),
+    (0x2900, 0x2B73, ON), (0x2B76, 0x2B95, ON), (0x2B97, 0x2BFF, ON), (0x2CE5, 0x2CEA, ON),
+    (0x2CEF, 0x2CF1, NSM), (0x2CF9, 0x2CFF, ON), (0x2D7F, 0x2D7F, NSM), (0x2DE0, 0x2DFF, NSM),
+    (0x2E00, 0x2E5D, ON), (0x2E80, 0x2E99, ON), (0x2E9B, 0x2EF3, ON), (0x2F00, 0x2FD5, ON),
+    (0x2FF0, 0x2FFF, ON), (0x3000, 0x3000, Other), (0x3001, 0x3004, ON), (0x3008, 0x3020, ON),
+    (0x302A, 0x302D, NSM), (0x3030, 0x3030, ON), (0x3036, 0x3037, ON), (0x303D, 0x303F, ON),
+    (0x3099, 0x309A, NSM), (0x309B, 0x309C, ON), (0x30A0, 0x30A0, ON), (0x30FB, 0x30FB, ON),
+    (0x31C0, 0x31E3, ON), (0x31EF, 0x31EF, ON), (0x321D, 0x321E, ON), (0x3250, 0x325F, ON),
+    (0x327C, 0x327E, ON), (0x32B1, 0x32BF, ON), (0x32CC, 0x32CF, ON), (0x3377, 0x337A, ON),
+    (0x33DE, 0x33DF, ON), (0x33FF, 0x33FF, ON), (0x4DC0, 0x4DFF, ON), (0xA490, 0xA4C6, ON),
+    (0xA60D, 0xA60F, ON), (0xA66F, 0xA672, NSM), (0xA673, 0xA673, ON), (0xA674, 0xA67D, NSM),
+    (0xA67E, 0xA67F, ON), (0xA69E, 0xA69F, NSM), (0xA6F0, 0xA6F1, NSM), (0xA700, 0xA721, ON),
+    (0xA788, 0xA788, ON), (0xA802, 0xA802, NSM), (0xA806, 0xA806, NSM), (0xA80B, 0xA80B, NSM),
+    (0xA825, 0xA826, NSM), (0xA828, 0xA82B, ON), (0xA82C, 0xA82C, NSM), (0xA838, 0xA839, ET),
+    (0xA874, 0xA877, ON), (0xA8C4, 0xA8C5, NSM), (0xA8E0, 0xA8F1, NSM), (0xA8FF, 0xA8FF, NSM),
+    (0xA926, 0xA92D, NSM), (0xA947, 0xA951, NSM), (0xA980, 0xA982, NSM), (0xA9B3, 0xA9B3, NSM),
+    (0xA9B6, 0xA9B9, NSM), (0xA9BC, 0xA9BD, NSM), (0xA9E5, 0xA9E5, NSM), (0xAA29, 0xAA2E, NSM),
+    (0xAA31, 0xAA32, NSM), (0xAA35, 0xAA36, NSM), (0xAA43, 0xAA43, NSM), (0xAA4C, 0xAA4C, NSM),
+    (0xAA7C, 0xAA7C, NSM), (0xAAB0, 0xAAB0, NSM), (0xAAB2, 0xAAB4, NSM), (0xAAB7, 0xAAB8, NSM),
+    (0xAABE, 0xAABF, NSM), (0xAAC1, 0xAAC1, NSM), (0xAAEC, 0xAAED, NSM), (0xAAF6, 0xAAF6, NSM),
+    (0xAB6A, 0xAB6B, ON), (0xABE5, 0xABE5, NSM), (0xABE8, 0xABE8, NSM), (0xABED, 0xABED, NSM),
+    (0xFB1D, 0xFB1D, R), (0xFB1E, 0xFB1E, NSM), (0xFB1F, 0xFB28, R), (0xFB29, 0xFB29, ES),
+    (0xFB2A, 0xFB36, R), (0xFB38, 0xFB3C, R), (0xFB3E, 0xFB3E, R), (0xFB40, 0xFB41, R),
+    (0xFB43, 0xFB44, R), (0xFB46, 0xFB4F, R), (0xFB50, 0xFBC2, AL), (0xFBD3, 0xFD3D, AL),
+    (0xFD3E, 0xFD4F, ON), (0xFD50, 0xFD8F, AL), (0xFD92, 0xFDC7, AL), (0xFDCF, 0xFDCF, ON),
+    (0xFDF0, 0xFDFC, AL), (0xFDFD, 0xFDFF, ON), (0xFE00, 0xFE0F, NSM), (0xFE10, 0xFE19, ON),
+    (0xFE20, 0xFE2F, NSM), (0xFE30, 0xFE4F, ON), (0xFE50, 0xFE50, CS), (0xFE51, 0xFE51, ON),
+    (0xFE52, 0xFE52, CS), (0xFE54, 0xFE54, ON), (0xFE55, 0xFE55, CS), (0xFE56, 0xFE5E, ON),
+    (0xFE5F, 0xFE5F, ET), (0xFE60, 0xFE61, ON), (0xFE62, 0xFE63, ES), (0xFE64, 0xFE66, ON),
+    (0xFE68, 0xFE68, ON), (0xFE69, 0xFE6A, ET), (0xFE6B, 0xFE6B, ON), (0xFE70, 0xFE74, AL),
+    (0xFE76, 0xFEFC, AL), (0xFEFF, 0xFEFF, BN), (0xFF01, 0xFF02, ON), (0xFF03, 0xFF05, ET),
+    (0xFF06, 0xFF0A, ON), (0xFF0B, 0xFF0B, ES), (0xFF0C, 0xFF0C, CS), (0xFF0D, 0xFF0D, ES),
+    (0xFF0E, 0xFF0F, CS), (0xFF10, 0xFF19, EN), (0xFF1A, 0xFF1A, CS), (0xFF1B, 0xFF20, ON),
+    (0xFF3B, 0xFF40, ON), (0xFF5B, 0xFF65, ON), (0xFFE0, 0xFFE1, ET), (0xFFE2, 0xFFE4, ON),
+    (0xFFE5, 0xFFE6, ET), (0xFFE8, 0xFFEE, ON), (0xFFF9, 0xFFFD, ON), (0x10101, 0x10101, ON),
+    (0x10140, 0x1018C, ON), (0x10190, 0x1019C, ON), (0x101A0, 0x101A0, ON), (0x101FD, 0x101FD, NSM),
+    (0x102E0, 0x102E0, NSM), (0x102E1, 0x102FB, EN), (0x10376, 0x1037A, NSM), (0x10800, 0x10805, R),
+    (0x10808, 0x10808, R), (0x1080A, 0x10835, R), (0x10837, 0x10838, R), (0x1083C, 0x1083C, R),
+    (0x1083F, 0x10855, R), (0x10857, 0x1089E, R), (0x108A7, 0x108AF, R), (0x108E0, 0x108F2, R),
+    (0x108F4, 0x108F5, R), (0x108FB, 0x1091B, R), (0x1091F, 0x1091F, ON), (0x10920, 0x10939, R),
+    (0x1093F, 0x1093F, R), (0x10980, 0x109B7, R), (0x109BC, 0x109CF, R), (0x109D2, 0x10A00, R),
+    (0x10A01, 0x10A03, NSM), (0x10A05, 0x10A06, NSM), (0x10A0C, 0x10A0F, NSM), (0x10A10, 0x10A13, R),
+    (0x10A15, 0x10A17, R), (0x10A19, 0x10A35, R), (0x10A38, 0x10A3A, NSM), (0x10A3F, 0x10A3F, NSM),
+    (0x10A40, 0x10A48, R), (0x10A50, 0x10A58, R), (0x10A60, 0x10A9F, R), (0x10AC0, 0x10AE4, R),
+    (0x10AE5, 0x10AE6, NSM), (0x10AEB, 0x10AF6, R), (0x10B00, 0x10B35, R), (0x10B39, 0x10B3F, ON),
+    (0x10B40, 0x10B55, R), (0x10B58, 0x10B72, R), (0x10B78, 0x10B91, R), (0x10B99, 0x10B9C, R),
+    (0x10BA9, 0x10BAF, R), (0x10C00, 0x10C48, R), (0x10C80, 0x10CB2, R), (0x10CC0, 0x10CF2, R),
+    (0x10CFA, 0x10CFF, R), (0x10D00, 0x10D23, AL), (0x10D24, 0x10D27, NSM), (0x10D30, 0x10D39, AN),
+    (0x10E60, 0x10E7E, AN), (0x10E80, 0x10EA9, R), (0x10EAB, 0x10EAC, NSM), (0x10EAD, 0x10EAD, R),
+    (0x10EB0, 0x10EB1, R), (0x10EFD, 0x10EFF, NSM), (0x10F00, 0x10F27, R), (0x10F30, 0x10F45, AL),
+    (0x10F46, 0x10F50, NSM), (0x10F51, 0x10F59, AL), (0x10F70, 0x10F81, R), (0x10F82, 0x10F85, NSM),
+    (0x10F86, 0x10F89, R), (0x10FB0, 0x10FCB, R), (0x10FE0, 0x10FF6, R), (0x11001, 0x11001, NSM),
+    (0x11038, 0x11046, NSM), (0x11052, 0x11065, ON), (0x11070, 0x11070, NSM), (0x11073, 0x11074, NSM),
+    (0x1107F, 0x11081, NSM), (0x110B3, 0x110B6, NSM), (0x110B9, 0x110BA, NSM), (0x110C2, 0x110C2, NSM),
+    (0x11100, 0x11102, NSM), (0x11127, 0x1112B, NSM), (0x1112D, 0x11134, NSM), (0x11173, 0x11173, NSM),
+    (0x11180, 0x11181, NSM), (0x111B6, 0x111BE, NSM), (0x111C9, 0x111CC, NSM), (0x111CF, 0x111CF, NSM),
+    (0x1122F, 0x11231, NSM), (0x11234, 0x11234, NSM), (0x11236, 0x11237, NSM), (0x1123E, 0x1123E, NSM),
+    (0x11241, 0x11241, NSM), (0x112DF, 0x112DF, NSM), (0x112E3, 0x112EA, NSM), (0x11300, 0x11301, NSM),
+    (0x1133B, 0x1133C, NSM), (0x11340, 0x11340, NSM), (0x11366, 0x1136C, NSM), (0x11370, 0x11374, NSM),
+    (0x11438, 0x1143F, NSM), (0x11442, 0x11444, NSM), (0x11446, 0x11446, NSM), (0x1145E, 0x1145E, NSM),
+    (0x114B3, 0x114B8, NSM), (0x114BA, 0x114BA, NSM), (0x114BF, 0x114C0, NSM), (0x114C2, 0x114C3, NSM),
+    (0x115B2, 0x115B5, NSM), (0x115BC, 0x115BD, NSM), (0x115BF, 0x115C0, NSM), (0x115DC, 0x115DD, NSM),
+    (0x11633, 0x1163A, NSM), (0x1163D, 0x1163D, NSM), (0x1163F, 0x11640, NSM), (0x11660, 0x1166C, ON),
+    (0x116AB, 0x116AB, NSM), (0x116AD, 0x116AD, NSM), (0x116B0, 0x116B5, NSM), (0x116B7, 0x116B7, NSM),
+    (0x1171D, 0x1171F, NSM), (0x11722, 0x11725, NSM), (0x11727, 0x1172B, NSM), (0x1182F, 0x11837, NSM),
+    (0x11839, 0x1183A, NSM), (0x1193B, 0x1193C, NSM), (0x1193E, 0x1193E, NSM), (0x11943, 0x11943, NSM),
+    (0x119D4, 0x119D7, NSM), (0x119DA, 0x119DB, NSM), (0x119E0, 0x119E0, NSM), (0x11A01, 0x11A06, NSM),
+    (0x11A09, 0x11A0A, NSM), (0x11A33, 0x11A38, NSM), (0x11A3B, 0x11A3E, NSM), (0x11A47, 0x11A47, NSM),
+    (0x11A51, 0x11A56, NSM), (0x11A59, 0x11A5B, NSM), (0x11A8A, 0x11A96, NSM), (0x11A98, 0x11A99, NSM),
+    (0x11C30, 0x11C36, NSM), (0x11C38, 0x11C3D, NSM), (0x11C92, 0x11CA7, NSM), (0x11CAA, 0x11CB0, NSM),
+    (0x11CB2, 0x11CB3, NSM), (0x11CB5, 0x11CB6, NSM), (0x11D31, 0x11D36, NSM), (0x11D3A, 0x11D3A, NSM),
+    (0x11D3C, 0x11D3D, NSM), (0x11D3F, 0x11D45, NSM), (0x11D47, 0x11D47, NSM), (0x11D90, 0x11D91, NSM),
+    (0x11D95, 0x11D95, NSM), (0x11D97, 0x11D97, NSM), (0x11EF3, 0x11EF4, NSM), (0x11F00, 0x11F01, NSM),
+    (0x11F36, 0x11F3A, NSM), (0x11F40, 0x11F40, NSM), (0x11F42, 0x11F42, NSM), (0x11FD5, 0x11FDC, ON),
+    (0x11FDD, 0x11FE0, ET), (0x11FE1, 0x11FF1, ON), (0x13440, 0x13440, NSM), (0x13447, 0x13455, NSM),
+    (0x16AF0, 0x16AF4, NSM), (0x16B30, 0x16B36, NSM), (0x16F4F, 0x16F4F, NSM), (0x16F8F, 0x16F92, NSM),
+    (0x16FE2, 0x16FE2, ON), (0x16FE4, 0x16FE4, NSM), (0x1BC9D, 0x1BC9E, NSM), (0x1BCA0, 0x1BCA3, BN),
+    (0x1CF00, 0x1CF2D, NSM), (0x1CF30, 0x1CF46, NSM), (0x1D167, 0x1D169, NSM), (0x1D173, 0x1D17A, BN),
+    (0x1D17B, 0x1D182, NSM), (0x1D185, 0x1D18B, NSM), (0x1D1AA, 0x1D1AD, NSM), (0x1D1E9, 0x1D1EA, ON),
+    (0x1D200, 0x1D241, ON), (0x1D242, 0x1D244, NSM), (0x1D245, 0x1D245, ON), (0x1D300, 0x1D356, ON),
+    (0x1D6DB, 0x1D6DB, ON), (0x1D715, 0x1D715, ON), (0x1D74F, 0x1D74F, ON), (0x1D789, 0x1D789, ON),
+    (0x1D7C3, 0x1D7C3, ON), (0x1D7CE, 0x1D7FF, EN), (0x1DA00, 0x1DA36, NSM), (0x1DA3B, 0x1DA6C, NSM),
+    (0x1DA75, 0x1DA75, NSM), (0x1DA84, 0x1DA84, NSM), (0x1DA9B, 0x1DA9F, NSM), (0x1DAA1, 0x1DAAF, NSM),
+    (0x1E000, 0x1E006, NSM), (0x1E008, 0x1E018, NSM), (0x1E01B, 0x1E021, NSM), (0x1E023, 0x1E024, NSM),
+    (0x1E026, 0x1E02A, NSM), (0x1E08F, 0x1E08F, NSM), (0x1E130, 0x1E136, NSM), (0x1E2AE, 0x1E2AE, NSM),
+    (0x1E2EC, 0x1E2EF, NSM), (0x1E2FF, 0x1E2FF, ET), (0x1E4EC, 0x1E4EF, NSM), (0x1E800, 0x1E8C4, R),
+    (0x1E8C7, 0x1E8CF, R), (0x1E8D0, 0x1E8D6, NSM), (0x1E900, 0x1E943, R), (0x1E944, 0x1E94A, NSM),
+    (0x1E94B, 0x1E94B, R), (0x1E950, 0x1E959, R), (0x1E95E, 0x1E95F, R), (0x1EC71, 0x1ECB4, AL),
+    (0x1ED01, 0x1ED3D, AL), (0x1EE00, 0x1EE03, AL), (0x1EE05, 0x1EE1F, AL), (0x1EE21, 0x1EE22, AL),
+    (0x1EE24, 0x1EE24, AL), (0x1EE27, 0x1EE27, AL), (0x1EE29, 0x1EE32, AL), (0x1EE34, 0x1EE37, AL),
+    (0x1EE39, 0x1EE39, AL), (0x1EE3B, 0x1EE3B, AL), (0x1EE42, 0x1EE42, AL), (0x1EE47, 0x1EE47, AL),
+    (0x1EE49, 0x1EE49, AL), (0x1EE4B, 0x1EE4B, AL), (0x1EE4D, 0x1EE4F, AL), (0x1EE51, 0x1EE52, AL),
+    (0x1EE54, 0x1EE54, AL), (0x1EE57, 0x1EE57, AL), (0x1EE59, 0x1EE59, AL), (0x1EE5B, 0x1EE5B, AL),
+    (0x1EE5D, 0x1EE5D, AL), (0x1EE5F, 0x1EE5F, AL), (0x1EE61, 0x1EE62, AL), (0x1EE64, 0x1EE64, AL),
+    (0x1EE67, 0x1EE6A, AL), (0x1EE6C, 0x1EE72, AL), (0x1EE74, 0x1EE77, AL), (0x1EE79, 0x1EE7C, AL),
+    (0x1EE7E, 0x1EE7E, AL), (0x1EE80, 0x1EE89, AL), (0x1EE8B, 0x1EE9B, AL), (0x1EEA1, 0x1EEA3, AL),
+    (0x1EEA5, 0x1EEA9, AL), (0x1EEAB, 0x1EEBB, AL), (0x1EEF0, 0x1EEF1, ON), (0x1F000, 0x1F02B, ON),
+    (0x1F030, 0x1F093, ON), (0x1F0A0, 0x1F0AE, ON), (0x1F0B1, 0x1F0BF, ON), (0x1F0C1, 0x1F0CF, ON),
+    (0x1F0D1, 0x1F0F5, ON), (0x1F100, 0x1F10A, EN), (0x1F10B, 0x1F10F, ON), (0x1F12F, 0x1F12F, ON),
+    (0x1F16A, 0x1F16F, ON), (0x1F1AD, 0x1F1AD, ON), (0x1F260, 0x1F265, ON), (0x1F300, 0x1F6D7, ON),
+    (0x1F6DC, 0x1F6EC, ON), (0x1F6F0, 0x1F6FC, ON), (0x1F700, 0x1F776, ON), (0x1F77B, 0x1F7D9, ON),
+    (0x1F7E0, 0x1F7EB, ON), (0x1F7F0, 0x1F7F0, ON), (0x1F800, 0x1F80B, ON), (0x1F810, 0x1F847, ON),
+    (0x1F850, 0x1F859, ON), (0x1F860, 0x1F887, ON), (0x1F890, 0x1F8AD, ON), (0x1F8B0, 0x1F8B1, ON),
+    (0x1F900, 0x1FA53, ON), (0x1FA60, 0x1FA6D, ON), (0x1FA70, 0x1FA7C, ON), (0x1FA80, 0x1FA88, ON),
+    (0x1FA90, 0x1FABD, ON), (0x1FABF, 0x1FAC5, ON), (0x1FACE, 0x1FADB, ON), (0x1FAE0, 0x1FAE8, ON),
+    (0x1FAF0, 0x1FAF8, ON), (0x1FB00, 0x1FB92, ON), (0x1FB94, 0x1FBCA, ON), (0x1FBF0, 0x1FBF9, EN),
+    (0xE0001, 0xE0001, BN), (0xE0020, 0xE007F, BN), (0xE0100, 0xE01EF, NSM),
+];
+
+use super::JoiningType;
+
+/// Joining_Type runs other than U (non-joining): (first, last, type).
+pub(super) static JOINING_TYPE: &[(u32, u32, JoiningType)] = &[
+    (0xAD, 0xAD, JoiningType::T), (0x300, 0x36F, JoiningType::T), (0x483, 0x489, JoiningType::T),
+    (0x591, 0x5BD, JoiningType::T), (0x5BF, 0x5BF, JoiningType::T), (0x5C1, 0x5C2, JoiningType::T),
+    (0x5C4, 0x5C5, JoiningType::T), (0x5C7, 0x5C7, JoiningType::T), (0x610, 0x61A, JoiningType::T),
+    (0x61C, 0x61C, JoiningType::T), (0x620, 0x620, JoiningType::D), (0x622, 0x625, JoiningType::R),
+    (0x626, 0x626, JoiningType::D), (0x627, 0x627, JoiningType::R), (0x628, 0x628, JoiningType::D),
+    (0x629, 0x629, JoiningType::R), (0x62A, 0x62E, JoiningType::D), (0x62F, 0x632, JoiningType::R),
+    (0x633, 0x63F, JoiningType::D), (0x640, 0x640, JoiningType::C), (0x641, 0x647, JoiningType::D),
+    (0x648, 0x648, JoiningType::R), (0x649, 0x64A, JoiningType::D), (0x64B, 0x65F, JoiningType::T),
+    (0x66E, 0x66F, JoiningType::D), (0x670, 0x670, JoiningType::T), (0x671, 0x673, JoiningType::R),
+    (0x675, 0x677, JoiningType::R), (0x678, 0x687, JoiningType::D), (0x688, 0x699, JoiningType::R),
+    (0x69A, 0x6BF, JoiningType::D), (0x6C0, 0x6C0, JoiningType::R), (0x6C1, 0x6C2, JoiningType::D),
+    (0x6C3, 0x6CB, JoiningType::R), (0x6CC, 0x6CC, JoiningType::D), (0x6CD, 0x6CD, JoiningType::R),
+    (0x6CE, 0x6CE, JoiningType::D), (0x6CF, 0x6CF, JoiningType::R), (0x6D0, 0x6D1, JoiningType::D),
+    (0x6D2, 0x6D3, JoiningType::R), (0x6D5, 0x6D5, JoiningType::R), (0x6D6, 0x6DC, JoiningType::T),
+    (0x6DF, 0x6E4, JoiningType::T), (0x6E7, 0x6E8, JoiningType::T), (0x6EA, 0x6ED, JoiningType::T),
+    (0x6EE, 0x6EF, JoiningType::R), (0x6FA, 0x6FC, JoiningType::D), (0x6FF, 0x6FF, JoiningType::D),
+    (0x70F, 0x70F, JoiningType::T), (0x710, 0x710, JoiningType::R), (0x711, 0x711, JoiningType::T),
+    (0x712, 0x714, JoiningType::D), (0x715, 0x719, JoiningType::R), (0x71A, 0x71D, JoiningType::D),
+    (0x71E, 0x71E, JoiningType::R), (0x71F, 0x727, JoiningType::D), (0x728, 0x728, JoiningType::R),
+    (0x729, 0x729, JoiningType::D), (0x72A, 0x72A, JoiningType::R), (0x72B, 0x72B, JoiningType::D),
+    (0x72C, 0x72C, JoiningType::R), (0x72D, 0x72E, JoiningType::D), (0x72F, 0x72F, JoiningType::R),
+    (0x730, 0x74A, JoiningType::T), (0x74D, 0x74D, JoiningType::R), (0x74E, 0x758, JoiningType::D),
+    (0x759, 0x75B, JoiningType::R), (0x75C, 0x76A, JoiningType::D), (0x76B, 0x76C, JoiningType::R),
+    (0x76D, 0x770, JoiningType::D), (0x771, 0x771, JoiningType::R), (0x772, 0x772, JoiningType::D),
+    (0x773, 0x774, JoiningType::R), (0x775, 0x777, JoiningType::D), (0x778, 0x779, JoiningType::R),
+    (0x77A, 0x77F, JoiningType::D), (0x7A6, 0x7B0, JoiningType::T), (0x7CA, 0x7EA, JoiningType::D),
+    (0x7EB, 0x7F3, JoiningType::T), (0x7FA, 0x7FA, JoiningType::C), (0x7FD, 0x7FD, JoiningType::T),
+    (0x816, 0x819, JoiningType::T), (0x81B, 0x823, JoiningType::T), (0x825, 0x827, JoiningType::T),
+    (0x829, 0x82D, JoiningType::T), (0x840, 0x840, JoiningType::R), (0x841, 0x845, JoiningType::D),
+    (0x846, 0x847, JoiningType::R), (0x848, 0x848, JoiningType::D), (0x849, 0x849, JoiningType::R),
+    (0x84A, 0x853, JoiningType::D), (0x854, 0x854, JoiningType::R), (0x855, 0x855, JoiningType::D),
+    (0x856, 0x858, JoiningType::R), (0x859, 0x85B, JoiningType::T), (0x860, 0x860, JoiningType::D),
+    (0x862, 0x865, JoiningType::D), (0x867, 0x867, JoiningType::R), (0x868, 0x868, JoiningType::D),
+    (0x869, 0x86A, JoiningType::R), (0x870, 0x882, JoiningType::R), (0x883, 0x885, JoiningType::C),
+    (0x886, 0x886, JoiningType::D), (0x889, 0x88D, JoiningType::D), (0x88E, 0x88E, JoiningType::R),
+    (0x898, 0x89F, JoiningType::T), (0x8A0, 0x8A9, JoiningType::D), (0x8AA, 0x8AC, JoiningType::R),
+    (0x8AE, 0x8AE, JoiningType::R), (0x8AF, 0x8B0, JoiningType::D), (0x8B1, 0x8B2, JoiningType::R),
+    (0x8B3, 0x8B8, JoiningType::D), (0x8B9, 0x8B9, JoiningType::R), (0x8BA, 0x8C8, JoiningType::D),
+    (0x8CA, 0x8E1, JoiningType::T), (0x8E3, 0x902, JoiningType::T), (0x93A, 0x93A, JoiningType::T),
+    (0x93C, 0x93C, JoiningType::T), (0x941, 0x948, JoiningType::T), (0x94D, 0x94D, JoiningType::T),
+    (0x951, 0x957, JoiningType::T), (0x962, 0x963, JoiningType::T), (0x981, 0x981, JoiningType::T),
+    (0x9BC, 0x9BC, JoiningType::T), (0x9C1, 0x9C4, JoiningType::T), (0x9CD, 0x9CD, JoiningType::T),
+    (0x9E2, 0x9E3, JoiningType::T), (0x9FE, 0x9FE, JoiningType::T), (0xA01, 0xA02, JoiningType::T),
+    (0xA3C, 0xA3C, JoiningType::T), (0xA41, 0xA42, JoiningType::T), (0xA47, 0xA48, JoiningType::T),
+    (0xA4B, 0xA4D, JoiningType::T), (0xA51, 0xA51, JoiningType::T), (0xA70, 0xA71, JoiningType::T),
+    (0xA75, 0xA75, JoiningType::T), (0xA81, 0xA82, JoiningType::T), (0xABC, 0xABC, JoiningType::T),
+    (0xAC1, 0xAC5, JoiningType::T), (0xAC7, 0xAC8, JoiningType::T), (0xACD, 0xACD, JoiningType::T),
+    (0xAE2, 0xAE3, JoiningType::T), (0xAFA, 0xAFF, JoiningType::T), (0xB01, 0xB01, JoiningType::T),
+    (0xB3C, 0xB3C, JoiningType::T), (0xB3F, 0xB3F, JoiningType::T), (0xB41, 0xB44, JoiningType::T),
+    (0xB4D, 0xB4D, JoiningType::T), (0xB55, 0xB56, JoiningType::T), (0xB62, 0xB63, JoiningType::T),
+    (0xB82, 0xB82, JoiningType::T), (0xBC0, 0xBC0, JoiningType::T), (0xBCD, 0xBCD, JoiningType::T),
+    (0xC00, 0xC00, JoiningType::T), (0xC04, 0xC04, JoiningType::T), (0xC3C, 0xC3C, JoiningType::T),
+    (0xC3E, 0xC40, JoiningType::T), (0xC46, 0xC48, JoiningType::T), (0xC4A, 0xC4D, JoiningType::T),
+    (0xC55, 0xC56, JoiningType::T), (0xC62, 0xC63, JoiningType::T), (0xC81, 0xC81, JoiningType::T),
+    (0xCBC, 0xCBC, JoiningType::T), (0xCBF, 0xCBF, JoiningType::T), (0xCC6, 0xCC6, JoiningType::T),
+    (0xCCC, 0xCCD, JoiningType::T), (0xCE2, 0xCE3, JoiningType::T), (0xD00, 0xD01, JoiningType::T),
+    (0xD3B, 0xD3C, JoiningType::T), (0xD41, 0xD44, JoiningType::T), (0xD4D, 0xD4D, JoiningType::T),
+    (0xD62, 0xD63, JoiningType::T), (0xD81, 0xD81, JoiningType::T), (0xDCA, 0xDCA, JoiningType::T),
+    (0xDD2, 0xDD4, JoiningType::T), (0xDD6, 0xDD6, JoiningType::T), (0xE31, 0xE31, JoiningType::T),
+    (0xE34, 0xE3A, JoiningType::T), (0xE47, 0xE4E, JoiningType::T), (0xEB1, 0xEB1, JoiningType::T),
+    (0xEB4, 0xEBC, JoiningType::T), (0xEC8, 0xECE, JoiningType::T), (0xF18, 0xF19, JoiningType::T),
+    (0xF35, 0xF35, JoiningType::T), (0xF37, 0xF37, JoiningType::T), (0xF39, 0xF39, JoiningType::T),
+    (0xF71, 0xF7E, JoiningType::T), (0xF80, 0xF84, JoiningType::T), (0xF86, 0xF87, JoiningType::T),
+    (0xF8D, 0xF97, JoiningType::T), (0xF99, 0xFBC, JoiningType::T), (0xFC6, 0xFC6, JoiningType::T),
+    (0x102D, 0x1030, JoiningType::T), (0x1032, 0x1037, JoiningType::T), (0x1039, 0x103A, JoiningType::T),
+    (0x103D, 0x103E, JoiningType::T), (0x1058, 0x1059, JoiningType::T), (0x105E, 0x1060, JoiningType::T),
+    (0x1071, 0x1074, JoiningType::T), (0x1082, 0x1082, JoiningType::T), (0x1085, 0x1086, JoiningType::T),
+    (0x108D, 0x108D, JoiningType::T), (0x109D, 0x109D, JoiningType::T), (0x135D, 0x135F, JoiningType::T),
+    (0x1712, 0x1714, JoiningType::T), (0x1732, 0x1733, JoiningType::T), (0x1752, 0x1753, JoiningType::T),
+    (0x1772, 0x1773, JoiningType::T), (0x17B4, 0x17B5, JoiningType::T), (0x17B7, 0x17BD, JoiningType::T),
+    (0x17C6, 0x17C6, JoiningType::T), (0x17C9, 0x17D3, JoiningType::T), (0x17DD, 0x17DD, JoiningType::T),
+    (0x1807, 0x1807, JoiningType::D), (0x180A, 0x180A, JoiningType::C), (0x180B, 0x180D, JoiningType::T),
+    (0x180F, 0x180F, JoiningType::T), (0x1820, 0x1878, JoiningType::D), (0x1885, 0x1886, JoiningType::T),
+    (0x1887, 0x18A8, JoiningType::D), (0x18A9, 0x18A9, JoiningType::T), (0x18AA, 0x18AA, JoiningType::D),
+    (0x1920, 0x1922, JoiningType::T), (0x1927, 0x1928, JoiningType::T), (0x1932, 0x1932, JoiningType::T),
+    (0x1939, 0x193B, JoiningType::T), (0x1A17, 0x1A18, JoiningType::T), (0x1A1B, 0x1A1B, JoiningType::T),
+    (0x1A56, 0x1A56, JoiningType::T), (0x1A58, 0x1A5E, JoiningType::T), (0x1A60, 0x1A60, JoiningType::T),
+    (0x1A62, 0x1A62, JoiningType::T), (0x1A65, 0x1A6C, JoiningType::T), (0x1A73, 0x1A7C, JoiningType::T),
+    (0x1A7F, 0x1A7F, JoiningType::T), (0x1AB0, 0x1ACE, JoiningType::T), (0x1B00, 0x1B03, JoiningType::T),
+    (0x1B34, 0x1B34, JoiningType::T), (0x1B36, 0x1B3A, JoiningType::T), (0x1B3C, 0x1B3C, JoiningType::T),
+    (0x1B42, 0x1B42, JoiningType::T), (0x1B6B, 0x1B73, JoiningType::T), (0x1B80, 0x1B81, JoiningType::T),
+    (0x1BA2, 0x1BA5, JoiningType::T), (0x1BA8, 0x1BA9, JoiningType::T), (0x1BAB, 0x1BAD, JoiningType::T),
+    (0x1BE6, 0x1BE6, JoiningType::T), (0x1BE8, 0x1BE9, JoiningType::T), (0x1BED, 0x1BED, JoiningType::T),
+    (0x1BEF, 0x1BF1, JoiningType::T), (0x1C2C, 0x1C33, JoiningType::T), (0x1C36, 0x1C37, JoiningType::T),
+    (0x1CD0, 0x1CD2, JoiningType::T), (0x1CD4, 0x1CE0, JoiningType::T), (0x1CE2, 0x1CE8, JoiningType::T),
+    (0x1CED, 0x1CED, JoiningType::T), (0x1CF4, 0x1CF4, JoiningType::T), (0x1CF8, 0x1CF9, JoiningType::T),
+    (0x1DC0, 0x1DFF, JoiningType::T), (0x200B, 0x200B, JoiningType::T), (0x200D, 0x200D, JoiningType::C),
+    (0x200E, 0x200F, JoiningType::T), (0x202A, 0x202E, JoiningType::T), (0x2060, 0x2064, JoiningType::T),
+    (0x206A, 0x206F, JoiningType::T), (0x20D0, 0x20F0, JoiningType::T), (0x2CEF, 0x2CF1, JoiningType::T),
+    (0x2D7F, 0x2D7F, JoiningType::T), (0x2DE0, 0x2DFF, JoiningType::T), (0x302A, 0x302D, JoiningType::T),
+    (0x3099, 0x309A, JoiningType::T), (0xA66F, 0xA672, JoiningType::T), (0xA674, 0xA67D, JoiningType::T),
+    (0xA69E, 0xA69F, JoiningType::T), (0xA6F0, 0xA6F1, JoiningType::T), (0xA802, 0xA802, JoiningType::T),
+    (0xA806, 0xA806, JoiningType::T), (0xA80B, 0xA80B, JoiningType::T), (0xA825, 0xA826, JoiningType::T),
+    (0xA82C, 0xA82C, JoiningType::T), (0xA840, 0xA871, JoiningType::D), (0xA872, 0xA872, JoiningType::L),
+    (0xA8C4, 0xA8C5, JoiningType::T), (0xA8E0, 0xA8F1, JoiningType::T), (0xA8FF, 0xA8FF, JoiningType::T),
+    (0xA926, 0xA92D, JoiningType::T), (0xA947, 0xA951, JoiningType::T), (0xA980, 0xA982, JoiningType::T),
+    (0xA9B3, 0xA9B3, JoiningType::T), (0xA9B6, 0xA9B9, JoiningType::T), (0xA9BC, 0xA9BD, JoiningType::T),
+    (0xA9E5, 0xA9E5, JoiningType::T), (0xAA29, 0xAA2E, JoiningType::T), (0xAA31, 0xAA32, JoiningType::T),
+    (0xAA35, 0xAA36, JoiningType::T), (0xAA43, 0xAA43, JoiningType::T), (0xAA4C, 0xAA4C, JoiningType::T),
+    (0xAA7C, 0xAA7C, JoiningType::T), (0xAAB0, 0xAAB0, JoiningType::T), (0xAAB2, 0xAAB4, JoiningType::T),
+    (0xAAB7, 0xAAB8, JoiningType::T), (0xAABE, 0xAABF, JoiningType::T), (0xAAC1, 0xAAC1, JoiningType::T),
+    (0xAAEC, 0xAAED, JoiningType::T), (0xAAF6, 0xAAF6, JoiningType::T), (0xABE5, 0xABE5, JoiningType::T),
+    (0xABE8, 0xABE8, JoiningType::T), (0xABED, 0xABED, JoiningType::T), (0xFB1E, 0xFB1E, JoiningType::T),
+    (0xFE00, 0xFE0F, JoiningType::T), (0xFE20, 0xFE2F, JoiningType::T), (0xFEFF, 0xFEFF, JoiningType::T),
+    (0xFFF9, 0xFFFB, JoiningType::T), (0x101FD, 0x101FD, JoiningType::T), (0x102E0, 0x102E0, JoiningType::T),
+    (0x10376, 0x1037A, JoiningType::T), (0x10A01, 0x10A03, JoiningType::T), (0x10A05, 0x10A06, JoiningType::T),
+    (0x10A0C, 0x10A0F, JoiningType::T), (0x10A38, 0x10A3A, JoiningType::T), (0x10A3F, 0x10A3F, JoiningType::T),
+    (0x10AC0, 0x10AC4, JoiningType::D), (0x10AC5, 0x10AC5, JoiningType::R), (0x10AC7, 0x10AC7, JoiningType::R),
+    (0x10AC9, 0x10ACA, JoiningType::R), (0x10ACD, 0x10ACD, JoiningType::L), (0x10ACE, 0x10AD2, JoiningType::R),
+    (0x10AD3, 0x10AD6, JoiningType::D), (0x10AD7, 0x10AD7, JoiningType::L), (0x10AD8, 0x10ADC, JoiningType::D),
+    (0x10ADD, 0x10ADD, JoiningType::R), (0x10ADE, 0x10AE0, JoiningType::D), (0x10AE1, 0x10AE1, JoiningType::R),
+    (0x10AE4, 0x10AE4, JoiningType::R), (0x10AE5, 0x10AE6, JoiningType::T), (0x10AEB, 0x10AEE, JoiningType::D),
+    (0x10AEF, 0x10AEF, JoiningType::R), (0x10B80, 0x10B80, JoiningType::D), (0x10B81, 0x10B81, JoiningType::R),
+    (0x10B82, 0x10B82, JoiningType::D), (0x10B83, 0x10B85, JoiningType::R), (0x10B86, 0x10B88, JoiningType::D),
+    (0x10B89, 0x10B89, JoiningType::R), (0x10B8A, 0x10B8B, JoiningType::D), (0x10B8C, 0x10B8C, JoiningType::R),
+    (0x10B8D, 0x10B8D, JoiningType::D), (0x10B8E, 0x10B8F, JoiningType::R), (0x10B90, 0x10B90, JoiningType::D),
+    (0x10B91, 0x10B91, JoiningType::R), (0x10BA9, 0x10BAC, JoiningType::R), (0x10BAD, 0x10BAE, JoiningType::D),
+    (0x10D00, 0x10D00, JoiningType::L), (0x10D01, 0x10D21, JoiningType::D), (0x10D22, 0x10D22, JoiningType::R),
+    (0x10D23, 0x10D23, JoiningType::D), (0x10D24, 0x10D27, JoiningType::T), (0x10EAB, 0x10EAC, JoiningType::T),
+    (0x10EFD, 0x10EFF, JoiningType::T), (0x10F30, 0x10F32, JoiningType::D), (0x10F33, 0x10F33, JoiningType::R),
+    (0x10F34, 0x10F44, JoiningType::D), (0x10F46, 0x10F50, JoiningType::T), (0x10F51, 0x10F53, JoiningType::D),
+    (0x10F54, 0x10F54, JoiningType::R), (0x10F70, 0x10F73, JoiningType::D), (0x10F74, 0x10F75, JoiningType::R),
+    (0x10F76, 0x10F81, JoiningType::D), (0x10F82, 0x10F85, JoiningType::T), (0x10FB0, 0x10FB0, JoiningType::D),
+    (0x10FB2, 0x10FB3, JoiningType::D), (0x10FB4, 0x10FB6, JoiningType::R), (0x10FB8, 0x10FB8, JoiningType::D),
+    (0x10FB9, 0x10FBA, JoiningType::R), (0x10FBB, 0x10FBC, JoiningType::D), (0x10FBD, 0x10FBD, JoiningType::R),
+    (0x10FBE, 0x10FBF, JoiningType::D), (0x10FC1, 0x10FC1, JoiningType::D), (0x10FC2, 0x10FC3, JoiningType::R),
+    (0x10FC4, 0x10FC4, JoiningType::D), (0x10FC9, 0x10FC9, JoiningType::R), (0x10FCA, 0x10FCA, JoiningType::D),
+    (0x10FCB, 0x10FCB, JoiningType::L), (0x11001, 0x11001, JoiningType::T), (0x11038, 0x11046, JoiningType::T),
+    (0x11070, 0x11070, JoiningType::T), (0x11073, 0x11074, JoiningType::T), (0x1107F, 0x11081, JoiningType::T),
+    (0x110B3, 0x110B6, JoiningType::T), (0x110B9, 0x110BA, JoiningType::T), (0x110C2, 0x110C2, JoiningType::T),
+    (0x11100, 0x11102, JoiningType::T), (0x11127, 0x1112B, JoiningType::T), (0x1112D, 0x11134, JoiningType::T),
+    (0x11173, 0x11173, JoiningType::T), (0x11180, 0x11181, JoiningType::T), (0x111B6, 0x111BE, JoiningType::T),
+    (0x111C9, 0x111CC, JoiningType::T), (0x111CF, 0x111CF, JoiningType::T), (0x1122F, 0x11231, JoiningType::T),
+    (0x11234, 0x11234, JoiningType::T), (0x11236, 0x11237, JoiningType::T), (0x1123E, 0x1123E, JoiningType::T),
+    (0x11241, 0x11241, JoiningType::T), (0x112DF, 0x112DF, JoiningType::T), (0x112E3, 0x112EA, JoiningType::T),
+    (0x11300, 0x11301, JoiningType::T), (0x1133B, 0x1133C, JoiningType::T), (0x11340, 0x11340, JoiningType::T),
+    (0x11366, 0x1136C, JoiningType::T), (0x11370, 0x11374, JoiningType::T), (0x11438, 0x1143F, JoiningType::T),
+    (0x11442, 0x11444, JoiningType::T), (0x11446, 0x11446, JoiningType::T), (0x1145E, 0x1145E, JoiningType::T),
+    (0x114B3, 0x114B8, JoiningType::T), (0x114BA, 0x114BA, JoiningType::T), (0x114BF, 0x114C0, JoiningType::T),
+    (0x114C2, 0x114C3, JoiningType::T), (0x115B2, 0x115B5, JoiningType::T), (0x115BC, 0x115BD, JoiningType::T),
+    (0x115BF, 0x115C0, JoiningType::T), (0x115DC, 0x115DD, JoiningType::T), (0x11633, 0x1163A, JoiningType::T),
+    (0x1163D, 0x1163D, JoiningType::T), (0x1163F, 0x11640, JoiningType::T), (0x116AB, 0x116AB, JoiningType::T),
+    (0x116AD, 0x116AD, JoiningType::T), (0x116B0, 0x116B5, JoiningType::T), (0x116B7, 0x116B7, JoiningType::T),
+    (0x1171D, 0x1171F, JoiningType::T), (0x11722, 0x11725, JoiningType::T), (0x11727, 0x1172B, JoiningType::T),
+    (0x1182F, 0x11837, JoiningType::T), (0x11839, 0x1183A, JoiningType::T), (0x1193B, 0x1193C, JoiningType::T),
+    (0x1193E, 0x1193E, JoiningType::T), (0x11943, 0x11943, JoiningType::T), (0x119D4, 0x119D7, JoiningType::T),
+    (0x119DA, 0x119DB, JoiningType::T), (0x119E0, 0x119E0, JoiningType::T), (0x11A01, 0x11A0A, JoiningType::T),
+    (0x11A33, 0x11A38, JoiningType::T), (0x11A3B, 0x11A3E, JoiningType::T), (0x11A47, 0x11A47, JoiningType::T),
+    (0x11A51, 0x11A56, JoiningType::T), (0x11A59, 0x11A5B, JoiningType::T), (0x11A8A, 0x11A96, JoiningType::T),
+    (0x11A98, 0x11A99, JoiningType::T), (0x11C30, 0x11C36, JoiningType::T), (0x11C38, 0x11C3D, JoiningType::T),
+    (0x11C3F, 0x11C3F, JoiningType::T), (0x11C92, 0x11CA7, JoiningType::T), (0x11CAA, 0x11CB0, JoiningType::T),
+    (0x11CB2, 0x11CB3, JoiningType::T), (0x11CB5, 0x11CB6, JoiningType::T), (0x11D31, 0x11D36, JoiningType::T),
+    (0x11D3A, 0x11D3A, JoiningType::T), (0x11D3C, 0x11D3D, JoiningType::T), (0x11D3F, 0x11D45, JoiningType::T),
+    (0x11D47, 0x11D47, JoiningType::T), (0x11D90, 0x11D91, JoiningType::T), (0x11D95, 0x11D95, JoiningType::T),
+    (0x11D97, 0x11D97, JoiningType::T), (0x11EF3, 0x11EF4, JoiningType::T), (0x11F00, 0x11F01, JoiningType::T),
+    (0x11F36, 0x11F3A, JoiningType::T), (0x11F40, 0x11F40, JoiningType::T), (0x11F42, 0x11F42, JoiningType::T),
+    (0x13430, 0x13440, JoiningType::T), (0x13447, 0x13455, JoiningType::T), (0x16AF0, 0x16AF4, JoiningType::T),
+    (0x16B30, 0x16B36, JoiningType::T), (0x16F4F, 0x16F4F, JoiningType::T), (0x16F8F, 0x16F92, JoiningType::T),
+    (0x16FE4, 0x16FE4, JoiningType::T), (0x1BC9D, 0x1BC9E, JoiningType::T), (0x1BCA0, 0x1BCA3, JoiningType::T),
+    (0x1CF00, 0x1CF2D, JoiningType::T), (0x1CF30, 0x1CF46, JoiningType::T), (0x1D167, 0x1D169, JoiningType::T),
+    (0x1D173, 0x1D182, JoiningType::T), (0x1D185, 0x1D18B, JoiningType::T), (0x1D1AA, 0x1D1AD, JoiningType::T),
+    (0x1D242, 0x1D244, JoiningType::T), (0x1DA00, 0x1DA36, JoiningType::T), (0x1DA3B, 0x1DA6C, JoiningType::T),
+    (0x1DA75, 0x1DA75, JoiningType::T), (0x1DA84, 0x1DA84, JoiningType::T), (0x1DA9B, 0x1DA9F, JoiningType::T),
+    (0x1DAA1, 0x1DAAF, JoiningType::T), (0x1E000, 0x1E006, JoiningType::T), (0x1E008, 0x1E018, JoiningType::T),
+    (0x1E01B, 0x1E021, JoiningType::T), (0x1E023, 0x1E024, JoiningType::T), (0x1E026, 0x1E02A, JoiningType::T),
+    (0x1E08F, 0x1E08F, JoiningType::T), (0x1E130, 0x1E136, JoiningType::T), (0x1E2AE, 0x1E2AE, JoiningType::T),
+    (0x1E2EC, 0x1E2EF, JoiningType::T), (0x1E4EC, 0x1E4EF, JoiningType::T), (0x1E8D0, 0x1E8D6, JoiningType::T),
+    (0x1E900, 0x1E943, JoiningType::D), (0x1E944, 0x1E94B, JoiningType::T), (0xE0001, 0xE0001, JoiningType::T),
+    (0xE0020, 0xE007F, JoiningType::T), (0xE0100, 0xE01EF, JoiningType::T),
+];
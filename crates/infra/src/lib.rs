@@ -4,9 +4,18 @@
 //! This crate hosts foundational utilities used across the project.
 
 pub mod ascii;
+pub mod base64;
 pub mod bytescanner;
+pub mod data_url;
+pub mod encoding;
+pub mod idna;
+pub mod mime;
 pub mod percent;
+pub mod psl;
+pub mod punycode;
 pub mod url;
+pub mod utf16;
+pub mod utf8;
 
 /// Returns true if the given byte is an ASCII whitespace per Infra definition (subset placeholder).
 /// This is a minimal placeholder and will be extended to match WHATWG Infra precisely.
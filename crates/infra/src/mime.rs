@@ -0,0 +1,452 @@
+// SPDX-License-Identifier: MIT
+//! MIME types as defined by the MIME Sniffing Standard ("parse a MIME type", "serialize a
+//! MIME type"). Parsing never fails on odd parameters: invalid ones are dropped, and only a
+//! missing or invalid type or subtype makes the whole input invalid.
+//! The HTTP token and quoted-string helpers from Fetch are public for header parsers that
+//! share the same grammar; they work on a `ByteScanner`.
+//! Content sniffing, which computes the MIME type a resource is handled as from its first
+//! bytes, lives in `sniff`.
+
+use std::fmt::{Display, Formatter};
+
+use crate::bytescanner::ByteScanner;
+
+mod sniff;
+
+pub use sniff::{
+    is_nosniff, match_archive_type, match_audio_or_video_type, match_font_type, match_image_type,
+    sniff, ResourceMetadata, RESOURCE_HEADER_LEN,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MimeType {
+    type_: String,
+    subtype: String,
+    parameters: Parameters,
+}
+
+/// The parameters of a MIME type: an ordered map from lowercase names to values.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parameters {
+    entries: Vec<(String, String)>,
+}
+
+impl Parameters {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+
+    /// Remove `name`, returning its value.
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        let index = self.entries.iter().position(|(n, _)| n == name)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Set `name` in place, or append it when it is new.
+    fn set(&mut self, name: String, value: String) {
+        match self.entries.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = value,
+            None => self.entries.push((name, value)),
+        }
+    }
+}
+
+impl MimeType {
+    /// Parse a MIME type such as `Text/HTML; charset="utf-8"`. Type, subtype and parameter
+    /// names are lowercased; the first occurrence of a parameter wins.
+    pub fn parse(input: &str) -> Option<MimeType> {
+        let input = input.trim_matches(|c: char| c.is_ascii() && is_http_whitespace(c as u8));
+        let mut scanner = ByteScanner::new(input.as_bytes());
+        let type_ = scanner.collect_while(|b| b != b'/');
+        if type_.is_empty() || !type_.iter().all(|&b| is_http_token(b)) {
+            return None;
+        }
+        scanner.next()?;
+        let subtype = trim_http_whitespace_end(scanner.collect_while(|b| b != b';'));
+        if subtype.is_empty() || !subtype.iter().all(|&b| is_http_token(b)) {
+            return None;
+        }
+        let mut mime_type = MimeType {
+            type_: ascii_lowercase(type_),
+            subtype: ascii_lowercase(subtype),
+            parameters: Parameters::default(),
+        };
+        // Each iteration starts on the ';' that ended the previous parameter.
+        while scanner.next().is_some() {
+            scanner.collect_while(is_http_whitespace);
+            let name = ascii_lowercase(scanner.collect_while(|b| b != b';' && b != b'='));
+            match scanner.peek() {
+                None => break,
+                Some(b';') => continue,
+                Some(_) => {
+                    scanner.next();
+                }
+            }
+            if scanner.is_eof() {
+                break;
+            }
+            let value = if scanner.peek() == Some(b'"') {
+                let value = collect_http_quoted_string(&mut scanner, true);
+                scanner.collect_while(|b| b != b';');
+                value
+            } else {
+                let value = trim_http_whitespace_end(scanner.collect_while(|b| b != b';'));
+                if value.is_empty() {
+                    continue;
+                }
+                String::from_utf8_lossy(value).into_owned()
+            };
+            if !name.is_empty()
+                && name.bytes().all(is_http_token)
+                && value.chars().all(is_http_quoted_string_token)
+                && !mime_type.parameters.contains(&name)
+            {
+                mime_type.parameters.set(name, value);
+            }
+        }
+        Some(mime_type)
+    }
+
+    /// A MIME type without parameters, e.g. `MimeType::new("text", "html")`. None when either
+    /// part is not an HTTP token.
+    pub fn new(type_: &str, subtype: &str) -> Option<MimeType> {
+        let is_token = |part: &str| !part.is_empty() && part.bytes().all(is_http_token);
+        if !is_token(type_) || !is_token(subtype) {
+            return None;
+        }
+        Some(MimeType {
+            type_: type_.to_ascii_lowercase(),
+            subtype: subtype.to_ascii_lowercase(),
+            parameters: Parameters::default(),
+        })
+    }
+
+    /// The type, e.g. `text` for `text/html`.
+    pub fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    /// The subtype, e.g. `html` for `text/html`.
+    pub fn subtype(&self) -> &str {
+        &self.subtype
+    }
+
+    /// The essence: type and subtype without parameters, e.g. `text/html`.
+    pub fn essence(&self) -> String {
+        format!("{}/{}", self.type_, self.subtype)
+    }
+
+    /// Compare the essence with `essence`, ASCII case-insensitively, so that
+    /// `text/html;charset=utf-8` has the essence `Text/HTML`.
+    pub fn has_essence(&self, essence: &str) -> bool {
+        essence.split_once('/').is_some_and(|(type_, subtype)| {
+            type_.eq_ignore_ascii_case(&self.type_) && subtype.eq_ignore_ascii_case(&self.subtype)
+        })
+    }
+
+    /// The value of the parameter `name` (lowercase).
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters.get(name)
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    /// Set a parameter, keeping its position if it already exists. The name is lowercased.
+    /// Returns false, leaving the parameters unchanged, when the name is not an HTTP token or
+    /// the value has characters a quoted string cannot hold.
+    pub fn set_parameter(&mut self, name: &str, value: &str) -> bool {
+        if name.is_empty()
+            || !name.bytes().all(is_http_token)
+            || !value.chars().all(is_http_quoted_string_token)
+        {
+            return false;
+        }
+        self.parameters
+            .set(name.to_ascii_lowercase(), value.to_owned());
+        true
+    }
+
+    pub fn remove_parameter(&mut self, name: &str) -> Option<String> {
+        self.parameters.remove(name)
+    }
+
+    // MIME type groups (MIME Sniffing Standard, section 4.6).
+
+    pub fn is_image(&self) -> bool {
+        self.type_ == "image"
+    }
+
+    pub fn is_audio_or_video(&self) -> bool {
+        matches!(self.type_.as_str(), "audio" | "video") || self.has_essence("application/ogg")
+    }
+
+    pub fn is_font(&self) -> bool {
+        self.type_ == "font"
+            || self.type_ == "application"
+                && matches!(
+                    self.subtype.as_str(),
+                    "font-cff"
+                        | "font-off"
+                        | "font-sfnt"
+                        | "font-ttf"
+                        | "font-woff"
+                        | "vnd.ms-fontobject"
+                        | "vnd.ms-opentype"
+                )
+    }
+
+    pub fn is_zip_based(&self) -> bool {
+        self.subtype.ends_with("+zip") || self.has_essence("application/zip")
+    }
+
+    pub fn is_archive(&self) -> bool {
+        self.type_ == "application"
+            && matches!(self.subtype.as_str(), "x-rar-compressed" | "zip" | "x-gzip")
+    }
+
+    pub fn is_xml(&self) -> bool {
+        self.subtype.ends_with("+xml")
+            || self.has_essence("text/xml")
+            || self.has_essence("application/xml")
+    }
+
+    pub fn is_html(&self) -> bool {
+        self.has_essence("text/html")
+    }
+
+    /// XML, HTML or PDF: types whose content can run script.
+    pub fn is_scriptable(&self) -> bool {
+        self.is_xml() || self.is_html() || self.has_essence("application/pdf")
+    }
+
+    pub fn is_javascript(&self) -> bool {
+        JAVASCRIPT_ESSENCES
+            .iter()
+            .any(|essence| self.has_essence(essence))
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.subtype.ends_with("+json")
+            || self.has_essence("application/json")
+            || self.has_essence("text/json")
+    }
+}
+
+const JAVASCRIPT_ESSENCES: &[&str] = &[
+    "application/ecmascript",
+    "application/javascript",
+    "application/x-ecmascript",
+    "application/x-javascript",
+    "text/ecmascript",
+    "text/javascript",
+    "text/javascript1.0",
+    "text/javascript1.1",
+    "text/javascript1.2",
+    "text/javascript1.3",
+    "text/javascript1.4",
+    "text/javascript1.5",
+    "text/jscript",
+    "text/livescript",
+    "text/x-ecmascript",
+    "text/x-javascript",
+];
+
+impl Display for MimeType {
+    /// Parameter values that are empty or not HTTP tokens are written as quoted strings.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.type_, self.subtype)?;
+        for (name, value) in self.parameters.iter() {
+            write!(f, ";{}=", name)?;
+            if !value.is_empty() && value.bytes().all(is_http_token) {
+                f.write_str(value)?;
+            } else {
+                f.write_str("\"")?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{}", c)?;
+                }
+                f.write_str("\"")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fetch's "collect an HTTP quoted string". The scanner must be on the opening '"'; it is left
+/// after the closing '"' or at the end of input. With `extract_value`, the content is returned
+/// with backslash escapes resolved; otherwise the raw text including the quotes is returned.
+pub fn collect_http_quoted_string(scanner: &mut ByteScanner<'_>, extract_value: bool) -> String {
+    let mut value = Vec::new();
+    let mut raw = Vec::new();
+    raw.extend(scanner.next());
+    loop {
+        let run = scanner.collect_while(|b| b != b'"' && b != b'\\');
+        value.extend_from_slice(run);
+        raw.extend_from_slice(run);
+        let Some(quote_or_backslash) = scanner.next() else {
+            break;
+        };
+        raw.push(quote_or_backslash);
+        if quote_or_backslash == b'"' {
+            break;
+        }
+        match scanner.next() {
+            Some(escaped) => {
+                value.push(escaped);
+                raw.push(escaped);
+            }
+            None => {
+                value.push(b'\\');
+                break;
+            }
+        }
+    }
+    // Only whole ASCII bytes were dropped, so both are still UTF-8.
+    String::from_utf8_lossy(if extract_value { &value } else { &raw }).into_owned()
+}
+
+/// HTTP whitespace: tab, LF, CR and space.
+pub fn is_http_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\t' | b'\n' | b'\r' | b' ')
+}
+
+/// HTTP token code points, which type, subtype and parameter names consist of.
+pub fn is_http_token(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+}
+
+/// HTTP quoted-string token code points: tab, U+0020 to U+007E and U+0080 to U+00FF.
+pub fn is_http_quoted_string_token(c: char) -> bool {
+    matches!(c, '\t' | ' '..='~' | '\u{80}'..='\u{FF}')
+}
+
+fn trim_http_whitespace_end(bytes: &[u8]) -> &[u8] {
+    let end = bytes
+        .iter()
+        .rposition(|&b| !is_http_whitespace(b))
+        .map_or(0, |i| i + 1);
+    &bytes[..end]
+}
+
+fn ascii_lowercase(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_serializes() {
+        let mime = MimeType::parse(" Text/HTML ; Charset=\"utf-8\"; charset=latin1;x").unwrap();
+        assert_eq!((mime.type_(), mime.subtype()), ("text", "html"));
+        assert_eq!(mime.parameter("charset"), Some("utf-8"));
+        assert_eq!(mime.to_string(), "text/html;charset=utf-8");
+
+        let mime = MimeType::parse("a/b;q=\"x\\\"y\\\\\" junk;empty=;e=\"\";sp=a b ").unwrap();
+        assert_eq!(
+            mime.parameters().iter().collect::<Vec<_>>(),
+            [("q", "x\"y\\"), ("e", ""), ("sp", "a b")]
+        );
+        assert_eq!(mime.to_string(), "a/b;q=\"x\\\"y\\\\\";e=\"\";sp=\"a b\"");
+    }
+
+    #[test]
+    fn rejects_invalid_types() {
+        for input in [
+            "",
+            "text",
+            "text/",
+            "/html",
+            "te xt/html",
+            "text/ht(ml",
+            "text/html\u{e9}",
+        ] {
+            assert_eq!(MimeType::parse(input), None, "{:?}", input);
+        }
+        let mime = MimeType::parse("text/plain;bad name=x;n=\u{100}").unwrap();
+        assert_eq!(mime.to_string(), "text/plain");
+    }
+
+    #[test]
+    fn essence_and_parameters() {
+        let mut mime = MimeType::parse("Text/HTML;Charset=UTF-8").unwrap();
+        assert_eq!(mime.essence(), "text/html");
+        assert!(mime.has_essence("TEXT/html"));
+        assert!(!mime.has_essence("text/plain") && !mime.has_essence("text"));
+
+        assert!(mime.set_parameter("Level", "1"));
+        assert!(mime.set_parameter("charset", "shift_jis"));
+        assert!(!mime.set_parameter("bad name", "x"));
+        assert!(!mime.set_parameter("x", "\u{3042}"));
+        assert_eq!(mime.to_string(), "text/html;charset=shift_jis;level=1");
+        assert_eq!(mime.remove_parameter("charset"), Some("shift_jis".into()));
+        assert_eq!(mime.parameters().len(), 1);
+
+        let plain = MimeType::new("Text", "Plain").unwrap();
+        assert_eq!(plain.to_string(), "text/plain");
+        assert_eq!(MimeType::new("text", "pl ain"), None);
+    }
+
+    #[test]
+    fn collects_http_quoted_strings() {
+        for (input, extracted, raw, rest) in [
+            ("\"\\", "\\", "\"\\", ""),
+            ("\"Hello\" World", "Hello", "\"Hello\"", " World"),
+            (
+                "\"Hello \\\\ World\\\"\"",
+                "Hello \\ World\"",
+                "\"Hello \\\\ World\\\"\"",
+                "",
+            ),
+        ] {
+            let mut scanner = ByteScanner::new(input.as_bytes());
+            assert_eq!(collect_http_quoted_string(&mut scanner, true), extracted);
+            assert_eq!(&input[scanner.position()..], rest);
+            let mut scanner = ByteScanner::new(input.as_bytes());
+            assert_eq!(collect_http_quoted_string(&mut scanner, false), raw);
+        }
+    }
+
+    #[test]
+    fn mime_type_groups() {
+        let mime = |input: &str| MimeType::parse(input).unwrap();
+        assert!(mime("image/svg+xml").is_image() && mime("image/svg+xml").is_xml());
+        assert!(mime("application/ogg").is_audio_or_video());
+        assert!(mime("application/vnd.ms-fontobject").is_font() && mime("font/woff2").is_font());
+        assert!(
+            mime("application/epub+zip").is_zip_based()
+                && !mime("application/epub+zip").is_archive()
+        );
+        assert!(mime("text/html;charset=utf-8").is_scriptable());
+        assert!(mime("application/pdf").is_scriptable() && !mime("text/plain").is_scriptable());
+        assert!(
+            mime("Text/JavaScript1.5").is_javascript() && !mime("text/javascript2").is_javascript()
+        );
+        assert!(mime("application/ld+json").is_json());
+    }
+}
@@ -0,0 +1,658 @@
+// SPDX-License-Identifier: MIT
+//! MIME type sniffing (MIME Sniffing Standard, sections 5 to 7).
+//! `sniff` computes the MIME type of a resource from the supplied `Content-Type`, the
+//! `X-Content-Type-Options: nosniff` flag and the resource header (its first 1445 bytes).
+//! The `match_*` functions are the spec's type pattern matching algorithms, which
+//! context-specific sniffing (for `<img>`, `@font-face`, ...) uses directly.
+
+use super::MimeType;
+
+/// The number of bytes of a resource that sniffing looks at.
+pub const RESOURCE_HEADER_LEN: usize = 1445;
+
+/// What the transport says about a resource.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceMetadata {
+    /// The MIME type from `Content-Type`, if any and if it parsed.
+    pub supplied_mime_type: Option<MimeType>,
+    /// Set for the `Content-Type` values old Apache versions sent for every file; such
+    /// resources are only checked for text versus binary.
+    pub check_for_apache_bug: bool,
+    /// Set by `X-Content-Type-Options: nosniff`.
+    pub no_sniff: bool,
+}
+
+impl ResourceMetadata {
+    /// Metadata for an HTTP response with the given header values.
+    pub fn from_headers(content_type: Option<&str>, content_type_options: Option<&str>) -> Self {
+        ResourceMetadata {
+            supplied_mime_type: content_type.and_then(MimeType::parse),
+            check_for_apache_bug: content_type.is_some_and(|value| {
+                matches!(
+                    value,
+                    "text/plain"
+                        | "text/plain; charset=ISO-8859-1"
+                        | "text/plain; charset=iso-8859-1"
+                        | "text/plain; charset=UTF-8"
+                )
+            }),
+            no_sniff: content_type_options.is_some_and(is_nosniff),
+        }
+    }
+}
+
+/// Fetch's "determine nosniff": the first value of `X-Content-Type-Options` is `nosniff`.
+pub fn is_nosniff(content_type_options: &str) -> bool {
+    content_type_options.split(',').next().is_some_and(|value| {
+        value
+            .trim_matches(is_http_whitespace_char)
+            .eq_ignore_ascii_case("nosniff")
+    })
+}
+
+/// The computed MIME type of a resource whose first bytes are `header`.
+pub fn sniff(metadata: &ResourceMetadata, header: &[u8]) -> MimeType {
+    let header = &header[..header.len().min(RESOURCE_HEADER_LEN)];
+    let supplied = match &metadata.supplied_mime_type {
+        Some(supplied)
+            if !supplied.has_essence("unknown/unknown")
+                && !supplied.has_essence("application/unknown")
+                && !supplied.has_essence("*/*") =>
+        {
+            supplied
+        }
+        _ => return sniff_unknown(header, !metadata.no_sniff),
+    };
+    if metadata.no_sniff {
+        return supplied.clone();
+    }
+    if metadata.check_for_apache_bug {
+        return sniff_text_or_binary(header);
+    }
+    if supplied.is_xml() {
+        return supplied.clone();
+    }
+    if supplied.is_html() {
+        return sniff_feed_or_html(header).unwrap_or_else(|| supplied.clone());
+    }
+    if supplied.is_image() {
+        if let Some(matched) = match_image_type(header) {
+            return matched;
+        }
+    }
+    if supplied.is_audio_or_video() {
+        if let Some(matched) = match_audio_or_video_type(header) {
+            return matched;
+        }
+    }
+    supplied.clone()
+}
+
+/// A row of a pattern table. An empty mask means every byte must match exactly.
+struct Pattern {
+    pattern: &'static [u8],
+    mask: &'static [u8],
+    leading_ignored: &'static [u8],
+    mime_type: &'static str,
+}
+
+const fn exact(pattern: &'static [u8], mime_type: &'static str) -> Pattern {
+    Pattern {
+        pattern,
+        mask: &[],
+        leading_ignored: &[],
+        mime_type,
+    }
+}
+
+const fn masked(pattern: &'static [u8], mask: &'static [u8], mime_type: &'static str) -> Pattern {
+    Pattern {
+        pattern,
+        mask,
+        leading_ignored: &[],
+        mime_type,
+    }
+}
+
+const WHITESPACE: &[u8] = b"\t\n\x0C\r ";
+
+impl Pattern {
+    /// The spec's pattern matching algorithm.
+    fn matches(&self, input: &[u8]) -> bool {
+        let start = input
+            .iter()
+            .position(|b| !self.leading_ignored.contains(b))
+            .unwrap_or(input.len());
+        let input = &input[start..];
+        input.len() >= self.pattern.len()
+            && self.pattern.iter().enumerate().all(|(i, &p)| {
+                let mask = self.mask.get(i).copied().unwrap_or(0xFF);
+                input[i] & mask == p
+            })
+    }
+}
+
+fn match_table(table: &[Pattern], input: &[u8]) -> Option<MimeType> {
+    table
+        .iter()
+        .find(|row| row.matches(input))
+        .map(|row| parse_known(row.mime_type))
+}
+
+fn parse_known(mime_type: &str) -> MimeType {
+    MimeType::parse(mime_type).expect("valid MIME type")
+}
+
+/// HTML signatures, matched ASCII case-insensitively after leading whitespace and followed by
+/// a tag-terminating byte (space or '>').
+const HTML_SIGNATURES: &[&[u8]] = &[
+    b"<!DOCTYPE HTML",
+    b"<HTML",
+    b"<HEAD",
+    b"<SCRIPT",
+    b"<IFRAME",
+    b"<H1",
+    b"<DIV",
+    b"<FONT",
+    b"<TABLE",
+    b"<A",
+    b"<STYLE",
+    b"<TITLE",
+    b"<B",
+    b"<BODY",
+    b"<BR",
+    b"<P",
+    b"<!--",
+];
+
+const SCRIPTABLE_PATTERNS: &[Pattern] = &[
+    Pattern {
+        pattern: b"<?xml",
+        mask: &[],
+        leading_ignored: WHITESPACE,
+        mime_type: "text/xml",
+    },
+    exact(b"%PDF-", "application/pdf"),
+];
+
+const UNKNOWN_PATTERNS: &[Pattern] = &[
+    exact(b"%!PS-Adobe-", "application/postscript"),
+    exact(b"\xFE\xFF", "text/plain"),
+    exact(b"\xFF\xFE", "text/plain"),
+    exact(b"\xEF\xBB\xBF", "text/plain"),
+];
+
+const IMAGE_PATTERNS: &[Pattern] = &[
+    exact(b"\x00\x00\x01\x00", "image/x-icon"),
+    exact(b"\x00\x00\x02\x00", "image/x-icon"),
+    exact(b"BM", "image/bmp"),
+    exact(b"GIF87a", "image/gif"),
+    exact(b"GIF89a", "image/gif"),
+    masked(
+        b"RIFF\x00\x00\x00\x00WEBPVP",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF\xFF\xFF",
+        "image/webp",
+    ),
+    exact(b"\x89PNG\r\n\x1A\n", "image/png"),
+    exact(b"\xFF\xD8\xFF", "image/jpeg"),
+];
+
+const AUDIO_VIDEO_PATTERNS: &[Pattern] = &[
+    masked(
+        b"FORM\x00\x00\x00\x00AIFF",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        "audio/aiff",
+    ),
+    exact(b"ID3", "audio/mpeg"),
+    exact(b"OggS\x00", "application/ogg"),
+    exact(b"MThd\x00\x00\x00\x06", "audio/midi"),
+    masked(
+        b"RIFF\x00\x00\x00\x00AVI ",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        "video/avi",
+    ),
+    masked(
+        b"RIFF\x00\x00\x00\x00WAVE",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        "audio/wave",
+    ),
+];
+
+const FONT_PATTERNS: &[Pattern] = &[
+    masked(
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+          \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00LP",
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+          \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xFF\xFF",
+        "application/vnd.ms-fontobject",
+    ),
+    exact(b"\x00\x01\x00\x00", "font/ttf"),
+    exact(b"OTTO", "font/otf"),
+    exact(b"ttcf", "font/collection"),
+    exact(b"wOFF", "font/woff"),
+    exact(b"wOF2", "font/woff2"),
+];
+
+// The standard lists "Rar " (0x20) rather than the "Rar!" that RAR files start with.
+const ARCHIVE_PATTERNS: &[Pattern] = &[
+    exact(b"\x1F\x8B\x08", "application/x-gzip"),
+    exact(b"PK\x03\x04", "application/zip"),
+    exact(b"Rar \x1A\x07\x00", "application/x-rar-compressed"),
+];
+
+/// Image type pattern matching.
+pub fn match_image_type(header: &[u8]) -> Option<MimeType> {
+    match_table(IMAGE_PATTERNS, header)
+}
+
+/// Audio or video type pattern matching, including the MP4, WebM and MP3 signatures.
+pub fn match_audio_or_video_type(header: &[u8]) -> Option<MimeType> {
+    match_table(AUDIO_VIDEO_PATTERNS, header).or_else(|| {
+        if matches_mp4(header) {
+            Some(parse_known("video/mp4"))
+        } else if matches_webm(header) {
+            Some(parse_known("video/webm"))
+        } else if matches_mp3_without_id3(header) {
+            Some(parse_known("audio/mpeg"))
+        } else {
+            None
+        }
+    })
+}
+
+/// Font type pattern matching.
+pub fn match_font_type(header: &[u8]) -> Option<MimeType> {
+    match_table(FONT_PATTERNS, header)
+}
+
+/// Archive type pattern matching.
+pub fn match_archive_type(header: &[u8]) -> Option<MimeType> {
+    match_table(ARCHIVE_PATTERNS, header)
+}
+
+/// The rules for identifying an unknown MIME type.
+fn sniff_unknown(header: &[u8], sniff_scriptable: bool) -> MimeType {
+    if sniff_scriptable {
+        if let Some(html) = match_html(header) {
+            return html;
+        }
+        if let Some(matched) = match_table(SCRIPTABLE_PATTERNS, header) {
+            return matched;
+        }
+    }
+    match_table(UNKNOWN_PATTERNS, header)
+        .or_else(|| match_image_type(header))
+        .or_else(|| match_audio_or_video_type(header))
+        .or_else(|| match_archive_type(header))
+        .unwrap_or_else(|| {
+            if header.iter().any(|&b| is_binary_data_byte(b)) {
+                parse_known("application/octet-stream")
+            } else {
+                parse_known("text/plain")
+            }
+        })
+}
+
+fn match_html(header: &[u8]) -> Option<MimeType> {
+    let start = header
+        .iter()
+        .position(|b| !WHITESPACE.contains(b))
+        .unwrap_or(header.len());
+    let rest = &header[start..];
+    HTML_SIGNATURES
+        .iter()
+        .any(|signature| {
+            rest.len() > signature.len()
+                && rest[..signature.len()].eq_ignore_ascii_case(signature)
+                && matches!(rest[signature.len()], b' ' | b'>')
+        })
+        .then(|| parse_known("text/html"))
+}
+
+/// The rules for distinguishing if a resource is text or binary.
+fn sniff_text_or_binary(header: &[u8]) -> MimeType {
+    if header.starts_with(b"\xFE\xFF")
+        || header.starts_with(b"\xFF\xFE")
+        || header.starts_with(b"\xEF\xBB\xBF")
+        || !header.iter().any(|&b| is_binary_data_byte(b))
+    {
+        return parse_known("text/plain");
+    }
+    sniff_unknown(header, false)
+}
+
+fn is_binary_data_byte(byte: u8) -> bool {
+    matches!(byte, 0x00..=0x08 | 0x0B | 0x0E..=0x1A | 0x1C..=0x1F)
+}
+
+fn is_http_whitespace_char(c: char) -> bool {
+    c.is_ascii() && super::is_http_whitespace(c as u8)
+}
+
+/// The rules for distinguishing if a resource is a feed or HTML: RSS and Atom feeds served as
+/// `text/html`. None means the resource stays HTML.
+fn sniff_feed_or_html(header: &[u8]) -> Option<MimeType> {
+    const RSS_NS: &[u8] = b"http://purl.org/rss/1.0/";
+    const RDF_NS: &[u8] = b"http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+    let at = |s: usize, pattern: &[u8]| {
+        header
+            .get(s..)
+            .is_some_and(|rest| rest.starts_with(pattern))
+    };
+    let find = |s: usize, pattern: &[u8]| {
+        header
+            .get(s..)
+            .and_then(|rest| {
+                rest.windows(pattern.len())
+                    .position(|window| window == pattern)
+            })
+            .map(|offset| s + offset)
+    };
+
+    let mut s = if header.starts_with(b"\xEF\xBB\xBF") {
+        3
+    } else {
+        0
+    };
+    loop {
+        match header.get(s) {
+            Some(b'<') => {
+                s += 1;
+                break;
+            }
+            Some(b) if WHITESPACE.contains(b) => s += 1,
+            _ => return None,
+        }
+    }
+    loop {
+        if at(s, b"!--") {
+            s = find(s + 3, b"-->")? + 3;
+        } else if at(s, b"!") {
+            s = find(s + 1, b">")? + 1;
+        } else if at(s, b"?") {
+            s = find(s + 1, b"?>")? + 2;
+        } else if at(s, b"rss") {
+            return Some(parse_known("application/rss+xml"));
+        } else if at(s, b"feed") {
+            return Some(parse_known("application/atom+xml"));
+        } else if at(s, b"rdf:RDF") {
+            // RSS 1.0 declares both namespaces, in either order.
+            let rest = s + b"rdf:RDF".len();
+            let declares_rss = find(rest, RSS_NS).is_some() && find(rest, RDF_NS).is_some();
+            return declares_rss.then(|| parse_known("application/rss+xml"));
+        } else {
+            return None;
+        }
+        s = find(s, b"<")? + 1;
+    }
+}
+
+/// The signature for MP4: an `ftyp` box whose major or a compatible brand is `mp4*`.
+fn matches_mp4(header: &[u8]) -> bool {
+    if header.len() < 12 {
+        return false;
+    }
+    let box_size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    if header.len() < box_size || !box_size.is_multiple_of(4) || &header[4..8] != b"ftyp" {
+        return false;
+    }
+    if &header[8..11] == b"mp4" {
+        return true;
+    }
+    (16..box_size)
+        .step_by(4)
+        .any(|offset| header.get(offset..offset + 3) == Some(&b"mp4"[..]))
+}
+
+/// The signature for WebM: an EBML header whose DocType element (0x4282) is `webm`.
+fn matches_webm(header: &[u8]) -> bool {
+    if !header.starts_with(b"\x1A\x45\xDF\xA3") {
+        return false;
+    }
+    let length = header.len();
+    let mut iter = 4;
+    while iter < length && iter < 38 {
+        if header[iter..].starts_with(b"\x42\x82") {
+            iter += 2;
+            if iter >= length {
+                break;
+            }
+            iter += vint_length(header[iter]);
+            if iter + 4 >= length {
+                break;
+            }
+            // The element data may be padded with leading zero bytes.
+            let data = &header[iter..];
+            let zeros = data.iter().take_while(|&&b| b == 0).count();
+            if data[zeros..].starts_with(b"webm") {
+                return true;
+            }
+        }
+        iter += 1;
+    }
+    false
+}
+
+/// The size in bytes of an EBML variable-length integer, from its first byte.
+fn vint_length(first: u8) -> usize {
+    (first.leading_zeros() as usize + 1).min(8)
+}
+
+/// The signature for MP3 without an ID3 tag: two consecutive MPEG audio frame headers.
+fn matches_mp3_without_id3(header: &[u8]) -> bool {
+    let Some(frame) = Mp3Frame::parse(header, 0) else {
+        return false;
+    };
+    let skipped_bytes = frame.size();
+    // The standard compares against "s - length"; the bytes left after `s` is what it means.
+    if skipped_bytes < 4 || skipped_bytes > header.len() {
+        return false;
+    }
+    Mp3Frame::parse(header, skipped_bytes).is_some()
+}
+
+/// The fields "parse an mp3 frame" extracts.
+struct Mp3Frame {
+    version: u8,
+    bitrate: u32,
+    freq: u32,
+    pad: bool,
+}
+
+impl Mp3Frame {
+    /// "Match an mp3 header" and "parse an mp3 frame" at offset `s`. Four bytes must remain
+    /// at `s`, and the frame sync needs both the 0xFF byte and the top three bits of the next.
+    fn parse(header: &[u8], s: usize) -> Option<Mp3Frame> {
+        const MP3_RATES: [u32; 15] = [
+            0, 32000, 40000, 48000, 56000, 64000, 80000, 96000, 112000, 128000, 160000, 192000,
+            224000, 256000, 320000,
+        ];
+        const MP25_RATES: [u32; 15] = [
+            0, 8000, 16000, 24000, 32000, 40000, 48000, 56000, 64000, 80000, 96000, 112000, 128000,
+            144000, 160000,
+        ];
+        const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+        let bytes = header.get(s..s + 4)?;
+        if bytes[0] != 0xFF || bytes[1] & 0xE0 != 0xE0 {
+            return None;
+        }
+        let layer = (bytes[1] & 0x06) >> 1;
+        if layer == 0 {
+            return None;
+        }
+        let bitrate_index = usize::from((bytes[2] & 0xF0) >> 4);
+        if bitrate_index == 15 {
+            return None;
+        }
+        let sample_rate_index = usize::from((bytes[2] & 0x0C) >> 2);
+        if sample_rate_index == 3 {
+            return None;
+        }
+        // The layer bits count down from Layer I; only Layer III is accepted.
+        if 4 - layer != 3 {
+            return None;
+        }
+        let version = (bytes[1] & 0x18) >> 3;
+        let bitrate = if version & 0x01 != 0 {
+            MP3_RATES[bitrate_index]
+        } else {
+            MP25_RATES[bitrate_index]
+        };
+        Some(Mp3Frame {
+            version,
+            bitrate,
+            freq: SAMPLE_RATES[sample_rate_index],
+            pad: bytes[2] & 0x02 != 0,
+        })
+    }
+
+    /// "Compute an mp3 frame size". As in the standard, `freq` is the MPEG-1 sample rate for
+    /// every version and only version 1 uses the smaller scale, so MPEG-2 and MPEG-2.5 sizes
+    /// differ from the real frame length.
+    fn size(&self) -> usize {
+        let scale = if self.version == 1 { 72 } else { 144 };
+        let size = self.bitrate * scale / self.freq;
+        size as usize + usize::from(self.pad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sniff_with(content_type: Option<&str>, header: &[u8]) -> String {
+        sniff(&ResourceMetadata::from_headers(content_type, None), header).to_string()
+    }
+
+    #[test]
+    fn unknown_resources() {
+        assert_eq!(sniff_with(None, b"  \n<!doctype html><p>"), "text/html");
+        assert_eq!(sniff_with(None, b"<HTML>"), "text/html");
+        assert_eq!(sniff_with(None, b"<htmlx>"), "text/plain");
+        assert_eq!(
+            sniff_with(Some("*/*"), b"<?xml version=\"1.0\"?>"),
+            "text/xml"
+        );
+        assert_eq!(sniff_with(None, b"%PDF-1.7\n"), "application/pdf");
+        assert_eq!(sniff_with(None, b"\x89PNG\r\n\x1A\n\0\0"), "image/png");
+        assert_eq!(sniff_with(None, b"RIFF\x24\0\0\0WEBPVP8 "), "image/webp");
+        assert_eq!(sniff_with(None, b"PK\x03\x04\x14\0"), "application/zip");
+        assert_eq!(sniff_with(None, b"\xEF\xBB\xBFhello"), "text/plain");
+        assert_eq!(sniff_with(None, b"plain text\r\n"), "text/plain");
+        assert_eq!(
+            sniff_with(None, b"\x00\x01\x02"),
+            "application/octet-stream"
+        );
+        // Fonts are only recognized by context-specific sniffing.
+        assert_eq!(sniff_with(None, b"wOF2\0\x01"), "application/octet-stream");
+        assert_eq!(
+            match_font_type(b"wOF2\0\x01").unwrap().to_string(),
+            "font/woff2"
+        );
+    }
+
+    #[test]
+    fn nosniff_and_supplied_types() {
+        let metadata = ResourceMetadata::from_headers(None, Some("NoSniff , other"));
+        assert!(metadata.no_sniff);
+        assert_eq!(sniff(&metadata, b"<html>").to_string(), "text/plain");
+        assert!(!is_nosniff("other, nosniff"));
+
+        let metadata = ResourceMetadata::from_headers(Some("text/css"), Some("nosniff"));
+        assert_eq!(sniff(&metadata, b"<html>").to_string(), "text/css");
+        assert_eq!(sniff_with(Some("text/css"), b"<html>"), "text/css");
+        assert_eq!(
+            sniff_with(Some("image/gif"), b"\x89PNG\r\n\x1A\n"),
+            "image/png"
+        );
+        assert_eq!(sniff_with(Some("image/gif"), b"not an image"), "image/gif");
+        assert_eq!(
+            sniff_with(Some("application/xml;charset=utf-8"), b"<html>"),
+            "application/xml;charset=utf-8"
+        );
+    }
+
+    #[test]
+    fn apache_bug_checks_text_or_binary() {
+        let content_type = Some("text/plain; charset=ISO-8859-1");
+        assert_eq!(sniff_with(content_type, b"just text"), "text/plain");
+        assert_eq!(
+            sniff_with(content_type, b"\xFF\xD8\xFF\xE0\x00\x10JFIF"),
+            "image/jpeg"
+        );
+        assert_eq!(
+            sniff_with(content_type, b"<html>\x00"),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            sniff_with(Some("text/plain;charset=utf-8"), b"\x00\x01"),
+            "text/plain;charset=utf-8"
+        );
+    }
+
+    #[test]
+    fn feeds_served_as_html() {
+        let html = Some("text/html");
+        assert_eq!(
+            sniff_with(html, b"<?xml version=\"1.0\"?>\n<!-- c --><rss>"),
+            "application/rss+xml"
+        );
+        assert_eq!(
+            sniff_with(html, b"\xEF\xBB\xBF <feed xmlns=\"...\">"),
+            "application/atom+xml"
+        );
+        let rdf = b"<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" \
+                    xmlns=\"http://purl.org/rss/1.0/\">";
+        assert_eq!(sniff_with(html, rdf), "application/rss+xml");
+        assert_eq!(sniff_with(html, b"<!DOCTYPE html><html>"), "text/html");
+        assert_eq!(sniff_with(html, b"<!-- unterminated <rss>"), "text/html");
+    }
+
+    #[test]
+    fn audio_and_video_signatures() {
+        let mp4 = b"\0\0\0\x18ftypisom\0\0\0\0isommp42";
+        assert_eq!(
+            match_audio_or_video_type(mp4).unwrap().to_string(),
+            "video/mp4"
+        );
+        let webm = b"\x1A\x45\xDF\xA3\x9F\x42\x86\x81\x01\x42\x82\x84webm\x42\x87";
+        assert_eq!(
+            match_audio_or_video_type(webm).unwrap().to_string(),
+            "video/webm"
+        );
+        // MPEG-1 layer III, 128 kbit/s, 44.1 kHz: frames are 417 bytes apart.
+        let mut mp3 = vec![0u8; 421];
+        mp3[..4].copy_from_slice(b"\xFF\xFB\x90\x00");
+        mp3[417..].copy_from_slice(b"\xFF\xFB\x90\x00");
+        assert_eq!(
+            match_audio_or_video_type(&mp3).unwrap().to_string(),
+            "audio/mpeg"
+        );
+        assert_eq!(match_audio_or_video_type(&mp3[..417]), None);
+        assert_eq!(sniff_with(Some("video/mp4"), b"OggS\0"), "application/ogg");
+    }
+
+    #[test]
+    fn mp3_frame_sizes_follow_the_standard() {
+        let frames_at = |first: &[u8], distance: usize| {
+            let mut mp3 = vec![0u8; distance + 4];
+            mp3[..4].copy_from_slice(first);
+            mp3[distance..].copy_from_slice(first);
+            match_audio_or_video_type(&mp3).map(|mime| mime.to_string())
+        };
+        // MPEG-2.5 layer III at 64 kbit/s: 144 * 64000 / 44100, not the real 417 bytes.
+        let mpeg25 = b"\xFF\xE2\x80\x00";
+        assert_eq!(frames_at(mpeg25, 208).as_deref(), Some("audio/mpeg"));
+        assert_eq!(frames_at(mpeg25, 417), None);
+        // The reserved version 1 is accepted and uses a scale of 72.
+        let reserved = b"\xFF\xEA\x90\x00";
+        assert_eq!(frames_at(reserved, 208).as_deref(), Some("audio/mpeg"));
+        // Padding adds a byte; layer II frames never match.
+        assert_eq!(
+            frames_at(b"\xFF\xFB\x92\x00", 418).as_deref(),
+            Some("audio/mpeg")
+        );
+        assert_eq!(frames_at(b"\xFF\xFD\x90\x00", 417), None);
+    }
+}
@@ -2,10 +2,90 @@
 // Copyright (c) SeleniaProject
 
 //! Percent-encoding utilities compatible with URL-like use cases.
-//! This module provides generic helpers; URL component specific rules can be built on top.
+//! This module provides generic helpers plus the URL Standard's named percent-encode sets
+//! (`C0_CONTROL`, `FRAGMENT`, `QUERY`, ...), which the URL parser encodes components with.
 
 use crate::ascii;
 
+/// A percent-encode set: the ASCII bytes to encode. Non-ASCII bytes are always encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeSet {
+    mask: u128,
+}
+
+impl EncodeSet {
+    /// A set that encodes only non-ASCII bytes.
+    pub const EMPTY: EncodeSet = EncodeSet { mask: 0 };
+
+    /// This set plus `byte`.
+    pub const fn add(self, byte: u8) -> Self {
+        assert!(byte.is_ascii(), "non-ASCII bytes are always in the set");
+        EncodeSet {
+            mask: self.mask | (1 << byte),
+        }
+    }
+
+    /// This set plus every byte of `bytes`.
+    pub const fn add_all(self, bytes: &[u8]) -> Self {
+        let mut set = self;
+        let mut i = 0;
+        while i < bytes.len() {
+            set = set.add(bytes[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// This set without `byte`.
+    pub const fn remove(self, byte: u8) -> Self {
+        assert!(byte.is_ascii(), "non-ASCII bytes are always in the set");
+        EncodeSet {
+            mask: self.mask & !(1 << byte),
+        }
+    }
+
+    /// Returns true when `byte` must be percent-encoded.
+    pub const fn contains(&self, byte: u8) -> bool {
+        !byte.is_ascii() || self.mask & (1 << byte) != 0
+    }
+}
+
+/// C0 controls and everything above U+007E.
+pub const C0_CONTROL: EncodeSet = EncodeSet {
+    mask: 0xFFFF_FFFF | (1 << 0x7F),
+};
+
+/// Used for URL fragments.
+pub const FRAGMENT: EncodeSet = C0_CONTROL.add_all(b" \"<>`");
+
+/// Used for queries of non-special URLs.
+pub const QUERY: EncodeSet = C0_CONTROL.add_all(b" \"#<>");
+
+/// Used for queries of special URLs: the query set plus U+0027 (').
+pub const SPECIAL_QUERY: EncodeSet = QUERY.add(b'\'');
+
+/// Used for path segments.
+pub const PATH: EncodeSet = QUERY.add_all(b"?^`{}");
+
+/// Used for usernames and passwords.
+pub const USERINFO: EncodeSet = PATH.add_all(b"/:;=@[\\]|");
+
+/// Used by `encodeURIComponent`-like APIs; the userinfo set plus `$%&+,`.
+pub const COMPONENT: EncodeSet = USERINFO.add_all(b"$%&+,");
+
+/// Used by application/x-www-form-urlencoded serialization (space is handled separately).
+pub const FORM_URLENCODED: EncodeSet = COMPONENT.add_all(b"!'()~");
+
+/// Percent-encode bytes using a percent-encode set.
+pub fn percent_encode_set(input: &[u8], set: &EncodeSet) -> String {
+    percent_encode(input, |b| !set.contains(b))
+}
+
+/// The URL Standard's "UTF-8 percent-encode" of a string.
+pub fn utf8_percent_encode(input: &str, set: &EncodeSet) -> String {
+    percent_encode_set(input.as_bytes(), set)
+}
+
 /// Percent-encode bytes using the provided allowlist predicate.
 /// Bytes for which `is_unreserved` returns true will be emitted as-is; others are %HH-encoded.
 pub fn percent_encode<F>(input: &[u8], mut is_unreserved: F) -> String
@@ -52,25 +132,40 @@ pub fn percent_decode(input: &str) -> Result<Vec<u8>, String> {
     Ok(out)
 }
 
+/// Percent-decode bytes per the URL Standard: a '%' not followed by two hex digits is kept as-is.
+pub fn percent_decode_lenient(input: &[u8]) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        let b = input[i];
+        if b == b'%' && i + 2 < input.len() {
+            let hi = ascii::hex_value(input[i + 1]);
+            let lo = ascii::hex_value(input[i + 2]);
+            if let (Some(hi), Some(lo)) = (hi, lo) {
+                out.push((hi << 4) | lo);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(b);
+        i += 1;
+    }
+    out
+}
+
 /// RFC3986 unreserved: ALPHA / DIGIT / "-" / "." / "_" / "~"
 pub fn is_unreserved_rfc3986(byte: u8) -> bool {
     ascii::is_ascii_alphanumeric(byte) || matches!(byte, b'-' | b'.' | b'_' | b'~')
 }
 
-/// x-www-form-urlencoded style encode (space -> '+', others percent-encoded unless unreserved).
+/// x-www-form-urlencoded style encode (space -> '+', others encoded with `FORM_URLENCODED`).
 pub fn form_urlencode(input: &[u8]) -> String {
     let mut out = String::with_capacity(input.len() * 3);
-    for &b in input {
-        match b {
-            b' ' => out.push('+'),
-            b if is_unreserved_rfc3986(b) => out.push(b as char),
-            b => {
-                out.push('%');
-                const HEX: &[u8; 16] = b"0123456789ABCDEF";
-                out.push(HEX[(b >> 4) as usize] as char);
-                out.push(HEX[(b & 0x0F) as usize] as char);
-            }
+    for (index, chunk) in input.split(|&b| b == b' ').enumerate() {
+        if index > 0 {
+            out.push('+');
         }
+        out.push_str(&percent_encode_set(chunk, &FORM_URLENCODED));
     }
     out
 }
@@ -104,6 +199,44 @@ pub fn form_urldecode(input: &str) -> Result<Vec<u8>, String> {
     Ok(out)
 }
 
+/// The application/x-www-form-urlencoded parser: split on '&', then on the first '=', with
+/// '+' as space and lenient percent-decoding. Invalid UTF-8 becomes U+FFFD.
+pub fn form_urlencoded_parse(input: &[u8]) -> Vec<(String, String)> {
+    input
+        .split(|&b| b == b'&')
+        .filter(|sequence| !sequence.is_empty())
+        .map(|sequence| {
+            let (name, value) = match sequence.iter().position(|&b| b == b'=') {
+                Some(position) => (&sequence[..position], &sequence[position + 1..]),
+                None => (sequence, &[][..]),
+            };
+            (form_decode_lossy(name), form_decode_lossy(value))
+        })
+        .collect()
+}
+
+/// The application/x-www-form-urlencoded serializer, e.g. `[("a", "b c")]` to `a=b+c`.
+pub fn form_urlencoded_serialize<'a, I>(pairs: I) -> String
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let mut out = String::new();
+    for (name, value) in pairs {
+        if !out.is_empty() {
+            out.push('&');
+        }
+        out.push_str(&form_urlencode(name.as_bytes()));
+        out.push('=');
+        out.push_str(&form_urlencode(value.as_bytes()));
+    }
+    out
+}
+
+fn form_decode_lossy(input: &[u8]) -> String {
+    let replaced: Vec<u8> = input.iter().map(|&b| if b == b'+' { b' ' } else { b }).collect();
+    String::from_utf8_lossy(&percent_decode_lenient(&replaced)).into_owned()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,14 +260,68 @@ mod tests {
         assert!(percent_decode("%0").is_err());
     }
 
+    #[test]
+    fn decode_lenient_keeps_invalid_escapes() {
+        assert_eq!(percent_decode_lenient(b"a%20b%zz%4"), b"a b%zz%4");
+    }
+
+    #[test]
+    fn url_standard_encode_sets() {
+        assert!(
+            C0_CONTROL.contains(0x1F) && C0_CONTROL.contains(0x7F) && C0_CONTROL.contains(0x80)
+        );
+        assert!(!C0_CONTROL.contains(b' ') && !C0_CONTROL.contains(b'~'));
+        assert_eq!(
+            utf8_percent_encode("a b\"#<>`", &FRAGMENT),
+            "a%20b%22#%3C%3E%60"
+        );
+        assert_eq!(utf8_percent_encode("a'#", &QUERY), "a'%23");
+        assert_eq!(utf8_percent_encode("a'#", &SPECIAL_QUERY), "a%27%23");
+        assert_eq!(utf8_percent_encode("a:@/?{é", &PATH), "a:@/%3F%7B%C3%A9");
+        assert_eq!(utf8_percent_encode("a:@/;", &USERINFO), "a%3A%40%2F%3B");
+        assert_eq!(
+            utf8_percent_encode("a&b=c+d$", &COMPONENT),
+            "a%26b%3Dc%2Bd%24"
+        );
+        assert_eq!(
+            utf8_percent_encode("*-._!~", &FORM_URLENCODED),
+            "*-._%21%7E"
+        );
+        let custom = EncodeSet::EMPTY.add(b'x').remove(b'x').add(b'y');
+        assert_eq!(utf8_percent_encode("xyz", &custom), "x%79z");
+    }
+
     #[test]
     fn form_urlencoding_roundtrip() {
         let src = "a b+c%";
         let enc = form_urlencode(src.as_bytes());
         assert_eq!(enc, "a+b%2Bc%25");
+        assert_eq!(form_urlencode(b"  x "), "++x+");
         let dec = form_urldecode(&enc).unwrap();
         assert_eq!(String::from_utf8(dec).unwrap(), src);
     }
-}
-
 
+    #[test]
+    fn form_urlencoded_pairs() {
+        let pairs = form_urlencoded_parse(b"a=1&&b=x+y%21&c&=d&e=%zz&f=%FF&a=2=3");
+        let expected = [
+            ("a", "1"),
+            ("b", "x y!"),
+            ("c", ""),
+            ("", "d"),
+            ("e", "%zz"),
+            ("f", "\u{FFFD}"),
+            ("a", "2=3"),
+        ];
+        let pairs: Vec<(&str, &str)> = pairs
+            .iter()
+            .map(|(n, v)| (n.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(pairs, expected);
+        assert_eq!(
+            form_urlencoded_serialize([("a b", "c&d"), ("é", "")]),
+            "a+b=c%26d&%C3%A9="
+        );
+        assert_eq!(form_urlencoded_serialize([]), "");
+    }
+}
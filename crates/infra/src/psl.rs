@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+//! Public Suffix List lookups (https://publicsuffix.org/list/).
+//! The list is compiled in by `build.rs` from `data/public_suffix_list.dat` as a trie of
+//! reversed labels; each node's children are contiguous and sorted, so a lookup is one binary
+//! search per label. Wildcard (`*.kobe.jp`) and exception (`!city.kobe.jp`) rules follow the
+//! list's algorithm, and rules from its PRIVATE section can be left out.
+
+const RULE: u8 = 1;
+const EXCEPTION: u8 = 2;
+const PRIVATE: u8 = 4;
+
+struct Node {
+    label_start: u32,
+    label_len: u8,
+    first_child: u16,
+    child_count: u16,
+    flags: u8,
+}
+
+const fn n(label_start: u32, label_len: u8, first_child: u16, child_count: u16, flags: u8) -> Node {
+    Node {
+        label_start,
+        label_len,
+        first_child,
+        child_count,
+        flags,
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/public_suffix_list.rs"));
+
+impl Node {
+    fn label(&self) -> &'static str {
+        let start = self.label_start as usize;
+        &LABELS[start..start + self.label_len as usize]
+    }
+
+    fn children(&self) -> &'static [Node] {
+        let start = self.first_child as usize;
+        &NODES[start..start + self.child_count as usize]
+    }
+
+    fn child(&self, label: &str) -> Option<&'static Node> {
+        let children = self.children();
+        children
+            .binary_search_by(|node| node.label().cmp(label))
+            .ok()
+            .map(|index| &children[index])
+    }
+
+    fn is_rule(&self, flags: u8, include_private: bool) -> bool {
+        self.flags & flags != 0 && (include_private || self.flags & PRIVATE == 0)
+    }
+}
+
+/// The public suffix of `domain`, a slice of it: `co.uk` for `www.example.co.uk`. A trailing
+/// dot is kept (`com.` for `example.com.`), and a domain no rule matches gets its last label.
+///
+/// `domain` must be ASCII lowercase, as `Host::Domain` is. With `include_private` false, rules
+/// from the list's PRIVATE section (such as `github.io`) are ignored.
+pub fn public_suffix(domain: &str, include_private: bool) -> &str {
+    let name = domain.strip_suffix('.').unwrap_or(domain);
+    let labels = suffix_label_count(name, include_private);
+    &domain[suffix_start(name, labels).unwrap_or(0)..]
+}
+
+/// The registrable domain of `domain`: its public suffix plus one more label, e.g. `example.co.uk`
+/// for `www.example.co.uk`. None when `domain` is itself a public suffix.
+pub fn registrable_domain(domain: &str, include_private: bool) -> Option<&str> {
+    let name = domain.strip_suffix('.').unwrap_or(domain);
+    let labels = suffix_label_count(name, include_private) + 1;
+    suffix_start(name, labels).map(|start| &domain[start..])
+}
+
+/// The number of trailing labels of `name` that make up its public suffix.
+fn suffix_label_count(name: &str, include_private: bool) -> usize {
+    let labels: Vec<&str> = name.rsplit('.').collect();
+    let mut matched = Match::default();
+    find_rules(&NODES[0], &labels, 0, include_private, &mut matched);
+    match matched.exception {
+        Some(length) => length - 1,
+        None => matched.longest.max(1),
+    }
+}
+
+#[derive(Default)]
+struct Match {
+    /// Label count of the longest matching normal rule.
+    longest: usize,
+    /// Label count of a matching exception rule, which wins over every normal rule.
+    exception: Option<usize>,
+}
+
+fn find_rules(
+    node: &Node,
+    labels: &[&str],
+    depth: usize,
+    include_private: bool,
+    matched: &mut Match,
+) {
+    let Some(&label) = labels.get(depth) else {
+        return;
+    };
+    for child in [node.child(label), node.child("*")].into_iter().flatten() {
+        if child.is_rule(RULE, include_private) {
+            matched.longest = matched.longest.max(depth + 1);
+        }
+        if child.is_rule(EXCEPTION, include_private) {
+            matched.exception = Some(depth + 1);
+        }
+        find_rules(child, labels, depth + 1, include_private, matched);
+    }
+}
+
+/// The byte offset where the last `labels` labels of `name` begin, or None when `name` has
+/// fewer labels.
+fn suffix_start(name: &str, labels: usize) -> Option<usize> {
+    let mut start = name.len();
+    for index in 0..labels {
+        match name[..start].rfind('.') {
+            Some(dot) => start = dot,
+            None if index + 1 == labels => return Some(0),
+            None => return None,
+        }
+    }
+    Some(start + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(domain: &str, suffix: &str, registrable: Option<&str>) {
+        assert_eq!(public_suffix(domain, true), suffix, "{}", domain);
+        assert_eq!(registrable_domain(domain, true), registrable, "{}", domain);
+    }
+
+    #[test]
+    fn normal_rules_and_default_rule() {
+        check("com", "com", None);
+        check("example.com", "com", Some("example.com"));
+        check("a.b.example.com", "com", Some("example.com"));
+        check("www.example.co.uk", "co.uk", Some("example.co.uk"));
+        check("example.example", "example", Some("example.example"));
+        check("example.com.", "com.", Some("example.com."));
+        check(
+            "xn--85x722f.xn--55qx5d.cn",
+            "xn--55qx5d.cn",
+            Some("xn--85x722f.xn--55qx5d.cn"),
+        );
+    }
+
+    #[test]
+    fn wildcard_and_exception_rules() {
+        check("c.kobe.jp", "c.kobe.jp", None);
+        check("b.c.kobe.jp", "c.kobe.jp", Some("b.c.kobe.jp"));
+        check("city.kobe.jp", "kobe.jp", Some("city.kobe.jp"));
+        check("www.city.kobe.jp", "kobe.jp", Some("city.kobe.jp"));
+        check("www.ck", "ck", Some("www.ck"));
+        check("www.www.ck", "ck", Some("www.ck"));
+        check("a.b.ck", "b.ck", Some("a.b.ck"));
+    }
+
+    #[test]
+    fn private_rules_can_be_excluded() {
+        assert_eq!(public_suffix("user.github.io", true), "github.io");
+        assert_eq!(
+            registrable_domain("user.github.io", true),
+            Some("user.github.io")
+        );
+        assert_eq!(public_suffix("user.github.io", false), "io");
+        assert_eq!(
+            registrable_domain("user.github.io", false),
+            Some("github.io")
+        );
+    }
+}
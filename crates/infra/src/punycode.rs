@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: MIT
+//! Punycode (RFC 3492), the Bootstring encoding behind IDNA `xn--` labels.
+//! These functions work on a single label without the `xn--` prefix; see `crate::idna` for
+//! whole-domain processing.
+
+use std::fmt::{Display, Formatter};
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PunycodeError {
+    /// An intermediate value does not fit in 32 bits (RFC 3492 section 6.4).
+    Overflow,
+    /// The basic code point part of the input contains non-ASCII characters.
+    NonBasic,
+    /// A character in the extended part is not a base-36 digit.
+    InvalidDigit,
+    /// The input ends in the middle of a variable-length integer.
+    UnexpectedEnd,
+    /// A decoded code point is a surrogate or beyond U+10FFFF.
+    InvalidCodePoint,
+}
+
+impl Display for PunycodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use PunycodeError::*;
+        match self {
+            Overflow => write!(f, "punycode overflow"),
+            NonBasic => write!(f, "non-basic code point in punycode input"),
+            InvalidDigit => write!(f, "invalid punycode digit"),
+            UnexpectedEnd => write!(f, "truncated punycode input"),
+            InvalidCodePoint => write!(f, "punycode decodes to an invalid code point"),
+        }
+    }
+}
+
+impl std::error::Error for PunycodeError {}
+
+/// Encode a Unicode label, e.g. "bücher" to "bcher-kva".
+pub fn encode(input: &str) -> Result<String, PunycodeError> {
+    encode_chars(&input.chars().collect::<Vec<_>>())
+}
+
+/// Decode a label, e.g. "bcher-kva" to "bücher".
+pub fn decode(input: &str) -> Result<String, PunycodeError> {
+    decode_to_chars(input).map(|chars| chars.into_iter().collect())
+}
+
+pub(crate) fn encode_chars(input: &[char]) -> Result<String, PunycodeError> {
+    use PunycodeError::Overflow;
+    let mut output: String = input.iter().filter(|c| c.is_ascii()).collect();
+    let basic_count = output.len() as u32;
+    let total = u32::try_from(input.len()).map_err(|_| Overflow)?;
+    let mut handled = basic_count;
+    if basic_count > 0 {
+        output.push('-');
+    }
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    while handled < total {
+        let Some(m) = input.iter().map(|&c| c as u32).filter(|&cp| cp >= n).min() else {
+            break;
+        };
+        let step = (m - n).checked_mul(handled + 1).ok_or(Overflow)?;
+        delta = delta.checked_add(step).ok_or(Overflow)?;
+        n = m;
+        for &c in input {
+            let cp = c as u32;
+            if cp < n {
+                delta = delta.checked_add(1).ok_or(Overflow)?;
+            }
+            if cp == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_count);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1).ok_or(Overflow)?;
+        n += 1;
+    }
+    Ok(output)
+}
+
+pub(crate) fn decode_to_chars(input: &str) -> Result<Vec<char>, PunycodeError> {
+    use PunycodeError::*;
+    let (basic, extended) = match input.rfind('-') {
+        Some(position) => (&input[..position], &input[position + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return Err(NonBasic);
+    }
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut digits = extended.chars().peekable();
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut weight: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let digit = decode_digit(digits.next().ok_or(UnexpectedEnd)?).ok_or(InvalidDigit)?;
+            let step = digit.checked_mul(weight).ok_or(Overflow)?;
+            i = i.checked_add(step).ok_or(Overflow)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            weight = weight.checked_mul(BASE - t).ok_or(Overflow)?;
+            k += BASE;
+        }
+        let length = output.len() as u32 + 1;
+        bias = adapt(i - old_i, length, old_i == 0);
+        n = n.checked_add(i / length).ok_or(Overflow)?;
+        i %= length;
+        // n starts at 0x80 and only grows, so no basic code point can be inserted.
+        let c = char::from_u32(n).ok_or(InvalidCodePoint)?;
+        output.insert(i as usize, c);
+        i += 1;
+    }
+    Ok(output)
+}
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn encode_digit(digit: u32) -> char {
+    let byte = if digit < 26 {
+        b'a' + digit as u8
+    } else {
+        b'0' + (digit - 26) as u8
+    };
+    byte as char
+}
+
+fn decode_digit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_rfc3492_samples() {
+        let samples = [
+            ("bücher", "bcher-kva"),
+            ("日本語", "wgv71a119e"),
+            ("ü", "tda"),
+            ("abc", "abc-"),
+            // RFC 3492 section 7.1 (L): Japanese with Latin letters and digits.
+            ("3年B組金八先生", "3B-ww4c5e180e575a65lsy2b"),
+            // RFC 3492 section 7.1 (B): Chinese (simplified).
+            (
+                "\u{4ED6}\u{4EEC}\u{4E3A}\u{4EC0}\u{4E48}\u{4E0D}\u{8BF4}\u{4E2D}\u{6587}",
+                "ihqwcrb4cv8a8dqg056pqjye",
+            ),
+        ];
+        for (unicode, ascii) in samples {
+            assert_eq!(encode(unicode).unwrap(), ascii);
+            assert_eq!(decode(ascii).unwrap(), unicode);
+        }
+        assert_eq!(encode("").unwrap(), "");
+        assert_eq!(decode("").unwrap(), "");
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(decode("99999999999z"), Err(PunycodeError::Overflow));
+        assert_eq!(decode("ü-a"), Err(PunycodeError::NonBasic));
+        assert_eq!(decode("bcher-k!a"), Err(PunycodeError::InvalidDigit));
+        assert_eq!(decode("bcher-kv"), Err(PunycodeError::UnexpectedEnd));
+    }
+}
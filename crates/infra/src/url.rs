@@ -1,42 +1,138 @@
 // SPDX-License-Identifier: MIT
-//! Minimal URL structure and parser/serializer for absolute HTTP(S) URLs.
-//! This is a conservative implementation intended to bootstrap further work.
-//! - Supports: scheme, userinfo, host (domain/IPv4/IPv6), port, path, query, fragment
-//! - Limitations: No relative-URL resolution, no special schemes beyond http/https, no IDNA/Punycode yet
+//! URL record, parser and serializer following the WHATWG URL Standard.
+//! - Parsing runs the basic URL parser state machine (see `parser`); validation errors are
+//!   collected instead of aborting, and only spec "failures" surface as `UrlParseError`.
+//! - A `Url` is its serialization plus component offsets, so getters are zero-copy slices;
+//!   the parser itself works on an owned `UrlRecord` (see `record`).
+//! - Components change only through the spec's setters (`set_host`, `set_pathname`, ...),
+//!   which re-run the parser with a state override; see `setters`. Query name-value pairs
+//!   are edited as a `SearchParams` list and written back with `Url::set_search_params`.
+//! - Relative references resolve against a base URL via `Url::parse_with_base` / `Url::join`.
+//! - Special schemes (http, https, ws, wss, ftp, file) get default-port elision and '\\' as '/'.
+//! - `file:` URLs follow the file states, including the Windows drive letter quirks; see `file`
+//!   for conversions to and from filesystem paths.
+//! - Domains go through UTS #46 domain-to-ASCII (see `crate::idna`), so hosts are stored in
+//!   their ASCII (`xn--`) form; `Host::to_unicode` gives the form to display.
+//! - Domains that end in a number go through the spec's IPv4 parser (see `host`), so
+//!   `0x7f.1` and `2130706433` are the address 127.0.0.1 rather than names.
+//! - Non-special schemes get opaque hosts (`Host::Opaque`); forbidden host code points fail.
+//! - `Host::public_suffix` and `Host::registrable_domain` consult the Public Suffix List
+//!   (see `crate::psl`).
+//! - `Url::origin` gives the tuple or opaque `Origin` used for same-origin checks.
 
 use std::fmt::{Display, Formatter};
 use std::net::{Ipv4Addr, Ipv6Addr};
 
-use crate::percent;
+use crate::{idna, psl};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+mod file;
+mod host;
+mod origin;
+mod parser;
+mod record;
+mod scheme;
+mod search_params;
+mod setters;
+
+pub use file::{FilePathError, PathStyle};
+pub use host::{is_forbidden_domain_code_point, is_forbidden_host_code_point};
+pub use origin::{OpaqueOrigin, Origin};
+use record::UrlRecord;
+pub use scheme::{default_port, is_special, SpecialScheme};
+pub use search_params::SearchParams;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Host {
+    /// An ASCII domain, after IDNA processing.
     Domain(String),
     Ipv4(Ipv4Addr),
     Ipv6(Ipv6Addr),
+    /// A percent-encoded host of a non-special URL, such as `foo://h%20st/`.
+    Opaque(String),
+    /// The empty host, as in `file:///x` or `foo:///x`.
+    Empty,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl Host {
+    /// The host for display: `xn--` labels of a domain are converted back to Unicode, so
+    /// `xn--bcher-kva.example` becomes `bücher.example`. Labels that fail to decode are kept.
+    pub fn to_unicode(&self) -> String {
+        match self {
+            Host::Domain(domain) => idna::domain_to_unicode(domain, false).0,
+            host => host.to_string(),
+        }
+    }
+
+    /// The host's public suffix (URL Standard), e.g. `co.uk` for `www.example.co.uk`, using
+    /// the full Public Suffix List. None for hosts other than domains.
+    pub fn public_suffix(&self) -> Option<&str> {
+        match self {
+            Host::Domain(domain) => Some(psl::public_suffix(domain, true)),
+            _ => None,
+        }
+    }
+
+    /// The host's registrable domain, e.g. `example.co.uk` for `www.example.co.uk`. None for
+    /// hosts other than domains and for domains that are themselves a public suffix.
+    pub fn registrable_domain(&self) -> Option<&str> {
+        match self {
+            Host::Domain(domain) => psl::registrable_domain(domain, true),
+            _ => None,
+        }
+    }
+
+    /// Same site (HTML Standard): equal hosts, or domains with the same registrable domain.
+    pub fn is_same_site(&self, other: &Host) -> bool {
+        self == other
+            || self
+                .registrable_domain()
+                .is_some_and(|domain| other.registrable_domain() == Some(domain))
+    }
+}
+
+impl Display for Host {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Host::Domain(domain) | Host::Opaque(domain) => f.write_str(domain),
+            Host::Ipv4(ip) => write!(f, "{}", ip),
+            Host::Ipv6(ip) => write!(f, "[{}]", host::serialize_ipv6(ip)),
+            Host::Empty => Ok(()),
+        }
+    }
+}
+
+/// A parsed URL, stored as its serialization plus component offsets.
+///
+/// Getters return slices of the serialization, so `as_str` and the component accessors are
+/// free. Components are percent-encoded exactly as serialized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Url {
-    pub scheme: String,
-    pub username: String,
-    pub password: Option<String>,
-    pub host: Host,
-    pub port: Option<u16>,
-    pub path: Vec<String>,
-    pub query: Option<String>,
-    pub fragment: Option<String>,
+    serialization: String,
+    /// Position of the ':' after the scheme.
+    scheme_end: usize,
+    /// End of the username; the username starts after "scheme://" (or is empty).
+    username_end: usize,
+    host_start: usize,
+    host_end: usize,
+    host: Option<Host>,
+    port: Option<u16>,
+    path_start: usize,
+    /// Position of the '?' that starts the query.
+    query_start: Option<usize>,
+    /// Position of the '#' that starts the fragment.
+    fragment_start: Option<usize>,
+    has_opaque_path: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UrlParseError {
     MissingScheme,
-    InvalidScheme,
-    MissingAuthority,
-    InvalidUserinfo,
+    MissingHost,
     InvalidPort,
+    PortOutOfRange,
     InvalidHost,
-    InvalidPercent,
+    /// The host contains a forbidden host (or, for domains, forbidden domain) code point.
+    ForbiddenHostCodePoint(char),
 }
 
 impl Display for UrlParseError {
@@ -44,157 +140,234 @@ impl Display for UrlParseError {
         use UrlParseError::*;
         match self {
             MissingScheme => write!(f, "missing scheme"),
-            InvalidScheme => write!(f, "invalid scheme"),
-            MissingAuthority => write!(f, "missing authority"),
-            InvalidUserinfo => write!(f, "invalid userinfo"),
+            MissingHost => write!(f, "missing host"),
             InvalidPort => write!(f, "invalid port"),
+            PortOutOfRange => write!(f, "port out of range"),
             InvalidHost => write!(f, "invalid host"),
-            InvalidPercent => write!(f, "invalid percent-encoding"),
+            ForbiddenHostCodePoint(c) => write!(f, "forbidden host code point {:?}", c),
         }
     }
 }
 
 impl std::error::Error for UrlParseError {}
 
+/// Non-fatal validation errors reported by the parser, named after the URL Standard's table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    InvalidUrlUnit,
+    SpecialSchemeMissingFollowingSolidus,
+    MissingSchemeNonRelativeUrl,
+    InvalidReverseSolidus,
+    InvalidCredentials,
+    HostMissing,
+    HostInvalidCodePoint,
+    DomainInvalidCodePoint,
+    DomainToAscii,
+    Ipv4EmptyPart,
+    Ipv4TooManyParts,
+    Ipv4NonNumericPart,
+    Ipv4NonDecimalPart,
+    Ipv4OutOfRangePart,
+    Ipv6Unclosed,
+    Ipv6InvalidCompression,
+    Ipv6TooManyPieces,
+    Ipv6MultipleCompression,
+    Ipv6InvalidCodePoint,
+    Ipv6TooFewPieces,
+    Ipv4InIpv6TooManyPieces,
+    Ipv4InIpv6InvalidCodePoint,
+    Ipv4InIpv6OutOfRangePart,
+    Ipv4InIpv6TooFewParts,
+    PortOutOfRange,
+    PortInvalid,
+    FileInvalidWindowsDriveLetter,
+    FileInvalidWindowsDriveLetterHost,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use ValidationError::*;
+        let name = match self {
+            InvalidUrlUnit => "invalid-URL-unit",
+            SpecialSchemeMissingFollowingSolidus => "special-scheme-missing-following-solidus",
+            MissingSchemeNonRelativeUrl => "missing-scheme-non-relative-URL",
+            InvalidReverseSolidus => "invalid-reverse-solidus",
+            InvalidCredentials => "invalid-credentials",
+            HostMissing => "host-missing",
+            HostInvalidCodePoint => "host-invalid-code-point",
+            DomainInvalidCodePoint => "domain-invalid-code-point",
+            DomainToAscii => "domain-to-ASCII",
+            Ipv4EmptyPart => "IPv4-empty-part",
+            Ipv4TooManyParts => "IPv4-too-many-parts",
+            Ipv4NonNumericPart => "IPv4-non-numeric-part",
+            Ipv4NonDecimalPart => "IPv4-non-decimal-part",
+            Ipv4OutOfRangePart => "IPv4-out-of-range-part",
+            Ipv6Unclosed => "IPv6-unclosed",
+            Ipv6InvalidCompression => "IPv6-invalid-compression",
+            Ipv6TooManyPieces => "IPv6-too-many-pieces",
+            Ipv6MultipleCompression => "IPv6-multiple-compression",
+            Ipv6InvalidCodePoint => "IPv6-invalid-code-point",
+            Ipv6TooFewPieces => "IPv6-too-few-pieces",
+            Ipv4InIpv6TooManyPieces => "IPv4-in-IPv6-too-many-pieces",
+            Ipv4InIpv6InvalidCodePoint => "IPv4-in-IPv6-invalid-code-point",
+            Ipv4InIpv6OutOfRangePart => "IPv4-in-IPv6-out-of-range-part",
+            Ipv4InIpv6TooFewParts => "IPv4-in-IPv6-too-few-parts",
+            PortOutOfRange => "port-out-of-range",
+            PortInvalid => "port-invalid",
+            FileInvalidWindowsDriveLetter => "file-invalid-Windows-drive-letter",
+            FileInvalidWindowsDriveLetterHost => "file-invalid-Windows-drive-letter-host",
+        };
+        f.write_str(name)
+    }
+}
+
 impl Url {
-    /// Parse an absolute URL (http/https) into components.
+    /// Parse an absolute URL, discarding validation errors.
     pub fn parse(input: &str) -> Result<Self, UrlParseError> {
-        // Split scheme
-        let (scheme, rest) = split_once(input, ':').ok_or(UrlParseError::MissingScheme)?;
-        if !is_valid_scheme(scheme) {
-            return Err(UrlParseError::InvalidScheme);
-        }
+        let mut errors = Vec::new();
+        Url::parse_with_errors(input, &mut errors)
+    }
 
-        // Expect //authority...
-        let rest = rest.strip_prefix("//").ok_or(UrlParseError::MissingAuthority)?;
+    /// Parse `input` as a URL, resolving it against `base` when it is a relative reference.
+    pub fn parse_with_base(input: &str, base: &Url) -> Result<Self, UrlParseError> {
+        let mut errors = Vec::new();
+        Url::parse_with_base_and_errors(input, base, &mut errors)
+    }
 
-        // Split off fragment
-        let (before_frag, fragment) = split_once(rest, '#').map_or((rest, None), |(a, b)| (a, Some(b.to_string())));
-        // Split off query
-        let (before_query, query) = split_once(before_frag, '?').map_or((before_frag, None), |(a, b)| (a, Some(b.to_string())));
+    /// `parse_with_base`, appending every validation error encountered to `errors`.
+    pub fn parse_with_base_and_errors(
+        input: &str,
+        base: &Url,
+        errors: &mut Vec<ValidationError>,
+    ) -> Result<Self, UrlParseError> {
+        let base = UrlRecord::from_url(base);
+        parser::basic_parse(input, Some(&base), errors).map(UrlRecord::into_url)
+    }
 
-        // Authority + path
-        let (authority, path_str) = if let Some((a, p)) = split_once(before_query, '/') {
-            (a, Some(p))
-        } else {
-            (before_query, None)
-        };
+    /// Parse an absolute URL, appending every validation error encountered to `errors`.
+    /// Errors are recorded even when parsing ultimately fails.
+    pub fn parse_with_errors(
+        input: &str,
+        errors: &mut Vec<ValidationError>,
+    ) -> Result<Self, UrlParseError> {
+        parser::basic_parse(input, None, errors).map(UrlRecord::into_url)
+    }
 
-        // userinfo@host:port
-        let (userinfo_opt, hostport) = if let Some((u, h)) = split_once(authority, '@') {
-            (Some(u), h)
-        } else {
-            (None, authority)
-        };
+    /// Resolve `input` against this URL, e.g. a link `href` against the document URL.
+    pub fn join(&self, input: &str) -> Result<Self, UrlParseError> {
+        Url::parse_with_base(input, self)
+    }
 
-        // Parse userinfo
-        let (username, password) = if let Some(ui) = userinfo_opt {
-            if let Some((u, p)) = split_once(ui, ':') {
-                (u.to_string(), Some(p.to_string()))
-            } else {
-                (ui.to_string(), None)
-            }
-        } else {
-            (String::new(), None)
-        };
+    /// The serialized URL (the spec's href).
+    pub fn as_str(&self) -> &str {
+        &self.serialization
+    }
 
-        // Parse host and port
-        let (host, port) = parse_host_port(hostport)?;
-
-        // Parse path segments (percent-decoded)
-        let path: Vec<String> = if let Some(p) = path_str {
-            if p.is_empty() { Vec::new() } else {
-                p.split('/')
-                    .map(|seg| percent::percent_decode(seg).map_err(|_| UrlParseError::InvalidPercent)
-                        .and_then(|v| String::from_utf8(v).map_err(|_| UrlParseError::InvalidPercent)))
-                    .collect::<Result<Vec<_>, _>>()?
-            }
-        } else { Vec::new() };
-
-        Ok(Url {
-            scheme: scheme.to_string(),
-            username,
-            password,
-            host,
-            port,
-            path,
-            query,
-            fragment,
-        })
-    }
-
-    /// Serialize URL back to string form with percent-encoding for path segments.
+    /// An owned copy of the serialization.
     pub fn serialize(&self) -> String {
-        let mut s = String::new();
-        s.push_str(&self.scheme);
-        s.push(':');
-        s.push_str("//");
-        if !self.username.is_empty() {
-            s.push_str(&self.username);
-            if let Some(pw) = &self.password { s.push(':'); s.push_str(pw); }
-            s.push('@');
-        }
-        match &self.host {
-            Host::Domain(d) => s.push_str(d),
-            Host::Ipv4(ip) => s.push_str(&ip.to_string()),
-            Host::Ipv6(ip) => { s.push('['); s.push_str(&ip.to_string()); s.push(']'); }
-        }
-        if let Some(port) = self.port { s.push(':'); s.push_str(&port.to_string()); }
-        if !self.path.is_empty() {
-            s.push('/');
-            let mut first = true;
-            for seg in &self.path {
-                if !first { s.push('/'); }
-                first = false;
-                let enc = percent::percent_encode(seg.as_bytes(), percent::is_unreserved_rfc3986);
-                s.push_str(&enc);
-            }
+        self.serialization.clone()
+    }
+
+    pub fn scheme(&self) -> &str {
+        &self.serialization[..self.scheme_end]
+    }
+
+    /// The percent-encoded username, or "" when there is none.
+    pub fn username(&self) -> &str {
+        &self.serialization[self.username_start()..self.username_end]
+    }
+
+    /// The percent-encoded password; None when absent or empty.
+    pub fn password(&self) -> Option<&str> {
+        let has_password = self.serialization.as_bytes().get(self.username_end) == Some(&b':')
+            && self.username_end < self.host_start;
+        has_password.then(|| &self.serialization[self.username_end + 1..self.host_start - 1])
+    }
+
+    pub fn host(&self) -> Option<&Host> {
+        self.host.as_ref()
+    }
+
+    /// The serialized host, e.g. "example.com" or "[::1]"; "" for the empty host.
+    pub fn host_str(&self) -> Option<&str> {
+        self.host
+            .as_ref()
+            .map(|_| &self.serialization[self.host_start..self.host_end])
+    }
+
+    /// The explicit port; default ports are elided during parsing.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// The serialized path: "/a/b" for hierarchical URLs, the opaque path otherwise.
+    pub fn path(&self) -> &str {
+        let end = self
+            .query_start
+            .or(self.fragment_start)
+            .unwrap_or(self.serialization.len());
+        &self.serialization[self.path_start..end]
+    }
+
+    /// The percent-encoded path segments, or None when the URL has an opaque path.
+    pub fn path_segments(&self) -> Option<impl Iterator<Item = &str>> {
+        if self.has_opaque_path {
+            return None;
         }
-        if let Some(q) = &self.query { s.push('?'); s.push_str(q); }
-        if let Some(f) = &self.fragment { s.push('#'); s.push_str(f); }
-        s
+        Some(
+            self.path()
+                .strip_prefix('/')
+                .into_iter()
+                .flat_map(|path| path.split('/')),
+        )
     }
-}
 
-fn split_once<'a>(input: &'a str, delim: char) -> Option<(&'a str, &'a str)> {
-    input.split_once(delim)
-}
+    /// Returns true for URLs such as `mailto:` or `javascript:` whose path is a plain string.
+    pub fn has_opaque_path(&self) -> bool {
+        self.has_opaque_path
+    }
 
-fn is_valid_scheme(s: &str) -> bool {
-    let mut chars = s.chars();
-    match chars.next() { Some(c) if c.is_ascii_alphabetic() => {}, _ => return false }
-    for c in chars {
-        if !(c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')) { return false }
+    /// The query without its leading '?'.
+    pub fn query(&self) -> Option<&str> {
+        let end = self.fragment_start.unwrap_or(self.serialization.len());
+        self.query_start
+            .map(|start| &self.serialization[start + 1..end])
     }
-    true
-}
 
-fn parse_host_port(input: &str) -> Result<(Host, Option<u16>), UrlParseError> {
-    if let Some(host) = input.strip_prefix('[') {
-        // IPv6 literal [..]
-        let (inside, rest) = split_once(host, ']').ok_or(UrlParseError::InvalidHost)?;
-        let ipv6 = inside.parse::<Ipv6Addr>().map_err(|_| UrlParseError::InvalidHost)?;
-        let port = if let Some(rest) = rest.strip_prefix(':') {
-            Some(parse_port(rest)?)
-        } else { None };
-        return Ok((Host::Ipv6(ipv6), port));
-    }
-    // Split host:port optionally
-    let (h, p) = if let Some((h, p)) = split_once(input, ':') { (h, Some(p)) } else { (input, None) };
-    // Try IPv4
-    if let Ok(ip4) = h.parse::<Ipv4Addr>() {
-        let port = if let Some(p) = p { Some(parse_port(p)?) } else { None };
-        return Ok((Host::Ipv4(ip4), port));
-    }
-    // Domain
-    let host = h.to_string();
-    if host.is_empty() { return Err(UrlParseError::InvalidHost); }
-    let port = if let Some(p) = p { Some(parse_port(p)?) } else { None };
-    Ok((Host::Domain(host), port))
+    /// The fragment without its leading '#'.
+    pub fn fragment(&self) -> Option<&str> {
+        self.fragment_start
+            .map(|start| &self.serialization[start + 1..])
+    }
+
+    /// Returns true when the scheme is a special scheme.
+    pub fn is_special(&self) -> bool {
+        scheme::is_special(self.scheme())
+    }
+
+    /// The explicit port, or the scheme's default port when none was given.
+    pub fn port_or_known_default(&self) -> Option<u16> {
+        self.port.or_else(|| scheme::default_port(self.scheme()))
+    }
+
+    /// Returns true when the URL has a non-empty username or password.
+    pub fn includes_credentials(&self) -> bool {
+        !self.username().is_empty() || self.password().is_some()
+    }
+
+    fn username_start(&self) -> usize {
+        if self.host.is_some() {
+            self.scheme_end + 3
+        } else {
+            self.scheme_end + 1
+        }
+    }
 }
 
-fn parse_port(p: &str) -> Result<u16, UrlParseError> {
-    p.parse::<u16>().map_err(|_| UrlParseError::InvalidPort)
+impl Display for Url {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.serialization)
+    }
 }
 
 #[cfg(test)]
@@ -204,38 +377,434 @@ mod tests {
     #[test]
     fn parse_http_basic() {
         let u = Url::parse("http://example.com/").unwrap();
-        assert_eq!(u.scheme, "http");
-        assert_eq!(u.username, "");
-        assert!(u.password.is_none());
-        assert_eq!(u.host, Host::Domain("example.com".into()));
-        assert!(u.port.is_none());
-        assert!(u.path.is_empty());
-        assert!(u.query.is_none());
-        assert!(u.fragment.is_none());
-        assert_eq!(u.serialize(), "http://example.com");
+        assert_eq!(u.scheme(), "http");
+        assert_eq!(u.username(), "");
+        assert!(u.password().is_none());
+        assert_eq!(u.host(), Some(&Host::Domain("example.com".into())));
+        assert!(u.port().is_none());
+        assert_eq!(u.path(), "/");
+        assert!(u.query().is_none());
+        assert!(u.fragment().is_none());
+        assert_eq!(u.serialize(), "http://example.com/");
     }
 
     #[test]
     fn parse_with_userinfo_ipv6_and_query_fragment() {
         let u = Url::parse("https://user:pw@[2001:db8::1]:8443/a/b%20c?x=1#frag").unwrap();
-        assert_eq!(u.scheme, "https");
-        assert_eq!(u.username, "user");
-        assert_eq!(u.password.as_deref(), Some("pw"));
-        assert!(matches!(u.host, Host::Ipv6(_)));
-        assert_eq!(u.port, Some(8443));
-        assert_eq!(u.path, vec!["a".to_string(), "b c".to_string()]);
-        assert_eq!(u.query.as_deref(), Some("x=1"));
-        assert_eq!(u.fragment.as_deref(), Some("frag"));
-        assert_eq!(u.serialize(), "https://user:pw@[2001:db8::1]:8443/a/b%20c?x=1#frag");
+        assert_eq!(u.scheme(), "https");
+        assert_eq!(u.username(), "user");
+        assert_eq!(u.password(), Some("pw"));
+        assert!(matches!(u.host(), Some(&Host::Ipv6(_))));
+        assert_eq!(u.port(), Some(8443));
+        assert_eq!(u.path(), "/a/b%20c");
+        assert_eq!(
+            u.path_segments().unwrap().collect::<Vec<_>>(),
+            ["a", "b%20c"]
+        );
+        assert_eq!(u.query(), Some("x=1"));
+        assert_eq!(u.fragment(), Some("frag"));
+        assert_eq!(
+            u.serialize(),
+            "https://user:pw@[2001:db8::1]:8443/a/b%20c?x=1#frag"
+        );
+    }
+
+    #[test]
+    fn ipv6_hosts_use_spec_serialization() {
+        let u = Url::parse("http://[::FFFF:1.2.3.4]/").unwrap();
+        assert_eq!(u.serialize(), "http://[::ffff:102:304]/");
+        let u = Url::parse("http://[0:0:1:0:0:0:0:1]:80/").unwrap();
+        assert_eq!(u.host().as_ref().unwrap().to_string(), "[0:0:1::1]");
+        assert_eq!(u.serialize(), "http://[0:0:1::1]/");
+
+        let mut errors = Vec::new();
+        assert_eq!(
+            Url::parse_with_errors("http://[::1%25eth0]/", &mut errors),
+            Err(UrlParseError::InvalidHost)
+        );
+        assert_eq!(errors, [ValidationError::Ipv6InvalidCodePoint]);
+        errors.clear();
+        assert_eq!(
+            Url::parse_with_errors("http://[::1/", &mut errors),
+            Err(UrlParseError::InvalidHost)
+        );
+        assert_eq!(errors, [ValidationError::Ipv6Unclosed]);
+    }
+
+    #[test]
+    fn component_slices_of_serialization() {
+        let u = Url::parse("https://user:pw@[::1]:8443/a/%2F/b?q=1#f").unwrap();
+        assert_eq!(u.as_str(), "https://user:pw@[::1]:8443/a/%2F/b?q=1#f");
+        assert_eq!(u.scheme(), "https");
+        assert_eq!((u.username(), u.password()), ("user", Some("pw")));
+        assert_eq!(u.host_str(), Some("[::1]"));
+        assert_eq!(u.port(), Some(8443));
+        // An encoded '/' stays distinct from a segment separator.
+        assert_eq!(u.path(), "/a/%2F/b");
+        assert_eq!(
+            u.path_segments().unwrap().collect::<Vec<_>>(),
+            ["a", "%2F", "b"]
+        );
+        assert_eq!((u.query(), u.fragment()), (Some("q=1"), Some("f")));
+
+        let u = Url::parse("http://user@h?#").unwrap();
+        assert_eq!((u.username(), u.password()), ("user", None));
+        assert_eq!(u.host_str(), Some("h"));
+        assert_eq!(
+            (u.path(), u.query(), u.fragment()),
+            ("/", Some(""), Some(""))
+        );
+
+        let u = Url::parse("mailto:a@b?subject=x").unwrap();
+        assert_eq!((u.username(), u.host_str()), ("", None));
+        assert_eq!(u.path(), "a@b");
+        assert!(u.path_segments().is_none());
+
+        let u = Url::parse("foo://h").unwrap();
+        assert_eq!(u.path(), "");
+        assert_eq!(u.path_segments().unwrap().count(), 0);
+        let u = Url::parse("file:///x").unwrap();
+        assert_eq!(u.host_str(), Some(""));
+        let u = Url::parse("web+demo:/.//p").unwrap();
+        assert_eq!((u.as_str(), u.path()), ("web+demo:/.//p", "//p"));
     }
 
     #[test]
     fn parse_ipv4_with_port() {
         let u = Url::parse("http://192.168.0.1:8080").unwrap();
-        assert!(matches!(u.host, Host::Ipv4(_)));
-        assert_eq!(u.port, Some(8080));
-        assert_eq!(u.serialize(), "http://192.168.0.1:8080");
+        assert!(matches!(u.host(), Some(&Host::Ipv4(_))));
+        assert_eq!(u.port(), Some(8080));
+        assert_eq!(u.serialize(), "http://192.168.0.1:8080/");
     }
-}
 
+    #[test]
+    fn parse_ipv4_shorthand_hosts() {
+        for input in [
+            "http://0x7f.1/",
+            "http://0177.0.0.1/",
+            "http://2130706433/",
+            "http://127.0.0.1./",
+            "http://%30x7f.1/",
+        ] {
+            let u = Url::parse(input).unwrap();
+            assert_eq!(
+                u.host(),
+                Some(&Host::Ipv4(Ipv4Addr::new(127, 0, 0, 1))),
+                "{input}"
+            );
+            assert_eq!(u.serialize(), "http://127.0.0.1/");
+        }
+        // Hosts that end in a number must be valid IPv4 addresses.
+        assert_eq!(
+            Url::parse("http://example.09/"),
+            Err(UrlParseError::InvalidHost)
+        );
+        assert_eq!(
+            Url::parse("http://1.2.3.4.5/"),
+            Err(UrlParseError::InvalidHost)
+        );
+        assert_eq!(
+            Url::parse("http://256.1.1.1/"),
+            Err(UrlParseError::InvalidHost)
+        );
+        assert!(matches!(
+            Url::parse("http://1.2.3.example/").unwrap().host(),
+            Some(&Host::Domain(_))
+        ));
+        // Opaque hosts are never interpreted as IPv4.
+        assert_eq!(
+            Url::parse("foo://0x7f.1/").unwrap().host(),
+            Some(&Host::Opaque("0x7f.1".into()))
+        );
+    }
+
+    #[test]
+    fn parse_opaque_paths() {
+        let u = Url::parse("mailto:someone@example.com?subject=hi").unwrap();
+        assert_eq!(u.scheme(), "mailto");
+        assert!(u.host().is_none());
+        assert!(u.has_opaque_path());
+        assert_eq!(u.path(), "someone@example.com");
+        assert_eq!(u.query(), Some("subject=hi"));
+
+        let u = Url::parse("javascript:alert(1)").unwrap();
+        assert_eq!(u.serialize(), "javascript:alert(1)");
+
+        let u = Url::parse("data:text/plain,a b").unwrap();
+        assert_eq!(u.serialize(), "data:text/plain,a b");
+    }
+
+    #[test]
+    fn parse_non_special_hierarchical() {
+        let u = Url::parse("foo://host/a/../b").unwrap();
+        assert_eq!(u.host(), Some(&Host::Opaque("host".into())));
+        assert_eq!(u.path(), "/b");
+
+        let u = Url::parse("web+demo:/.//not-a-host/").unwrap();
+        assert!(u.host().is_none());
+        assert_eq!(u.path(), "//not-a-host/");
+        assert_eq!(
+            u.path_segments().unwrap().collect::<Vec<_>>(),
+            ["", "not-a-host", ""]
+        );
+        assert_eq!(u.serialize(), "web+demo:/.//not-a-host/");
+    }
+
+    #[test]
+    fn percent_encodes_by_component() {
+        let u = Url::parse("http://h/a b/<c>?q=\"x\" 'y'#f g`").unwrap();
+        assert_eq!(u.path(), "/a%20b/%3Cc%3E");
+        assert_eq!(u.query(), Some("q=%22x%22%20%27y%27"));
+        assert_eq!(u.fragment(), Some("f%20g%60"));
+
+        // The path set leaves ':', '@' and friends alone; userinfo encodes them.
+        let u = Url::parse("http://us:er@h/a:b@c;d=e{f}").unwrap();
+        assert_eq!(u.serialize(), "http://us:er@h/a:b@c;d=e%7Bf%7D");
+        let u = Url::parse("foo://h/?'").unwrap();
+        assert_eq!(u.query(), Some("'"));
+        let u = Url::parse("http://a@b:c@h/").unwrap();
+        assert_eq!(u.as_str(), "http://a%40b:c@h/");
+    }
+
+    #[test]
+    fn collects_validation_errors() {
+        let mut errors = Vec::new();
+        let u = Url::parse_with_errors(" \thttp:example.com/%zz \n", &mut errors).unwrap();
+        assert_eq!(u.serialize(), "http://example.com/%zz");
+        assert!(errors.contains(&ValidationError::InvalidUrlUnit));
+        assert!(errors.contains(&ValidationError::SpecialSchemeMissingFollowingSolidus));
+
+        errors.clear();
+        let base = Url::parse("http://a/b/c").unwrap();
+        let u = Url::parse_with_base_and_errors("\\g", &base, &mut errors).unwrap();
+        assert_eq!(u.serialize(), "http://a/g");
+        assert_eq!(errors, [ValidationError::InvalidReverseSolidus]);
+    }
+
+    #[test]
+    fn resolve_relative_references() {
+        let base = Url::parse("http://a/b/c/d;p?q").unwrap();
+        let cases = [
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g/"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../../../g", "http://a/g"),
+            ("%2e%2E/g", "http://a/b/g"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("http:g", "http://a/b/c/g"),
+            ("https:g", "https://g/"),
+            ("mailto:x", "mailto:x"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(base.join(input).unwrap().serialize(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn resolve_against_opaque_base() {
+        let base = Url::parse("mailto:someone@example.com").unwrap();
+        let u = Url::parse_with_base("#top", &base).unwrap();
+        assert_eq!(u.serialize(), "mailto:someone@example.com#top");
+        assert_eq!(base.join("other"), Err(UrlParseError::MissingScheme));
+    }
+
+    #[test]
+    fn special_scheme_normalization() {
+        let u = Url::parse("HTTP://example.com:80/").unwrap();
+        assert_eq!(u.port(), None);
+        assert_eq!(u.port_or_known_default(), Some(80));
+        assert_eq!(u, Url::parse("http://example.com/").unwrap());
+        assert_eq!(Url::parse("wss://h:443").unwrap().serialize(), "wss://h/");
+        assert_eq!(Url::parse("ftp://h:21/").unwrap().port(), None);
+        assert_eq!(Url::parse("https://h:80/").unwrap().port(), Some(80));
+        assert_eq!(Url::parse("foo://h:80/").unwrap().port(), Some(80));
+
+        let mut errors = Vec::new();
+        let u = Url::parse_with_errors("https:\\\\h\\a\\b", &mut errors).unwrap();
+        assert_eq!(u.serialize(), "https://h/a/b");
+        assert!(errors.contains(&ValidationError::InvalidReverseSolidus));
+        assert_eq!(
+            Url::parse("foo://h\\a"),
+            Err(UrlParseError::ForbiddenHostCodePoint('\\'))
+        );
+
+        let base = Url::parse("http://h/a/b").unwrap();
+        assert_eq!(
+            base.join("\\\\other\\x").unwrap().serialize(),
+            "http://other/x"
+        );
+        assert_eq!(base.join("..\\c").unwrap().serialize(), "http://h/c");
+    }
+
+    #[test]
+    fn empty_host_rules() {
+        assert_eq!(Url::parse("https://"), Err(UrlParseError::MissingHost));
+        assert_eq!(Url::parse("ws://?x"), Err(UrlParseError::MissingHost));
+        assert_eq!(Url::parse("ws:///x").unwrap().serialize(), "ws://x/");
+        assert_eq!(Url::parse("foo:///x").unwrap().serialize(), "foo:///x");
+        assert_eq!(Url::parse("file:///x").unwrap().serialize(), "file:///x");
+        assert_eq!(Url::parse("foo:///x").unwrap().host(), Some(&Host::Empty));
+        assert_eq!(
+            Url::parse("file://localhost/x").unwrap().host(),
+            Some(&Host::Empty)
+        );
+    }
+
+    #[test]
+    fn opaque_hosts_and_forbidden_code_points() {
+        let u = Url::parse("foo://H%20st\u{E9}/").unwrap();
+        assert_eq!(u.host(), Some(&Host::Opaque("H%20st%C3%A9".into())));
+        assert_eq!(u.serialize(), "foo://H%20st%C3%A9/");
+
+        let mut errors = Vec::new();
+        for (input, c) in [
+            ("foo://a b/", ' '),
+            ("foo://a<b/", '<'),
+            ("foo://a^b/", '^'),
+        ] {
+            assert_eq!(
+                Url::parse_with_errors(input, &mut errors),
+                Err(UrlParseError::ForbiddenHostCodePoint(c))
+            );
+        }
+        assert_eq!(errors, [ValidationError::HostInvalidCodePoint; 3]);
+
+        errors.clear();
+        assert_eq!(
+            Url::parse_with_errors("http://a%3Cb/", &mut errors),
+            Err(UrlParseError::ForbiddenHostCodePoint('<'))
+        );
+        assert_eq!(errors, [ValidationError::DomainInvalidCodePoint]);
+        assert_eq!(
+            Url::parse("http://a%25b/"),
+            Err(UrlParseError::ForbiddenHostCodePoint('%'))
+        );
+        assert_eq!(
+            Url::parse("http://a|b/"),
+            Err(UrlParseError::ForbiddenHostCodePoint('|'))
+        );
+        // '%' is allowed in opaque hosts; a bad escape is only a validation error.
+        errors.clear();
+        let u = Url::parse_with_errors("foo://a%zz/", &mut errors).unwrap();
+        assert_eq!(u.host(), Some(&Host::Opaque("a%zz".into())));
+        assert_eq!(errors, [ValidationError::InvalidUrlUnit]);
+    }
+
+    #[test]
+    fn parse_file_urls() {
+        let cases = [
+            ("file:///C:/Users/x", "file:///C:/Users/x"),
+            ("file:///C|/Users/x", "file:///C:/Users/x"),
+            ("file://localhost/etc/hosts", "file:///etc/hosts"),
+            ("file://LOCALHOST/etc/hosts", "file:///etc/hosts"),
+            ("file://server/share/a", "file://server/share/a"),
+            ("file:c:\\dir\\f.txt", "file:///c:/dir/f.txt"),
+            ("file://C|/x", "file:///C:/x"),
+            ("file:/x", "file:///x"),
+            ("file:///C:/../..", "file:///C:/"),
+            ("file:?q#f", "file:///?q#f"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                Url::parse(input).unwrap().serialize(),
+                expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_against_file_base() {
+        let base = Url::parse("file:///C:/dir/page.html").unwrap();
+        assert_eq!(
+            base.join("img.png").unwrap().serialize(),
+            "file:///C:/dir/img.png"
+        );
+        assert_eq!(base.join("/other").unwrap().serialize(), "file:///C:/other");
+        assert_eq!(base.join("../../../x").unwrap().serialize(), "file:///C:/x");
+        assert_eq!(base.join("/D:/y").unwrap().serialize(), "file:///D:/y");
+        assert_eq!(base.join("file:D|/y").unwrap().serialize(), "file:///D:/y");
+        assert_eq!(base.join("//host/z").unwrap().serialize(), "file://host/z");
+
+        let mut errors = Vec::new();
+        Url::parse_with_errors("file://C:/x", &mut errors).unwrap();
+        assert!(errors.contains(&ValidationError::FileInvalidWindowsDriveLetterHost));
+    }
+
+    #[test]
+    fn hosts_use_domain_to_ascii() {
+        let u = Url::parse("http://Bücher.example/").unwrap();
+        assert_eq!(
+            u.host(),
+            Some(&Host::Domain("xn--bcher-kva.example".into()))
+        );
+        assert_eq!(
+            Url::parse("http://EXAMPLE.com/").unwrap().host(),
+            Url::parse("http://example.com/").unwrap().host()
+        );
+        let u = Url::parse("https://%E6%97%A5%E6%9C%AC.jp/").unwrap();
+        assert_eq!(u.serialize(), "https://xn--wgv71a.jp/");
+        let u = Url::parse("http://ＥＸＡＭＰＬＥ．com/").unwrap();
+        assert_eq!(u.serialize(), "http://example.com/");
+
+        let mut errors = Vec::new();
+        assert_eq!(
+            Url::parse_with_errors("http://a\u{200C}b/", &mut errors),
+            Err(UrlParseError::InvalidHost)
+        );
+        assert!(errors.contains(&ValidationError::DomainToAscii));
+        // Opaque hosts of non-special URLs are not IDNA-processed.
+        assert_eq!(
+            Url::parse("foo://Bücher/").unwrap().host(),
+            Some(&Host::Opaque("B%C3%BCcher".into()))
+        );
+    }
 
+    #[test]
+    fn host_to_unicode_for_display() {
+        let u = Url::parse("http://xn--bcher-kva.example/").unwrap();
+        assert_eq!(u.host().unwrap().to_unicode(), "bücher.example");
+        let u = Url::parse("http://127.0.0.1/").unwrap();
+        assert_eq!(u.host().unwrap().to_unicode(), "127.0.0.1");
+        assert_eq!(
+            Host::Domain("example.com".into()).to_unicode(),
+            "example.com"
+        );
+    }
+
+    #[test]
+    fn host_public_suffix_and_registrable_domain() {
+        let host = |input: &str| Url::parse(input).unwrap().host().unwrap().clone();
+        let h = host("https://www.Example.CO.uk./");
+        assert_eq!(h.public_suffix(), Some("co.uk."));
+        assert_eq!(h.registrable_domain(), Some("example.co.uk."));
+        assert_eq!(host("http://co.uk/").registrable_domain(), None);
+        assert_eq!(host("http://127.0.0.1/").public_suffix(), None);
+        assert_eq!(host("http://[::1]/").registrable_domain(), None);
+        assert!(host("http://a.example.com/").is_same_site(&host("http://b.example.com/")));
+        assert!(!host("http://example.com/").is_same_site(&host("http://example.net/")));
+    }
+
+    #[test]
+    fn parse_failures() {
+        assert_eq!(Url::parse("example.com"), Err(UrlParseError::MissingScheme));
+        assert_eq!(Url::parse("http://"), Err(UrlParseError::MissingHost));
+        assert_eq!(Url::parse("http://user@/"), Err(UrlParseError::MissingHost));
+        assert_eq!(Url::parse("http://h:8a/"), Err(UrlParseError::InvalidPort));
+        assert_eq!(
+            Url::parse("http://h:65536/"),
+            Err(UrlParseError::PortOutOfRange)
+        );
+        assert_eq!(Url::parse("http://[::1/"), Err(UrlParseError::InvalidHost));
+    }
+}
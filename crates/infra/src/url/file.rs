@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: MIT
+//! Conversions between `file:` URLs and filesystem paths.
+//! Both path flavours are available on every platform so that Windows-style paths can be
+//! handled (and tested) on POSIX hosts and vice versa.
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use super::{parser, Host, Url, UrlParseError};
+use crate::percent;
+
+/// Filesystem path syntax used by `Url::from_file_path_with_style` and friends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStyle {
+    /// `/home/user/file`
+    Posix,
+    /// `C:\Users\file` or `\\server\share\file`
+    Windows,
+}
+
+impl PathStyle {
+    /// The path style of the platform this crate was compiled for.
+    pub fn native() -> Self {
+        if cfg!(windows) {
+            PathStyle::Windows
+        } else {
+            PathStyle::Posix
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilePathError {
+    /// The path is relative and cannot become a URL.
+    NotAbsolute,
+    /// The path is not valid UTF-8.
+    NotUtf8,
+    /// The UNC server name is not a valid URL host.
+    InvalidHost(UrlParseError),
+    /// The URL's scheme is not `file`.
+    NotFileUrl,
+    /// The URL names a remote host, which POSIX paths cannot express.
+    RemoteHost,
+    /// A segment decodes to a separator, a NUL byte, or invalid UTF-8.
+    InvalidSegment,
+    /// A Windows path needs a drive letter or a UNC host.
+    MissingDriveLetter,
+}
+
+impl Display for FilePathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use FilePathError::*;
+        match self {
+            NotAbsolute => write!(f, "path is not absolute"),
+            NotUtf8 => write!(f, "path is not valid UTF-8"),
+            InvalidHost(err) => write!(f, "invalid UNC host: {}", err),
+            NotFileUrl => write!(f, "not a file URL"),
+            RemoteHost => write!(f, "file URL has a remote host"),
+            InvalidSegment => write!(f, "invalid path segment"),
+            MissingDriveLetter => write!(f, "missing drive letter"),
+        }
+    }
+}
+
+impl std::error::Error for FilePathError {}
+
+impl Url {
+    /// Build a `file:` URL from an absolute path of the current platform.
+    pub fn from_file_path(path: &Path) -> Result<Self, FilePathError> {
+        let path = path.to_str().ok_or(FilePathError::NotUtf8)?;
+        Url::from_file_path_with_style(path, PathStyle::native())
+    }
+
+    /// Build a `file:` URL from an absolute path written in `style`.
+    pub fn from_file_path_with_style(path: &str, style: PathStyle) -> Result<Self, FilePathError> {
+        let (host, segments): (&str, Vec<&str>) = match style {
+            PathStyle::Posix => {
+                let rest = path.strip_prefix('/').ok_or(FilePathError::NotAbsolute)?;
+                ("", rest.split('/').collect())
+            }
+            PathStyle::Windows => split_windows_path(path)?,
+        };
+        let mut serialization = String::from("file://");
+        serialization.push_str(host);
+        for segment in segments {
+            serialization.push('/');
+            serialization.push_str(&encode_segment(segment));
+        }
+        Url::parse(&serialization).map_err(FilePathError::InvalidHost)
+    }
+
+    /// Convert a `file:` URL to a path of the current platform.
+    pub fn to_file_path(&self) -> Result<PathBuf, FilePathError> {
+        self.to_file_path_with_style(PathStyle::native())
+            .map(PathBuf::from)
+    }
+
+    /// Convert a `file:` URL to a path written in `style`.
+    pub fn to_file_path_with_style(&self, style: PathStyle) -> Result<String, FilePathError> {
+        if self.scheme() != "file" {
+            return Err(FilePathError::NotFileUrl);
+        }
+        let host = match self.host() {
+            Some(Host::Empty) | None => None,
+            Some(host) => Some(host.to_string()),
+        };
+        let segments = self
+            .path_segments()
+            .into_iter()
+            .flatten()
+            .map(|segment| decode_segment(segment, style))
+            .collect::<Result<Vec<_>, _>>()?;
+        match style {
+            PathStyle::Posix => {
+                if host.is_some() {
+                    return Err(FilePathError::RemoteHost);
+                }
+                Ok(format!("/{}", segments.join("/")))
+            }
+            PathStyle::Windows => {
+                if let Some(host) = host {
+                    return Ok(format!("\\\\{}\\{}", host, segments.join("\\")));
+                }
+                let drive = segments
+                    .first()
+                    .filter(|segment| parser::is_normalized_windows_drive_letter(segment))
+                    .ok_or(FilePathError::MissingDriveLetter)?;
+                let rest = &segments[1..];
+                Ok(format!("{}\\{}", drive, rest.join("\\")))
+            }
+        }
+    }
+}
+
+/// Split a Windows path into a UNC host (empty for drive paths) and its segments.
+fn split_windows_path(path: &str) -> Result<(&str, Vec<&str>), FilePathError> {
+    if let Some(unc) = path.strip_prefix(r"\\?\UNC\") {
+        return split_unc_path(unc);
+    }
+    let path = path.strip_prefix(r"\\?\").unwrap_or(path);
+    if let Some(unc) = path.strip_prefix(r"\\").or_else(|| path.strip_prefix("//")) {
+        return split_unc_path(unc);
+    }
+    let bytes = path.as_bytes();
+    let has_drive = bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':';
+    let drive_is_absolute =
+        bytes.len() == 2 || bytes.get(2).is_some_and(|&b| b == b'\\' || b == b'/');
+    if !has_drive || !drive_is_absolute {
+        return Err(FilePathError::NotAbsolute);
+    }
+    let mut segments = vec![&path[..2]];
+    if bytes.len() > 3 {
+        segments.extend(path[3..].split(is_windows_separator));
+    } else {
+        segments.push("");
+    }
+    Ok(("", segments))
+}
+
+/// Split `server\share\rest` into the server name and the remaining segments.
+fn split_unc_path(unc: &str) -> Result<(&str, Vec<&str>), FilePathError> {
+    let (host, rest) = unc.split_once(is_windows_separator).unwrap_or((unc, ""));
+    if host.is_empty() {
+        return Err(FilePathError::NotAbsolute);
+    }
+    Ok((host, rest.split(is_windows_separator).collect()))
+}
+
+fn is_windows_separator(c: char) -> bool {
+    c == '\\' || c == '/'
+}
+
+fn encode_segment(segment: &str) -> String {
+    // Beyond the path set, '%' and '\' must not be reinterpreted and '|' must not form a
+    // "C|" drive letter quirk when the URL is parsed again.
+    const SEGMENT: percent::EncodeSet = percent::PATH.add_all(b"%\\|");
+    percent::utf8_percent_encode(segment, &SEGMENT)
+}
+
+fn decode_segment(segment: &str, style: PathStyle) -> Result<String, FilePathError> {
+    let bytes = percent::percent_decode_lenient(segment.as_bytes());
+    let forbidden = |b: &u8| match style {
+        PathStyle::Posix => matches!(b, b'/' | 0),
+        PathStyle::Windows => matches!(b, b'/' | b'\\' | 0),
+    };
+    if bytes.iter().any(forbidden) {
+        return Err(FilePathError::InvalidSegment);
+    }
+    String::from_utf8(bytes).map_err(|_| FilePathError::InvalidSegment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn posix_round_trip() {
+        let u = Url::from_file_path_with_style("/home/user/a b#1.txt", PathStyle::Posix).unwrap();
+        assert_eq!(u.serialize(), "file:///home/user/a%20b%231.txt");
+        assert_eq!(
+            u.to_file_path_with_style(PathStyle::Posix).unwrap(),
+            "/home/user/a b#1.txt"
+        );
+        assert_eq!(
+            Url::from_file_path_with_style("relative/x", PathStyle::Posix),
+            Err(FilePathError::NotAbsolute)
+        );
+        let remote = Url::parse("file://server/x").unwrap();
+        assert_eq!(
+            remote.to_file_path_with_style(PathStyle::Posix),
+            Err(FilePathError::RemoteHost)
+        );
+    }
+
+    #[test]
+    fn windows_round_trip() {
+        let u = Url::from_file_path_with_style(r"C:\Users\x\file.txt", PathStyle::Windows).unwrap();
+        assert_eq!(u.serialize(), "file:///C:/Users/x/file.txt");
+        assert_eq!(
+            u.to_file_path_with_style(PathStyle::Windows).unwrap(),
+            r"C:\Users\x\file.txt"
+        );
+
+        let root = Url::from_file_path_with_style("D:", PathStyle::Windows).unwrap();
+        assert_eq!(root.serialize(), "file:///D:/");
+        assert_eq!(
+            root.to_file_path_with_style(PathStyle::Windows).unwrap(),
+            r"D:\"
+        );
+
+        let unc =
+            Url::from_file_path_with_style(r"\\server\share\a.txt", PathStyle::Windows).unwrap();
+        assert_eq!(unc.serialize(), "file://server/share/a.txt");
+        assert_eq!(
+            unc.to_file_path_with_style(PathStyle::Windows).unwrap(),
+            r"\\server\share\a.txt"
+        );
+
+        let verbatim = Url::from_file_path_with_style(r"\\?\C:\x", PathStyle::Windows).unwrap();
+        assert_eq!(verbatim.serialize(), "file:///C:/x");
+        assert_eq!(
+            Url::from_file_path_with_style(r"C:relative", PathStyle::Windows),
+            Err(FilePathError::NotAbsolute)
+        );
+        assert_eq!(
+            Url::from_file_path_with_style(r"\\bad host\share", PathStyle::Windows),
+            Err(FilePathError::InvalidHost(
+                UrlParseError::ForbiddenHostCodePoint(' ')
+            ))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_path() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = Path::new(OsStr::from_bytes(b"/tmp/\xFF"));
+        assert_eq!(Url::from_file_path(path), Err(FilePathError::NotUtf8));
+    }
+
+    #[test]
+    fn to_file_path_errors() {
+        let http = Url::parse("http://example.com/").unwrap();
+        assert_eq!(
+            http.to_file_path_with_style(PathStyle::Posix),
+            Err(FilePathError::NotFileUrl)
+        );
+        let posix = Url::parse("file:///etc/hosts").unwrap();
+        assert_eq!(
+            posix.to_file_path_with_style(PathStyle::Windows),
+            Err(FilePathError::MissingDriveLetter)
+        );
+        let slash = Url::parse("file:///a%2Fb").unwrap();
+        assert_eq!(
+            slash.to_file_path_with_style(PathStyle::Posix),
+            Err(FilePathError::InvalidSegment)
+        );
+    }
+}
@@ -0,0 +1,461 @@
+// SPDX-License-Identifier: MIT
+//! The host parser and the IPv6 serializer from the URL Standard's host section.
+//! Special schemes get domains (IDNA-processed), IPv4 or IPv6 hosts; other schemes get
+//! opaque hosts, which are only percent-encoded. Both reject forbidden host code points.
+//! IPv4 follows the spec rather than `Ipv4Addr::from_str`: parts may be hex (`0x7f`), octal
+//! (`0177`) or fewer than four (`127.1`, `2130706433`), matching what browsers connect to.
+//! IPv6 likewise uses the spec grammar (no zone IDs) and the spec's compressed output, which
+//! never prints embedded IPv4 dotted quads.
+
+use std::fmt::Write;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use super::{parser, Host, UrlParseError, ValidationError};
+use crate::{idna, percent};
+
+/// The host parser. `is_opaque` is true for non-special schemes.
+pub(super) fn parse_host(
+    input: &str,
+    is_opaque: bool,
+    errors: &mut Vec<ValidationError>,
+) -> Result<Host, UrlParseError> {
+    if let Some(inner) = input.strip_prefix('[') {
+        let inner = inner.strip_suffix(']').ok_or_else(|| {
+            errors.push(ValidationError::Ipv6Unclosed);
+            UrlParseError::InvalidHost
+        })?;
+        return parse_ipv6(inner, errors).map(Host::Ipv6);
+    }
+    if is_opaque {
+        return parse_opaque_host(input, errors);
+    }
+    let decoded = percent::percent_decode_lenient(input.as_bytes());
+    let domain = String::from_utf8_lossy(&decoded);
+    let domain = idna::domain_to_ascii(&domain, false).map_err(|_| {
+        errors.push(ValidationError::DomainToAscii);
+        UrlParseError::InvalidHost
+    })?;
+    if domain.is_empty() {
+        errors.push(ValidationError::DomainToAscii);
+        return Err(UrlParseError::InvalidHost);
+    }
+    if let Some(c) = domain.chars().find(|&c| is_forbidden_domain_code_point(c)) {
+        errors.push(ValidationError::DomainInvalidCodePoint);
+        return Err(UrlParseError::ForbiddenHostCodePoint(c));
+    }
+    if ends_in_a_number(&domain) {
+        return parse_ipv4(&domain, errors).map(Host::Ipv4);
+    }
+    Ok(Host::Domain(domain))
+}
+
+/// The opaque-host parser.
+fn parse_opaque_host(
+    input: &str,
+    errors: &mut Vec<ValidationError>,
+) -> Result<Host, UrlParseError> {
+    if let Some(c) = input.chars().find(|&c| is_forbidden_host_code_point(c)) {
+        errors.push(ValidationError::HostInvalidCodePoint);
+        return Err(UrlParseError::ForbiddenHostCodePoint(c));
+    }
+    let bytes = input.as_bytes();
+    for (index, c) in input.char_indices() {
+        let valid = if c == '%' {
+            bytes.len() > index + 2
+                && bytes[index + 1].is_ascii_hexdigit()
+                && bytes[index + 2].is_ascii_hexdigit()
+        } else {
+            parser::is_url_code_point(c)
+        };
+        if !valid {
+            errors.push(ValidationError::InvalidUrlUnit);
+        }
+    }
+    if input.is_empty() {
+        return Ok(Host::Empty);
+    }
+    let encoded = percent::percent_encode_set(bytes, &percent::C0_CONTROL);
+    Ok(Host::Opaque(encoded))
+}
+
+/// Forbidden host code points may not appear in any host.
+pub fn is_forbidden_host_code_point(c: char) -> bool {
+    matches!(
+        c,
+        '\0' | '\t'
+            | '\n'
+            | '\r'
+            | ' '
+            | '#'
+            | '/'
+            | ':'
+            | '<'
+            | '>'
+            | '?'
+            | '@'
+            | '['
+            | '\\'
+            | ']'
+            | '^'
+            | '|'
+    )
+}
+
+/// Forbidden domain code points additionally exclude C0 controls, '%' and U+007F.
+pub fn is_forbidden_domain_code_point(c: char) -> bool {
+    is_forbidden_host_code_point(c) || matches!(c, '\u{0}'..='\u{1F}' | '%' | '\u{7F}')
+}
+
+/// The "ends in a number" checker: decides whether a domain must be parsed as IPv4.
+pub(super) fn ends_in_a_number(input: &str) -> bool {
+    let mut parts: Vec<&str> = input.split('.').collect();
+    if parts.last() == Some(&"") {
+        if parts.len() == 1 {
+            return false;
+        }
+        parts.pop();
+    }
+    let last = parts.last().copied().unwrap_or("");
+    if !last.is_empty() && last.bytes().all(|b| b.is_ascii_digit()) {
+        return true;
+    }
+    parse_ipv4_number(last).is_some()
+}
+
+/// The IPv4 parser. Non-fatal oddities (hex/octal parts, a trailing dot) are reported to
+/// `errors`; anything that cannot be an address is a failure.
+pub(super) fn parse_ipv4(
+    input: &str,
+    errors: &mut Vec<ValidationError>,
+) -> Result<Ipv4Addr, UrlParseError> {
+    let mut parts: Vec<&str> = input.split('.').collect();
+    if parts.last() == Some(&"") {
+        errors.push(ValidationError::Ipv4EmptyPart);
+        if parts.len() > 1 {
+            parts.pop();
+        }
+    }
+    if parts.len() > 4 {
+        errors.push(ValidationError::Ipv4TooManyParts);
+        return Err(UrlParseError::InvalidHost);
+    }
+    let mut numbers = Vec::with_capacity(parts.len());
+    for part in &parts {
+        let Some((number, non_decimal)) = parse_ipv4_number(part) else {
+            errors.push(ValidationError::Ipv4NonNumericPart);
+            return Err(UrlParseError::InvalidHost);
+        };
+        if non_decimal {
+            errors.push(ValidationError::Ipv4NonDecimalPart);
+        }
+        numbers.push(number);
+    }
+    if numbers.iter().any(|&n| n > 255) {
+        errors.push(ValidationError::Ipv4OutOfRangePart);
+    }
+    let (&last, leading) = numbers.split_last().ok_or(UrlParseError::InvalidHost)?;
+    if leading.iter().any(|&n| n > 255) {
+        return Err(UrlParseError::InvalidHost);
+    }
+    // The last part fills all remaining bytes: 127.1 is 127.0.0.1.
+    let last_limit = 1u64 << (8 * (5 - numbers.len()));
+    if last >= last_limit {
+        return Err(UrlParseError::InvalidHost);
+    }
+    let mut address = last;
+    for (counter, &n) in leading.iter().enumerate() {
+        address += n << (8 * (3 - counter));
+    }
+    Ok(Ipv4Addr::from(address as u32))
+}
+
+/// The IPv6 parser, for the text between `[` and `]`. Every IPv6 validation error is fatal.
+pub(super) fn parse_ipv6(
+    input: &str,
+    errors: &mut Vec<ValidationError>,
+) -> Result<Ipv6Addr, UrlParseError> {
+    use ValidationError::*;
+    let mut fail = |error: ValidationError| {
+        errors.push(error);
+        Err(UrlParseError::InvalidHost)
+    };
+    let input = input.as_bytes();
+    let mut address = [0u16; 8];
+    let mut piece_index = 0;
+    let mut compress = None;
+    let mut pointer = 0;
+    let at = |pointer: usize| input.get(pointer).copied();
+
+    if at(pointer) == Some(b':') {
+        if at(pointer + 1) != Some(b':') {
+            return fail(Ipv6InvalidCompression);
+        }
+        pointer += 2;
+        piece_index += 1;
+        compress = Some(piece_index);
+    }
+    while let Some(c) = at(pointer) {
+        if piece_index == 8 {
+            return fail(Ipv6TooManyPieces);
+        }
+        if c == b':' {
+            if compress.is_some() {
+                return fail(Ipv6MultipleCompression);
+            }
+            pointer += 1;
+            piece_index += 1;
+            compress = Some(piece_index);
+            continue;
+        }
+        let mut value: u16 = 0;
+        let mut length = 0;
+        while length < 4 {
+            let Some(digit) = at(pointer).and_then(|b| (b as char).to_digit(16)) else {
+                break;
+            };
+            value = value * 0x10 + digit as u16;
+            pointer += 1;
+            length += 1;
+        }
+        match at(pointer) {
+            Some(b'.') => {
+                if length == 0 {
+                    return fail(Ipv4InIpv6InvalidCodePoint);
+                }
+                pointer -= length;
+                if piece_index > 6 {
+                    return fail(Ipv4InIpv6TooManyPieces);
+                }
+                let mut numbers_seen = 0;
+                while let Some(mut c) = at(pointer) {
+                    if numbers_seen > 0 {
+                        if c == b'.' && numbers_seen < 4 {
+                            pointer += 1;
+                            c = at(pointer).unwrap_or(0);
+                        } else {
+                            return fail(Ipv4InIpv6InvalidCodePoint);
+                        }
+                    }
+                    if !c.is_ascii_digit() {
+                        return fail(Ipv4InIpv6InvalidCodePoint);
+                    }
+                    let mut ipv4_piece: Option<u16> = None;
+                    while let Some(digit) = at(pointer).filter(u8::is_ascii_digit) {
+                        let number = u16::from(digit - b'0');
+                        ipv4_piece = match ipv4_piece {
+                            None => Some(number),
+                            Some(0) => return fail(Ipv4InIpv6InvalidCodePoint),
+                            Some(piece) => Some(piece * 10 + number),
+                        };
+                        if ipv4_piece > Some(255) {
+                            return fail(Ipv4InIpv6OutOfRangePart);
+                        }
+                        pointer += 1;
+                    }
+                    address[piece_index] = address[piece_index] * 0x100 + ipv4_piece.unwrap_or(0);
+                    numbers_seen += 1;
+                    if numbers_seen == 2 || numbers_seen == 4 {
+                        piece_index += 1;
+                    }
+                }
+                if numbers_seen != 4 {
+                    return fail(Ipv4InIpv6TooFewParts);
+                }
+                break;
+            }
+            Some(b':') => {
+                pointer += 1;
+                if at(pointer).is_none() {
+                    return fail(Ipv6InvalidCodePoint);
+                }
+            }
+            Some(_) => return fail(Ipv6InvalidCodePoint),
+            None => {}
+        }
+        address[piece_index] = value;
+        piece_index += 1;
+    }
+    match compress {
+        Some(compress) => {
+            // Move the pieces after "::" to the end of the address.
+            let mut swaps = piece_index - compress;
+            piece_index = 7;
+            while piece_index != 0 && swaps > 0 {
+                address.swap(piece_index, compress + swaps - 1);
+                piece_index -= 1;
+                swaps -= 1;
+            }
+        }
+        None if piece_index != 8 => return fail(Ipv6TooFewPieces),
+        None => {}
+    }
+    Ok(Ipv6Addr::from(address))
+}
+
+/// The IPv6 serializer (without brackets): lowercase hex pieces, with the first longest run
+/// of two or more zero pieces compressed to "::".
+pub(super) fn serialize_ipv6(address: &Ipv6Addr) -> String {
+    let pieces = address.segments();
+    let mut compress = None;
+    let mut longest = 1;
+    let mut index = 0;
+    while index < 8 {
+        let run = pieces[index..]
+            .iter()
+            .take_while(|&&piece| piece == 0)
+            .count();
+        if run > longest {
+            longest = run;
+            compress = Some(index);
+        }
+        index += run.max(1);
+    }
+    let mut output = String::new();
+    let mut ignore_zero = false;
+    for (index, &piece) in pieces.iter().enumerate() {
+        if ignore_zero && piece == 0 {
+            continue;
+        }
+        ignore_zero = false;
+        if compress == Some(index) {
+            output.push_str(if index == 0 { "::" } else { ":" });
+            ignore_zero = true;
+            continue;
+        }
+        let _ = write!(output, "{:x}", piece);
+        if index != 7 {
+            output.push(':');
+        }
+    }
+    output
+}
+
+/// The IPv4 number parser: returns the value and whether it was written in hex or octal.
+/// Values too large for a u64 saturate, which is still out of range for any part.
+fn parse_ipv4_number(input: &str) -> Option<(u64, bool)> {
+    if input.is_empty() {
+        return None;
+    }
+    let (digits, radix) = if let Some(hex) = input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+    {
+        (hex, 16)
+    } else if input.len() >= 2 && input.starts_with('0') {
+        (&input[1..], 8)
+    } else {
+        (input, 10)
+    };
+    let non_decimal = radix != 10;
+    if digits.is_empty() {
+        return Some((0, true));
+    }
+    let mut value: u64 = 0;
+    for c in digits.chars() {
+        let digit = c.to_digit(radix)?;
+        value = value
+            .saturating_mul(u64::from(radix))
+            .saturating_add(u64::from(digit));
+    }
+    Some((value, non_decimal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ipv4(input: &str) -> Result<Ipv4Addr, UrlParseError> {
+        parse_ipv4(input, &mut Vec::new())
+    }
+
+    #[test]
+    fn parses_ipv4_forms() {
+        let loopback = Ok(Ipv4Addr::new(127, 0, 0, 1));
+        assert_eq!(ipv4("127.0.0.1"), loopback);
+        assert_eq!(ipv4("0x7f.1"), loopback);
+        assert_eq!(ipv4("0177.0.0.1"), loopback);
+        assert_eq!(ipv4("2130706433"), loopback);
+        assert_eq!(ipv4("0x7F000001"), loopback);
+        assert_eq!(ipv4("127.0.1"), loopback);
+        assert_eq!(ipv4("127.0.0.1."), loopback);
+        assert_eq!(ipv4("0x"), Ok(Ipv4Addr::new(0, 0, 0, 0)));
+        assert_eq!(ipv4("255.255.255.255"), Ok(Ipv4Addr::BROADCAST));
+
+        let mut errors = Vec::new();
+        parse_ipv4("0x7f.1.", &mut errors).unwrap();
+        assert_eq!(
+            errors,
+            [
+                ValidationError::Ipv4EmptyPart,
+                ValidationError::Ipv4NonDecimalPart
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_ipv4() {
+        assert_eq!(ipv4("1.2.3.4.5"), Err(UrlParseError::InvalidHost));
+        assert_eq!(ipv4("256.0.0.1"), Err(UrlParseError::InvalidHost));
+        assert_eq!(ipv4("1.2.3.256"), Err(UrlParseError::InvalidHost));
+        assert_eq!(ipv4("4294967296"), Err(UrlParseError::InvalidHost));
+        assert_eq!(ipv4("1.2..4"), Err(UrlParseError::InvalidHost));
+        assert_eq!(ipv4("08.1.1.1"), Err(UrlParseError::InvalidHost));
+        assert_eq!(
+            ipv4("99999999999999999999999"),
+            Err(UrlParseError::InvalidHost)
+        );
+    }
+
+    fn ipv6(input: &str) -> Result<String, Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        parse_ipv6(input, &mut errors)
+            .map(|address| serialize_ipv6(&address))
+            .map_err(|_| errors)
+    }
+
+    #[test]
+    fn parses_and_compresses_ipv6() {
+        assert_eq!(ipv6("::1").unwrap(), "::1");
+        assert_eq!(ipv6("::").unwrap(), "::");
+        assert_eq!(ipv6("0:0:0:0:0:0:0:0").unwrap(), "::");
+        assert_eq!(ipv6("2001:DB8:0:0:1:0:0:1").unwrap(), "2001:db8::1:0:0:1");
+        assert_eq!(ipv6("1:0:2:0:3:0:4:0").unwrap(), "1:0:2:0:3:0:4:0");
+        assert_eq!(ipv6("0:0:1:0:0:0:0:1").unwrap(), "0:0:1::1");
+        assert_eq!(ipv6("1::").unwrap(), "1::");
+        assert_eq!(ipv6("::ffff:1.2.3.4").unwrap(), "::ffff:102:304");
+        assert_eq!(ipv6("::127.0.0.1").unwrap(), "::7f00:1");
+        assert_eq!(ipv6("1:2:3:4:5:6:0.0.0.0").unwrap(), "1:2:3:4:5:6::");
+    }
+
+    #[test]
+    fn rejects_invalid_ipv6() {
+        use ValidationError::*;
+        assert_eq!(ipv6(":1"), Err(vec![Ipv6InvalidCompression]));
+        assert_eq!(ipv6("1:2:3:4:5:6:7:8:9"), Err(vec![Ipv6TooManyPieces]));
+        assert_eq!(ipv6("1::2::3"), Err(vec![Ipv6MultipleCompression]));
+        assert_eq!(ipv6("::1%eth0"), Err(vec![Ipv6InvalidCodePoint]));
+        assert_eq!(ipv6("1:"), Err(vec![Ipv6InvalidCodePoint]));
+        assert_eq!(ipv6("1:2:3:4:5:6:7"), Err(vec![Ipv6TooFewPieces]));
+        assert_eq!(ipv6("12345::"), Err(vec![Ipv6InvalidCodePoint]));
+        assert_eq!(
+            ipv6("1:2:3:4:5:6:7:1.2.3.4"),
+            Err(vec![Ipv4InIpv6TooManyPieces])
+        );
+        assert_eq!(ipv6("::1.2.3"), Err(vec![Ipv4InIpv6TooFewParts]));
+        assert_eq!(ipv6("::1.2.3.256"), Err(vec![Ipv4InIpv6OutOfRangePart]));
+        assert_eq!(ipv6("::01.2.3.4"), Err(vec![Ipv4InIpv6InvalidCodePoint]));
+        assert_eq!(ipv6("::.1.2.3"), Err(vec![Ipv4InIpv6InvalidCodePoint]));
+    }
+
+    #[test]
+    fn ends_in_a_number_checker() {
+        assert!(ends_in_a_number("127.0.0.1"));
+        assert!(ends_in_a_number("example.0x1"));
+        assert!(ends_in_a_number("a.1."));
+        assert!(ends_in_a_number("foo.09"));
+        assert!(!ends_in_a_number("example.com"));
+        assert!(!ends_in_a_number("1.example"));
+        assert!(!ends_in_a_number(""));
+        assert!(!ends_in_a_number("0xg"));
+    }
+}
@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: MIT
+//! Origins (HTML Standard, "origin"; URL Standard, "URL origin").
+//! Special schemes other than `file` have a tuple origin of scheme, host and port; `blob:` URLs
+//! take the origin of the URL they wrap; everything else gets a fresh opaque origin.
+
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::scheme::SpecialScheme;
+use super::{Host, Url};
+
+/// An opaque origin. Each one is distinct from every other, including ones created later for
+/// the same URL, so it only compares equal to its own clones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OpaqueOrigin(u64);
+
+impl OpaqueOrigin {
+    pub fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        OpaqueOrigin(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for OpaqueOrigin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Origin {
+    Opaque(OpaqueOrigin),
+    /// A scheme, host and port; `port` is None when it is the scheme's default.
+    Tuple {
+        scheme: String,
+        host: Host,
+        port: Option<u16>,
+    },
+}
+
+impl Origin {
+    pub fn new_opaque() -> Self {
+        Origin::Opaque(OpaqueOrigin::new())
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        matches!(self, Origin::Opaque(_))
+    }
+
+    /// Same origin: identical tuples, or the very same opaque origin.
+    pub fn is_same_origin(&self, other: &Origin) -> bool {
+        self == other
+    }
+
+    /// Same site (HTML Standard): the same opaque origin, or tuples with the same scheme whose
+    /// hosts are equal or share a registrable domain. Ports are ignored.
+    pub fn is_same_site(&self, other: &Origin) -> bool {
+        match (self, other) {
+            (Origin::Opaque(a), Origin::Opaque(b)) => a == b,
+            (
+                Origin::Tuple {
+                    scheme: scheme_a,
+                    host: host_a,
+                    ..
+                },
+                Origin::Tuple {
+                    scheme: scheme_b,
+                    host: host_b,
+                    ..
+                },
+            ) => scheme_a == scheme_b && host_a.is_same_site(host_b),
+            _ => false,
+        }
+    }
+
+    /// The ASCII serialization, e.g. `https://example.com:8443`, or `null` when opaque.
+    pub fn ascii_serialization(&self) -> String {
+        self.serialize_with(Host::to_string)
+    }
+
+    /// Like `ascii_serialization`, with the host converted back to Unicode for display.
+    pub fn unicode_serialization(&self) -> String {
+        self.serialize_with(Host::to_unicode)
+    }
+
+    fn serialize_with(&self, host_to_string: impl Fn(&Host) -> String) -> String {
+        match self {
+            Origin::Opaque(_) => "null".to_string(),
+            Origin::Tuple { scheme, host, port } => {
+                let mut out = format!("{}://{}", scheme, host_to_string(host));
+                if let Some(port) = port {
+                    out.push(':');
+                    out.push_str(&port.to_string());
+                }
+                out
+            }
+        }
+    }
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.ascii_serialization())
+    }
+}
+
+impl Url {
+    /// The URL's origin. URLs without a tuple origin get a new opaque origin on every call.
+    pub fn origin(&self) -> Origin {
+        if self.scheme() == "blob" {
+            return match Url::parse(self.path()) {
+                Ok(inner) if matches!(inner.scheme(), "http" | "https") => inner.origin(),
+                _ => Origin::new_opaque(),
+            };
+        }
+        match (SpecialScheme::from_scheme(self.scheme()), self.host()) {
+            (Some(special), Some(host)) if special != SpecialScheme::File => Origin::Tuple {
+                scheme: self.scheme().to_owned(),
+                host: host.clone(),
+                port: self.port(),
+            },
+            _ => Origin::new_opaque(),
+        }
+    }
+
+    /// Returns true when both URLs have the same tuple origin.
+    pub fn is_same_origin(&self, other: &Url) -> bool {
+        self.origin().is_same_origin(&other.origin())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn origin(input: &str) -> Origin {
+        Url::parse(input).unwrap().origin()
+    }
+
+    #[test]
+    fn tuple_and_opaque_origins() {
+        assert_eq!(
+            origin("https://user@example.com:8443/a?b#c").to_string(),
+            "https://example.com:8443"
+        );
+        assert_eq!(
+            origin("HTTP://EXAMPLE.com:80/").to_string(),
+            "http://example.com"
+        );
+        assert_eq!(origin("ws://[::1]:81").to_string(), "ws://[::1]:81");
+        assert_eq!(
+            origin("blob:https://example.com:443/uuid").to_string(),
+            "https://example.com"
+        );
+        for opaque in [
+            "file:///etc",
+            "data:text/plain,x",
+            "blob:ftp://h/x",
+            "foo://h/",
+        ] {
+            assert!(origin(opaque).is_opaque(), "{}", opaque);
+            assert_eq!(origin(opaque).to_string(), "null");
+        }
+        assert_eq!(
+            origin("https://xn--bcher-kva.example/").unicode_serialization(),
+            "https://bücher.example"
+        );
+    }
+
+    #[test]
+    fn same_origin_and_same_site() {
+        let a = Url::parse("https://example.com/a").unwrap();
+        let b = Url::parse("https://example.com:443/b?q").unwrap();
+        let c = Url::parse("https://example.com:8443/").unwrap();
+        let d = Url::parse("http://example.com/").unwrap();
+        assert!(a.is_same_origin(&b));
+        assert!(!a.is_same_origin(&c));
+        assert!(a.origin().is_same_site(&c.origin()));
+        assert!(!a.origin().is_same_site(&d.origin()));
+        let sub = Url::parse("https://www.example.com/").unwrap();
+        assert!(a.origin().is_same_site(&sub.origin()));
+        let pages = |user: &str| Url::parse(&format!("https://{}.github.io/", user)).unwrap();
+        assert!(!pages("a").origin().is_same_site(&pages("b").origin()));
+
+        let data = Url::parse("data:,x").unwrap();
+        assert!(!data.is_same_origin(&data));
+        let opaque = data.origin();
+        assert!(opaque.is_same_origin(&opaque.clone()));
+        assert!(opaque.is_same_site(&opaque.clone()));
+        assert!(!opaque.is_same_site(&a.origin()));
+    }
+}
@@ -0,0 +1,861 @@
+// SPDX-License-Identifier: MIT
+//! Basic URL parser state machine (URL Standard, "basic URL parser").
+//! The parser walks the input one code point at a time; `pointer` may step back by one so
+//! that the next state re-consumes the current code point, mirroring the specification text.
+//! Setters run the same machine on an existing record with a state override: they start in
+//! the given state and stop once the component they own has been parsed.
+
+use super::record::UrlRecord;
+use super::{host, scheme, Host, UrlParseError, ValidationError};
+use crate::percent;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum State {
+    SchemeStart,
+    Scheme,
+    NoScheme,
+    SpecialRelativeOrAuthority,
+    SpecialAuthoritySlashes,
+    PathOrAuthority,
+    SpecialAuthorityIgnoreSlashes,
+    Relative,
+    RelativeSlash,
+    Authority,
+    File,
+    FileSlash,
+    FileHost,
+    Host,
+    /// The host state, entered only as the `hostname` setter's state override.
+    Hostname,
+    Port,
+    PathStart,
+    Path,
+    OpaquePath,
+    Query,
+    Fragment,
+    /// Not a spec state: the parser stops here when the spec says "return".
+    Done,
+}
+
+/// Run the basic URL parser over `input`, resolving against `base` when given and recording
+/// validation errors into `errors`.
+pub(super) fn basic_parse(
+    input: &str,
+    base: Option<&UrlRecord>,
+    errors: &mut Vec<ValidationError>,
+) -> Result<UrlRecord, UrlParseError> {
+    let input = preprocess(input, true, errors);
+    let mut parser = Parser::new(&input, base, UrlRecord::default(), None, errors);
+    parser.run(State::SchemeStart)?;
+    Ok(parser.finish())
+}
+
+/// Run the basic URL parser over `input` for a setter, starting in `state_override` and
+/// modifying `url` in place. As in the specification, components committed before a failure
+/// stay committed (a host setter value of "h:99999" still changes the host).
+pub(super) fn parse_with_state_override(
+    input: &str,
+    url: &mut UrlRecord,
+    state_override: State,
+) -> Result<(), UrlParseError> {
+    let mut errors = Vec::new();
+    let input = preprocess(input, false, &mut errors);
+    let record = std::mem::take(url);
+    let mut parser = Parser::new(&input, None, record, Some(state_override), &mut errors);
+    let result = parser.run(state_override);
+    *url = parser.finish();
+    result
+}
+
+/// Remove ASCII tab or newline; without a state override, also strip leading/trailing C0
+/// control or space.
+fn preprocess(input: &str, trim: bool, errors: &mut Vec<ValidationError>) -> Vec<char> {
+    let is_c0_or_space = |c: char| c <= ' ';
+    let trimmed = if trim {
+        input.trim_matches(is_c0_or_space)
+    } else {
+        input
+    };
+    if trimmed.len() != input.len() {
+        errors.push(ValidationError::InvalidUrlUnit);
+    }
+    let mut out = Vec::with_capacity(trimmed.len());
+    let mut saw_tab_or_newline = false;
+    for c in trimmed.chars() {
+        if matches!(c, '\t' | '\n' | '\r') {
+            saw_tab_or_newline = true;
+        } else {
+            out.push(c);
+        }
+    }
+    if saw_tab_or_newline {
+        errors.push(ValidationError::InvalidUrlUnit);
+    }
+    out
+}
+
+struct Parser<'a> {
+    input: &'a [char],
+    base: Option<&'a UrlRecord>,
+    pointer: isize,
+    errors: &'a mut Vec<ValidationError>,
+    url: UrlRecord,
+    state_override: Option<State>,
+    buffer: String,
+    at_sign_seen: bool,
+    inside_brackets: bool,
+    password_token_seen: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn new(
+        input: &'a [char],
+        base: Option<&'a UrlRecord>,
+        url: UrlRecord,
+        state_override: Option<State>,
+        errors: &'a mut Vec<ValidationError>,
+    ) -> Self {
+        Parser {
+            input,
+            base,
+            pointer: 0,
+            errors,
+            url,
+            state_override,
+            buffer: String::new(),
+            at_sign_seen: false,
+            inside_brackets: false,
+            password_token_seen: false,
+        }
+    }
+
+    fn finish(self) -> UrlRecord {
+        let mut url = self.url;
+        if url.password.as_deref() == Some("") {
+            url.password = None;
+        }
+        url
+    }
+
+    fn run(&mut self, mut state: State) -> Result<(), UrlParseError> {
+        loop {
+            let c = self.current();
+            state = match state {
+                State::SchemeStart => self.scheme_start(c)?,
+                State::Scheme => self.scheme(c)?,
+                State::NoScheme => self.no_scheme(c)?,
+                State::SpecialRelativeOrAuthority => self.special_relative_or_authority(c),
+                State::SpecialAuthoritySlashes => self.special_authority_slashes(c),
+                State::PathOrAuthority => self.path_or_authority(c),
+                State::SpecialAuthorityIgnoreSlashes => self.special_authority_ignore_slashes(c),
+                State::Relative => self.relative(c),
+                State::RelativeSlash => self.relative_slash(c),
+                State::Authority => self.authority(c)?,
+                State::File => self.file(c),
+                State::FileSlash => self.file_slash(c),
+                State::FileHost => self.file_host(c)?,
+                State::Host | State::Hostname => self.host(c)?,
+                State::Port => self.port(c)?,
+                State::PathStart => self.path_start(c),
+                State::Path => self.path(c),
+                State::OpaquePath => self.opaque_path(c),
+                State::Query => self.query(c),
+                State::Fragment => self.fragment(c),
+                State::Done => return Ok(()),
+            };
+            if state == State::Done || self.pointer >= self.input.len() as isize {
+                return Ok(());
+            }
+            self.pointer += 1;
+        }
+    }
+
+    fn current(&self) -> Option<char> {
+        if self.pointer < 0 {
+            return None;
+        }
+        self.input.get(self.pointer as usize).copied()
+    }
+
+    /// Code points after the current one.
+    fn remaining(&self) -> &[char] {
+        let start = (self.pointer + 1).max(0) as usize;
+        self.input.get(start..).unwrap_or(&[])
+    }
+
+    fn is_special(&self) -> bool {
+        scheme::is_special(&self.url.scheme)
+    }
+
+    /// '/' always separates components; special URLs also accept '\' (with a validation error
+    /// reported by the caller where the specification asks for one).
+    fn is_slash(&self, c: Option<char>) -> bool {
+        c == Some('/') || (c == Some('\\') && self.is_special())
+    }
+
+    /// End of the authority, host or port: EOF, a slash, '?' or '#'.
+    fn ends_authority(&self, c: Option<char>) -> bool {
+        matches!(c, None | Some('?' | '#')) || self.is_slash(c)
+    }
+
+    fn report_reverse_solidus(&mut self, c: Option<char>) {
+        if c == Some('\\') {
+            self.validation_error(ValidationError::InvalidReverseSolidus);
+        }
+    }
+
+    fn validation_error(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    fn failure(&mut self, error: ValidationError, failure: UrlParseError) -> UrlParseError {
+        self.errors.push(error);
+        failure
+    }
+
+    fn scheme_start(&mut self, c: Option<char>) -> Result<State, UrlParseError> {
+        match c {
+            Some(c) if c.is_ascii_alphabetic() => {
+                self.buffer.push(c.to_ascii_lowercase());
+                Ok(State::Scheme)
+            }
+            _ if self.state_override.is_none() => {
+                self.pointer -= 1;
+                Ok(State::NoScheme)
+            }
+            _ => Err(UrlParseError::MissingScheme),
+        }
+    }
+
+    fn scheme(&mut self, c: Option<char>) -> Result<State, UrlParseError> {
+        match c {
+            Some(c) if c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.') => {
+                self.buffer.push(c.to_ascii_lowercase());
+                Ok(State::Scheme)
+            }
+            Some(':') if self.state_override.is_some() => {
+                // The protocol setter cannot switch between special and non-special schemes,
+                // nor turn a URL with credentials, a port or an empty host into a file URL.
+                let url = &self.url;
+                let blocked = self.is_special() != scheme::is_special(&self.buffer)
+                    || (self.buffer == "file"
+                        && (url.includes_credentials() || url.port.is_some()))
+                    || (url.scheme == "file" && url.host == Some(Host::Empty));
+                if !blocked {
+                    self.url.scheme = std::mem::take(&mut self.buffer);
+                    if self.url.port == scheme::default_port(&self.url.scheme) {
+                        self.url.port = None;
+                    }
+                }
+                Ok(State::Done)
+            }
+            Some(':') => {
+                self.url.scheme = std::mem::take(&mut self.buffer);
+                let base_has_same_scheme =
+                    self.base.is_some_and(|base| base.scheme == self.url.scheme);
+                let state = if self.url.scheme == "file" {
+                    if !self.remaining().starts_with(&['/', '/']) {
+                        self.validation_error(
+                            ValidationError::SpecialSchemeMissingFollowingSolidus,
+                        );
+                    }
+                    State::File
+                } else if self.is_special() && base_has_same_scheme {
+                    State::SpecialRelativeOrAuthority
+                } else if self.is_special() {
+                    State::SpecialAuthoritySlashes
+                } else if self.remaining().first() == Some(&'/') {
+                    self.pointer += 1;
+                    State::PathOrAuthority
+                } else {
+                    self.url.path = vec![String::new()];
+                    self.url.has_opaque_path = true;
+                    State::OpaquePath
+                };
+                Ok(state)
+            }
+            _ if self.state_override.is_none() => {
+                // Not a scheme after all; start over from the first code point.
+                self.buffer.clear();
+                self.pointer = -1;
+                Ok(State::NoScheme)
+            }
+            _ => Err(UrlParseError::MissingScheme),
+        }
+    }
+
+    fn no_scheme(&mut self, c: Option<char>) -> Result<State, UrlParseError> {
+        let base = match self.base {
+            Some(base) if !base.has_opaque_path || c == Some('#') => base,
+            _ => {
+                return Err(self.failure(
+                    ValidationError::MissingSchemeNonRelativeUrl,
+                    UrlParseError::MissingScheme,
+                ))
+            }
+        };
+        if base.has_opaque_path {
+            // Only a fragment may be resolved against a base with an opaque path.
+            self.url.scheme = base.scheme.clone();
+            self.url.path = base.path.clone();
+            self.url.has_opaque_path = true;
+            self.url.query = base.query.clone();
+            self.url.fragment = Some(String::new());
+            return Ok(State::Fragment);
+        }
+        self.pointer -= 1;
+        if base.scheme == "file" {
+            Ok(State::File)
+        } else {
+            Ok(State::Relative)
+        }
+    }
+
+    fn special_relative_or_authority(&mut self, c: Option<char>) -> State {
+        if c == Some('/') && self.remaining().first() == Some(&'/') {
+            self.pointer += 1;
+            State::SpecialAuthorityIgnoreSlashes
+        } else {
+            self.validation_error(ValidationError::SpecialSchemeMissingFollowingSolidus);
+            self.pointer -= 1;
+            State::Relative
+        }
+    }
+
+    fn relative(&mut self, c: Option<char>) -> State {
+        let base = self.base.expect("relative state requires a base URL");
+        self.url.scheme = base.scheme.clone();
+        if self.is_slash(c) {
+            self.report_reverse_solidus(c);
+            return State::RelativeSlash;
+        }
+        self.copy_authority_from(base);
+        self.url.path = base.path.clone();
+        self.url.query = base.query.clone();
+        match c {
+            Some('?') => {
+                self.url.query = Some(String::new());
+                State::Query
+            }
+            Some('#') => {
+                self.url.fragment = Some(String::new());
+                State::Fragment
+            }
+            Some(_) => {
+                self.url.query = None;
+                self.shorten_path();
+                self.pointer -= 1;
+                State::Path
+            }
+            None => State::Relative,
+        }
+    }
+
+    fn relative_slash(&mut self, c: Option<char>) -> State {
+        if self.is_special() && self.is_slash(c) {
+            self.report_reverse_solidus(c);
+            return State::SpecialAuthorityIgnoreSlashes;
+        }
+        if c == Some('/') {
+            return State::Authority;
+        }
+        let base = self.base.expect("relative slash state requires a base URL");
+        self.copy_authority_from(base);
+        self.pointer -= 1;
+        State::Path
+    }
+
+    fn file(&mut self, c: Option<char>) -> State {
+        self.url.scheme = "file".to_string();
+        self.url.host = Some(Host::Empty);
+        if matches!(c, Some('/' | '\\')) {
+            self.report_reverse_solidus(c);
+            return State::FileSlash;
+        }
+        let Some(base) = self.base.filter(|base| base.scheme == "file") else {
+            self.pointer -= 1;
+            return State::Path;
+        };
+        self.url.host = base.host.clone();
+        self.url.path = base.path.clone();
+        self.url.query = base.query.clone();
+        match c {
+            Some('?') => {
+                self.url.query = Some(String::new());
+                State::Query
+            }
+            Some('#') => {
+                self.url.fragment = Some(String::new());
+                State::Fragment
+            }
+            Some(_) => {
+                self.url.query = None;
+                if starts_with_windows_drive_letter(self.rest_from_pointer()) {
+                    // "file:C:/x" against a file base must not inherit the base path.
+                    self.validation_error(ValidationError::FileInvalidWindowsDriveLetter);
+                    self.url.path.clear();
+                } else {
+                    self.shorten_path();
+                }
+                self.pointer -= 1;
+                State::Path
+            }
+            None => State::File,
+        }
+    }
+
+    fn file_slash(&mut self, c: Option<char>) -> State {
+        if matches!(c, Some('/' | '\\')) {
+            self.report_reverse_solidus(c);
+            return State::FileHost;
+        }
+        if let Some(base) = self.base.filter(|base| base.scheme == "file") {
+            self.url.host = base.host.clone();
+            // "/dir" against "file:///C:/x" stays on drive C:.
+            let base_drive = base
+                .path
+                .first()
+                .filter(|segment| is_normalized_windows_drive_letter(segment));
+            if !starts_with_windows_drive_letter(self.rest_from_pointer()) {
+                if let Some(drive) = base_drive {
+                    self.url.path.push(drive.clone());
+                }
+            }
+        }
+        self.pointer -= 1;
+        State::Path
+    }
+
+    fn file_host(&mut self, c: Option<char>) -> Result<State, UrlParseError> {
+        if let Some(c) = c.filter(|c| !matches!(c, '/' | '\\' | '?' | '#')) {
+            self.buffer.push(c);
+            return Ok(State::FileHost);
+        }
+        self.pointer -= 1;
+        if self.state_override.is_none() && is_windows_drive_letter(&self.buffer) {
+            // "file://C:/x" names a drive, not a host; the buffer is consumed by the path state.
+            self.validation_error(ValidationError::FileInvalidWindowsDriveLetterHost);
+            return Ok(State::Path);
+        }
+        let buffer = std::mem::take(&mut self.buffer);
+        let mut host = Host::Empty;
+        if !buffer.is_empty() {
+            host = host::parse_host(&buffer, false, self.errors)?;
+            if host == Host::Domain("localhost".to_string()) {
+                host = Host::Empty;
+            }
+        }
+        self.url.host = Some(host);
+        if self.state_override.is_some() {
+            return Ok(State::Done);
+        }
+        Ok(State::PathStart)
+    }
+
+    /// Code points from the current one to the end of the input.
+    fn rest_from_pointer(&self) -> &[char] {
+        let start = self.pointer.max(0) as usize;
+        self.input.get(start..).unwrap_or(&[])
+    }
+
+    /// Remove the last path segment, except a lone drive letter of a file URL.
+    fn shorten_path(&mut self) {
+        let path = &self.url.path;
+        if self.url.scheme == "file"
+            && path.len() == 1
+            && is_normalized_windows_drive_letter(&path[0])
+        {
+            return;
+        }
+        self.url.path.pop();
+    }
+
+    fn copy_authority_from(&mut self, base: &UrlRecord) {
+        self.url.username = base.username.clone();
+        self.url.password = base.password.clone();
+        self.url.host = base.host.clone();
+        self.url.port = base.port;
+    }
+
+    fn special_authority_slashes(&mut self, c: Option<char>) -> State {
+        if c == Some('/') && self.remaining().first() == Some(&'/') {
+            self.pointer += 1;
+        } else {
+            self.validation_error(ValidationError::SpecialSchemeMissingFollowingSolidus);
+            self.pointer -= 1;
+        }
+        State::SpecialAuthorityIgnoreSlashes
+    }
+
+    fn path_or_authority(&mut self, c: Option<char>) -> State {
+        if c == Some('/') {
+            State::Authority
+        } else {
+            self.pointer -= 1;
+            State::Path
+        }
+    }
+
+    fn special_authority_ignore_slashes(&mut self, c: Option<char>) -> State {
+        if matches!(c, Some('/' | '\\')) {
+            self.validation_error(ValidationError::SpecialSchemeMissingFollowingSolidus);
+            State::SpecialAuthorityIgnoreSlashes
+        } else {
+            self.pointer -= 1;
+            State::Authority
+        }
+    }
+
+    fn authority(&mut self, c: Option<char>) -> Result<State, UrlParseError> {
+        match c {
+            Some('@') => {
+                self.validation_error(ValidationError::InvalidCredentials);
+                if self.at_sign_seen {
+                    self.buffer.insert_str(0, "%40");
+                }
+                self.at_sign_seen = true;
+                let buffer = std::mem::take(&mut self.buffer);
+                for code_point in buffer.chars() {
+                    if code_point == ':' && !self.password_token_seen {
+                        self.password_token_seen = true;
+                        continue;
+                    }
+                    let encoded = encode_code_point(code_point, &percent::USERINFO);
+                    if self.password_token_seen {
+                        self.url
+                            .password
+                            .get_or_insert_with(String::new)
+                            .push_str(&encoded);
+                    } else {
+                        self.url.username.push_str(&encoded);
+                    }
+                }
+                Ok(State::Authority)
+            }
+            Some(c) if !self.ends_authority(Some(c)) => {
+                self.buffer.push(c);
+                Ok(State::Authority)
+            }
+            _ => {
+                if self.at_sign_seen && self.buffer.is_empty() {
+                    return Err(
+                        self.failure(ValidationError::HostMissing, UrlParseError::MissingHost)
+                    );
+                }
+                self.pointer -= self.buffer.chars().count() as isize + 1;
+                self.buffer.clear();
+                Ok(State::Host)
+            }
+        }
+    }
+
+    fn host(&mut self, c: Option<char>) -> Result<State, UrlParseError> {
+        if self.state_override.is_some() && self.url.scheme == "file" {
+            self.pointer -= 1;
+            return Ok(State::FileHost);
+        }
+        match c {
+            Some(':') if !self.inside_brackets => {
+                if self.buffer.is_empty() {
+                    return Err(
+                        self.failure(ValidationError::HostMissing, UrlParseError::MissingHost)
+                    );
+                }
+                if self.state_override == Some(State::Hostname) {
+                    // The hostname setter rejects a value that carries a port.
+                    return Err(UrlParseError::InvalidHost);
+                }
+                self.commit_host()?;
+                Ok(State::Port)
+            }
+            Some(c) if !self.ends_authority(Some(c)) => {
+                if c == '[' {
+                    self.inside_brackets = true;
+                } else if c == ']' {
+                    self.inside_brackets = false;
+                }
+                self.buffer.push(c);
+                Ok(State::Host)
+            }
+            _ => {
+                self.pointer -= 1;
+                if self.is_special() && self.buffer.is_empty() {
+                    return Err(
+                        self.failure(ValidationError::HostMissing, UrlParseError::MissingHost)
+                    );
+                }
+                if self.state_override.is_some() {
+                    // A URL with credentials or a port cannot lose its host.
+                    let keeps_authority =
+                        self.url.includes_credentials() || self.url.port.is_some();
+                    if !(self.buffer.is_empty() && keeps_authority) {
+                        self.commit_host()?;
+                    }
+                    return Ok(State::Done);
+                }
+                self.commit_host()?;
+                Ok(State::PathStart)
+            }
+        }
+    }
+
+    fn commit_host(&mut self) -> Result<(), UrlParseError> {
+        let buffer = std::mem::take(&mut self.buffer);
+        if buffer.is_empty() {
+            self.url.host = Some(Host::Empty);
+            return Ok(());
+        }
+        let host = host::parse_host(&buffer, !self.is_special(), self.errors)?;
+        self.url.host = Some(host);
+        Ok(())
+    }
+
+    fn port(&mut self, c: Option<char>) -> Result<State, UrlParseError> {
+        match c {
+            Some(c) if c.is_ascii_digit() => {
+                self.buffer.push(c);
+                Ok(State::Port)
+            }
+            Some(_) if !self.ends_authority(c) && self.state_override.is_none() => {
+                Err(self.failure(ValidationError::PortInvalid, UrlParseError::InvalidPort))
+            }
+            _ => {
+                if !self.buffer.is_empty() {
+                    let port = self
+                        .buffer
+                        .parse::<u16>()
+                        .map_err(|_| self.failure_port_out_of_range())?;
+                    // Default ports are elided so "http://a:80/" equals "http://a/".
+                    let is_default = scheme::default_port(&self.url.scheme) == Some(port);
+                    self.url.port = if is_default { None } else { Some(port) };
+                    self.buffer.clear();
+                }
+                if self.state_override.is_some() {
+                    return Ok(State::Done);
+                }
+                self.pointer -= 1;
+                Ok(State::PathStart)
+            }
+        }
+    }
+
+    fn failure_port_out_of_range(&mut self) -> UrlParseError {
+        // The buffer only holds ASCII digits, so any parse error means the value is too large.
+        self.failure(
+            ValidationError::PortOutOfRange,
+            UrlParseError::PortOutOfRange,
+        )
+    }
+
+    fn path_start(&mut self, c: Option<char>) -> State {
+        if self.is_special() {
+            self.report_reverse_solidus(c);
+            if !self.is_slash(c) {
+                self.pointer -= 1;
+            }
+            return State::Path;
+        }
+        match c {
+            Some('?') if self.state_override.is_none() => {
+                self.url.query = Some(String::new());
+                State::Query
+            }
+            Some('#') if self.state_override.is_none() => {
+                self.url.fragment = Some(String::new());
+                State::Fragment
+            }
+            Some(c) => {
+                if c != '/' {
+                    self.pointer -= 1;
+                }
+                State::Path
+            }
+            None => {
+                if self.state_override.is_some() && self.url.host.is_none() {
+                    self.url.path.push(String::new());
+                }
+                State::PathStart
+            }
+        }
+    }
+
+    fn path(&mut self, c: Option<char>) -> State {
+        // With a state override (the pathname setter) '?' and '#' are path code points.
+        let ends_segment = c.is_none()
+            || self.is_slash(c)
+            || (self.state_override.is_none() && matches!(c, Some('?' | '#')));
+        match c {
+            Some(c) if !ends_segment => {
+                self.check_url_unit(c);
+                self.buffer.push_str(&encode_code_point(c, &percent::PATH));
+                State::Path
+            }
+            _ => {
+                self.report_reverse_solidus(c);
+                let slash = self.is_slash(c);
+                if is_double_dot_segment(&self.buffer) {
+                    self.shorten_path();
+                    if !slash {
+                        self.url.path.push(String::new());
+                    }
+                } else if is_single_dot_segment(&self.buffer) {
+                    if !slash {
+                        self.url.path.push(String::new());
+                    }
+                } else {
+                    if self.url.scheme == "file"
+                        && self.url.path.is_empty()
+                        && is_windows_drive_letter(&self.buffer)
+                    {
+                        // Normalize the "C|" quirk to "C:".
+                        self.buffer.replace_range(1..2, ":");
+                    }
+                    self.url.path.push(std::mem::take(&mut self.buffer));
+                }
+                self.buffer.clear();
+                match c {
+                    Some('?') => {
+                        self.url.query = Some(String::new());
+                        State::Query
+                    }
+                    Some('#') => {
+                        self.url.fragment = Some(String::new());
+                        State::Fragment
+                    }
+                    _ => State::Path,
+                }
+            }
+        }
+    }
+
+    fn opaque_path(&mut self, c: Option<char>) -> State {
+        let next_starts_query_or_fragment = matches!(self.remaining().first(), Some('?' | '#'));
+        let opaque = &mut self.url.path[0];
+        match c {
+            Some('?') => {
+                self.url.query = Some(String::new());
+                State::Query
+            }
+            Some('#') => {
+                self.url.fragment = Some(String::new());
+                State::Fragment
+            }
+            Some(' ') => {
+                // A trailing space would otherwise be lost when the query or fragment is removed.
+                if next_starts_query_or_fragment {
+                    opaque.push_str("%20");
+                } else {
+                    opaque.push(' ');
+                }
+                State::OpaquePath
+            }
+            Some(c) => {
+                opaque.push_str(&encode_code_point(c, &percent::C0_CONTROL));
+                self.check_url_unit(c);
+                State::OpaquePath
+            }
+            None => State::OpaquePath,
+        }
+    }
+
+    fn query(&mut self, c: Option<char>) -> State {
+        let ends_query = c.is_none() || (self.state_override.is_none() && c == Some('#'));
+        match c {
+            Some(c) if !ends_query => {
+                self.check_url_unit(c);
+                self.buffer.push(c);
+                State::Query
+            }
+            _ => {
+                let set = if self.is_special() {
+                    &percent::SPECIAL_QUERY
+                } else {
+                    &percent::QUERY
+                };
+                let encoded = percent::utf8_percent_encode(&self.buffer, set);
+                self.url
+                    .query
+                    .get_or_insert_with(String::new)
+                    .push_str(&encoded);
+                self.buffer.clear();
+                if c == Some('#') {
+                    self.url.fragment = Some(String::new());
+                    return State::Fragment;
+                }
+                State::Query
+            }
+        }
+    }
+
+    fn fragment(&mut self, c: Option<char>) -> State {
+        if let Some(c) = c {
+            self.check_url_unit(c);
+            let encoded = encode_code_point(c, &percent::FRAGMENT);
+            self.url
+                .fragment
+                .get_or_insert_with(String::new)
+                .push_str(&encoded);
+        }
+        State::Fragment
+    }
+
+    /// Report invalid-URL-unit for non URL code points and for '%' not followed by two hex digits.
+    fn check_url_unit(&mut self, c: char) {
+        if c == '%' {
+            let rest = self.remaining();
+            let valid =
+                rest.len() >= 2 && rest[0].is_ascii_hexdigit() && rest[1].is_ascii_hexdigit();
+            if !valid {
+                self.validation_error(ValidationError::InvalidUrlUnit);
+            }
+        } else if !is_url_code_point(c) {
+            self.validation_error(ValidationError::InvalidUrlUnit);
+        }
+    }
+}
+
+/// Two code points: an ASCII alpha followed by ':' or '|'.
+fn is_windows_drive_letter(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && matches!(bytes[1], b':' | b'|')
+}
+
+pub(super) fn is_normalized_windows_drive_letter(s: &str) -> bool {
+    is_windows_drive_letter(s) && s.as_bytes()[1] == b':'
+}
+
+/// A drive letter followed by the end of input or a path/query/fragment delimiter.
+fn starts_with_windows_drive_letter(rest: &[char]) -> bool {
+    rest.len() >= 2
+        && rest[0].is_ascii_alphabetic()
+        && matches!(rest[1], ':' | '|')
+        && (rest.len() == 2 || matches!(rest[2], '/' | '\\' | '?' | '#'))
+}
+
+fn is_single_dot_segment(segment: &str) -> bool {
+    segment == "." || segment.eq_ignore_ascii_case("%2e")
+}
+
+fn is_double_dot_segment(segment: &str) -> bool {
+    matches!(segment.len(), 2 | 4 | 6)
+        && ["..", ".%2e", "%2e.", "%2e%2e"]
+            .iter()
+            .any(|dots| segment.eq_ignore_ascii_case(dots))
+}
+
+/// URL code points: ASCII alphanumerics, a fixed punctuation set, and non-ASCII scalar values
+/// other than noncharacters.
+pub(super) fn is_url_code_point(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_alphanumeric() || "!$&'()*+,-./:;=?@_~".contains(c);
+    }
+    let cp = c as u32;
+    let noncharacter = (0xFDD0..=0xFDEF).contains(&cp) || (cp & 0xFFFE) == 0xFFFE;
+    cp >= 0xA0 && !noncharacter
+}
+
+/// UTF-8 percent-encode a single code point using `set`.
+fn encode_code_point(c: char, set: &percent::EncodeSet) -> String {
+    let mut utf8 = [0u8; 4];
+    percent::utf8_percent_encode(c.encode_utf8(&mut utf8), set)
+}
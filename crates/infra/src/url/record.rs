@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+//! The URL record of the specification, with owned components.
+//! The parser builds a `UrlRecord`; `into_url` runs the URL serializer once and keeps the
+//! component offsets so that `Url` getters are slices of a single string.
+
+use super::{Host, Url};
+
+/// Owned URL components, exactly as the URL Standard's URL record holds them.
+///
+/// `path` holds the percent-encoded path segments. When `has_opaque_path` is set (for example
+/// `mailto:` or `javascript:` URLs) it holds exactly one element: the opaque path string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct UrlRecord {
+    pub(super) scheme: String,
+    pub(super) username: String,
+    pub(super) password: Option<String>,
+    pub(super) host: Option<Host>,
+    pub(super) port: Option<u16>,
+    pub(super) path: Vec<String>,
+    pub(super) has_opaque_path: bool,
+    pub(super) query: Option<String>,
+    pub(super) fragment: Option<String>,
+}
+
+impl UrlRecord {
+    /// Copy the components of `url` back out of its serialization.
+    pub(super) fn from_url(url: &Url) -> Self {
+        let path = if url.has_opaque_path() {
+            vec![url.path().to_owned()]
+        } else {
+            url.path_segments()
+                .into_iter()
+                .flatten()
+                .map(str::to_owned)
+                .collect()
+        };
+        UrlRecord {
+            scheme: url.scheme().to_owned(),
+            username: url.username().to_owned(),
+            password: url.password().map(str::to_owned),
+            host: url.host().cloned(),
+            port: url.port(),
+            path,
+            has_opaque_path: url.has_opaque_path(),
+            query: url.query().map(str::to_owned),
+            fragment: url.fragment().map(str::to_owned),
+        }
+    }
+
+    /// Returns true when the username or password is non-empty.
+    pub(super) fn includes_credentials(&self) -> bool {
+        !self.username.is_empty() || self.password.as_deref().is_some_and(|pw| !pw.is_empty())
+    }
+
+    /// The spec's "cannot have a username/password/port": no host, the empty host, or `file`.
+    pub(super) fn cannot_have_credentials_or_port(&self) -> bool {
+        matches!(self.host, None | Some(Host::Empty)) || self.scheme == "file"
+    }
+
+    /// The URL serializer, recording where each component starts and ends.
+    pub(super) fn into_url(self) -> Url {
+        let mut s = String::with_capacity(self.scheme.len() + 16);
+        s.push_str(&self.scheme);
+        let scheme_end = s.len();
+        s.push(':');
+        let username_end;
+        let host_start;
+        let host_end;
+        if let Some(host) = &self.host {
+            s.push_str("//");
+            if self.includes_credentials() {
+                s.push_str(&self.username);
+                username_end = s.len();
+                if let Some(pw) = self.password.as_deref().filter(|pw| !pw.is_empty()) {
+                    s.push(':');
+                    s.push_str(pw);
+                }
+                s.push('@');
+            } else {
+                username_end = s.len();
+            }
+            host_start = s.len();
+            s.push_str(&host.to_string());
+            host_end = s.len();
+            if let Some(port) = self.port {
+                s.push(':');
+                s.push_str(&port.to_string());
+            }
+        } else {
+            username_end = s.len();
+            host_start = s.len();
+            host_end = s.len();
+        }
+        if self.host.is_none()
+            && !self.has_opaque_path
+            && self.path.len() > 1
+            && self.path[0].is_empty()
+        {
+            // Keep "web+demo:/.//not-a-host/" from reparsing with a host.
+            s.push_str("/.");
+        }
+        let path_start = s.len();
+        if self.has_opaque_path {
+            s.push_str(self.path.first().map_or("", String::as_str));
+        } else {
+            for segment in &self.path {
+                s.push('/');
+                s.push_str(segment);
+            }
+        }
+        let query_start = self.query.as_ref().map(|q| {
+            let start = s.len();
+            s.push('?');
+            s.push_str(q);
+            start
+        });
+        let fragment_start = self.fragment.as_ref().map(|f| {
+            let start = s.len();
+            s.push('#');
+            s.push_str(f);
+            start
+        });
+        Url {
+            serialization: s,
+            scheme_end,
+            username_end,
+            host_start,
+            host_end,
+            host: self.host,
+            port: self.port,
+            path_start,
+            query_start,
+            fragment_start,
+            has_opaque_path: self.has_opaque_path,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+//! Special schemes and their default ports (URL Standard, "special scheme").
+
+/// The schemes the URL Standard treats specially: they always have a host (except `file`),
+/// treat '\' like '/', and elide their default port during parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialScheme {
+    Ftp,
+    File,
+    Http,
+    Https,
+    Ws,
+    Wss,
+}
+
+impl SpecialScheme {
+    /// Look up a special scheme by its (already ASCII-lowercased) name.
+    pub fn from_scheme(scheme: &str) -> Option<Self> {
+        match scheme {
+            "ftp" => Some(SpecialScheme::Ftp),
+            "file" => Some(SpecialScheme::File),
+            "http" => Some(SpecialScheme::Http),
+            "https" => Some(SpecialScheme::Https),
+            "ws" => Some(SpecialScheme::Ws),
+            "wss" => Some(SpecialScheme::Wss),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SpecialScheme::Ftp => "ftp",
+            SpecialScheme::File => "file",
+            SpecialScheme::Http => "http",
+            SpecialScheme::Https => "https",
+            SpecialScheme::Ws => "ws",
+            SpecialScheme::Wss => "wss",
+        }
+    }
+
+    /// The scheme's default port; `file` has none.
+    pub fn default_port(self) -> Option<u16> {
+        match self {
+            SpecialScheme::Ftp => Some(21),
+            SpecialScheme::File => None,
+            SpecialScheme::Http | SpecialScheme::Ws => Some(80),
+            SpecialScheme::Https | SpecialScheme::Wss => Some(443),
+        }
+    }
+}
+
+/// Returns true when `scheme` is one of the special schemes.
+pub fn is_special(scheme: &str) -> bool {
+    SpecialScheme::from_scheme(scheme).is_some()
+}
+
+/// The default port for `scheme`, or None for non-special schemes and `file`.
+pub fn default_port(scheme: &str) -> Option<u16> {
+    SpecialScheme::from_scheme(scheme).and_then(SpecialScheme::default_port)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn special_schemes_and_default_ports() {
+        assert_eq!(default_port("http"), Some(80));
+        assert_eq!(default_port("wss"), Some(443));
+        assert_eq!(default_port("ftp"), Some(21));
+        assert_eq!(default_port("file"), None);
+        assert_eq!(default_port("gopher"), None);
+        assert!(is_special("file"));
+        assert!(!is_special("HTTP"));
+        assert_eq!(
+            SpecialScheme::from_scheme("ws").map(SpecialScheme::as_str),
+            Some("ws")
+        );
+    }
+}
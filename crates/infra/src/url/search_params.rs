@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MIT
+//! `SearchParams`, the counterpart of the URL Standard's `URLSearchParams`: an ordered list of
+//! name-value pairs read from and written to a query as application/x-www-form-urlencoded.
+//! A `Url` keeps only its serialization, so the list is a parsed copy; `Url::search_params`
+//! reads it and `Url::set_search_params` runs the spec's update steps to write it back.
+
+use std::fmt::{Display, Formatter};
+
+use super::setters::strip_trailing_spaces_from_opaque_path;
+use super::Url;
+use crate::percent;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchParams {
+    list: Vec<(String, String)>,
+}
+
+impl SearchParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a query string; a single leading '?' is ignored, as in `new URLSearchParams("?a=b")`.
+    pub fn parse(input: &str) -> Self {
+        let input = input.strip_prefix('?').unwrap_or(input);
+        SearchParams {
+            list: percent::form_urlencoded_parse(input.as_bytes()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Add a pair at the end of the list.
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.list.push((name.to_owned(), value.to_owned()));
+    }
+
+    /// Remove every pair named `name`.
+    pub fn delete(&mut self, name: &str) {
+        self.list.retain(|(n, _)| n != name);
+    }
+
+    /// Remove every pair named `name` whose value is `value`.
+    pub fn delete_value(&mut self, name: &str, value: &str) {
+        self.list.retain(|(n, v)| n != name || v != value);
+    }
+
+    /// The value of the first pair named `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.list
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The values of every pair named `name`, in list order.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.list
+            .iter()
+            .filter(move |(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.list.iter().any(|(n, _)| n == name)
+    }
+
+    /// Set the value of the first pair named `name` and remove the others; append a new pair
+    /// if there is none.
+    pub fn set(&mut self, name: &str, value: &str) {
+        match self.list.iter().position(|(n, _)| n == name) {
+            Some(first) => {
+                self.list[first].1 = value.to_owned();
+                let mut index = 0;
+                self.list.retain(|(n, _)| {
+                    let keep = index <= first || n != name;
+                    index += 1;
+                    keep
+                });
+            }
+            None => self.append(name, value),
+        }
+    }
+
+    /// Stable sort by name, comparing UTF-16 code units like JavaScript does, so U+FFFD sorts
+    /// after U+1F600 (a surrogate pair).
+    pub fn sort(&mut self) {
+        self.list
+            .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.list.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+}
+
+impl Display for SearchParams {
+    /// The application/x-www-form-urlencoded serialization, without a leading '?'.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&percent::form_urlencoded_serialize(self.iter()))
+    }
+}
+
+impl<N: Into<String>, V: Into<String>> FromIterator<(N, V)> for SearchParams {
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self {
+        SearchParams {
+            list: iter
+                .into_iter()
+                .map(|(n, v)| (n.into(), v.into()))
+                .collect(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a SearchParams {
+    type Item = (&'a str, &'a str);
+    type IntoIter = Box<dyn Iterator<Item = (&'a str, &'a str)> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+impl Url {
+    /// The query parsed as a name-value list; empty when there is no query.
+    pub fn search_params(&self) -> SearchParams {
+        self.query().map(SearchParams::parse).unwrap_or_default()
+    }
+
+    /// Replace the query with the serialization of `params`; an empty list removes the query.
+    pub fn set_search_params(&mut self, params: &SearchParams) {
+        self.update(|url| {
+            let query = params.to_string();
+            url.query = Some(query).filter(|q| !q.is_empty());
+            strip_trailing_spaces_from_opaque_path(url);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_operations() {
+        let mut params = SearchParams::parse("?a=1&b=2&a=3&c");
+        assert_eq!(params.len(), 4);
+        assert_eq!(params.get("a"), Some("1"));
+        assert_eq!(params.get_all("a").collect::<Vec<_>>(), ["1", "3"]);
+        assert_eq!(params.get("c"), Some(""));
+        assert!(!params.has("d"));
+
+        params.set("a", "x y");
+        assert_eq!(params.to_string(), "a=x+y&b=2&c=");
+        params.set("d", "&");
+        params.append("b", "4");
+        params.delete_value("b", "2");
+        assert_eq!(params.to_string(), "a=x+y&c=&d=%26&b=4");
+        params.delete("a");
+        assert_eq!(
+            params.iter().collect::<Vec<_>>(),
+            [("c", ""), ("d", "&"), ("b", "4")]
+        );
+    }
+
+    #[test]
+    fn sort_by_utf16_code_units_is_stable() {
+        let mut params: SearchParams = [
+            ("\u{FFFD}", "1"),
+            ("z", "2"),
+            ("\u{1F600}", "3"),
+            ("a", "4"),
+            ("z", "5"),
+        ]
+        .into_iter()
+        .collect();
+        params.sort();
+        let names: Vec<_> = params.iter().map(|(_, v)| v).collect();
+        assert_eq!(names, ["4", "2", "5", "3", "1"]);
+    }
+
+    #[test]
+    fn syncs_with_url_query() {
+        let mut u = Url::parse("https://example.com/?b=2&a=1#top").unwrap();
+        let mut params = u.search_params();
+        params.sort();
+        params.append("q", "rust lang");
+        u.set_search_params(&params);
+        assert_eq!(u.as_str(), "https://example.com/?a=1&b=2&q=rust+lang#top");
+
+        u.set_search("");
+        assert!(u.search_params().is_empty());
+        u.set_search_params(&SearchParams::new());
+        assert_eq!(u.query(), None);
+        assert_eq!(u.as_str(), "https://example.com/#top");
+    }
+}
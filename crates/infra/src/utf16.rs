@@ -0,0 +1,409 @@
+// SPDX-License-Identifier: MIT
+//! UTF-16: the Encoding Standard's UTF-16LE and UTF-16BE decoders, and `Utf16String`, a
+//! sequence of code units with the semantics of a JavaScript string.
+//! `Utf16Decoder` turns bytes into text and, like `Utf8Decoder`, replaces a lone surrogate or
+//! a truncated code unit with U+FFFD unless running in fatal mode.
+//! `Utf16String` may hold lone surrogates. Converting it to `String` is lossy or fallible;
+//! `to_wtf8` and `from_wtf8` keep lone surrogates by encoding them as three-byte sequences,
+//! the way WTF-8 does.
+
+use std::fmt::{Display, Formatter};
+use std::ops::Index;
+use std::string::FromUtf16Error;
+
+use crate::utf8::ErrorMode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Utf16ErrorKind {
+    /// A lead surrogate not followed by a trail surrogate, or a trail surrogate on its own.
+    LoneSurrogate,
+    /// The input ended in the middle of a code unit or after a lead surrogate.
+    UnexpectedEof,
+}
+
+impl Display for Utf16ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use Utf16ErrorKind::*;
+        match self {
+            LoneSurrogate => write!(f, "lone surrogate"),
+            UnexpectedEof => write!(f, "unexpected end of input"),
+        }
+    }
+}
+
+impl std::error::Error for Utf16ErrorKind {}
+
+fn is_lead_surrogate(unit: u16) -> bool {
+    (0xD800..=0xDBFF).contains(&unit)
+}
+
+fn is_trail_surrogate(unit: u16) -> bool {
+    (0xDC00..=0xDFFF).contains(&unit)
+}
+
+/// Incremental UTF-16LE or UTF-16BE decoder.
+#[derive(Debug, Clone, Default)]
+pub struct Utf16Decoder {
+    mode: ErrorMode,
+    big_endian: bool,
+    lead_byte: Option<u8>,
+    lead_surrogate: Option<u16>,
+}
+
+impl Utf16Decoder {
+    pub fn new_le(mode: ErrorMode) -> Self {
+        Utf16Decoder {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    pub fn new_be(mode: ErrorMode) -> Self {
+        Utf16Decoder {
+            mode,
+            big_endian: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn mode(&self) -> ErrorMode {
+        self.mode
+    }
+
+    pub fn is_big_endian(&self) -> bool {
+        self.big_endian
+    }
+
+    /// Returns true when half a code unit or a lead surrogate is waiting for more bytes.
+    pub fn has_pending(&self) -> bool {
+        self.lead_byte.is_some() || self.lead_surrogate.is_some()
+    }
+
+    /// Decode `chunk`, appending to `output`. Set `last` on the final chunk so that a
+    /// truncated code unit or surrogate pair at the end of the stream is reported.
+    ///
+    /// In fatal mode the first error is returned; text decoded before it has already been
+    /// appended, and the decoder is ready for a new stream.
+    pub fn decode(
+        &mut self,
+        chunk: &[u8],
+        output: &mut String,
+        last: bool,
+    ) -> Result<(), Utf16ErrorKind> {
+        output.reserve(chunk.len() / 2);
+        for &byte in chunk {
+            let Some(lead_byte) = self.lead_byte.take() else {
+                self.lead_byte = Some(byte);
+                continue;
+            };
+            let unit = if self.big_endian {
+                u16::from_be_bytes([lead_byte, byte])
+            } else {
+                u16::from_le_bytes([lead_byte, byte])
+            };
+            if let Some(lead) = self.lead_surrogate.take() {
+                if is_trail_surrogate(unit) {
+                    let cp =
+                        0x10000 + ((u32::from(lead) - 0xD800) << 10) + u32::from(unit) - 0xDC00;
+                    output.push(char::from_u32(cp).expect("surrogate pair is a scalar value"));
+                    continue;
+                }
+                // The unit after a lone lead surrogate is decoded afresh.
+                self.error(Utf16ErrorKind::LoneSurrogate, output)?;
+            }
+            if is_lead_surrogate(unit) {
+                self.lead_surrogate = Some(unit);
+            } else if is_trail_surrogate(unit) {
+                self.error(Utf16ErrorKind::LoneSurrogate, output)?;
+            } else {
+                output.push(char::from_u32(u32::from(unit)).expect("not a surrogate"));
+            }
+        }
+        if last && self.has_pending() {
+            self.lead_byte = None;
+            self.lead_surrogate = None;
+            self.error(Utf16ErrorKind::UnexpectedEof, output)?;
+        }
+        Ok(())
+    }
+
+    fn error(&mut self, kind: Utf16ErrorKind, output: &mut String) -> Result<(), Utf16ErrorKind> {
+        match self.mode {
+            ErrorMode::Replacement => {
+                output.push(char::REPLACEMENT_CHARACTER);
+                Ok(())
+            }
+            ErrorMode::Fatal => {
+                self.lead_byte = None;
+                self.lead_surrogate = None;
+                Err(kind)
+            }
+        }
+    }
+}
+
+/// A string of UTF-16 code units, possibly with lone surrogates, like a JavaScript string.
+/// Lengths and indices count code units, and the ordering compares code units, which is how
+/// JavaScript compares strings and how `URLSearchParams.sort` orders names.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utf16String {
+    units: Vec<u16>,
+}
+
+impl Utf16String {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_units(units: Vec<u16>) -> Self {
+        Utf16String { units }
+    }
+
+    pub fn units(&self) -> &[u16] {
+        &self.units
+    }
+
+    pub fn into_units(self) -> Vec<u16> {
+        self.units
+    }
+
+    /// The length in code units, as `String.prototype.length` counts.
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// The code unit at `index`, like `charCodeAt`.
+    pub fn code_unit_at(&self, index: usize) -> Option<u16> {
+        self.units.get(index).copied()
+    }
+
+    /// The code point starting at `index`, like `codePointAt`: a surrogate pair is combined,
+    /// and a lone surrogate or a trail surrogate in the middle of a pair is returned as is.
+    pub fn code_point_at(&self, index: usize) -> Option<u32> {
+        let unit = self.code_unit_at(index)?;
+        match self.code_unit_at(index + 1) {
+            Some(next) if is_lead_surrogate(unit) && is_trail_surrogate(next) => {
+                Some(0x10000 + ((u32::from(unit) - 0xD800) << 10) + u32::from(next) - 0xDC00)
+            }
+            _ => Some(u32::from(unit)),
+        }
+    }
+
+    /// The code units from `start` up to `end`, like `substring`: both are clamped to the
+    /// length and swapped if `start` is greater. A surrogate pair may be split.
+    pub fn substring(&self, start: usize, end: usize) -> Utf16String {
+        let start = start.min(self.len());
+        let end = end.min(self.len());
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        Utf16String::from_units(self.units[start..end].to_vec())
+    }
+
+    pub fn push(&mut self, c: char) {
+        let mut buffer = [0; 2];
+        self.units.extend_from_slice(c.encode_utf16(&mut buffer));
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.units.extend(s.encode_utf16());
+    }
+
+    pub fn push_unit(&mut self, unit: u16) {
+        self.units.push(unit);
+    }
+
+    /// Iterate over the characters, with an error for each lone surrogate.
+    pub fn chars(&self) -> std::char::DecodeUtf16<std::iter::Copied<std::slice::Iter<'_, u16>>> {
+        char::decode_utf16(self.units.iter().copied())
+    }
+
+    /// Returns true if there are no lone surrogates, like `isWellFormed`.
+    pub fn is_well_formed(&self) -> bool {
+        self.chars().all(|c| c.is_ok())
+    }
+
+    /// Convert to UTF-8, replacing each lone surrogate with U+FFFD.
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(&self.units)
+    }
+
+    /// Encode as WTF-8: UTF-8 in which each lone surrogate takes the three-byte form its code
+    /// point would have. Well-formed strings give exactly their UTF-8 bytes.
+    pub fn to_wtf8(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.units.len());
+        for c in self.chars() {
+            match c {
+                Ok(c) => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                Err(e) => {
+                    let unit = e.unpaired_surrogate();
+                    out.extend_from_slice(&[
+                        0xE0 | (unit >> 12) as u8,
+                        0x80 | (unit >> 6 & 0x3F) as u8,
+                        0x80 | (unit & 0x3F) as u8,
+                    ]);
+                }
+            }
+        }
+        out
+    }
+
+    /// Decode WTF-8, or `None` if `bytes` is not well-formed WTF-8. A lead surrogate directly
+    /// followed by a trail surrogate is rejected, since WTF-8 writes the pair as one
+    /// four-byte sequence.
+    pub fn from_wtf8(bytes: &[u8]) -> Option<Utf16String> {
+        let mut units = Vec::with_capacity(bytes.len());
+        let mut rest = bytes;
+        loop {
+            let (valid, tail) = match std::str::from_utf8(rest) {
+                Ok(text) => (text, &[][..]),
+                Err(e) => {
+                    let (valid, tail) = rest.split_at(e.valid_up_to());
+                    let valid =
+                        std::str::from_utf8(valid).expect("prefix up to valid_up_to is UTF-8");
+                    (valid, tail)
+                }
+            };
+            units.extend(valid.encode_utf16());
+            let [0xED, second @ 0xA0..=0xBF, third @ 0x80..=0xBF, tail @ ..] = tail else {
+                return tail.is_empty().then_some(Utf16String { units });
+            };
+            let unit = 0xD000 | u16::from(second & 0x3F) << 6 | u16::from(third & 0x3F);
+            if is_trail_surrogate(unit) && units.last().is_some_and(|&u| is_lead_surrogate(u)) {
+                return None;
+            }
+            units.push(unit);
+            rest = tail;
+        }
+    }
+}
+
+impl Index<usize> for Utf16String {
+    type Output = u16;
+
+    fn index(&self, index: usize) -> &u16 {
+        &self.units[index]
+    }
+}
+
+impl From<&str> for Utf16String {
+    fn from(s: &str) -> Self {
+        Utf16String {
+            units: s.encode_utf16().collect(),
+        }
+    }
+}
+
+impl From<&String> for Utf16String {
+    fn from(s: &String) -> Self {
+        Utf16String::from(s.as_str())
+    }
+}
+
+impl TryFrom<&Utf16String> for String {
+    type Error = FromUtf16Error;
+
+    /// Convert to UTF-8, failing on a lone surrogate.
+    fn try_from(s: &Utf16String) -> Result<String, FromUtf16Error> {
+        String::from_utf16(&s.units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(decoder: &mut Utf16Decoder, bytes: &[u8]) -> Result<String, Utf16ErrorKind> {
+        let mut output = String::new();
+        decoder.decode(bytes, &mut output, true).map(|()| output)
+    }
+
+    #[test]
+    fn decodes_both_byte_orders() {
+        let le = Utf16Decoder::new_le(ErrorMode::Fatal);
+        let be = Utf16Decoder::new_be(ErrorMode::Fatal);
+        assert_eq!(
+            decode(&mut le.clone(), b"a\0\xfd\xff=\xd8\0\xde").unwrap(),
+            "a\u{FFFD}😀"
+        );
+        assert_eq!(
+            decode(&mut be.clone(), b"\0a\x30\x42\xd8=\xde\0").unwrap(),
+            "aあ😀"
+        );
+        assert_eq!(
+            decode(&mut le.clone(), b"a\0b"),
+            Err(Utf16ErrorKind::UnexpectedEof)
+        );
+        assert_eq!(
+            decode(&mut le.clone(), b"\0\xdc"),
+            Err(Utf16ErrorKind::LoneSurrogate)
+        );
+
+        let mut le = Utf16Decoder::new_le(ErrorMode::Replacement);
+        // A lone lead surrogate is replaced and the unit after it decoded afresh.
+        assert_eq!(
+            decode(&mut le, b"=\xd8a\0=\xd8=\xd8\0\xde").unwrap(),
+            "\u{FFFD}a\u{FFFD}😀"
+        );
+        assert_eq!(
+            decode(&mut le, b"\0\xdcb\0=\xd8").unwrap(),
+            "\u{FFFD}b\u{FFFD}"
+        );
+        assert_eq!(decode(&mut le, b"b\0c").unwrap(), "b\u{FFFD}");
+    }
+
+    #[test]
+    fn decodes_across_chunks() {
+        let mut decoder = Utf16Decoder::new_be(ErrorMode::Fatal);
+        let mut output = String::new();
+        let pending = [true, true, true, false, true, false];
+        for (byte, pending) in b"\xd8=\xde\0\0!".iter().zip(pending) {
+            decoder.decode(&[*byte], &mut output, false).unwrap();
+            assert_eq!(decoder.has_pending(), pending);
+        }
+        decoder.decode(&[], &mut output, true).unwrap();
+        assert_eq!(output, "😀!");
+    }
+
+    #[test]
+    fn code_unit_indexing() {
+        let s = Utf16String::from("a😀b");
+        assert_eq!(s.len(), 4);
+        assert_eq!(s[1], 0xD83D);
+        assert_eq!(s.code_unit_at(2), Some(0xDE00));
+        assert_eq!(s.code_unit_at(4), None);
+        assert_eq!(s.code_point_at(1), Some(0x1F600));
+        assert_eq!(s.code_point_at(2), Some(0xDE00));
+        let half = s.substring(5, 2);
+        assert_eq!(half.units(), &[0xDE00, u16::from(b'b')]);
+        assert!(!half.is_well_formed());
+        assert_eq!(half.to_string_lossy(), "\u{FFFD}b");
+        assert!(String::try_from(&half).is_err());
+        assert_eq!(String::try_from(&s).unwrap(), "a😀b");
+        // Code unit order puts U+1F600 (0xD83D ...) before U+FFFD.
+        assert!(Utf16String::from("😀") < Utf16String::from("\u{FFFD}"));
+    }
+
+    #[test]
+    fn wtf8_round_trips_lone_surrogates() {
+        let mut s = Utf16String::from("x");
+        s.push_unit(0xD800);
+        s.push('é');
+        s.push_unit(0xDFFF);
+        s.push_str("😀");
+        let wtf8 = s.to_wtf8();
+        assert_eq!(wtf8, b"x\xed\xa0\x80\xc3\xa9\xed\xbf\xbf\xf0\x9f\x98\x80");
+        assert_eq!(Utf16String::from_wtf8(&wtf8), Some(s));
+        assert_eq!(Utf16String::from("plain").to_wtf8(), b"plain");
+        // A split surrogate pair is not WTF-8, and other invalid UTF-8 stays invalid.
+        assert_eq!(Utf16String::from_wtf8(b"\xed\xa0\xbd\xed\xb8\x80"), None);
+        assert_eq!(Utf16String::from_wtf8(b"a\xff"), None);
+        assert_eq!(Utf16String::from_wtf8(b"\xed\xa0"), None);
+    }
+}
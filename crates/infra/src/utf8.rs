@@ -1,92 +1,391 @@
 // SPDX-License-Identifier: MIT
-//! Minimal UTF-8 validation and code point iterator.
+//! UTF-8 validation, a code point iterator and a streaming decoder.
+//! `validate_utf8` reports where the input stops being valid and how long the bad sequence
+//! is, like `std::str::from_utf8`, with a finer `Utf8ErrorKind`; ASCII runs are skipped a
+//! block at a time.
+//! `Utf8Decoder` is the Encoding Standard's UTF-8 decoder: it decodes a stream chunk by chunk,
+//! carrying incomplete sequences across chunk boundaries, and either stops at the first error
+//! (fatal mode) or emits one U+FFFD per maximal subpart of an ill-formed sequence.
+
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Utf8ErrorKind {
+    /// A continuation byte (0x80..=0xBF) where a sequence should start.
     InvalidStart,
+    /// The input ends in the middle of a sequence.
     UnexpectedEof,
+    /// A sequence is cut short by a byte that is not a continuation byte.
     InvalidContinuation,
+    /// 0xC0 or 0xC1, or a 3- or 4-byte sequence for a code point that fits in fewer bytes.
     Overlong,
+    /// An encoded UTF-16 surrogate (U+D800..=U+DFFF).
     Surrogate,
+    /// 0xF5..=0xFF, or a sequence above U+10FFFF.
     OutOfRange,
 }
 
-pub fn validate_utf8(bytes: &[u8]) -> Result<(), Utf8ErrorKind> {
+impl Display for Utf8ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use Utf8ErrorKind::*;
+        match self {
+            InvalidStart => write!(f, "unexpected continuation byte"),
+            UnexpectedEof => write!(f, "incomplete sequence at end of input"),
+            InvalidContinuation => write!(f, "missing continuation byte"),
+            Overlong => write!(f, "overlong encoding"),
+            Surrogate => write!(f, "encoded surrogate"),
+            OutOfRange => write!(f, "code point beyond U+10FFFF"),
+        }
+    }
+}
+
+/// Where and why validation failed, in the shape of `std::str::Utf8Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf8Error {
+    valid_up_to: usize,
+    error_len: Option<u8>,
+    kind: Utf8ErrorKind,
+}
+
+impl Utf8Error {
+    /// The length of the valid prefix of the input.
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// The length of the maximal subpart of the ill-formed sequence at `valid_up_to`: the
+    /// bytes a replacing decoder turns into one U+FFFD. None when the input ends inside a
+    /// sequence that more input could still complete.
+    pub fn error_len(&self) -> Option<usize> {
+        self.error_len.map(usize::from)
+    }
+
+    pub fn kind(&self) -> Utf8ErrorKind {
+        self.kind
+    }
+}
+
+impl Display for Utf8Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.error_len {
+            Some(len) => write!(
+                f,
+                "invalid UTF-8 sequence of {} bytes from index {}: {}",
+                len, self.valid_up_to, self.kind
+            ),
+            None => write!(
+                f,
+                "incomplete UTF-8 byte sequence from index {}",
+                self.valid_up_to
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Utf8Error {}
+
+pub fn validate_utf8(bytes: &[u8]) -> Result<(), Utf8Error> {
     let mut i = 0;
     while i < bytes.len() {
-        let b = bytes[i];
-        if b < 0x80 {
-            i += 1;
+        if bytes[i] < 0x80 {
+            i += ascii_prefix_len(&bytes[i..]);
             continue;
-        } else if (0xC2..=0xDF).contains(&b) {
-            if i + 1 >= bytes.len() { return Err(Utf8ErrorKind::UnexpectedEof); }
-            if !is_cont(bytes[i+1]) { return Err(Utf8ErrorKind::InvalidContinuation); }
-            i += 2;
-        } else if b == 0xE0 {
-            if i + 2 >= bytes.len() { return Err(Utf8ErrorKind::UnexpectedEof); }
-            let b1 = bytes[i+1]; let b2 = bytes[i+2];
-            if !(0xA0..=0xBF).contains(&b1) || !is_cont(b2) { return Err(Utf8ErrorKind::InvalidContinuation); }
-            i += 3;
-        } else if (0xE1..=0xEC).contains(&b) || (0xEE..=0xEF).contains(&b) {
-            if i + 2 >= bytes.len() { return Err(Utf8ErrorKind::UnexpectedEof); }
-            if !is_cont(bytes[i+1]) || !is_cont(bytes[i+2]) { return Err(Utf8ErrorKind::InvalidContinuation); }
-            i += 3;
-        } else if b == 0xED {
-            if i + 2 >= bytes.len() { return Err(Utf8ErrorKind::UnexpectedEof); }
-            // 0xED 0xA0..0xBF => surrogates U+D800..U+DFFF (invalid)
-            let b1 = bytes[i+1]; let b2 = bytes[i+2];
-            if !(0x80..=0x9F).contains(&b1) || !is_cont(b2) { return Err(Utf8ErrorKind::InvalidContinuation); }
-            return Err(Utf8ErrorKind::Surrogate);
-        } else if b == 0xF0 {
-            if i + 3 >= bytes.len() { return Err(Utf8ErrorKind::UnexpectedEof); }
-            let b1 = bytes[i+1]; let b2 = bytes[i+2]; let b3 = bytes[i+3];
-            if !(0x90..=0xBF).contains(&b1) || !is_cont(b2) || !is_cont(b3) { return Err(Utf8ErrorKind::InvalidContinuation); }
-            i += 4;
-        } else if (0xF1..=0xF3).contains(&b) {
-            if i + 3 >= bytes.len() { return Err(Utf8ErrorKind::UnexpectedEof); }
-            if !is_cont(bytes[i+1]) || !is_cont(bytes[i+2]) || !is_cont(bytes[i+3]) { return Err(Utf8ErrorKind::InvalidContinuation); }
-            i += 4;
-        } else if b == 0xF4 {
-            if i + 3 >= bytes.len() { return Err(Utf8ErrorKind::UnexpectedEof); }
-            let b1 = bytes[i+1]; let b2 = bytes[i+2]; let b3 = bytes[i+3];
-            if !(0x80..=0x8F).contains(&b1) || !is_cont(b2) || !is_cont(b3) { return Err(Utf8ErrorKind::InvalidContinuation); }
-            i += 4;
-        } else {
-            return Err(Utf8ErrorKind::InvalidStart);
+        }
+        match check_sequence(&bytes[i..]) {
+            Ok(len) => i += len,
+            Err((kind, error_len)) => {
+                return Err(Utf8Error {
+                    valid_up_to: i,
+                    error_len,
+                    kind,
+                })
+            }
         }
     }
     Ok(())
 }
 
+/// The length of the ASCII prefix of `bytes`. Whole 16-byte blocks are tested with one mask
+/// so the loop can be vectorized; the rest is checked byte by byte.
+fn ascii_prefix_len(bytes: &[u8]) -> usize {
+    const HIGH_BITS: u128 = u128::from_ne_bytes([0x80; 16]);
+    let mut len = 0;
+    for block in bytes.chunks_exact(16) {
+        let word = u128::from_ne_bytes(block.try_into().expect("16-byte block"));
+        if word & HIGH_BITS != 0 {
+            break;
+        }
+        len += 16;
+    }
+    len + bytes[len..].iter().take_while(|b| b.is_ascii()).count()
+}
+
+/// Check the sequence at the start of `bytes` (whose first byte is not ASCII), returning its
+/// length, or the error kind and maximal-subpart length.
+fn check_sequence(bytes: &[u8]) -> Result<usize, (Utf8ErrorKind, Option<u8>)> {
+    let lead = bytes[0];
+    let Some((needed, lower, upper)) = sequence_shape(lead) else {
+        return Err((invalid_lead_kind(lead), Some(1)));
+    };
+    for k in 1..=needed {
+        let Some(&b) = bytes.get(k) else {
+            return Err((Utf8ErrorKind::UnexpectedEof, None));
+        };
+        let (lower, upper) = if k == 1 { (lower, upper) } else { (0x80, 0xBF) };
+        if !(lower..=upper).contains(&b) {
+            let kind = if k == 1 {
+                second_byte_error_kind(lead, b)
+            } else {
+                Utf8ErrorKind::InvalidContinuation
+            };
+            return Err((kind, Some(k as u8)));
+        }
+    }
+    Ok(needed + 1)
+}
+
+/// For a lead byte: the number of continuation bytes and the allowed range of the first one.
+fn sequence_shape(lead: u8) -> Option<(usize, u8, u8)> {
+    match lead {
+        0xC2..=0xDF => Some((1, 0x80, 0xBF)),
+        0xE0 => Some((2, 0xA0, 0xBF)),
+        0xED => Some((2, 0x80, 0x9F)),
+        0xE1..=0xEF => Some((2, 0x80, 0xBF)),
+        0xF0 => Some((3, 0x90, 0xBF)),
+        0xF4 => Some((3, 0x80, 0x8F)),
+        0xF1..=0xF3 => Some((3, 0x80, 0xBF)),
+        _ => None,
+    }
+}
+
+fn invalid_lead_kind(byte: u8) -> Utf8ErrorKind {
+    match byte {
+        0xC0 | 0xC1 => Utf8ErrorKind::Overlong,
+        0xF5..=0xFF => Utf8ErrorKind::OutOfRange,
+        _ => Utf8ErrorKind::InvalidStart,
+    }
+}
+
+/// Why `byte` cannot follow `lead`: a continuation byte outside the lead's narrowed range
+/// means overlong, surrogate or out of range; anything else is a missing continuation.
+fn second_byte_error_kind(lead: u8, byte: u8) -> Utf8ErrorKind {
+    if !is_cont(byte) {
+        return Utf8ErrorKind::InvalidContinuation;
+    }
+    match lead {
+        0xE0 | 0xF0 => Utf8ErrorKind::Overlong,
+        0xED => Utf8ErrorKind::Surrogate,
+        0xF4 => Utf8ErrorKind::OutOfRange,
+        _ => Utf8ErrorKind::InvalidContinuation,
+    }
+}
+
 #[inline]
-fn is_cont(b: u8) -> bool { (b & 0xC0) == 0x80 }
+fn is_cont(b: u8) -> bool {
+    (b & 0xC0) == 0x80
+}
+
+/// What to do with malformed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorMode {
+    /// Emit U+FFFD for each maximal subpart of an ill-formed sequence.
+    #[default]
+    Replacement,
+    /// Stop at the first error.
+    Fatal,
+}
+
+/// Incremental UTF-8 decoder.
+#[derive(Debug, Clone, Default)]
+pub struct Utf8Decoder {
+    mode: ErrorMode,
+    code_point: u32,
+    bytes_seen: u8,
+    bytes_needed: u8,
+    lower_boundary: u8,
+    upper_boundary: u8,
+    lead: u8,
+}
+
+/// The result of feeding one byte to the decoder.
+enum Step {
+    Continue,
+    Emit(char),
+    /// An error; when `reprocess` is set the byte was not consumed and must be fed again.
+    Error {
+        kind: Utf8ErrorKind,
+        reprocess: bool,
+    },
+}
+
+impl Utf8Decoder {
+    pub fn new(mode: ErrorMode) -> Self {
+        Utf8Decoder {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    pub fn mode(&self) -> ErrorMode {
+        self.mode
+    }
+
+    /// Returns true when a sequence is incomplete and waiting for more bytes.
+    pub fn has_pending(&self) -> bool {
+        self.bytes_needed != 0
+    }
 
+    /// Decode `chunk`, appending to `output`. Set `last` on the final chunk so that a
+    /// truncated sequence at the end of the stream is reported.
+    ///
+    /// In fatal mode the first error is returned; text decoded before it has already been
+    /// appended, and the decoder is ready for a new stream.
+    pub fn decode(
+        &mut self,
+        chunk: &[u8],
+        output: &mut String,
+        last: bool,
+    ) -> Result<(), Utf8ErrorKind> {
+        output.reserve(chunk.len());
+        let mut rest = chunk;
+        loop {
+            if self.bytes_needed == 0 {
+                // Outside a sequence, hand the longest valid run to the standard library.
+                let valid = match std::str::from_utf8(rest) {
+                    Ok(text) => text,
+                    Err(e) => std::str::from_utf8(&rest[..e.valid_up_to()])
+                        .expect("prefix up to valid_up_to is UTF-8"),
+                };
+                output.push_str(valid);
+                rest = &rest[valid.len()..];
+            }
+            let Some((&byte, tail)) = rest.split_first() else {
+                break;
+            };
+            match self.step(byte) {
+                Step::Continue => rest = tail,
+                Step::Emit(c) => {
+                    output.push(c);
+                    rest = tail;
+                }
+                Step::Error { kind, reprocess } => {
+                    if !reprocess {
+                        rest = tail;
+                    }
+                    self.error(kind, output)?;
+                }
+            }
+        }
+        if last && self.bytes_needed != 0 {
+            self.reset();
+            self.error(Utf8ErrorKind::UnexpectedEof, output)?;
+        }
+        Ok(())
+    }
+
+    fn error(&mut self, kind: Utf8ErrorKind, output: &mut String) -> Result<(), Utf8ErrorKind> {
+        match self.mode {
+            ErrorMode::Replacement => {
+                output.push(char::REPLACEMENT_CHARACTER);
+                Ok(())
+            }
+            ErrorMode::Fatal => {
+                self.reset();
+                Err(kind)
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Utf8Decoder::new(self.mode);
+    }
+
+    /// The UTF-8 decoder's handler for one byte.
+    fn step(&mut self, byte: u8) -> Step {
+        if self.bytes_needed == 0 {
+            if byte.is_ascii() {
+                return Step::Emit(byte as char);
+            }
+            let Some((needed, lower, upper)) = sequence_shape(byte) else {
+                return Step::Error {
+                    kind: invalid_lead_kind(byte),
+                    reprocess: false,
+                };
+            };
+            self.lead = byte;
+            self.bytes_needed = needed as u8;
+            self.lower_boundary = lower;
+            self.upper_boundary = upper;
+            self.code_point = u32::from(byte) & (0x7F >> (needed + 1));
+            return Step::Continue;
+        }
+        if !(self.lower_boundary..=self.upper_boundary).contains(&byte) {
+            let kind = if self.bytes_seen == 0 {
+                second_byte_error_kind(self.lead, byte)
+            } else {
+                Utf8ErrorKind::InvalidContinuation
+            };
+            self.reset();
+            return Step::Error {
+                kind,
+                reprocess: true,
+            };
+        }
+        self.lower_boundary = 0x80;
+        self.upper_boundary = 0xBF;
+        self.code_point = (self.code_point << 6) | u32::from(byte & 0x3F);
+        self.bytes_seen += 1;
+        if self.bytes_seen != self.bytes_needed {
+            return Step::Continue;
+        }
+        let c = char::from_u32(self.code_point).expect("boundaries exclude invalid scalars");
+        self.reset();
+        Step::Emit(c)
+    }
+}
+
+/// Iterates over the code points of UTF-8 bytes, yielding an error for each maximal subpart of
+/// an ill-formed sequence (where the replacement decoder would emit U+FFFD).
 pub struct CodePoints<'a> {
     bytes: &'a [u8],
     i: usize,
+    decoder: Utf8Decoder,
 }
 
 impl<'a> CodePoints<'a> {
-    pub fn new(bytes: &'a [u8]) -> Self { Self { bytes, i: 0 } }
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            i: 0,
+            decoder: Utf8Decoder::default(),
+        }
+    }
 }
 
 impl<'a> Iterator for CodePoints<'a> {
     type Item = Result<char, Utf8ErrorKind>;
+
     fn next(&mut self) -> Option<Self::Item> {
-        if self.i >= self.bytes.len() { return None; }
-        let b = self.bytes[self.i];
-        if b < 0x80 { self.i += 1; return Some(Ok(b as char)); }
-        // For brevity, reuse validate logic by slicing; in perf paths, a fused decoder would be used.
-        for len in [2usize,3,4] {
-            if self.i + len <= self.bytes.len() {
-                if validate_utf8(&self.bytes[self.i..self.i+len]).is_ok() {
-                    let ch = std::str::from_utf8(&self.bytes[self.i..self.i+len]).ok()?.chars().next().unwrap();
-                    self.i += len;
-                    return Some(Ok(ch));
+        while let Some(&byte) = self.bytes.get(self.i) {
+            match self.decoder.step(byte) {
+                Step::Continue => self.i += 1,
+                Step::Emit(c) => {
+                    self.i += 1;
+                    return Some(Ok(c));
+                }
+                Step::Error { kind, reprocess } => {
+                    if !reprocess {
+                        self.i += 1;
+                    }
+                    return Some(Err(kind));
                 }
             }
         }
-        Some(Err(Utf8ErrorKind::InvalidStart))
+        if self.decoder.has_pending() {
+            self.decoder.reset();
+            return Some(Err(Utf8ErrorKind::UnexpectedEof));
+        }
+        None
     }
 }
 
@@ -102,8 +401,135 @@ mod tests {
 
     #[test]
     fn invalid_sequences() {
-        assert_eq!(validate_utf8(&[0xC0, 0x80]).unwrap_err(), Utf8ErrorKind::Overlong.or(Utf8ErrorKind::InvalidStart));
+        use Utf8ErrorKind::*;
+        let cases: [(&[u8], usize, Option<usize>, Utf8ErrorKind); 12] = [
+            (b"ab\x80", 2, Some(1), InvalidStart),
+            (b"\xC0\x80", 0, Some(1), Overlong),
+            (b"\xC1\xBF", 0, Some(1), Overlong),
+            (b"\xE0\x9F\x80", 0, Some(1), Overlong),
+            (b"\xF0\x8F\x80\x80", 0, Some(1), Overlong),
+            (b"\xED\xA0\x80", 0, Some(1), Surrogate),
+            (b"\xF4\x90\x80\x80", 0, Some(1), OutOfRange),
+            (b"\xF5\x80", 0, Some(1), OutOfRange),
+            (b"\xE2\x82(", 0, Some(2), InvalidContinuation),
+            (b"\xF0\x9F\x98(", 0, Some(3), InvalidContinuation),
+            (b"\xC3(", 0, Some(1), InvalidContinuation),
+            (b"ok\xF0\x9F\x98", 2, None, UnexpectedEof),
+        ];
+        for (bytes, valid_up_to, error_len, kind) in cases {
+            let error = validate_utf8(bytes).unwrap_err();
+            assert_eq!(
+                (error.valid_up_to(), error.error_len(), error.kind()),
+                (valid_up_to, error_len, kind),
+                "{:02X?}",
+                bytes
+            );
+        }
+        // A valid 0xED sequence below the surrogates.
+        assert!(validate_utf8("\u{D7FF}".as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn matches_std_positions() {
+        let mut long = vec![b'x'; 1000];
+        long.extend_from_slice("\u{20AC}".as_bytes());
+        long.extend_from_slice(&[b'y'; 40]);
+        long.push(0xFF);
+        let mut inputs: Vec<Vec<u8>> = vec![long];
+        for first in 0x80..=0xFFu8 {
+            for second in [0x00, 0x7F, 0x80, 0x8F, 0x90, 0x9F, 0xA0, 0xBF, 0xC0] {
+                inputs.push(vec![b'a', first, second, 0x80, 0x80]);
+                inputs.push(vec![first, second]);
+            }
+        }
+        for input in inputs {
+            match (validate_utf8(&input), std::str::from_utf8(&input)) {
+                (Ok(()), Ok(_)) => {}
+                (Err(ours), Err(theirs)) => {
+                    assert_eq!(ours.valid_up_to(), theirs.valid_up_to(), "{:02X?}", input);
+                    assert_eq!(ours.error_len(), theirs.error_len(), "{:02X?}", input);
+                }
+                (ours, theirs) => panic!("{:02X?}: {:?} vs {:?}", input, ours, theirs),
+            }
+        }
     }
-}
 
+    #[test]
+    fn code_points() {
+        let items: Vec<_> = CodePoints::new(b"a\xC3\xA9\xE2\x28\xF0\x9F").collect();
+        assert_eq!(
+            items,
+            [
+                Ok('a'),
+                Ok('\u{E9}'),
+                Err(Utf8ErrorKind::InvalidContinuation),
+                Ok('('),
+                Err(Utf8ErrorKind::UnexpectedEof),
+            ]
+        );
+    }
 
+    fn decode_all(chunks: &[&[u8]], mode: ErrorMode) -> Result<String, Utf8ErrorKind> {
+        let mut decoder = Utf8Decoder::new(mode);
+        let mut output = String::new();
+        for (i, chunk) in chunks.iter().enumerate() {
+            decoder.decode(chunk, &mut output, i + 1 == chunks.len())?;
+        }
+        Ok(output)
+    }
+
+    #[test]
+    fn decodes_sequences_split_across_chunks() {
+        let text = "a\u{E9}\u{20AC}\u{1F600}z";
+        let bytes = text.as_bytes();
+        for split in 0..=bytes.len() {
+            let (head, tail) = bytes.split_at(split);
+            assert_eq!(decode_all(&[head, tail], ErrorMode::Fatal).unwrap(), text);
+        }
+        let mut decoder = Utf8Decoder::default();
+        let mut output = String::new();
+        decoder.decode(b"\xE2\x82", &mut output, false).unwrap();
+        assert!(decoder.has_pending() && output.is_empty());
+        decoder.decode(b"\xAC", &mut output, false).unwrap();
+        assert_eq!(output, "\u{20AC}");
+    }
+
+    #[test]
+    fn replaces_maximal_subparts() {
+        let cases: [(&[u8], &str); 7] = [
+            (b"\xF0\x80\x80", "\u{FFFD}\u{FFFD}\u{FFFD}"),
+            (b"\xE2\x82A", "\u{FFFD}A"),
+            (b"\xF1\x80\x80\xE1\x80\xC2", "\u{FFFD}\u{FFFD}\u{FFFD}"),
+            (b"\xED\xA0\x80", "\u{FFFD}\u{FFFD}\u{FFFD}"),
+            (b"\xC0\xAF", "\u{FFFD}\u{FFFD}"),
+            (b"a\xFFb", "a\u{FFFD}b"),
+            (b"\xF4\x90\x80\x80", "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}"),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(
+                decode_all(&[bytes], ErrorMode::Replacement).unwrap(),
+                expected
+            );
+            let split: Vec<&[u8]> = bytes.chunks(1).collect();
+            assert_eq!(
+                decode_all(&split, ErrorMode::Replacement).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn fatal_mode_reports_errors() {
+        use Utf8ErrorKind::*;
+        let cases: [(&[&[u8]], Utf8ErrorKind); 5] = [
+            (&[b"ok\xE2\x82"], UnexpectedEof),
+            (&[b"\xED", b"\xA0\x80"], Surrogate),
+            (&[b"\xE0\x80\x80"], Overlong),
+            (&[b"\xC3("], InvalidContinuation),
+            (&[b"\xF8"], OutOfRange),
+        ];
+        for (chunks, kind) in cases {
+            assert_eq!(decode_all(chunks, ErrorMode::Fatal), Err(kind));
+        }
+    }
+}